@@ -10,19 +10,23 @@ $rt clippy -Aclippy::arbitrary_source_item_ordering,-Aclippy::doc-include-withou
 # WTX
 
 $rt check-generic wtx
+$rt test-with-features wtx acme
 $rt test-with-features wtx aes-gcm
 $rt test-with-features wtx arbitrary
 $rt test-with-features wtx argon2
 $rt test-with-features wtx base64
 $rt test-with-features wtx borsh
 $rt test-with-features wtx chrono
+$rt test-with-features wtx ciborium
 $rt test-with-features wtx cl-aux
 $rt test-with-features wtx client-api-framework
 $rt test-with-features wtx crypto-common
+$rt test-with-features wtx csv
 $rt test-with-features wtx data-transformation
 $rt test-with-features wtx database
 $rt test-with-features wtx digest
 $rt test-with-features wtx embassy-time
+$rt test-with-features wtx embedded-io-async
 $rt test-with-features wtx fastrand
 $rt test-with-features wtx flate2
 $rt test-with-features wtx foldhash
@@ -34,15 +38,20 @@ $rt test-with-features wtx hmac
 $rt test-with-features wtx http-client-pool
 $rt test-with-features wtx http-server-framework
 $rt test-with-features wtx http2
+$rt test-with-features wtx http3
 $rt test-with-features wtx httparse
 $rt test-with-features wtx matchit
 $rt test-with-features wtx memchr
+$rt test-with-features wtx metrics
+$rt test-with-features wtx mqtt
 $rt test-with-features wtx mysql
 $rt test-with-features wtx pool
 $rt test-with-features wtx postgres
 $rt test-with-features wtx quick-protobuf
+$rt test-with-features wtx quick-xml
 $rt test-with-features wtx rand_chacha
 $rt test-with-features wtx ring
+$rt test-with-features wtx rmp-serde
 $rt test-with-features wtx rust_decimal
 $rt test-with-features wtx rustls
 $rt test-with-features wtx schema-manager
@@ -51,7 +60,9 @@ $rt test-with-features wtx serde
 $rt test-with-features wtx serde_json
 $rt test-with-features wtx sha1
 $rt test-with-features wtx sha2
+$rt test-with-features wtx simd-json
 $rt test-with-features wtx simdutf8
+$rt test-with-features wtx smol
 $rt test-with-features wtx std
 $rt test-with-features wtx tokio
 $rt test-with-features wtx tokio-rustls
@@ -0,0 +1,42 @@
+//! Echoes the text frames of WebSocket connections that are tunneled through HTTP/2 streams
+//! (RFC 8441). The handshake is performed by the framework before the endpoint is called.
+
+use tokio::net::tcp::OwnedWriteHalf;
+use wtx::{
+  http::server_framework::{Router, ServerFrameworkBuilder, web_socket},
+  http2::{Http2Buffer, Http2DataTokio, ServerStream, WebSocketOverStream},
+  misc::{Vector, Xorshift64, simple_seed},
+  web_socket::{Frame, OpCode},
+};
+
+type Stream = ServerStream<Http2DataTokio<Http2Buffer, OwnedWriteHalf, false>>;
+
+#[tokio::main]
+async fn main() -> wtx::Result<()> {
+  let router = Router::paths(wtx::paths!(("/echo", web_socket(echo))))?;
+  ServerFrameworkBuilder::new(Xorshift64::from(simple_seed()), router)
+    .enable_connect_protocol(true)
+    .without_aux()
+    .tokio(
+      &wtx_instances::host_from_args(),
+      |error| eprintln!("{error}"),
+      |_| Ok(()),
+      |error| eprintln!("{error}"),
+    )
+    .await
+}
+
+async fn echo(mut wos: WebSocketOverStream<Stream>) -> wtx::Result<()> {
+  let mut buffer = Vector::new();
+  loop {
+    let mut frame = wos.read_frame(&mut buffer).await?;
+    match frame.op_code() {
+      OpCode::Close => break,
+      OpCode::Text => {
+        wos.write_frame(&mut Frame::new_fin(OpCode::Text, frame.payload_mut())).await?
+      }
+      _ => {}
+    }
+  }
+  wos.close().await
+}
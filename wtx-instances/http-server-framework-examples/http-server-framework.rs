@@ -11,7 +11,7 @@ extern crate tokio;
 extern crate wtx;
 extern crate wtx_instances;
 
-use core::{fmt::Write, ops::ControlFlow};
+use core::{fmt::Write, net::IpAddr, ops::ControlFlow};
 use rand_chacha::rand_core::SeedableRng;
 use tokio::net::{TcpStream, tcp::OwnedWriteHalf};
 use wtx::{
@@ -115,6 +115,7 @@ impl Middleware<(), wtx::Error, Pool> for CustomMiddleware {
     &self,
     _: &mut (),
     _: &mut Self::Aux,
+    _: IpAddr,
     _: &mut Request<ReqResBuffer>,
     _: &mut Pool,
   ) -> wtx::Result<ControlFlow<StatusCode, ()>> {
@@ -0,0 +1,40 @@
+//! Low level HTTP/2 client that tunnels a WebSocket connection through a single stream.
+//!
+//! The server must enable the connect protocol through its settings.
+
+extern crate tokio;
+extern crate wtx;
+
+use tokio::net::TcpStream;
+use wtx::{
+  http::Headers,
+  http2::{Http2Buffer, Http2ErrorCode, Http2Params, Http2Tokio, WebSocketOverStream},
+  misc::{Uri, Vector, Xorshift64, simple_seed},
+  web_socket::{Frame, OpCode},
+};
+
+#[tokio::main]
+async fn main() -> wtx::Result<()> {
+  let uri = Uri::new("SOME_URI");
+  let mut rng = Xorshift64::from(simple_seed());
+  let (frame_reader, mut http2) = Http2Tokio::connect(
+    Http2Buffer::new(&mut rng),
+    Http2Params::default(),
+    TcpStream::connect(uri.hostname_with_implied_port()).await?.into_split(),
+  )
+  .await?;
+  let _jh = tokio::spawn(frame_reader);
+  let mut stream = http2.stream().await?;
+  let mut buffer = Vector::new();
+  let mut wos =
+    WebSocketOverStream::connect(&Headers::new(), false, rng, &mut stream, &uri.to_ref()).await?;
+  wos.write_frame(&mut Frame::new_fin(OpCode::Text, *b"Hello!")).await?;
+  let frame = wos.read_frame(&mut buffer).await?;
+  if let Some(elem) = frame.text_payload() {
+    println!("{elem}");
+  }
+  wos.close().await?;
+  stream.common().clear(false).await?;
+  http2.send_go_away(Http2ErrorCode::NoError).await;
+  Ok(())
+}
@@ -5,12 +5,13 @@ extern crate tokio_rustls;
 extern crate wtx;
 extern crate wtx_instances;
 
+use core::time::Duration;
 use tokio::{io::WriteHalf, net::TcpStream};
 use tokio_rustls::server::TlsStream;
 use wtx::{
   http::{
-    AutoStream, ManualServerStreamTokio, OperationMode, OptionedServer, ReqResBuffer, Response,
-    StatusCode, is_web_socket_handshake,
+    AutoStream, ConnLimits, ManualServerStreamTokio, OperationMode, OptionedServer, ReqResBuffer,
+    Response, StatusCode, is_web_socket_handshake,
   },
   http2::{Http2Buffer, Http2Params, WebSocketOverStream},
   misc::{TokioRustlsAcceptor, Vector, Xorshift64, simple_seed},
@@ -25,6 +26,8 @@ async fn main() -> wtx::Result<()> {
         .http2()
         .build_with_cert_chain_and_priv_key(wtx_instances::CERT, wtx_instances::KEY)?,
       &wtx_instances::host_from_args(),
+      ConnLimits::new(),
+      (core::future::pending(), Duration::ZERO),
       (),
       (),
     ),
@@ -44,6 +47,7 @@ async fn main() -> wtx::Result<()> {
     |_, _, protocol, req, _| {
       Ok((
         (),
+        None,
         if is_web_socket_handshake(&mut req.rrd.headers, req.method, protocol) {
           OperationMode::Manual
         } else {
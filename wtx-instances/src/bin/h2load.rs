@@ -2,11 +2,12 @@
 
 #![expect(clippy::print_stderr, reason = "internal")]
 
+use core::time::Duration;
 use tokio::net::tcp::OwnedWriteHalf;
 use wtx::{
   http::{
-    AutoStream, ManualServerStreamTokio, OperationMode, OptionedServer, ReqResBuffer, Response,
-    StatusCode,
+    AutoStream, ConnLimits, ManualServerStreamTokio, OperationMode, OptionedServer, ReqResBuffer,
+    Response, StatusCode,
   },
   http2::{Http2Buffer, Http2Params},
   misc::{Xorshift64, simple_seed},
@@ -15,7 +16,14 @@ use wtx::{
 #[tokio::main]
 async fn main() -> wtx::Result<()> {
   OptionedServer::http2_tokio(
-    ((), "127.0.0.1:9000", Xorshift64::from(simple_seed()), ()),
+    (
+      (),
+      "127.0.0.1:9000",
+      ConnLimits::new(),
+      (core::future::pending(), Duration::ZERO),
+      Xorshift64::from(simple_seed()),
+      (),
+    ),
     |_| {},
     |_, stream| async move { Ok(stream.into_split()) },
     |error| eprintln!("{error}"),
@@ -29,7 +37,7 @@ async fn main() -> wtx::Result<()> {
       ))
     },
     |_| Ok(((), ReqResBuffer::empty())),
-    |_, _, _, _, _| Ok(((), OperationMode::Auto)),
+    |_, _, _, _, _| Ok(((), None, OperationMode::Auto)),
     |error| eprintln!("{error}"),
     auto,
     manual,
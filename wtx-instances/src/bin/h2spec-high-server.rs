@@ -2,11 +2,12 @@
 
 #![expect(clippy::print_stderr, reason = "internal")]
 
+use core::time::Duration;
 use tokio::net::tcp::OwnedWriteHalf;
 use wtx::{
   http::{
-    AutoStream, ManualServerStreamTokio, OperationMode, OptionedServer, ReqResBuffer, Response,
-    StatusCode,
+    AutoStream, ConnLimits, ManualServerStreamTokio, OperationMode, OptionedServer, ReqResBuffer,
+    Response, StatusCode,
   },
   http2::{Http2Buffer, Http2Params},
   misc::{Xorshift64, simple_seed},
@@ -15,13 +16,13 @@ use wtx::{
 #[tokio::main]
 async fn main() -> wtx::Result<()> {
   OptionedServer::http2_tokio(
-    ((), "127.0.0.1:9000", (), ()),
+    ((), "127.0.0.1:9000", ConnLimits::new(), (core::future::pending(), Duration::ZERO), (), ()),
     |_| {},
     |_, stream| async move { Ok(stream.into_split()) },
     |error| eprintln!("{error}"),
     |_| Ok(((), Http2Buffer::new(&mut Xorshift64::from(simple_seed())), Http2Params::default())),
     |_| Ok(((), ReqResBuffer::empty())),
-    |_, _, _, _, _| Ok(((), OperationMode::Auto)),
+    |_, _, _, _, _| Ok(((), None, OperationMode::Auto)),
     |error| eprintln!("{error}"),
     auto,
     manual,
@@ -7,6 +7,7 @@ use syn::{Lit, Meta, NestedMeta};
 #[derive(Debug)]
 pub(crate) enum DataFormat {
   Borsh,
+  GraphQl(String),
   Json,
   JsonRpc(String),
   Protobuf,
@@ -42,6 +43,7 @@ impl DataFormat {
       };
     }
     match *self {
+      DataFormat::GraphQl(_) => rslt!(http_method_and_mime_type!(Post, ApplicationJson)),
       DataFormat::Json => rslt!(http_mime_type!(ApplicationJson)),
       DataFormat::JsonRpc(_) => rslt!(http_method_and_mime_type!(Post, ApplicationJson)),
       DataFormat::Protobuf => rslt!(http_mime_type!(ApplicationVndGoogleProtobuf)),
@@ -59,6 +61,12 @@ impl DataFormat {
         dfe_ext_res_ctnt_wrapper: ident_fn("VerbatimResponse"),
         dfe_pkgs_aux_call: quote::quote!(verbatim_request(data)),
       },
+      DataFormat::GraphQl(query) => DataFormatElems {
+        dfe_data_format_builder_fn: ident_fn("build_graph_ql"),
+        dfe_ext_req_ctnt_wrapper: ident_fn("GraphQlPkgRequest"),
+        dfe_ext_res_ctnt_wrapper: ident_fn("GraphQlPkgResponse"),
+        dfe_pkgs_aux_call: quote::quote!(graph_ql_request(#query, data)),
+      },
       DataFormat::Json => DataFormatElems {
         dfe_data_format_builder_fn: ident_fn("build_json"),
         dfe_ext_req_ctnt_wrapper: ident_fn("VerbatimRequest"),
@@ -111,12 +119,14 @@ impl<'attrs> TryFrom<&'attrs NestedMeta> for DataFormat {
     };
     if let Meta::List(meta_list) = meta {
       let first_path_seg_ident = first_path_seg_ident!(meta_list.path);
-      if first_path_seg_ident == "json_rpc" {
-        if let Some(NestedMeta::Lit(Lit::Str(elem))) = meta_list.nested.first() {
-          Ok(Self::JsonRpc(elem.value()))
-        } else {
-          Err(crate::Error::IncorrectJsonRpcDataFormat)
-        }
+      let lit_str = || match meta_list.nested.first() {
+        Some(NestedMeta::Lit(Lit::Str(elem))) => Some(elem.value()),
+        _ => None,
+      };
+      if first_path_seg_ident == "graph_ql" {
+        lit_str().map(Self::GraphQl).ok_or(crate::Error::IncorrectGraphQlDataFormat)
+      } else if first_path_seg_ident == "json_rpc" {
+        lit_str().map(Self::JsonRpc).ok_or(crate::Error::IncorrectJsonRpcDataFormat)
       } else {
         Err(crate::Error::UnknownDataFormat)
       }
@@ -25,6 +25,7 @@ pub(crate) enum Error {
   BadRes(Span),
   DuplicatedGlobalPkgAttr(Span),
   DuplicatedLocalPkgAttr(Span),
+  IncorrectGraphQlDataFormat,
   IncorrectJsonRpcDataFormat,
   MandatoryOuterAttrsAreNotPresent,
   NoEnumStructOrType(Span),
@@ -108,6 +109,11 @@ impl From<Error> for syn::Error {
         span,
         "It is not possible to have more than one `pkg` attribute in the same element.",
       ),
+      Error::IncorrectGraphQlDataFormat => syn::Error::new(
+        Span::call_site(),
+        "GraphQL expects its query document. For example, \
+          `#[pkg(data_format(graph_ql(\"query { field }\")))]`",
+      ),
       Error::IncorrectJsonRpcDataFormat => syn::Error::new(
         Span::call_site(),
         "JSON-RPC expects the name of its method. For example, \
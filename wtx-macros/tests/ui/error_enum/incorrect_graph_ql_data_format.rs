@@ -0,0 +1,11 @@
+#[wtx_macros::pkg(data_format(graph_ql(Bar)), id(Foo))]
+mod pkg {
+  #[pkg::req_data]
+  struct Req;
+
+  #[pkg::res_data]
+  struct Res;
+}
+
+fn main() {
+}
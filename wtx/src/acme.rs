@@ -0,0 +1,25 @@
+//! Automatic Certificate Management Environment (ACME) client that issues and renews the
+//! certificates of TLS servers through HTTP-01 or TLS-ALPN-01 challenges.
+//!
+//! TLS-ALPN-01 challenges are answered by [`crate::misc::TokioRustlsCertResolver`] when
+//! configured with [`crate::misc::TokioRustlsCertResolver::acme_challenges`] while HTTP-01
+//! challenges must be answered by HTTP/1.1 servers listening on port 80 with the help of
+//! [`AcmeChallenges::http01_key_authorization`].
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc8555>
+
+mod acme_certificate;
+mod acme_challenge_ty;
+mod acme_challenges;
+mod acme_client;
+mod acme_error;
+mod der;
+mod protocol;
+
+pub use acme_certificate::AcmeCertificate;
+pub use acme_challenge_ty::AcmeChallengeTy;
+pub use acme_challenges::AcmeChallenges;
+pub use acme_client::AcmeClient;
+pub use acme_error::AcmeError;
+
+pub(crate) const ACME_TLS_ALPN: &[u8] = b"acme-tls/1";
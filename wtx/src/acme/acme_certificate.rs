@@ -0,0 +1,12 @@
+use alloc::string::String;
+
+/// Certificate issued by an ACME server.
+///
+/// Both elements can be stored and later given to [`crate::misc::TokioRustlsCertResolver::new`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcmeCertificate {
+  /// PEM-encoded certificate chain
+  pub cert_chain: String,
+  /// PEM-encoded private key of the leaf certificate
+  pub priv_key: String,
+}
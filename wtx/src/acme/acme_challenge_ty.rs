@@ -0,0 +1,24 @@
+/// Type of the challenge used to prove the control of domains.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AcmeChallengeTy {
+  /// Key authorizations are served through HTTP/1.1 on port 80.
+  ///
+  /// <https://datatracker.ietf.org/doc/html/rfc8555#section-8.3>
+  Http01,
+  /// Self-signed certificates are presented on port 443 to handshakes that negotiate the
+  /// `acme-tls/1` protocol.
+  ///
+  /// <https://datatracker.ietf.org/doc/html/rfc8737>
+  TlsAlpn01,
+}
+
+impl AcmeChallengeTy {
+  /// Identifier used by ACME servers.
+  #[inline]
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::Http01 => "http-01",
+      Self::TlsAlpn01 => "tls-alpn-01",
+    }
+  }
+}
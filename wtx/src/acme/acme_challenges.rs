@@ -0,0 +1,207 @@
+use crate::acme::{AcmeChallengeTy, AcmeError, der};
+use alloc::{string::String, sync::Arc, vec::Vec};
+use ring::{
+  digest,
+  rand::SystemRandom,
+  signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair},
+};
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use std::sync::RwLock;
+use tokio_rustls::rustls::{crypto::ring::sign::any_supported_type, sign::CertifiedKey};
+
+/// Challenges that are pending while certificates are issued by [`crate::acme::AcmeClient`].
+///
+/// Instances are shared with servers, which must answer the challenges of the selected
+/// [`AcmeChallengeTy`].
+#[derive(Debug, Default)]
+pub struct AcmeChallenges {
+  http01: RwLock<Vec<(String, String)>>,
+  tls_alpn01: RwLock<Vec<(String, Arc<CertifiedKey>)>>,
+}
+
+impl AcmeChallenges {
+  /// Instance without challenges.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { http01: RwLock::new(Vec::new()), tls_alpn01: RwLock::new(Vec::new()) }
+  }
+
+  /// Key authorization of `token`, which must be returned with a `200 OK` status code to
+  /// `GET /.well-known/acme-challenge/{token}` requests.
+  #[inline]
+  pub fn http01_key_authorization(&self, token: &str) -> Option<String> {
+    let guard = self.http01.read().ok()?;
+    guard.iter().find(|el| el.0 == token).map(|el| el.1.clone())
+  }
+
+  /// Certificate presented to handshakes that negotiate `acme-tls/1` for `domain`.
+  #[inline]
+  pub fn tls_alpn01_certified_key(&self, domain: &str) -> Option<Arc<CertifiedKey>> {
+    let guard = self.tls_alpn01.read().ok()?;
+    guard.iter().find(|el| el.0.eq_ignore_ascii_case(domain)).map(|el| Arc::clone(&el.1))
+  }
+
+  pub(crate) fn insert(
+    &self,
+    domain: &str,
+    key_authorization: String,
+    token: &str,
+    ty: AcmeChallengeTy,
+  ) -> crate::Result<()> {
+    match ty {
+      AcmeChallengeTy::Http01 => {
+        if let Ok(mut guard) = self.http01.write() {
+          guard.push((token.into(), key_authorization));
+        }
+      }
+      AcmeChallengeTy::TlsAlpn01 => {
+        let certified_key = Arc::new(tls_alpn01_certified_key(domain, &key_authorization)?);
+        if let Ok(mut guard) = self.tls_alpn01.write() {
+          guard.push((domain.into(), certified_key));
+        }
+      }
+    }
+    Ok(())
+  }
+
+  pub(crate) fn remove(&self, domain: &str, token: &str, ty: AcmeChallengeTy) {
+    match ty {
+      AcmeChallengeTy::Http01 => {
+        if let Ok(mut guard) = self.http01.write() {
+          guard.retain(|el| el.0 != token);
+        }
+      }
+      AcmeChallengeTy::TlsAlpn01 => {
+        if let Ok(mut guard) = self.tls_alpn01.write() {
+          guard.retain(|el| el.0 != domain);
+        }
+      }
+    }
+  }
+}
+
+fn tls_alpn01_certified_key(domain: &str, key_authorization: &str) -> crate::Result<CertifiedKey> {
+  let rng = SystemRandom::new();
+  let alg = &ECDSA_P256_SHA256_ASN1_SIGNING;
+  let pkcs8 = EcdsaKeyPair::generate_pkcs8(alg, &rng).map_err(|_err| AcmeError::InvalidKey)?;
+  let key_pair =
+    EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref(), &rng).map_err(|_err| AcmeError::InvalidKey)?;
+  let digest = digest::digest(&digest::SHA256, key_authorization.as_bytes());
+  let cert = der::tls_alpn01_cert(digest.as_ref(), domain, &key_pair, &rng)?;
+  let priv_key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(pkcs8.as_ref().to_vec()));
+  Ok(CertifiedKey::new(alloc::vec![CertificateDer::from(cert)], any_supported_type(&priv_key)?))
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    acme::{ACME_TLS_ALPN, AcmeChallengeTy, AcmeChallenges},
+    misc::{TokioRustlsAcceptor, TokioRustlsCertResolver, TokioRustlsConnector},
+  };
+  use alloc::{sync::Arc, vec::Vec};
+  use core::net::SocketAddr;
+  use rustls_pki_types::{CertificateDer, ServerName, UnixTime};
+  use tokio::net::{TcpListener, TcpStream};
+  use tokio_rustls::{
+    TlsConnector,
+    rustls::{
+      ClientConfig, DigitallySignedStruct, Error, SignatureScheme,
+      client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+      crypto::ring::default_provider,
+    },
+  };
+
+  static CERT: &[u8] = include_bytes!("../../../.certs/cert.pem");
+  static KEY: &[u8] = include_bytes!("../../../.certs/key.pem");
+  static ROOT_CA: &[u8] = include_bytes!("../../../.certs/root-ca.crt");
+
+  #[tokio::test]
+  async fn tls_alpn01_challenges_are_answered() {
+    let challenges = Arc::new(AcmeChallenges::new());
+    challenges.insert("localhost", "foo.bar".into(), "foo", AcmeChallengeTy::TlsAlpn01).unwrap();
+    let resolver =
+      TokioRustlsCertResolver::new(CERT, KEY).unwrap().acme_challenges(Arc::clone(&challenges));
+    let acceptor =
+      TokioRustlsAcceptor::without_client_auth().http2().build_with_cert_resolver(resolver.into());
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let _server = tokio::spawn(async move {
+      loop {
+        let (stream, _) = listener.accept().await.unwrap();
+        let _rslt = acceptor.accept(stream).await;
+      }
+    });
+
+    let (alpn, peer_certs) = validate(addr).await.unwrap();
+    let certified_key = challenges.tls_alpn01_certified_key("localhost").unwrap();
+    assert_eq!(alpn.as_deref(), Some(ACME_TLS_ALPN));
+    assert_eq!(peer_certs, certified_key.cert);
+
+    let stream = TokioRustlsConnector::default()
+      .push_certs(ROOT_CA)
+      .unwrap()
+      .http2()
+      .connect_without_client_auth("localhost", TcpStream::connect(addr).await.unwrap())
+      .await
+      .unwrap();
+    assert_eq!(stream.get_ref().1.alpn_protocol(), Some(&b"h2"[..]));
+
+    challenges.remove("localhost", "foo", AcmeChallengeTy::TlsAlpn01);
+    assert!(validate(addr).await.is_err());
+  }
+
+  // Connects like ACME servers, which only offer `acme-tls/1` and don't verify certificates
+  // against trust anchors.
+  async fn validate(
+    addr: SocketAddr,
+  ) -> crate::Result<(Option<Vec<u8>>, Vec<CertificateDer<'static>>)> {
+    let mut config = ClientConfig::builder()
+      .dangerous()
+      .with_custom_certificate_verifier(Arc::new(AcmeValidator))
+      .with_no_client_auth();
+    config.alpn_protocols.push(ACME_TLS_ALPN.into());
+    let stream = TlsConnector::from(Arc::new(config))
+      .connect(ServerName::try_from("localhost").unwrap(), TcpStream::connect(addr).await?)
+      .await?;
+    let conn = stream.get_ref().1;
+    Ok((conn.alpn_protocol().map(Into::into), conn.peer_certificates().unwrap_or_default().into()))
+  }
+
+  #[derive(Debug)]
+  struct AcmeValidator;
+
+  impl ServerCertVerifier for AcmeValidator {
+    fn verify_server_cert(
+      &self,
+      _: &CertificateDer<'_>,
+      _: &[CertificateDer<'_>],
+      _: &ServerName<'_>,
+      _: &[u8],
+      _: UnixTime,
+    ) -> Result<ServerCertVerified, Error> {
+      Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+      &self,
+      _: &[u8],
+      _: &CertificateDer<'_>,
+      _: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+      Ok(HandshakeSignatureValid::assertion())
+    }
+
+    fn verify_tls13_signature(
+      &self,
+      _: &[u8],
+      _: &CertificateDer<'_>,
+      _: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+      Ok(HandshakeSignatureValid::assertion())
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+      default_provider().signature_verification_algorithms.supported_schemes()
+    }
+  }
+}
@@ -0,0 +1,21 @@
+use alloc::boxed::Box;
+
+/// ACME error
+#[derive(Debug)]
+pub enum AcmeError {
+  /// An authorization or an order didn't reach a final state after the maximum number of polls.
+  ExhaustedPolls,
+  /// An authorization or the selected challenge became invalid.
+  FailedAuthorization,
+  /// The order became invalid or doesn't have a certificate.
+  FailedOrder,
+  /// Key couldn't be generated, parsed or used to sign.
+  InvalidKey,
+  /// The server didn't offer the selected type of challenge.
+  MissingChallenge,
+  /// Response doesn't contain a `Replay-Nonce` header.
+  MissingNonce,
+  /// The server returned a problem document with the contained type, e.g.,
+  /// `urn:ietf:params:acme:error:rateLimited`.
+  Problem(Box<str>),
+}
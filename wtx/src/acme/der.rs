@@ -0,0 +1,253 @@
+// Minimal DER writer used to create certificate signing requests and TLS-ALPN-01 certificates,
+// both signed with ECDSA P-256.
+
+use crate::acme::AcmeError;
+use alloc::vec::Vec;
+use ring::{
+  rand::SecureRandom,
+  signature::{EcdsaKeyPair, KeyPair},
+};
+
+const BIT_STRING: u8 = 0x03;
+const BOOLEAN: u8 = 0x01;
+const CONTEXT_0: u8 = 0xA0;
+const CONTEXT_3: u8 = 0xA3;
+const DNS_NAME: u8 = 0x82;
+const GENERALIZED_TIME: u8 = 0x18;
+const INTEGER: u8 = 0x02;
+const OCTET_STRING: u8 = 0x04;
+const OID: u8 = 0x06;
+const SEQUENCE: u8 = 0x30;
+const SET: u8 = 0x31;
+const UTC_TIME: u8 = 0x17;
+const UTF8_STRING: u8 = 0x0C;
+
+// 1.3.6.1.5.5.7.1.31
+const OID_ACME_IDENTIFIER: &[u8] = &[43, 6, 1, 5, 5, 7, 1, 31];
+// 2.5.4.3
+const OID_COMMON_NAME: &[u8] = &[85, 4, 3];
+// 1.2.840.10045.2.1
+const OID_EC_PUBLIC_KEY: &[u8] = &[42, 134, 72, 206, 61, 2, 1];
+// 1.2.840.10045.4.3.2
+const OID_ECDSA_WITH_SHA256: &[u8] = &[42, 134, 72, 206, 61, 4, 3, 2];
+// 1.2.840.113549.1.9.14
+const OID_EXTENSION_REQUEST: &[u8] = &[42, 134, 72, 134, 247, 13, 1, 9, 14];
+// 1.2.840.10045.3.1.7
+const OID_PRIME256V1: &[u8] = &[42, 134, 72, 206, 61, 3, 1, 7];
+// 2.5.29.17
+const OID_SUBJECT_ALT_NAME: &[u8] = &[85, 29, 17];
+
+/// PKCS#10 request with an empty subject and all `domains` as subject alternative names.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc2986>
+pub(crate) fn csr(
+  domains: &[&str],
+  key_pair: &EcdsaKeyPair,
+  rng: &dyn SecureRandom,
+) -> crate::Result<Vec<u8>> {
+  let mut info = Vec::new();
+  tlv(&mut info, SEQUENCE, |info| {
+    tlv(info, INTEGER, |el| el.push(0));
+    tlv(info, SEQUENCE, |_| {});
+    subject_public_key_info(info, key_pair);
+    tlv(info, CONTEXT_0, |attributes| {
+      tlv(attributes, SEQUENCE, |attribute| {
+        tlv(attribute, OID, |el| el.extend_from_slice(OID_EXTENSION_REQUEST));
+        tlv(attribute, SET, |set| {
+          tlv(set, SEQUENCE, |extensions| subject_alt_name(extensions, domains));
+        });
+      });
+    });
+  });
+  signed(&info, key_pair, rng)
+}
+
+/// Self-signed certificate of `domain` with the critical `acmeIdentifier` extension, which
+/// contains the SHA-256 digest of the key authorization.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc8737#section-3>
+pub(crate) fn tls_alpn01_cert(
+  digest: &[u8],
+  domain: &str,
+  key_pair: &EcdsaKeyPair,
+  rng: &dyn SecureRandom,
+) -> crate::Result<Vec<u8>> {
+  let mut serial = [0; 16];
+  rng.fill(&mut serial).map_err(|_err| AcmeError::InvalidKey)?;
+  if let Some(elem) = serial.first_mut() {
+    *elem = (*elem & 0b0111_1111) | 0b0100_0000;
+  }
+  let name = |buffer: &mut Vec<u8>| {
+    tlv(buffer, SEQUENCE, |rdns| {
+      tlv(rdns, SET, |rdn| {
+        tlv(rdn, SEQUENCE, |attribute| {
+          tlv(attribute, OID, |el| el.extend_from_slice(OID_COMMON_NAME));
+          tlv(attribute, UTF8_STRING, |el| el.extend_from_slice(domain.as_bytes()));
+        });
+      });
+    });
+  };
+  let mut tbs = Vec::new();
+  tlv(&mut tbs, SEQUENCE, |tbs| {
+    tlv(tbs, CONTEXT_0, |version| tlv(version, INTEGER, |el| el.push(2)));
+    tlv(tbs, INTEGER, |el| el.extend_from_slice(&serial));
+    signature_algorithm(tbs);
+    name(tbs);
+    tlv(tbs, SEQUENCE, |validity| {
+      tlv(validity, UTC_TIME, |el| el.extend_from_slice(b"700101000000Z"));
+      tlv(validity, GENERALIZED_TIME, |el| el.extend_from_slice(b"99991231235959Z"));
+    });
+    name(tbs);
+    subject_public_key_info(tbs, key_pair);
+    tlv(tbs, CONTEXT_3, |extensions| {
+      tlv(extensions, SEQUENCE, |extensions| {
+        subject_alt_name(extensions, &[domain]);
+        tlv(extensions, SEQUENCE, |extension| {
+          tlv(extension, OID, |el| el.extend_from_slice(OID_ACME_IDENTIFIER));
+          tlv(extension, BOOLEAN, |el| el.push(0xFF));
+          tlv(extension, OCTET_STRING, |value| {
+            tlv(value, OCTET_STRING, |el| el.extend_from_slice(digest));
+          });
+        });
+      });
+    });
+  });
+  signed(&tbs, key_pair, rng)
+}
+
+fn signature_algorithm(buffer: &mut Vec<u8>) {
+  tlv(buffer, SEQUENCE, |el| {
+    tlv(el, OID, |local_el| local_el.extend_from_slice(OID_ECDSA_WITH_SHA256))
+  });
+}
+
+// Wraps `data` alongside its algorithm and its signature, which is the common structure of
+// certificates and certificate signing requests.
+fn signed(data: &[u8], key_pair: &EcdsaKeyPair, rng: &dyn SecureRandom) -> crate::Result<Vec<u8>> {
+  let signature = key_pair.sign(rng, data).map_err(|_err| AcmeError::InvalidKey)?;
+  let mut buffer = Vec::new();
+  tlv(&mut buffer, SEQUENCE, |buffer| {
+    buffer.extend_from_slice(data);
+    signature_algorithm(buffer);
+    tlv(buffer, BIT_STRING, |el| {
+      el.push(0);
+      el.extend_from_slice(signature.as_ref());
+    });
+  });
+  Ok(buffer)
+}
+
+fn subject_alt_name(buffer: &mut Vec<u8>, domains: &[&str]) {
+  tlv(buffer, SEQUENCE, |extension| {
+    tlv(extension, OID, |el| el.extend_from_slice(OID_SUBJECT_ALT_NAME));
+    tlv(extension, OCTET_STRING, |value| {
+      tlv(value, SEQUENCE, |names| {
+        for domain in domains {
+          tlv(names, DNS_NAME, |el| el.extend_from_slice(domain.as_bytes()));
+        }
+      });
+    });
+  });
+}
+
+fn subject_public_key_info(buffer: &mut Vec<u8>, key_pair: &EcdsaKeyPair) {
+  tlv(buffer, SEQUENCE, |spki| {
+    tlv(spki, SEQUENCE, |algorithm| {
+      tlv(algorithm, OID, |el| el.extend_from_slice(OID_EC_PUBLIC_KEY));
+      tlv(algorithm, OID, |el| el.extend_from_slice(OID_PRIME256V1));
+    });
+    tlv(spki, BIT_STRING, |el| {
+      el.push(0);
+      el.extend_from_slice(key_pair.public_key().as_ref());
+    });
+  });
+}
+
+// Pushes `tag`, the contents written by `cb` and then inserts the length of the contents.
+fn tlv(buffer: &mut Vec<u8>, tag: u8, cb: impl FnOnce(&mut Vec<u8>)) {
+  buffer.push(tag);
+  let start = buffer.len();
+  cb(buffer);
+  let len = buffer.len().wrapping_sub(start);
+  match u8::try_from(len) {
+    Ok(elem) if elem < 128 => buffer.insert(start, elem),
+    _ => {
+      let bytes = len.to_be_bytes();
+      let zeros = usize::try_from(len.leading_zeros() / 8).unwrap_or_default();
+      let significant = bytes.get(zeros..).unwrap_or_default();
+      let prefix = 0b1000_0000 | u8::try_from(significant.len()).unwrap_or_default();
+      let _ = buffer.splice(start..start, [prefix].into_iter().chain(significant.iter().copied()));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::acme::der::{csr, tls_alpn01_cert, tlv};
+  use alloc::vec::Vec;
+  use ring::{
+    rand::SystemRandom,
+    signature::{self, ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair, KeyPair, UnparsedPublicKey},
+  };
+  use rustls_pki_types::CertificateDer;
+  use tokio_rustls::rustls::server::ParsedCertificate;
+
+  #[test]
+  fn lengths_are_encoded() {
+    for (len, prefix) in [(0, &[0][..]), (127, &[127]), (128, &[129, 128]), (300, &[130, 1, 44])] {
+      let mut buffer = Vec::new();
+      tlv(&mut buffer, 4, |el| el.extend(core::iter::repeat_n(0, len)));
+      assert_eq!(buffer.get(1..prefix.len().wrapping_add(1)), Some(prefix));
+      assert_eq!(buffer.len(), len.wrapping_add(prefix.len()).wrapping_add(1));
+    }
+  }
+
+  #[test]
+  fn signatures_are_verifiable() {
+    let rng = SystemRandom::new();
+    let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+    let key_pair =
+      EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng).unwrap();
+    let public_key =
+      UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, key_pair.public_key().as_ref());
+    let cert = tls_alpn01_cert(&[1; 32], "foo.com", &key_pair, &rng).unwrap();
+    // The whole structure is parsed but the `acmeIdentifier` extension is only known by ACME
+    // servers.
+    let err = ParsedCertificate::try_from(&CertificateDer::from(cert.as_slice())).err().unwrap();
+    assert!(alloc::format!("{err:?}").contains("UnsupportedCriticalExtension"));
+    for der in [csr(&["foo.com", "bar.com"], &key_pair, &rng).unwrap(), cert] {
+      let (data, signature) = split_signed(&der);
+      public_key.verify(data, signature).unwrap();
+      assert!(der.windows(7).any(|el| el == b"foo.com"));
+    }
+  }
+
+  // Returns the signed data and the signature of a structure created by `signed`.
+  fn split_signed(der: &[u8]) -> (&[u8], &[u8]) {
+    let elements = content(der);
+    let (data, rest) = elements.split_at(element_len(elements));
+    let bit_string = rest.get(element_len(rest)..).unwrap();
+    (data, content(bit_string).get(1..).unwrap())
+  }
+
+  fn content(der: &[u8]) -> &[u8] {
+    let (header_len, content_len) = header(der);
+    der.get(header_len..header_len.wrapping_add(content_len)).unwrap()
+  }
+
+  fn element_len(der: &[u8]) -> usize {
+    let (header_len, content_len) = header(der);
+    header_len.wrapping_add(content_len)
+  }
+
+  fn header(der: &[u8]) -> (usize, usize) {
+    match der.get(1).copied().unwrap() {
+      elem @ 0..128 => (2, elem.into()),
+      elem => {
+        let len_len = usize::from(elem & 0b0111_1111);
+        let bytes = der.get(2..len_len.wrapping_add(2)).unwrap();
+        (len_len.wrapping_add(2), bytes.iter().fold(0, |acc, el| (acc << 8) | usize::from(*el)))
+      }
+    }
+  }
+}
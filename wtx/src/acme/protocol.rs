@@ -0,0 +1,105 @@
+// Objects exchanged with ACME servers.
+//
+// https://datatracker.ietf.org/doc/html/rfc8555#section-7.1
+
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Authorization {
+  pub(crate) challenges: Vec<Challenge>,
+  pub(crate) identifier: Identifier<String>,
+  pub(crate) status: Status,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Challenge {
+  #[serde(default)]
+  pub(crate) token: String,
+  #[serde(rename = "type")]
+  pub(crate) ty: String,
+  pub(crate) url: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Directory {
+  pub(crate) new_account: String,
+  pub(crate) new_nonce: String,
+  pub(crate) new_order: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Finalize<'any> {
+  pub(crate) csr: &'any str,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct Identifier<S> {
+  #[serde(rename = "type")]
+  pub(crate) ty: S,
+  pub(crate) value: S,
+}
+
+// Members are sorted lexicographically, which is required by thumbprints.
+//
+// https://datatracker.ietf.org/doc/html/rfc7638#section-3
+#[derive(Debug, Serialize)]
+pub(crate) struct Jwk<'any> {
+  pub(crate) crv: &'static str,
+  pub(crate) kty: &'static str,
+  pub(crate) x: &'any str,
+  pub(crate) y: &'any str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NewAccount<'any> {
+  pub(crate) contact: &'any [&'any str],
+  pub(crate) terms_of_service_agreed: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct NewOrder<'any> {
+  pub(crate) identifiers: Vec<Identifier<&'any str>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Order {
+  #[serde(default)]
+  pub(crate) authorizations: Vec<String>,
+  #[serde(default)]
+  pub(crate) certificate: Option<String>,
+  pub(crate) finalize: String,
+  pub(crate) status: Status,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Problem {
+  #[serde(default, rename = "type")]
+  pub(crate) ty: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Protected<'any> {
+  pub(crate) alg: &'static str,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) jwk: Option<Jwk<'any>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) kid: Option<&'any str>,
+  pub(crate) nonce: &'any str,
+  pub(crate) url: &'any str,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Status {
+  Deactivated,
+  Expired,
+  Invalid,
+  Pending,
+  Processing,
+  Ready,
+  Revoked,
+  Valid,
+}
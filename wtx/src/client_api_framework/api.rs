@@ -1,3 +1,7 @@
+use crate::{
+  client_api_framework::misc::{ApiRateLimiter, TransportObserver},
+  sync::Arc,
+};
 use core::fmt::Display;
 
 /// Api definitions group different packages into a common namespace and define custom additional
@@ -19,6 +23,24 @@ pub trait Api {
   fn before_sending(&mut self) -> impl Future<Output = Result<(), Self::Error>> {
     async { Ok(()) }
   }
+
+  /// If a package that failed with `err` can be sent again according to its retry policy.
+  #[inline]
+  fn is_transient_error(&self, _err: &Self::Error) -> bool {
+    false
+  }
+
+  /// Throttling component consulted before sending any related request.
+  #[inline]
+  fn rate_limiter(&self) -> Option<&Arc<ApiRateLimiter>> {
+    None
+  }
+
+  /// Component notified about the metrics of every exchange of related packages.
+  #[inline]
+  fn transport_observer(&self) -> Option<&dyn TransportObserver> {
+    None
+  }
 }
 
 impl Api for () {
@@ -42,6 +64,21 @@ where
   async fn before_sending(&mut self) -> Result<(), Self::Error> {
     (**self).before_sending().await
   }
+
+  #[inline]
+  fn is_transient_error(&self, err: &Self::Error) -> bool {
+    (**self).is_transient_error(err)
+  }
+
+  #[inline]
+  fn rate_limiter(&self) -> Option<&Arc<ApiRateLimiter>> {
+    (**self).rate_limiter()
+  }
+
+  #[inline]
+  fn transport_observer(&self) -> Option<&dyn TransportObserver> {
+    (**self).transport_observer()
+  }
 }
 
 /// Identification of an API.
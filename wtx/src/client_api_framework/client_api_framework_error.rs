@@ -3,14 +3,44 @@
 pub enum ClientApiFrameworkError {
   /// A slice-like batch of package is not sorted
   BatchPackagesAreNotSorted,
+  /// The MQTT broker closed the connection with a `DISCONNECT` packet.
+  ClosedMqttConnection,
   /// The server closed the connection
   ClosedWsConnection,
   /// A server was not able to receive the full request data after several attempts.
   CouldNotSendTheFullRequestData,
+  /// The deadline of [`crate::client_api_framework::pkg::PkgsAux`] expired before the end of the
+  /// exchange.
+  DeadlineExceeded,
+  /// The delimiter of a [`crate::client_api_framework::network::transport::Framing`] is empty.
+  EmptyFramingDelimiter,
+  /// A batch response doesn't start or end with the bytes of its `BatchJoiner`.
+  InvalidBatchResponse,
+  /// A gRPC response message is compressed or its length prefix doesn't match the received body.
+  InvalidGrpcMessage,
+  /// An MQTT packet is malformed, unexpected or larger than the configured maximum size.
+  InvalidMqttPacket,
+  /// A package of a batch didn't receive a response with its id.
+  MissingBatchResponse(usize),
+  /// The MQTT broker answered with the contained failure reason code.
+  MqttFailureReasonCode(u8),
+  /// A [`crate::client_api_framework::network::transport::CircuitBreaker`] is open.
+  OpenCircuitBreaker,
+  /// The authorization server returned an OAuth2 token response with the contained status code.
+  OAuth2TokenRequestFailed(u16),
   /// A given response id is not present in the set of sent packages.
   ResponseIdIsNotPresentInTheOfSentBatchPackages(usize),
   /// No stored test response to return a result from a request
   TestTransportNoResponse,
   /// It is not possible to convert a `u16` into a HTTP status code
   UnknownHttpStatusCode(u16),
+  /// A subscription of [`crate::client_api_framework::network::transport::SubscriptionDemux`] wasn't
+  /// registered.
+  UnknownSubscription,
+  /// OAuth2 token responses must have the `Bearer` type.
+  UnsupportedOAuth2TokenType,
+  /// A framed message with the contained length is larger than the configured maximum size.
+  VeryLargeFramedMessage(usize),
+  /// A UDP datagram with the contained length is larger than the configured maximum size.
+  VeryLargeUdpDatagram(usize),
 }
@@ -1,25 +1,54 @@
 //! Utility functions and structures
 
+mod api_rate_limiter;
 mod from_bytes;
+#[cfg(all(feature = "crypto-common", feature = "hmac", feature = "http", feature = "sha2"))]
+mod hmac_signer;
+#[cfg(all(feature = "http", feature = "jwt"))]
+mod jwt_bearer;
+#[cfg(all(feature = "http", feature = "serde_json"))]
+mod oauth2;
 mod pair;
+mod pkg_retry_budget;
+mod pkg_retry_policy;
 mod request_counter;
 mod request_limit;
 mod request_throttling;
+#[cfg(all(feature = "crypto-common", feature = "hmac", feature = "http", feature = "sha2"))]
+mod sig_v4_signer;
+mod transport_observer;
 
 use crate::{
   client_api_framework::{
-    Api, SendBytesSource,
-    network::{TransportGroup, transport::Transport},
+    Api, ClientApiFrameworkError, SendBytesSource,
+    network::{
+      TransportGroup,
+      transport::{Transport, TransportParams},
+    },
     pkg::{Package, PkgsAux},
   },
   data_transformation::dnsn::EncodeWrapper,
-  misc::Encode,
+  misc::{Encode, GenericTime, within},
 };
+pub use api_rate_limiter::ApiRateLimiter;
+pub(crate) use api_rate_limiter::ApiRateLimiterPermit;
+use core::time::Duration;
 pub use from_bytes::FromBytes;
+#[cfg(all(feature = "crypto-common", feature = "hmac", feature = "http", feature = "sha2"))]
+pub use hmac_signer::HmacSigner;
+#[cfg(all(feature = "http", feature = "jwt"))]
+pub use jwt_bearer::JwtBearer;
+#[cfg(all(feature = "http", feature = "serde_json"))]
+pub use oauth2::OAuth2;
 pub use pair::{Pair, PairMut};
+pub use pkg_retry_budget::PkgRetryBudget;
+pub use pkg_retry_policy::PkgRetryPolicy;
 pub use request_counter::RequestCounter;
 pub use request_limit::RequestLimit;
 pub use request_throttling::RequestThrottling;
+#[cfg(all(feature = "crypto-common", feature = "hmac", feature = "http", feature = "sha2"))]
+pub use sig_v4_signer::SigV4Signer;
+pub use transport_observer::{ExchangeErrorKind, ExchangeMetrics, TransportObserver};
 
 /// Used in [`crate::network::transport::Transport::send_recv_decode_contained`] and all implementations of
 /// [`crate::Requests::decode_responses`].
@@ -35,6 +64,30 @@ pub(crate) fn _log_res(_log_body: bool, _res: &[u8], _tg: TransportGroup) {
   }
 }
 
+// Time left until `deadline`, if any.
+#[inline]
+pub(crate) fn deadline_timeout(deadline: Option<GenericTime>) -> crate::Result<Option<Duration>> {
+  let Some(elem) = deadline else {
+    return Ok(None);
+  };
+  let timeout = elem.duration_since(GenericTime::now()).unwrap_or_default();
+  if timeout.is_zero() {
+    return Err(ClientApiFrameworkError::DeadlineExceeded.into());
+  }
+  Ok(Some(timeout))
+}
+
+#[inline]
+pub(crate) async fn manage_after_receiving<A, DRSR, TP>(
+  pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+) -> crate::Result<()>
+where
+  TP: TransportParams,
+{
+  pkgs_aux.tp.after_receiving(&mut pkgs_aux.byte_buffer).await?;
+  Ok(())
+}
+
 #[inline]
 pub(crate) async fn manage_after_sending_bytes<A, DRSR, TP>(
   pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
@@ -76,9 +129,15 @@ pub(crate) async fn manage_before_sending_bytes<A, DRSR, T, TP>(
 where
   A: Api,
   T: Transport<TP>,
+  TP: TransportParams,
 {
   log_req(bytes.bytes(&pkgs_aux.byte_buffer), pkgs_aux.log_body.1, trans);
+  if let Some(elem) = pkgs_aux.api.rate_limiter() {
+    elem.wait_interval().await?;
+  }
   pkgs_aux.api.before_sending().await?;
+  pkgs_aux.tp.before_sending(bytes.bytes(&pkgs_aux.byte_buffer)).await?;
+  pkgs_aux.exchange = (bytes.bytes(&pkgs_aux.byte_buffer).len(), Duration::ZERO);
   Ok(())
 }
 
@@ -92,7 +151,14 @@ where
   A: Api,
   P: Package<A, DRSR, T, TP>,
   T: Transport<TP>,
+  TP: TransportParams,
 {
+  if let Some(elem) = pkgs_aux.api.rate_limiter() {
+    elem.wait_interval().await?;
+  }
+  if let Some(elem) = pkg.rate_limiter() {
+    elem.wait_interval().await?;
+  }
   pkgs_aux.api.before_sending().await?;
   pkg
     .before_sending(
@@ -100,9 +166,13 @@ where
       (trans, &mut pkgs_aux.tp),
     )
     .await?;
+  let begin = pkgs_aux.api.transport_observer().map(|_| GenericTime::now());
   pkg
     .ext_req_content_mut()
     .encode(&mut pkgs_aux.drsr, &mut EncodeWrapper::new(&mut pkgs_aux.byte_buffer))?;
+  let serialize_time = begin.and_then(|el| el.elapsed().ok()).unwrap_or_default();
+  pkgs_aux.tp.before_sending(&pkgs_aux.byte_buffer).await?;
+  pkgs_aux.exchange = (pkgs_aux.byte_buffer.len(), serialize_time);
   log_req(&pkgs_aux.byte_buffer, pkgs_aux.log_body.1, trans);
   Ok(())
 }
@@ -118,3 +188,85 @@ where
     _debug!(trans_ty = display(_trans.ty()), "Request");
   }
 }
+
+// Awaits `fut` for at most `timeout`.
+#[inline]
+pub(crate) async fn with_timeout<E, F>(fut: F, timeout: Option<Duration>) -> Result<(), E>
+where
+  E: From<crate::Error>,
+  F: Future<Output = Result<(), E>>,
+{
+  let Some(elem) = timeout else {
+    return fut.await;
+  };
+  match within(elem, fut).await? {
+    Some(rslt) => rslt,
+    None => Err(crate::Error::from(ClientApiFrameworkError::DeadlineExceeded).into()),
+  }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      ClientApiFrameworkError, SendBytesSource,
+      misc::with_timeout,
+      network::transport::{MockStr, SendingReceivingTransport},
+      pkg::{Package, PkgsAux},
+    },
+    misc::GenericTime,
+  };
+  use alloc::borrow::Cow;
+  use core::{future::pending, time::Duration};
+
+  struct ExpiredPkg(());
+
+  impl<DRSR, T, TP> Package<(), DRSR, T, TP> for ExpiredPkg {
+    type ExternalRequestContent = ();
+    type ExternalResponseContent<'de> = ();
+    type PackageParams = ();
+
+    fn deadline(&self) -> Option<GenericTime> {
+      GenericTime::now().checked_sub(Duration::from_secs(1)).ok()
+    }
+
+    fn ext_req_content(&self) -> &Self::ExternalRequestContent {
+      &self.0
+    }
+
+    fn ext_req_content_mut(&mut self) -> &mut Self::ExternalRequestContent {
+      &mut self.0
+    }
+
+    fn pkg_params(&self) -> &Self::PackageParams {
+      &self.0
+    }
+
+    fn pkg_params_mut(&mut self) -> &mut Self::PackageParams {
+      &mut self.0
+    }
+  }
+
+  #[tokio::test]
+  async fn deadline() {
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), ());
+    let mut trans = MockStr::default();
+    trans.push_response(Cow::Borrowed("pong"));
+    pkgs_aux.deadline = Some(GenericTime::now().checked_add(Duration::from_secs(60)).unwrap());
+    trans.send_bytes_recv(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.unwrap();
+    pkgs_aux.deadline = Some(GenericTime::now().checked_sub(Duration::from_secs(1)).unwrap());
+    assert!(matches!(
+      trans.send_bytes_recv(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await,
+      Err(crate::Error::ClientApiFrameworkError(ClientApiFrameworkError::DeadlineExceeded))
+    ));
+    pkgs_aux.deadline = None;
+    assert!(matches!(
+      trans.send_pkg_recv(&mut ExpiredPkg(()), &mut pkgs_aux).await,
+      Err(crate::Error::ClientApiFrameworkError(ClientApiFrameworkError::DeadlineExceeded))
+    ));
+    assert!(matches!(
+      with_timeout::<crate::Error, _>(pending(), Some(Duration::from_millis(10))).await,
+      Err(crate::Error::ClientApiFrameworkError(ClientApiFrameworkError::DeadlineExceeded))
+    ));
+  }
+}
@@ -0,0 +1,262 @@
+use crate::{
+  client_api_framework::misc::RequestLimit,
+  misc::{GenericTime, Vector, sleep},
+  sync::{Arc, AtomicCell, AtomicU32, AtomicU64, AtomicUsize, Ordering},
+};
+use core::{
+  fmt::{Debug, Formatter},
+  future::poll_fn,
+  task::{Poll, Waker},
+  time::Duration,
+};
+
+/// Delays requests to respect the quotas of third-party APIs, which can be expressed as a
+/// maximum number of requests within an interval and as a maximum number of concurrent requests.
+///
+/// Consulted through [`crate::client_api_framework::Api::rate_limiter`] and, for per-endpoint
+/// quotas, through [`crate::client_api_framework::pkg::Package::rate_limiter`]. Different API
+/// instances that share the same quota should share the same [`Arc`].
+#[derive(Debug)]
+pub struct ApiRateLimiter {
+  counter: AtomicU32,
+  in_flight: AtomicUsize,
+  is_token_bucket: bool,
+  max_concurrent: usize,
+  rl: RequestLimit,
+  theoretical_arrival: AtomicU64,
+  waiters: Waiters,
+  window_start: AtomicU64,
+}
+
+impl ApiRateLimiter {
+  /// Requests are limited by `rl` within fixed windows and concurrency is unbounded.
+  #[inline]
+  pub const fn new(rl: RequestLimit) -> Self {
+    Self {
+      counter: AtomicU32::new(0),
+      in_flight: AtomicUsize::new(0),
+      is_token_bucket: false,
+      max_concurrent: usize::MAX,
+      rl,
+      theoretical_arrival: AtomicU64::new(0),
+      waiters: Waiters(AtomicCell::new(Vector::new())),
+      window_start: AtomicU64::new(0),
+    }
+  }
+
+  /// Requests are limited by a token bucket that holds up to [`RequestLimit::limit`] tokens and is
+  /// fully refilled within [`RequestLimit::duration`]. Concurrency is unbounded.
+  ///
+  /// Unlike [`Self::new`], requests that exceed a burst are evenly spread instead of being
+  /// released all at once when a new window starts.
+  #[inline]
+  pub const fn token_bucket(rl: RequestLimit) -> Self {
+    let mut this = Self::new(rl);
+    this.is_token_bucket = true;
+    this
+  }
+
+  /// Number of requests that are awaiting their responses.
+  #[inline]
+  pub fn in_flight(&self) -> usize {
+    self.in_flight.load(Ordering::Relaxed)
+  }
+
+  /// Maximum number of requests that can await their responses at the same time. Values of `0`
+  /// are converted to `1` to avoid infinite hangs.
+  #[inline]
+  #[must_use]
+  pub fn max_concurrent(mut self, elem: usize) -> Self {
+    self.max_concurrent = elem.max(1);
+    self
+  }
+
+  /// Awaits until a request is allowed by [`RequestLimit`].
+  pub(crate) async fn wait_interval(&self) -> crate::Result<()> {
+    let duration = u64::try_from(self.rl.duration().as_nanos()).unwrap_or(u64::MAX);
+    if duration == 0 {
+      return Ok(());
+    }
+    if self.is_token_bucket {
+      return self.wait_token(duration).await;
+    }
+    loop {
+      let now = u64::try_from(GenericTime::now_timestamp()?.as_nanos()).unwrap_or(u64::MAX);
+      let start = self.window_start.load(Ordering::Acquire);
+      let elapsed = now.saturating_sub(start);
+      if elapsed >= duration {
+        if self
+          .window_start
+          .compare_exchange(start, now, Ordering::AcqRel, Ordering::Acquire)
+          .is_ok()
+        {
+          self.counter.store(0, Ordering::Release);
+        }
+        continue;
+      }
+      if self.counter.fetch_add(1, Ordering::AcqRel) < u32::from(self.rl.limit()) {
+        return Ok(());
+      }
+      sleep(Duration::from_nanos(duration.wrapping_sub(elapsed))).await?;
+    }
+  }
+
+  // Generic cell rate algorithm, which is equivalent to a token bucket that receives a new token
+  // after each emission interval.
+  async fn wait_token(&self, duration: u64) -> crate::Result<()> {
+    let limit = u64::from(self.rl.limit());
+    let interval = duration.checked_div(limit).unwrap_or(duration);
+    let tolerance = interval.saturating_mul(limit.wrapping_sub(1));
+    loop {
+      let now = u64::try_from(GenericTime::now_timestamp()?.as_nanos()).unwrap_or(u64::MAX);
+      let tat = self.theoretical_arrival.load(Ordering::Acquire);
+      let base = tat.max(now);
+      let allowed_at = base.saturating_sub(tolerance);
+      if allowed_at > now {
+        sleep(Duration::from_nanos(allowed_at.wrapping_sub(now))).await?;
+        continue;
+      }
+      let new_tat = base.saturating_add(interval);
+      if self
+        .theoretical_arrival
+        .compare_exchange(tat, new_tat, Ordering::AcqRel, Ordering::Acquire)
+        .is_ok()
+      {
+        return Ok(());
+      }
+    }
+  }
+
+  /// Awaits until the number of in-flight requests is lower than [`Self::max_concurrent`].
+  pub(crate) async fn acquire(this: Arc<Self>) -> ApiRateLimiterPermit {
+    poll_fn(|cx| {
+      if this.try_acquire() {
+        return Poll::Ready(());
+      }
+      this.waiters.push(cx.waker());
+      // A slot could have been released before the registration of the waker.
+      if this.try_acquire() { Poll::Ready(()) } else { Poll::Pending }
+    })
+    .await;
+    ApiRateLimiterPermit(this)
+  }
+
+  fn try_acquire(&self) -> bool {
+    self
+      .in_flight
+      .fetch_update(Ordering::AcqRel, Ordering::Acquire, |el| {
+        (el < self.max_concurrent).then(|| el.wrapping_add(1))
+      })
+      .is_ok()
+  }
+}
+
+/// Releases a concurrent slot of [`ApiRateLimiter`] when dropped.
+#[derive(Debug)]
+pub(crate) struct ApiRateLimiterPermit(Arc<ApiRateLimiter>);
+
+impl Drop for ApiRateLimiterPermit {
+  #[inline]
+  fn drop(&mut self) {
+    let _ = self.0.in_flight.fetch_sub(1, Ordering::AcqRel);
+    self.0.waiters.wake_all();
+  }
+}
+
+// Tasks that are waiting for a concurrent slot.
+struct Waiters(AtomicCell<Vector<Waker>>);
+
+impl Waiters {
+  fn push(&self, waker: &Waker) {
+    let mut wakers = self.0.swap(Vector::new());
+    loop {
+      if !wakers.iter().any(|el| el.will_wake(waker)) {
+        let _rslt = wakers.push(waker.clone());
+      }
+      // Other tasks can push their wakers while `wakers` is detached.
+      let others = self.0.swap(wakers);
+      if others.is_empty() {
+        break;
+      }
+      wakers = self.0.swap(Vector::new());
+      let _rslt = wakers.extend_from_iter(others);
+    }
+  }
+
+  fn wake_all(&self) {
+    for waker in self.0.swap(Vector::new()) {
+      waker.wake();
+    }
+  }
+}
+
+impl Debug for Waiters {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("Waiters").finish_non_exhaustive()
+  }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    client_api_framework::misc::{ApiRateLimiter, RequestLimit},
+    sync::Arc,
+  };
+  use core::{cell::Cell, future::poll_fn, pin::pin, time::Duration};
+  use std::time::Instant;
+
+  #[tokio::test]
+  async fn limits_concurrency() {
+    let limiter = Arc::new(ApiRateLimiter::new(RequestLimit::unlimited()).max_concurrent(1));
+    let permit = ApiRateLimiter::acquire(limiter.clone()).await;
+    assert_eq!(limiter.in_flight(), 1);
+    let waiting = tokio::spawn(ApiRateLimiter::acquire(limiter.clone()));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!waiting.is_finished());
+    drop(permit);
+    drop(waiting.await.unwrap());
+    assert_eq!(limiter.in_flight(), 0);
+  }
+
+  #[tokio::test]
+  async fn limits_requests_within_interval() {
+    let limiter = ApiRateLimiter::new(RequestLimit::new(2, Duration::from_millis(100)));
+    let instant = Instant::now();
+    for _ in 0..3 {
+      limiter.wait_interval().await.unwrap();
+    }
+    assert!(instant.elapsed() >= Duration::from_millis(90));
+  }
+
+  #[tokio::test]
+  async fn token_bucket_spreads_requests_after_bursts() {
+    let limiter = ApiRateLimiter::token_bucket(RequestLimit::new(2, Duration::from_millis(100)));
+    let instant = Instant::now();
+    limiter.wait_interval().await.unwrap();
+    limiter.wait_interval().await.unwrap();
+    assert!(instant.elapsed() < Duration::from_millis(40));
+    limiter.wait_interval().await.unwrap();
+    let elapsed = instant.elapsed();
+    assert!(elapsed >= Duration::from_millis(40) && elapsed < Duration::from_millis(90));
+  }
+
+  #[tokio::test]
+  async fn waiting_tasks_are_not_polled_repeatedly() {
+    let limiter = Arc::new(ApiRateLimiter::new(RequestLimit::unlimited()).max_concurrent(1));
+    let permit = ApiRateLimiter::acquire(limiter.clone()).await;
+    let polls = Cell::new(0u32);
+    let mut acquire = pin!(ApiRateLimiter::acquire(limiter.clone()));
+    let waiting = poll_fn(|cx| {
+      polls.set(polls.get().wrapping_add(1));
+      acquire.as_mut().poll(cx)
+    });
+    let release = async {
+      tokio::time::sleep(Duration::from_millis(50)).await;
+      drop(permit);
+    };
+    drop(tokio::join!(waiting, release));
+    assert!(polls.get() <= 3);
+    assert_eq!(limiter.in_flight(), 0);
+  }
+}
@@ -0,0 +1,73 @@
+use crate::{
+  client_api_framework::network::{
+    HttpParams,
+    transport::{Interceptor, TransportParams},
+  },
+  http::Header,
+  misc::Vector,
+};
+use alloc::string::String;
+use core::fmt::Write;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Signs the body of HTTP requests with HMAC-SHA256. The hexadecimal signature is sent through
+/// a custom header, which is a common authentication method of exchanges.
+///
+/// Should be used with [`crate::client_api_framework::network::transport::Intercepted`].
+#[derive(Debug)]
+pub struct HmacSigner {
+  buffer: String,
+  header_name: String,
+  secret: Vector<u8>,
+}
+
+impl HmacSigner {
+  /// The signature is sent through `header_name`.
+  #[inline]
+  pub const fn new(header_name: String, secret: Vector<u8>) -> Self {
+    Self { buffer: String::new(), header_name, secret }
+  }
+
+  /// Hexadecimal signature of `bytes`.
+  #[inline]
+  pub fn sign(&mut self, bytes: &[u8]) -> crate::Result<&str> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)?;
+    mac.update(bytes);
+    self.buffer.clear();
+    push_hex(&mac.finalize().into_bytes(), &mut self.buffer)?;
+    Ok(&self.buffer)
+  }
+}
+
+impl Interceptor<HttpParams> for HmacSigner {
+  #[inline]
+  async fn before_sending(&mut self, bytes: &[u8], tp: &mut HttpParams) -> crate::Result<()> {
+    let _ = self.sign(bytes)?;
+    let headers = &mut tp.ext_req_params_mut().headers;
+    headers.retain(|el| !el.name.eq_ignore_ascii_case(&self.header_name));
+    headers.push_from_iter(Header::from_name_and_value(&self.header_name, [self.buffer.as_str()]))
+  }
+}
+
+pub(crate) fn push_hex(bytes: &[u8], buffer: &mut String) -> crate::Result<()> {
+  for byte in bytes {
+    buffer.write_fmt(format_args!("{byte:02x}"))?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{client_api_framework::misc::HmacSigner, misc::Vector};
+
+  // https://datatracker.ietf.org/doc/html/rfc4231#section-4.3
+  #[test]
+  fn sign() {
+    let mut signer = HmacSigner::new("signature".into(), Vector::from_iter(*b"Jefe").unwrap());
+    assert_eq!(
+      signer.sign(b"what do ya want for nothing?").unwrap(),
+      "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+  }
+}
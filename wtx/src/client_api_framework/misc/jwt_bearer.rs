@@ -0,0 +1,110 @@
+use crate::{
+  client_api_framework::network::{
+    HttpParams,
+    transport::{Interceptor, TransportParams},
+  },
+  http::{Header, Headers, KnownHeaderName},
+  jwt::{JwtClaims, JwtEncoder},
+  misc::GenericTime,
+};
+use alloc::string::String;
+use core::time::Duration;
+use serde::Serialize;
+
+// Tokens are renewed a little earlier to account for network latency.
+const EXPIRATION_MARGIN: u64 = 30;
+
+/// Signs JSON Web Tokens that are injected into HTTP requests through the `Authorization: Bearer`
+/// header, which is common in service-to-service authentication.
+///
+/// The `iat` and `exp` claims are overwritten whenever a token is created and tokens are reused
+/// until they are about to expire.
+///
+/// Should be used with [`crate::client_api_framework::network::transport::Intercepted`].
+#[derive(Debug)]
+pub struct JwtBearer<T> {
+  claims: JwtClaims<T>,
+  encoder: JwtEncoder,
+  expires_at: u64,
+  lifetime: Duration,
+  token: String,
+}
+
+impl<T> JwtBearer<T>
+where
+  T: Serialize,
+{
+  /// Tokens are signed by `encoder` and are valid for `lifetime`.
+  #[inline]
+  pub const fn new(claims: JwtClaims<T>, encoder: JwtEncoder, lifetime: Duration) -> Self {
+    Self { claims, encoder, expires_at: 0, lifetime, token: String::new() }
+  }
+
+  /// Returns the cached token or signs a new one if it is missing or about to expire.
+  #[inline]
+  pub fn token(&mut self) -> crate::Result<&str> {
+    self.token_at(GenericTime::now_timestamp()?.as_secs())
+  }
+
+  fn push_authorization(&mut self, headers: &mut Headers, now: u64) -> crate::Result<()> {
+    let token = self.token_at(now)?;
+    let name = KnownHeaderName::Authorization.into();
+    headers.retain(|el| !el.name.eq_ignore_ascii_case(name));
+    headers.push_from_iter(Header::from_name_and_value(name, ["Bearer ", token]))
+  }
+
+  fn token_at(&mut self, now: u64) -> crate::Result<&str> {
+    if self.token.is_empty() || now.saturating_add(EXPIRATION_MARGIN) >= self.expires_at {
+      self.expires_at = now.saturating_add(self.lifetime.as_secs());
+      self.claims.exp = Some(self.expires_at);
+      self.claims.iat = Some(now);
+      self.token = self.encoder.encode(&self.claims)?;
+    }
+    Ok(&self.token)
+  }
+}
+
+impl<T> Interceptor<HttpParams> for JwtBearer<T>
+where
+  T: Serialize,
+{
+  #[inline]
+  async fn before_sending(&mut self, _: &[u8], tp: &mut HttpParams) -> crate::Result<()> {
+    let now = GenericTime::now_timestamp()?.as_secs();
+    self.push_authorization(&mut tp.ext_req_params_mut().headers, now)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      misc::JwtBearer,
+      network::{HttpParams, transport::TransportParams},
+    },
+    jwt::{JwtClaims, JwtDecoder, JwtEncoder},
+  };
+  use alloc::string::String;
+  use core::time::Duration;
+
+  #[test]
+  fn pushes_and_renews_bearer_tokens() {
+    let mut claims = JwtClaims::new(());
+    claims.sub = Some("foo".into());
+    let mut bearer = JwtBearer::new(claims, JwtEncoder::hs256(&[1; 32]), Duration::from_secs(60));
+    let mut params = HttpParams::from_uri("http://localhost".into());
+    let headers = &mut params.ext_req_params_mut().headers;
+    bearer.push_authorization(headers, 1_000).unwrap();
+    bearer.push_authorization(headers, 1_010).unwrap();
+    assert_eq!(headers.headers_len(), 1);
+    let value = headers.get_by_name(b"authorization").unwrap().value;
+    let first = String::from(value.strip_prefix("Bearer ").unwrap());
+    let decoded: JwtClaims<()> = JwtDecoder::hs256(&[1; 32]).decode_unvalidated(&first).unwrap();
+    assert_eq!(decoded.exp, Some(1_060));
+    assert_eq!(decoded.iat, Some(1_000));
+    assert_eq!(decoded.sub.as_deref(), Some("foo"));
+    bearer.push_authorization(headers, 1_040).unwrap();
+    let value = headers.get_by_name(b"authorization").unwrap().value;
+    assert_ne!(value.strip_prefix("Bearer ").unwrap(), first);
+  }
+}
@@ -0,0 +1,160 @@
+use crate::{
+  client_api_framework::{
+    ClientApiFrameworkError,
+    network::{
+      HttpParams,
+      transport::{Interceptor, TransportParams},
+    },
+  },
+  http::{
+    FormUrlencodedWriter, Header, HttpClient, KnownHeaderName, Method, Mime, ReqResBuffer,
+    ReqResBuilder,
+  },
+  misc::{GenericTime, UriString},
+};
+use alloc::string::String;
+
+// Tokens are renewed a little earlier to account for network latency.
+const EXPIRATION_MARGIN: u64 = 30;
+
+/// Fetches, caches and renews OAuth2 access tokens that are injected into HTTP requests through
+/// the `Authorization: Bearer` header.
+///
+/// Supports the client credentials and the refresh token grants. Refresh tokens returned by the
+/// authorization server are used in subsequent renewals.
+///
+/// Should be used with [`crate::client_api_framework::network::transport::Intercepted`].
+///
+/// <https://datatracker.ietf.org/doc/html/rfc6749>
+#[derive(Debug)]
+pub struct OAuth2<C> {
+  access_token: String,
+  client: C,
+  client_id: String,
+  client_secret: String,
+  expires_at: Option<u64>,
+  refresh_token: Option<String>,
+  rrb: ReqResBuffer,
+  scope: Option<String>,
+  token_uri: UriString,
+}
+
+impl<C> OAuth2<C>
+where
+  C: HttpClient,
+{
+  /// Tokens are obtained using the client credentials grant.
+  #[inline]
+  pub fn client_credentials(
+    client: C,
+    client_id: String,
+    client_secret: String,
+    token_uri: String,
+  ) -> Self {
+    Self {
+      access_token: String::new(),
+      client,
+      client_id,
+      client_secret,
+      expires_at: None,
+      refresh_token: None,
+      rrb: ReqResBuffer::empty(),
+      scope: None,
+      token_uri: UriString::new(token_uri),
+    }
+  }
+
+  /// Tokens are obtained using the refresh token grant.
+  #[inline]
+  pub fn refresh_token(
+    client: C,
+    client_id: String,
+    client_secret: String,
+    refresh_token: String,
+    token_uri: String,
+  ) -> Self {
+    let mut this = Self::client_credentials(client, client_id, client_secret, token_uri);
+    this.refresh_token = Some(refresh_token);
+    this
+  }
+
+  /// Space-delimited list of requested scopes.
+  #[inline]
+  #[must_use]
+  pub fn scope(mut self, elem: Option<String>) -> Self {
+    self.scope = elem;
+    self
+  }
+
+  /// Returns the cached access token or fetches a new one if it is missing or expired.
+  #[inline]
+  pub async fn access_token(&mut self) -> crate::Result<&str> {
+    let now = GenericTime::now_timestamp()?.as_secs();
+    let is_expired = self.expires_at.is_some_and(|el| now.saturating_add(EXPIRATION_MARGIN) >= el);
+    if self.access_token.is_empty() || is_expired {
+      self.fetch(now).await?;
+    }
+    Ok(&self.access_token)
+  }
+
+  /// Discards the cached access token, which forces a renewal in the next request. Useful when a
+  /// token is revoked before its expiration.
+  #[inline]
+  pub fn invalidate(&mut self) {
+    self.access_token.clear();
+  }
+
+  async fn fetch(&mut self, now: u64) -> crate::Result<()> {
+    self.rrb.clear();
+    let writer = FormUrlencodedWriter::new(&mut self.rrb.body);
+    let _ = match &self.refresh_token {
+      Some(elem) => writer.write("grant_type", "refresh_token")?.write("refresh_token", elem)?,
+      None => writer.write("grant_type", "client_credentials")?,
+    }
+    .write("client_id", &self.client_id)?
+    .write("client_secret", &self.client_secret)?
+    .write_opt("scope", self.scope.as_deref())?;
+    let _ = ReqResBuilder::new(&mut self.rrb).content_type(Mime::ApplicationXWwwFormUrlEncoded)?;
+    let res = self
+      .client
+      .send_recv_single(Method::Post, core::mem::take(&mut self.rrb), &self.token_uri.to_ref())
+      .await?;
+    self.rrb = res.rrd;
+    let status_code = u16::from(res.status_code);
+    if !(200..300).contains(&status_code) {
+      return Err(ClientApiFrameworkError::OAuth2TokenRequestFailed(status_code).into());
+    }
+    let token: TokenResponse = serde_json::from_slice(&self.rrb.body)?;
+    if !token.token_type.eq_ignore_ascii_case("bearer") {
+      return Err(ClientApiFrameworkError::UnsupportedOAuth2TokenType.into());
+    }
+    self.access_token = token.access_token;
+    self.expires_at = token.expires_in.map(|el| now.saturating_add(el));
+    if let Some(elem) = token.refresh_token {
+      self.refresh_token = Some(elem);
+    }
+    Ok(())
+  }
+}
+
+impl<C> Interceptor<HttpParams> for OAuth2<C>
+where
+  C: HttpClient,
+{
+  #[inline]
+  async fn before_sending(&mut self, _: &[u8], tp: &mut HttpParams) -> crate::Result<()> {
+    let access_token = self.access_token().await?;
+    let headers = &mut tp.ext_req_params_mut().headers;
+    let name = KnownHeaderName::Authorization.into();
+    headers.retain(|el| !el.name.eq_ignore_ascii_case(name));
+    headers.push_from_fmt(Header::from_name_and_value(name, format_args!("Bearer {access_token}")))
+  }
+}
+
+#[derive(serde::Deserialize)]
+struct TokenResponse {
+  access_token: String,
+  expires_in: Option<u64>,
+  refresh_token: Option<String>,
+  token_type: String,
+}
@@ -0,0 +1,86 @@
+/// Limits the number of retries of all packages that share the same
+/// [`crate::client_api_framework::pkg::PkgsAux`], which prevents retry storms when a counterpart
+/// is overloaded.
+///
+/// Each successful exchange deposits one token and each retry withdraws [`Self::retry_cost`]
+/// tokens. Retries are denied while the balance is lower than the cost.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PkgRetryBudget {
+  balance: u16,
+  max_balance: u16,
+  retry_cost: u16,
+}
+
+impl PkgRetryBudget {
+  /// Full balance of 100 tokens with retries that cost 10 tokens, which allows one retry for every
+  /// 10 successful exchanges in the long run.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { balance: 100, max_balance: 100, retry_cost: 10 }
+  }
+
+  /// Number of available tokens.
+  #[inline]
+  pub const fn balance(&self) -> u16 {
+    self.balance
+  }
+
+  /// Maximum number of tokens, which is also the initial balance.
+  #[inline]
+  #[must_use]
+  pub const fn max_balance(mut self, elem: u16) -> Self {
+    self.balance = elem;
+    self.max_balance = elem;
+    self
+  }
+
+  /// Number of tokens withdrawn by each retry.
+  #[inline]
+  #[must_use]
+  pub const fn retry_cost(mut self, elem: u16) -> Self {
+    self.retry_cost = elem;
+    self
+  }
+
+  #[inline]
+  pub(crate) fn deposit(&mut self) {
+    self.balance = self.balance.wrapping_add(1).min(self.max_balance);
+  }
+
+  #[inline]
+  pub(crate) fn withdraw(&mut self) -> bool {
+    let Some(elem) = self.balance.checked_sub(self.retry_cost) else {
+      return false;
+    };
+    self.balance = elem;
+    true
+  }
+}
+
+impl Default for PkgRetryBudget {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::client_api_framework::misc::PkgRetryBudget;
+
+  #[test]
+  fn withdrawals_are_limited_by_the_balance() {
+    let mut budget = PkgRetryBudget::new().max_balance(4).retry_cost(2);
+    assert!(budget.withdraw());
+    assert!(budget.withdraw());
+    assert!(!budget.withdraw());
+    budget.deposit();
+    assert!(!budget.withdraw());
+    budget.deposit();
+    assert!(budget.withdraw());
+    for _ in 0..10 {
+      budget.deposit();
+    }
+    assert_eq!(budget.balance(), 4);
+  }
+}
@@ -0,0 +1,88 @@
+use crate::misc::{_backoff_with_jitter, Rng};
+use core::time::Duration;
+
+/// Determines how packages are sent again after failing with errors that
+/// [`crate::client_api_framework::Api::is_transient_error`] considers transient.
+///
+/// Waiting periods grow exponentially with a random jitter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PkgRetryPolicy {
+  base_delay: Duration,
+  max_attempts: u8,
+  max_delay: Duration,
+}
+
+impl PkgRetryPolicy {
+  /// Up to 3 attempts with delays that start at 100 milliseconds and can not exceed 10 seconds.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      base_delay: Duration::from_millis(100),
+      max_attempts: 3,
+      max_delay: Duration::from_secs(10),
+    }
+  }
+
+  /// Delay of the first retry, which is doubled after each subsequent attempt.
+  #[inline]
+  #[must_use]
+  pub const fn base_delay(mut self, elem: Duration) -> Self {
+    self.base_delay = elem;
+    self
+  }
+
+  /// Maximum number of attempts, including the first one.
+  #[inline]
+  #[must_use]
+  pub const fn max_attempts(mut self, elem: u8) -> Self {
+    self.max_attempts = elem;
+    self
+  }
+
+  /// Upper bound of computed delays.
+  #[inline]
+  #[must_use]
+  pub const fn max_delay(mut self, elem: Duration) -> Self {
+    self.max_delay = elem;
+    self
+  }
+
+  /// Amount of time that should be waited after the failed `attempt`, if any.
+  #[inline]
+  pub(crate) fn next_delay<RNG>(&self, attempt: u8, rng: &mut RNG) -> Option<Duration>
+  where
+    RNG: Rng,
+  {
+    if attempt >= self.max_attempts {
+      return None;
+    }
+    Some(_backoff_with_jitter(attempt, self.base_delay, self.max_delay, rng))
+  }
+}
+
+impl Default for PkgRetryPolicy {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    client_api_framework::misc::PkgRetryPolicy,
+    misc::{Xorshift64, simple_seed},
+  };
+  use core::time::Duration;
+
+  #[test]
+  fn next_delay() {
+    let mut rng = Xorshift64::from(simple_seed());
+    let policy = PkgRetryPolicy::new().base_delay(Duration::from_secs(2)).max_attempts(3);
+    let first = policy.next_delay(1, &mut rng).unwrap();
+    assert!(first >= Duration::from_secs(1) && first <= Duration::from_secs(2));
+    let second = policy.next_delay(2, &mut rng).unwrap();
+    assert!(second >= Duration::from_secs(2) && second <= Duration::from_secs(4));
+    assert_eq!(policy.next_delay(3, &mut rng), None);
+  }
+}
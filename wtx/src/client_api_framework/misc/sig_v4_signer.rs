@@ -0,0 +1,284 @@
+use crate::{
+  client_api_framework::{
+    misc::hmac_signer::push_hex,
+    network::{
+      HttpParams, HttpReqParams,
+      transport::{Interceptor, TransportParams},
+    },
+  },
+  http::{Header, KnownHeaderName, http_date::civil_from_days},
+  misc::{AsciiSet, GenericTime, PercentDecode, PercentEncode, Vector, from_utf8_basic},
+};
+use alloc::string::String;
+use core::fmt::Write;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+/// Signs HTTP requests using the AWS Signature Version 4, which is also supported by several
+/// AWS-compatible services.
+///
+/// The `host` header and all `x-amz-*` headers are signed. Paths are encoded only once, as
+/// expected by S3.
+///
+/// Should be used with [`crate::client_api_framework::network::transport::Intercepted`].
+///
+/// <https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv-create-signed-request.html>
+#[derive(Debug)]
+pub struct SigV4Signer {
+  access_key: String,
+  content_sha256: bool,
+  region: String,
+  secret_key: String,
+  service: String,
+  session_token: Option<String>,
+}
+
+impl SigV4Signer {
+  /// Credentials of the `service` located in `region`.
+  #[inline]
+  pub const fn new(
+    access_key: String,
+    secret_key: String,
+    region: String,
+    service: String,
+  ) -> Self {
+    Self { access_key, content_sha256: false, region, secret_key, service, session_token: None }
+  }
+
+  /// Sends the hash of the payload through the `x-amz-content-sha256` header, which is required
+  /// by S3.
+  #[inline]
+  #[must_use]
+  pub const fn content_sha256(mut self, elem: bool) -> Self {
+    self.content_sha256 = elem;
+    self
+  }
+
+  /// Temporary credentials are sent through the `x-amz-security-token` header.
+  #[inline]
+  #[must_use]
+  pub fn session_token(mut self, elem: Option<String>) -> Self {
+    self.session_token = elem;
+    self
+  }
+
+  /// Inserts the `Authorization` header, as well as auxiliary headers, of a request with the
+  /// `bytes` body issued at `timestamp`, in seconds since the UNIX epoch.
+  #[inline]
+  pub fn sign(
+    &self,
+    bytes: &[u8],
+    params: &mut HttpReqParams,
+    timestamp: u64,
+  ) -> crate::Result<()> {
+    let HttpReqParams { headers, method, uri, .. } = params;
+    headers.retain(|el| {
+      !el.name.eq_ignore_ascii_case(KnownHeaderName::Authorization.into())
+        && !is_amz_header(el.name)
+    });
+    let (year, month, day) = civil_from_days(timestamp / 86_400);
+    let secs = timestamp % 86_400;
+    let mut amz_date = String::new();
+    amz_date.write_fmt(format_args!(
+      "{year:04}{month:02}{day:02}T{:02}{:02}{:02}Z",
+      secs / 3600,
+      secs % 3600 / 60,
+      secs % 60
+    ))?;
+    let date = amz_date.get(..8).unwrap_or_default();
+    let mut payload_hash = String::new();
+    push_hex(&Sha256::digest(bytes), &mut payload_hash)?;
+    headers.push_from_iter(Header::from_name_and_value("x-amz-date", [amz_date.as_str()]))?;
+    if self.content_sha256 {
+      headers.push_from_iter(Header::from_name_and_value(
+        "x-amz-content-sha256",
+        [payload_hash.as_str()],
+      ))?;
+    }
+    if let Some(elem) = &self.session_token {
+      headers
+        .push_from_iter(Header::from_name_and_value("x-amz-security-token", [elem.as_str()]))?;
+    }
+
+    let mut signed_headers = Vector::new();
+    signed_headers.push(("host", uri.host()))?;
+    for header in headers.iter() {
+      if is_amz_header(header.name) {
+        signed_headers.push((header.name, header.value.trim()))?;
+      }
+    }
+    signed_headers.sort_unstable_by(|a, b| lowercase(a.0).cmp(lowercase(b.0)));
+
+    let mut canonical_request = String::new();
+    canonical_request.write_fmt(format_args!("{}\n", method.strings().custom[0]))?;
+    let path = uri.path();
+    if path.is_empty() {
+      canonical_request.push('/');
+    } else {
+      encode(path, unreserved().remove(b'/'), &mut canonical_request)?;
+    }
+    canonical_request.push('\n');
+    push_canonical_query(&mut canonical_request, uri.query_and_fragment())?;
+    canonical_request.push('\n');
+    for (name, value) in signed_headers.iter() {
+      canonical_request.extend(lowercase(name));
+      canonical_request.write_fmt(format_args!(":{value}\n"))?;
+    }
+    canonical_request.push('\n');
+    let signed_headers_start = canonical_request.len();
+    push_signed_headers(&mut canonical_request, &signed_headers);
+    let signed_headers_end = canonical_request.len();
+    canonical_request.write_fmt(format_args!("\n{payload_hash}"))?;
+
+    let mut string_to_sign = String::new();
+    string_to_sign.write_fmt(format_args!(
+      "{ALGORITHM}\n{amz_date}\n{date}/{}/{}/aws4_request\n",
+      self.region, self.service
+    ))?;
+    push_hex(&Sha256::digest(canonical_request.as_bytes()), &mut string_to_sign)?;
+
+    let mut key = String::new();
+    key.write_fmt(format_args!("AWS4{}", self.secret_key))?;
+    let mut signing_key = hmac_sha256(key.as_bytes(), date.as_bytes())?;
+    for elem in [self.region.as_str(), self.service.as_str(), "aws4_request"] {
+      signing_key = hmac_sha256(&signing_key, elem.as_bytes())?;
+    }
+    let mut signature = String::new();
+    push_hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes())?, &mut signature)?;
+    headers.push_from_fmt(Header::from_name_and_value(
+      KnownHeaderName::Authorization.into(),
+      format_args!(
+        "{ALGORITHM} Credential={}/{date}/{}/{}/aws4_request, SignedHeaders={}, Signature={signature}",
+        self.access_key,
+        self.region,
+        self.service,
+        canonical_request.get(signed_headers_start..signed_headers_end).unwrap_or_default(),
+      ),
+    ))
+  }
+}
+
+impl Interceptor<HttpParams> for SigV4Signer {
+  #[inline]
+  async fn before_sending(&mut self, bytes: &[u8], tp: &mut HttpParams) -> crate::Result<()> {
+    let timestamp = GenericTime::now_timestamp()?.as_secs();
+    self.sign(bytes, tp.ext_req_params_mut(), timestamp)
+  }
+}
+
+fn encode(component: &str, ascii_set: AsciiSet, buffer: &mut String) -> crate::Result<()> {
+  let mut decoded = Vector::new();
+  let bytes = if PercentDecode::new(component.as_bytes()).decode(&mut decoded)? {
+    &decoded
+  } else {
+    component.as_bytes()
+  };
+  for elem in PercentEncode::new(bytes, ascii_set) {
+    buffer.push_str(from_utf8_basic(elem)?);
+  }
+  Ok(())
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> crate::Result<[u8; 32]> {
+  let mut mac = Hmac::<Sha256>::new_from_slice(key)?;
+  mac.update(data);
+  let mut rslt = [0; 32];
+  rslt.copy_from_slice(&mac.finalize().into_bytes());
+  Ok(rslt)
+}
+
+fn is_amz_header(name: &str) -> bool {
+  name.get(..6).is_some_and(|el| el.eq_ignore_ascii_case("x-amz-"))
+}
+
+fn push_canonical_query(buffer: &mut String, query_and_fragment: &str) -> crate::Result<()> {
+  let query = query_and_fragment.split('#').next().unwrap_or_default();
+  let mut pairs = Vector::new();
+  for pair in query.strip_prefix('?').unwrap_or(query).split('&') {
+    if pair.is_empty() {
+      continue;
+    }
+    let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+    let mut encoded_name = String::new();
+    encode(name, unreserved(), &mut encoded_name)?;
+    let mut encoded_value = String::new();
+    encode(value, unreserved(), &mut encoded_value)?;
+    pairs.push((encoded_name, encoded_value))?;
+  }
+  pairs.sort_unstable();
+  for (idx, (name, value)) in pairs.iter().enumerate() {
+    if idx > 0 {
+      buffer.push('&');
+    }
+    buffer.write_fmt(format_args!("{name}={value}"))?;
+  }
+  Ok(())
+}
+
+fn push_signed_headers(buffer: &mut String, signed_headers: &[(&str, &str)]) {
+  for (idx, (name, _)) in signed_headers.iter().enumerate() {
+    if idx > 0 {
+      buffer.push(';');
+    }
+    buffer.extend(lowercase(name));
+  }
+}
+
+fn lowercase(name: &str) -> impl Iterator<Item = char> {
+  name.chars().map(|el| el.to_ascii_lowercase())
+}
+
+// https://datatracker.ietf.org/doc/html/rfc3986#section-2.3
+fn unreserved() -> AsciiSet {
+  AsciiSet::NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~')
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      misc::SigV4Signer,
+      network::{HttpParams, transport::TransportParams},
+    },
+    http::KnownHeaderName,
+  };
+
+  // https://github.com/awslabs/aws-c-auth/tree/main/tests/aws-signing-test-suite/v4
+  #[test]
+  fn sign() {
+    let signer = SigV4Signer::new(
+      "AKIDEXAMPLE".into(),
+      "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".into(),
+      "us-east-1".into(),
+      "service".into(),
+    );
+    for (uri, signature) in [
+      (
+        "https://example.amazonaws.com/",
+        "5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31",
+      ),
+      (
+        "https://example.amazonaws.com/?Param2=value2&Param1=value1",
+        "b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500",
+      ),
+    ] {
+      let mut params = HttpParams::from_uri(uri.into());
+      let req_params = params.ext_req_params_mut();
+      signer.sign(&[], req_params, 1_440_938_160).unwrap();
+      signer.sign(&[], req_params, 1_440_938_160).unwrap();
+      assert_eq!(req_params.headers.headers_len(), 2);
+      let authorization =
+        req_params.headers.get_by_name(KnownHeaderName::Authorization.into()).unwrap().value;
+      assert_eq!(
+        authorization.split_once("Signature=").unwrap(),
+        (
+          "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+           SignedHeaders=host;x-amz-date, ",
+          signature
+        )
+      );
+    }
+  }
+}
@@ -0,0 +1,108 @@
+use crate::client_api_framework::network::TransportGroup;
+use core::time::Duration;
+
+/// Receives the metrics of every exchange performed through
+/// [`crate::client_api_framework::network::transport::SendingReceivingTransport`], regardless of
+/// the underlying transport.
+///
+/// Provided by [`crate::client_api_framework::Api::transport_observer`].
+pub trait TransportObserver {
+  /// Called after each request/response exchange, successful or not.
+  fn on_exchange(&self, metrics: &ExchangeMetrics);
+}
+
+impl<T> TransportObserver for &T
+where
+  T: TransportObserver + ?Sized,
+{
+  #[inline]
+  fn on_exchange(&self, metrics: &ExchangeMetrics) {
+    (**self).on_exchange(metrics);
+  }
+}
+
+/// Phase where an exchange failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExchangeErrorKind {
+  /// Failed while receiving or handling the response.
+  Receiving {
+    /// See [`crate::client_api_framework::Api::is_transient_error`].
+    is_transient: bool,
+  },
+  /// Failed while encoding or sending the request.
+  Sending {
+    /// See [`crate::client_api_framework::Api::is_transient_error`].
+    is_transient: bool,
+  },
+}
+
+/// Measurements of a single request/response exchange.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExchangeMetrics {
+  /// Length of the received response. Zero if the exchange failed.
+  pub bytes_in: usize,
+  /// Length of the sent request.
+  pub bytes_out: usize,
+  /// If any, the error classification of a failed exchange.
+  pub error: Option<ExchangeErrorKind>,
+  /// Time spent encoding the request. Always zero for raw bytes.
+  pub serialize_time: Duration,
+  /// Transport that performed the exchange.
+  pub transport_group: TransportGroup,
+  /// Elapsed time between the beginning of the sending and the end of the receiving, excluding
+  /// the encoding of the request.
+  pub wire_latency: Duration,
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      Api, SendBytesSource,
+      misc::{ExchangeErrorKind, ExchangeMetrics, TransportObserver},
+      network::{
+        TransportGroup,
+        transport::{MockStr, SendingReceivingTransport},
+      },
+      pkg::PkgsAux,
+    },
+    misc::Vector,
+  };
+  use alloc::borrow::Cow;
+  use core::cell::RefCell;
+
+  #[derive(Debug, Default)]
+  struct ObservedApi(RefCell<Vector<ExchangeMetrics>>);
+
+  impl Api for ObservedApi {
+    type Error = crate::Error;
+    type Id = ();
+
+    fn transport_observer(&self) -> Option<&dyn TransportObserver> {
+      Some(self)
+    }
+  }
+
+  impl TransportObserver for ObservedApi {
+    fn on_exchange(&self, metrics: &ExchangeMetrics) {
+      self.0.borrow_mut().push(*metrics).unwrap();
+    }
+  }
+
+  #[tokio::test]
+  async fn observes_exchanges() {
+    let mut pkgs_aux = PkgsAux::from_minimum(ObservedApi::default(), (), ());
+    let mut trans = MockStr::default();
+    trans.push_response(Cow::Borrowed("pong!"));
+    trans.send_bytes_recv(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.unwrap();
+    assert!(trans.send_bytes_recv(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.is_err());
+    let metrics = pkgs_aux.api.0.borrow();
+    assert_eq!(metrics.len(), 2);
+    assert_eq!(metrics[0].bytes_in, 5);
+    assert_eq!(metrics[0].bytes_out, 4);
+    assert_eq!(metrics[0].error, None);
+    assert_eq!(metrics[0].transport_group, TransportGroup::Stub);
+    assert_eq!(metrics[1].bytes_in, 0);
+    assert_eq!(metrics[1].error, Some(ExchangeErrorKind::Receiving { is_transient: false }));
+  }
+}
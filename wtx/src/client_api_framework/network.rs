@@ -1,12 +1,22 @@
 //! Auxiliary network function and structures as well as different transport implementations
 
+#[cfg(feature = "grpc-client")]
+mod grpc;
 #[cfg(feature = "http")]
 mod http;
+#[cfg(feature = "mqtt")]
+mod mqtt;
 pub mod transport;
 mod transport_group;
+mod udp;
 mod ws;
 
+#[cfg(feature = "grpc-client")]
+pub use grpc::*;
 #[cfg(feature = "http")]
 pub use http::*;
+#[cfg(feature = "mqtt")]
+pub use mqtt::*;
 pub use transport_group::*;
+pub use udp::*;
 pub use ws::*;
@@ -0,0 +1,107 @@
+use crate::{
+  client_api_framework::network::transport::TransportParams,
+  grpc::GrpcStatusCode,
+  http::{Headers, StatusCode},
+  misc::{Lease, LeaseMut, UriString},
+};
+use alloc::string::String;
+use core::time::Duration;
+
+#[derive(Debug)]
+#[doc = generic_trans_params_doc!()]
+pub struct GrpcParams(GrpcReqParams, GrpcResParams);
+
+impl GrpcParams {
+  /// The URI must contain the full method path, e.g., `http://localhost/package.Service/Method`.
+  #[inline]
+  pub fn from_uri(uri: String) -> Self {
+    Self(
+      GrpcReqParams { headers: Headers::new(), timeout: None, uri: UriString::new(uri) },
+      GrpcResParams {
+        grpc_message: String::new(),
+        grpc_status: GrpcStatusCode::Unknown,
+        status_code: StatusCode::InternalServerError,
+      },
+    )
+  }
+}
+
+impl Lease<GrpcParams> for GrpcParams {
+  #[inline]
+  fn lease(&self) -> &GrpcParams {
+    self
+  }
+}
+
+impl LeaseMut<GrpcParams> for GrpcParams {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut GrpcParams {
+    self
+  }
+}
+
+impl TransportParams for GrpcParams {
+  type ExternalRequestParams = GrpcReqParams;
+  type ExternalResponseParams = GrpcResParams;
+
+  #[inline]
+  fn ext_params(&self) -> (&Self::ExternalRequestParams, &Self::ExternalResponseParams) {
+    (&self.0, &self.1)
+  }
+
+  #[inline]
+  fn ext_params_mut(
+    &mut self,
+  ) -> (&mut Self::ExternalRequestParams, &mut Self::ExternalResponseParams) {
+    (&mut self.0, &mut self.1)
+  }
+
+  #[inline]
+  fn reset(&mut self) {
+    self.0.reset();
+    self.1.reset();
+  }
+}
+
+#[derive(Debug)]
+#[doc = generic_trans_req_params_doc!("gRPC")]
+pub struct GrpcReqParams {
+  /// Custom metadata. Mandatory gRPC headers are automatically included.
+  pub headers: Headers,
+  /// Sent through the `grpc-timeout` header.
+  pub timeout: Option<Duration>,
+  /// URI.
+  pub uri: UriString,
+}
+
+impl GrpcReqParams {
+  /// Sets the inner parameters with their default values.
+  #[inline]
+  pub fn reset(&mut self) {
+    self.headers.clear();
+    self.timeout = None;
+    self.uri.truncate_with_initial_len();
+  }
+}
+
+#[derive(Debug)]
+#[doc = generic_trans_res_params_doc!("gRPC")]
+pub struct GrpcResParams {
+  /// Percent-encoded contents of the `grpc-message` trailer, if any.
+  pub grpc_message: String,
+  /// Status of the call. Derived from the HTTP status code when the `grpc-status` trailer is
+  /// missing.
+  pub grpc_status: GrpcStatusCode,
+  /// HTTP status code.
+  pub status_code: StatusCode,
+}
+
+impl GrpcResParams {
+  /// Sets the inner parameters with their default values.
+  #[inline]
+  pub fn reset(&mut self) {
+    self.grpc_message.clear();
+    self.grpc_status = GrpcStatusCode::Unknown;
+    self.status_code = StatusCode::InternalServerError;
+  }
+}
@@ -9,7 +9,7 @@ use alloc::string::String;
 
 #[derive(Debug)]
 #[doc = generic_trans_params_doc!()]
-pub struct HttpParams(HttpReqParams, HttpResParams);
+pub struct HttpParams(HttpReqParams, HttpResParams, Option<usize>);
 
 impl HttpParams {
   /// For example, from `http://localhost`.
@@ -17,14 +17,41 @@ impl HttpParams {
   pub fn from_uri(uri: String) -> Self {
     Self(
       HttpReqParams {
+        accept: None,
         headers: Headers::new(),
         method: Method::Get,
         mime: None,
         uri: UriString::new(uri),
       },
       HttpResParams { status_code: StatusCode::Forbidden },
+      Some(4 * 1024 * 1024),
     )
   }
+
+  /// Maximum length of decompressed response bodies, which defaults to 4MiB.
+  ///
+  /// While enabled, all supported content codings are advertised through the `Accept-Encoding`
+  /// header and received bodies are transparently decompressed. `None` disables this behavior.
+  #[inline]
+  #[must_use]
+  pub fn decompression(mut self, elem: Option<usize>) -> Self {
+    self.2 = elem;
+    self
+  }
+
+  /// See [`HttpParams::decompression`].
+  #[inline]
+  pub fn max_decompressed_len(&self) -> Option<usize> {
+    self.2
+  }
+
+  /// Mutable version of [`HttpParams::max_decompressed_len`], which allows toggling the
+  /// decompression after construction, e.g., when bodies must be forwarded as is by proxies.
+  /// The value is not reset between requests.
+  #[inline]
+  pub fn max_decompressed_len_mut(&mut self) -> &mut Option<usize> {
+    &mut self.2
+  }
 }
 
 impl Lease<HttpParams> for HttpParams {
@@ -67,11 +94,13 @@ impl TransportParams for HttpParams {
 #[derive(Debug)]
 #[doc = generic_trans_req_params_doc!("HTTP")]
 pub struct HttpReqParams {
+  /// Media type expected in responses, sent through the `Accept` header.
+  pub accept: Option<Mime>,
   /// Http headers.
   pub headers: Headers,
   /// Http method.
   pub method: Method,
-  /// MIME type.
+  /// Media type of the request body, sent through the `Content-Type` header.
   pub mime: Option<Mime>,
   /// URI.
   pub uri: UriString,
@@ -81,6 +110,7 @@ impl HttpReqParams {
   /// Sets the inner parameters with their default values.
   #[inline]
   pub fn reset(&mut self) {
+    self.accept = None;
     self.headers.clear();
     self.method = Method::Get;
     self.mime = None;
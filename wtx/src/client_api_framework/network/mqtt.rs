@@ -0,0 +1,111 @@
+use crate::{
+  client_api_framework::{ClientApiFrameworkError, network::transport::TransportParams},
+  misc::{Lease, LeaseMut},
+};
+use alloc::string::String;
+
+/// Delivery guarantee of MQTT messages. Exactly once delivery is not supported.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MqttQos {
+  /// Messages are delivered at most once without acknowledgements.
+  AtMostOnce,
+  /// Messages are delivered at least once and acknowledged by the receiver.
+  AtLeastOnce,
+}
+
+impl From<MqttQos> for u8 {
+  #[inline]
+  fn from(from: MqttQos) -> Self {
+    match from {
+      MqttQos::AtMostOnce => 0,
+      MqttQos::AtLeastOnce => 1,
+    }
+  }
+}
+
+impl TryFrom<u8> for MqttQos {
+  type Error = crate::Error;
+
+  #[inline]
+  fn try_from(from: u8) -> Result<Self, Self::Error> {
+    Ok(match from {
+      0 => Self::AtMostOnce,
+      1 => Self::AtLeastOnce,
+      _ => return Err(ClientApiFrameworkError::InvalidMqttPacket.into()),
+    })
+  }
+}
+
+#[derive(Debug)]
+#[doc = generic_trans_params_doc!()]
+pub struct MqttParams(MqttReqParams, MqttResParams);
+
+impl MqttParams {
+  /// Requests are published to `topic`.
+  #[inline]
+  pub fn from_topic(topic: String) -> Self {
+    Self(
+      MqttReqParams { qos: MqttQos::AtMostOnce, retain: false, topic },
+      MqttResParams { qos: MqttQos::AtMostOnce, topic: String::new() },
+    )
+  }
+}
+
+impl Lease<MqttParams> for MqttParams {
+  #[inline]
+  fn lease(&self) -> &MqttParams {
+    self
+  }
+}
+
+impl LeaseMut<MqttParams> for MqttParams {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut MqttParams {
+    self
+  }
+}
+
+impl TransportParams for MqttParams {
+  type ExternalRequestParams = MqttReqParams;
+  type ExternalResponseParams = MqttResParams;
+
+  #[inline]
+  fn ext_params(&self) -> (&Self::ExternalRequestParams, &Self::ExternalResponseParams) {
+    (&self.0, &self.1)
+  }
+
+  #[inline]
+  fn ext_params_mut(
+    &mut self,
+  ) -> (&mut Self::ExternalRequestParams, &mut Self::ExternalResponseParams) {
+    (&mut self.0, &mut self.1)
+  }
+
+  #[inline]
+  fn reset(&mut self) {
+    self.0.qos = MqttQos::AtMostOnce;
+    self.0.retain = false;
+    self.1.qos = MqttQos::AtMostOnce;
+    self.1.topic.clear();
+  }
+}
+
+#[derive(Debug)]
+#[doc = generic_trans_req_params_doc!("MQTT")]
+pub struct MqttReqParams {
+  /// Delivery guarantee of the published message.
+  pub qos: MqttQos,
+  /// If the broker should store the message for future subscribers.
+  pub retain: bool,
+  /// Topic name. Isn't modified when the parameters are reset.
+  pub topic: String,
+}
+
+#[derive(Debug)]
+#[doc = generic_trans_res_params_doc!("MQTT")]
+pub struct MqttResParams {
+  /// Delivery guarantee of the received message.
+  pub qos: MqttQos,
+  /// Topic of the received message.
+  pub topic: String,
+}
@@ -1,22 +1,44 @@
 //! Implementations of the [Transport] trait.
 
+mod circuit_breaker;
+mod framing;
+mod interceptor;
 mod mock;
+#[cfg(feature = "mqtt")]
+mod mqtt;
 mod recieving_transport;
 mod sending_receiving_transport;
 mod sending_transport;
+mod subscription_demux;
+mod tcp;
 mod transport_params;
+#[cfg(feature = "std")]
+mod udp;
 mod unit;
+mod unix;
+#[cfg(feature = "grpc-client")]
+mod wtx_grpc;
 #[cfg(feature = "http2")]
 mod wtx_http;
 #[cfg(feature = "web-socket")]
 mod wtx_ws;
 
 use crate::client_api_framework::network::TransportGroup;
-pub use mock::{Mock, MockBytes, MockStr};
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerState};
+pub use framing::Framing;
+pub use interceptor::{Intercepted, Interceptor};
+pub use mock::{Mock, MockBytes, MockMatcher, MockStr};
+#[cfg(feature = "mqtt")]
+pub use mqtt::{MqttClient, MqttConfig};
 pub use recieving_transport::ReceivingTransport;
 pub use sending_receiving_transport::SendingReceivingTransport;
 pub use sending_transport::SendingTransport;
+pub use subscription_demux::{DemuxRoute, SubscriptionDemux};
+pub use tcp::TcpTransport;
 pub use transport_params::TransportParams;
+pub use unix::UnixTransport;
+#[cfg(feature = "web-socket")]
+pub use wtx_ws::ReconnectingWebSocket;
 
 /// Any means of transferring data between two parties.
 ///
@@ -0,0 +1,284 @@
+use crate::{
+  client_api_framework::{
+    Api, ClientApiFrameworkError, SendBytesSource,
+    network::{
+      TransportGroup,
+      transport::{ReceivingTransport, SendingTransport, Transport},
+    },
+    pkg::{Package, PkgsAux},
+  },
+  misc::GenericTime,
+};
+use core::time::Duration;
+
+/// State of a [`CircuitBreaker`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CircuitBreakerState {
+  /// Requests are sent and their outcomes are monitored.
+  Closed,
+  /// Requests are sent as probes. A single failure opens the circuit again.
+  HalfOpen,
+  /// Requests fail immediately with [`ClientApiFrameworkError::OpenCircuitBreaker`].
+  Open,
+}
+
+/// Wrapper that stops using the inner transport when the rate of failures gets too high.
+///
+/// Outcomes are recorded when responses are received or when requests can't be sent. Once the
+/// failure rate of the last [`Self::window`] outcomes reaches [`Self::failure_rate`], the circuit
+/// opens and all sending attempts fail fast. After [`Self::open_duration`], subsequent requests
+/// are sent as probes and the circuit closes after [`Self::probes`] successful responses.
+#[derive(Debug)]
+pub struct CircuitBreaker<T> {
+  failure_rate: u8,
+  open_duration: Duration,
+  opened_at: Option<GenericTime>,
+  outcomes: u64,
+  outcomes_len: u8,
+  probes: u8,
+  state: CircuitBreakerState,
+  successful_probes: u8,
+  trans: T,
+  window: u8,
+}
+
+impl<T> CircuitBreaker<T> {
+  /// Opens when at least 50% of the last 20 outcomes are failures, stays open for 30 seconds and
+  /// closes after 1 successful probe.
+  #[inline]
+  pub const fn new(trans: T) -> Self {
+    Self {
+      failure_rate: 50,
+      open_duration: Duration::from_secs(30),
+      opened_at: None,
+      outcomes: 0,
+      outcomes_len: 0,
+      probes: 1,
+      state: CircuitBreakerState::Closed,
+      successful_probes: 0,
+      trans,
+      window: 20,
+    }
+  }
+
+  /// Percentage, from 1 to 100, of failed outcomes that opens the circuit.
+  #[inline]
+  #[must_use]
+  pub const fn failure_rate(mut self, elem: u8) -> Self {
+    self.failure_rate = match elem {
+      0 => 1,
+      101..=u8::MAX => 100,
+      _ => elem,
+    };
+    self
+  }
+
+  /// Amount of time that the circuit stays open before sending probes.
+  #[inline]
+  #[must_use]
+  pub const fn open_duration(mut self, elem: Duration) -> Self {
+    self.open_duration = elem;
+    self
+  }
+
+  /// Number of successful probes that closes a half-open circuit.
+  #[inline]
+  #[must_use]
+  pub const fn probes(mut self, elem: u8) -> Self {
+    self.probes = if elem == 0 { 1 } else { elem };
+    self
+  }
+
+  /// Current state.
+  #[inline]
+  pub fn state(&self) -> CircuitBreakerState {
+    self.state
+  }
+
+  /// Inner transport
+  #[inline]
+  pub fn transport(&self) -> &T {
+    &self.trans
+  }
+
+  /// Mutable version of [`Self::transport`].
+  #[inline]
+  pub fn transport_mut(&mut self) -> &mut T {
+    &mut self.trans
+  }
+
+  /// Number of most recent outcomes, from 1 to 64, that are used to calculate the failure rate.
+  /// The circuit can only open after this amount of outcomes is recorded.
+  #[inline]
+  #[must_use]
+  pub const fn window(mut self, elem: u8) -> Self {
+    self.window = match elem {
+      0 => 1,
+      65..=u8::MAX => 64,
+      _ => elem,
+    };
+    self
+  }
+
+  fn check(&mut self) -> crate::Result<()> {
+    if let CircuitBreakerState::Open = self.state {
+      let elapsed = self.opened_at.and_then(|el| el.elapsed().ok()).unwrap_or_default();
+      if elapsed < self.open_duration {
+        return Err(ClientApiFrameworkError::OpenCircuitBreaker.into());
+      }
+      _debug!("Circuit breaker is half-open");
+      self.state = CircuitBreakerState::HalfOpen;
+      self.successful_probes = 0;
+    }
+    Ok(())
+  }
+
+  fn close(&mut self) {
+    _debug!("Circuit breaker is closed");
+    self.outcomes = 0;
+    self.outcomes_len = 0;
+    self.state = CircuitBreakerState::Closed;
+  }
+
+  fn open(&mut self) {
+    _debug!("Circuit breaker is open");
+    self.opened_at = Some(GenericTime::now());
+    self.state = CircuitBreakerState::Open;
+  }
+
+  fn record(&mut self, is_failure: bool) {
+    match self.state {
+      CircuitBreakerState::Closed => {
+        self.outcomes = self.outcomes.wrapping_shl(1) | u64::from(is_failure);
+        self.outcomes_len = self.outcomes_len.saturating_add(1).min(self.window);
+        if self.outcomes_len < self.window {
+          return;
+        }
+        let mask = u64::MAX.checked_shr(64u32.wrapping_sub(self.window.into())).unwrap_or(0);
+        let failures = u64::from((self.outcomes & mask).count_ones());
+        let threshold = u64::from(self.failure_rate).wrapping_mul(self.window.into());
+        if failures.wrapping_mul(100) >= threshold {
+          self.open();
+        }
+      }
+      CircuitBreakerState::HalfOpen => {
+        if is_failure {
+          self.open();
+          return;
+        }
+        self.successful_probes = self.successful_probes.wrapping_add(1);
+        if self.successful_probes >= self.probes {
+          self.close();
+        }
+      }
+      CircuitBreakerState::Open => {}
+    }
+  }
+}
+
+impl<T, TP> ReceivingTransport<TP> for CircuitBreaker<T>
+where
+  T: ReceivingTransport<TP>,
+{
+  #[inline]
+  async fn recv<A, DRSR>(
+    &mut self,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    req_id: Self::ReqId,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    let rslt = self.trans.recv(pkgs_aux, req_id).await;
+    self.record(rslt.is_err());
+    rslt
+  }
+}
+
+impl<T, TP> SendingTransport<TP> for CircuitBreaker<T>
+where
+  T: SendingTransport<TP>,
+{
+  #[inline]
+  async fn send_bytes<A, DRSR>(
+    &mut self,
+    bytes: SendBytesSource<'_>,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<Self::ReqId, A::Error>
+  where
+    A: Api,
+  {
+    self.check()?;
+    let rslt = self.trans.send_bytes(bytes, pkgs_aux).await;
+    if rslt.is_err() {
+      self.record(true);
+    }
+    rslt
+  }
+
+  #[inline]
+  async fn send_pkg<A, DRSR, P>(
+    &mut self,
+    pkg: &mut P,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<Self::ReqId, A::Error>
+  where
+    A: Api,
+    P: Package<A, DRSR, Self::Inner, TP>,
+  {
+    self.check()?;
+    let rslt = self.trans.send_pkg(pkg, pkgs_aux).await;
+    if rslt.is_err() {
+      self.record(true);
+    }
+    rslt
+  }
+}
+
+impl<T, TP> Transport<TP> for CircuitBreaker<T>
+where
+  T: Transport<TP>,
+{
+  const GROUP: TransportGroup = T::GROUP;
+  type Inner = T::Inner;
+  type ReqId = T::ReqId;
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      SendBytesSource,
+      network::transport::{
+        CircuitBreaker, CircuitBreakerState, MockStr, SendingReceivingTransport,
+      },
+      pkg::PkgsAux,
+    },
+    misc::sleep,
+  };
+  use alloc::borrow::Cow;
+  use core::time::Duration;
+
+  #[tokio::test]
+  async fn opens_and_closes() {
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), ());
+    let mut trans = CircuitBreaker::new(MockStr::default())
+      .failure_rate(50)
+      .open_duration(Duration::from_millis(20))
+      .window(4);
+    trans.transport_mut().push_responses(["a", "b"].map(Cow::Borrowed));
+    for _ in 0..4 {
+      let _rslt = trans.send_bytes_recv(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await;
+    }
+    assert_eq!(trans.state(), CircuitBreakerState::Open);
+    assert!(trans.send_bytes_recv(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.is_err());
+    for _ in 0..4 {
+      trans.transport_mut().assert_request("ping");
+    }
+    trans.transport().assert_does_not_have_non_asserted_requests();
+    sleep(Duration::from_millis(30)).await.unwrap();
+    trans.transport_mut().push_response(Cow::Borrowed("c"));
+    trans.send_bytes_recv(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.unwrap();
+    assert_eq!(trans.state(), CircuitBreakerState::Closed);
+  }
+}
@@ -0,0 +1,242 @@
+use crate::{
+  client_api_framework::{
+    ClientApiFrameworkError,
+    misc::{_log_res, manage_after_receiving},
+    network::{TransportGroup, transport::TransportParams},
+    pkg::PkgsAux,
+  },
+  misc::{BufferMode, Stream, Vector},
+};
+
+const MAX_VARINT_LEN: usize = 5;
+const READ_LEN: usize = 1024;
+
+/// How messages are delimited in byte streams.
+#[derive(Clone, Copy, Debug)]
+pub enum Framing {
+  /// Messages are followed by the contained non-empty sequence of bytes, which must not be present
+  /// in the messages themselves.
+  Delimiter(&'static [u8]),
+  /// Messages are preceded by their length encoded as a big-endian `u16`.
+  U16BeLen,
+  /// Messages are preceded by their length encoded as a little-endian `u16`.
+  U16LeLen,
+  /// Messages are preceded by their length encoded as a big-endian `u32`.
+  U32BeLen,
+  /// Messages are preceded by their length encoded as a little-endian `u32`.
+  U32LeLen,
+  /// Messages are preceded by their length encoded as a base-128 varint of at most 5 bytes. Used
+  /// by length-delimited Protocol Buffers.
+  VarintLen,
+}
+
+// Stream that reads and writes messages according to a `Framing`.
+#[derive(Debug)]
+pub(crate) struct FramedStream<S> {
+  buffer: Vector<u8>,
+  consumed: usize,
+  framing: Framing,
+  max_message_len: usize,
+  searched: usize,
+  stream: S,
+}
+
+impl<S> FramedStream<S> {
+  pub(crate) const fn new(framing: Framing, stream: S) -> Self {
+    Self {
+      buffer: Vector::new(),
+      consumed: 0,
+      framing,
+      max_message_len: 16 * 1024 * 1024,
+      searched: 0,
+      stream,
+    }
+  }
+
+  pub(crate) fn set_max_message_len(&mut self, elem: usize) {
+    self.max_message_len = elem;
+  }
+
+  pub(crate) fn stream(&self) -> &S {
+    &self.stream
+  }
+
+  pub(crate) fn stream_mut(&mut self) -> &mut S {
+    &mut self.stream
+  }
+
+  // Returns the beginning and ending indices of the message as well as the total length of the
+  // frame.
+  fn parse(&mut self) -> crate::Result<Option<(usize, usize, usize)>> {
+    let buffer = self.buffer.as_slice();
+    let (prefix_len, message_len): (usize, usize) = match self.framing {
+      Framing::Delimiter(delimiter) => {
+        if delimiter.is_empty() {
+          return Err(ClientApiFrameworkError::EmptyFramingDelimiter.into());
+        }
+        let begin = self.searched;
+        let idx_opt = buffer
+          .get(begin..)
+          .unwrap_or_default()
+          .windows(delimiter.len())
+          .position(|el| el == delimiter);
+        let Some(idx) = idx_opt else {
+          self.searched = buffer.len().saturating_sub(delimiter.len().wrapping_sub(1));
+          if self.searched > self.max_message_len {
+            return Err(ClientApiFrameworkError::VeryLargeFramedMessage(self.searched).into());
+          }
+          return Ok(None);
+        };
+        self.searched = 0;
+        let end = begin.wrapping_add(idx);
+        if end > self.max_message_len {
+          return Err(ClientApiFrameworkError::VeryLargeFramedMessage(end).into());
+        }
+        return Ok(Some((0, end, end.wrapping_add(delimiter.len()))));
+      }
+      Framing::U16BeLen => match buffer {
+        [a, b, ..] => (2, usize::from(u16::from_be_bytes([*a, *b]))),
+        _ => return Ok(None),
+      },
+      Framing::U16LeLen => match buffer {
+        [a, b, ..] => (2, usize::from(u16::from_le_bytes([*a, *b]))),
+        _ => return Ok(None),
+      },
+      Framing::U32BeLen => match buffer {
+        [a, b, c, d, ..] => (4, usize::try_from(u32::from_be_bytes([*a, *b, *c, *d]))?),
+        _ => return Ok(None),
+      },
+      Framing::U32LeLen => match buffer {
+        [a, b, c, d, ..] => (4, usize::try_from(u32::from_le_bytes([*a, *b, *c, *d]))?),
+        _ => return Ok(None),
+      },
+      Framing::VarintLen => match decode_varint(buffer)? {
+        Some((len, varint_len)) => (varint_len, len),
+        None => return Ok(None),
+      },
+    };
+    if message_len > self.max_message_len {
+      return Err(ClientApiFrameworkError::VeryLargeFramedMessage(message_len).into());
+    }
+    let end = prefix_len.wrapping_add(message_len);
+    if buffer.len() < end {
+      return Ok(None);
+    }
+    Ok(Some((prefix_len, end, end)))
+  }
+}
+
+impl<S> FramedStream<S>
+where
+  S: Stream,
+{
+  // Appends the next received message to `bytes`.
+  pub(crate) async fn read(&mut self, bytes: &mut Vector<u8>) -> crate::Result<()> {
+    let _ = self.buffer.drain(..self.consumed);
+    self.consumed = 0;
+    loop {
+      if let Some((begin, end, frame_len)) = self.parse()? {
+        bytes.extend_from_copyable_slice(self.buffer.get(begin..end).unwrap_or_default())?;
+        self.consumed = frame_len;
+        return Ok(());
+      }
+      let len = self.buffer.len();
+      self.buffer.expand(BufferMode::Additional(READ_LEN), 0)?;
+      let rslt = self.stream.read(self.buffer.get_mut(len..).unwrap_or_default()).await;
+      self.buffer.truncate(len.wrapping_add(*rslt.as_ref().unwrap_or(&0)));
+      if rslt? == 0 {
+        return Err(crate::Error::ClosedConnection);
+      }
+    }
+  }
+
+  // Fills the buffer of `pkgs_aux` with the next received message.
+  pub(crate) async fn recv<A, DRSR, TP>(
+    &mut self,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    tg: TransportGroup,
+  ) -> crate::Result<()>
+  where
+    TP: TransportParams,
+  {
+    pkgs_aux.byte_buffer.clear();
+    self.read(&mut pkgs_aux.byte_buffer).await?;
+    manage_after_receiving(pkgs_aux).await?;
+    _log_res(pkgs_aux.log_body.1, &pkgs_aux.byte_buffer, tg);
+    Ok(())
+  }
+
+  pub(crate) async fn write(&mut self, bytes: &[u8]) -> crate::Result<()> {
+    if bytes.len() > self.max_message_len {
+      return Err(ClientApiFrameworkError::VeryLargeFramedMessage(bytes.len()).into());
+    }
+    let varint;
+    let prefix: &[u8] = match self.framing {
+      Framing::Delimiter(delimiter) => {
+        if delimiter.is_empty() {
+          return Err(ClientApiFrameworkError::EmptyFramingDelimiter.into());
+        }
+        return self.stream.write_all_vectored(&[bytes, delimiter]).await;
+      }
+      Framing::U16BeLen => &u16::try_from(bytes.len())?.to_be_bytes(),
+      Framing::U16LeLen => &u16::try_from(bytes.len())?.to_le_bytes(),
+      Framing::U32BeLen => &u32::try_from(bytes.len())?.to_be_bytes(),
+      Framing::U32LeLen => &u32::try_from(bytes.len())?.to_le_bytes(),
+      Framing::VarintLen => {
+        varint = encode_varint(u32::try_from(bytes.len())?);
+        varint.0.get(..varint.1).unwrap_or_default()
+      }
+    };
+    self.stream.write_all_vectored(&[prefix, bytes]).await
+  }
+}
+
+// Returns the value and the number of bytes of a varint or `None` if `bytes` is incomplete.
+fn decode_varint(bytes: &[u8]) -> crate::Result<Option<(usize, usize)>> {
+  let mut value: u64 = 0;
+  let mut shift: u32 = 0;
+  for (idx, byte) in bytes.iter().take(MAX_VARINT_LEN).enumerate() {
+    value |= u64::from(byte & 0b0111_1111) << shift;
+    shift = shift.wrapping_add(7);
+    if byte & 0b1000_0000 == 0 {
+      return Ok(Some((usize::try_from(u32::try_from(value)?)?, idx.wrapping_add(1))));
+    }
+  }
+  if bytes.len() >= MAX_VARINT_LEN {
+    return Err(ClientApiFrameworkError::VeryLargeFramedMessage(usize::MAX).into());
+  }
+  Ok(None)
+}
+
+// Returns the encoded bytes and the number of used bytes.
+fn encode_varint(mut value: u32) -> ([u8; MAX_VARINT_LEN], usize) {
+  let mut rslt = [0; MAX_VARINT_LEN];
+  for (idx, byte) in rslt.iter_mut().enumerate() {
+    let [first, ..] = value.to_le_bytes();
+    value >>= 7;
+    if value == 0 {
+      *byte = first & 0b0111_1111;
+      return (rslt, idx.wrapping_add(1));
+    }
+    *byte = first | 0b1000_0000;
+  }
+  (rslt, MAX_VARINT_LEN)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::client_api_framework::network::transport::framing::{decode_varint, encode_varint};
+
+  #[test]
+  fn varint() {
+    for (value, len) in [(0, 1), (127, 1), (128, 2), (16_383, 2), (16_384, 3), (u32::MAX, 5)] {
+      let (bytes, local_len) = encode_varint(value);
+      assert_eq!(local_len, len);
+      let expected = Some((usize::try_from(value).unwrap(), len));
+      assert_eq!(decode_varint(&bytes[..len]).unwrap(), expected);
+      assert_eq!(decode_varint(&bytes[..len - 1]).unwrap(), None);
+    }
+    assert!(decode_varint(&[255, 255, 255, 255, 255]).is_err());
+    assert!(decode_varint(&[255, 255, 255, 255, 16]).is_err());
+  }
+}
@@ -0,0 +1,212 @@
+use crate::{
+  client_api_framework::network::transport::TransportParams,
+  misc::{Lease, LeaseMut, Vector},
+};
+
+/// Custom logic that intercepts the requests and responses of any transport. Useful for signing,
+/// logging, metrics or mutations.
+///
+/// See [`Intercepted`].
+pub trait Interceptor<TP> {
+  /// Called with the received bytes of a response, which can be modified before decoding.
+  #[inline]
+  fn after_receiving(
+    &mut self,
+    _bytes: &mut Vector<u8>,
+    _tp: &mut TP,
+  ) -> impl Future<Output = crate::Result<()>> {
+    async { Ok(()) }
+  }
+
+  /// Called with the serialized bytes of a request that is about to be sent. Parameters like
+  /// headers can still be modified.
+  #[inline]
+  fn before_sending(
+    &mut self,
+    _bytes: &[u8],
+    _tp: &mut TP,
+  ) -> impl Future<Output = crate::Result<()>> {
+    async { Ok(()) }
+  }
+}
+
+impl<I, TP> Interceptor<TP> for &mut I
+where
+  I: Interceptor<TP>,
+{
+  #[inline]
+  async fn after_receiving(&mut self, bytes: &mut Vector<u8>, tp: &mut TP) -> crate::Result<()> {
+    (**self).after_receiving(bytes, tp).await
+  }
+
+  #[inline]
+  async fn before_sending(&mut self, bytes: &[u8], tp: &mut TP) -> crate::Result<()> {
+    (**self).before_sending(bytes, tp).await
+  }
+}
+
+/// Transport parameters that call the hooks of an [`Interceptor`].
+///
+/// Can be used with any transport that accepts the inner parameters.
+///
+/// ```rust,no_run
+/// use wtx::client_api_framework::{
+///   network::{
+///     WsParams,
+///     transport::{Intercepted, Interceptor},
+///   },
+///   pkg::PkgsAux,
+/// };
+///
+/// struct Signer;
+///
+/// impl Interceptor<WsParams> for Signer {
+///   async fn before_sending(&mut self, _: &[u8], _: &mut WsParams) -> wtx::Result<()> {
+///     Ok(())
+///   }
+/// }
+///
+/// let tp = Intercepted::new(Signer, WsParams::default());
+/// let _pkgs_aux = PkgsAux::from_minimum((), (), tp);
+/// ```
+#[derive(Debug)]
+pub struct Intercepted<I, TP> {
+  interceptor: I,
+  tp: TP,
+}
+
+impl<I, TP> Intercepted<I, TP> {
+  /// New instance
+  #[inline]
+  pub const fn new(interceptor: I, tp: TP) -> Self {
+    Self { interceptor, tp }
+  }
+
+  /// Interceptor
+  #[inline]
+  pub fn interceptor(&self) -> &I {
+    &self.interceptor
+  }
+
+  /// Mutable version of [`Self::interceptor`].
+  #[inline]
+  pub fn interceptor_mut(&mut self) -> &mut I {
+    &mut self.interceptor
+  }
+
+  /// Inner parameters
+  #[inline]
+  pub fn tp(&self) -> &TP {
+    &self.tp
+  }
+
+  /// Mutable version of [`Self::tp`].
+  #[inline]
+  pub fn tp_mut(&mut self) -> &mut TP {
+    &mut self.tp
+  }
+}
+
+impl<I, TP, U> Lease<U> for Intercepted<I, TP>
+where
+  TP: Lease<U>,
+  U: ?Sized,
+{
+  #[inline]
+  fn lease(&self) -> &U {
+    self.tp.lease()
+  }
+}
+
+impl<I, TP, U> LeaseMut<U> for Intercepted<I, TP>
+where
+  TP: LeaseMut<U>,
+  U: ?Sized,
+{
+  #[inline]
+  fn lease_mut(&mut self) -> &mut U {
+    self.tp.lease_mut()
+  }
+}
+
+impl<I, TP> TransportParams for Intercepted<I, TP>
+where
+  I: Interceptor<TP>,
+  TP: TransportParams,
+{
+  type ExternalRequestParams = TP::ExternalRequestParams;
+  type ExternalResponseParams = TP::ExternalResponseParams;
+
+  #[inline]
+  async fn after_receiving(&mut self, bytes: &mut Vector<u8>) -> crate::Result<()> {
+    self.tp.after_receiving(bytes).await?;
+    self.interceptor.after_receiving(bytes, &mut self.tp).await
+  }
+
+  #[inline]
+  async fn before_sending(&mut self, bytes: &[u8]) -> crate::Result<()> {
+    self.tp.before_sending(bytes).await?;
+    self.interceptor.before_sending(bytes, &mut self.tp).await
+  }
+
+  #[inline]
+  fn ext_params(&self) -> (&Self::ExternalRequestParams, &Self::ExternalResponseParams) {
+    self.tp.ext_params()
+  }
+
+  #[inline]
+  fn ext_params_mut(
+    &mut self,
+  ) -> (&mut Self::ExternalRequestParams, &mut Self::ExternalResponseParams) {
+    self.tp.ext_params_mut()
+  }
+
+  #[inline]
+  fn reset(&mut self) {
+    self.tp.reset();
+  }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      SendBytesSource,
+      network::transport::{Intercepted, Interceptor, MockBytes, SendingReceivingTransport},
+      pkg::PkgsAux,
+    },
+    misc::Vector,
+  };
+  use alloc::borrow::Cow;
+
+  #[derive(Debug, Default)]
+  struct Counter {
+    received: Vector<u8>,
+    sent: Vector<u8>,
+  }
+
+  impl Interceptor<()> for Counter {
+    async fn after_receiving(&mut self, bytes: &mut Vector<u8>, _: &mut ()) -> crate::Result<()> {
+      self.received.extend_from_copyable_slice(bytes)?;
+      bytes.clear();
+      Ok(())
+    }
+
+    async fn before_sending(&mut self, bytes: &[u8], _: &mut ()) -> crate::Result<()> {
+      self.sent.extend_from_copyable_slice(bytes)?;
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn calls_hooks() {
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), Intercepted::new(Counter::default(), ()));
+    let mut trans = MockBytes::default();
+    trans.push_response(Cow::Borrowed(&[2]));
+    trans.send_bytes_recv(SendBytesSource::Param(&[1]), &mut pkgs_aux).await.unwrap();
+    trans.assert_request(&[1]);
+    assert_eq!(pkgs_aux.tp.interceptor().sent.as_slice(), &[1]);
+    assert_eq!(pkgs_aux.tp.interceptor().received.as_slice(), &[2]);
+    assert!(pkgs_aux.byte_buffer.is_empty());
+  }
+}
@@ -4,8 +4,8 @@ use crate::{
   client_api_framework::{
     Api, ClientApiFrameworkError, SendBytesSource,
     misc::{
-      FromBytes, manage_after_sending_bytes, manage_after_sending_pkg, manage_before_sending_bytes,
-      manage_before_sending_pkg,
+      FromBytes, manage_after_receiving, manage_after_sending_bytes, manage_after_sending_pkg,
+      manage_before_sending_bytes, manage_before_sending_pkg,
     },
     network::{
       TransportGroup,
@@ -25,6 +25,9 @@ pub type MockStr<TP> = Mock<str, TP>;
 
 /// Used to assert issued requests as well as returned responses in a local environment.
 ///
+/// Sent requests can also be validated against ordered expectations registered through
+/// [`Self::expect`]. Dropping an instance with unmet expectations results in a panic.
+///
 /// Almost all methods panic at runtime.
 ///
 /// ```rust,no_run
@@ -45,6 +48,7 @@ where
   <T as ToOwned>::Owned: Debug + FromBytes,
 {
   asserted: usize,
+  expectations: Deque<(MockMatcher<T>, Vector<Cow<'static, T>>)>,
   phantom: PhantomData<TP>,
   requests: Vector<Cow<'static, T>>,
   responses: Deque<Cow<'static, T>>,
@@ -78,15 +82,46 @@ where
     assert_eq!(req, stored.as_ref());
   }
 
+  /// The next sent request that isn't covered by previous expectations must satisfy `matcher`,
+  /// otherwise the sending method panics. When satisfied, `responses` are stored into the inner
+  /// response storage.
+  #[inline]
+  pub fn expect(
+    &mut self,
+    matcher: MockMatcher<T>,
+    responses: impl IntoIterator<Item = Cow<'static, T>>,
+  ) {
+    self.expectations.push_back((matcher, Vector::from_iter(responses).unwrap())).unwrap();
+  }
+
   /// Stores `res` into the inner response storage
   #[inline]
   pub fn push_response(&mut self, res: Cow<'static, T>) {
     self.responses.push_back(res).unwrap();
   }
 
+  /// Stores all `responses` into the inner response storage, in order.
+  #[inline]
+  pub fn push_responses(&mut self, responses: impl IntoIterator<Item = Cow<'static, T>>) {
+    self.responses.extend_back_from_iter(responses).unwrap();
+  }
+
   fn pop_response(&mut self) -> crate::Result<Cow<'static, T>> {
     Ok(self.responses.pop_front().ok_or(ClientApiFrameworkError::TestTransportNoResponse)?)
   }
+
+  fn store_request(&mut self, req: Cow<'static, T>) -> crate::Result<()> {
+    if let Some((matcher, responses)) = self.expectations.pop_front() {
+      assert!(
+        matcher.matches(&req),
+        "Request #{} doesn't satisfy {matcher:?}: {req:?}",
+        self.requests.len()
+      );
+      self.responses.extend_back_from_iter(responses)?;
+    }
+    self.requests.push(req)?;
+    Ok(())
+  }
 }
 
 impl<T, TP> ReceivingTransport<TP> for Mock<T, TP>
@@ -107,6 +142,7 @@ where
     let response = self.pop_response()?;
     pkgs_aux.byte_buffer.clear();
     pkgs_aux.byte_buffer.extend_from_copyable_slice(response.as_ref().lease())?;
+    manage_after_receiving(pkgs_aux).await?;
     Ok(())
   }
 }
@@ -127,7 +163,7 @@ where
     A: Api,
   {
     manage_before_sending_bytes(bytes, pkgs_aux, &mut *self).await?;
-    self.requests.push(Cow::Owned(FromBytes::from_bytes(bytes.bytes(&pkgs_aux.byte_buffer))?))?;
+    self.store_request(Cow::Owned(FromBytes::from_bytes(bytes.bytes(&pkgs_aux.byte_buffer))?))?;
     pkgs_aux.byte_buffer.clear();
     manage_after_sending_bytes(pkgs_aux).await?;
     Ok(())
@@ -144,7 +180,7 @@ where
     P: Package<A, DRSR, Self::Inner, TP>,
   {
     manage_before_sending_pkg(pkg, pkgs_aux, &mut *self).await?;
-    self.requests.push(Cow::Owned(FromBytes::from_bytes(&pkgs_aux.byte_buffer)?))?;
+    self.store_request(Cow::Owned(FromBytes::from_bytes(&pkgs_aux.byte_buffer)?))?;
     pkgs_aux.byte_buffer.clear();
     manage_after_sending_pkg(pkg, pkgs_aux, &mut *self).await?;
     Ok(())
@@ -169,6 +205,119 @@ where
 {
   #[inline]
   fn default() -> Self {
-    Self { asserted: 0, phantom: PhantomData, requests: Vector::new(), responses: Deque::new() }
+    Self {
+      asserted: 0,
+      expectations: Deque::new(),
+      phantom: PhantomData,
+      requests: Vector::new(),
+      responses: Deque::new(),
+    }
+  }
+}
+
+impl<T, TP> Drop for Mock<T, TP>
+where
+  T: ToOwned + 'static + ?Sized,
+  <T as ToOwned>::Owned: Debug + FromBytes,
+{
+  #[inline]
+  fn drop(&mut self) {
+    #[cfg(feature = "std")]
+    if std::thread::panicking() {
+      return;
+    }
+    assert!(
+      self.expectations.len() == 0,
+      "{} expectation(s) weren't consumed",
+      self.expectations.len()
+    );
+  }
+}
+
+/// Criteria that a request sent through [`Mock`] must satisfy.
+#[derive(Debug)]
+pub enum MockMatcher<T>
+where
+  T: ToOwned + 'static + ?Sized,
+  <T as ToOwned>::Owned: Debug,
+{
+  /// Any request is accepted.
+  Any,
+  /// The request must contain the given sequence.
+  Contains(Cow<'static, T>),
+  /// The request must be equal to the given element.
+  Eq(Cow<'static, T>),
+  /// The request must satisfy the given predicate.
+  Fn(fn(&T) -> bool),
+}
+
+impl<T> MockMatcher<T>
+where
+  T: Lease<[u8]> + PartialEq + ToOwned + 'static + ?Sized,
+  <T as ToOwned>::Owned: Debug,
+{
+  fn matches(&self, req: &T) -> bool {
+    match self {
+      Self::Any => true,
+      Self::Contains(elem) => {
+        let needle = elem.as_ref().lease();
+        needle.is_empty() || req.lease().windows(needle.len()).any(|el| el == needle)
+      }
+      Self::Eq(elem) => elem.as_ref() == req,
+      Self::Fn(elem) => elem(req),
+    }
+  }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::client_api_framework::{
+    SendBytesSource,
+    network::transport::{MockMatcher, MockStr, ReceivingTransport, SendingTransport},
+    pkg::PkgsAux,
+  };
+  use alloc::borrow::Cow;
+
+  #[tokio::test]
+  async fn expectations() {
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), ());
+    let mut trans = MockStr::default();
+    trans.expect(
+      MockMatcher::Eq(Cow::Borrowed("login")),
+      [Cow::Borrowed("ok"), Cow::Borrowed("welcome")],
+    );
+    trans.expect(MockMatcher::Contains(Cow::Borrowed("\"id\":2")), [Cow::Borrowed("quote")]);
+    trans.expect(MockMatcher::Fn(|el| el.starts_with("log")), []);
+    trans.send_bytes(SendBytesSource::Param(b"login"), &mut pkgs_aux).await.unwrap();
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"ok");
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"welcome");
+    assert!(trans.recv(&mut pkgs_aux, ()).await.is_err());
+    trans.send_bytes(SendBytesSource::Param(b"{\"id\":2}"), &mut pkgs_aux).await.unwrap();
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"quote");
+    trans.send_bytes(SendBytesSource::Param(b"logout"), &mut pkgs_aux).await.unwrap();
+    trans.assert_request("login");
+    trans.assert_request("{\"id\":2}");
+    trans.assert_request("logout");
+    trans.assert_does_not_have_non_asserted_requests();
+  }
+
+  #[should_panic(expected = "doesn't satisfy")]
+  #[tokio::test]
+  async fn unsatisfied_expectation() {
+    let mut trans = MockStr::default();
+    trans.expect(MockMatcher::Eq(Cow::Borrowed("login")), []);
+    let _rslt = trans
+      .send_bytes(SendBytesSource::Param(b"logout"), &mut PkgsAux::from_minimum((), (), ()))
+      .await;
+  }
+
+  #[should_panic(expected = "weren't consumed")]
+  #[test]
+  fn unconsumed_expectation() {
+    let mut trans = MockStr::<()>::default();
+    trans.expect(MockMatcher::Any, []);
   }
 }
@@ -0,0 +1,108 @@
+// https://docs.oasis-open.org/mqtt/mqtt/v5.0/mqtt-v5.0.html
+
+mod mqtt_client;
+mod mqtt_config;
+
+use crate::{
+  client_api_framework::ClientApiFrameworkError,
+  misc::{StreamWriter, Vector},
+};
+pub use mqtt_client::MqttClient;
+pub use mqtt_config::MqttConfig;
+
+const CONNACK: u8 = 2;
+const CONNECT: u8 = 1;
+const DISCONNECT: u8 = 14;
+const MAX_VAR_INT: usize = 268_435_455;
+const PINGREQ: u8 = 12;
+const PINGRESP: u8 = 13;
+const PUBACK: u8 = 4;
+const PUBLISH: u8 = 3;
+const SUBACK: u8 = 9;
+const SUBSCRIBE: u8 = 8;
+const UNSUBACK: u8 = 11;
+const UNSUBSCRIBE: u8 = 10;
+
+// Returns the value and the number of bytes of a variable byte integer or `None` if `bytes` is
+// incomplete.
+fn decode_var_int(bytes: &[u8]) -> crate::Result<Option<(usize, usize)>> {
+  let mut value: usize = 0;
+  let mut shift: u32 = 0;
+  for (idx, byte) in bytes.iter().take(4).enumerate() {
+    value = value.wrapping_add(usize::from(byte & 0b0111_1111) << shift);
+    shift = shift.wrapping_add(7);
+    if byte & 0b1000_0000 == 0 {
+      return Ok(Some((value, idx.wrapping_add(1))));
+    }
+  }
+  if bytes.len() >= 4 {
+    return Err(invalid_packet());
+  }
+  Ok(None)
+}
+
+// Returns the encoded bytes and the number of used bytes.
+fn encode_var_int(mut value: usize) -> crate::Result<([u8; 4], usize)> {
+  if value > MAX_VAR_INT {
+    return Err(invalid_packet());
+  }
+  let mut rslt = [0; 4];
+  for (idx, byte) in rslt.iter_mut().enumerate() {
+    let [first, ..] = value.to_le_bytes();
+    value >>= 7;
+    if value == 0 {
+      *byte = first & 0b0111_1111;
+      return Ok((rslt, idx.wrapping_add(1)));
+    }
+    *byte = first | 0b1000_0000;
+  }
+  Ok((rslt, 4))
+}
+
+fn invalid_packet() -> crate::Error {
+  ClientApiFrameworkError::InvalidMqttPacket.into()
+}
+
+fn push_bytes(buffer: &mut Vector<u8>, bytes: &[u8]) -> crate::Result<()> {
+  let _ =
+    buffer.extend_from_copyable_slices([&u16::try_from(bytes.len())?.to_be_bytes()[..], bytes])?;
+  Ok(())
+}
+
+// Skips the property section and returns the remaining bytes.
+fn skip_properties(bytes: &[u8]) -> crate::Result<&[u8]> {
+  let (len, var_int_len) = decode_var_int(bytes)?.ok_or_else(invalid_packet)?;
+  bytes.get(var_int_len.wrapping_add(len)..).ok_or_else(invalid_packet)
+}
+
+// The body is composed by the variable header and the payload.
+async fn write_packet<SW>(
+  first_byte: u8,
+  (variable_header, payload): (&[u8], &[u8]),
+  stream: &mut SW,
+) -> crate::Result<()>
+where
+  SW: StreamWriter,
+{
+  let ([a, b, c, d], len) = encode_var_int(variable_header.len().wrapping_add(payload.len()))?;
+  let fixed_header = [first_byte, a, b, c, d];
+  let fixed_header_slice = fixed_header.get(..len.wrapping_add(1)).unwrap_or_default();
+  stream.write_all_vectored(&[fixed_header_slice, variable_header, payload]).await
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::client_api_framework::network::transport::mqtt::{decode_var_int, encode_var_int};
+
+  #[test]
+  fn var_int() {
+    for (value, len) in [(0, 1), (127, 1), (128, 2), (16_383, 2), (16_384, 3), (268_435_455, 4)] {
+      let (bytes, local_len) = encode_var_int(value).unwrap();
+      assert_eq!(local_len, len);
+      assert_eq!(decode_var_int(&bytes[..len]).unwrap(), Some((value, len)));
+      assert_eq!(decode_var_int(&bytes[..len - 1]).unwrap(), None);
+    }
+    assert!(encode_var_int(268_435_456).is_err());
+    assert!(decode_var_int(&[255, 255, 255, 255]).is_err());
+  }
+}
@@ -0,0 +1,448 @@
+use crate::{
+  client_api_framework::{
+    Api, ClientApiFrameworkError, SendBytesSource,
+    misc::{
+      _log_res, manage_after_receiving, manage_after_sending_bytes, manage_after_sending_pkg,
+      manage_before_sending_bytes, manage_before_sending_pkg,
+    },
+    network::{
+      MqttParams, MqttQos, MqttReqParams, MqttResParams, TransportGroup,
+      transport::{
+        MqttConfig, ReceivingTransport, SendingTransport, Transport, TransportParams,
+        mqtt::{
+          CONNACK, CONNECT, DISCONNECT, PINGREQ, PINGRESP, PUBACK, PUBLISH, SUBACK, SUBSCRIBE,
+          UNSUBACK, UNSUBSCRIBE, decode_var_int, invalid_packet, push_bytes, skip_properties,
+          write_packet,
+        },
+      },
+    },
+    pkg::{Package, PkgsAux},
+  },
+  misc::{BufferMode, LeaseMut, Stream, Vector, from_utf8_basic},
+};
+
+const MAXIMUM_PACKET_SIZE: u8 = 0x27;
+const READ_LEN: usize = 1024;
+const SERVER_KEEP_ALIVE: u8 = 0x13;
+
+/// MQTT 5 client that publishes requests and receives messages of subscribed topics.
+///
+/// Messages are published to the topic and with the delivery guarantee specified in
+/// [`MqttReqParams`]. Received messages fill [`MqttResParams`] and acknowledgements of the
+/// broker are verified while waiting for messages.
+#[derive(Debug)]
+pub struct MqttClient<S> {
+  buffer: Vector<u8>,
+  consumed: usize,
+  keep_alive: u16,
+  max_packet_size: u32,
+  packet_id: u16,
+  stream: S,
+  wbuffer: Vector<u8>,
+}
+
+impl<S> MqttClient<S>
+where
+  S: Stream,
+{
+  /// Sends a `CONNECT` packet and waits for the `CONNACK` packet of the broker.
+  #[inline]
+  pub async fn connect(config: &MqttConfig<'_>, stream: S) -> crate::Result<Self> {
+    let MqttConfig { clean_start, client_id, keep_alive, max_packet_size, password, username } =
+      *config;
+    let mut this = Self {
+      buffer: Vector::new(),
+      consumed: 0,
+      keep_alive,
+      max_packet_size,
+      packet_id: 0,
+      stream,
+      wbuffer: Vector::new(),
+    };
+    let mut flags = 0;
+    if clean_start {
+      flags |= 0b0000_0010;
+    }
+    if password.is_some() {
+      flags |= 0b0100_0000;
+    }
+    if username.is_some() {
+      flags |= 0b1000_0000;
+    }
+    push_bytes(&mut this.wbuffer, b"MQTT")?;
+    let _ = this.wbuffer.extend_from_copyable_slices([
+      &[5, flags][..],
+      &keep_alive.to_be_bytes(),
+      &[5, MAXIMUM_PACKET_SIZE],
+      &max_packet_size.to_be_bytes(),
+    ])?;
+    push_bytes(&mut this.wbuffer, client_id.as_bytes())?;
+    if let Some(elem) = username {
+      push_bytes(&mut this.wbuffer, elem.as_bytes())?;
+    }
+    if let Some(elem) = password {
+      push_bytes(&mut this.wbuffer, elem)?;
+    }
+    write_packet(CONNECT << 4, (&this.wbuffer, &[]), &mut this.stream).await?;
+    let (first_byte, begin, end) = this.read_packet().await?;
+    let body = this.buffer.get(begin..end).unwrap_or_default();
+    let ([_, reason_code, properties @ ..], CONNACK) = (body, first_byte >> 4) else {
+      return Err(invalid_packet());
+    };
+    check_reason_code(*reason_code)?;
+    if let Some(elem) = server_keep_alive(properties)? {
+      this.keep_alive = elem;
+    }
+    Ok(this)
+  }
+
+  /// Sends a `DISCONNECT` packet that gracefully closes the connection.
+  #[inline]
+  pub async fn disconnect(&mut self) -> crate::Result<()> {
+    write_packet(DISCONNECT << 4, (&[], &[]), &mut self.stream).await
+  }
+
+  /// Maximum interval, in seconds, between two sent packets, which may have been overridden by the
+  /// broker.
+  #[inline]
+  pub const fn keep_alive(&self) -> u16 {
+    self.keep_alive
+  }
+
+  /// Sends a `PINGREQ` packet, which should be periodically called when no other packets are sent
+  /// within the [`Self::keep_alive`] interval.
+  #[inline]
+  pub async fn ping(&mut self) -> crate::Result<()> {
+    write_packet(PINGREQ << 4, (&[], &[]), &mut self.stream).await
+  }
+
+  /// Publishes `payload` according to `params`.
+  #[inline]
+  pub async fn publish(&mut self, payload: &[u8], params: &MqttReqParams) -> crate::Result<()> {
+    let MqttReqParams { qos, retain, topic } = params;
+    let mut first_byte = (PUBLISH << 4) | (u8::from(*qos) << 1);
+    if *retain {
+      first_byte |= 0b0000_0001;
+    }
+    self.wbuffer.clear();
+    push_bytes(&mut self.wbuffer, topic.as_bytes())?;
+    if let MqttQos::AtLeastOnce = qos {
+      let packet_id = self.next_packet_id();
+      self.wbuffer.extend_from_copyable_slice(&packet_id.to_be_bytes())?;
+    }
+    self.wbuffer.push(0)?;
+    write_packet(first_byte, (&self.wbuffer, payload), &mut self.stream).await
+  }
+
+  /// Subscribes to a set of topic filters with their maximum delivery guarantees.
+  #[inline]
+  pub async fn subscribe(&mut self, topic_filters: &[(&str, MqttQos)]) -> crate::Result<()> {
+    let packet_id = self.next_packet_id();
+    self.wbuffer.clear();
+    let _ = self.wbuffer.extend_from_copyable_slices([&packet_id.to_be_bytes()[..], &[0]])?;
+    for (topic_filter, qos) in topic_filters {
+      push_bytes(&mut self.wbuffer, topic_filter.as_bytes())?;
+      self.wbuffer.push((*qos).into())?;
+    }
+    write_packet((SUBSCRIBE << 4) | 0b0000_0010, (&self.wbuffer, &[]), &mut self.stream).await
+  }
+
+  /// Unsubscribes from a set of topic filters.
+  #[inline]
+  pub async fn unsubscribe(&mut self, topic_filters: &[&str]) -> crate::Result<()> {
+    let packet_id = self.next_packet_id();
+    self.wbuffer.clear();
+    let _ = self.wbuffer.extend_from_copyable_slices([&packet_id.to_be_bytes()[..], &[0]])?;
+    for topic_filter in topic_filters {
+      push_bytes(&mut self.wbuffer, topic_filter.as_bytes())?;
+    }
+    write_packet((UNSUBSCRIBE << 4) | 0b0000_0010, (&self.wbuffer, &[]), &mut self.stream).await
+  }
+
+  fn next_packet_id(&mut self) -> u16 {
+    self.packet_id = self.packet_id.wrapping_add(1).max(1);
+    self.packet_id
+  }
+
+  // Returns the first byte as well as the beginning and the ending indices of the body of the next
+  // packet.
+  async fn read_packet(&mut self) -> crate::Result<(u8, usize, usize)> {
+    let _ = self.buffer.drain(..self.consumed);
+    self.consumed = 0;
+    let max_packet_size = usize::try_from(self.max_packet_size)?;
+    loop {
+      if let [first_byte, rest @ ..] = self.buffer.as_slice() {
+        if let Some((len, var_int_len)) = decode_var_int(rest)? {
+          let begin = var_int_len.wrapping_add(1);
+          let end = begin.wrapping_add(len);
+          if end > max_packet_size {
+            return Err(invalid_packet());
+          }
+          if self.buffer.len() >= end {
+            self.consumed = end;
+            return Ok((*first_byte, begin, end));
+          }
+        }
+      }
+      let len = self.buffer.len();
+      self.buffer.expand(BufferMode::Additional(READ_LEN), 0)?;
+      let rslt = self.stream.read(self.buffer.get_mut(len..).unwrap_or_default()).await;
+      self.buffer.truncate(len.wrapping_add(*rslt.as_ref().unwrap_or(&0)));
+      if rslt? == 0 {
+        return Err(crate::Error::ClosedConnection);
+      }
+    }
+  }
+
+  // Processes incoming packets until a message is received.
+  async fn recv_publish(
+    &mut self,
+    bytes: &mut Vector<u8>,
+    res_params: &mut MqttResParams,
+  ) -> crate::Result<()> {
+    loop {
+      let (first_byte, begin, end) = self.read_packet().await?;
+      let body = self.buffer.get(begin..end).unwrap_or_default();
+      match first_byte >> 4 {
+        PUBLISH => {
+          let qos = MqttQos::try_from((first_byte >> 1) & 0b0000_0011)?;
+          let [a, b, rest @ ..] = body else {
+            return Err(invalid_packet());
+          };
+          let (topic, rest) = rest
+            .split_at_checked(usize::from(u16::from_be_bytes([*a, *b])))
+            .ok_or_else(invalid_packet)?;
+          let (packet_id, rest) = match qos {
+            MqttQos::AtMostOnce => (None, rest),
+            MqttQos::AtLeastOnce => {
+              let [c, d, local_rest @ ..] = rest else {
+                return Err(invalid_packet());
+              };
+              (Some([*c, *d]), local_rest)
+            }
+          };
+          res_params.qos = qos;
+          res_params.topic.clear();
+          res_params.topic.push_str(from_utf8_basic(topic)?);
+          bytes.extend_from_copyable_slice(skip_properties(rest)?)?;
+          if let Some(elem) = packet_id {
+            write_packet(PUBACK << 4, (&elem, &[]), &mut self.stream).await?;
+          }
+          return Ok(());
+        }
+        PUBACK => {
+          if let [_, _, reason_code, ..] = body {
+            check_reason_code(*reason_code)?;
+          }
+        }
+        SUBACK | UNSUBACK => {
+          let [_, _, rest @ ..] = body else {
+            return Err(invalid_packet());
+          };
+          for reason_code in skip_properties(rest)? {
+            check_reason_code(*reason_code)?;
+          }
+        }
+        PINGRESP => {}
+        DISCONNECT => return Err(ClientApiFrameworkError::ClosedMqttConnection.into()),
+        _ => return Err(invalid_packet()),
+      }
+    }
+  }
+}
+
+impl<S, TP> ReceivingTransport<TP> for MqttClient<S>
+where
+  S: Stream,
+  TP: LeaseMut<MqttParams> + TransportParams,
+{
+  #[inline]
+  async fn recv<A, DRSR>(
+    &mut self,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    _: Self::ReqId,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    pkgs_aux.byte_buffer.clear();
+    self
+      .recv_publish(&mut pkgs_aux.byte_buffer, pkgs_aux.tp.lease_mut().ext_res_params_mut())
+      .await?;
+    manage_after_receiving(pkgs_aux).await?;
+    _log_res(pkgs_aux.log_body.1, &pkgs_aux.byte_buffer, TransportGroup::MQTT);
+    Ok(())
+  }
+}
+
+impl<S, TP> SendingTransport<TP> for MqttClient<S>
+where
+  S: Stream,
+  TP: LeaseMut<MqttParams> + TransportParams,
+{
+  #[inline]
+  async fn send_bytes<A, DRSR>(
+    &mut self,
+    bytes: SendBytesSource<'_>,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    manage_before_sending_bytes(bytes, pkgs_aux, self).await?;
+    self
+      .publish(bytes.bytes(&pkgs_aux.byte_buffer), pkgs_aux.tp.lease_mut().ext_req_params_mut())
+      .await?;
+    manage_after_sending_bytes(pkgs_aux).await?;
+    Ok(())
+  }
+
+  #[inline]
+  async fn send_pkg<A, DRSR, P>(
+    &mut self,
+    pkg: &mut P,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+    P: Package<A, DRSR, Self::Inner, TP>,
+  {
+    manage_before_sending_pkg(pkg, pkgs_aux, self).await?;
+    self.publish(&pkgs_aux.byte_buffer, pkgs_aux.tp.lease_mut().ext_req_params_mut()).await?;
+    manage_after_sending_pkg(pkg, pkgs_aux, self).await
+  }
+}
+
+impl<S, TP> Transport<TP> for MqttClient<S> {
+  const GROUP: TransportGroup = TransportGroup::MQTT;
+  type Inner = Self;
+  type ReqId = ();
+}
+
+fn check_reason_code(reason_code: u8) -> crate::Result<()> {
+  if reason_code >= 0x80 {
+    return Err(ClientApiFrameworkError::MqttFailureReasonCode(reason_code).into());
+  }
+  Ok(())
+}
+
+// Length of a value prefixed by two bytes.
+fn prefixed_len(bytes: &[u8]) -> crate::Result<usize> {
+  let [a, b, ..] = bytes else {
+    return Err(invalid_packet());
+  };
+  Ok(usize::from(u16::from_be_bytes([*a, *b])).wrapping_add(2))
+}
+
+fn server_keep_alive(bytes: &[u8]) -> crate::Result<Option<u16>> {
+  let (len, var_int_len) = decode_var_int(bytes)?.ok_or_else(invalid_packet)?;
+  let mut properties =
+    bytes.get(var_int_len..var_int_len.wrapping_add(len)).ok_or_else(invalid_packet)?;
+  let mut rslt = None;
+  while let [id, rest @ ..] = properties {
+    let value_len = match *id {
+      0x01 | 0x17 | 0x19 | 0x24 | 0x25 | 0x28 | 0x29 | 0x2A => 1,
+      0x13 | 0x21 | 0x22 | 0x23 => 2,
+      0x02 | 0x11 | 0x18 | 0x27 => 4,
+      0x0B => decode_var_int(rest)?.ok_or_else(invalid_packet)?.1,
+      0x03 | 0x08 | 0x09 | 0x12 | 0x15 | 0x16 | 0x1A | 0x1C | 0x1F => prefixed_len(rest)?,
+      0x26 => {
+        let name_len = prefixed_len(rest)?;
+        name_len.wrapping_add(prefixed_len(rest.get(name_len..).unwrap_or_default())?)
+      }
+      _ => return Err(invalid_packet()),
+    };
+    let (value, local_rest) = rest.split_at_checked(value_len).ok_or_else(invalid_packet)?;
+    if let ([a, b], SERVER_KEEP_ALIVE) = (value, *id) {
+      rslt = Some(u16::from_be_bytes([*a, *b]));
+    }
+    properties = local_rest;
+  }
+  Ok(rslt)
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      SendBytesSource,
+      network::{
+        MqttParams, MqttQos,
+        transport::{
+          MqttClient, MqttConfig, ReceivingTransport, SendingTransport, TransportParams,
+        },
+      },
+      pkg::PkgsAux,
+    },
+    misc::{StreamReader, StreamWriter, Vector},
+  };
+
+  struct MockStream {
+    read: &'static [u8],
+    written: Vector<u8>,
+  }
+
+  impl StreamReader for MockStream {
+    async fn read(&mut self, bytes: &mut [u8]) -> crate::Result<usize> {
+      let len = bytes.len().min(self.read.len());
+      let (lhs, rhs) = self.read.split_at(len);
+      bytes.get_mut(..len).unwrap().copy_from_slice(lhs);
+      self.read = rhs;
+      Ok(len)
+    }
+  }
+
+  impl StreamWriter for MockStream {
+    async fn write_all(&mut self, bytes: &[u8]) -> crate::Result<()> {
+      self.written.extend_from_copyable_slice(bytes)
+    }
+
+    async fn write_all_vectored(&mut self, bytes: &[&[u8]]) -> crate::Result<()> {
+      for elem in bytes {
+        self.written.extend_from_copyable_slice(elem)?;
+      }
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn connect_with_failure_reason_code() {
+    let mut stream = MockStream { read: &[32, 3, 0, 134, 0], written: Vector::new() };
+    assert!(MqttClient::connect(&MqttConfig::new("id"), &mut stream).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn publish_and_receive() {
+    let mut stream = MockStream {
+      read: &[
+        32, 6, 0, 0, 3, 19, 0, 10, // CONNACK
+        144, 4, 0, 1, 0, 1, // SUBACK
+        64, 2, 0, 2, // PUBACK
+        50, 12, 0, 3, b'a', b'/', b'b', 0, 7, 0, b'p', b'o', b'n', b'g', // PUBLISH
+      ],
+      written: Vector::new(),
+    };
+    let config = MqttConfig::new("id").max_packet_size(1024);
+    let mut trans = MqttClient::connect(&config, &mut stream).await.unwrap();
+    assert_eq!(trans.keep_alive(), 10);
+    trans.subscribe(&[("a/b", MqttQos::AtLeastOnce)]).await.unwrap();
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), MqttParams::from_topic("a/b".into()));
+    pkgs_aux.tp.ext_req_params_mut().qos = MqttQos::AtLeastOnce;
+    trans.send_bytes(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.unwrap();
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"pong");
+    assert_eq!(pkgs_aux.tp.ext_res_params().qos, MqttQos::AtLeastOnce);
+    assert_eq!(pkgs_aux.tp.ext_res_params().topic, "a/b");
+    drop(trans);
+    assert_eq!(
+      stream.written.as_slice(),
+      &[
+        16, 20, 0, 4, b'M', b'Q', b'T', b'T', 5, 2, 0, 60, 5, 39, 0, 0, 4, 0, 0, 2, b'i',
+        b'd', // CONNECT
+        130, 9, 0, 1, 0, 0, 3, b'a', b'/', b'b', 1, // SUBSCRIBE
+        50, 12, 0, 3, b'a', b'/', b'b', 0, 2, 0, b'p', b'i', b'n', b'g', // PUBLISH
+        64, 2, 0, 7, // PUBACK
+      ]
+    );
+  }
+}
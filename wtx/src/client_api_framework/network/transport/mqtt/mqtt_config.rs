@@ -0,0 +1,71 @@
+/// Parameters sent in the `CONNECT` packet.
+#[derive(Clone, Copy, Debug)]
+pub struct MqttConfig<'any> {
+  pub(crate) clean_start: bool,
+  pub(crate) client_id: &'any str,
+  pub(crate) keep_alive: u16,
+  pub(crate) max_packet_size: u32,
+  pub(crate) password: Option<&'any [u8]>,
+  pub(crate) username: Option<&'any str>,
+}
+
+impl<'any> MqttConfig<'any> {
+  /// An empty `client_id` lets the broker assign an identifier.
+  #[inline]
+  pub const fn new(client_id: &'any str) -> Self {
+    Self {
+      clean_start: true,
+      client_id,
+      keep_alive: 60,
+      max_packet_size: 16 * 1024 * 1024,
+      password: None,
+      username: None,
+    }
+  }
+
+  /// If `false`, the broker resumes the existing session of the client identifier.
+  ///
+  /// Defaults to `true`.
+  #[inline]
+  #[must_use]
+  pub const fn clean_start(mut self, elem: bool) -> Self {
+    self.clean_start = elem;
+    self
+  }
+
+  /// Maximum interval, in seconds, between two sent packets. Zero disables the mechanism.
+  ///
+  /// Defaults to 60.
+  #[inline]
+  #[must_use]
+  pub const fn keep_alive(mut self, elem: u16) -> Self {
+    self.keep_alive = elem;
+    self
+  }
+
+  /// The broker won't send packets that are larger than `elem` bytes.
+  ///
+  /// Defaults to 16 MiB.
+  #[inline]
+  #[must_use]
+  pub const fn max_packet_size(mut self, elem: u32) -> Self {
+    self.max_packet_size = elem;
+    self
+  }
+
+  /// Password used for authentication.
+  #[inline]
+  #[must_use]
+  pub const fn password(mut self, elem: Option<&'any [u8]>) -> Self {
+    self.password = elem;
+    self
+  }
+
+  /// User name used for authentication.
+  #[inline]
+  #[must_use]
+  pub const fn username(mut self, elem: Option<&'any str>) -> Self {
+    self.username = elem;
+    self
+  }
+}
@@ -1,11 +1,21 @@
 use crate::{
   client_api_framework::{
     Api, SendBytesSource,
-    network::transport::{ReceivingTransport, SendingTransport},
-    pkg::{BatchElems, BatchPkg, Package, PkgsAux},
+    misc::{
+      ApiRateLimiter, ApiRateLimiterPermit, ExchangeErrorKind, ExchangeMetrics, deadline_timeout,
+      with_timeout,
+    },
+    network::{
+      TransportGroup,
+      transport::{ReceivingTransport, SendingTransport, Transport},
+    },
+    pkg::{BatchElems, BatchJoiner, BatchPkg, Package, PkgsAux, demux_batch_responses},
   },
-  data_transformation::dnsn::{De, DecodeWrapper},
-  misc::{Decode, DecodeSeq, Encode, Vector},
+  data_transformation::{
+    Id,
+    dnsn::{De, DecodeWrapper},
+  },
+  misc::{Decode, DecodeSeq, Encode, GenericTime, Lease, Vector, Xorshift64, simple_seed, sleep},
 };
 
 /// Transport that sends and receives package data
@@ -27,8 +37,18 @@ pub trait SendingReceivingTransport<TP>: ReceivingTransport<TP> + SendingTranspo
     A: Api,
   {
     async move {
-      let req_id = self.send_bytes(bytes, pkgs_aux).await?;
-      self.recv(pkgs_aux, req_id).await
+      let _permit = acquire_permit(pkgs_aux).await;
+      let begin = pkgs_aux.api.transport_observer().map(|_| GenericTime::now());
+      let timeout = deadline_timeout(pkgs_aux.deadline)?;
+      let mut was_sent = false;
+      let exchange = async {
+        let req_id = self.send_bytes(bytes, pkgs_aux).await?;
+        was_sent = true;
+        self.recv(pkgs_aux, req_id).await
+      };
+      let rslt = with_timeout(exchange, timeout).await.map_err(|err| (err, was_sent));
+      observe_exchange(begin, pkgs_aux, &rslt, self.ty());
+      rslt.map_err(|el| el.0)
     }
   }
 
@@ -45,10 +65,7 @@ pub trait SendingReceivingTransport<TP>: ReceivingTransport<TP> + SendingTranspo
     A: Api,
     P: Package<A, DRSR, Self::Inner, TP>,
   {
-    async {
-      let req_id = self.send_pkg(pkg, pkgs_aux).await?;
-      self.recv(pkgs_aux, req_id).await
-    }
+    async move { send_pkg_recv(self, pkg, pkgs_aux).await.map_err(|el| el.0) }
   }
 
   /// Convenient method similar to [`Self::send_pkg_recv_decode_contained`] but used for batch
@@ -78,8 +95,74 @@ pub trait SendingReceivingTransport<TP>: ReceivingTransport<TP> + SendingTranspo
     }
   }
 
+  /// Similar to [`Self::send_pkg_recv_decode_batch`] but the received responses, which can arrive
+  /// in any order, are matched by id. Afterwards, the response of the N-th package is the N-th
+  /// newly pushed element of `buffer`.
+  ///
+  /// Useful for JSON-RPC batches.
+  #[inline]
+  fn send_pkg_recv_decode_batch_by_id<'pkgs_aux, A, DRSR, P>(
+    &mut self,
+    buffer: &mut Vector<P::ExternalResponseContent<'pkgs_aux>>,
+    pkgs: &mut [P],
+    pkgs_aux: &'pkgs_aux mut PkgsAux<A, DRSR, TP>,
+  ) -> impl Future<Output = Result<(), A::Error>>
+  where
+    A: Api,
+    P: Package<A, DRSR, Self::Inner, TP>,
+    P::ExternalRequestContent: Lease<Id>,
+    P::ExternalResponseContent<'pkgs_aux>: Lease<Id>,
+    for<'pkgs> BatchElems<'pkgs, A, DRSR, P, Self::Inner, TP>: Encode<De<DRSR>>,
+  {
+    async {
+      let start = buffer.len();
+      self.send_pkg_recv(&mut BatchPkg::new(&mut *pkgs), pkgs_aux).await?;
+      P::ExternalResponseContent::decode_seq(
+        &mut pkgs_aux.drsr,
+        buffer,
+        &mut DecodeWrapper::new(&pkgs_aux.byte_buffer),
+      )?;
+      demux_batch_responses(
+        pkgs.iter().map(|el| *el.ext_req_content().lease()),
+        buffer.get_mut(start..).unwrap_or_default(),
+      )?;
+      Ok(())
+    }
+  }
+
+  /// Similar to [`Self::send_pkg_recv_decode_batch`] but requests are joined according to `joiner`
+  /// and the received response is split in the same manner. Each part is decoded individually.
+  #[inline]
+  fn send_pkg_recv_decode_joined_batch<'pkgs, 'pkgs_aux, A, DRSR, P>(
+    &mut self,
+    buffer: &mut Vector<P::ExternalResponseContent<'pkgs_aux>>,
+    joiner: BatchJoiner,
+    pkgs: &'pkgs mut [P],
+    pkgs_aux: &'pkgs_aux mut PkgsAux<A, DRSR, TP>,
+  ) -> impl Future<Output = Result<(), A::Error>>
+  where
+    A: Api,
+    P: Package<A, DRSR, Self::Inner, TP>,
+    BatchElems<'pkgs, A, DRSR, P, Self::Inner, TP>: Encode<De<DRSR>>,
+  {
+    async move {
+      self.send_pkg_recv(&mut BatchPkg::with_joiner(pkgs, joiner), pkgs_aux).await?;
+      for part in joiner.split(&pkgs_aux.byte_buffer)? {
+        buffer.push(P::ExternalResponseContent::decode(
+          &mut pkgs_aux.drsr,
+          &mut DecodeWrapper::new(part),
+        )?)?;
+      }
+      Ok(())
+    }
+  }
+
   /// Internally calls [`Self::send_pkg_recv`] and then tries to decode the defined response specified
   /// in [`Package::ExternalResponseContent`].
+  ///
+  /// Transient errors are retried according to [`Package::retry_policy`] or
+  /// [`PkgsAux::retry_policy`], as long as [`PkgsAux::retry_budget`] allows it. Non-idempotent
+  /// packages are only retried when the request wasn't sent.
   #[inline]
   fn send_pkg_recv_decode_contained<'de, A, DRSR, P>(
     &mut self,
@@ -90,8 +173,28 @@ pub trait SendingReceivingTransport<TP>: ReceivingTransport<TP> + SendingTranspo
     A: Api,
     P: Package<A, DRSR, Self::Inner, TP>,
   {
-    async {
-      self.send_pkg_recv(pkg, pkgs_aux).await?;
+    async move {
+      let policy = pkg.retry_policy().or(pkgs_aux.retry_policy);
+      let mut attempt: u8 = 0;
+      let mut rng = Xorshift64::from(simple_seed());
+      loop {
+        attempt = attempt.wrapping_add(1);
+        let Err((err, was_sent)) = send_pkg_recv(self, pkg, pkgs_aux).await else {
+          if let Some(elem) = &mut pkgs_aux.retry_budget {
+            elem.deposit();
+          }
+          break;
+        };
+        let delay = policy.and_then(|el| el.next_delay(attempt, &mut rng));
+        let Some(elem) = delay.filter(|_| {
+          pkgs_aux.api.is_transient_error(&err)
+            && (pkg.is_idempotent() || !was_sent)
+            && pkgs_aux.retry_budget.as_mut().is_none_or(|el| el.withdraw())
+        }) else {
+          return Err(err);
+        };
+        sleep(elem).await?;
+      }
       Ok(P::ExternalResponseContent::decode(
         &mut pkgs_aux.drsr,
         &mut DecodeWrapper::new(&pkgs_aux.byte_buffer),
@@ -100,6 +203,76 @@ pub trait SendingReceivingTransport<TP>: ReceivingTransport<TP> + SendingTranspo
   }
 }
 
+async fn acquire_permit<A, DRSR, TP>(
+  pkgs_aux: &PkgsAux<A, DRSR, TP>,
+) -> Option<ApiRateLimiterPermit>
+where
+  A: Api,
+{
+  Some(ApiRateLimiter::acquire(pkgs_aux.api.rate_limiter()?.clone()).await)
+}
+
+// The boolean of the error indicates whether the request was sent.
+async fn send_pkg_recv<A, DRSR, P, T, TP>(
+  trans: &mut T,
+  pkg: &mut P,
+  pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+) -> Result<(), (A::Error, bool)>
+where
+  A: Api,
+  P: Package<A, DRSR, T::Inner, TP>,
+  T: ReceivingTransport<TP> + SendingTransport<TP>,
+{
+  let _permit = acquire_permit(pkgs_aux).await;
+  let begin = pkgs_aux.api.transport_observer().map(|_| GenericTime::now());
+  let timeout = deadline_timeout(pkg.deadline().or(pkgs_aux.deadline))
+    .map_err(|err| (A::Error::from(err), false))?;
+  let mut was_sent = false;
+  let exchange = async {
+    let req_id = trans.send_pkg(pkg, pkgs_aux).await?;
+    was_sent = true;
+    trans.recv(pkgs_aux, req_id).await
+  };
+  let rslt = with_timeout(exchange, timeout).await.map_err(|err| (err, was_sent));
+  observe_exchange(begin, pkgs_aux, &rslt, trans.ty());
+  rslt
+}
+
+// The boolean of the error indicates whether the request was sent.
+fn observe_exchange<A, DRSR, TP>(
+  begin: Option<GenericTime>,
+  pkgs_aux: &PkgsAux<A, DRSR, TP>,
+  rslt: &Result<(), (A::Error, bool)>,
+  transport_group: TransportGroup,
+) where
+  A: Api,
+{
+  let (Some(observer), Some(elem)) = (pkgs_aux.api.transport_observer(), begin) else {
+    return;
+  };
+  let (bytes_in, error) = match rslt {
+    Ok(()) => (pkgs_aux.byte_buffer.len(), None),
+    Err((err, was_sent)) => {
+      let is_transient = pkgs_aux.api.is_transient_error(err);
+      let kind = if *was_sent {
+        ExchangeErrorKind::Receiving { is_transient }
+      } else {
+        ExchangeErrorKind::Sending { is_transient }
+      };
+      (0, Some(kind))
+    }
+  };
+  let (bytes_out, serialize_time) = pkgs_aux.exchange;
+  observer.on_exchange(&ExchangeMetrics {
+    bytes_in,
+    bytes_out,
+    error,
+    serialize_time,
+    transport_group,
+    wire_latency: elem.elapsed().unwrap_or_default().saturating_sub(serialize_time),
+  });
+}
+
 impl<T, TP> SendingReceivingTransport<TP> for T where
   T: ReceivingTransport<TP> + SendingTransport<TP>
 {
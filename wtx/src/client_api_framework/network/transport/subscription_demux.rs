@@ -0,0 +1,201 @@
+use crate::{
+  client_api_framework::{
+    Api, ClientApiFrameworkError,
+    network::transport::{ReceivingTransport, SendingTransport},
+    pkg::{Package, PkgsAux},
+  },
+  data_transformation::Id,
+  misc::{Deque, Lease, Vector},
+};
+
+/// Destination of a received message, as determined by the router of a [`SubscriptionDemux`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DemuxRoute<K> {
+  /// Message that isn't related to any request or subscription. Discarded.
+  Ignored,
+  /// Response of the request with the contained id.
+  Response(Id),
+  /// Message pushed by the server to the subscription identified by the contained key.
+  Subscription(K),
+}
+
+/// Routes the messages of a single connection, usually a WebSocket, to per-subscription consumers
+/// as well as to the requests that are waiting for their responses.
+///
+/// Messages that arrive while awaiting something else are buffered until they are requested.
+/// Messages of subscriptions that weren't registered through [`Self::subscribe`] are discarded.
+#[derive(Debug)]
+pub struct SubscriptionDemux<K> {
+  responses: Vector<(Id, Vector<u8>)>,
+  router: fn(&[u8]) -> crate::Result<DemuxRoute<K>>,
+  subscriptions: Vector<(K, Deque<Vector<u8>>)>,
+}
+
+impl<K> SubscriptionDemux<K>
+where
+  K: PartialEq,
+{
+  /// `router` determines the destination of every received message.
+  #[inline]
+  pub const fn new(router: fn(&[u8]) -> crate::Result<DemuxRoute<K>>) -> Self {
+    Self { responses: Vector::new(), router, subscriptions: Vector::new() }
+  }
+
+  /// Number of buffered messages of the subscription identified by `key`.
+  #[inline]
+  pub fn buffered(&self, key: &K) -> usize {
+    self.subscriptions.iter().find(|el| &el.0 == key).map_or(0, |el| el.1.len())
+  }
+
+  /// Receives the response of the request identified by `id`.
+  #[inline]
+  pub async fn recv_response<A, DRSR, T, TP>(
+    &mut self,
+    id: Id,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    trans: &mut T,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+    T: ReceivingTransport<TP, ReqId = ()>,
+  {
+    if let Some(idx) = self.responses.iter().position(|el| el.0 == id) {
+      if let Some((_, bytes)) = self.responses.remove(idx) {
+        pkgs_aux.byte_buffer.clear();
+        pkgs_aux.byte_buffer.extend_from_copyable_slice(&bytes)?;
+        return Ok(());
+      }
+    }
+    loop {
+      trans.recv(pkgs_aux, ()).await?;
+      if self.route(&pkgs_aux.byte_buffer, Some(id))? {
+        return Ok(());
+      }
+    }
+  }
+
+  /// Receives the next message of the subscription identified by `key`.
+  #[inline]
+  pub async fn recv_subscription<A, DRSR, T, TP>(
+    &mut self,
+    key: &K,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    trans: &mut T,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+    T: ReceivingTransport<TP, ReqId = ()>,
+  {
+    let Some(idx) = self.subscriptions.iter().position(|el| &el.0 == key) else {
+      return Err(crate::Error::from(ClientApiFrameworkError::UnknownSubscription).into());
+    };
+    loop {
+      if let Some(bytes) = self.subscriptions.get_mut(idx).and_then(|el| el.1.pop_front()) {
+        pkgs_aux.byte_buffer.clear();
+        pkgs_aux.byte_buffer.extend_from_copyable_slice(&bytes)?;
+        return Ok(());
+      }
+      trans.recv(pkgs_aux, ()).await?;
+      let _ = self.route(&pkgs_aux.byte_buffer, None)?;
+    }
+  }
+
+  /// Sends `pkg` and then receives the response with the same id of its request.
+  #[inline]
+  pub async fn send_pkg_recv<A, DRSR, P, T, TP>(
+    &mut self,
+    pkg: &mut P,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    trans: &mut T,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+    P: Package<A, DRSR, T::Inner, TP>,
+    P::ExternalRequestContent: Lease<Id>,
+    T: ReceivingTransport<TP, ReqId = ()> + SendingTransport<TP>,
+  {
+    let id = *pkg.ext_req_content().lease();
+    trans.send_pkg(pkg, pkgs_aux).await?;
+    self.recv_response(id, pkgs_aux, trans).await
+  }
+
+  /// Starts buffering the messages of the subscription identified by `key`.
+  #[inline]
+  pub fn subscribe(&mut self, key: K) -> crate::Result<()> {
+    if self.subscriptions.iter().all(|el| el.0 != key) {
+      self.subscriptions.push((key, Deque::new()))?;
+    }
+    Ok(())
+  }
+
+  /// Stops buffering the messages of the subscription identified by `key`. Already buffered
+  /// messages are discarded.
+  #[inline]
+  pub fn unsubscribe(&mut self, key: &K) {
+    self.subscriptions.retain(|el| &el.0 != key);
+  }
+
+  // Buffers `bytes` according to its route, unless it is the response of `awaited`. Returns
+  // `true` in such a case.
+  fn route(&mut self, bytes: &[u8], awaited: Option<Id>) -> crate::Result<bool> {
+    match (self.router)(bytes)? {
+      DemuxRoute::Ignored => {}
+      DemuxRoute::Response(id) => {
+        if awaited == Some(id) {
+          return Ok(true);
+        }
+        let mut vector = Vector::new();
+        vector.extend_from_copyable_slice(bytes)?;
+        self.responses.push((id, vector))?;
+      }
+      DemuxRoute::Subscription(key) => {
+        if let Some((_, queue)) = self.subscriptions.iter_mut().find(|el| el.0 == key) {
+          let mut vector = Vector::new();
+          vector.extend_from_copyable_slice(bytes)?;
+          queue.push_back(vector)?;
+        }
+      }
+    }
+    Ok(false)
+  }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::client_api_framework::{
+    SendBytesSource,
+    network::transport::{DemuxRoute, MockStr, SendingTransport, SubscriptionDemux},
+    pkg::PkgsAux,
+  };
+  use alloc::borrow::Cow;
+
+  fn router(bytes: &[u8]) -> crate::Result<DemuxRoute<u8>> {
+    Ok(match bytes {
+      [b'r', id, ..] => DemuxRoute::Response(usize::from(*id)),
+      [b's', key, ..] => DemuxRoute::Subscription(*key),
+      _ => DemuxRoute::Ignored,
+    })
+  }
+
+  #[tokio::test]
+  async fn routes_messages() {
+    let mut demux = SubscriptionDemux::new(router);
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), ());
+    let mut trans = MockStr::default();
+    trans.push_responses(["sa1", "x", "sb1", "r\x02", "sa2", "r\x01"].map(Cow::Borrowed));
+    demux.subscribe(b'a').unwrap();
+    trans.send_bytes(SendBytesSource::Param(b"req"), &mut pkgs_aux).await.unwrap();
+    demux.recv_response(1, &mut pkgs_aux, &mut trans).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"r\x01");
+    assert_eq!(demux.buffered(&b'a'), 2);
+    demux.recv_response(2, &mut pkgs_aux, &mut trans).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"r\x02");
+    demux.recv_subscription(&b'a', &mut pkgs_aux, &mut trans).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"sa1");
+    demux.recv_subscription(&b'a', &mut pkgs_aux, &mut trans).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"sa2");
+    assert!(demux.recv_subscription(&b'b', &mut pkgs_aux, &mut trans).await.is_err());
+    demux.unsubscribe(&b'a');
+    assert_eq!(demux.buffered(&b'a'), 0);
+  }
+}
@@ -0,0 +1,195 @@
+use crate::{
+  client_api_framework::{
+    Api, SendBytesSource,
+    misc::{
+      manage_after_sending_bytes, manage_after_sending_pkg, manage_before_sending_bytes,
+      manage_before_sending_pkg,
+    },
+    network::{
+      TransportGroup,
+      transport::{
+        Framing, ReceivingTransport, SendingTransport, Transport, TransportParams,
+        framing::FramedStream,
+      },
+    },
+    pkg::{Package, PkgsAux},
+  },
+  misc::Stream,
+};
+
+/// Plain TCP transport that exchanges messages delimited according to a [`Framing`]. Useful for
+/// proprietary protocols.
+///
+/// Any [`Stream`] can be used, including encrypted ones.
+#[derive(Debug)]
+pub struct TcpTransport<S>(FramedStream<S>);
+
+impl<S> TcpTransport<S> {
+  /// New instance
+  #[inline]
+  pub const fn new(framing: Framing, stream: S) -> Self {
+    Self(FramedStream::new(framing, stream))
+  }
+
+  /// Sent or received messages that are larger than `elem` bytes are rejected.
+  ///
+  /// Defaults to 16 MiB.
+  #[inline]
+  #[must_use]
+  pub fn max_message_len(mut self, elem: usize) -> Self {
+    self.0.set_max_message_len(elem);
+    self
+  }
+
+  /// Underlying stream
+  #[inline]
+  pub fn stream(&self) -> &S {
+    self.0.stream()
+  }
+
+  /// Mutable version of [`Self::stream`].
+  #[inline]
+  pub fn stream_mut(&mut self) -> &mut S {
+    self.0.stream_mut()
+  }
+}
+
+impl<S, TP> ReceivingTransport<TP> for TcpTransport<S>
+where
+  S: Stream,
+  TP: TransportParams,
+{
+  #[inline]
+  async fn recv<A, DRSR>(
+    &mut self,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    _: Self::ReqId,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    self.0.recv(pkgs_aux, TransportGroup::TCP).await?;
+    Ok(())
+  }
+}
+
+impl<S, TP> SendingTransport<TP> for TcpTransport<S>
+where
+  S: Stream,
+  TP: TransportParams,
+{
+  #[inline]
+  async fn send_bytes<A, DRSR>(
+    &mut self,
+    bytes: SendBytesSource<'_>,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    manage_before_sending_bytes(bytes, pkgs_aux, self).await?;
+    self.0.write(bytes.bytes(&pkgs_aux.byte_buffer)).await?;
+    manage_after_sending_bytes(pkgs_aux).await?;
+    Ok(())
+  }
+
+  #[inline]
+  async fn send_pkg<A, DRSR, P>(
+    &mut self,
+    pkg: &mut P,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+    P: Package<A, DRSR, Self::Inner, TP>,
+  {
+    manage_before_sending_pkg(pkg, pkgs_aux, self).await?;
+    self.0.write(&pkgs_aux.byte_buffer).await?;
+    manage_after_sending_pkg(pkg, pkgs_aux, self).await
+  }
+}
+
+impl<S, TP> Transport<TP> for TcpTransport<S> {
+  const GROUP: TransportGroup = TransportGroup::TCP;
+  type Inner = Self;
+  type ReqId = ();
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      SendBytesSource,
+      network::transport::{Framing, ReceivingTransport, SendingTransport, TcpTransport},
+      pkg::PkgsAux,
+    },
+    misc::{StreamReader, StreamWriter, Vector},
+  };
+
+  struct MockStream {
+    read: &'static [u8],
+    written: Vector<u8>,
+  }
+
+  impl StreamReader for MockStream {
+    async fn read(&mut self, bytes: &mut [u8]) -> crate::Result<usize> {
+      let len = bytes.len().min(self.read.len()).min(3);
+      let (lhs, rhs) = self.read.split_at(len);
+      bytes.get_mut(..len).unwrap().copy_from_slice(lhs);
+      self.read = rhs;
+      Ok(len)
+    }
+  }
+
+  impl StreamWriter for MockStream {
+    async fn write_all(&mut self, bytes: &[u8]) -> crate::Result<()> {
+      self.written.extend_from_copyable_slice(bytes)
+    }
+
+    async fn write_all_vectored(&mut self, bytes: &[&[u8]]) -> crate::Result<()> {
+      for elem in bytes {
+        self.written.extend_from_copyable_slice(elem)?;
+      }
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn delimiter() {
+    let stream = MockStream { read: b"pong\r\n\r\nlast\r", written: Vector::new() };
+    let mut trans = TcpTransport::new(Framing::Delimiter(b"\r\n"), stream);
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), ());
+    trans.send_bytes(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.unwrap();
+    assert_eq!(trans.stream().written.as_slice(), b"ping\r\n");
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"pong");
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"");
+    assert!(trans.recv(&mut pkgs_aux, ()).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn length_prefix() {
+    let stream = MockStream { read: &[0, 4, b'p', b'o', b'n', b'g', 0, 9], written: Vector::new() };
+    let mut trans = TcpTransport::new(Framing::U16BeLen, stream).max_message_len(8);
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), ());
+    trans.send_bytes(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.unwrap();
+    assert_eq!(trans.stream().written.as_slice(), &[0, 4, b'p', b'i', b'n', b'g']);
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"pong");
+    assert!(trans.recv(&mut pkgs_aux, ()).await.is_err());
+    assert!(trans.send_bytes(SendBytesSource::Param(&[0; 9]), &mut pkgs_aux).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn varint_length_prefix() {
+    let stream = MockStream { read: &[4, b'p', b'o', b'n', b'g', 128], written: Vector::new() };
+    let mut trans = TcpTransport::new(Framing::VarintLen, stream);
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), ());
+    trans.send_bytes(SendBytesSource::Param(&[1; 130]), &mut pkgs_aux).await.unwrap();
+    assert_eq!(trans.stream().written.get(..3).unwrap(), &[130, 1, 1]);
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"pong");
+    assert!(trans.recv(&mut pkgs_aux, ()).await.is_err());
+  }
+}
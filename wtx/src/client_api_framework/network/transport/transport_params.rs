@@ -1,3 +1,5 @@
+use crate::misc::Vector;
+
 /// Additional information or metadata received or transmitted by a transport.
 pub trait TransportParams {
   /// For example, HTTP has request headers.
@@ -5,6 +7,22 @@ pub trait TransportParams {
   /// For example, HTTP has response headers.
   type ExternalResponseParams;
 
+  /// Hook that is automatically called after receiving the bytes of any response.
+  #[inline]
+  fn after_receiving(
+    &mut self,
+    _bytes: &mut Vector<u8>,
+  ) -> impl Future<Output = crate::Result<()>> {
+    async { Ok(()) }
+  }
+
+  /// Hook that is automatically called with the serialized bytes of any request that is about to
+  /// be sent.
+  #[inline]
+  fn before_sending(&mut self, _bytes: &[u8]) -> impl Future<Output = crate::Result<()>> {
+    async { Ok(()) }
+  }
+
   /// External Request and Response Parameters.
   fn ext_params(&self) -> (&Self::ExternalRequestParams, &Self::ExternalResponseParams);
 
@@ -64,6 +82,16 @@ where
   type ExternalRequestParams = TP::ExternalRequestParams;
   type ExternalResponseParams = TP::ExternalResponseParams;
 
+  #[inline]
+  async fn after_receiving(&mut self, bytes: &mut Vector<u8>) -> crate::Result<()> {
+    (**self).after_receiving(bytes).await
+  }
+
+  #[inline]
+  async fn before_sending(&mut self, bytes: &[u8]) -> crate::Result<()> {
+    (**self).before_sending(bytes).await
+  }
+
   #[inline]
   fn ext_params(&self) -> (&Self::ExternalRequestParams, &Self::ExternalResponseParams) {
     (**self).ext_params()
@@ -0,0 +1,267 @@
+use crate::{
+  client_api_framework::{
+    Api, ClientApiFrameworkError, SendBytesSource,
+    misc::{
+      _log_res, manage_after_receiving, manage_after_sending_bytes, manage_after_sending_pkg,
+      manage_before_sending_bytes, manage_before_sending_pkg,
+    },
+    network::{
+      TransportGroup, UdpParams, UdpReqParams,
+      transport::{ReceivingTransport, SendingTransport, Transport, TransportParams},
+    },
+    pkg::{Package, PkgsAux},
+  },
+  misc::{BufferMode, LeaseMut},
+};
+use core::net::SocketAddr;
+use std::net::UdpSocket;
+
+impl<TP> ReceivingTransport<TP> for UdpSocket
+where
+  TP: LeaseMut<UdpParams> + TransportParams,
+{
+  #[inline]
+  async fn recv<A, DRSR>(
+    &mut self,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    _: Self::ReqId,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    recv(pkgs_aux, self).await?;
+    Ok(())
+  }
+}
+
+impl<TP> SendingTransport<TP> for UdpSocket
+where
+  TP: LeaseMut<UdpParams> + TransportParams,
+{
+  #[inline]
+  async fn send_bytes<A, DRSR>(
+    &mut self,
+    bytes: SendBytesSource<'_>,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    manage_before_sending_bytes(bytes, pkgs_aux, self).await?;
+    send(bytes.bytes(&pkgs_aux.byte_buffer), pkgs_aux.tp.lease_mut().ext_req_params(), self)?;
+    manage_after_sending_bytes(pkgs_aux).await?;
+    Ok(())
+  }
+
+  #[inline]
+  async fn send_pkg<A, DRSR, P>(
+    &mut self,
+    pkg: &mut P,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+    P: Package<A, DRSR, Self::Inner, TP>,
+  {
+    manage_before_sending_pkg(pkg, pkgs_aux, self).await?;
+    send(&pkgs_aux.byte_buffer, pkgs_aux.tp.lease_mut().ext_req_params(), self)?;
+    manage_after_sending_pkg(pkg, pkgs_aux, self).await
+  }
+}
+
+impl<TP> Transport<TP> for UdpSocket {
+  const GROUP: TransportGroup = TransportGroup::UDP;
+  type Inner = Self;
+  type ReqId = ();
+}
+
+async fn manage_recv<A, DRSR, TP>(
+  pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  (len, addr): (usize, SocketAddr),
+) -> crate::Result<()>
+where
+  TP: LeaseMut<UdpParams> + TransportParams,
+{
+  let (req_params, res_params) = pkgs_aux.tp.lease_mut().ext_params_mut();
+  if len > req_params.max_datagram_size {
+    return Err(ClientApiFrameworkError::VeryLargeUdpDatagram(len).into());
+  }
+  res_params.remote_addr = Some(addr);
+  pkgs_aux.byte_buffer.truncate(len);
+  manage_after_receiving(pkgs_aux).await?;
+  _log_res(pkgs_aux.log_body.1, &pkgs_aux.byte_buffer, TransportGroup::UDP);
+  Ok(())
+}
+
+async fn recv<A, DRSR, TP>(
+  pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  socket: &UdpSocket,
+) -> crate::Result<()>
+where
+  TP: LeaseMut<UdpParams> + TransportParams,
+{
+  let rslt = socket.recv_from(recv_buffer(pkgs_aux)?)?;
+  manage_recv(pkgs_aux, rslt).await
+}
+
+// One additional byte is reserved to detect truncated datagrams.
+fn recv_buffer<A, DRSR, TP>(pkgs_aux: &mut PkgsAux<A, DRSR, TP>) -> crate::Result<&mut [u8]>
+where
+  TP: LeaseMut<UdpParams>,
+{
+  let len = pkgs_aux.tp.lease_mut().ext_req_params().max_datagram_size.saturating_add(1);
+  pkgs_aux.byte_buffer.clear();
+  pkgs_aux.byte_buffer.expand(BufferMode::Len(len), 0)?;
+  Ok(pkgs_aux.byte_buffer.as_slice_mut())
+}
+
+fn send(data: &[u8], req_params: &UdpReqParams, socket: &UdpSocket) -> crate::Result<()> {
+  let _ = match remote_addr(data, req_params)? {
+    Some(elem) => socket.send_to(data, elem)?,
+    None => socket.send(data)?,
+  };
+  Ok(())
+}
+
+fn remote_addr(data: &[u8], req_params: &UdpReqParams) -> crate::Result<Option<SocketAddr>> {
+  if data.len() > req_params.max_datagram_size {
+    return Err(ClientApiFrameworkError::VeryLargeUdpDatagram(data.len()).into());
+  }
+  Ok(req_params.remote_addr)
+}
+
+#[cfg(feature = "tokio")]
+mod tokio {
+  use crate::{
+    client_api_framework::{
+      Api, SendBytesSource,
+      misc::{
+        manage_after_sending_bytes, manage_after_sending_pkg, manage_before_sending_bytes,
+        manage_before_sending_pkg,
+      },
+      network::{
+        TransportGroup, UdpParams, UdpReqParams,
+        transport::{
+          ReceivingTransport, SendingTransport, Transport, TransportParams,
+          udp::{manage_recv, recv_buffer, remote_addr},
+        },
+      },
+      pkg::{Package, PkgsAux},
+    },
+    misc::LeaseMut,
+  };
+  use tokio::net::UdpSocket;
+
+  impl<TP> ReceivingTransport<TP> for UdpSocket
+  where
+    TP: LeaseMut<UdpParams> + TransportParams,
+  {
+    #[inline]
+    async fn recv<A, DRSR>(
+      &mut self,
+      pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+      _: Self::ReqId,
+    ) -> Result<(), A::Error>
+    where
+      A: Api,
+    {
+      recv(pkgs_aux, self).await?;
+      Ok(())
+    }
+  }
+
+  impl<TP> SendingTransport<TP> for UdpSocket
+  where
+    TP: LeaseMut<UdpParams> + TransportParams,
+  {
+    #[inline]
+    async fn send_bytes<A, DRSR>(
+      &mut self,
+      bytes: SendBytesSource<'_>,
+      pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    ) -> Result<(), A::Error>
+    where
+      A: Api,
+    {
+      manage_before_sending_bytes(bytes, pkgs_aux, self).await?;
+      send(bytes.bytes(&pkgs_aux.byte_buffer), pkgs_aux.tp.lease_mut().ext_req_params(), self)
+        .await?;
+      manage_after_sending_bytes(pkgs_aux).await?;
+      Ok(())
+    }
+
+    #[inline]
+    async fn send_pkg<A, DRSR, P>(
+      &mut self,
+      pkg: &mut P,
+      pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    ) -> Result<(), A::Error>
+    where
+      A: Api,
+      P: Package<A, DRSR, Self::Inner, TP>,
+    {
+      manage_before_sending_pkg(pkg, pkgs_aux, self).await?;
+      send(&pkgs_aux.byte_buffer, pkgs_aux.tp.lease_mut().ext_req_params(), self).await?;
+      manage_after_sending_pkg(pkg, pkgs_aux, self).await
+    }
+  }
+
+  impl<TP> Transport<TP> for UdpSocket {
+    const GROUP: TransportGroup = TransportGroup::UDP;
+    type Inner = Self;
+    type ReqId = ();
+  }
+
+  async fn recv<A, DRSR, TP>(
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    socket: &UdpSocket,
+  ) -> crate::Result<()>
+  where
+    TP: LeaseMut<UdpParams> + TransportParams,
+  {
+    let rslt = socket.recv_from(recv_buffer(pkgs_aux)?).await?;
+    manage_recv(pkgs_aux, rslt).await
+  }
+
+  async fn send(data: &[u8], req_params: &UdpReqParams, socket: &UdpSocket) -> crate::Result<()> {
+    let _ = match remote_addr(data, req_params)? {
+      Some(elem) => socket.send_to(data, elem).await?,
+      None => socket.send(data).await?,
+    };
+    Ok(())
+  }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::client_api_framework::{
+    SendBytesSource,
+    network::{
+      UdpParams,
+      transport::{ReceivingTransport, SendingTransport, TransportParams},
+    },
+    pkg::PkgsAux,
+  };
+  use std::net::UdpSocket;
+
+  #[tokio::test]
+  async fn send_and_recv() {
+    let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let server_addr = server.local_addr().unwrap();
+    let mut trans = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let mut pkgs_aux =
+      PkgsAux::from_minimum((), (), UdpParams::from_remote_addr(Some(server_addr)));
+    trans.send_bytes(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.unwrap();
+    let mut buffer = [0; 8];
+    let (len, addr) = server.recv_from(&mut buffer).unwrap();
+    assert_eq!(&buffer[..len], b"ping");
+    let _ = server.send_to(b"pong", addr).unwrap();
+    ReceivingTransport::recv(&mut trans, &mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"pong");
+    assert_eq!(pkgs_aux.tp.ext_res_params().remote_addr, Some(server_addr));
+    pkgs_aux.tp.ext_req_params_mut().max_datagram_size = 2;
+    assert!(trans.send_bytes(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.is_err());
+    let _ = server.send_to(b"pong", addr).unwrap();
+    assert!(ReceivingTransport::recv(&mut trans, &mut pkgs_aux, ()).await.is_err());
+  }
+}
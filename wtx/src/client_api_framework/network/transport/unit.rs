@@ -6,7 +6,7 @@ use crate::client_api_framework::{
   },
   network::{
     TransportGroup,
-    transport::{ReceivingTransport, SendingTransport, Transport},
+    transport::{ReceivingTransport, SendingTransport, Transport, TransportParams},
   },
   pkg::{Package, PkgsAux},
 };
@@ -25,7 +25,10 @@ impl<TP> ReceivingTransport<TP> for () {
   }
 }
 
-impl<TP> SendingTransport<TP> for () {
+impl<TP> SendingTransport<TP> for ()
+where
+  TP: TransportParams,
+{
   #[inline]
   async fn send_bytes<A, DRSR>(
     &mut self,
@@ -0,0 +1,143 @@
+use crate::{
+  client_api_framework::{
+    Api, SendBytesSource,
+    misc::{
+      manage_after_sending_bytes, manage_after_sending_pkg, manage_before_sending_bytes,
+      manage_before_sending_pkg,
+    },
+    network::{
+      TransportGroup,
+      transport::{
+        Framing, ReceivingTransport, SendingTransport, Transport, TransportParams,
+        framing::FramedStream,
+      },
+    },
+    pkg::{Package, PkgsAux},
+  },
+  misc::Stream,
+};
+
+/// Unix domain socket transport that exchanges messages delimited according to a [`Framing`].
+/// Useful for communicating with local daemons.
+///
+/// Usually used with the `UnixStream` structure of `std` or `tokio`.
+#[derive(Debug)]
+pub struct UnixTransport<S>(FramedStream<S>);
+
+impl<S> UnixTransport<S> {
+  /// New instance
+  #[inline]
+  pub const fn new(framing: Framing, stream: S) -> Self {
+    Self(FramedStream::new(framing, stream))
+  }
+
+  /// Sent or received messages that are larger than `elem` bytes are rejected.
+  ///
+  /// Defaults to 16 MiB.
+  #[inline]
+  #[must_use]
+  pub fn max_message_len(mut self, elem: usize) -> Self {
+    self.0.set_max_message_len(elem);
+    self
+  }
+
+  /// Underlying stream
+  #[inline]
+  pub fn stream(&self) -> &S {
+    self.0.stream()
+  }
+
+  /// Mutable version of [`Self::stream`].
+  #[inline]
+  pub fn stream_mut(&mut self) -> &mut S {
+    self.0.stream_mut()
+  }
+}
+
+impl<S, TP> ReceivingTransport<TP> for UnixTransport<S>
+where
+  S: Stream,
+  TP: TransportParams,
+{
+  #[inline]
+  async fn recv<A, DRSR>(
+    &mut self,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    _: Self::ReqId,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    self.0.recv(pkgs_aux, TransportGroup::UnixSocket).await?;
+    Ok(())
+  }
+}
+
+impl<S, TP> SendingTransport<TP> for UnixTransport<S>
+where
+  S: Stream,
+  TP: TransportParams,
+{
+  #[inline]
+  async fn send_bytes<A, DRSR>(
+    &mut self,
+    bytes: SendBytesSource<'_>,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    manage_before_sending_bytes(bytes, pkgs_aux, self).await?;
+    self.0.write(bytes.bytes(&pkgs_aux.byte_buffer)).await?;
+    manage_after_sending_bytes(pkgs_aux).await?;
+    Ok(())
+  }
+
+  #[inline]
+  async fn send_pkg<A, DRSR, P>(
+    &mut self,
+    pkg: &mut P,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+    P: Package<A, DRSR, Self::Inner, TP>,
+  {
+    manage_before_sending_pkg(pkg, pkgs_aux, self).await?;
+    self.0.write(&pkgs_aux.byte_buffer).await?;
+    manage_after_sending_pkg(pkg, pkgs_aux, self).await
+  }
+}
+
+impl<S, TP> Transport<TP> for UnixTransport<S> {
+  const GROUP: TransportGroup = TransportGroup::UnixSocket;
+  type Inner = Self;
+  type ReqId = ();
+}
+
+#[cfg(all(feature = "_async-tests", feature = "std", test, unix))]
+mod tests {
+  use crate::client_api_framework::{
+    SendBytesSource,
+    network::transport::{Framing, ReceivingTransport, SendingTransport, UnixTransport},
+    pkg::PkgsAux,
+  };
+  use std::{
+    io::{Read, Write},
+    os::unix::net::UnixStream,
+  };
+
+  #[tokio::test]
+  async fn send_and_recv() {
+    let (local, mut remote) = UnixStream::pair().unwrap();
+    let mut trans = UnixTransport::new(Framing::U32LeLen, local);
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), ());
+    trans.send_bytes(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.unwrap();
+    let mut buffer = [0; 8];
+    remote.read_exact(&mut buffer).unwrap();
+    assert_eq!(&buffer, &[4, 0, 0, 0, b'p', b'i', b'n', b'g']);
+    remote.write_all(&[4, 0, 0, 0, b'p', b'o', b'n', b'g']).unwrap();
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"pong");
+  }
+}
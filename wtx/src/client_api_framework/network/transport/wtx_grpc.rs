@@ -0,0 +1,176 @@
+use crate::{
+  client_api_framework::{
+    Api, ClientApiFrameworkError, SendBytesSource,
+    misc::{
+      _log_res, deadline_timeout, manage_after_receiving, manage_after_sending_bytes,
+      manage_after_sending_pkg, manage_before_sending_bytes, manage_before_sending_pkg,
+    },
+    network::{
+      GrpcParams, GrpcReqParams, GrpcResParams, TransportGroup,
+      transport::{ReceivingTransport, SendingTransport, Transport, TransportParams},
+    },
+    pkg::{Package, PkgsAux},
+  },
+  grpc::{GrpcClient, GrpcStatusCode, grpc_client::push_headers},
+  http::{Header, Headers, HttpClient, Method, ReqResBuffer},
+  misc::{LeaseMut, Vector},
+};
+use core::{mem, time::Duration};
+
+impl<C, DRSR, TP> ReceivingTransport<TP> for GrpcClient<C, DRSR>
+where
+  C: HttpClient,
+  TP: LeaseMut<GrpcParams> + TransportParams,
+{
+  #[inline]
+  async fn recv<A, DRSR_>(
+    &mut self,
+    pkgs_aux: &mut PkgsAux<A, DRSR_, TP>,
+    req_id: Self::ReqId,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    let (req_params, res_params) = pkgs_aux.tp.lease_mut().ext_params_mut();
+    let GrpcReqParams { headers, .. } = req_params;
+    let GrpcResParams { grpc_message, grpc_status, status_code } = res_params;
+    let mut rrb = ReqResBuffer::empty();
+    mem::swap(&mut rrb.body, &mut pkgs_aux.byte_buffer);
+    mem::swap(&mut rrb.headers, headers);
+    rrb.clear();
+    let mut res = self.client.recv_res(rrb, req_id).await?;
+    *grpc_status = match res.rrd.headers.get_by_name(b"grpc-status") {
+      Some(elem) => GrpcStatusCode::_from_number_str(elem.value),
+      None => GrpcStatusCode::_from_http_status(res.status_code),
+    };
+    grpc_message.clear();
+    if let Some(elem) = res.rrd.headers.get_by_name(b"grpc-message") {
+      grpc_message.push_str(elem.value);
+    }
+    *status_code = res.status_code;
+    remove_message_prefix(&mut res.rrd.body)?;
+    mem::swap(&mut res.rrd.body, &mut pkgs_aux.byte_buffer);
+    mem::swap(&mut res.rrd.headers, headers);
+    manage_after_receiving(pkgs_aux).await?;
+    _log_res(pkgs_aux.log_body.1, &pkgs_aux.byte_buffer, TransportGroup::GRPC);
+    Ok(())
+  }
+}
+
+impl<C, DRSR, TP> SendingTransport<TP> for GrpcClient<C, DRSR>
+where
+  C: HttpClient,
+  TP: LeaseMut<GrpcParams> + TransportParams,
+{
+  #[inline]
+  async fn send_bytes<A, DRSR_>(
+    &mut self,
+    bytes: SendBytesSource<'_>,
+    pkgs_aux: &mut PkgsAux<A, DRSR_, TP>,
+  ) -> Result<Self::ReqId, A::Error>
+  where
+    A: Api,
+  {
+    manage_before_sending_bytes(bytes, pkgs_aux, self).await?;
+    let req_id = send(
+      bytes.bytes(&pkgs_aux.byte_buffer),
+      &mut self.client,
+      deadline_timeout(pkgs_aux.deadline)?,
+      pkgs_aux.tp.lease_mut().ext_req_params_mut(),
+    )
+    .await?;
+    manage_after_sending_bytes(pkgs_aux).await?;
+    Ok(req_id)
+  }
+
+  #[inline]
+  async fn send_pkg<A, DRSR_, P>(
+    &mut self,
+    pkg: &mut P,
+    pkgs_aux: &mut PkgsAux<A, DRSR_, TP>,
+  ) -> Result<Self::ReqId, A::Error>
+  where
+    A: Api,
+    P: Package<A, DRSR_, Self::Inner, TP>,
+  {
+    manage_before_sending_pkg(pkg, pkgs_aux, self).await?;
+    let req_id = send(
+      &pkgs_aux.byte_buffer,
+      &mut self.client,
+      deadline_timeout(pkg.deadline().or(pkgs_aux.deadline))?,
+      pkgs_aux.tp.lease_mut().ext_req_params_mut(),
+    )
+    .await?;
+    manage_after_sending_pkg(pkg, pkgs_aux, self).await?;
+    Ok(req_id)
+  }
+}
+
+impl<C, DRSR, TP> Transport<TP> for GrpcClient<C, DRSR>
+where
+  C: HttpClient,
+{
+  const GROUP: TransportGroup = TransportGroup::GRPC;
+  type Inner = Self;
+  type ReqId = C::ReqId;
+}
+
+// https://github.com/grpc/grpc/blob/master/doc/PROTOCOL-HTTP2.md
+fn push_grpc_timeout(headers: &mut Headers, timeout: Duration) -> crate::Result<()> {
+  const MAX: u128 = 99_999_999;
+  let nanos = timeout.as_nanos();
+  let (value, unit) = [
+    (1, 'n'),
+    (1_000, 'u'),
+    (1_000_000, 'm'),
+    (1_000_000_000, 'S'),
+    (60_000_000_000, 'M'),
+    (3_600_000_000_000, 'H'),
+  ]
+  .into_iter()
+  .map(|(divisor, unit)| (nanos.div_ceil(divisor), unit))
+  .find(|el| el.0 <= MAX)
+  .unwrap_or((MAX, 'H'));
+  headers.push_from_fmt(Header::from_name_and_value("grpc-timeout", format_args!("{value}{unit}")))
+}
+
+// Compressed messages are not supported.
+fn remove_message_prefix(body: &mut Vector<u8>) -> crate::Result<()> {
+  let [flag, a, b, c, d, rest @ ..] = body.as_slice() else {
+    if body.is_empty() {
+      return Ok(());
+    }
+    return Err(ClientApiFrameworkError::InvalidGrpcMessage.into());
+  };
+  if *flag != 0 || usize::try_from(u32::from_be_bytes([*a, *b, *c, *d]))? != rest.len() {
+    return Err(ClientApiFrameworkError::InvalidGrpcMessage.into());
+  }
+  let _ = body.drain(..5);
+  Ok(())
+}
+
+// The smallest value between `deadline` and the timeout of `params` is sent to the server.
+async fn send<C>(
+  bytes: &[u8],
+  client: &mut C,
+  deadline: Option<Duration>,
+  params: &mut GrpcReqParams,
+) -> crate::Result<C::ReqId>
+where
+  C: HttpClient,
+{
+  let GrpcReqParams { headers, timeout, uri } = params;
+  push_headers(headers)?;
+  let local_timeout = match (*timeout, deadline) {
+    (Some(lhs), Some(rhs)) => Some(lhs.min(rhs)),
+    (lhs, rhs) => lhs.or(rhs),
+  };
+  if let Some(elem) = local_timeout {
+    push_grpc_timeout(headers, elem)?;
+  }
+  let mut body = Vector::with_capacity(bytes.len().wrapping_add(5))?;
+  body.extend_from_copyable_slice(&[0])?;
+  body.extend_from_copyable_slice(&u32::try_from(bytes.len())?.to_be_bytes())?;
+  body.extend_from_copyable_slice(bytes)?;
+  client.send_req(Method::Post, (&body, &*headers), &uri.to_ref()).await
+}
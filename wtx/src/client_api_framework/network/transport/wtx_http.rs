@@ -2,8 +2,8 @@ use crate::{
   client_api_framework::{
     Api, SendBytesSource,
     misc::{
-      _log_res, manage_after_sending_bytes, manage_after_sending_pkg, manage_before_sending_bytes,
-      manage_before_sending_pkg,
+      _log_res, manage_after_receiving, manage_after_sending_bytes, manage_after_sending_pkg,
+      manage_before_sending_bytes, manage_before_sending_pkg,
     },
     network::{
       HttpParams, HttpReqParams, HttpResParams, TransportGroup,
@@ -11,9 +11,12 @@ use crate::{
     },
     pkg::{Package, PkgsAux},
   },
-  http::{HttpClient, ReqResBuffer, ResBuilder, WTX_USER_AGENT},
+  http::{
+    ContentCoding, Header, HttpClient, KnownHeaderName, ReqResBuffer, ResBuilder, WTX_USER_AGENT,
+    decompress_body,
+  },
   http2::{ClientStream, Http2, Http2Buffer, Http2Data},
-  misc::{LeaseMut, Lock, RefCounter, StreamWriter},
+  misc::{Intersperse, LeaseMut, Lock, RefCounter, StreamWriter, Vector},
 };
 use core::mem;
 
@@ -22,7 +25,7 @@ where
   HD: RefCounter,
   HD::Item: Lock<Resource = Http2Data<Http2Buffer, SW, true>>,
   SW: StreamWriter,
-  TP: LeaseMut<HttpParams>,
+  TP: LeaseMut<HttpParams> + TransportParams,
 {
   #[inline]
   async fn recv<A, DRSR>(
@@ -42,7 +45,7 @@ where
   HD: RefCounter,
   HD::Item: Lock<Resource = Http2Data<Http2Buffer, SW, true>>,
   SW: StreamWriter,
-  TP: LeaseMut<HttpParams>,
+  TP: LeaseMut<HttpParams> + TransportParams,
 {
   #[inline]
   async fn send_bytes<A, DRSR>(
@@ -87,9 +90,22 @@ where
   TP: LeaseMut<HttpParams>,
 {
   let params = pkgs_aux.tp.lease_mut();
-  let HttpReqParams { headers, mime, .. } = &mut params.ext_params_mut().0;
+  let max_decompressed_len = params.max_decompressed_len();
+  let HttpReqParams { accept, headers, mime, .. } = &mut params.ext_params_mut().0;
+  if max_decompressed_len.is_some()
+    && headers.get_by_name(KnownHeaderName::AcceptEncoding.into()).is_none()
+  {
+    headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::AcceptEncoding.into(),
+      Intersperse::new(ContentCoding::SUPPORTED.iter().map(|el| el.as_str()), ", "),
+    ))?;
+  }
+  let has_accept = headers.get_by_name(KnownHeaderName::Accept.into()).is_some();
   let mut rb = ResBuilder::ok(headers);
   let _ = rb.user_agent(WTX_USER_AGENT)?;
+  if let Some(elem) = accept.filter(|_| !has_accept) {
+    let _ = rb.accept(elem)?;
+  }
   if let Some(elem) = mime {
     let _ = rb.content_type(*elem)?;
   }
@@ -97,19 +113,18 @@ where
 }
 
 #[inline]
-async fn recv<A, DRSR, HD, SW, TP>(
-  client: &mut Http2<HD, true>,
+async fn recv<A, C, DRSR, TP>(
+  client: &mut C,
   pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
-  req_id: ClientStream<HD>,
+  req_id: C::ReqId,
 ) -> Result<(), A::Error>
 where
   A: Api,
-  HD: RefCounter,
-  HD::Item: Lock<Resource = Http2Data<Http2Buffer, SW, true>>,
-  SW: StreamWriter,
-  TP: LeaseMut<HttpParams>,
+  C: HttpClient,
+  TP: LeaseMut<HttpParams> + TransportParams,
 {
   let tp = pkgs_aux.tp.lease_mut();
+  let max_decompressed_len = tp.max_decompressed_len();
   let (req_params, res_params) = tp.ext_params_mut();
   let HttpReqParams { headers, .. } = req_params;
   let HttpResParams { status_code } = res_params;
@@ -118,25 +133,27 @@ where
   mem::swap(&mut rrb.headers, headers);
   rrb.clear();
   let mut res = client.recv_res(rrb, req_id).await?;
+  if let Some(elem) = max_decompressed_len {
+    decompress_body(&mut Vector::new(), elem, &mut res.rrd)?;
+  }
   mem::swap(&mut res.rrd.body, &mut pkgs_aux.byte_buffer);
   mem::swap(&mut res.rrd.headers, headers);
   *status_code = res.status_code;
+  manage_after_receiving(pkgs_aux).await?;
   _log_res(pkgs_aux.log_body.1, &pkgs_aux.byte_buffer, TransportGroup::HTTP);
   Ok(())
 }
 
 #[inline]
-async fn send_bytes<A, DRSR, HD, SW, TP>(
+async fn send_bytes<A, C, DRSR, TP>(
   bytes: SendBytesSource<'_>,
-  client: &mut Http2<HD, true>,
+  client: &mut C,
   pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
-) -> Result<ClientStream<HD>, A::Error>
+) -> Result<<C as HttpClient>::ReqId, A::Error>
 where
   A: Api,
-  HD: RefCounter,
-  HD::Item: Lock<Resource = Http2Data<Http2Buffer, SW, true>>,
-  SW: StreamWriter,
-  TP: LeaseMut<HttpParams>,
+  C: HttpClient + Transport<TP>,
+  TP: LeaseMut<HttpParams> + TransportParams,
 {
   manage_before_sending_bytes(bytes, pkgs_aux, client).await?;
   manage_params(pkgs_aux)?;
@@ -149,18 +166,16 @@ where
 }
 
 #[inline]
-async fn send_pkg<A, DRSR, HD, P, SW, TP>(
-  client: &mut Http2<HD, true>,
+async fn send_pkg<A, C, DRSR, P, TP>(
+  client: &mut C,
   pkg: &mut P,
   pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
-) -> Result<ClientStream<HD>, A::Error>
+) -> Result<<C as HttpClient>::ReqId, A::Error>
 where
   A: Api,
-  HD: RefCounter,
-  HD::Item: Lock<Resource = Http2Data<Http2Buffer, SW, true>>,
-  P: Package<A, DRSR, Http2<HD, true>, TP>,
-  SW: StreamWriter,
-  TP: LeaseMut<HttpParams>,
+  C: HttpClient + Transport<TP>,
+  P: Package<A, DRSR, C, TP>,
+  TP: LeaseMut<HttpParams> + TransportParams,
 {
   manage_before_sending_pkg(pkg, pkgs_aux, client).await?;
   manage_params(pkgs_aux)?;
@@ -171,6 +186,82 @@ where
   Ok(rslt)
 }
 
+#[cfg(feature = "http3")]
+mod http3 {
+  use crate::{
+    client_api_framework::{
+      Api, SendBytesSource,
+      network::{
+        HttpParams, TransportGroup,
+        transport::{
+          ReceivingTransport, SendingTransport, Transport, TransportParams,
+          wtx_http::{recv, send_bytes, send_pkg},
+        },
+      },
+      pkg::{Package, PkgsAux},
+    },
+    http3::{Http3Client, QuicConnection},
+    misc::LeaseMut,
+  };
+
+  impl<C, TP> ReceivingTransport<TP> for Http3Client<C>
+  where
+    C: QuicConnection,
+    TP: LeaseMut<HttpParams> + TransportParams,
+  {
+    #[inline]
+    async fn recv<A, DRSR>(
+      &mut self,
+      pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+      req_id: Self::ReqId,
+    ) -> Result<(), A::Error>
+    where
+      A: Api,
+    {
+      recv(self, pkgs_aux, req_id).await?;
+      Ok(())
+    }
+  }
+  impl<C, TP> SendingTransport<TP> for Http3Client<C>
+  where
+    C: QuicConnection,
+    TP: LeaseMut<HttpParams> + TransportParams,
+  {
+    #[inline]
+    async fn send_bytes<A, DRSR>(
+      &mut self,
+      bytes: SendBytesSource<'_>,
+      pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    ) -> Result<Self::ReqId, A::Error>
+    where
+      A: Api,
+    {
+      send_bytes(bytes, self, pkgs_aux).await
+    }
+
+    #[inline]
+    async fn send_pkg<A, DRSR, P>(
+      &mut self,
+      pkg: &mut P,
+      pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    ) -> Result<Self::ReqId, A::Error>
+    where
+      A: Api,
+      P: Package<A, DRSR, Self::Inner, TP>,
+    {
+      send_pkg(self, pkg, pkgs_aux).await
+    }
+  }
+  impl<C, TP> Transport<TP> for Http3Client<C>
+  where
+    C: QuicConnection,
+  {
+    const GROUP: TransportGroup = TransportGroup::HTTP;
+    type Inner = Self;
+    type ReqId = C::BiStream;
+  }
+}
+
 #[cfg(feature = "http-client-pool")]
 mod http_client_pool {
   use crate::{
@@ -203,7 +294,7 @@ mod http_client_pool {
         Resource = ClientPoolResource<AUX, Http2<HD, true>>,
       >,
     SW: StreamWriter,
-    TP: LeaseMut<HttpParams>,
+    TP: LeaseMut<HttpParams> + TransportParams,
     for<'any> RL: 'any,
     for<'any> RM: 'any,
   {
@@ -237,7 +328,7 @@ mod http_client_pool {
         Resource = ClientPoolResource<AUX, Http2<HD, true>>,
       >,
     SW: StreamWriter,
-    TP: LeaseMut<HttpParams>,
+    TP: LeaseMut<HttpParams> + TransportParams,
     for<'any> RL: 'any,
     for<'any> RM: 'any,
   {
@@ -306,7 +397,7 @@ mod http_client_pool {
         Resource = ClientPoolResource<AUX, Http2<HD, true>>,
       >,
     SW: StreamWriter,
-    TP: LeaseMut<HttpParams>,
+    TP: LeaseMut<HttpParams> + TransportParams,
     for<'any> RL: 'any,
     for<'any> RM: 'any,
   {
@@ -340,7 +431,7 @@ mod http_client_pool {
         Resource = ClientPoolResource<AUX, Http2<HD, true>>,
       >,
     SW: StreamWriter,
-    TP: LeaseMut<HttpParams>,
+    TP: LeaseMut<HttpParams> + TransportParams,
     for<'any> RL: 'any,
     for<'any> RM: 'any,
   {
@@ -1,3 +1,4 @@
+mod reconnecting_web_socket;
 mod web_socket;
 mod web_socket_parts_owned;
 mod web_socket_reader_part_owned;
@@ -7,8 +8,8 @@ use crate::{
   client_api_framework::{
     Api, ClientApiFrameworkError, SendBytesSource,
     misc::{
-      _log_res, manage_after_sending_bytes, manage_after_sending_pkg, manage_before_sending_bytes,
-      manage_before_sending_pkg,
+      _log_res, manage_after_receiving, manage_after_sending_bytes, manage_after_sending_pkg,
+      manage_before_sending_bytes, manage_before_sending_pkg,
     },
     network::{
       TransportGroup, WsParams, WsReqParamsTy,
@@ -19,6 +20,7 @@ use crate::{
   misc::{FnMutFut, LeaseMut, Vector},
   web_socket::{Frame, OpCode},
 };
+pub use reconnecting_web_socket::ReconnectingWebSocket;
 
 fn op_code<A, DRSR, TP>(pkgs_aux: &mut PkgsAux<A, DRSR, TP>) -> OpCode
 where
@@ -33,12 +35,16 @@ where
 async fn recv<A, DRSR, TP>(
   frame: Frame<&mut [u8], true>,
   pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
-) -> crate::Result<()> {
+) -> crate::Result<()>
+where
+  TP: TransportParams,
+{
   if let OpCode::Close = frame.op_code() {
     return Err(ClientApiFrameworkError::ClosedWsConnection.into());
   }
   pkgs_aux.byte_buffer.clear();
   pkgs_aux.byte_buffer.extend_from_copyable_slice(frame.payload())?;
+  manage_after_receiving(pkgs_aux).await?;
   _log_res(pkgs_aux.log_body.1, &pkgs_aux.byte_buffer, TransportGroup::WebSocket);
   Ok(())
 }
@@ -55,7 +61,7 @@ async fn send_bytes<A, DRSR, T, TP>(
 where
   A: Api,
   T: Transport<TP>,
-  TP: LeaseMut<WsParams>,
+  TP: LeaseMut<WsParams> + TransportParams,
 {
   manage_before_sending_bytes(bytes, pkgs_aux, trans).await?;
   if let SendBytesSource::Param(elem) = bytes {
@@ -79,7 +85,7 @@ where
   A: Api,
   P: Package<A, DRSR, T, TP>,
   T: Transport<TP>,
-  TP: LeaseMut<WsParams>,
+  TP: LeaseMut<WsParams> + TransportParams,
 {
   manage_before_sending_pkg(pkg, pkgs_aux, trans).await?;
   cb.call((Frame::new_fin(op_code(pkgs_aux), &mut pkgs_aux.byte_buffer), trans)).await?;
@@ -0,0 +1,332 @@
+use crate::{
+  client_api_framework::{
+    Api, SendBytesSource,
+    misc::{PkgRetryPolicy, manage_after_sending_pkg, manage_before_sending_pkg},
+    network::{
+      TransportGroup, WsParams,
+      transport::{
+        ReceivingTransport, SendingTransport, Transport, TransportParams,
+        wtx_ws::{op_code, recv, send_bytes, send_pkg},
+      },
+    },
+    pkg::{Package, PkgsAux},
+  },
+  misc::{FnMutFut, LeaseMut, Rng, Stream, Vector, Xorshift64, simple_seed, sleep},
+  web_socket::{Frame, OpCode, WebSocket, WebSocketBuffer, compression::NegotiatedCompression},
+};
+
+/// WebSocket client that transparently establishes a new connection when the current one is
+/// closed or fails.
+///
+/// Connections are created by `connector` and waiting periods between failed attempts are
+/// dictated by a [`PkgRetryPolicy`]. Packages sent through [`Self::subscribe`] are replayed after
+/// each reconnection, which allows long-lived subscriptions to resume delivering responses.
+#[derive(Debug)]
+pub struct ReconnectingWebSocket<C, NC, R, S, WSB> {
+  buffer: Vector<u8>,
+  connector: C,
+  policy: PkgRetryPolicy,
+  reconnections: u64,
+  rng: Xorshift64,
+  subscriptions: Vector<(OpCode, Vector<u8>)>,
+  ws: WebSocket<NC, R, S, WSB, true>,
+}
+
+impl<C, NC, R, S, WSB> ReconnectingWebSocket<C, NC, R, S, WSB>
+where
+  C: FnMutFut<(), Result = crate::Result<WebSocket<NC, R, S, WSB, true>>>,
+  NC: NegotiatedCompression,
+  R: Rng,
+  S: Stream,
+  WSB: LeaseMut<WebSocketBuffer>,
+{
+  /// Establishes the first connection, which is also subjected to `policy`.
+  #[inline]
+  pub async fn new(mut connector: C, policy: PkgRetryPolicy) -> crate::Result<Self> {
+    let mut buffer = Vector::new();
+    let mut rng = Xorshift64::from(simple_seed());
+    let ws = connect(&mut buffer, &mut connector, &policy, &mut rng, &[]).await?;
+    Ok(Self { buffer, connector, policy, reconnections: 0, rng, subscriptions: Vector::new(), ws })
+  }
+
+  /// Removes all registered subscriptions. Doesn't send any message to the server.
+  #[inline]
+  pub fn clear_subscriptions(&mut self) {
+    self.subscriptions.clear();
+  }
+
+  /// Number of times a new connection was successfully established after the first one.
+  #[inline]
+  pub fn reconnections(&self) -> u64 {
+    self.reconnections
+  }
+
+  /// Sends `pkg` and registers its encoded contents to be sent again on every new connection.
+  ///
+  /// A sending failure triggers a reconnection, which already replays the subscription.
+  #[inline]
+  pub async fn subscribe<A, DRSR, P, TP>(
+    &mut self,
+    pkg: &mut P,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+    P: Package<A, DRSR, Self, TP>,
+    TP: LeaseMut<WsParams> + TransportParams,
+  {
+    manage_before_sending_pkg(pkg, pkgs_aux, self).await?;
+    let local_op_code = op_code(pkgs_aux);
+    let mut payload = Vector::new();
+    payload.extend_from_copyable_slice(&pkgs_aux.byte_buffer)?;
+    self.subscriptions.push((local_op_code, payload))?;
+    let frame = &mut Frame::new_fin(local_op_code, &mut pkgs_aux.byte_buffer);
+    if self.ws.write_frame(frame).await.is_err() {
+      self.reconnect().await?;
+    }
+    manage_after_sending_pkg(pkg, pkgs_aux, self).await
+  }
+
+  /// Underlying WebSocket of the current connection.
+  #[inline]
+  pub fn web_socket(&self) -> &WebSocket<NC, R, S, WSB, true> {
+    &self.ws
+  }
+
+  async fn reconnect(&mut self) -> crate::Result<()> {
+    _debug!("Re-establishing WebSocket connection");
+    self.ws = connect(
+      &mut self.buffer,
+      &mut self.connector,
+      &self.policy,
+      &mut self.rng,
+      &self.subscriptions,
+    )
+    .await?;
+    self.reconnections = self.reconnections.wrapping_add(1);
+    Ok(())
+  }
+}
+
+impl<C, NC, R, S, TP, WSB> ReceivingTransport<TP> for ReconnectingWebSocket<C, NC, R, S, WSB>
+where
+  C: FnMutFut<(), Result = crate::Result<WebSocket<NC, R, S, WSB, true>>>,
+  NC: NegotiatedCompression,
+  R: Rng,
+  S: Stream,
+  TP: TransportParams,
+  WSB: LeaseMut<WebSocketBuffer>,
+{
+  #[inline]
+  async fn recv<A, DRSR>(
+    &mut self,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+    _: Self::ReqId,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    loop {
+      match self.ws.read_frame().await {
+        Ok(frame) if !matches!(frame.op_code(), OpCode::Close) => {
+          recv(frame, pkgs_aux).await?;
+          return Ok(());
+        }
+        Ok(_) | Err(_) => {}
+      }
+      self.reconnect().await?;
+    }
+  }
+}
+
+impl<C, NC, R, S, TP, WSB> SendingTransport<TP> for ReconnectingWebSocket<C, NC, R, S, WSB>
+where
+  C: FnMutFut<(), Result = crate::Result<WebSocket<NC, R, S, WSB, true>>>,
+  NC: NegotiatedCompression,
+  R: Rng,
+  S: Stream,
+  TP: LeaseMut<WsParams> + TransportParams,
+  WSB: LeaseMut<WebSocketBuffer>,
+{
+  #[inline]
+  async fn send_bytes<A, DRSR>(
+    &mut self,
+    bytes: SendBytesSource<'_>,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+  {
+    send_bytes(bytes, pkgs_aux, self, cb).await
+  }
+
+  #[inline]
+  async fn send_pkg<A, DRSR, P>(
+    &mut self,
+    pkg: &mut P,
+    pkgs_aux: &mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<(), A::Error>
+  where
+    A: Api,
+    P: Package<A, DRSR, Self::Inner, TP>,
+  {
+    send_pkg(pkg, pkgs_aux, self, cb).await
+  }
+}
+
+impl<C, NC, R, S, TP, WSB> Transport<TP> for ReconnectingWebSocket<C, NC, R, S, WSB> {
+  const GROUP: TransportGroup = TransportGroup::WebSocket;
+  type Inner = Self;
+  type ReqId = ();
+}
+
+// A failed request is not sent again but the connection is re-established for subsequent calls.
+async fn cb<C, NC, R, S, WSB>(
+  mut frame: Frame<&mut Vector<u8>, true>,
+  trans: &mut ReconnectingWebSocket<C, NC, R, S, WSB>,
+) -> crate::Result<()>
+where
+  C: FnMutFut<(), Result = crate::Result<WebSocket<NC, R, S, WSB, true>>>,
+  NC: NegotiatedCompression,
+  R: Rng,
+  S: Stream,
+  WSB: LeaseMut<WebSocketBuffer>,
+{
+  let Err(err) = trans.ws.write_frame(&mut frame).await else {
+    return Ok(());
+  };
+  trans.reconnect().await?;
+  Err(err)
+}
+
+async fn connect<C, NC, R, S, WSB>(
+  buffer: &mut Vector<u8>,
+  connector: &mut C,
+  policy: &PkgRetryPolicy,
+  rng: &mut Xorshift64,
+  subscriptions: &[(OpCode, Vector<u8>)],
+) -> crate::Result<WebSocket<NC, R, S, WSB, true>>
+where
+  C: FnMutFut<(), Result = crate::Result<WebSocket<NC, R, S, WSB, true>>>,
+  NC: NegotiatedCompression,
+  R: Rng,
+  S: Stream,
+  WSB: LeaseMut<WebSocketBuffer>,
+{
+  let mut attempt: u8 = 0;
+  loop {
+    attempt = attempt.wrapping_add(1);
+    let err = match connect_and_replay(buffer, connector, subscriptions).await {
+      Ok(elem) => return Ok(elem),
+      Err(err) => err,
+    };
+    let Some(elem) = policy.next_delay(attempt, rng) else {
+      return Err(err);
+    };
+    sleep(elem).await?;
+  }
+}
+
+async fn connect_and_replay<C, NC, R, S, WSB>(
+  buffer: &mut Vector<u8>,
+  connector: &mut C,
+  subscriptions: &[(OpCode, Vector<u8>)],
+) -> crate::Result<WebSocket<NC, R, S, WSB, true>>
+where
+  C: FnMutFut<(), Result = crate::Result<WebSocket<NC, R, S, WSB, true>>>,
+  NC: NegotiatedCompression,
+  R: Rng,
+  S: Stream,
+  WSB: LeaseMut<WebSocketBuffer>,
+{
+  let mut ws = connector.call(()).await?;
+  for (local_op_code, payload) in subscriptions {
+    buffer.clear();
+    buffer.extend_from_copyable_slice(payload)?;
+    ws.write_frame(&mut Frame::new_fin(*local_op_code, &mut *buffer)).await?;
+  }
+  Ok(ws)
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      SendBytesSource,
+      misc::PkgRetryPolicy,
+      network::{
+        WsParams,
+        transport::{
+          ReceivingTransport, ReconnectingWebSocket, SendingTransport,
+          tests::{_Ping, _PingPong},
+        },
+      },
+      pkg::PkgsAux,
+    },
+    misc::{StreamReader, StreamWriter, Vector, Xorshift64, simple_seed},
+    web_socket::{WebSocket, WebSocketBuffer},
+  };
+  use alloc::rc::Rc;
+  use core::{cell::RefCell, time::Duration};
+
+  struct MockStream {
+    read: &'static [u8],
+    written: Rc<RefCell<Vector<u8>>>,
+  }
+
+  impl StreamReader for MockStream {
+    async fn read(&mut self, bytes: &mut [u8]) -> crate::Result<usize> {
+      let len = bytes.len().min(self.read.len());
+      let (lhs, rhs) = self.read.split_at(len);
+      bytes.get_mut(..len).unwrap().copy_from_slice(lhs);
+      self.read = rhs;
+      Ok(len)
+    }
+  }
+
+  impl StreamWriter for MockStream {
+    async fn write_all(&mut self, bytes: &[u8]) -> crate::Result<()> {
+      self.written.borrow_mut().extend_from_copyable_slice(bytes)
+    }
+
+    async fn write_all_vectored(&mut self, bytes: &[&[u8]]) -> crate::Result<()> {
+      for elem in bytes {
+        self.written.borrow_mut().extend_from_copyable_slice(elem)?;
+      }
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn replays_subscriptions() {
+    let written = Rc::new(RefCell::new(Vector::new()));
+    let mut connections: u8 = 0;
+    let connector = || {
+      connections = connections.wrapping_add(1);
+      let read: &'static [u8] =
+        if connections == 1 { &[] } else { &[129, 4, b'p', b'o', b'n', b'g'] };
+      let stream = MockStream { read, written: written.clone() };
+      async move {
+        WebSocket::new(
+          (),
+          true,
+          Xorshift64::from(simple_seed()),
+          stream,
+          WebSocketBuffer::default(),
+        )
+      }
+    };
+    let policy = PkgRetryPolicy::new().base_delay(Duration::from_millis(1));
+    let mut trans = ReconnectingWebSocket::new(connector, policy).await.unwrap();
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), WsParams::default());
+    trans.subscribe(&mut _PingPong(_Ping, ()), &mut pkgs_aux).await.unwrap();
+    assert_eq!(written.borrow().as_slice(), &[129, 4, b'p', b'i', b'n', b'g']);
+    written.borrow_mut().clear();
+    trans.recv(&mut pkgs_aux, ()).await.unwrap();
+    assert_eq!(pkgs_aux.byte_buffer.as_slice(), b"pong");
+    assert_eq!(trans.reconnections(), 1);
+    assert_eq!(written.borrow().as_slice(), &[129, 4, b'p', b'i', b'n', b'g']);
+    trans.clear_subscriptions();
+    trans.send_bytes(SendBytesSource::Param(b"ping"), &mut pkgs_aux).await.unwrap();
+  }
+}
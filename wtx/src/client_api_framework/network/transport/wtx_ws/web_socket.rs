@@ -4,7 +4,7 @@ use crate::{
     network::{
       TransportGroup, WsParams,
       transport::{
-        ReceivingTransport, SendingTransport, Transport,
+        ReceivingTransport, SendingTransport, Transport, TransportParams,
         wtx_ws::{recv, send_bytes, send_pkg},
       },
     },
@@ -19,6 +19,7 @@ where
   NC: NegotiatedCompression,
   R: Rng,
   S: Stream,
+  TP: TransportParams,
   WSB: LeaseMut<WebSocketBuffer>,
 {
   #[inline]
@@ -40,7 +41,7 @@ where
   NC: NegotiatedCompression,
   R: Rng,
   S: Stream,
-  TP: LeaseMut<WsParams>,
+  TP: LeaseMut<WsParams> + TransportParams,
   WSB: LeaseMut<WebSocketBuffer>,
 {
   #[inline]
@@ -3,7 +3,7 @@ use crate::{
     Api, SendBytesSource,
     network::{
       TransportGroup, WsParams,
-      transport::{ReceivingTransport, SendingTransport, Transport},
+      transport::{ReceivingTransport, SendingTransport, Transport, TransportParams},
     },
     pkg::{Package, PkgsAux},
   },
@@ -21,7 +21,7 @@ where
   R: Rng,
   SR: StreamReader,
   SW: StreamWriter,
-  TP: LeaseMut<WsParams>,
+  TP: LeaseMut<WsParams> + TransportParams,
 {
   #[inline]
   async fn recv<A, DRSR>(
@@ -43,7 +43,7 @@ where
   R: Rng,
   SR: StreamReader,
   SW: StreamWriter,
-  TP: LeaseMut<WsParams>,
+  TP: LeaseMut<WsParams> + TransportParams,
 {
   #[inline]
   async fn send_bytes<A, DRSR>(
@@ -3,7 +3,7 @@ use crate::{
     Api,
     network::{
       TransportGroup, WsParams,
-      transport::{ReceivingTransport, Transport, wtx_ws::recv},
+      transport::{ReceivingTransport, Transport, TransportParams, wtx_ws::recv},
     },
     pkg::PkgsAux,
   },
@@ -20,7 +20,7 @@ where
   R: Rng,
   SR: StreamReader,
   SW: StreamWriter,
-  TP: LeaseMut<WsParams>,
+  TP: LeaseMut<WsParams> + TransportParams,
 {
   #[inline]
   async fn recv<A, DRSR>(
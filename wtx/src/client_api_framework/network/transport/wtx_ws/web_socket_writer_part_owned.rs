@@ -4,7 +4,7 @@ use crate::{
     network::{
       TransportGroup, WsParams,
       transport::{
-        SendingTransport, Transport,
+        SendingTransport, Transport, TransportParams,
         wtx_ws::{send_bytes, send_pkg},
       },
     },
@@ -22,7 +22,7 @@ where
   NC: NegotiatedCompression,
   R: Rng,
   SW: StreamWriter,
-  TP: LeaseMut<WsParams>,
+  TP: LeaseMut<WsParams> + TransportParams,
 {
   #[inline]
   async fn send_bytes<A, DRSR>(
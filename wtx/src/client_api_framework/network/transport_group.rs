@@ -5,10 +5,20 @@ use core::fmt::{Display, Formatter};
 pub enum TransportGroup {
   /// Transport group depending outside of `wtx`.
   Custom(&'static str),
+  /// gRPC over HTTP/2
+  GRPC,
   /// Hypertext Transfer Protocol
   HTTP,
+  /// Message Queuing Telemetry Transport
+  MQTT,
   /// Mock or dummy implementations
   Stub,
+  /// Transmission Control Protocol
+  TCP,
+  /// User Datagram Protocol
+  UDP,
+  /// Unix domain socket
+  UnixSocket,
   /// WebSocket
   WebSocket,
 }
@@ -18,8 +28,13 @@ impl From<TransportGroup> for &'static str {
   fn from(from: TransportGroup) -> Self {
     match from {
       TransportGroup::Custom(elem) => elem,
+      TransportGroup::GRPC => "gRPC",
       TransportGroup::HTTP => "HTTP",
+      TransportGroup::MQTT => "MQTT",
       TransportGroup::Stub => "Stub",
+      TransportGroup::TCP => "TCP",
+      TransportGroup::UDP => "UDP",
+      TransportGroup::UnixSocket => "UnixSocket",
       TransportGroup::WebSocket => "WebSocket",
     }
   }
@@ -0,0 +1,85 @@
+use crate::{
+  client_api_framework::network::transport::TransportParams,
+  misc::{Lease, LeaseMut},
+};
+use core::net::SocketAddr;
+
+// Largest payload of an IPv4 datagram.
+const MAX_DATAGRAM_SIZE: usize = 65_507;
+
+#[derive(Debug)]
+#[doc = generic_trans_params_doc!()]
+pub struct UdpParams(UdpReqParams, UdpResParams);
+
+impl UdpParams {
+  /// Requests are sent to `remote_addr` or to the connected address of the socket if `None`.
+  #[inline]
+  pub const fn from_remote_addr(remote_addr: Option<SocketAddr>) -> Self {
+    Self(
+      UdpReqParams { max_datagram_size: MAX_DATAGRAM_SIZE, remote_addr },
+      UdpResParams { remote_addr: None },
+    )
+  }
+}
+
+impl Default for UdpParams {
+  #[inline]
+  fn default() -> Self {
+    Self::from_remote_addr(None)
+  }
+}
+
+impl Lease<UdpParams> for UdpParams {
+  #[inline]
+  fn lease(&self) -> &UdpParams {
+    self
+  }
+}
+
+impl LeaseMut<UdpParams> for UdpParams {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut UdpParams {
+    self
+  }
+}
+
+impl TransportParams for UdpParams {
+  type ExternalRequestParams = UdpReqParams;
+  type ExternalResponseParams = UdpResParams;
+
+  #[inline]
+  fn ext_params(&self) -> (&Self::ExternalRequestParams, &Self::ExternalResponseParams) {
+    (&self.0, &self.1)
+  }
+
+  #[inline]
+  fn ext_params_mut(
+    &mut self,
+  ) -> (&mut Self::ExternalRequestParams, &mut Self::ExternalResponseParams) {
+    (&mut self.0, &mut self.1)
+  }
+
+  #[inline]
+  fn reset(&mut self) {
+    self.1.remote_addr = None;
+  }
+}
+
+/// All possible UDP parameters that a request can manipulate for sending. Aren't modified when the
+/// parameters are reset.
+#[derive(Debug)]
+pub struct UdpReqParams {
+  /// Sent or received datagrams that are larger than this value are rejected.
+  ///
+  /// Defaults to 65507.
+  pub max_datagram_size: usize,
+  /// Destination of the request. If `None`, the socket must be connected to a remote address.
+  pub remote_addr: Option<SocketAddr>,
+}
+
+#[derive(Debug)]
+#[doc = generic_trans_res_params_doc!("UDP")]
+pub struct UdpResParams {
+  /// Address of the sender of the received datagram.
+  pub remote_addr: Option<SocketAddr>,
+}
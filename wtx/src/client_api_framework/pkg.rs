@@ -3,15 +3,22 @@
 //! Groups all elements that interact with packages.
 
 mod batch_pkg;
+mod paginated;
 mod pkg_with_helper;
 mod pkgs_aux;
 
 use crate::{
-  client_api_framework::Api,
+  client_api_framework::{
+    Api,
+    misc::{ApiRateLimiter, PkgRetryPolicy},
+  },
   data_transformation::dnsn::De,
-  misc::{DecodeSeq, Encode, Vector},
+  misc::{DecodeSeq, Encode, GenericTime, Vector},
+  sync::Arc,
 };
-pub use batch_pkg::{BatchElems, BatchPkg};
+pub(crate) use batch_pkg::demux_batch_responses;
+pub use batch_pkg::{BatchElems, BatchJoiner, BatchPkg};
+pub use paginated::Paginated;
 pub use pkg_with_helper::*;
 pub use pkgs_aux::*;
 
@@ -71,6 +78,35 @@ where
 
   /// Similar to [`Self::pkg_params`] but returns a mutable reference instead.
   fn pkg_params_mut(&mut self) -> &mut Self::PackageParams;
+
+  /// Overrides [`crate::client_api_framework::pkg::PkgsAux::deadline`] for this package.
+  #[inline]
+  fn deadline(&self) -> Option<GenericTime> {
+    None
+  }
+
+  /// If sending this package more than once has the same effect of sending it a single time.
+  ///
+  /// Non-idempotent packages are only retried when the failure happened before the request was
+  /// sent.
+  #[inline]
+  fn is_idempotent(&self) -> bool {
+    true
+  }
+
+  /// Throttling component of the endpoint of this package, consulted after
+  /// [`Api::rate_limiter`]. Only the quota of requests is considered, concurrency is solely
+  /// controlled at the API level.
+  #[inline]
+  fn rate_limiter(&self) -> Option<&Arc<ApiRateLimiter>> {
+    None
+  }
+
+  /// Overrides [`crate::client_api_framework::pkg::PkgsAux::retry_policy`] for this package.
+  #[inline]
+  fn retry_policy(&self) -> Option<PkgRetryPolicy> {
+    None
+  }
 }
 
 impl<A, DRSR, T, TP> Package<A, DRSR, T, TP> for ()
@@ -129,6 +165,11 @@ where
     (**self).before_sending((api, bytes, drsr), (trans, trans_params)).await
   }
 
+  #[inline]
+  fn deadline(&self) -> Option<GenericTime> {
+    (**self).deadline()
+  }
+
   #[inline]
   fn ext_req_content(&self) -> &Self::ExternalRequestContent {
     (**self).ext_req_content()
@@ -139,6 +180,11 @@ where
     (**self).ext_req_content_mut()
   }
 
+  #[inline]
+  fn is_idempotent(&self) -> bool {
+    (**self).is_idempotent()
+  }
+
   #[inline]
   fn pkg_params(&self) -> &Self::PackageParams {
     (**self).pkg_params()
@@ -148,4 +194,14 @@ where
   fn pkg_params_mut(&mut self) -> &mut Self::PackageParams {
     (**self).pkg_params_mut()
   }
+
+  #[inline]
+  fn rate_limiter(&self) -> Option<&Arc<ApiRateLimiter>> {
+    (**self).rate_limiter()
+  }
+
+  #[inline]
+  fn retry_policy(&self) -> Option<PkgRetryPolicy> {
+    (**self).retry_policy()
+  }
 }
@@ -1,19 +1,90 @@
 use crate::{
-  client_api_framework::{Api, pkg::Package},
-  data_transformation::dnsn::De,
-  misc::{Encode, Vector},
+  client_api_framework::{Api, ClientApiFrameworkError, pkg::Package},
+  data_transformation::{Id, dnsn::De},
+  misc::{Encode, Lease, Vector},
 };
 use core::marker::PhantomData;
 
+/// Bytes that surround and separate the encoded requests of a [`BatchPkg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BatchJoiner {
+  /// Written before the first request.
+  pub prefix: &'static [u8],
+  /// Written between requests. Must not be present in the requests or in the responses.
+  pub separator: &'static [u8],
+  /// Written after the last request.
+  pub suffix: &'static [u8],
+}
+
+impl BatchJoiner {
+  /// `[a,b,c]`. Used by JSON-RPC.
+  pub const JSON_ARRAY: Self = Self { prefix: b"[", separator: b",", suffix: b"]" };
+  /// `a\nb\nc`, also known as NDJSON or JSON Lines.
+  pub const NEWLINE_DELIMITED: Self = Self { prefix: b"", separator: b"\n", suffix: b"" };
+
+  /// Splits a response that was joined in the same manner of the request, yielding the bytes of
+  /// each individual response.
+  ///
+  /// Shouldn't be used with [`Self::JSON_ARRAY`] because its separator can appear inside elements.
+  #[inline]
+  pub fn split<'bytes>(
+    &self,
+    bytes: &'bytes [u8],
+  ) -> crate::Result<impl Iterator<Item = &'bytes [u8]> + use<'bytes>> {
+    if self.separator.is_empty() {
+      return Err(ClientApiFrameworkError::EmptyFramingDelimiter.into());
+    }
+    let Some(mut rest) =
+      bytes.strip_prefix(self.prefix).and_then(|el| el.strip_suffix(self.suffix))
+    else {
+      return Err(ClientApiFrameworkError::InvalidBatchResponse.into());
+    };
+    let separator = self.separator;
+    let mut is_done = rest.is_empty();
+    Ok(core::iter::from_fn(move || {
+      if is_done {
+        return None;
+      }
+      match rest.windows(separator.len()).position(|el| el == separator) {
+        Some(idx) => {
+          let (lhs, rhs) = rest.split_at(idx);
+          rest = rhs.get(separator.len()..).unwrap_or_default();
+          Some(lhs)
+        }
+        None => {
+          is_done = true;
+          Some(rest)
+        }
+      }
+    }))
+  }
+}
+
+impl Default for BatchJoiner {
+  #[inline]
+  fn default() -> Self {
+    Self::JSON_ARRAY
+  }
+}
+
 /// Used to perform batch requests with multiple packages.
+///
+/// The requests are encoded individually and then joined according to a [`BatchJoiner`].
 #[derive(Debug)]
 pub struct BatchPkg<'slice, A, DRSR, P, T, TP>(BatchElems<'slice, A, DRSR, P, T, TP>, ());
 
 impl<'slice, A, DRSR, P, T, TP> BatchPkg<'slice, A, DRSR, P, T, TP> {
-  /// Currently, only slices of packages are allowed to perform batch requests.
+  /// Currently, only slices of packages are allowed to perform batch requests. Requests are joined
+  /// as a JSON array.
   #[inline]
   pub fn new(slice: &'slice mut [P]) -> Self {
-    Self(BatchElems(slice, PhantomData), ())
+    Self::with_joiner(slice, BatchJoiner::JSON_ARRAY)
+  }
+
+  /// Similar to [`Self::new`] but requests are joined according to `joiner`.
+  #[inline]
+  pub fn with_joiner(slice: &'slice mut [P], joiner: BatchJoiner) -> Self {
+    Self(BatchElems(slice, joiner, PhantomData), ())
   }
 }
 
@@ -74,7 +145,36 @@ where
 
 /// Used internally and exclusively by [BatchPkg]. Not intended for public usage.
 #[derive(Debug)]
-pub struct BatchElems<'slice, A, DRSR, P, T, TP>(&'slice mut [P], PhantomData<(A, DRSR, T, TP)>);
+pub struct BatchElems<'slice, A, DRSR, P, T, TP>(
+  &'slice mut [P],
+  #[allow(dead_code, reason = "Depends on selected features")] BatchJoiner,
+  PhantomData<(A, DRSR, T, TP)>,
+);
+
+/// Reorders `responses` so that each element is located at the same index of the package that
+/// originated it.
+pub(crate) fn demux_batch_responses<R>(
+  pkg_ids: impl ExactSizeIterator<Item = Id>,
+  responses: &mut [R],
+) -> crate::Result<()>
+where
+  R: Lease<Id>,
+{
+  let pkgs_len = pkg_ids.len();
+  for (idx, pkg_id) in pkg_ids.enumerate() {
+    let rest = responses.get_mut(idx..).unwrap_or_default();
+    let Some(pos) = rest.iter().position(|el| *el.lease() == pkg_id) else {
+      return Err(ClientApiFrameworkError::MissingBatchResponse(pkg_id).into());
+    };
+    rest.swap(0, pos);
+  }
+  if let Some(elem) = responses.get(pkgs_len) {
+    return Err(
+      ClientApiFrameworkError::ResponseIdIsNotPresentInTheOfSentBatchPackages(*elem.lease()).into(),
+    );
+  }
+  Ok(())
+}
 
 #[cfg(feature = "serde_json")]
 mod serde_json {
@@ -82,12 +182,12 @@ mod serde_json {
     client_api_framework::{
       Api,
       network::transport::Transport,
-      pkg::{BatchElems, Package},
+      pkg::{BatchElems, BatchJoiner, Package},
     },
     data_transformation::dnsn::{De, EncodeWrapper, SerdeJson},
     misc::Encode,
   };
-  use serde::Serializer as _;
+  use serde::Serialize as _;
 
   impl<A, DRSR, P, T, TP> Encode<De<SerdeJson>> for BatchElems<'_, A, DRSR, P, T, TP>
   where
@@ -98,9 +198,106 @@ mod serde_json {
   {
     #[inline]
     fn encode(&self, _: &mut SerdeJson, ew: &mut EncodeWrapper<'_>) -> crate::Result<()> {
-      serde_json::Serializer::new(&mut *ew.vector)
-        .collect_seq(self.0.iter().map(Package::ext_req_content))?;
+      let BatchJoiner { prefix, separator, suffix } = self.1;
+      ew.vector.extend_from_copyable_slice(prefix)?;
+      for (idx, elem) in self.0.iter().enumerate() {
+        if idx > 0 {
+          ew.vector.extend_from_copyable_slice(separator)?;
+        }
+        elem.ext_req_content().serialize(&mut serde_json::Serializer::new(&mut *ew.vector))?;
+      }
+      ew.vector.extend_from_copyable_slice(suffix)?;
       Ok(())
     }
   }
 }
+
+#[cfg(feature = "simd-json")]
+mod simd_json {
+  use crate::{
+    client_api_framework::{
+      Api,
+      network::transport::Transport,
+      pkg::{BatchElems, BatchJoiner, Package},
+    },
+    data_transformation::dnsn::{De, EncodeWrapper, SimdJson},
+    misc::Encode,
+  };
+
+  impl<A, DRSR, P, T, TP> Encode<De<SimdJson>> for BatchElems<'_, A, DRSR, P, T, TP>
+  where
+    A: Api,
+    P: Package<A, DRSR, T, TP>,
+    P::ExternalRequestContent: serde::Serialize,
+    T: Transport<TP>,
+  {
+    #[inline]
+    fn encode(&self, _: &mut SimdJson, ew: &mut EncodeWrapper<'_>) -> crate::Result<()> {
+      let BatchJoiner { prefix, separator, suffix } = self.1;
+      ew.vector.extend_from_copyable_slice(prefix)?;
+      for (idx, elem) in self.0.iter().enumerate() {
+        if idx > 0 {
+          ew.vector.extend_from_copyable_slice(separator)?;
+        }
+        simd_json::serde::to_writer(&mut *ew.vector, elem.ext_req_content())?;
+      }
+      ew.vector.extend_from_copyable_slice(suffix)?;
+      Ok(())
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      ClientApiFrameworkError,
+      pkg::{BatchJoiner, demux_batch_responses},
+    },
+    data_transformation::Id,
+    misc::Lease,
+  };
+  use alloc::vec::Vec;
+
+  #[derive(Debug, PartialEq)]
+  struct Res(Id);
+
+  impl Lease<Id> for Res {
+    fn lease(&self) -> &Id {
+      &self.0
+    }
+  }
+
+  #[test]
+  fn splits_joined_responses() {
+    let joiner = BatchJoiner { prefix: b"<", separator: b"--", suffix: b">" };
+    let parts: Vec<_> = joiner.split(b"<a--bc---d>").unwrap().collect();
+    assert_eq!(parts, [&b"a"[..], b"bc", b"-d"]);
+    assert_eq!(joiner.split(b"<>").unwrap().count(), 0);
+    assert!(joiner.split(b"a--b>").is_err());
+    assert!(joiner.split(b"<").is_err());
+    let parts: Vec<_> = BatchJoiner::NEWLINE_DELIMITED.split(b"1\n\n2").unwrap().collect();
+    assert_eq!(parts, [&b"1"[..], b"", b"2"]);
+  }
+
+  #[test]
+  fn demuxes_unordered_responses() {
+    let mut responses = [Res(3), Res(1), Res(2)];
+    demux_batch_responses([2, 3, 1].into_iter(), &mut responses).unwrap();
+    assert_eq!(responses, [Res(2), Res(3), Res(1)]);
+  }
+
+  #[test]
+  fn rejects_missing_or_unknown_responses() {
+    assert!(matches!(
+      demux_batch_responses([1, 2].into_iter(), &mut [Res(1)]),
+      Err(crate::Error::ClientApiFrameworkError(ClientApiFrameworkError::MissingBatchResponse(2)))
+    ));
+    assert!(matches!(
+      demux_batch_responses([1].into_iter(), &mut [Res(1), Res(4)]),
+      Err(crate::Error::ClientApiFrameworkError(
+        ClientApiFrameworkError::ResponseIdIsNotPresentInTheOfSentBatchPackages(4)
+      ))
+    ));
+  }
+}
@@ -0,0 +1,161 @@
+use crate::client_api_framework::{
+  Api,
+  network::transport::SendingReceivingTransport,
+  pkg::{Package, PkgsAux},
+};
+
+/// Walks paginated endpoints by sending the same package several times.
+///
+/// After each page, `next` receives the package and the decoded response to feed the cursor or
+/// offset of the next request. Returning `false` signals that the last page was reached.
+///
+/// ```rust,no_run
+/// # async fn fun() -> wtx::Result<()> {
+/// use wtx::client_api_framework::{
+///   network::transport::MockStr,
+///   pkg::{Paginated, PkgsAux},
+/// };
+/// let mut paginated = Paginated::new((), |_: &mut (), _: &()| false);
+/// let (pkgs_aux, trans) = (&mut PkgsAux::from_minimum((), (), ()), &mut MockStr::default());
+/// while let Some(_page) = paginated.next_page(trans, pkgs_aux).await? {}
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct Paginated<F, P> {
+  has_next: bool,
+  next: F,
+  pkg: P,
+}
+
+impl<F, P> Paginated<F, P> {
+  /// The first request is sent with the current contents of `pkg`.
+  #[inline]
+  pub const fn new(pkg: P, next: F) -> Self {
+    Self { has_next: true, next, pkg }
+  }
+
+  /// Package used by the next request.
+  #[inline]
+  pub fn pkg(&self) -> &P {
+    &self.pkg
+  }
+
+  /// Mutable version of [`Self::pkg`].
+  #[inline]
+  pub fn pkg_mut(&mut self) -> &mut P {
+    &mut self.pkg
+  }
+
+  /// Sends the package and returns the decoded page. Returns `None` when all pages were fetched.
+  ///
+  /// The contents of [`PkgsAux::byte_buffer`], which hold the previous page, are discarded.
+  #[inline]
+  pub async fn next_page<'de, A, DRSR, T, TP>(
+    &mut self,
+    trans: &mut T,
+    pkgs_aux: &'de mut PkgsAux<A, DRSR, TP>,
+  ) -> Result<Option<P::ExternalResponseContent<'de>>, A::Error>
+  where
+    A: Api,
+    F: FnMut(&mut P, &P::ExternalResponseContent<'de>) -> bool,
+    P: Package<A, DRSR, T::Inner, TP>,
+    T: SendingReceivingTransport<TP>,
+  {
+    if !self.has_next {
+      return Ok(None);
+    }
+    pkgs_aux.byte_buffer.clear();
+    let res = trans.send_pkg_recv_decode_contained(&mut self.pkg, pkgs_aux).await?;
+    self.has_next = (self.next)(&mut self.pkg, &res);
+    Ok(Some(res))
+  }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    client_api_framework::{
+      network::transport::{MockBytes, Transport},
+      pkg::{Package, Paginated, PkgsAux},
+    },
+    data_transformation::dnsn::{De, DecodeWrapper, EncodeWrapper},
+    misc::{Decode, DecodeSeq, Encode, Vector},
+  };
+  use alloc::borrow::Cow;
+
+  struct PagePkg(Cursor, ());
+
+  impl<T, TP> Package<(), (), T, TP> for PagePkg
+  where
+    T: Transport<TP>,
+  {
+    type ExternalRequestContent = Cursor;
+    type ExternalResponseContent<'de> = Page;
+    type PackageParams = ();
+
+    fn ext_req_content(&self) -> &Self::ExternalRequestContent {
+      &self.0
+    }
+
+    fn ext_req_content_mut(&mut self) -> &mut Self::ExternalRequestContent {
+      &mut self.0
+    }
+
+    fn pkg_params(&self) -> &Self::PackageParams {
+      &self.1
+    }
+
+    fn pkg_params_mut(&mut self) -> &mut Self::PackageParams {
+      &mut self.1
+    }
+  }
+
+  struct Cursor(u8);
+
+  impl Encode<De<()>> for Cursor {
+    fn encode(&self, _: &mut (), ew: &mut EncodeWrapper<'_>) -> crate::Result<()> {
+      ew.vector.push(self.0)?;
+      Ok(())
+    }
+  }
+
+  #[derive(Debug, PartialEq)]
+  struct Page(Option<u8>);
+
+  impl<'de> Decode<'de, De<()>> for Page {
+    fn decode(_: &mut (), dw: &mut DecodeWrapper<'de>) -> crate::Result<Self> {
+      Ok(Self(dw.bytes.first().copied()))
+    }
+  }
+
+  impl<'de> DecodeSeq<'de, De<()>> for Page {
+    fn decode_seq(
+      _: &mut (),
+      _: &mut Vector<Self>,
+      _: &mut DecodeWrapper<'de>,
+    ) -> crate::Result<()> {
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn walks_all_pages() {
+    let mut pkgs_aux = PkgsAux::from_minimum((), (), ());
+    let mut trans = MockBytes::default();
+    trans.push_response(Cow::Borrowed(&[7]));
+    trans.push_response(Cow::Borrowed(&[]));
+    let mut paginated = Paginated::new(PagePkg(Cursor(0), ()), |pkg: &mut PagePkg, res: &Page| {
+      let Some(cursor) = res.0 else {
+        return false;
+      };
+      pkg.0 = Cursor(cursor);
+      true
+    });
+    assert_eq!(paginated.next_page(&mut trans, &mut pkgs_aux).await.unwrap(), Some(Page(Some(7))));
+    assert_eq!(paginated.next_page(&mut trans, &mut pkgs_aux).await.unwrap(), Some(Page(None)));
+    assert_eq!(paginated.next_page(&mut trans, &mut pkgs_aux).await.unwrap(), None);
+    trans.assert_request(&[0]);
+    trans.assert_request(&[7]);
+    trans.assert_does_not_have_non_asserted_requests();
+  }
+}
@@ -1,10 +1,12 @@
 use crate::{
+  client_api_framework::misc::{PkgRetryBudget, PkgRetryPolicy},
   data_transformation::{
     Id,
-    format::{JsonRpcRequest, VerbatimRequest},
+    format::{GraphQlRequest, JsonRpcRequest, VerbatimRequest},
   },
-  misc::Vector,
+  misc::{GenericTime, Vector},
 };
+use core::time::Duration;
 
 /// # Packages Auxiliary
 ///
@@ -23,6 +25,12 @@ pub struct PkgsAux<A, DRSR, TP> {
   ///
   /// Some transports require a pre-filled buffer so it is important to not modify indiscriminately.
   pub byte_buffer: Vector<u8>,
+  /// Exchanges that don't finish before this instant fail with
+  /// [`crate::client_api_framework::ClientApiFrameworkError::DeadlineExceeded`]. Also propagated to
+  /// transports that support timeouts, like gRPC.
+  ///
+  /// Can be overridden by [`crate::client_api_framework::pkg::Package::deadline`].
+  pub deadline: Option<GenericTime>,
   /// Deserializer/Serializer instance
   pub drsr: DRSR,
   /// The second element is a back-up of the first element. Such a structure is used
@@ -30,22 +38,50 @@ pub struct PkgsAux<A, DRSR, TP> {
   ///
   /// See [Self::log_body]
   pub log_body: (bool, bool),
+  /// Shared limit of the retries of all packages. See [`PkgRetryBudget`].
+  pub retry_budget: Option<PkgRetryBudget>,
+  /// Retry policy of packages that don't define their own. See [`PkgRetryPolicy`].
+  pub retry_policy: Option<PkgRetryPolicy>,
   /// External request and response parameters.
   pub tp: TP,
   pub(crate) built_requests: Id,
+  // Length and encoding time of the last sent request.
+  pub(crate) exchange: (usize, Duration),
 }
 
 impl<A, DRSR, TP> PkgsAux<A, DRSR, TP> {
   /// Creates an instance with the minimum amount of mandatory parameters.
   #[inline]
   pub fn from_minimum(api: A, drsr: DRSR, tp: TP) -> Self {
-    Self { api, byte_buffer: Vector::new(), drsr, log_body: (false, false), tp, built_requests: 0 }
+    Self {
+      api,
+      byte_buffer: Vector::new(),
+      deadline: None,
+      drsr,
+      log_body: (false, false),
+      retry_budget: None,
+      retry_policy: None,
+      tp,
+      built_requests: 0,
+      exchange: (0, Duration::ZERO),
+    }
   }
 
   /// New instance
   #[inline]
   pub fn new(api: A, byte_buffer: Vector<u8>, drsr: DRSR, log_body: bool, tp: TP) -> Self {
-    Self { api, byte_buffer, drsr, log_body: (log_body, false), tp, built_requests: 0 }
+    Self {
+      api,
+      byte_buffer,
+      deadline: None,
+      drsr,
+      log_body: (log_body, false),
+      retry_budget: None,
+      retry_policy: None,
+      tp,
+      built_requests: 0,
+      exchange: (0, Duration::ZERO),
+    }
   }
 
   /// The number of constructed requests that is not necessarily equal the number of sent requests.
@@ -62,6 +98,18 @@ impl<A, DRSR, TP> PkgsAux<A, DRSR, TP> {
     self.log_body.0 = elem;
   }
 
+  /// Constructs [GraphQlRequest] without an operation name and also increases the number of
+  /// requests.
+  #[inline]
+  pub fn graph_ql_request<V>(
+    &mut self,
+    query: &'static str,
+    variables: V,
+  ) -> GraphQlRequest<&'static str, &'static str, V> {
+    self.increase_requests_num();
+    GraphQlRequest { operation_name: None, query, variables: Some(variables) }
+  }
+
   /// Constructs [JsonRpcRequest] and also increases the number of requests.
   #[inline]
   pub fn json_rpc_request<P>(&mut self, method: &'static str, params: P) -> JsonRpcRequest<P> {
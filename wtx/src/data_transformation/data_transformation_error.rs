@@ -1,9 +1,13 @@
-use crate::data_transformation::format::JsonRpcResponseError;
-use alloc::boxed::Box;
+use crate::{data_transformation::format::JsonRpcResponseError, misc::Vector};
+use alloc::{boxed::Box, string::String};
 
 /// Client API Framework Error
 #[derive(Debug)]
 pub enum DataTransformationError {
+  /// A CSV document doesn't contain any record.
+  EmptyCsvDocument,
+  /// Messages of all the errors returned in a `GraphQL` response
+  GraphQlResultErr(Box<Vector<String>>),
   /// JSON-RPC response error
   JsonRpcResultErr(Box<JsonRpcResponseError>),
   /// `wtx` can not perform this operation due to known limitations.
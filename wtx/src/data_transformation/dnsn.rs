@@ -9,15 +9,25 @@ mod tests;
 
 #[cfg(feature = "borsh")]
 mod borsh;
+#[cfg(feature = "ciborium")]
+mod cbor;
+#[cfg(feature = "csv")]
+mod csv;
 mod de;
 mod decode_wrapper;
 mod encode_wrapper;
+#[cfg(feature = "rmp-serde")]
+mod message_pack;
 #[cfg(feature = "quick-protobuf")]
 mod quick_protobuf;
 #[cfg(feature = "serde_json")]
 mod serde_json;
+#[cfg(feature = "simd-json")]
+mod simd_json;
 #[cfg(feature = "serde_urlencoded")]
 mod urlencoded;
+#[cfg(feature = "quick-xml")]
+mod xml;
 
 #[cfg(feature = "borsh")]
 pub use self::borsh::*;
@@ -25,8 +35,20 @@ pub use self::borsh::*;
 pub use self::quick_protobuf::*;
 #[cfg(feature = "serde_json")]
 pub use self::serde_json::*;
+#[cfg(feature = "ciborium")]
+pub use cbor::Cbor;
+#[cfg(feature = "csv")]
+pub use csv::Csv;
 pub use de::De;
 pub use decode_wrapper::DecodeWrapper;
 pub use encode_wrapper::EncodeWrapper;
+#[cfg(feature = "rmp-serde")]
+pub use message_pack::MessagePack;
+#[cfg(feature = "simd-json")]
+pub use simd_json::SimdJson;
 #[cfg(feature = "serde_urlencoded")]
 pub use urlencoded::Urlencoded;
+#[cfg(feature = "quick-xml")]
+pub(crate) use xml::XML_DECLARATION;
+#[cfg(feature = "quick-xml")]
+pub use xml::Xml;
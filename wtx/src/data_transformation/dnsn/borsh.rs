@@ -18,8 +18,66 @@ impl LeaseMut<Borsh> for Borsh {
   }
 }
 
+/// Wrapper that serializes its contents as the Base64 representation of their Borsh encoding,
+/// which is how binary payloads are usually embedded into the parameters of JSON-RPC requests.
+#[cfg(all(feature = "base64", feature = "serde"))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BorshBase64<T>(
+  /// Contents
+  pub T,
+);
+
+#[cfg(all(feature = "base64", feature = "serde"))]
+mod serde {
+  use crate::data_transformation::dnsn::BorshBase64;
+  use alloc::string::String;
+  use base64::{Engine, engine::general_purpose::STANDARD};
+  use borsh::{BorshDeserialize, BorshSerialize};
+  use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+  impl<'de, T> Deserialize<'de> for BorshBase64<T>
+  where
+    T: BorshDeserialize,
+  {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+      D: Deserializer<'de>,
+    {
+      let string = <String as Deserialize>::deserialize(deserializer)?;
+      let bytes = STANDARD.decode(string).map_err(serde::de::Error::custom)?;
+      Ok(Self(borsh::from_slice(&bytes).map_err(serde::de::Error::custom)?))
+    }
+  }
+
+  impl<T> Serialize for BorshBase64<T>
+  where
+    T: BorshSerialize,
+  {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+      S: Serializer,
+    {
+      let bytes = borsh::to_vec(&self.0).map_err(serde::ser::Error::custom)?;
+      serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
+  #[cfg(all(feature = "base64", feature = "client-api-framework", feature = "serde_json"))]
+  #[test]
+  fn borsh_base64() {
+    use crate::data_transformation::dnsn::{BorshBase64, tests::Bar};
+
+    let json = serde_json::to_string(&BorshBase64(Bar { bar: "bar".into() })).unwrap();
+    assert_eq!(json, r#""AwAAAGJhcg==""#);
+    let elem: BorshBase64<Bar> = serde_json::from_str(&json).unwrap();
+    assert_eq!(elem.0, Bar { bar: "bar".into() });
+  }
+
   _create_dnsn_test!(
     borsh,
     (VerbatimRequest, VerbatimResponse),
@@ -0,0 +1,39 @@
+use crate::misc::{Lease, LeaseMut};
+
+/// Type that indicates the usage of the `ciborium` dependency.
+///
+/// Concise Binary Object Representation, as defined in RFC 8949. Decoded elements can't borrow from
+/// the received bytes.
+#[derive(Debug, Default)]
+pub struct Cbor;
+
+impl Lease<Cbor> for Cbor {
+  #[inline]
+  fn lease(&self) -> &Cbor {
+    self
+  }
+}
+
+impl LeaseMut<Cbor> for Cbor {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut Cbor {
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  _create_dnsn_test!(
+    cbor,
+    (VerbatimRequest, VerbatimResponse),
+    Cbor as Cbor,
+    (
+      [161, 99, 102, 111, 111, 99, 102, 111, 111][..].into(),
+      [161, 99, 98, 97, 114, 99, 98, 97, 114][..].into()
+    ),
+    (
+      VerbatimRequest { data: Foo { foo: "foo" } },
+      VerbatimResponse { data: Bar { bar: "bar".into() } }
+    ),
+  );
+}
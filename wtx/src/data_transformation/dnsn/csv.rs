@@ -0,0 +1,142 @@
+use crate::misc::{Lease, LeaseMut, Vector};
+
+/// Type that indicates the usage of the `csv` dependency.
+///
+/// Sequences of records are encoded from slices or vectors and each decoded record is pushed as
+/// an individual element. When headers are present, fields are mapped by name.
+#[derive(Debug)]
+pub struct Csv {
+  delimiter: u8,
+  has_headers: bool,
+}
+
+impl Csv {
+  /// Comma-separated values preceded by a header record.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { delimiter: b',', has_headers: true }
+  }
+
+  /// Byte that separates fields.
+  #[inline]
+  #[must_use]
+  pub const fn delimiter(mut self, elem: u8) -> Self {
+    self.delimiter = elem;
+    self
+  }
+
+  /// If the first record contains the names of the fields. Without headers, fields are mapped by
+  /// position.
+  #[inline]
+  #[must_use]
+  pub const fn has_headers(mut self, elem: bool) -> Self {
+    self.has_headers = elem;
+    self
+  }
+
+  pub(crate) fn reader<'bytes>(&self, bytes: &'bytes [u8]) -> csv::Reader<&'bytes [u8]> {
+    csv::ReaderBuilder::new()
+      .delimiter(self.delimiter)
+      .has_headers(self.has_headers)
+      .from_reader(bytes)
+  }
+
+  pub(crate) fn write_records<'any, T>(
+    &self,
+    bytes: &mut Vector<u8>,
+    records: impl IntoIterator<Item = &'any T>,
+  ) -> crate::Result<()>
+  where
+    T: serde::Serialize + 'any,
+  {
+    let mut writer = csv::WriterBuilder::new()
+      .delimiter(self.delimiter)
+      .has_headers(self.has_headers)
+      .from_writer(bytes);
+    for record in records {
+      writer.serialize(record)?;
+    }
+    writer.flush()?;
+    Ok(())
+  }
+}
+
+impl Default for Csv {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Lease<Csv> for Csv {
+  #[inline]
+  fn lease(&self) -> &Csv {
+    self
+  }
+}
+
+impl LeaseMut<Csv> for Csv {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut Csv {
+    self
+  }
+}
+
+_impl_se_collections!(
+  (Csv, serde::Serialize),
+  array: |this, bytes, drsr| { drsr.write_records(bytes, this.iter())?; }
+  arrayvector: |this, bytes, drsr| { drsr.write_records(bytes, this.iter())?; }
+  slice_ref: |this, bytes, drsr| { drsr.write_records(bytes, this.iter())?; }
+  vec: |this, bytes, drsr| { drsr.write_records(bytes, this.iter())?; }
+);
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    data_transformation::{
+      dnsn::{Csv, De, DecodeWrapper, EncodeWrapper, tests::Bar},
+      format::VerbatimResponse,
+    },
+    misc::{DecodeSeq, Encode, Vector},
+  };
+
+  #[derive(Debug, serde::Serialize)]
+  struct Record {
+    id: u8,
+    name: &'static str,
+  }
+
+  #[test]
+  fn records() {
+    let records = [Record { id: 1, name: "a" }, Record { id: 2, name: "b" }];
+    let mut vector = Vector::new();
+    <_ as Encode<De<Csv>>>::encode(&records, &mut Csv::new(), &mut EncodeWrapper::new(&mut vector))
+      .unwrap();
+    assert_eq!(vector.as_slice(), b"id,name\n1,a\n2,b\n");
+    let mut buffer = Vector::new();
+    <VerbatimResponse<Bar> as DecodeSeq<'_, De<Csv>>>::decode_seq(
+      &mut Csv::new().delimiter(b';'),
+      &mut buffer,
+      &mut DecodeWrapper::new(b"baz;bar\n1;a\n2;b\n"),
+    )
+    .unwrap();
+    assert_eq!(
+      buffer.as_slice(),
+      [
+        VerbatimResponse { data: Bar { bar: "a".into() } },
+        VerbatimResponse { data: Bar { bar: "b".into() } }
+      ]
+    );
+  }
+
+  _create_dnsn_test!(
+    csv,
+    (VerbatimRequest, VerbatimResponse),
+    Csv as Csv::new(),
+    (b"foo\nfoo\n"[..].into(), b"bar\nbar\n"[..].into()),
+    (
+      VerbatimRequest { data: Foo { foo: "foo" } },
+      VerbatimResponse { data: Bar { bar: "bar".into() } }
+    ),
+  );
+}
@@ -0,0 +1,39 @@
+use crate::misc::{Lease, LeaseMut};
+
+/// Type that indicates the usage of the `rmp-serde` dependency.
+///
+/// Structures are encoded as maps with named fields, which keeps payloads compatible with their
+/// JSON counterparts. Both maps and arrays are accepted when decoding.
+#[derive(Debug, Default)]
+pub struct MessagePack;
+
+impl Lease<MessagePack> for MessagePack {
+  #[inline]
+  fn lease(&self) -> &MessagePack {
+    self
+  }
+}
+
+impl LeaseMut<MessagePack> for MessagePack {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut MessagePack {
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  _create_dnsn_test!(
+    message_pack,
+    (VerbatimRequest, VerbatimResponse),
+    MessagePack as MessagePack,
+    (
+      [129, 163, 102, 111, 111, 163, 102, 111, 111][..].into(),
+      [129, 163, 98, 97, 114, 163, 98, 97, 114][..].into()
+    ),
+    (
+      VerbatimRequest { data: Foo { foo: "foo" } },
+      VerbatimResponse { data: Bar { bar: "bar".into() } }
+    ),
+  );
+}
@@ -1,6 +1,10 @@
 use crate::misc::{Lease, LeaseMut};
 
 /// Type that indicates the usage of the `quick-protobuf` dependency.
+///
+/// Sequences are decoded from length-delimited messages, i.e., each message is preceded by its
+/// length encoded as a varint. Streams of such messages can be exchanged through
+/// `Framing::VarintLen`.
 #[derive(Debug, Default)]
 pub struct QuickProtobuf;
 
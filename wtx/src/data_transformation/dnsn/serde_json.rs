@@ -1,6 +1,11 @@
 use crate::misc::{Lease, LeaseMut};
 
 /// Type that indicates the usage of the `serde_json` dependency.
+///
+/// Decoded elements can borrow `&str` or `&[u8]` fields from the received bytes through the `'de`
+/// lifetime of `ExternalResponseContent`, which avoids allocations on large responses. Strings
+/// with escape sequences can't be borrowed, as such, `Cow<'de, str>` fields marked with
+/// `#[serde(borrow)]` should be used when escapes are possible.
 #[derive(Debug)]
 pub struct SerdeJson;
 
@@ -28,6 +33,60 @@ _impl_se_collections!(
 
 #[cfg(test)]
 mod tests {
+  use crate::{
+    data_transformation::{
+      dnsn::{De, DecodeWrapper, SerdeJson},
+      format::{JsonRpcResponse, VerbatimResponse},
+    },
+    misc::{Decode, DecodeSeq, Vector},
+  };
+  use alloc::borrow::Cow;
+
+  #[derive(Debug, PartialEq, serde::Deserialize)]
+  struct Baz<'any> {
+    bytes: &'any [u8],
+    #[serde(borrow)]
+    cow: Cow<'any, str>,
+    #[serde(borrow)]
+    escaped: Cow<'any, str>,
+    text: &'any str,
+  }
+
+  #[test]
+  fn borrows_from_received_bytes() {
+    let bytes = br#"{"bytes":"a","cow":"b","escaped":"c\"","text":"d"}"#;
+    let range = bytes.as_ptr_range();
+    let res = <VerbatimResponse<Baz<'_>> as Decode<'_, De<SerdeJson>>>::decode(
+      &mut SerdeJson,
+      &mut DecodeWrapper::new(bytes),
+    )
+    .unwrap();
+    assert_eq!(res.data.bytes, b"a");
+    assert!(range.contains(&res.data.bytes.as_ptr()));
+    assert!(matches!(res.data.cow, Cow::Borrowed("b")));
+    assert!(matches!(&res.data.escaped, Cow::Owned(elem) if elem == "c\""));
+    assert_eq!(res.data.text, "d");
+    assert!(range.contains(&res.data.text.as_ptr()));
+  }
+
+  #[test]
+  fn borrows_from_received_batch() {
+    let bytes = br#"[{"jsonrpc":"2.0","result":"a","id":1},{"jsonrpc":"2.0","result":"b","id":2}]"#;
+    let range = bytes.as_ptr_range();
+    let mut buffer = Vector::new();
+    <JsonRpcResponse<&str> as DecodeSeq<'_, De<SerdeJson>>>::decode_seq(
+      &mut SerdeJson,
+      &mut buffer,
+      &mut DecodeWrapper::new(bytes),
+    )
+    .unwrap();
+    for (elem, expected) in buffer.iter().zip(["a", "b"]) {
+      let result = *elem.result.as_ref().unwrap();
+      assert_eq!(result, expected);
+      assert!(range.contains(&result.as_ptr()));
+    }
+  }
+
   _create_dnsn_test!(
     json,
     (VerbatimRequest, VerbatimResponse),
@@ -0,0 +1,71 @@
+use crate::misc::{Lease, LeaseMut, Vector};
+
+/// Type that indicates the usage of the `simd-json` dependency.
+///
+/// Produces the same outputs of `SerdeJson` but parses with SIMD instructions, which is useful for
+/// high-throughput consumers. Parsing happens in-place, as such, the received bytes are copied to
+/// an internal buffer and decoded elements can't borrow from them.
+#[derive(Debug, Default)]
+pub struct SimdJson {
+  buffer: Vector<u8>,
+}
+
+impl SimdJson {
+  /// Creates a new instance with an empty internal buffer.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { buffer: Vector::new() }
+  }
+
+  pub(crate) fn copy_to_buffer(&mut self, bytes: &[u8]) -> crate::Result<&mut [u8]> {
+    self.buffer.clear();
+    self.buffer.extend_from_copyable_slice(bytes)?;
+    Ok(self.buffer.as_slice_mut())
+  }
+}
+
+impl Lease<SimdJson> for SimdJson {
+  #[inline]
+  fn lease(&self) -> &SimdJson {
+    self
+  }
+}
+
+impl LeaseMut<SimdJson> for SimdJson {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut SimdJson {
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  _create_dnsn_test!(
+    simd_json,
+    (VerbatimRequest, VerbatimResponse),
+    SimdJson as SimdJson::new(),
+    (r#"{"foo":"foo"}"#.into(), r#"{"bar":"bar"}"#.into()),
+    (
+      VerbatimRequest { data: Foo { foo: "foo" } },
+      VerbatimResponse { data: Bar { bar: "bar".into() } }
+    ),
+  );
+
+  _create_dnsn_test!(
+    json_rpc,
+    (JsonRpcRequest, JsonRpcResponse),
+    SimdJson as SimdJson::new(),
+    (
+      r#"{"jsonrpc":"2.0","method":"method","params":{"foo":"foo"},"id":0}"#.into(),
+      r#"{"jsonrpc":"2.0","method":"method","result":{"bar":"bar"},"id":0}"#.into()
+    ),
+    (
+      JsonRpcRequest { id: 0, method: "method", params: Foo { foo: "foo" } },
+      JsonRpcResponse {
+        id: 0,
+        method: Some("method".into()),
+        result: Ok(Bar { bar: "bar".into() })
+      }
+    ),
+  );
+}
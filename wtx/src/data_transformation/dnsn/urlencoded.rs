@@ -1,6 +1,9 @@
 use crate::misc::{Lease, LeaseMut};
 
 /// Type that indicates messages encoded or decoded with percent-encoding
+///
+/// Packages declared with the `urlencoded` data format also set the
+/// `application/x-www-form-urlencoded` MIME type of HTTP requests.
 #[derive(Debug, Default)]
 pub struct Urlencoded;
 
@@ -17,3 +20,17 @@ impl LeaseMut<Urlencoded> for Urlencoded {
     self
   }
 }
+
+#[cfg(test)]
+mod tests {
+  _create_dnsn_test!(
+    urlencoded,
+    (VerbatimRequest, VerbatimResponse),
+    Urlencoded as Urlencoded,
+    ("foo=foo".as_bytes().into(), "bar=bar".as_bytes().into()),
+    (
+      VerbatimRequest { data: Foo { foo: "foo" } },
+      VerbatimResponse { data: Bar { bar: "bar".into() } }
+    ),
+  );
+}
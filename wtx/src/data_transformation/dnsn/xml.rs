@@ -0,0 +1,127 @@
+use crate::misc::{Lease, LeaseMut};
+
+pub(crate) const XML_DECLARATION: &[u8] = br#"<?xml version="1.0" encoding="UTF-8"?>"#;
+
+/// Type that indicates the usage of the `quick-xml` dependency.
+///
+/// The root element is named after the encoded structure, which can be renamed to include a
+/// namespace prefix like `soap:Envelope`. Namespaces are declared through fields renamed to
+/// `@xmlns:prefix`, which are written as attributes.
+#[derive(Debug)]
+pub struct Xml {
+  declaration: bool,
+}
+
+impl Xml {
+  /// Documents are written without the XML declaration.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { declaration: false }
+  }
+
+  /// Writes `<?xml version="1.0" encoding="UTF-8"?>` before encoded documents. Usually required by
+  /// SOAP services.
+  #[inline]
+  #[must_use]
+  pub const fn declaration(mut self, elem: bool) -> Self {
+    self.declaration = elem;
+    self
+  }
+
+  pub(crate) const fn has_declaration(&self) -> bool {
+    self.declaration
+  }
+}
+
+impl Default for Xml {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Lease<Xml> for Xml {
+  #[inline]
+  fn lease(&self) -> &Xml {
+    self
+  }
+}
+
+impl LeaseMut<Xml> for Xml {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut Xml {
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    data_transformation::{
+      dnsn::{De, EncodeWrapper, Xml},
+      format::VerbatimRequest,
+    },
+    misc::{Encode, Vector},
+  };
+
+  #[derive(Debug, serde::Serialize)]
+  #[serde(rename = "soap:Envelope")]
+  struct Envelope {
+    #[serde(rename = "@xmlns:soap")]
+    xmlns: &'static str,
+    #[serde(rename = "soap:Body")]
+    body: Body,
+  }
+
+  #[derive(Debug, serde::Serialize)]
+  struct Body {
+    #[serde(rename = "m:GetPrice")]
+    get_price: GetPrice,
+  }
+
+  #[derive(Debug, serde::Serialize)]
+  struct GetPrice {
+    #[serde(rename = "@xmlns:m")]
+    xmlns: &'static str,
+    #[serde(rename = "m:Item")]
+    item: &'static str,
+  }
+
+  #[test]
+  fn envelope() {
+    let req = VerbatimRequest {
+      data: Envelope {
+        xmlns: "http://www.w3.org/2003/05/soap-envelope",
+        body: Body { get_price: GetPrice { xmlns: "https://example.com/prices", item: "Apples" } },
+      },
+    };
+    let mut vector = Vector::new();
+    <_ as Encode<De<Xml>>>::encode(
+      &req,
+      &mut Xml::new().declaration(true),
+      &mut EncodeWrapper::new(&mut vector),
+    )
+    .unwrap();
+    assert_eq!(
+      vector.as_slice(),
+      concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope"><soap:Body>"#,
+        r#"<m:GetPrice xmlns:m="https://example.com/prices"><m:Item>Apples</m:Item></m:GetPrice>"#,
+        r#"</soap:Body></soap:Envelope>"#
+      )
+      .as_bytes()
+    );
+  }
+
+  _create_dnsn_test!(
+    xml,
+    (VerbatimRequest, VerbatimResponse),
+    Xml as Xml::new(),
+    ("<Foo><foo>foo</foo></Foo>".as_bytes().into(), "<Bar><bar>bar</bar></Bar>".as_bytes().into()),
+    (
+      VerbatimRequest { data: Foo { foo: "foo" } },
+      VerbatimResponse { data: Bar { bar: "bar".into() } }
+    ),
+  );
+}
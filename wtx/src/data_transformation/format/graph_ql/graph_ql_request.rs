@@ -17,6 +17,10 @@ pub struct GraphQlRequest<ON, Q, V> {
   pub variables: Option<V>,
 }
 
+/// [`GraphQlRequest`] created by packages of the `graph_ql` data format, where the query is
+/// static and the request data represents the variables.
+pub type GraphQlPkgRequest<V> = GraphQlRequest<&'static str, &'static str, V>;
+
 impl<'de, ON, Q, V> Decode<'de, De<()>> for GraphQlRequest<ON, Q, V>
 where
   Q: Default,
@@ -1,10 +1,12 @@
 use crate::{
   data_transformation::{
+    DataTransformationError,
     dnsn::{De, DecodeWrapper, EncodeWrapper},
     format::GraphQlResponseError,
   },
   misc::{Decode, DecodeSeq, Encode, Vector},
 };
+use alloc::boxed::Box;
 
 /// Replied from an issued [`crate::data_transformation::format::GraphQlRequest`].
 #[derive(Debug)]
@@ -13,6 +15,29 @@ pub struct GraphQlResponse<D, E> {
   pub result: Result<D, Vector<GraphQlResponseError<E>>>,
 }
 
+/// [`GraphQlResponse`] expected by packages of the `graph_ql` data format. Extensions are kept
+/// as raw JSON values.
+#[cfg(feature = "serde_json")]
+pub type GraphQlPkgResponse<D> = GraphQlResponse<D, ::serde_json::Value>;
+
+impl<D, E> GraphQlResponse<D, E> {
+  /// Returns the data of a successful response or an error containing the messages of all
+  /// returned errors.
+  #[inline]
+  pub fn into_data(self) -> crate::Result<D> {
+    match self.result {
+      Ok(elem) => Ok(elem),
+      Err(errors) => {
+        let mut messages = Vector::with_capacity(errors.len())?;
+        for error in errors {
+          messages.push(error.message)?;
+        }
+        Err(DataTransformationError::GraphQlResultErr(Box::new(messages)).into())
+      }
+    }
+  }
+}
+
 impl<'de, D, E> Decode<'de, De<()>> for GraphQlResponse<D, E>
 where
   D: Default,
@@ -184,3 +209,38 @@ mod serde_json {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    data_transformation::{
+      DataTransformationError,
+      format::{GraphQlResponse, GraphQlResponseError},
+    },
+    misc::Vector,
+  };
+
+  #[test]
+  fn into_data() {
+    assert_eq!(GraphQlResponse::<u8, ()> { result: Ok(1) }.into_data().unwrap(), 1);
+    let mut errors = Vector::new();
+    for message in ["foo", "bar"] {
+      errors
+        .push(GraphQlResponseError::<()> {
+          extensions: None,
+          locations: None,
+          message: message.into(),
+          path: None,
+        })
+        .unwrap();
+    }
+    let res = GraphQlResponse::<u8, ()> { result: Err(errors) };
+    let Err(crate::Error::DataTransformationError(DataTransformationError::GraphQlResultErr(
+      messages,
+    ))) = res.into_data()
+    else {
+      panic!();
+    };
+    assert_eq!(messages.as_slice(), ["foo", "bar"]);
+  }
+}
@@ -140,3 +140,17 @@ mod serde_json {
     }
   }
 }
+
+#[cfg(feature = "simd-json")]
+mod simd_json {
+  use crate::data_transformation::{dnsn::SimdJson, format::JsonRpcRequest};
+  use serde::Serialize;
+
+  _impl_enc! {
+    JsonRpcRequest<R: Serialize>,
+    SimdJson,
+    |this, _aux, ew| {
+      simd_json::serde::to_writer(&mut *ew.vector, this)?;
+    }
+  }
+}
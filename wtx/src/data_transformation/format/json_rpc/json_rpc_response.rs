@@ -1,7 +1,8 @@
 use crate::{
   data_transformation::{
-    Id,
+    DataTransformationError, Id,
     dnsn::{De, DecodeWrapper, EncodeWrapper},
+    format::JsonRpcResponseError,
   },
   misc::{Decode, DecodeSeq, Encode, Lease, Vector},
 };
@@ -25,6 +26,26 @@ pub struct JsonRpcResponse<R> {
   pub result: crate::Result<R>,
 }
 
+impl<R> JsonRpcResponse<R> {
+  /// Converts the contained result into a result with an error type defined by the API.
+  ///
+  /// Errors returned by the counterpart are converted from [`JsonRpcResponseError`], which
+  /// preserves their code, message and data. Other errors are converted from [`crate::Error`].
+  #[inline]
+  pub fn into_result<E>(self) -> Result<R, E>
+  where
+    E: From<crate::Error> + From<JsonRpcResponseError>,
+  {
+    match self.result {
+      Ok(elem) => Ok(elem),
+      Err(crate::Error::DataTransformationError(DataTransformationError::JsonRpcResultErr(
+        elem,
+      ))) => Err(E::from(*elem)),
+      Err(err) => Err(E::from(err)),
+    }
+  }
+}
+
 impl<P> Borrow<Id> for JsonRpcResponse<P> {
   #[inline]
   fn borrow(&self) -> &Id {
@@ -280,3 +301,81 @@ mod serde_json {
     }
   }
 }
+
+#[cfg(feature = "simd-json")]
+mod simd_json {
+  use crate::data_transformation::{
+    dnsn::SimdJson,
+    format::{JsonRpcResponse, misc::collect_using_serde},
+  };
+  use serde::{Serialize, de::DeserializeOwned};
+
+  _impl_dec! {
+    JsonRpcResponse<R: DeserializeOwned>,
+    SimdJson,
+    |aux, dw| {
+      Ok(simd_json::serde::from_slice(aux.copy_to_buffer(dw.bytes)?)?)
+    }
+  }
+
+  _impl_dec_seq! {
+    JsonRpcResponse<R: DeserializeOwned>,
+    SimdJson,
+    |aux, buffer, dw| {
+      let bytes = aux.copy_to_buffer(dw.bytes)?;
+      collect_using_serde(buffer, &mut simd_json::Deserializer::from_slice(bytes)?)?;
+      Ok(())
+    }
+  }
+
+  _impl_enc! {
+    JsonRpcResponse<R: Serialize>,
+    SimdJson,
+    |this, _aux, ew| {
+      simd_json::serde::to_writer(&mut *ew.vector, this)?;
+    }
+  }
+}
+
+#[cfg(all(feature = "serde_json", test))]
+mod tests {
+  use crate::data_transformation::format::{JsonRpcResponse, JsonRpcResponseError};
+
+  #[derive(Debug)]
+  enum ApiError {
+    Other,
+    Rpc(i32, Option<u8>),
+  }
+
+  impl From<crate::Error> for ApiError {
+    fn from(_: crate::Error) -> Self {
+      Self::Other
+    }
+  }
+
+  impl From<JsonRpcResponseError> for ApiError {
+    fn from(from: JsonRpcResponseError) -> Self {
+      let data = from.data_as::<Data>().unwrap().map(|el| el.retry_after);
+      Self::Rpc(from.code, data)
+    }
+  }
+
+  #[derive(serde::Deserialize)]
+  struct Data {
+    retry_after: u8,
+  }
+
+  #[test]
+  fn into_result() {
+    let res: JsonRpcResponse<u8> = serde_json::from_str(
+      r#"{"jsonrpc":"2.0","error":{"code":-32005,"message":"Limit","data":{"retry_after":3}},"id":1}"#,
+    )
+    .unwrap();
+    assert!(matches!(res.into_result::<ApiError>(), Err(ApiError::Rpc(-32005, Some(3)))));
+    let res =
+      JsonRpcResponse::<u8> { id: 1, method: None, result: Err(crate::Error::ClosedConnection) };
+    assert!(matches!(res.into_result::<ApiError>(), Err(ApiError::Other)));
+    let res = JsonRpcResponse { id: 1, method: None, result: Ok(2u8) };
+    assert_eq!(res.into_result::<ApiError>().unwrap(), 2);
+  }
+}
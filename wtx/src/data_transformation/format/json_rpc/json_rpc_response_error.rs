@@ -12,3 +12,19 @@ pub struct JsonRpcResponseError {
   /// Short description of the error.
   pub message: Box<str>,
 }
+
+impl JsonRpcResponseError {
+  /// Deserializes the additional information of the error into a type defined by the API, which
+  /// can borrow from `self`.
+  #[cfg(feature = "serde_json")]
+  #[inline]
+  pub fn data_as<'this, T>(&'this self) -> crate::Result<Option<T>>
+  where
+    T: serde::Deserialize<'this>,
+  {
+    match &self.data {
+      Some(elem) => Ok(Some(T::deserialize(elem)?)),
+      None => Ok(None),
+    }
+  }
+}
@@ -1,8 +1,10 @@
+#[cfg(any(feature = "rmp-serde", feature = "serde_json", feature = "simd-json"))]
+pub(crate) use serde::collect_using_serde;
 #[cfg(feature = "serde_json")]
 pub(crate) use serde_json::collect_using_serde_json;
 
-#[cfg(feature = "serde_json")]
-mod serde_json {
+#[cfg(any(feature = "rmp-serde", feature = "serde_json", feature = "simd-json"))]
+mod serde {
   use crate::misc::Vector;
   use core::{any::type_name, fmt::Formatter};
   use serde::{
@@ -10,11 +12,13 @@ mod serde_json {
     de::{Deserializer, Error, SeqAccess, Visitor},
   };
 
-  pub(crate) fn collect_using_serde_json<'de, T>(
+  /// Pushes all the elements of the sequence provided by `deserializer` into `buffer`.
+  pub(crate) fn collect_using_serde<'de, D, T>(
     buffer: &mut Vector<T>,
-    bytes: &mut &'de [u8],
-  ) -> crate::Result<()>
+    deserializer: D,
+  ) -> Result<(), D::Error>
   where
+    D: Deserializer<'de>,
     T: Deserialize<'de>,
   {
     struct Buffer<'any, T>(&'any mut Vector<T>);
@@ -45,7 +49,23 @@ mod serde_json {
       }
     }
 
-    serde_json::Deserializer::from_slice(bytes).deserialize_seq(Buffer(buffer))?;
+    deserializer.deserialize_seq(Buffer(buffer))
+  }
+}
+
+#[cfg(feature = "serde_json")]
+mod serde_json {
+  use crate::{data_transformation::format::misc::collect_using_serde, misc::Vector};
+  use serde::Deserialize;
+
+  pub(crate) fn collect_using_serde_json<'de, T>(
+    buffer: &mut Vector<T>,
+    bytes: &mut &'de [u8],
+  ) -> crate::Result<()>
+  where
+    T: Deserialize<'de>,
+  {
+    collect_using_serde(buffer, &mut serde_json::Deserializer::from_slice(bytes))?;
     Ok(())
   }
 
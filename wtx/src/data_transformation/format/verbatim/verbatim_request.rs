@@ -69,11 +69,112 @@ mod borsh {
   }
 }
 
-#[cfg(feature = "quick-protobuf")]
-mod quick_protobuf {
+#[cfg(feature = "ciborium")]
+mod cbor {
+  use crate::{
+    data_transformation::{dnsn::Cbor, format::VerbatimRequest},
+    misc::Vector,
+  };
+  use serde::{Serialize, de::DeserializeOwned};
+
+  _impl_dec! {
+    VerbatimRequest<D: DeserializeOwned>,
+    Cbor,
+    |_aux, dw| {
+      Ok(ciborium::from_reader(dw.bytes)?)
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimRequest<D: DeserializeOwned>,
+    Cbor,
+    |_aux, buffer, dw| {
+      buffer.extend_from_iter(ciborium::from_reader::<Vector<Self>, _>(dw.bytes)?)
+    }
+  }
+
+  _impl_enc! {
+    VerbatimRequest<D: Serialize>,
+    Cbor,
+    |this, _aux, ew| {
+      ciborium::into_writer(&this.data, &mut *ew.vector)?;
+    }
+  }
+}
+
+#[cfg(feature = "csv")]
+mod csv {
+  use crate::data_transformation::{DataTransformationError, dnsn::Csv, format::VerbatimRequest};
+  use serde::{Serialize, de::DeserializeOwned};
+
+  _impl_dec! {
+    VerbatimRequest<D: DeserializeOwned>,
+    Csv,
+    |aux, dw| {
+      match aux.reader(dw.bytes).deserialize().next() {
+        Some(elem) => Ok(elem?),
+        None => Err(DataTransformationError::EmptyCsvDocument.into()),
+      }
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimRequest<D: DeserializeOwned>,
+    Csv,
+    |aux, buffer, dw| {
+      for elem in aux.reader(dw.bytes).deserialize() {
+        buffer.push(elem?)?;
+      }
+      Ok(())
+    }
+  }
+
+  _impl_enc! {
+    VerbatimRequest<D: Serialize>,
+    Csv,
+    |this, aux, ew| {
+      aux.write_records(ew.vector, [&this.data])?;
+    }
+  }
+}
+
+#[cfg(feature = "rmp-serde")]
+mod message_pack {
   use crate::data_transformation::{
-    DataTransformationError, dnsn::QuickProtobuf, format::VerbatimRequest,
+    dnsn::MessagePack,
+    format::{VerbatimRequest, misc::collect_using_serde},
   };
+  use serde::{Deserialize, Serialize};
+
+  _impl_dec! {
+    VerbatimRequest<D: Deserialize<'de>>,
+    MessagePack,
+    |_aux, dw| {
+      Ok(rmp_serde::from_slice(dw.bytes)?)
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimRequest<D: Deserialize<'de>>,
+    MessagePack,
+    |_aux, buffer, dw| {
+      collect_using_serde(buffer, &mut rmp_serde::Deserializer::from_read_ref(dw.bytes))?;
+      Ok(())
+    }
+  }
+
+  _impl_enc! {
+    VerbatimRequest<D: Serialize>,
+    MessagePack,
+    |this, _aux, ew| {
+      rmp_serde::encode::write_named(&mut *ew.vector, &this.data)?;
+    }
+  }
+}
+
+#[cfg(feature = "quick-protobuf")]
+mod quick_protobuf {
+  use crate::data_transformation::{dnsn::QuickProtobuf, format::VerbatimRequest};
   use quick_protobuf::{BytesReader, MessageRead, MessageWrite, Writer};
 
   _impl_dec! {
@@ -87,8 +188,12 @@ mod quick_protobuf {
   _impl_dec_seq! {
     VerbatimRequest<D: MessageRead<'de>>,
     QuickProtobuf,
-    |_aux, _buffer, _dw| {
-      Err(DataTransformationError::UnsupportedOperation.into())
+    |_aux, buffer, dw| {
+      let mut reader = BytesReader::from_bytes(dw.bytes);
+      while !reader.is_eof() {
+        buffer.push(Self { data: reader.read_message(dw.bytes)? })?;
+      }
+      Ok(())
     }
   }
 
@@ -134,6 +239,41 @@ mod serde_json {
   }
 }
 
+#[cfg(feature = "simd-json")]
+mod simd_json {
+  use crate::data_transformation::{
+    dnsn::SimdJson,
+    format::{VerbatimRequest, misc::collect_using_serde},
+  };
+  use serde::{Serialize, de::DeserializeOwned};
+
+  _impl_dec! {
+    VerbatimRequest<D: DeserializeOwned>,
+    SimdJson,
+    |aux, dw| {
+      Ok(simd_json::serde::from_slice(aux.copy_to_buffer(dw.bytes)?)?)
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimRequest<D: DeserializeOwned>,
+    SimdJson,
+    |aux, buffer, dw| {
+      let bytes = aux.copy_to_buffer(dw.bytes)?;
+      collect_using_serde(buffer, &mut simd_json::Deserializer::from_slice(bytes)?)?;
+      Ok(())
+    }
+  }
+
+  _impl_enc! {
+    VerbatimRequest<D: Serialize>,
+    SimdJson,
+    |this, _aux, ew| {
+      simd_json::serde::to_writer(&mut *ew.vector, &this.data)?;
+    }
+  }
+}
+
 #[cfg(feature = "serde_urlencoded")]
 mod urlencoded {
   use crate::data_transformation::{
@@ -165,3 +305,43 @@ mod urlencoded {
     }
   }
 }
+
+#[cfg(feature = "quick-xml")]
+mod xml {
+  use crate::{
+    data_transformation::{
+      DataTransformationError,
+      dnsn::{XML_DECLARATION, Xml},
+      format::VerbatimRequest,
+    },
+    misc::from_utf8_basic,
+  };
+  use serde::{Deserialize, Serialize};
+
+  _impl_dec! {
+    VerbatimRequest<D: Deserialize<'de>>,
+    Xml,
+    |_aux, dw| {
+      Ok(Self { data: quick_xml::de::from_str(from_utf8_basic(dw.bytes)?)? })
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimRequest<D: Deserialize<'de>>,
+    Xml,
+    |_aux, _buffer, _dw| {
+      Err(DataTransformationError::UnsupportedOperation.into())
+    }
+  }
+
+  _impl_enc! {
+    VerbatimRequest<D: Serialize>,
+    Xml,
+    |this, aux, ew| {
+      if aux.has_declaration() {
+        ew.vector.extend_from_copyable_slice(XML_DECLARATION)?;
+      }
+      let _ = quick_xml::se::to_writer(&mut *ew.vector, &this.data)?;
+    }
+  }
+}
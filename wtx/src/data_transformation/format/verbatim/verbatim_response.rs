@@ -71,11 +71,112 @@ mod borsh {
   }
 }
 
-#[cfg(feature = "quick-protobuf")]
-mod quick_protobuf {
+#[cfg(feature = "ciborium")]
+mod cbor {
+  use crate::{
+    data_transformation::{dnsn::Cbor, format::VerbatimResponse},
+    misc::Vector,
+  };
+  use serde::{Serialize, de::DeserializeOwned};
+
+  _impl_dec! {
+    VerbatimResponse<D: DeserializeOwned>,
+    Cbor,
+    |_aux, dw| {
+      Ok(ciborium::from_reader(dw.bytes)?)
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimResponse<D: DeserializeOwned>,
+    Cbor,
+    |_aux, buffer, dw| {
+      buffer.extend_from_iter(ciborium::from_reader::<Vector<Self>, _>(dw.bytes)?)
+    }
+  }
+
+  _impl_enc! {
+    VerbatimResponse<D: Serialize>,
+    Cbor,
+    |this, _aux, ew| {
+      ciborium::into_writer(&this.data, &mut *ew.vector)?;
+    }
+  }
+}
+
+#[cfg(feature = "csv")]
+mod csv {
+  use crate::data_transformation::{DataTransformationError, dnsn::Csv, format::VerbatimResponse};
+  use serde::{Serialize, de::DeserializeOwned};
+
+  _impl_dec! {
+    VerbatimResponse<D: DeserializeOwned>,
+    Csv,
+    |aux, dw| {
+      match aux.reader(dw.bytes).deserialize().next() {
+        Some(elem) => Ok(elem?),
+        None => Err(DataTransformationError::EmptyCsvDocument.into()),
+      }
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimResponse<D: DeserializeOwned>,
+    Csv,
+    |aux, buffer, dw| {
+      for elem in aux.reader(dw.bytes).deserialize() {
+        buffer.push(elem?)?;
+      }
+      Ok(())
+    }
+  }
+
+  _impl_enc! {
+    VerbatimResponse<D: Serialize>,
+    Csv,
+    |this, aux, ew| {
+      aux.write_records(ew.vector, [&this.data])?;
+    }
+  }
+}
+
+#[cfg(feature = "rmp-serde")]
+mod message_pack {
   use crate::data_transformation::{
-    DataTransformationError, dnsn::QuickProtobuf, format::VerbatimResponse,
+    dnsn::MessagePack,
+    format::{VerbatimResponse, misc::collect_using_serde},
   };
+  use serde::{Deserialize, Serialize};
+
+  _impl_dec! {
+    VerbatimResponse<D: Deserialize<'de>>,
+    MessagePack,
+    |_aux, dw| {
+      Ok(rmp_serde::from_slice(dw.bytes)?)
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimResponse<D: Deserialize<'de>>,
+    MessagePack,
+    |_aux, buffer, dw| {
+      collect_using_serde(buffer, &mut rmp_serde::Deserializer::from_read_ref(dw.bytes))?;
+      Ok(())
+    }
+  }
+
+  _impl_enc! {
+    VerbatimResponse<D: Serialize>,
+    MessagePack,
+    |this, _aux, ew| {
+      rmp_serde::encode::write_named(&mut *ew.vector, &this.data)?;
+    }
+  }
+}
+
+#[cfg(feature = "quick-protobuf")]
+mod quick_protobuf {
+  use crate::data_transformation::{dnsn::QuickProtobuf, format::VerbatimResponse};
   use quick_protobuf::{BytesReader, MessageRead, MessageWrite, Writer};
 
   _impl_dec! {
@@ -89,8 +190,12 @@ mod quick_protobuf {
   _impl_dec_seq! {
     VerbatimResponse<D: MessageRead<'de>>,
     QuickProtobuf,
-    |_aux, _buffer, _dw| {
-      Err(DataTransformationError::UnsupportedOperation.into())
+    |_aux, buffer, dw| {
+      let mut reader = BytesReader::from_bytes(dw.bytes);
+      while !reader.is_eof() {
+        buffer.push(Self { data: reader.read_message(dw.bytes)? })?;
+      }
+      Ok(())
     }
   }
 
@@ -136,6 +241,41 @@ mod serde_json {
   }
 }
 
+#[cfg(feature = "simd-json")]
+mod simd_json {
+  use crate::data_transformation::{
+    dnsn::SimdJson,
+    format::{VerbatimResponse, misc::collect_using_serde},
+  };
+  use serde::{Serialize, de::DeserializeOwned};
+
+  _impl_dec! {
+    VerbatimResponse<D: DeserializeOwned>,
+    SimdJson,
+    |aux, dw| {
+      Ok(simd_json::serde::from_slice(aux.copy_to_buffer(dw.bytes)?)?)
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimResponse<D: DeserializeOwned>,
+    SimdJson,
+    |aux, buffer, dw| {
+      let bytes = aux.copy_to_buffer(dw.bytes)?;
+      collect_using_serde(buffer, &mut simd_json::Deserializer::from_slice(bytes)?)?;
+      Ok(())
+    }
+  }
+
+  _impl_enc! {
+    VerbatimResponse<D: Serialize>,
+    SimdJson,
+    |this, _aux, ew| {
+      simd_json::serde::to_writer(&mut *ew.vector, &this.data)?;
+    }
+  }
+}
+
 #[cfg(feature = "serde_urlencoded")]
 mod urlencoded {
   use crate::data_transformation::{
@@ -167,3 +307,43 @@ mod urlencoded {
     }
   }
 }
+
+#[cfg(feature = "quick-xml")]
+mod xml {
+  use crate::{
+    data_transformation::{
+      DataTransformationError,
+      dnsn::{XML_DECLARATION, Xml},
+      format::VerbatimResponse,
+    },
+    misc::from_utf8_basic,
+  };
+  use serde::{Deserialize, Serialize};
+
+  _impl_dec! {
+    VerbatimResponse<D: Deserialize<'de>>,
+    Xml,
+    |_aux, dw| {
+      Ok(Self { data: quick_xml::de::from_str(from_utf8_basic(dw.bytes)?)? })
+    }
+  }
+
+  _impl_dec_seq! {
+    VerbatimResponse<D: Deserialize<'de>>,
+    Xml,
+    |_aux, _buffer, _dw| {
+      Err(DataTransformationError::UnsupportedOperation.into())
+    }
+  }
+
+  _impl_enc! {
+    VerbatimResponse<D: Serialize>,
+    Xml,
+    |this, aux, ew| {
+      if aux.has_declaration() {
+        ew.vector.extend_from_copyable_slice(XML_DECLARATION)?;
+      }
+      let _ = quick_xml::se::to_writer(&mut *ew.vector, &this.data)?;
+    }
+  }
+}
@@ -148,7 +148,7 @@ macro_rules! _impl_se_collections {
         T: $bound,
       {
         #[inline]
-        fn encode(&self, $array_drsr: &mut SerdeJson, ew: &mut crate::data_transformation::dnsn::EncodeWrapper<'_>) -> crate::Result<()> {
+        fn encode(&self, $array_drsr: &mut $drsr, ew: &mut crate::data_transformation::dnsn::EncodeWrapper<'_>) -> crate::Result<()> {
           let $array_self = self;
           let $array_bytes = &mut *ew.vector;
           $array_block;
@@ -163,7 +163,7 @@ macro_rules! _impl_se_collections {
         T: $bound,
       {
         #[inline]
-        fn encode(&self, $arrayvector_drsr: &mut SerdeJson, ew: &mut crate::data_transformation::dnsn::EncodeWrapper<'_>) -> crate::Result<()> {
+        fn encode(&self, $arrayvector_drsr: &mut $drsr, ew: &mut crate::data_transformation::dnsn::EncodeWrapper<'_>) -> crate::Result<()> {
           let $arrayvector_self = self;
           let $arrayvector_bytes = &mut *ew.vector;
           $arrayvector_block;
@@ -177,7 +177,7 @@ macro_rules! _impl_se_collections {
       T: $bound,
     {
       #[inline]
-      fn encode(&self, $slice_ref_drsr: &mut SerdeJson, ew: &mut crate::data_transformation::dnsn::EncodeWrapper<'_>) -> crate::Result<()> {
+      fn encode(&self, $slice_ref_drsr: &mut $drsr, ew: &mut crate::data_transformation::dnsn::EncodeWrapper<'_>) -> crate::Result<()> {
         let $slice_ref_self = self;
         let $slice_ref_bytes = &mut *ew.vector;
         $slice_ref_block;
@@ -190,7 +190,7 @@ macro_rules! _impl_se_collections {
       T: $bound,
     {
       #[inline]
-      fn encode(&self, $vec_drsr: &mut SerdeJson, ew: &mut crate::data_transformation::dnsn::EncodeWrapper<'_>) -> crate::Result<()> {
+      fn encode(&self, $vec_drsr: &mut $drsr, ew: &mut crate::data_transformation::dnsn::EncodeWrapper<'_>) -> crate::Result<()> {
         let $vec_self = self;
         let $vec_bytes = &mut *ew.vector;
         $vec_block;
@@ -14,6 +14,8 @@ mod message;
 mod msg_field;
 mod postgres_error;
 mod postgres_executor;
+#[cfg(feature = "metrics")]
+mod postgres_metrics;
 mod postgres_record;
 mod postgres_records;
 mod protocol;
@@ -44,6 +46,8 @@ pub use encode_wrapper::EncodeWrapper;
 pub use executor_buffer::ExecutorBuffer;
 pub use postgres_error::PostgresError;
 pub use postgres_executor::PostgresExecutor;
+#[cfg(feature = "metrics")]
+pub use postgres_metrics::PostgresMetrics;
 pub use postgres_record::PostgresRecord;
 pub use postgres_records::PostgresRecords;
 pub use sql_state::SqlState;
@@ -9,7 +9,11 @@ pub struct Config<'data> {
   pub(crate) application_name: &'data str,
   pub(crate) channel_binding: ChannelBinding,
   pub(crate) db: &'data str,
+  pub(crate) host: &'data str,
   pub(crate) password: &'data str,
+  pub(crate) ssl_cert: &'data str,
+  pub(crate) ssl_key: &'data str,
+  pub(crate) ssl_mode: SslMode,
   pub(crate) user: &'data str,
 }
 
@@ -18,14 +22,34 @@ impl<'data> Config<'data> {
   #[inline]
   pub fn from_uri(uri: &'data UriRef<'_>) -> crate::Result<Config<'data>> {
     let db = uri.path().get(1..).unwrap_or_default();
+    let host = uri.hostname();
     let password = uri.password();
     let user = uri.user();
-    let mut this =
-      Self { application_name: "", channel_binding: ChannelBinding::Prefer, db, password, user };
+    let mut this = Self {
+      application_name: "",
+      channel_binding: ChannelBinding::Prefer,
+      db,
+      host,
+      password,
+      ssl_cert: "",
+      ssl_key: "",
+      ssl_mode: SslMode::Prefer,
+      user,
+    };
     query_walker(uri, |key, value| this.set_param(key, value))?;
     Ok(this)
   }
 
+  /// Paths of the PEM-encoded client certificate and of its PEM-encoded private key, as specified
+  /// by the `sslcert` and `sslkey` parameters. Used for mutual TLS.
+  #[inline]
+  pub fn ssl_cert_and_key(&self) -> Option<(&'data str, &'data str)> {
+    if self.ssl_cert.is_empty() || self.ssl_key.is_empty() {
+      return None;
+    }
+    Some((self.ssl_cert, self.ssl_key))
+  }
+
   #[inline]
   fn set_param(&mut self, key: &str, value: &'data str) -> crate::Result<()> {
     match key {
@@ -41,6 +65,23 @@ impl<'data> Config<'data> {
         };
         self.channel_binding = channel_binding;
       }
+      "sslcert" => {
+        self.ssl_cert = value;
+      }
+      "sslkey" => {
+        self.ssl_key = value;
+      }
+      "sslmode" => {
+        let ssl_mode = match value {
+          "disable" => SslMode::Disable,
+          "prefer" => SslMode::Prefer,
+          "require" => SslMode::Require,
+          "verify-full" => SslMode::VerifyFull,
+          "allow" | "verify-ca" => return Err(PostgresError::UnsupportedSslMode.into()),
+          _ => return Err(PostgresError::UnknownConfigurationParameter.into()),
+        };
+        self.ssl_mode = ssl_mode;
+      }
       _ => return Err(PostgresError::UnknownConfigurationParameter.into()),
     }
     Ok(())
@@ -54,10 +95,25 @@ pub(crate) enum ChannelBinding {
   Require,
 }
 
+/// Encryption requirements of a connection, as specified by the `sslmode` URI parameter.
+///
+/// Certificates are always verified by the TLS connector, which means that `Require` is as strict
+/// as `VerifyFull`. The `allow` and `verify-ca` values are rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SslMode {
+  Disable,
+  Prefer,
+  Require,
+  VerifyFull,
+}
+
 #[cfg(test)]
 mod tests {
   use crate::{
-    database::client::postgres::{Config, config::ChannelBinding},
+    database::client::postgres::{
+      Config, PostgresError,
+      config::{ChannelBinding, SslMode},
+    },
     misc::Uri,
   };
 
@@ -68,7 +124,37 @@ mod tests {
     assert_eq!(config.application_name, "ij");
     assert_eq!(config.channel_binding, ChannelBinding::Disable);
     assert_eq!(config.db, "gh");
+    assert_eq!(config.host, "ef");
     assert_eq!(config.password, "cd");
+    assert_eq!(config.ssl_mode, SslMode::Prefer);
     assert_eq!(config.user, "ab");
   }
+
+  #[test]
+  fn ssl_mode() {
+    for (value, ssl_mode) in [
+      ("disable", SslMode::Disable),
+      ("prefer", SslMode::Prefer),
+      ("require", SslMode::Require),
+      ("verify-full", SslMode::VerifyFull),
+    ] {
+      let uri = Uri::new(alloc::format!("postgres://ab:cd@ef:5432/gh?sslmode={value}"));
+      assert_eq!(Config::from_uri(&uri.to_ref()).unwrap().ssl_mode, ssl_mode);
+    }
+    for value in ["allow", "verify-ca"] {
+      let uri = Uri::new(alloc::format!("postgres://ab:cd@ef:5432/gh?sslmode={value}"));
+      let err = Config::from_uri(&uri.to_ref()).unwrap_err();
+      assert!(matches!(err, crate::Error::PostgresError(PostgresError::UnsupportedSslMode)));
+    }
+    let uri = Uri::new("postgres://ab:cd@ef:5432/gh?sslmode=foo");
+    assert!(Config::from_uri(&uri).is_err());
+  }
+
+  #[test]
+  fn ssl_cert_and_key() {
+    let uri = Uri::new("postgres://ab:cd@ef:5432/gh?sslcert=/a.crt&sslkey=/a.key");
+    assert_eq!(Config::from_uri(&uri).unwrap().ssl_cert_and_key(), Some(("/a.crt", "/a.key")));
+    let uri = Uri::new("postgres://ab:cd@ef:5432/gh?sslcert=/a.crt");
+    assert_eq!(Config::from_uri(&uri).unwrap().ssl_cert_and_key(), None);
+  }
 }
@@ -473,6 +473,28 @@ async fn tls() {
   .unwrap();
 }
 
+#[cfg(feature = "tokio-rustls")]
+#[tokio::test]
+async fn tls_with_ssl_mode() {
+  let uri_string = &*URI;
+  let uri = UriRef::new(uri_string.as_str());
+  let mut config = Config::from_uri(&uri).unwrap();
+  config.ssl_mode = crate::database::client::postgres::config::SslMode::Require;
+  let mut rng = ChaCha20Rng::from_seed(_32_bytes_seed());
+  let executor = PostgresExecutor::<crate::Error, _, _>::connect_with_ssl_mode(
+    &config,
+    ExecutorBuffer::new(usize::MAX, &mut rng),
+    &mut rng,
+    TcpStream::connect(uri.hostname_with_implied_port()).await.unwrap(),
+    crate::misc::TokioRustlsConnector::default()
+      .push_certs(include_bytes!("../../../../../.certs/root-ca.crt"))
+      .unwrap(),
+  )
+  .await
+  .unwrap();
+  assert!(matches!(executor.stream, crate::misc::Either::Right(_)));
+}
+
 async fn executor<E>() -> PostgresExecutor<E, ExecutorBuffer, TcpStream> {
   let uri_string = &*URI;
   let uri = UriRef::new(uri_string.as_str());
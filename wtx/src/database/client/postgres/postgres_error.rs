@@ -24,6 +24,8 @@ pub enum PostgresError {
   /// It is required to connect without using a TLS channel but the server only provided a way to
   /// connect using channels. Probably because the connection is encrypted.
   RequiredChannel,
+  /// The `sslmode` parameter requires an encrypted connection
+  RequiredEncryption,
   /// Server does not support encryption
   ServerDoesNotSupportEncryption,
   /// A query
@@ -39,6 +41,8 @@ pub enum PostgresError {
   UnknownAuthenticationMethod,
   /// The system does not support a provided parameter.
   UnknownConfigurationParameter,
+  /// The `allow` and `verify-ca` values of the `sslmode` parameter are not supported.
+  UnsupportedSslMode,
   /// The system only supports decimals with 64 digits.
   VeryLargeDecimal,
 }
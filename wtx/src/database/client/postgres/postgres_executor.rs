@@ -4,12 +4,18 @@ mod fetch;
 mod prepare;
 mod simple_query;
 
+#[cfg(feature = "metrics")]
+use crate::{
+  database::client::postgres::{PostgresMetrics, postgres_metrics::QueryRecord},
+  sync::Arc,
+};
 use crate::{
   database::{
     Database, Executor, RecordValues, StmtCmd,
     client::{
       postgres::{
         Config, Postgres, PostgresError, PostgresRecord, PostgresRecords,
+        config::SslMode,
         executor_buffer::ExecutorBuffer,
         message::MessageTy,
         postgres_executor::commons::FetchWithStmtCommons,
@@ -18,7 +24,10 @@ use crate::{
       rdbms::{clear_cmd_buffers, common_executor_buffer::CommonExecutorBuffer},
     },
   },
-  misc::{ConnectionState, CryptoRng, DEController, Lease, LeaseMut, Stream, StreamWithTls},
+  misc::{
+    ConnectionState, CryptoRng, DEController, Either, Lease, LeaseMut, Stream, StreamWithTls,
+    TlsConnector,
+  },
 };
 use core::marker::PhantomData;
 
@@ -27,6 +36,8 @@ use core::marker::PhantomData;
 pub struct PostgresExecutor<E, EB, S> {
   pub(crate) cs: ConnectionState,
   pub(crate) eb: EB,
+  #[cfg(feature = "metrics")]
+  pub(crate) metrics: Option<Arc<PostgresMetrics>>,
   pub(crate) phantom: PhantomData<fn() -> E>,
   pub(crate) stream: S,
 }
@@ -37,6 +48,8 @@ where
   S: Stream,
 {
   /// Connects with an unencrypted stream.
+  ///
+  /// Returns an error if the `sslmode` of `config` requires encryption.
   #[inline]
   pub async fn connect<RNG>(
     config: &Config<'_>,
@@ -47,6 +60,9 @@ where
   where
     RNG: CryptoRng,
   {
+    if let SslMode::Require | SslMode::VerifyFull = config.ssl_mode {
+      return Err(PostgresError::RequiredEncryption.into());
+    }
     eb.lease_mut().clear();
     Self::do_connect(config, eb, rng, stream, None).await
   }
@@ -68,14 +84,7 @@ where
     S: StreamWithTls,
   {
     eb.lease_mut().clear();
-    {
-      let mut sw = eb.lease_mut().common.net_buffer._suffix_writer();
-      encrypted_conn(&mut sw)?;
-      stream.write_all(sw._curr_bytes()).await?;
-    }
-    let mut buf = [0];
-    let _ = stream.read(&mut buf).await?;
-    if buf[0] != b'S' {
+    if !request_encryption(&mut eb, &mut stream).await? {
       return Err(PostgresError::ServerDoesNotSupportEncryption.into());
     }
     let enc_stream = cb(stream).await?;
@@ -90,6 +99,14 @@ where
     self.eb.lease_mut()
   }
 
+  /// Shared instance that records the number, the failures and the duration of queries. Defaults
+  /// to `None`.
+  #[cfg(feature = "metrics")]
+  #[inline]
+  pub fn set_metrics(&mut self, metrics: Option<Arc<PostgresMetrics>>) {
+    self.metrics = metrics;
+  }
+
   #[inline]
   async fn do_connect<RNG>(
     config: &Config<'_>,
@@ -101,7 +118,14 @@ where
   where
     RNG: CryptoRng,
   {
-    let mut this = Self { eb, cs: ConnectionState::Open, phantom: PhantomData, stream };
+    let mut this = Self {
+      eb,
+      cs: ConnectionState::Open,
+      #[cfg(feature = "metrics")]
+      metrics: None,
+      phantom: PhantomData,
+      stream,
+    };
     this.send_initial_conn_msg(config).await?;
     this.manage_authentication(config, rng, tls_server_end_point).await?;
     this.read_after_authentication_data().await?;
@@ -116,6 +140,48 @@ where
   }
 }
 
+impl<E, EB, IS, TS> PostgresExecutor<E, EB, Either<IS, TS>>
+where
+  E: From<crate::Error>,
+  EB: LeaseMut<ExecutorBuffer>,
+  IS: Stream,
+  TS: Stream + StreamWithTls,
+{
+  /// Negotiates encryption according to the `sslmode` of `config`, upgrading `stream` through
+  /// `connector` when the server supports TLS.
+  ///
+  /// With `disable`, the connection is never encrypted. With `prefer`, the connection continues
+  /// unencrypted if the server doesn't support TLS. With `require` or `verify-full`, such a
+  /// situation results in an error. Certificates are always verified by `connector`.
+  #[inline]
+  pub async fn connect_with_ssl_mode<C, RNG>(
+    config: &Config<'_>,
+    mut eb: EB,
+    rng: &mut RNG,
+    mut stream: IS,
+    connector: C,
+  ) -> crate::Result<Self>
+  where
+    C: TlsConnector<IS, TlsStream = TS>,
+    RNG: CryptoRng,
+  {
+    eb.lease_mut().clear();
+    if let SslMode::Disable = config.ssl_mode {
+      return Self::do_connect(config, eb, rng, Either::Left(stream), None).await;
+    }
+    if !request_encryption(&mut eb, &mut stream).await? {
+      if let SslMode::Prefer = config.ssl_mode {
+        return Self::do_connect(config, eb, rng, Either::Left(stream), None).await;
+      }
+      return Err(PostgresError::ServerDoesNotSupportEncryption.into());
+    }
+    let enc_stream = connector.connect(config.host, stream).await?;
+    let tls_server_end_point = enc_stream.tls_server_end_point()?;
+    let tsep = tls_server_end_point.as_ref().map(Lease::lease);
+    Self::do_connect(config, eb, rng, Either::Right(enc_stream), tsep).await
+  }
+}
+
 impl<E, EB, S> Executor for PostgresExecutor<E, EB, S>
 where
   E: From<crate::Error>,
@@ -135,10 +201,18 @@ where
     cmd: &str,
     cb: impl FnMut(u64) -> Result<(), <Self::Database as DEController>::Error>,
   ) -> Result<(), <Self::Database as DEController>::Error> {
+    #[cfg(feature = "metrics")]
+    let mut qr = QueryRecord::new(self.metrics.as_deref());
     let ExecutorBuffer { common, .. } = self.eb.lease_mut();
     let CommonExecutorBuffer { net_buffer, records_params, values_params, .. } = common;
     clear_cmd_buffers(net_buffer, records_params, values_params);
-    Self::simple_query_execute(cmd, &mut self.cs, net_buffer, &mut self.stream, cb).await
+    let rslt =
+      Self::simple_query_execute(cmd, &mut self.cs, net_buffer, &mut self.stream, cb).await;
+    #[cfg(feature = "metrics")]
+    {
+      qr.is_ok = rslt.is_ok();
+    }
+    rslt
   }
 
   #[inline]
@@ -151,7 +225,16 @@ where
     RV: RecordValues<Self::Database>,
     SC: StmtCmd,
   {
-    let Self { cs, eb, phantom: _, stream } = self;
+    let Self {
+      cs,
+      eb,
+      #[cfg(feature = "metrics")]
+      metrics,
+      phantom: _,
+      stream,
+    } = self;
+    #[cfg(feature = "metrics")]
+    let mut qr = QueryRecord::new(metrics.as_deref());
     let ExecutorBuffer { common, .. } = eb.lease_mut();
     let CommonExecutorBuffer { net_buffer, records_params, stmts, values_params } = common;
     clear_cmd_buffers(net_buffer, records_params, values_params);
@@ -176,6 +259,10 @@ where
         }
       }
     }
+    #[cfg(feature = "metrics")]
+    {
+      qr.is_ok = true;
+    }
     Ok(rows)
   }
 
@@ -190,7 +277,16 @@ where
     RV: RecordValues<Self::Database>,
     SC: StmtCmd,
   {
-    let Self { cs, eb, phantom: _, stream } = self;
+    let Self {
+      cs,
+      eb,
+      #[cfg(feature = "metrics")]
+      metrics,
+      phantom: _,
+      stream,
+    } = self;
+    #[cfg(feature = "metrics")]
+    let mut qr = QueryRecord::new(metrics.as_deref());
     let ExecutorBuffer { common, .. } = eb.lease_mut();
     let CommonExecutorBuffer { net_buffer, records_params, stmts, values_params } = common;
     clear_cmd_buffers(net_buffer, records_params, values_params);
@@ -234,6 +330,10 @@ where
         }
       }
     }
+    #[cfg(feature = "metrics")]
+    {
+      qr.is_ok = true;
+    }
     Ok(PostgresRecords::new(
       net_buffer._all().get(begin_data..net_buffer._current_end_idx()).unwrap_or_default(),
       records_params,
@@ -252,14 +352,23 @@ where
     RV: RecordValues<Self::Database>,
     SC: StmtCmd,
   {
-    let Self { cs, eb, phantom: _, stream } = self;
+    let Self {
+      cs,
+      eb,
+      #[cfg(feature = "metrics")]
+      metrics,
+      phantom: _,
+      stream,
+    } = self;
+    #[cfg(feature = "metrics")]
+    let mut qr = QueryRecord::new(metrics.as_deref());
     let ExecutorBuffer { common, .. } = eb.lease_mut();
     let CommonExecutorBuffer { net_buffer, records_params, stmts, values_params, .. } = common;
     clear_cmd_buffers(net_buffer, records_params, values_params);
     let mut fwsc = FetchWithStmtCommons { cs, stream, tys: &[] };
     let (_, stmt_cmd_id, stmt) =
       Self::write_send_await_stmt_prot(&mut fwsc, net_buffer, sc, stmts).await?;
-    Self::write_send_await_fetch_with_stmt_wo_prot(
+    let rslt = Self::write_send_await_fetch_with_stmt_wo_prot(
       &mut fwsc,
       net_buffer,
       rv,
@@ -267,12 +376,24 @@ where
       stmt_cmd_id.as_bytes(),
       values_params,
     )
-    .await
+    .await;
+    #[cfg(feature = "metrics")]
+    {
+      qr.is_ok = rslt.is_ok();
+    }
+    rslt
   }
 
   #[inline]
   async fn prepare(&mut self, cmd: &str) -> Result<u64, E> {
-    let Self { cs, eb, phantom: _, stream } = self;
+    let Self {
+      cs,
+      eb,
+      #[cfg(feature = "metrics")]
+        metrics: _,
+      phantom: _,
+      stream,
+    } = self;
     let ExecutorBuffer { common, .. } = eb.lease_mut();
     let CommonExecutorBuffer { net_buffer, records_params, stmts, values_params } = common;
     clear_cmd_buffers(net_buffer, records_params, values_params);
@@ -280,3 +401,19 @@ where
     Ok(Self::write_send_await_stmt_prot(&mut fwsc, net_buffer, cmd, stmts).await?.0)
   }
 }
+
+// Sends a SSLRequest and returns `true` if the server accepts to continue with TLS.
+async fn request_encryption<EB, S>(eb: &mut EB, stream: &mut S) -> crate::Result<bool>
+where
+  EB: LeaseMut<ExecutorBuffer>,
+  S: Stream,
+{
+  {
+    let mut sw = eb.lease_mut().common.net_buffer._suffix_writer();
+    encrypted_conn(&mut sw)?;
+    stream.write_all(sw._curr_bytes()).await?;
+  }
+  let mut buf = [0];
+  let _ = stream.read(&mut buf).await?;
+  Ok(buf[0] == b'S')
+}
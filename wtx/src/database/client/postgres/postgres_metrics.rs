@@ -0,0 +1,121 @@
+use crate::{
+  metrics::{Counter, Histogram, push_metric_header},
+  misc::{GenericTime, Vector},
+};
+
+/// Metrics of Postgres executors that share the same instance through
+/// [`crate::database::client::postgres::PostgresExecutor::set_metrics`].
+///
+/// Queries are the commands issued by the `execute*` and `fetch*` methods of
+/// [`crate::database::Executor`].
+#[derive(Debug)]
+pub struct PostgresMetrics {
+  duration: Histogram<11>,
+  errors: Counter,
+  queries: Counter,
+}
+
+impl PostgresMetrics {
+  /// Instance without any recorded query.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { duration: Histogram::durations(), errors: Counter::new(), queries: Counter::new() }
+  }
+
+  /// Time taken to perform queries.
+  #[inline]
+  pub fn duration(&self) -> &Histogram<11> {
+    &self.duration
+  }
+
+  /// Number of queries that returned an error.
+  #[inline]
+  pub fn errors(&self) -> &Counter {
+    &self.errors
+  }
+
+  /// Number of performed queries, successful or not.
+  #[inline]
+  pub fn queries(&self) -> &Counter {
+    &self.queries
+  }
+
+  /// Writes all metrics into `buffer` using the text exposition format.
+  #[inline]
+  pub fn render(&self, buffer: &mut Vector<u8>) -> crate::Result<()> {
+    push_metric_header(
+      buffer,
+      "postgres_query_duration_seconds",
+      "Time taken to perform queries.",
+      "histogram",
+    )?;
+    self.duration.push(buffer, "postgres_query_duration_seconds")?;
+    push_metric_header(buffer, "postgres_query_errors_total", "Failed queries.", "counter")?;
+    self.errors.push(buffer, "postgres_query_errors_total", "")?;
+    push_metric_header(buffer, "postgres_queries_total", "Performed queries.", "counter")?;
+    self.queries.push(buffer, "postgres_queries_total", "")?;
+    Ok(())
+  }
+}
+
+impl Default for PostgresMetrics {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// Records a query when dropped, which also covers early returns. Queries are considered failed
+// unless `is_ok` is set.
+pub(crate) struct QueryRecord<'metrics> {
+  pub(crate) is_ok: bool,
+  metrics: Option<&'metrics PostgresMetrics>,
+  start: GenericTime,
+}
+
+impl<'metrics> QueryRecord<'metrics> {
+  #[inline]
+  pub(crate) fn new(metrics: Option<&'metrics PostgresMetrics>) -> Self {
+    Self { is_ok: false, metrics, start: GenericTime::now() }
+  }
+}
+
+impl Drop for QueryRecord<'_> {
+  #[inline]
+  fn drop(&mut self) {
+    let Some(metrics) = self.metrics else {
+      return;
+    };
+    metrics.queries.inc();
+    if !self.is_ok {
+      metrics.errors.inc();
+    }
+    metrics.duration.observe_duration(self.start.elapsed().unwrap_or_default());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    database::client::postgres::{PostgresMetrics, postgres_metrics::QueryRecord},
+    misc::{Vector, from_utf8_basic},
+  };
+
+  #[test]
+  fn query_record() {
+    let metrics = PostgresMetrics::new();
+    drop(QueryRecord::new(Some(&metrics)));
+    let mut qr = QueryRecord::new(Some(&metrics));
+    qr.is_ok = true;
+    drop(qr);
+    assert_eq!(metrics.errors().get(), 1);
+    assert_eq!(metrics.queries().get(), 2);
+    assert_eq!(metrics.duration().count(), 2);
+    let mut buffer = Vector::new();
+    metrics.render(&mut buffer).unwrap();
+    let text = from_utf8_basic(&buffer).unwrap();
+    assert!(text.contains("postgres_query_errors_total 1\n"));
+    assert!(text.contains("postgres_queries_total 2\n"));
+    assert!(text.contains("postgres_query_duration_seconds_count 2\n"));
+  }
+}
@@ -27,10 +27,16 @@ pub enum Error {
   AtoiInvalidBytes,
   #[cfg(feature = "chrono")]
   ChronoParseError(chrono::ParseError),
+  #[cfg(feature = "ciborium")]
+  CiboriumDe(Box<ciborium::de::Error<std::io::Error>>),
+  #[cfg(feature = "ciborium")]
+  CiboriumSer(Box<ciborium::ser::Error<std::io::Error>>),
   #[cfg(feature = "cl-aux")]
   ClAux(cl_aux::Error),
   #[cfg(feature = "http-cookie")]
   Cookie(crate::http::CookieError),
+  #[cfg(feature = "csv")]
+  Csv(csv::Error),
   #[cfg(feature = "crypto-common")]
   CryptoCommonInvalidLength(crypto_common::InvalidLength),
   #[cfg(feature = "base64")]
@@ -39,6 +45,8 @@ pub enum Error {
   DecodeSliceError(base64::DecodeSliceError),
   #[cfg(feature = "embassy-net")]
   EmbassyNet(embassy_net::tcp::Error),
+  #[cfg(feature = "embedded-io-async")]
+  EmbeddedIo(embedded_io_async::ErrorKind),
   #[cfg(feature = "base64")]
   EncodeSliceError(base64::EncodeSliceError),
   #[cfg(feature = "flate2")]
@@ -61,6 +69,14 @@ pub enum Error {
   PostgresDbError(Box<crate::database::client::postgres::DbError>),
   #[cfg(feature = "quick-protobuf")]
   QuickProtobuf(Box<quick_protobuf::Error>),
+  #[cfg(feature = "quick-xml")]
+  QuickXmlDe(Box<quick_xml::de::DeError>),
+  #[cfg(feature = "quick-xml")]
+  QuickXmlSe(Box<quick_xml::se::SeError>),
+  #[cfg(feature = "rmp-serde")]
+  RmpSerdeDecode(Box<rmp_serde::decode::Error>),
+  #[cfg(feature = "rmp-serde")]
+  RmpSerdeEncode(Box<rmp_serde::encode::Error>),
   #[cfg(feature = "rsa")]
   RsaError(Box<rsa::Error>),
   #[cfg(feature = "rustls")]
@@ -73,6 +89,8 @@ pub enum Error {
   SerdeUrlencodedSer(Box<serde_urlencoded::ser::Error>),
   #[cfg(feature = "http-session")]
   SessionError(crate::http::SessionError),
+  #[cfg(feature = "simd-json")]
+  SimdJson(Box<simd_json::Error>),
   #[cfg(feature = "spki")]
   SpkiError(Box<spki::Error>),
   #[cfg(feature = "tokio")]
@@ -152,6 +170,8 @@ pub enum Error {
   UnexpectedUint {
     received: u64,
   },
+  /// A hostname couldn't be translated into IP addresses.
+  UnresolvedHostname,
   /// Only appending is possible but overwritten is still viable through resetting.
   UriCanNotBeOverwritten,
   /// In the current platform a number is larger than `usize`.
@@ -159,6 +179,8 @@ pub enum Error {
 
   // Internal
   //
+  #[cfg(feature = "acme")]
+  AcmeError(Box<crate::acme::AcmeError>),
   ArrayStringError(ArrayStringError),
   ArrayVectorError(ArrayVectorError),
   BlocksQueueError(BlocksDequeError),
@@ -175,6 +197,10 @@ pub enum Error {
   Http2ErrorGoAway(crate::http2::Http2ErrorCode, Option<crate::http2::Http2Error>),
   #[cfg(feature = "http2")]
   Http2ErrorReset(crate::http2::Http2ErrorCode, Option<crate::http2::Http2Error>, u32),
+  #[cfg(feature = "http3")]
+  Http3Error(crate::http3::Http3Error),
+  #[cfg(feature = "jwt")]
+  JwtError(crate::jwt::JwtError),
   #[cfg(feature = "mysql")]
   MysqlError(crate::database::client::mysql::MysqlError),
   #[cfg(feature = "postgres")]
@@ -230,6 +256,22 @@ impl From<chrono::ParseError> for Error {
   }
 }
 
+#[cfg(feature = "ciborium")]
+impl From<ciborium::de::Error<std::io::Error>> for Error {
+  #[inline]
+  fn from(from: ciborium::de::Error<std::io::Error>) -> Self {
+    Self::CiboriumDe(from.into())
+  }
+}
+
+#[cfg(feature = "ciborium")]
+impl From<ciborium::ser::Error<std::io::Error>> for Error {
+  #[inline]
+  fn from(from: ciborium::ser::Error<std::io::Error>) -> Self {
+    Self::CiboriumSer(from.into())
+  }
+}
+
 #[cfg(feature = "cl-aux")]
 impl From<cl_aux::Error> for Error {
   #[inline]
@@ -247,6 +289,14 @@ impl From<crate::http::CookieError> for Error {
   }
 }
 
+#[cfg(feature = "csv")]
+impl From<csv::Error> for Error {
+  #[inline]
+  fn from(from: csv::Error) -> Self {
+    Self::Csv(from)
+  }
+}
+
 #[cfg(feature = "crypto-common")]
 impl From<crypto_common::InvalidLength> for Error {
   #[inline]
@@ -282,6 +332,14 @@ impl From<embassy_net::tcp::Error> for Error {
   }
 }
 
+#[cfg(feature = "embedded-io-async")]
+impl From<embedded_io_async::ErrorKind> for Error {
+  #[inline]
+  fn from(from: embedded_io_async::ErrorKind) -> Self {
+    Self::EmbeddedIo(from)
+  }
+}
+
 #[cfg(feature = "base64")]
 impl From<base64::EncodeSliceError> for Error {
   #[inline]
@@ -410,6 +468,38 @@ impl From<quick_protobuf::Error> for Error {
   }
 }
 
+#[cfg(feature = "quick-xml")]
+impl From<quick_xml::de::DeError> for Error {
+  #[inline]
+  fn from(from: quick_xml::de::DeError) -> Self {
+    Self::QuickXmlDe(from.into())
+  }
+}
+
+#[cfg(feature = "quick-xml")]
+impl From<quick_xml::se::SeError> for Error {
+  #[inline]
+  fn from(from: quick_xml::se::SeError) -> Self {
+    Self::QuickXmlSe(from.into())
+  }
+}
+
+#[cfg(feature = "rmp-serde")]
+impl From<rmp_serde::decode::Error> for Error {
+  #[inline]
+  fn from(from: rmp_serde::decode::Error) -> Self {
+    Self::RmpSerdeDecode(from.into())
+  }
+}
+
+#[cfg(feature = "rmp-serde")]
+impl From<rmp_serde::encode::Error> for Error {
+  #[inline]
+  fn from(from: rmp_serde::encode::Error) -> Self {
+    Self::RmpSerdeEncode(from.into())
+  }
+}
+
 #[cfg(feature = "rsa")]
 impl From<rsa::Error> for Error {
   #[inline]
@@ -458,6 +548,14 @@ impl From<crate::http::SessionError> for Error {
   }
 }
 
+#[cfg(feature = "simd-json")]
+impl From<simd_json::Error> for Error {
+  #[inline]
+  fn from(from: simd_json::Error) -> Self {
+    Self::SimdJson(from.into())
+  }
+}
+
 #[cfg(feature = "spki")]
 impl From<spki::Error> for Error {
   #[inline]
@@ -527,6 +625,14 @@ impl From<x509_certificate::X509CertificateError> for Error {
 
 // Internal
 
+#[cfg(feature = "acme")]
+impl From<crate::acme::AcmeError> for Error {
+  #[inline]
+  fn from(from: crate::acme::AcmeError) -> Self {
+    Self::AcmeError(from.into())
+  }
+}
+
 impl From<ArrayStringError> for Error {
   #[inline]
   fn from(from: ArrayStringError) -> Self {
@@ -556,6 +662,14 @@ impl From<crate::http::HttpError> for Error {
   }
 }
 
+#[cfg(feature = "http3")]
+impl From<crate::http3::Http3Error> for Error {
+  #[inline]
+  fn from(from: crate::http3::Http3Error) -> Self {
+    Self::Http3Error(from)
+  }
+}
+
 #[cfg(feature = "client-api-framework")]
 impl From<crate::client_api_framework::ClientApiFrameworkError> for Error {
   #[inline]
@@ -587,6 +701,14 @@ impl From<FromRadix10Error> for Error {
   }
 }
 
+#[cfg(feature = "jwt")]
+impl From<crate::jwt::JwtError> for Error {
+  #[inline]
+  fn from(from: crate::jwt::JwtError) -> Self {
+    Self::JwtError(from)
+  }
+}
+
 #[cfg(feature = "mysql")]
 impl From<crate::database::client::mysql::MysqlError> for Error {
   #[inline]
@@ -2,7 +2,7 @@
 //! framework.
 
 #[cfg(feature = "grpc-client")]
-mod grpc_client;
+pub(crate) mod grpc_client;
 mod grpc_manager;
 #[cfg(feature = "grpc-server")]
 mod grpc_middleware;
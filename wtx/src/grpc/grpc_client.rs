@@ -14,10 +14,21 @@ use crate::{
 /// Performs requests to gRPC servers.
 #[derive(Debug)]
 pub struct GrpcClient<C, DRSR> {
-  client: C,
+  pub(crate) client: C,
   drsr: DRSR,
 }
 
+impl<C, DRSR> GrpcClient<C, DRSR> {
+  /// Constructor
+  ///
+  /// When used as a transport of the client API framework, `client` can also be a
+  /// [`crate::http::client_pool::ClientPool`].
+  #[inline]
+  pub fn new(client: C, drsr: DRSR) -> Self {
+    Self { client, drsr }
+  }
+}
+
 impl<C, DRSR, HD, SW> GrpcClient<C, DRSR>
 where
   C: LeaseMut<Http2<HD, true>> + SingleTypeStorage<Item = HD>,
@@ -25,12 +36,6 @@ where
   HD::Item: Lock<Resource = Http2Data<Http2Buffer, SW, true>>,
   SW: StreamWriter,
 {
-  /// Constructor
-  #[inline]
-  pub fn new(client: C, drsr: DRSR) -> Self {
-    Self { client, drsr }
-  }
-
   /// Deserialize From Response Bytes
   #[inline]
   pub fn des_from_res_bytes<'de, T>(&mut self, bytes: &mut &'de [u8]) -> crate::Result<T>
@@ -58,21 +63,21 @@ where
   {
     rrb.clear();
     serialize(&mut rrb.body, VerbatimRequest { data }, &mut self.drsr)?;
-    Self::push_headers(&mut rrb.headers)?;
+    push_headers(&mut rrb.headers)?;
     let res = self.client.lease_mut().send_recv_single(Method::Post, rrb, uri).await?;
     Ok(Response::http2(res.rrd, res.status_code))
   }
+}
 
-  #[inline]
-  fn push_headers(headers: &mut Headers) -> crate::Result<()> {
-    headers.push_from_iter_many([
-      Header::from_name_and_value(
-        KnownHeaderName::ContentType.into(),
-        ["application/grpc"].into_iter(),
-      ),
-      Header::from_name_and_value(KnownHeaderName::Te.into(), ["trailers"].into_iter()),
-      Header::from_name_and_value(KnownHeaderName::UserAgent.into(), [WTX_USER_AGENT].into_iter()),
-    ])?;
-    Ok(())
-  }
+#[inline]
+pub(crate) fn push_headers(headers: &mut Headers) -> crate::Result<()> {
+  headers.push_from_iter_many([
+    Header::from_name_and_value(
+      KnownHeaderName::ContentType.into(),
+      ["application/grpc"].into_iter(),
+    ),
+    Header::from_name_and_value(KnownHeaderName::Te.into(), ["trailers"].into_iter()),
+    Header::from_name_and_value(KnownHeaderName::UserAgent.into(), [WTX_USER_AGENT].into_iter()),
+  ])?;
+  Ok(())
 }
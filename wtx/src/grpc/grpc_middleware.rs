@@ -5,7 +5,7 @@ use crate::{
     server_framework::Middleware,
   },
 };
-use core::ops::ControlFlow;
+use core::{net::IpAddr, ops::ControlFlow};
 
 /// Applies gRPC headers
 #[derive(Debug)]
@@ -25,6 +25,7 @@ where
     &self,
     _: &mut CA,
     _: &mut Self::Aux,
+    _: IpAddr,
     req: &mut Request<ReqResBuffer>,
     stream_aux: &mut GrpcManager<DRSR>,
   ) -> Result<ControlFlow<StatusCode, ()>, E> {
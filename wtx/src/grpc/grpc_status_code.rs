@@ -1,5 +1,5 @@
 /// gRPC status codes.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum GrpcStatusCode {
   /// Not an error; returned on success.
   Ok = 0,
@@ -41,6 +41,48 @@ pub enum GrpcStatusCode {
 }
 
 impl GrpcStatusCode {
+  // https://github.com/grpc/grpc/blob/master/doc/http-grpc-status-mapping.md
+  #[cfg(feature = "grpc-client")]
+  #[inline]
+  pub(crate) fn _from_http_status(status_code: crate::http::StatusCode) -> Self {
+    use crate::http::StatusCode;
+    match status_code {
+      StatusCode::BadRequest => GrpcStatusCode::Internal,
+      StatusCode::Unauthorized => GrpcStatusCode::Unauthenticated,
+      StatusCode::Forbidden => GrpcStatusCode::PermissionDenied,
+      StatusCode::NotFound => GrpcStatusCode::Unimplemented,
+      StatusCode::TooManyRequests
+      | StatusCode::BadGateway
+      | StatusCode::ServiceUnavailable
+      | StatusCode::GatewayTimeout => GrpcStatusCode::Unavailable,
+      _ => GrpcStatusCode::Unknown,
+    }
+  }
+
+  /// Unknown numbers are mapped to [`GrpcStatusCode::Unknown`].
+  #[inline]
+  pub(crate) fn _from_number_str(str: &str) -> Self {
+    match str.trim() {
+      "0" => GrpcStatusCode::Ok,
+      "1" => GrpcStatusCode::Cancelled,
+      "3" => GrpcStatusCode::InvalidArgument,
+      "4" => GrpcStatusCode::DeadlineExceeded,
+      "5" => GrpcStatusCode::NotFound,
+      "6" => GrpcStatusCode::AlreadyExists,
+      "7" => GrpcStatusCode::PermissionDenied,
+      "8" => GrpcStatusCode::ResourceExhausted,
+      "9" => GrpcStatusCode::FailedPrecondition,
+      "10" => GrpcStatusCode::Aborted,
+      "11" => GrpcStatusCode::OutOfRange,
+      "12" => GrpcStatusCode::Unimplemented,
+      "13" => GrpcStatusCode::Internal,
+      "14" => GrpcStatusCode::Unavailable,
+      "15" => GrpcStatusCode::DataLoss,
+      "16" => GrpcStatusCode::Unauthenticated,
+      _ => GrpcStatusCode::Unknown,
+    }
+  }
+
   #[inline]
   pub(crate) fn _number_as_str(self) -> &'static str {
     match self {
@@ -1,46 +1,72 @@
 //! Generic HTTP elements
 
+mod cache;
 #[cfg(feature = "http-client-pool")]
 pub mod client_pool;
+mod conn_limits;
 #[cfg(any(feature = "http-client-pool", feature = "http-server-framework"))]
 mod conn_params;
+mod content_coding;
 #[cfg(feature = "http-cookie")]
 mod cookie;
+mod form_urlencoded;
 mod generic_header;
 mod generic_request;
 mod generic_response;
 mod header_name;
 mod headers;
 mod http_client;
+pub(crate) mod http_date;
 mod http_error;
+mod http_instrumentation;
 mod method;
 mod mime;
 mod misc;
+pub mod multipart;
 mod operation_mode;
 #[cfg(feature = "nightly")]
 mod optioned_server;
 mod protocol;
+mod proxy;
+mod range;
+mod redirect_policy;
 mod req_res_buffer;
+#[cfg(feature = "std")]
+mod req_res_buffer_pool;
 mod req_res_builder;
 mod req_res_data;
 mod request;
 mod response;
+mod retry_policy;
 #[cfg(feature = "http-server-framework")]
 pub mod server_framework;
+mod server_sent_event;
 #[cfg(feature = "http-session")]
 mod session;
 mod status_code;
+mod structured_field;
+mod typed_header;
 mod version;
 
-#[cfg(feature = "http-session")]
+pub use cache::{HttpCache, HttpCacheEntry, HttpCacheStore, MemoryHttpCacheStore};
+pub use conn_limits::ConnLimits;
+pub use content_coding::{ContentCoding, decompress_body};
+#[cfg(feature = "http-cookie")]
 pub use cookie::*;
+pub use form_urlencoded::{
+  FormUrlencodedIter, FormUrlencodedWriter, form_urlencoded_decode, form_urlencoded_encode,
+};
+#[cfg(feature = "serde_urlencoded")]
+pub use form_urlencoded::{from_form_urlencoded, to_form_urlencoded};
 pub use generic_header::GenericHeader;
 pub use generic_request::GenericRequest;
 pub use generic_response::GenericResponse;
 pub use header_name::*;
 pub use headers::{Header, Headers, Trailers};
 pub use http_client::HttpClient;
+pub use http_date::{format_http_date, parse_http_date};
 pub use http_error::HttpError;
+pub use http_instrumentation::*;
 pub use method::Method;
 pub use mime::Mime;
 pub use misc::*;
@@ -48,14 +74,26 @@ pub use operation_mode::*;
 #[cfg(feature = "nightly")]
 pub use optioned_server::OptionedServer;
 pub use protocol::Protocol;
+pub use proxy::Proxy;
+pub use range::{ByteRangeSpec, RangeRequest};
+pub use redirect_policy::RedirectPolicy;
 pub use req_res_buffer::ReqResBuffer;
+#[cfg(feature = "std")]
+pub use req_res_buffer_pool::ReqResBufferPool;
 pub use req_res_builder::*;
 pub use req_res_data::{ReqResData, ReqResDataMut};
 pub use request::Request;
 pub use response::Response;
+pub use retry_policy::RetryPolicy;
+pub use server_sent_event::ServerSentEvent;
 #[cfg(feature = "http-session")]
 pub use session::*;
 pub use status_code::StatusCode;
+pub use structured_field::{
+  StructuredBareItem, StructuredDictionary, StructuredInnerList, StructuredItem, StructuredList,
+  StructuredMember,
+};
+pub use typed_header::{ContentLength, ContentType, Date, LastModified, TypedHeader};
 pub use version::Version;
 
 /// Maximum number of bytes for the name of a header.
@@ -0,0 +1,6 @@
+mod cache_control;
+mod http_cache;
+mod http_cache_store;
+
+pub use http_cache::HttpCache;
+pub use http_cache_store::{HttpCacheEntry, HttpCacheStore, MemoryHttpCacheStore};
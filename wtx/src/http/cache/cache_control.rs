@@ -0,0 +1,68 @@
+use crate::{
+  http::{Headers, KnownHeaderName},
+  misc::{str_split_once1, str_split1},
+};
+
+/// Subset of the `Cache-Control` directives that are relevant for private caches. Stale entries
+/// are always revalidated, as such, `must-revalidate` is implied.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc9111#section-5.2>
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct CacheControl {
+  pub(crate) max_age: Option<u64>,
+  pub(crate) no_cache: bool,
+  pub(crate) no_store: bool,
+  pub(crate) public: bool,
+}
+
+impl CacheControl {
+  /// Merges the directives of all `Cache-Control` headers. Unknown directives are ignored.
+  pub(crate) fn from_headers(headers: &Headers) -> Self {
+    let mut this = Self::default();
+    let name: &str = KnownHeaderName::CacheControl.into();
+    for header in headers.iter().filter(|el| el.name.eq_ignore_ascii_case(name)) {
+      for directive in str_split1(header.value, b',') {
+        let (key, value) = match str_split_once1(directive.trim(), b'=') {
+          Some((key, value)) => (key.trim(), Some(value.trim().trim_matches('"'))),
+          None => (directive.trim(), None),
+        };
+        match key {
+          _ if key.eq_ignore_ascii_case("max-age") => {
+            this.max_age = value.and_then(|el| el.parse().ok());
+          }
+          _ if key.eq_ignore_ascii_case("no-cache") => this.no_cache = true,
+          _ if key.eq_ignore_ascii_case("no-store") => this.no_store = true,
+          _ if key.eq_ignore_ascii_case("public") => this.public = true,
+          _ => {}
+        }
+      }
+    }
+    this
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::{Header, Headers, KnownHeaderName, cache::cache_control::CacheControl};
+
+  #[test]
+  fn from_headers() {
+    let mut headers = Headers::new();
+    headers
+      .push_from_iter(Header::from_name_and_value(
+        KnownHeaderName::CacheControl.into(),
+        ["public, max-age=\"60\", foo=bar"],
+      ))
+      .unwrap();
+    headers
+      .push_from_iter(Header::from_name_and_value(
+        KnownHeaderName::CacheControl.into(),
+        ["No-Cache"],
+      ))
+      .unwrap();
+    assert_eq!(
+      CacheControl::from_headers(&headers),
+      CacheControl { max_age: Some(60), no_cache: true, no_store: false, public: true }
+    );
+  }
+}
@@ -0,0 +1,348 @@
+use crate::{
+  http::{
+    Header, Headers, KnownHeaderName, Method, ReqResBuffer, Response, StatusCode,
+    cache::{
+      HttpCacheEntry, HttpCacheStore, cache_control::CacheControl, http_cache_store::copy_headers,
+    },
+    parse_http_date,
+  },
+  misc::{Either, GenericTime, UriRef, Vector, str_split1},
+};
+
+/// Private HTTP cache that stores the responses of GET requests.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc9111>
+///
+/// Fresh responses are returned without contacting the server while stale responses are
+/// revalidated through `If-None-Match` or `If-Modified-Since`. Successful responses to unsafe
+/// methods invalidate the response stored for the same URI.
+#[derive(Debug)]
+pub struct HttpCache<S> {
+  store: S,
+}
+
+impl<S> HttpCache<S> {
+  /// New instance
+  #[inline]
+  pub const fn new(store: S) -> Self {
+    Self { store }
+  }
+
+  /// See [`HttpCacheStore`].
+  #[inline]
+  pub fn store(&self) -> &S {
+    &self.store
+  }
+
+  /// Mutable version of [`Self::store`].
+  #[inline]
+  pub fn store_mut(&mut self) -> &mut S {
+    &mut self.store
+  }
+}
+
+impl<S> HttpCache<S>
+where
+  S: HttpCacheStore,
+{
+  /// Writes the contents of a fresh response into `rrb`, if any. Otherwise, the validators of a
+  /// stale response are added to the headers of `rrb`.
+  pub(crate) async fn lookup(
+    &mut self,
+    method: Method,
+    rrb: &mut ReqResBuffer,
+    uri: &UriRef<'_>,
+  ) -> crate::Result<Either<StatusCode, CacheMiss>> {
+    let request_time = now_secs()?;
+    let mut req_headers = Headers::new();
+    copy_headers(&rrb.headers, &mut req_headers)?;
+    let req_cc = CacheControl::from_headers(&rrb.headers);
+    let mut stale = None;
+    if method == Method::Get && !req_cc.no_store {
+      if let Some(entry) = self.store.get(uri.as_str()).await? {
+        if vary_matches(&entry, &rrb.headers) {
+          let res_cc = CacheControl::from_headers(&entry.headers);
+          let age = current_age(&entry, request_time);
+          let is_fresh = !req_cc.no_cache
+            && !res_cc.no_cache
+            && age < freshness_lifetime(&entry, res_cc)
+            && req_cc.max_age.is_none_or(|el| age <= el);
+          if is_fresh {
+            let status_code = entry.status_code;
+            fill_rrb(age, entry, rrb)?;
+            return Ok(Either::Left(status_code));
+          }
+          push_validators(&entry.headers, &mut rrb.headers)?;
+          stale = Some(entry);
+        }
+      }
+    }
+    Ok(Either::Right(CacheMiss { req_headers, request_time, stale }))
+  }
+
+  /// Stores, refreshes or invalidates entries according to the received response.
+  pub(crate) async fn manage_response(
+    &mut self,
+    cm: CacheMiss,
+    method: Method,
+    mut res: Response<ReqResBuffer>,
+    uri: &UriRef<'_>,
+  ) -> crate::Result<Response<ReqResBuffer>> {
+    let CacheMiss { req_headers, request_time, stale } = cm;
+    let key = uri.as_str();
+    if method != Method::Get {
+      let is_safe = matches!(method, Method::Head | Method::Options | Method::Trace);
+      if !is_safe && (200..400).contains(&u16::from(res.status_code)) {
+        self.store.remove(key).await?;
+      }
+      return Ok(res);
+    }
+    let response_time = now_secs()?;
+    match (res.status_code, stale) {
+      (StatusCode::NotModified, Some(mut entry)) => {
+        freshen_headers(&mut entry.headers, &res.rrd.headers)?;
+        entry.request_time = request_time;
+        entry.response_time = response_time;
+        if is_storable(&entry.headers, entry.status_code) {
+          self.store.insert(key, entry.try_clone()?).await?;
+        } else {
+          self.store.remove(key).await?;
+        }
+        res.status_code = entry.status_code;
+        fill_rrb(current_age(&entry, response_time), entry, &mut res.rrd)?;
+        Ok(res)
+      }
+      (_, stale) => {
+        if CacheControl::from_headers(&req_headers).no_store
+          || !is_storable(&res.rrd.headers, res.status_code)
+        {
+          if stale.is_some() {
+            self.store.remove(key).await?;
+          }
+          return Ok(res);
+        }
+        let mut headers = Headers::new();
+        copy_headers(&res.rrd.headers, &mut headers)?;
+        let mut vary_headers = Headers::new();
+        for name in vary_names(&res.rrd.headers) {
+          if let Some(value) = header_value(&req_headers, name) {
+            vary_headers.push_from_iter(Header::from_name_and_value(name, [value]))?;
+          }
+        }
+        let entry = HttpCacheEntry {
+          body: Vector::from_slice(&res.rrd.body)?,
+          headers,
+          request_time,
+          response_time,
+          status_code: res.status_code,
+          vary_headers,
+        };
+        self.store.insert(key, entry).await?;
+        Ok(res)
+      }
+    }
+  }
+}
+
+/// Parameters of a request that wasn't answered by the cache.
+#[derive(Debug)]
+pub(crate) struct CacheMiss {
+  req_headers: Headers,
+  request_time: u64,
+  stale: Option<HttpCacheEntry>,
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9111#section-4.2.3
+fn current_age(entry: &HttpCacheEntry, now: u64) -> u64 {
+  let date = header_date(&entry.headers, KnownHeaderName::Date).unwrap_or(entry.response_time);
+  let age_value = header_value(&entry.headers, KnownHeaderName::Age.into())
+    .and_then(|el| el.trim().parse::<u64>().ok())
+    .unwrap_or_default();
+  let apparent_age = entry.response_time.saturating_sub(date);
+  let response_delay = entry.response_time.saturating_sub(entry.request_time);
+  let corrected_initial_age = apparent_age.max(age_value.saturating_add(response_delay));
+  corrected_initial_age.saturating_add(now.saturating_sub(entry.response_time))
+}
+
+fn fill_rrb(age: u64, entry: HttpCacheEntry, rrb: &mut ReqResBuffer) -> crate::Result<()> {
+  let age_name: &str = KnownHeaderName::Age.into();
+  rrb.clear();
+  rrb.body = entry.body;
+  rrb.headers = entry.headers;
+  rrb.headers.retain(|el| !el.name.eq_ignore_ascii_case(age_name));
+  rrb.headers.push_from_fmt(Header::from_name_and_value(age_name, format_args!("{age}")))?;
+  Ok(())
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9111#section-4.2.1
+fn freshness_lifetime(entry: &HttpCacheEntry, cc: CacheControl) -> u64 {
+  if let Some(elem) = cc.max_age {
+    return elem;
+  }
+  let date = header_date(&entry.headers, KnownHeaderName::Date).unwrap_or(entry.response_time);
+  if let Some(elem) = header_value(&entry.headers, KnownHeaderName::Expires.into()) {
+    return parse_http_date(elem).map_or(0, |expires| expires.saturating_sub(date));
+  }
+  if let Some(elem) = header_date(&entry.headers, KnownHeaderName::LastModified) {
+    return date.saturating_sub(elem) / 10;
+  }
+  0
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9111#section-4.3.4
+fn freshen_headers(entry_headers: &mut Headers, res_headers: &Headers) -> crate::Result<()> {
+  let content_length: &str = KnownHeaderName::ContentLength.into();
+  let is_replaced = |name: &str| !name.eq_ignore_ascii_case(content_length);
+  entry_headers.retain(|el| {
+    !res_headers.iter().any(|res| is_replaced(res.name) && res.name.eq_ignore_ascii_case(el.name))
+  });
+  for header in res_headers.iter().filter(|el| is_replaced(el.name)) {
+    entry_headers.push_from_iter(Header::from_name_and_value(header.name, [header.value]))?;
+  }
+  Ok(())
+}
+
+fn header_date(headers: &Headers, name: KnownHeaderName) -> Option<u64> {
+  header_value(headers, name.into()).and_then(parse_http_date)
+}
+
+fn header_value<'headers>(headers: &'headers Headers, name: &str) -> Option<&'headers str> {
+  headers.iter().find(|el| el.name.eq_ignore_ascii_case(name)).map(|el| el.value)
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9111#section-3
+fn is_storable(headers: &Headers, status_code: StatusCode) -> bool {
+  let cc = CacheControl::from_headers(headers);
+  if cc.no_store || vary_names(headers).any(|el| el == "*") {
+    return false;
+  }
+  let is_heuristically_cacheable = matches!(
+    status_code,
+    StatusCode::Ok
+      | StatusCode::NonAuthoritativeInformation
+      | StatusCode::NoContent
+      | StatusCode::MultipleChoice
+      | StatusCode::MovedPermanently
+      | StatusCode::PermanentRedirect
+      | StatusCode::NotFound
+      | StatusCode::MethodNotAllowed
+      | StatusCode::Gone
+      | StatusCode::UriTooLong
+      | StatusCode::NotImplemented
+  );
+  let has_explicit_lifetime =
+    cc.max_age.is_some() || header_value(headers, KnownHeaderName::Expires.into()).is_some();
+  let has_validator = header_value(headers, KnownHeaderName::Etag.into()).is_some()
+    || header_value(headers, KnownHeaderName::LastModified.into()).is_some();
+  if is_heuristically_cacheable {
+    return has_explicit_lifetime || has_validator || cc.public;
+  }
+  has_explicit_lifetime
+    && (200..600).contains(&u16::from(status_code))
+    && !matches!(status_code, StatusCode::PartialContent | StatusCode::NotModified)
+}
+
+fn now_secs() -> crate::Result<u64> {
+  Ok(GenericTime::now_timestamp()?.as_secs())
+}
+
+fn push_validators(entry_headers: &Headers, req_headers: &mut Headers) -> crate::Result<()> {
+  let pairs = [
+    (KnownHeaderName::Etag, KnownHeaderName::IfNoneMatch),
+    (KnownHeaderName::LastModified, KnownHeaderName::IfModifiedSince),
+  ];
+  for (validator, condition) in pairs {
+    let Some(value) = header_value(entry_headers, validator.into()) else {
+      continue;
+    };
+    if header_value(req_headers, condition.into()).is_none() {
+      req_headers.push_from_iter(Header::from_name_and_value(condition.into(), [value]))?;
+    }
+  }
+  Ok(())
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9111#section-4.1
+fn vary_matches(entry: &HttpCacheEntry, req_headers: &Headers) -> bool {
+  vary_names(&entry.headers)
+    .all(|name| header_value(req_headers, name) == header_value(&entry.vary_headers, name))
+}
+
+fn vary_names(headers: &Headers) -> impl Iterator<Item = &str> {
+  let name: &str = KnownHeaderName::Vary.into();
+  headers
+    .iter()
+    .filter(move |el| el.name.eq_ignore_ascii_case(name))
+    .flat_map(|el| str_split1(el.value, b',').map(str::trim))
+    .filter(|el| !el.is_empty())
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    http::{
+      Header, HttpCache, KnownHeaderName, MemoryHttpCacheStore, Method, ReqResBuffer, Response,
+      StatusCode,
+    },
+    misc::{Either, UriRef},
+  };
+
+  #[tokio::test]
+  async fn fresh_responses_are_served() {
+    let mut cache = HttpCache::new(MemoryHttpCacheStore::new(1024));
+    let uri = UriRef::new("http://localhost/foo");
+    let mut rrb = ReqResBuffer::empty();
+    let Either::Right(cm) = cache.lookup(Method::Get, &mut rrb, &uri).await.unwrap() else {
+      panic!();
+    };
+    let res = response("max-age=60", StatusCode::Ok);
+    let _res = cache.manage_response(cm, Method::Get, res, &uri).await.unwrap();
+    let mut rrb = ReqResBuffer::empty();
+    let Either::Left(status_code) = cache.lookup(Method::Get, &mut rrb, &uri).await.unwrap() else {
+      panic!();
+    };
+    assert_eq!((status_code, rrb.body.as_slice()), (StatusCode::Ok, b"data".as_slice()));
+    assert_eq!(rrb.headers.get_by_name(b"age").unwrap().value, "0");
+    let Either::Right(cm) = cache.lookup(Method::Post, &mut rrb, &uri).await.unwrap() else {
+      panic!();
+    };
+    let res = response("no-store", StatusCode::Created);
+    let _res = cache.manage_response(cm, Method::Post, res, &uri).await.unwrap();
+    assert!(cache.store().is_empty());
+  }
+
+  #[tokio::test]
+  async fn stale_responses_are_revalidated() {
+    let mut cache = HttpCache::new(MemoryHttpCacheStore::new(1024));
+    let uri = UriRef::new("http://localhost/foo");
+    let mut rrb = ReqResBuffer::empty();
+    let Either::Right(cm) = cache.lookup(Method::Get, &mut rrb, &uri).await.unwrap() else {
+      panic!();
+    };
+    let res = response("no-cache", StatusCode::Ok);
+    let _res = cache.manage_response(cm, Method::Get, res, &uri).await.unwrap();
+    let mut rrb = ReqResBuffer::empty();
+    let Either::Right(cm) = cache.lookup(Method::Get, &mut rrb, &uri).await.unwrap() else {
+      panic!();
+    };
+    assert_eq!(rrb.headers.get_by_name(b"if-none-match").unwrap().value, "\"1\"");
+    rrb.clear();
+    let res = Response::http2(rrb, StatusCode::NotModified);
+    let res = cache.manage_response(cm, Method::Get, res, &uri).await.unwrap();
+    assert_eq!((res.status_code, res.rrd.body.as_slice()), (StatusCode::Ok, b"data".as_slice()));
+  }
+
+  fn response(cc: &str, status_code: StatusCode) -> Response<ReqResBuffer> {
+    let mut local_rrb = ReqResBuffer::empty();
+    local_rrb.body.extend_from_copyable_slice(b"data").unwrap();
+    local_rrb
+      .headers
+      .push_from_iter(Header::from_name_and_value(KnownHeaderName::CacheControl.into(), [cc]))
+      .unwrap();
+    local_rrb
+      .headers
+      .push_from_iter(Header::from_name_and_value(KnownHeaderName::Etag.into(), ["\"1\""]))
+      .unwrap();
+    Response::http2(local_rrb, status_code)
+  }
+}
@@ -0,0 +1,227 @@
+use crate::{
+  http::{Header, Headers, StatusCode},
+  misc::Vector,
+};
+use alloc::string::String;
+
+/// Response stored by a [`HttpCacheStore`].
+#[derive(Debug)]
+pub struct HttpCacheEntry {
+  /// Body of the response.
+  pub body: Vector<u8>,
+  /// Headers of the response.
+  pub headers: Headers,
+  /// Seconds since the UNIX epoch when the request was sent.
+  pub request_time: u64,
+  /// Seconds since the UNIX epoch when the response was received.
+  pub response_time: u64,
+  /// Status code of the response.
+  pub status_code: StatusCode,
+  /// Request headers nominated by the `Vary` header of the response.
+  pub vary_headers: Headers,
+}
+
+impl HttpCacheEntry {
+  /// Approximated number of bytes used by this instance.
+  #[inline]
+  pub fn bytes_len(&self) -> usize {
+    self
+      .body
+      .len()
+      .wrapping_add(self.headers.bytes_len())
+      .wrapping_add(self.vary_headers.bytes_len())
+  }
+
+  /// Fallible version of [`Clone::clone`].
+  #[inline]
+  pub fn try_clone(&self) -> crate::Result<Self> {
+    let mut headers = Headers::new();
+    copy_headers(&self.headers, &mut headers)?;
+    let mut vary_headers = Headers::new();
+    copy_headers(&self.vary_headers, &mut vary_headers)?;
+    Ok(Self {
+      body: Vector::from_slice(&self.body)?,
+      headers,
+      request_time: self.request_time,
+      response_time: self.response_time,
+      status_code: self.status_code,
+      vary_headers,
+    })
+  }
+}
+
+/// Storage of the responses used by [`crate::http::HttpCache`].
+///
+/// Custom implementations can, for example, persist entries in files or in remote databases.
+pub trait HttpCacheStore {
+  /// Returns a copy of the entry associated with `key`, if any.
+  fn get(&mut self, key: &str) -> impl Future<Output = crate::Result<Option<HttpCacheEntry>>>;
+
+  /// Associates `entry` with `key`, replacing any previous entry.
+  fn insert(&mut self, key: &str, entry: HttpCacheEntry)
+  -> impl Future<Output = crate::Result<()>>;
+
+  /// Removes the entry associated with `key`, if any.
+  fn remove(&mut self, key: &str) -> impl Future<Output = crate::Result<()>>;
+}
+
+impl<T> HttpCacheStore for &mut T
+where
+  T: HttpCacheStore,
+{
+  #[inline]
+  async fn get(&mut self, key: &str) -> crate::Result<Option<HttpCacheEntry>> {
+    (**self).get(key).await
+  }
+
+  #[inline]
+  async fn insert(&mut self, key: &str, entry: HttpCacheEntry) -> crate::Result<()> {
+    (**self).insert(key, entry).await
+  }
+
+  #[inline]
+  async fn remove(&mut self, key: &str) -> crate::Result<()> {
+    (**self).remove(key).await
+  }
+}
+
+/// In-memory [`HttpCacheStore`] delimited by a maximum number of bytes. The least recently used
+/// entries are evicted when there is no space for new entries.
+#[derive(Debug)]
+pub struct MemoryHttpCacheStore {
+  bytes_len: usize,
+  entries: Vector<(String, HttpCacheEntry)>,
+  max_bytes_len: usize,
+}
+
+impl MemoryHttpCacheStore {
+  /// Entries can not occupy more than `max_bytes_len` bytes.
+  #[inline]
+  pub const fn new(max_bytes_len: usize) -> Self {
+    Self { bytes_len: 0, entries: Vector::new(), max_bytes_len }
+  }
+
+  /// Number of bytes occupied by all entries.
+  #[inline]
+  pub fn bytes_len(&self) -> usize {
+    self.bytes_len
+  }
+
+  /// Removes all entries.
+  #[inline]
+  pub fn clear(&mut self) {
+    self.bytes_len = 0;
+    self.entries.clear();
+  }
+
+  /// If there are no entries.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  /// Number of entries.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  fn remove_idx(&mut self, idx: usize) -> Option<(String, HttpCacheEntry)> {
+    let elem = self.entries.remove(idx)?;
+    self.bytes_len = self.bytes_len.wrapping_sub(entry_bytes_len(&elem.0, &elem.1));
+    Some(elem)
+  }
+}
+
+impl HttpCacheStore for MemoryHttpCacheStore {
+  #[inline]
+  async fn get(&mut self, key: &str) -> crate::Result<Option<HttpCacheEntry>> {
+    let Some(idx) = self.entries.iter().position(|el| el.0 == key) else {
+      return Ok(None);
+    };
+    let Some(elem) = self.entries.remove(idx) else {
+      return Ok(None);
+    };
+    let rslt = elem.1.try_clone();
+    self.entries.push(elem)?;
+    Ok(Some(rslt?))
+  }
+
+  #[inline]
+  async fn insert(&mut self, key: &str, entry: HttpCacheEntry) -> crate::Result<()> {
+    if let Some(idx) = self.entries.iter().position(|el| el.0 == key) {
+      let _elem = self.remove_idx(idx);
+    }
+    let len = entry_bytes_len(key, &entry);
+    if len > self.max_bytes_len {
+      return Ok(());
+    }
+    while self.bytes_len.wrapping_add(len) > self.max_bytes_len {
+      if self.remove_idx(0).is_none() {
+        break;
+      }
+    }
+    self.entries.push((key.into(), entry))?;
+    self.bytes_len = self.bytes_len.wrapping_add(len);
+    Ok(())
+  }
+
+  #[inline]
+  async fn remove(&mut self, key: &str) -> crate::Result<()> {
+    if let Some(idx) = self.entries.iter().position(|el| el.0 == key) {
+      let _elem = self.remove_idx(idx);
+    }
+    Ok(())
+  }
+}
+
+pub(crate) fn copy_headers(from: &Headers, to: &mut Headers) -> crate::Result<()> {
+  for header in from.iter() {
+    to.push_from_iter(Header {
+      is_sensitive: header.is_sensitive,
+      is_trailer: header.is_trailer,
+      name: header.name,
+      value: [header.value],
+    })?;
+  }
+  Ok(())
+}
+
+fn entry_bytes_len(key: &str, entry: &HttpCacheEntry) -> usize {
+  key.len().wrapping_add(entry.bytes_len())
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    http::{Headers, HttpCacheEntry, HttpCacheStore, MemoryHttpCacheStore, StatusCode},
+    misc::Vector,
+  };
+
+  #[tokio::test]
+  async fn evicts_least_recently_used() {
+    let mut store = MemoryHttpCacheStore::new(10);
+    store.insert("a", entry(3)).await.unwrap();
+    store.insert("b", entry(3)).await.unwrap();
+    assert_eq!(store.bytes_len(), 8);
+    assert!(store.get("a").await.unwrap().is_some());
+    store.insert("c", entry(3)).await.unwrap();
+    assert!(store.get("b").await.unwrap().is_none());
+    assert!(store.get("a").await.unwrap().is_some());
+    store.insert("d", entry(20)).await.unwrap();
+    assert_eq!(store.len(), 2);
+    store.remove("a").await.unwrap();
+    assert_eq!((store.len(), store.bytes_len()), (1, 4));
+  }
+
+  fn entry(len: usize) -> HttpCacheEntry {
+    HttpCacheEntry {
+      body: Vector::from_cloneable_elem(len, 0).unwrap(),
+      headers: Headers::new(),
+      request_time: 0,
+      response_time: 0,
+      status_code: StatusCode::Ok,
+      vary_headers: Headers::new(),
+    }
+  }
+}
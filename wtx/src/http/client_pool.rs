@@ -15,7 +15,7 @@ mod integration_tests;
 use crate::{
   http::conn_params::ConnParams,
   http2::{Http2, Http2Buffer, Http2Data, Http2ErrorCode},
-  misc::{Lock, RefCounter, StreamWriter, UriRef},
+  misc::{GenericTime, Lock, RefCounter, StreamWriter, UriRef},
   pool::{Pool, ResourceManager, SimplePool, SimplePoolGetElem, SimplePoolResource},
 };
 pub use client_pool_builder::ClientPoolBuilder;
@@ -63,47 +63,64 @@ where
   }
 
   /// Returns a guard that contains the internal elements.
+  ///
+  /// Connections that are closed or that exceeded the limits specified in [`ClientPoolBuilder`]
+  /// are replaced by new connections.
   #[inline]
   pub async fn lock(
     &self,
     uri: &UriRef<'_>,
   ) -> crate::Result<SimplePoolGetElem<<RL as Lock>::Guard<'_>>> {
-    self.pool.get(uri.as_str(), uri.as_str()).await
+    let mut elem = self.pool.get(uri.as_str(), uri.as_str()).await?;
+    elem._last_used = GenericTime::now();
+    Ok(elem)
   }
 }
 
 #[cfg(feature = "tokio")]
 mod tokio {
   use crate::{
-    http::client_pool::{ClientPool, ClientPoolBuilder, ClientPoolRM, ClientPoolResource, NoAuxFn},
-    http2::{Http2Buffer, Http2Tokio},
-    misc::{Fun, UriRef},
+    http::{
+      Proxy,
+      client_pool::{ClientPool, ClientPoolBuilder, ClientPoolRM, ClientPoolResource, NoAuxFn},
+    },
+    http2::{Http2Buffer, Http2ErrorCode, Http2Tokio},
+    misc::{DnsResolver, Fun, GenericTime, TokioDnsResolver, UriRef, Vector},
     pool::{ResourceManager, SimplePoolResource},
   };
+  use core::net::IpAddr;
   use tokio::{
     net::{TcpStream, tcp::OwnedWriteHalf},
     sync::Mutex,
   };
 
   /// A [`ClientPool`] using the elements of `tokio`.
-  pub type ClientPoolTokio<F> = ClientPool<
+  pub type ClientPoolTokio<F, DR = TokioDnsResolver> = ClientPool<
     Mutex<SimplePoolResource<Resource<<F as Fun<()>>::Output>>>,
-    ClientPoolRM<F, TcpStream>,
+    ClientPoolRM<DR, F, TcpStream>,
   >;
   type Resource<AUX> = ClientPoolResource<AUX, Http2Tokio<Http2Buffer, OwnedWriteHalf, true>>;
 
-  impl<AUX> ClientPoolBuilder<NoAuxFn, Mutex<SimplePoolResource<Resource<AUX>>>, TcpStream> {
+  impl<AUX>
+    ClientPoolBuilder<
+      TokioDnsResolver,
+      NoAuxFn,
+      Mutex<SimplePoolResource<Resource<AUX>>>,
+      TcpStream,
+    >
+  {
     /// Creates a new builder with the maximum number of connections delimited by `len`.
     ///
     /// Connection is established using the elements provided by the `tokio` project.
     #[inline]
     pub fn tokio(len: usize) -> Self {
-      Self::_no_aux_fun(len)
+      Self::_no_aux_fun(TokioDnsResolver, len)
     }
   }
 
-  impl<AUX, F> ResourceManager for ClientPoolRM<F, TcpStream>
+  impl<AUX, DR, F> ResourceManager for ClientPoolRM<DR, F, TcpStream>
   where
+    DR: DnsResolver,
     F: Fn() -> AUX,
   {
     type CreateAux = str;
@@ -117,16 +134,16 @@ mod tokio {
       let (frame_reader, http2) = Http2Tokio::connect(
         Http2Buffer::default(),
         self._cp._to_hp(),
-        TcpStream::connect(uri.hostname_with_implied_port()).await?.into_split(),
+        connect(&self._dns_resolver, &self._proxies, &uri).await?.into_split(),
       )
       .await?;
       let _jh = tokio::spawn(frame_reader);
-      Ok(ClientPoolResource { aux: (self._fun)(), client: http2 })
+      Ok(ClientPoolResource::_new((self._fun)(), http2))
     }
 
     #[inline]
     async fn is_invalid(&self, resource: &Self::Resource) -> bool {
-      resource.client.connection_state().is_closed()
+      resource.client.connection_state().is_closed() || self._is_expired(resource)
     }
 
     #[inline]
@@ -136,52 +153,107 @@ mod tokio {
       resource: &mut Self::Resource,
     ) -> Result<(), Self::Error> {
       let uri = UriRef::new(ra);
+      resource.client.send_go_away(Http2ErrorCode::NoError).await;
       let mut buffer = Http2Buffer::default();
       resource.client._swap_buffers(&mut buffer).await;
       let (frame_reader, http2) = Http2Tokio::connect(
         buffer,
         self._cp._to_hp(),
-        TcpStream::connect(uri.hostname_with_implied_port()).await?.into_split(),
+        connect(&self._dns_resolver, &self._proxies, &uri).await?.into_split(),
       )
       .await?;
       let _jh = tokio::spawn(frame_reader);
       resource.client = http2;
+      resource._created_at = GenericTime::now();
       Ok(())
     }
   }
+
+  // Connects directly or through the first matching proxy.
+  pub(crate) async fn connect<DR>(
+    dns_resolver: &DR,
+    proxies: &[Proxy],
+    uri: &UriRef<'_>,
+  ) -> crate::Result<TcpStream>
+  where
+    DR: DnsResolver,
+  {
+    let (hostname, port) = uri.hostname_with_implied_port();
+    let Some(proxy) = proxies.iter().find(|el| el.matches(hostname)) else {
+      return connect_to_host(dns_resolver, hostname, port).await;
+    };
+    let (proxy_hostname, proxy_port) = proxy.address();
+    let mut stream = connect_to_host(dns_resolver, proxy_hostname, proxy_port).await?;
+    proxy.tunnel(hostname, port, &mut stream).await?;
+    Ok(stream)
+  }
+
+  // Resolved addresses are tried in order until a connection is established.
+  async fn connect_to_host<DR>(
+    dns_resolver: &DR,
+    hostname: &str,
+    port: u16,
+  ) -> crate::Result<TcpStream>
+  where
+    DR: DnsResolver,
+  {
+    let literal =
+      hostname.strip_prefix('[').and_then(|el| el.strip_suffix(']')).unwrap_or(hostname);
+    if let Ok(elem) = literal.parse::<IpAddr>() {
+      return Ok(TcpStream::connect((elem, port)).await?);
+    }
+    let mut addrs = Vector::new();
+    let _ttl = dns_resolver.resolve(hostname, &mut addrs).await?;
+    let mut rslt: crate::Result<TcpStream> = Err(crate::Error::UnresolvedHostname);
+    for addr in addrs.iter() {
+      rslt = TcpStream::connect((*addr, port)).await.map_err(Into::into);
+      if rslt.is_ok() {
+        break;
+      }
+    }
+    rslt
+  }
 }
 
 #[cfg(feature = "tokio-rustls")]
 mod tokio_rustls {
   use crate::{
-    http::client_pool::{ClientPool, ClientPoolBuilder, ClientPoolRM, ClientPoolResource, NoAuxFn},
-    http2::{Http2Buffer, Http2Tokio},
-    misc::{Fun, TokioRustlsConnector, UriRef},
+    http::{
+      HttpError, Proxy,
+      client_pool::{
+        ClientPool, ClientPoolBuilder, ClientPoolRM, ClientPoolResource, NoAuxFn, tokio::connect,
+      },
+    },
+    http2::{Http2Buffer, Http2ErrorCode, Http2Tokio},
+    misc::{DnsResolver, Fun, GenericTime, TokioDnsResolver, TokioRustlsConnector, UriRef},
     pool::{ResourceManager, SimplePoolResource},
   };
   use tokio::{io::WriteHalf, net::TcpStream, sync::Mutex};
   use tokio_rustls::client::TlsStream;
 
-  /// A [`ClientPool`] using the elements of `tokio-rustls`.
-  pub type ClientPoolTokioRustls<F> = ClientPool<
+  /// A [`ClientPool`] using the elements of `tokio-rustls`. Servers must support HTTP/2.
+  pub type ClientPoolTokioRustls<F, DR = TokioDnsResolver> = ClientPool<
     Mutex<SimplePoolResource<Resource<<F as Fun<()>>::Output>>>,
-    ClientPoolRM<F, Writer>,
+    ClientPoolRM<DR, F, Writer>,
   >;
   type Resource<AUX> = ClientPoolResource<AUX, Http2Tokio<Http2Buffer, Writer, true>>;
   type Writer = WriteHalf<TlsStream<TcpStream>>;
 
-  impl<AUX> ClientPoolBuilder<NoAuxFn, Mutex<SimplePoolResource<Resource<AUX>>>, Writer> {
+  impl<AUX>
+    ClientPoolBuilder<TokioDnsResolver, NoAuxFn, Mutex<SimplePoolResource<Resource<AUX>>>, Writer>
+  {
     /// Creates a new builder with the maximum number of connections delimited by `len`.
     ///
     /// Connection is established using the elements provided by the `tokio-rustls` project.
     #[inline]
     pub fn tokio_rustls(len: usize) -> Self {
-      Self::_no_aux_fun(len)
+      Self::_no_aux_fun(TokioDnsResolver, len)
     }
   }
 
-  impl<AUX, F> ResourceManager for ClientPoolRM<F, Writer>
+  impl<AUX, DR, F> ResourceManager for ClientPoolRM<DR, F, Writer>
   where
+    DR: DnsResolver,
     F: Fn() -> AUX,
   {
     type CreateAux = str;
@@ -192,27 +264,18 @@ mod tokio_rustls {
     #[inline]
     async fn create(&self, ca: &Self::CreateAux) -> Result<Self::Resource, Self::Error> {
       let uri = UriRef::new(ca);
-      let (frame_reader, http2) = Http2Tokio::connect(
-        Http2Buffer::default(),
-        self._cp._to_hp(),
-        tokio::io::split(
-          TokioRustlsConnector::from_auto()?
-            .http2()
-            .connect_without_client_auth(
-              uri.hostname(),
-              TcpStream::connect(uri.hostname_with_implied_port()).await?,
-            )
-            .await?,
-        ),
-      )
-      .await?;
+      let connector = TokioRustlsConnector::from_auto()?;
+      let stream = connect_tls(connector, &self._dns_resolver, &self._proxies, &uri).await?;
+      let (frame_reader, http2) =
+        Http2Tokio::connect(Http2Buffer::default(), self._cp._to_hp(), tokio::io::split(stream))
+          .await?;
       let _jh = tokio::spawn(frame_reader);
-      Ok(ClientPoolResource { aux: (self._fun)(), client: http2 })
+      Ok(ClientPoolResource::_new((self._fun)(), http2))
     }
 
     #[inline]
     async fn is_invalid(&self, resource: &Self::Resource) -> bool {
-      resource.client.connection_state().is_closed()
+      resource.client.connection_state().is_closed() || self._is_expired(resource)
     }
 
     #[inline]
@@ -222,25 +285,78 @@ mod tokio_rustls {
       resource: &mut Self::Resource,
     ) -> Result<(), Self::Error> {
       let uri = UriRef::new(ra);
+      resource.client.send_go_away(Http2ErrorCode::NoError).await;
       let mut buffer = Http2Buffer::default();
       resource.client._swap_buffers(&mut buffer).await;
-      let (frame_reader, http2) = Http2Tokio::connect(
-        Http2Buffer::default(),
-        self._cp._to_hp(),
-        tokio::io::split(
-          TokioRustlsConnector::from_auto()?
-            .http2()
-            .connect_without_client_auth(
-              uri.hostname(),
-              TcpStream::connect(uri.hostname_with_implied_port()).await?,
-            )
-            .await?,
-        ),
-      )
-      .await?;
+      let connector = TokioRustlsConnector::from_auto()?;
+      let stream = connect_tls(connector, &self._dns_resolver, &self._proxies, &uri).await?;
+      let (frame_reader, http2) =
+        Http2Tokio::connect(buffer, self._cp._to_hp(), tokio::io::split(stream)).await?;
       let _jh = tokio::spawn(frame_reader);
       resource.client = http2;
+      resource._created_at = GenericTime::now();
       Ok(())
     }
   }
+
+  // HTTP/1.1 is not supported. `h2` is the only offered protocol so servers without HTTP/2 either
+  // abort the handshake or ignore ALPN, which is rejected before the sending of the HTTP/2 preface.
+  pub(crate) async fn connect_tls<DR>(
+    connector: TokioRustlsConnector,
+    dns_resolver: &DR,
+    proxies: &[Proxy],
+    uri: &UriRef<'_>,
+  ) -> crate::Result<TlsStream<TcpStream>>
+  where
+    DR: DnsResolver,
+  {
+    let stream = connector
+      .http2()
+      .connect_without_client_auth(uri.hostname(), connect(dns_resolver, proxies, uri).await?)
+      .await?;
+    if stream.get_ref().1.alpn_protocol().is_none() {
+      return Err(HttpError::UnsupportedNegotiatedProtocol.into());
+    }
+    Ok(stream)
+  }
+}
+
+#[cfg(all(feature = "_async-tests", feature = "tokio-rustls", test))]
+mod tests {
+  use crate::{
+    http::{HttpError, client_pool::tokio_rustls::connect_tls},
+    misc::{TokioDnsResolver, TokioRustlsAcceptor, TokioRustlsConnector, UriRef},
+  };
+  use tokio::net::TcpListener;
+
+  static CERT: &[u8] = include_bytes!("../../../.certs/cert.pem");
+  static KEY: &[u8] = include_bytes!("../../../.certs/key.pem");
+  static ROOT_CA: &[u8] = include_bytes!("../../../.certs/root-ca.crt");
+
+  #[tokio::test]
+  async fn connect_tls_requires_http2() {
+    for is_http2 in [false, true] {
+      let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+      let port = listener.local_addr().unwrap().port();
+      let mut acceptor = TokioRustlsAcceptor::without_client_auth();
+      if is_http2 {
+        acceptor = acceptor.http2();
+      }
+      let tls_acceptor = acceptor.build_with_cert_chain_and_priv_key(CERT, KEY).unwrap();
+      let _jh = tokio::spawn(async move {
+        let _stream = tls_acceptor.accept(listener.accept().await.unwrap().0).await;
+      });
+      let uri = alloc::format!("https://localhost:{port}");
+      let connector = TokioRustlsConnector::default().push_certs(ROOT_CA).unwrap();
+      let rslt = connect_tls(connector, &TokioDnsResolver, &[], &UriRef::new(&uri)).await;
+      if is_http2 {
+        assert!(rslt.is_ok());
+      } else {
+        assert!(matches!(
+          rslt,
+          Err(crate::Error::HttpError(HttpError::UnsupportedNegotiatedProtocol))
+        ));
+      }
+    }
+  }
 }
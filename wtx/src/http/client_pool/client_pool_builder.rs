@@ -1,55 +1,129 @@
 use crate::{
   http::{
+    Proxy,
     client_pool::{ClientPool, ClientPoolRM, NoAuxFn},
     conn_params::ConnParams,
   },
-  misc::Lock,
+  misc::{Lock, Vector},
   pool::{ResourceManager, SimplePool, SimplePoolResource},
 };
-use core::marker::PhantomData;
+use core::{marker::PhantomData, time::Duration};
 
 /// Allows the customization of parameters that control HTTP requests and responses.
 #[derive(Debug)]
-pub struct ClientPoolBuilder<F, RL, S> {
+pub struct ClientPoolBuilder<DR, F, RL, S> {
   cp: ConnParams,
+  dns_resolver: DR,
   fun: F,
   len: usize,
+  max_idle: Option<Duration>,
+  max_lifetime: Option<Duration>,
   phantom: PhantomData<(RL, S)>,
+  proxies: Vector<Proxy>,
 }
 
-impl<F, RL, S> ClientPoolBuilder<F, RL, S> {
+impl<DR, F, RL, S> ClientPoolBuilder<DR, F, RL, S> {
   /// Auxiliary structure returned by a function.
   #[inline]
-  pub fn aux<NF>(self, fun: NF) -> ClientPoolBuilder<NF, RL, S> {
-    ClientPoolBuilder { cp: self.cp, fun, len: self.len, phantom: self.phantom }
+  pub fn aux<NF>(self, fun: NF) -> ClientPoolBuilder<DR, NF, RL, S> {
+    ClientPoolBuilder {
+      cp: self.cp,
+      dns_resolver: self.dns_resolver,
+      fun,
+      len: self.len,
+      max_idle: self.max_idle,
+      max_lifetime: self.max_lifetime,
+      phantom: self.phantom,
+      proxies: self.proxies,
+    }
+  }
+
+  /// Translates the hostnames of requested URIs and proxies into IP addresses.
+  #[inline]
+  pub fn dns_resolver<NDR>(self, elem: NDR) -> ClientPoolBuilder<NDR, F, RL, S> {
+    ClientPoolBuilder {
+      cp: self.cp,
+      dns_resolver: elem,
+      fun: self.fun,
+      len: self.len,
+      max_idle: self.max_idle,
+      max_lifetime: self.max_lifetime,
+      phantom: self.phantom,
+      proxies: self.proxies,
+    }
+  }
+
+  /// Connections that weren't acquired within the given duration are closed and replaced by new
+  /// connections in their next acquisition.
+  #[inline]
+  #[must_use]
+  pub fn max_idle(mut self, elem: Option<Duration>) -> Self {
+    self.max_idle = elem;
+    self
+  }
+
+  /// Connections that are older than the given duration are closed and replaced by new
+  /// connections in their next acquisition, which avoids long-lived connections to outdated
+  /// servers.
+  #[inline]
+  #[must_use]
+  pub fn max_lifetime(mut self, elem: Option<Duration>) -> Self {
+    self.max_lifetime = elem;
+    self
+  }
+
+  /// Connections are established through `elem` when its hostnames match the hostname of the
+  /// requested URI. Proxies are evaluated in insertion order.
+  #[inline]
+  pub fn proxy(mut self, elem: Proxy) -> crate::Result<Self> {
+    self.proxies.push(elem)?;
+    Ok(self)
   }
 
   _conn_params_methods!();
 }
 
-impl<RL, S> ClientPoolBuilder<NoAuxFn, RL, S> {
+impl<DR, RL, S> ClientPoolBuilder<DR, NoAuxFn, RL, S> {
   #[inline]
-  pub(crate) fn _no_aux_fun(len: usize) -> Self {
+  pub(crate) fn _no_aux_fun(dns_resolver: DR, len: usize) -> Self {
     fn fun() {}
-    Self { cp: ConnParams::default(), fun, len, phantom: PhantomData }
+    Self {
+      cp: ConnParams::default(),
+      dns_resolver,
+      fun,
+      len,
+      max_idle: None,
+      max_lifetime: None,
+      phantom: PhantomData,
+      proxies: Vector::new(),
+    }
   }
 }
 
-impl<F, RL, S> ClientPoolBuilder<F, RL, S>
+impl<DR, F, RL, S> ClientPoolBuilder<DR, F, RL, S>
 where
-  RL: Lock<Resource = SimplePoolResource<<ClientPoolRM<F, S> as ResourceManager>::Resource>>,
+  RL: Lock<Resource = SimplePoolResource<<ClientPoolRM<DR, F, S> as ResourceManager>::Resource>>,
+  for<'any> DR: 'any,
   for<'any> F: 'any,
   for<'any> RL: 'any,
   for<'any> S: 'any,
-  ClientPoolRM<F, S>: ResourceManager,
+  ClientPoolRM<DR, F, S>: ResourceManager,
 {
   /// Creates a new client with inner parameters.
   #[inline]
-  pub fn build(self) -> ClientPool<RL, ClientPoolRM<F, S>> {
+  pub fn build(self) -> ClientPool<RL, ClientPoolRM<DR, F, S>> {
     ClientPool {
       pool: SimplePool::new(
         self.len,
-        ClientPoolRM { _cp: self.cp, _fun: self.fun, _phantom: PhantomData },
+        ClientPoolRM {
+          _cp: self.cp,
+          _dns_resolver: self.dns_resolver,
+          _fun: self.fun,
+          _max_idle: self.max_idle,
+          _max_lifetime: self.max_lifetime,
+          _phantom: PhantomData,
+          _proxies: self.proxies,
+        },
       ),
     }
   }
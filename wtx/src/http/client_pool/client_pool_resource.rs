@@ -1,3 +1,5 @@
+use crate::misc::GenericTime;
+
 /// Client pool resource
 #[derive(Debug)]
 pub struct ClientPoolResource<AUX, C> {
@@ -5,4 +7,14 @@ pub struct ClientPoolResource<AUX, C> {
   pub aux: AUX,
   /// Client
   pub client: C,
+  pub(crate) _created_at: GenericTime,
+  pub(crate) _last_used: GenericTime,
+}
+
+impl<AUX, C> ClientPoolResource<AUX, C> {
+  #[inline]
+  pub(crate) fn _new(aux: AUX, client: C) -> Self {
+    let now = GenericTime::now();
+    Self { aux, client, _created_at: now, _last_used: now }
+  }
 }
@@ -1,10 +1,32 @@
-use crate::http::client_pool::ConnParams;
-use core::marker::PhantomData;
+use crate::{
+  http::{
+    Proxy,
+    client_pool::{ClientPoolResource, ConnParams},
+  },
+  misc::{GenericTime, Vector},
+};
+use core::{marker::PhantomData, time::Duration};
 
 /// Resource manager for `ClientPool`.
 #[derive(Debug)]
-pub struct ClientPoolRM<F, S> {
+pub struct ClientPoolRM<DR, F, S> {
   pub(crate) _cp: ConnParams,
+  pub(crate) _dns_resolver: DR,
   pub(crate) _fun: F,
+  pub(crate) _max_idle: Option<Duration>,
+  pub(crate) _max_lifetime: Option<Duration>,
   pub(crate) _phantom: PhantomData<S>,
+  pub(crate) _proxies: Vector<Proxy>,
+}
+
+impl<DR, F, S> ClientPoolRM<DR, F, S> {
+  /// If `resource` exceeded the idle or lifetime limits and should be replaced by a new connection.
+  #[inline]
+  pub(crate) fn _is_expired<AUX, C>(&self, resource: &ClientPoolResource<AUX, C>) -> bool {
+    let has_elapsed = |time: GenericTime, limit: Option<Duration>| {
+      limit.is_some_and(|el| time.elapsed().is_ok_and(|elapsed| elapsed >= el))
+    };
+    has_elapsed(resource._created_at, self._max_lifetime)
+      || has_elapsed(resource._last_used, self._max_idle)
+  }
 }
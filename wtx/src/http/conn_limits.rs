@@ -0,0 +1,54 @@
+/// Restrictions applied to incoming connections, which keep servers responsive under connection
+/// floods. No limit is imposed by default.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ConnLimits {
+  max_conns: u32,
+  max_conns_per_ip: u32,
+}
+
+impl ConnLimits {
+  /// New instance without limits.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { max_conns: u32::MAX, max_conns_per_ip: u32::MAX }
+  }
+
+  /// Maximum number of concurrent connections
+  ///
+  /// When reached, new connections are only accepted after existing ones are closed.
+  #[inline]
+  pub const fn max_conns(&self) -> u32 {
+    self.max_conns
+  }
+
+  /// Maximum number of concurrent connections of a single IP address
+  ///
+  /// Connections that exceed this limit are closed right after being accepted.
+  #[inline]
+  pub const fn max_conns_per_ip(&self) -> u32 {
+    self.max_conns_per_ip
+  }
+
+  /// Mutable version of [`Self::max_conns`].
+  #[inline]
+  #[must_use]
+  pub const fn set_max_conns(mut self, value: u32) -> Self {
+    self.max_conns = value;
+    self
+  }
+
+  /// Mutable version of [`Self::max_conns_per_ip`].
+  #[inline]
+  #[must_use]
+  pub const fn set_max_conns_per_ip(mut self, value: u32) -> Self {
+    self.max_conns_per_ip = value;
+    self
+  }
+}
+
+impl Default for ConnLimits {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
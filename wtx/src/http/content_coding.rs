@@ -0,0 +1,338 @@
+use crate::{
+  http::{Headers, HttpError, KnownHeaderName, ReqResBuffer},
+  misc::{Vector, str_split_once1, str_split1},
+};
+use core::mem;
+
+create_enum! {
+  /// Transformations applied to the body of a message.
+  ///
+  /// <https://datatracker.ietf.org/doc/html/rfc9110#section-8.4.1>
+  #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+  pub enum ContentCoding<u8> {
+    /// Brotli
+    Br = (0, "br"),
+    /// Zlib
+    Deflate = (1, "deflate"),
+    /// Gzip
+    Gzip = (2, "gzip"),
+    /// No transformation
+    Identity = (3, "identity"),
+    /// Zstandard
+    Zstd = (4, "zstd"),
+  }
+}
+
+impl ContentCoding {
+  /// All non-identity codings enabled through features, ordered by server preference.
+  pub const SUPPORTED: &[Self] = &[
+    #[cfg(feature = "brotli")]
+    Self::Br,
+    #[cfg(feature = "zstd")]
+    Self::Zstd,
+    #[cfg(all(feature = "flate2", feature = "std"))]
+    Self::Gzip,
+    #[cfg(all(feature = "flate2", feature = "std"))]
+    Self::Deflate,
+  ];
+
+  /// Textual representation used in headers.
+  #[inline]
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::Br => "br",
+      Self::Deflate => "deflate",
+      Self::Gzip => "gzip",
+      Self::Identity => "identity",
+      Self::Zstd => "zstd",
+    }
+  }
+
+  /// Case-insensitive version of [`TryFrom<&str>`].
+  #[inline]
+  pub fn from_token(token: &str) -> Option<Self> {
+    Self::all().into_iter().find(|el| el.as_str().eq_ignore_ascii_case(token))
+  }
+
+  /// Selects the coding with the highest quality value of a received `Accept-Encoding` value.
+  ///
+  /// Ties are resolved according to the order of `supported`. Returns [`Option::None`] if
+  /// nothing is acceptable, including the identity coding.
+  ///
+  /// ```rust
+  /// use wtx::http::ContentCoding;
+  /// let supported = &[ContentCoding::Br, ContentCoding::Gzip];
+  /// let elem = ContentCoding::negotiate("gzip;q=0.9, br;q=0.8", supported);
+  /// assert_eq!(elem, Some(ContentCoding::Gzip));
+  /// ```
+  #[inline]
+  pub fn negotiate(accept_encoding: &str, supported: &[Self]) -> Option<Self> {
+    let mut identity_q = None;
+    let mut rslt = (None, 0);
+    let mut wildcard_q = None;
+    for (token, q) in weighted_elems(accept_encoding) {
+      if token == "*" {
+        wildcard_q = Some(q);
+      } else if token.eq_ignore_ascii_case(Self::Identity.as_str()) {
+        identity_q = Some(q);
+      }
+    }
+    for coding in supported.iter().copied().filter(|el| *el != Self::Identity) {
+      let explicit_q = weighted_elems(accept_encoding)
+        .find_map(|(token, q)| token.eq_ignore_ascii_case(coding.as_str()).then_some(q));
+      let q = explicit_q.or(wildcard_q).unwrap_or(0);
+      if q > rslt.1 {
+        rslt = (Some(coding), q);
+      }
+    }
+    if rslt.0.is_some() {
+      return rslt.0;
+    }
+    match identity_q.or(wildcard_q) {
+      Some(0) => None,
+      _ => Some(Self::Identity),
+    }
+  }
+
+  /// Appends the compressed version of `input` into `output`.
+  #[inline]
+  pub fn compress(self, input: &[u8], output: &mut Vector<u8>) -> crate::Result<()> {
+    match self {
+      #[cfg(feature = "brotli")]
+      Self::Br => {
+        let _ = brotli::BrotliCompress(
+          &mut &*input,
+          output,
+          &brotli::enc::BrotliEncoderParams::default(),
+        )?;
+      }
+      #[cfg(all(feature = "flate2", feature = "std"))]
+      Self::Deflate => {
+        use std::io::Write as _;
+        let mut encoder = flate2::write::ZlibEncoder::new(output, flate2::Compression::default());
+        encoder.write_all(input)?;
+        let _ = encoder.finish()?;
+      }
+      #[cfg(all(feature = "flate2", feature = "std"))]
+      Self::Gzip => {
+        use std::io::Write as _;
+        let mut encoder = flate2::write::GzEncoder::new(output, flate2::Compression::default());
+        encoder.write_all(input)?;
+        let _ = encoder.finish()?;
+      }
+      Self::Identity => {
+        output.extend_from_copyable_slice(input)?;
+      }
+      #[cfg(feature = "zstd")]
+      Self::Zstd => {
+        zstd::stream::copy_encode(input, output, 0)?;
+      }
+      #[allow(unreachable_patterns, reason = "depends on the selected set of features")]
+      _ => return Err(HttpError::UnsupportedContentCoding.into()),
+    }
+    Ok(())
+  }
+
+  /// Appends the decompressed version of `input` into `output`. The number of appended bytes can
+  /// not exceed `max_len`.
+  #[inline]
+  pub fn decompress(
+    self,
+    input: &[u8],
+    max_len: usize,
+    output: &mut Vector<u8>,
+  ) -> crate::Result<()> {
+    if self == Self::Identity {
+      if input.len() > max_len {
+        return Err(HttpError::LargeDecompressedBody.into());
+      }
+      return output.extend_from_copyable_slice(input);
+    }
+    #[cfg(feature = "std")]
+    let mut _writer = LimitedWriter { exceeded: false, max_len, output };
+    let rslt: crate::Result<()> = match self {
+      #[cfg(feature = "brotli")]
+      Self::Br => brotli::BrotliDecompress(&mut &*input, &mut _writer).map_err(From::from),
+      #[cfg(all(feature = "flate2", feature = "std"))]
+      Self::Deflate => {
+        use std::io::Write as _;
+        let mut decoder = flate2::write::ZlibDecoder::new(&mut _writer);
+        decoder.write_all(input).and_then(|_| decoder.finish().map(|_| ())).map_err(From::from)
+      }
+      #[cfg(all(feature = "flate2", feature = "std"))]
+      Self::Gzip => {
+        use std::io::Write as _;
+        let mut decoder = flate2::write::GzDecoder::new(&mut _writer);
+        decoder.write_all(input).and_then(|_| decoder.finish().map(|_| ())).map_err(From::from)
+      }
+      #[cfg(feature = "zstd")]
+      Self::Zstd => zstd::stream::copy_decode(input, &mut _writer).map_err(From::from),
+      _ => Err(HttpError::UnsupportedContentCoding.into()),
+    };
+    #[cfg(feature = "std")]
+    if _writer.exceeded {
+      return Err(HttpError::LargeDecompressedBody.into());
+    }
+    rslt
+  }
+}
+
+/// Replaces the body of `rrb` with its decompressed version according to the `Content-Encoding`
+/// header. Associated headers are removed if the operation is successful.
+///
+/// Multiple codings are decoded in the reverse order of their application.
+#[inline]
+pub fn decompress_body(
+  buffer: &mut Vector<u8>,
+  max_len: usize,
+  rrb: &mut ReqResBuffer,
+) -> crate::Result<()> {
+  let Some(header) = rrb.headers.get_by_name(KnownHeaderName::ContentEncoding.into()) else {
+    return Ok(());
+  };
+  let mut codings = Vector::new();
+  for token in str_split1(header.value, b',') {
+    let token = token.trim_ascii();
+    if token.is_empty() {
+      continue;
+    }
+    codings.push(ContentCoding::from_token(token).ok_or(HttpError::UnsupportedContentCoding)?)?;
+  }
+  for coding in codings.iter().rev() {
+    if *coding == ContentCoding::Identity {
+      continue;
+    }
+    buffer.clear();
+    coding.decompress(&rrb.body, max_len, buffer)?;
+    mem::swap(buffer, &mut rrb.body);
+  }
+  remove_content_headers(&mut rrb.headers);
+  Ok(())
+}
+
+/// Removes the headers that describe an encoded body.
+#[inline]
+pub(crate) fn remove_content_headers(headers: &mut Headers) {
+  headers.retain(|el| {
+    !el.name.eq_ignore_ascii_case(KnownHeaderName::ContentEncoding.into())
+      && !el.name.eq_ignore_ascii_case(KnownHeaderName::ContentLength.into())
+  });
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9110#section-12.4.2
+fn parse_qvalue(value: &str) -> Option<u16> {
+  let (int, frac) = str_split_once1(value, b'.').unwrap_or((value, ""));
+  if frac.len() > 3 || !frac.bytes().all(|el| el.is_ascii_digit()) {
+    return None;
+  }
+  let mut rslt: u16 = match int {
+    "0" => 0,
+    "1" => 1000,
+    _ => return None,
+  };
+  let mut factor = 100;
+  for byte in frac.bytes() {
+    rslt = rslt.wrapping_add(u16::from(byte.wrapping_sub(b'0')).wrapping_mul(factor));
+    factor /= 10;
+  }
+  (rslt <= 1000).then_some(rslt)
+}
+
+// Yields pairs of tokens and quality values multiplied by 1000 of headers like `Accept` or
+// `Accept-Encoding`.
+pub(crate) fn weighted_elems(value: &str) -> impl Iterator<Item = (&str, u16)> {
+  str_split1(value, b',').filter_map(|elem| {
+    let mut params = str_split1(elem, b';');
+    let token = params.next()?.trim_ascii();
+    if token.is_empty() {
+      return None;
+    }
+    let mut q = 1000;
+    for param in params {
+      if let Some((name, value)) = str_split_once1(param.trim_ascii(), b'=') {
+        if name.trim_ascii().eq_ignore_ascii_case("q") {
+          q = parse_qvalue(value.trim_ascii()).unwrap_or(0);
+        }
+      }
+    }
+    Some((token, q))
+  })
+}
+
+#[cfg(feature = "std")]
+struct LimitedWriter<'any> {
+  exceeded: bool,
+  max_len: usize,
+  output: &'any mut Vector<u8>,
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for LimitedWriter<'_> {
+  #[inline]
+  fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    if self.output.len().wrapping_add(buf.len()) > self.max_len {
+      self.exceeded = true;
+      return Err(std::io::Error::other("decompressed body is too large"));
+    }
+    std::io::Write::write(&mut *self.output, buf)
+  }
+
+  #[inline]
+  fn flush(&mut self) -> std::io::Result<()> {
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{
+      ContentCoding, Header, KnownHeaderName, ReqResBuffer, content_coding::parse_qvalue,
+      decompress_body,
+    },
+    misc::Vector,
+  };
+
+  #[test]
+  fn compressed_bodies_are_decompressed() {
+    for coding in ContentCoding::SUPPORTED.iter().copied() {
+      let data = b"abcabcabcabcabcabcabcabcabcabcabcabcabcabc";
+      let mut rrb = ReqResBuffer::empty();
+      coding.compress(data, &mut rrb.body).unwrap();
+      rrb
+        .headers
+        .push_from_iter(Header::from_name_and_value(
+          KnownHeaderName::ContentEncoding.into(),
+          [coding.as_str()],
+        ))
+        .unwrap();
+      assert!(decompress_body(&mut Vector::new(), 8, &mut rrb).is_err());
+      decompress_body(&mut Vector::new(), 64, &mut rrb).unwrap();
+      assert_eq!(rrb.body.as_slice(), data);
+      assert_eq!(rrb.headers.headers_len(), 0);
+    }
+  }
+
+  #[test]
+  fn negotiate() {
+    let all = &[ContentCoding::Br, ContentCoding::Zstd, ContentCoding::Gzip];
+    assert_eq!(ContentCoding::negotiate("", all), Some(ContentCoding::Identity));
+    assert_eq!(ContentCoding::negotiate("gzip, br", all), Some(ContentCoding::Br));
+    assert_eq!(ContentCoding::negotiate("GZIP, br;q=0.5", all), Some(ContentCoding::Gzip));
+    assert_eq!(ContentCoding::negotiate("*;q=0.1, zstd;q=0", all), Some(ContentCoding::Br));
+    assert_eq!(ContentCoding::negotiate("deflate", all), Some(ContentCoding::Identity));
+    assert_eq!(ContentCoding::negotiate("deflate, identity;q=0", all), None);
+    assert_eq!(ContentCoding::negotiate("*;q=0", all), None);
+  }
+
+  #[test]
+  fn qvalues() {
+    assert_eq!(parse_qvalue("0"), Some(0));
+    assert_eq!(parse_qvalue("0.5"), Some(500));
+    assert_eq!(parse_qvalue("0.123"), Some(123));
+    assert_eq!(parse_qvalue("1.000"), Some(1000));
+    assert_eq!(parse_qvalue("1.001"), None);
+    assert_eq!(parse_qvalue("0.1234"), None);
+    assert_eq!(parse_qvalue("2"), None);
+  }
+}
@@ -1,14 +1,22 @@
 mod cookie_error;
 pub(crate) mod cookie_generic;
+mod cookie_jar;
+mod cookie_store;
 pub(crate) mod cookie_str;
 mod same_site;
+mod set_cookie;
 
 use crate::misc::{ArrayVector, Rng, Vector};
 pub use cookie_error::CookieError;
+pub use cookie_jar::{Cookie, CookieJar};
+pub use cookie_store::CookieStore;
 use core::str;
 pub use same_site::SameSite;
+pub use set_cookie::SetCookie;
 
 const NONCE_LEN: usize = 12;
+#[cfg(feature = "http-cookie-secure")]
+const SIGNATURE_LEN: usize = 44;
 const TAG_LEN: usize = 16;
 
 static FMT1: &str = "%a, %d %b %Y %H:%M:%S GMT";
@@ -136,6 +144,57 @@ where
   Ok(())
 }
 
+#[cfg(feature = "http-cookie-secure")]
+#[inline]
+pub(crate) fn sign(
+  buffer: &mut Vector<u8>,
+  secret: &[u8; 32],
+  (name, value): (&str, &str),
+) -> crate::Result<()> {
+  use crate::misc::BufferMode;
+  use base64::{Engine, engine::general_purpose::STANDARD};
+  use hmac::Mac;
+
+  let tag = mac(secret, (name, value))?.finalize().into_bytes();
+  let start = buffer.len();
+  buffer.expand(BufferMode::Additional(SIGNATURE_LEN), 0)?;
+  let len = STANDARD.encode_slice(tag, buffer.get_mut(start..).unwrap_or_default())?;
+  buffer.truncate(start.wrapping_add(len));
+  buffer.extend_from_copyable_slice(value.as_bytes())?;
+  Ok(())
+}
+
+#[cfg(feature = "http-cookie-secure")]
+#[inline]
+pub(crate) fn verify<'value>(
+  secret: &[u8; 32],
+  (name, value): (&str, &'value str),
+) -> crate::Result<&'value str> {
+  use base64::{Engine, engine::general_purpose::STANDARD};
+  use hmac::Mac;
+
+  let (Some(signature), Some(content)) = (value.get(..SIGNATURE_LEN), value.get(SIGNATURE_LEN..))
+  else {
+    return Err(digest::MacError.into());
+  };
+  let mut buffer = [0; 33];
+  let len = STANDARD.decode_slice(signature, &mut buffer)?;
+  mac(secret, (name, content))?.verify_slice(buffer.get(..len).unwrap_or_default())?;
+  Ok(content)
+}
+
+#[cfg(feature = "http-cookie-secure")]
+#[inline]
+fn mac(secret: &[u8; 32], (name, value): (&str, &str)) -> crate::Result<hmac::Hmac<sha2::Sha256>> {
+  use hmac::Mac;
+
+  let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(secret)?;
+  mac.update(name.as_bytes());
+  mac.update(b"=");
+  mac.update(value.as_bytes());
+  Ok(mac)
+}
+
 #[inline]
 fn make_lowercase<const UPPER_BOUND: usize>(buffer: &mut ArrayVector<u8, 12>, slice: &str) {
   buffer.clear();
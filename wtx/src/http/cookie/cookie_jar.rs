@@ -0,0 +1,163 @@
+use crate::{
+  http::{CookieError, Headers, KnownHeaderName},
+  misc::{PercentDecode, Vector, from_utf8_basic, str_split_once1, str_split1},
+};
+
+/// A name-value pair sent by a client in the `Cookie` header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cookie<'str> {
+  /// Name
+  pub name: &'str str,
+  /// Value
+  pub value: &'str str,
+}
+
+/// Percent-decoded cookies received in the `Cookie` headers of a request.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc6265#section-5.4>
+#[derive(Debug, Default)]
+pub struct CookieJar {
+  buffer: Vector<u8>,
+  indices: Vector<[usize; 3]>,
+}
+
+impl CookieJar {
+  /// Empty instance
+  #[inline]
+  pub const fn new() -> Self {
+    Self { buffer: Vector::new(), indices: Vector::new() }
+  }
+
+  /// Collects the cookies of all `Cookie` headers.
+  #[inline]
+  pub fn from_headers(headers: &Headers) -> crate::Result<Self> {
+    let mut this = Self::new();
+    for header in headers.iter() {
+      if header.name == <&str>::from(KnownHeaderName::Cookie) {
+        this.parse(header.value)?;
+      }
+    }
+    Ok(this)
+  }
+
+  /// Removes all cookies.
+  #[inline]
+  pub fn clear(&mut self) {
+    self.buffer.clear();
+    self.indices.clear();
+  }
+
+  /// Returns the value of the first cookie named `name`.
+  #[inline]
+  pub fn get(&self, name: &str) -> Option<&str> {
+    self.iter().find(|el| el.name == name).map(|el| el.value)
+  }
+
+  /// Returns the decrypted value of a cookie created by [`crate::http::SetCookie::push_encrypted`].
+  ///
+  /// An error is returned if the value was tampered with or if it wasn't encrypted with `secret`.
+  #[cfg(feature = "http-cookie-secure")]
+  #[inline]
+  pub fn get_encrypted<'buffer>(
+    &self,
+    buffer: &'buffer mut Vector<u8>,
+    name: &str,
+    secret: &[u8; 32],
+  ) -> crate::Result<Option<&'buffer mut [u8]>> {
+    let Some(value) = self.get(name) else {
+      return Ok(None);
+    };
+    Ok(Some(crate::http::cookie::decrypt(buffer, secret, (name.as_bytes(), value.as_bytes()))?))
+  }
+
+  /// Returns the original value of a cookie created by [`crate::http::SetCookie::push_signed`].
+  ///
+  /// An error is returned if the value was tampered with or if it wasn't signed with `secret`.
+  #[cfg(feature = "http-cookie-secure")]
+  #[inline]
+  pub fn get_signed(&self, name: &str, secret: &[u8; 32]) -> crate::Result<Option<&str>> {
+    let Some(value) = self.get(name) else {
+      return Ok(None);
+    };
+    Ok(Some(crate::http::cookie::verify(secret, (name, value))?))
+  }
+
+  /// If there are no cookies.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.indices.is_empty()
+  }
+
+  /// Iterator over all cookies in the order they were received.
+  #[inline]
+  pub fn iter(&self) -> impl Iterator<Item = Cookie<'_>> {
+    self.indices.iter().map(|[name_begin, value_begin, value_end]| {
+      let name = self.buffer.get(*name_begin..*value_begin).unwrap_or_default();
+      let value = self.buffer.get(*value_begin..*value_end).unwrap_or_default();
+      Cookie {
+        name: from_utf8_basic(name).unwrap_or_default(),
+        value: from_utf8_basic(value).unwrap_or_default(),
+      }
+    })
+  }
+
+  /// Number of cookies
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.indices.len()
+  }
+
+  /// Parses the value of a `Cookie` header, i.e., a list of pairs separated by semicolons.
+  #[inline]
+  pub fn parse(&mut self, value: &str) -> crate::Result<()> {
+    for pair in str_split1(value, b';') {
+      let pair = pair.trim_ascii();
+      if pair.is_empty() {
+        continue;
+      }
+      let Some((name, value)) = str_split_once1(pair, b'=') else {
+        return Err(CookieError::IrregularCookie.into());
+      };
+      let (name, value) = (name.trim_ascii(), value.trim_ascii());
+      if name.is_empty() {
+        return Err(CookieError::MissingName.into());
+      }
+      let value = value.strip_prefix('"').and_then(|el| el.strip_suffix('"')).unwrap_or(value);
+      let name_begin = self.buffer.len();
+      push_decoded(&mut self.buffer, name)?;
+      let value_begin = self.buffer.len();
+      push_decoded(&mut self.buffer, value)?;
+      let _ = from_utf8_basic(self.buffer.get(name_begin..).unwrap_or_default())?;
+      self.indices.push([name_begin, value_begin, self.buffer.len()])?;
+    }
+    Ok(())
+  }
+}
+
+fn push_decoded(buffer: &mut Vector<u8>, str: &str) -> crate::Result<()> {
+  if !PercentDecode::new(str.as_bytes()).decode(buffer)? {
+    buffer.extend_from_copyable_slice(str.as_bytes())?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::{Cookie, CookieJar, Header, Headers};
+
+  #[test]
+  fn from_headers() {
+    let mut headers = Headers::new();
+    headers.push_from_iter(Header::from_name_and_value("cookie", ["a=1; b=\"2\""])).unwrap();
+    headers.push_from_iter(Header::from_name_and_value("cookie", ["c%20d=%F0%9F%8D%AA;"])).unwrap();
+    let jar = CookieJar::from_headers(&headers).unwrap();
+    assert_eq!(jar.len(), 3);
+    assert_eq!(jar.get("a"), Some("1"));
+    assert_eq!(jar.get("b"), Some("2"));
+    assert_eq!(jar.get("c d"), Some("🍪"));
+    assert_eq!(jar.get("d"), None);
+    assert_eq!(jar.iter().next(), Some(Cookie { name: "a", value: "1" }));
+    assert!(CookieJar::new().parse("=1").is_err());
+    assert!(CookieJar::new().parse("a").is_err());
+  }
+}
@@ -0,0 +1,235 @@
+use crate::{
+  http::{Header, Headers, KnownHeaderName, cookie::cookie_str::CookieStr},
+  misc::{GenericTime, UriRef, Vector, bytes_rpos1},
+};
+use alloc::string::String;
+
+/// Client-side storage of cookies received through `Set-Cookie` headers, which are sent back in
+/// `Cookie` headers of subsequent requests to matching URIs.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc6265#section-5.3>
+#[derive(Debug, Default)]
+pub struct CookieStore {
+  cookies: Vector<StoredCookie>,
+}
+
+impl CookieStore {
+  /// Empty instance
+  #[inline]
+  pub const fn new() -> Self {
+    Self { cookies: Vector::new() }
+  }
+
+  /// Removes all cookies.
+  #[inline]
+  pub fn clear(&mut self) {
+    self.cookies.clear();
+  }
+
+  /// If there are no cookies.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.cookies.is_empty()
+  }
+
+  /// Number of stored cookies, including expired ones that weren't evicted yet.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.cookies.len()
+  }
+
+  /// Pushes a `Cookie` header containing all non-expired cookies that match `uri`. Nothing is
+  /// pushed if there are no matching cookies.
+  #[inline]
+  pub fn push_cookie_header(&self, headers: &mut Headers, uri: &UriRef<'_>) -> crate::Result<()> {
+    let now = now_secs()?;
+    let hostname = uri.hostname();
+    let path = if uri.path().is_empty() { "/" } else { uri.path() };
+    let is_secure = matches!(uri.scheme(), "https" | "wss");
+    let mut value = String::new();
+    for cookie in self.cookies.iter() {
+      if cookie.expires_at.is_some_and(|el| el <= now)
+        || (cookie.secure && !is_secure)
+        || !cookie.matches_domain(hostname)
+        || !path_matches(path, &cookie.path)
+      {
+        continue;
+      }
+      if !value.is_empty() {
+        value.push_str("; ");
+      }
+      value.push_str(&cookie.name);
+      value.push('=');
+      value.push_str(&cookie.value);
+    }
+    if value.is_empty() {
+      return Ok(());
+    }
+    headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::Cookie.into(),
+      [value.as_str()],
+    ))?;
+    Ok(())
+  }
+
+  /// Captures the `Set-Cookie` headers of a response received from `uri`. Cookies with invalid
+  /// syntax or with domains that don't match `uri` are ignored.
+  #[inline]
+  pub fn store(&mut self, headers: &Headers, uri: &UriRef<'_>) -> crate::Result<()> {
+    let now = now_secs()?;
+    let hostname = uri.hostname();
+    let mut buffer = Vector::new();
+    for header in headers.iter() {
+      if header.name != <&str>::from(KnownHeaderName::SetCookie) {
+        continue;
+      }
+      buffer.clear();
+      let Ok(cookie_str) = CookieStr::parse(header.value, &mut buffer) else {
+        continue;
+      };
+      let generic = &cookie_str.generic;
+      let domain = generic.domain.strip_prefix('.').unwrap_or(generic.domain);
+      let (domain, host_only) = if domain.is_empty() {
+        (String::from(hostname), true)
+      } else {
+        let domain = domain.to_ascii_lowercase();
+        if !domain_matches(hostname, &domain) {
+          continue;
+        }
+        (domain, false)
+      };
+      let path = if generic.path.starts_with('/') {
+        String::from(generic.path)
+      } else {
+        String::from(default_path(uri.path()))
+      };
+      let expires_at = match (generic.max_age, generic.expires) {
+        (Some(elem), _) => Some(now.saturating_add(elem.as_secs())),
+        (None, Some(elem)) => Some(u64::try_from(elem.timestamp()).unwrap_or_default()),
+        (None, None) => None,
+      };
+      self.cookies.retain(|el| {
+        el.name != generic.name || !el.domain.eq_ignore_ascii_case(&domain) || el.path != path
+      });
+      if expires_at.is_some_and(|el| el <= now) {
+        continue;
+      }
+      self.cookies.push(StoredCookie {
+        domain,
+        expires_at,
+        host_only,
+        name: String::from(generic.name),
+        path,
+        secure: generic.secure,
+        value: String::from(generic.value),
+      })?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+struct StoredCookie {
+  domain: String,
+  expires_at: Option<u64>,
+  host_only: bool,
+  name: String,
+  path: String,
+  secure: bool,
+  value: String,
+}
+
+impl StoredCookie {
+  fn matches_domain(&self, hostname: &str) -> bool {
+    if self.host_only {
+      self.domain.eq_ignore_ascii_case(hostname)
+    } else {
+      domain_matches(hostname, &self.domain)
+    }
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc6265#section-5.1.4
+fn default_path(uri_path: &str) -> &str {
+  if !uri_path.starts_with('/') {
+    return "/";
+  }
+  match bytes_rpos1(uri_path, b'/') {
+    Some(0) | None => "/",
+    Some(idx) => uri_path.get(..idx).unwrap_or("/"),
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc6265#section-5.1.3
+fn domain_matches(hostname: &str, domain: &str) -> bool {
+  if hostname.eq_ignore_ascii_case(domain) {
+    return true;
+  }
+  if hostname.parse::<core::net::IpAddr>().is_ok() {
+    return false;
+  }
+  let Some(prefix_len) = hostname.len().checked_sub(domain.len()) else {
+    return false;
+  };
+  let (prefix, suffix) = hostname.split_at_checked(prefix_len).unwrap_or_default();
+  prefix.ends_with('.') && suffix.eq_ignore_ascii_case(domain)
+}
+
+fn now_secs() -> crate::Result<u64> {
+  Ok(GenericTime::now_timestamp()?.as_secs())
+}
+
+// https://datatracker.ietf.org/doc/html/rfc6265#section-5.1.4
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+  let Some(rest) = request_path.strip_prefix(cookie_path) else {
+    return false;
+  };
+  rest.is_empty() || cookie_path.ends_with('/') || rest.starts_with('/')
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{CookieStore, Header, Headers, KnownHeaderName},
+    misc::UriRef,
+  };
+
+  #[test]
+  fn cookies_are_sent_to_matching_uris() {
+    let mut store = CookieStore::new();
+    let mut res_headers = Headers::new();
+    for value in [
+      "a=1",
+      "b=2; Domain=.foo.com; Path=/api",
+      "c=3; Secure",
+      "d=4; Domain=bar.com",
+      "e=5; Max-Age=0",
+    ] {
+      res_headers
+        .push_from_iter(Header::from_name_and_value(KnownHeaderName::SetCookie.into(), [value]))
+        .unwrap();
+    }
+    store.store(&res_headers, &UriRef::new("http://www.foo.com/api/users")).unwrap();
+    assert_eq!(store.len(), 3);
+    assert_eq!(cookie(&store, "http://www.foo.com/api/users/1"), Some("a=1; b=2".into()));
+    assert_eq!(cookie(&store, "https://www.foo.com/api"), Some("a=1; b=2; c=3".into()));
+    assert_eq!(cookie(&store, "http://sub.foo.com/api"), Some("b=2".into()));
+    assert_eq!(cookie(&store, "http://www.foo.com/apis"), None);
+    assert_eq!(cookie(&store, "http://bar.com/"), None);
+    res_headers.clear();
+    res_headers
+      .push_from_iter(Header::from_name_and_value(
+        KnownHeaderName::SetCookie.into(),
+        ["a=; Max-Age=0"],
+      ))
+      .unwrap();
+    store.store(&res_headers, &UriRef::new("http://www.foo.com/api/users")).unwrap();
+    assert_eq!(cookie(&store, "http://www.foo.com/api"), Some("b=2".into()));
+  }
+
+  fn cookie(store: &CookieStore, uri: &str) -> Option<alloc::string::String> {
+    let mut headers = Headers::new();
+    store.push_cookie_header(&mut headers, &UriRef::new(uri)).unwrap();
+    headers.get_by_name(KnownHeaderName::Cookie.into()).map(|el| el.value.into())
+  }
+}
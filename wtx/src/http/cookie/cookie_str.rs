@@ -73,7 +73,7 @@ impl<'str> CookieStr<'str> {
       let (name, value) = if let Some(elem) = str_split_once1(semicolon, b'=') {
         (elem.0.trim_ascii(), elem.1.trim_ascii())
       } else {
-        return Err(crate::Error::from(CookieError::IrregularCookie));
+        (semicolon.trim_ascii(), "")
       };
       make_lowercase::<12>(&mut lower_case, name);
       match (lower_case.as_ref(), value.as_bytes()) {
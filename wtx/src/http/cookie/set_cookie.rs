@@ -0,0 +1,251 @@
+use crate::{
+  http::{
+    Header, Headers, KnownHeaderName,
+    cookie::{SameSite, cookie_generic::CookieGeneric, cookie_str::CookieStr},
+  },
+  misc::Vector,
+};
+use chrono::{DateTime, Utc};
+use core::{
+  fmt::{Display, Formatter},
+  time::Duration,
+};
+
+/// Instructs user agents to store a cookie through the `Set-Cookie` header.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie>
+#[derive(Debug)]
+pub struct SetCookie<'str> {
+  generic: CookieGeneric<&'str str, &'str str>,
+}
+
+impl<'str> SetCookie<'str> {
+  /// New instance without attributes.
+  #[inline]
+  pub const fn new(name: &'str str, value: &'str str) -> Self {
+    Self {
+      generic: CookieGeneric {
+        domain: "",
+        expires: None,
+        http_only: false,
+        max_age: None,
+        name,
+        path: "",
+        same_site: None,
+        secure: false,
+        value,
+      },
+    }
+  }
+
+  /// Parses the value of a `Set-Cookie` header. Percent-encoded names or values are decoded
+  /// into `buffer`.
+  #[inline]
+  pub fn parse<'local_str, 'vector>(
+    str: &'local_str str,
+    buffer: &'vector mut Vector<u8>,
+  ) -> crate::Result<Self>
+  where
+    'local_str: 'str,
+    'vector: 'str,
+  {
+    Ok(Self { generic: CookieStr::parse(str, buffer)?.generic })
+  }
+
+  /// Instance that instructs user agents to remove the cookie named `name`. Attributes like
+  /// `Domain` or `Path` must match the ones used when the cookie was created.
+  #[inline]
+  pub fn removal(name: &'str str) -> Self {
+    let mut this = Self::new(name, "");
+    this.generic.expires = Some(DateTime::<Utc>::UNIX_EPOCH);
+    this.generic.max_age = Some(Duration::ZERO);
+    this
+  }
+
+  /// Hosts to which the cookie will be sent.
+  #[inline]
+  #[must_use]
+  pub const fn domain(mut self, elem: &'str str) -> Self {
+    self.generic.domain = elem;
+    self
+  }
+
+  /// Maximum lifetime of the cookie as an HTTP-date timestamp.
+  #[inline]
+  #[must_use]
+  pub fn expires(mut self, elem: Option<DateTime<Utc>>) -> Self {
+    self.generic.expires = elem;
+    self
+  }
+
+  /// Forbids JavaScript from accessing the cookie.
+  #[inline]
+  #[must_use]
+  pub const fn http_only(mut self, elem: bool) -> Self {
+    self.generic.http_only = elem;
+    self
+  }
+
+  /// Number of seconds until the cookie expires. Has precedence over `Expires`.
+  #[inline]
+  #[must_use]
+  pub const fn max_age(mut self, elem: Option<Duration>) -> Self {
+    self.generic.max_age = elem;
+    self
+  }
+
+  /// Name
+  #[inline]
+  pub const fn name(&self) -> &'str str {
+    self.generic.name
+  }
+
+  /// Path that must exist in the requested URL.
+  #[inline]
+  #[must_use]
+  pub const fn path(mut self, elem: &'str str) -> Self {
+    self.generic.path = elem;
+    self
+  }
+
+  /// Pushes a `Set-Cookie` header.
+  #[inline]
+  pub fn push(&self, headers: &mut Headers) -> crate::Result<()> {
+    push_generic(&self.generic, headers)
+  }
+
+  /// Pushes a `Set-Cookie` header whose value is encrypted with AES-256-GCM. The name of the
+  /// cookie is used as associated data.
+  ///
+  /// `buffer` is only used as temporary storage. See [`crate::http::CookieJar::get_encrypted`].
+  #[cfg(feature = "http-cookie-secure")]
+  #[inline]
+  pub fn push_encrypted<RNG>(
+    &self,
+    buffer: &mut Vector<u8>,
+    headers: &mut Headers,
+    rng: RNG,
+    secret: &[u8; 32],
+  ) -> crate::Result<()>
+  where
+    RNG: crate::misc::Rng,
+  {
+    let begin = buffer.len();
+    let rslt = crate::http::cookie::encrypt(
+      buffer,
+      secret,
+      (self.generic.name.as_bytes(), self.generic.value.as_bytes()),
+      rng,
+    )
+    .and_then(|_| self.push_with_value(buffer.get(begin..).unwrap_or_default(), headers));
+    buffer.truncate(begin);
+    rslt
+  }
+
+  /// Pushes a `Set-Cookie` header whose value is prefixed with a Base64 HMAC-SHA256 signature
+  /// that covers the name and the value of the cookie. The value itself remains readable by
+  /// clients.
+  ///
+  /// `buffer` is only used as temporary storage. See [`crate::http::CookieJar::get_signed`].
+  #[cfg(feature = "http-cookie-secure")]
+  #[inline]
+  pub fn push_signed(
+    &self,
+    buffer: &mut Vector<u8>,
+    headers: &mut Headers,
+    secret: &[u8; 32],
+  ) -> crate::Result<()> {
+    let begin = buffer.len();
+    let rslt = crate::http::cookie::sign(buffer, secret, (self.generic.name, self.generic.value))
+      .and_then(|_| self.push_with_value(buffer.get(begin..).unwrap_or_default(), headers));
+    buffer.truncate(begin);
+    rslt
+  }
+
+  /// Controls whether or not the cookie is sent with cross-site requests.
+  #[inline]
+  #[must_use]
+  pub const fn same_site(mut self, elem: Option<SameSite>) -> Self {
+    self.generic.same_site = elem;
+    self
+  }
+
+  /// Only sends the cookie through HTTPS.
+  #[inline]
+  #[must_use]
+  pub const fn secure(mut self, elem: bool) -> Self {
+    self.generic.secure = elem;
+    self
+  }
+
+  /// Value
+  #[inline]
+  pub const fn value(&self) -> &'str str {
+    self.generic.value
+  }
+
+  #[cfg(feature = "http-cookie-secure")]
+  fn push_with_value(&self, value: &[u8], headers: &mut Headers) -> crate::Result<()> {
+    let value = crate::misc::from_utf8_basic(value)?;
+    let generic = CookieGeneric {
+      domain: self.generic.domain,
+      expires: self.generic.expires,
+      http_only: self.generic.http_only,
+      max_age: self.generic.max_age,
+      name: self.generic.name,
+      path: self.generic.path,
+      same_site: self.generic.same_site,
+      secure: self.generic.secure,
+      value,
+    };
+    push_generic(&generic, headers)
+  }
+}
+
+impl Display for SetCookie<'_> {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    Display::fmt(&self.generic, f)
+  }
+}
+
+fn push_generic(generic: &CookieGeneric<&str, &str>, headers: &mut Headers) -> crate::Result<()> {
+  headers.push_from_fmt(Header::from_name_and_value(
+    KnownHeaderName::SetCookie.into(),
+    format_args!("{generic}"),
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{SameSite, SetCookie},
+    misc::Vector,
+  };
+  use alloc::string::ToString;
+  use core::time::Duration;
+
+  #[test]
+  fn parse_and_display() {
+    let cookie = SetCookie::new("id", "a3fWa")
+      .http_only(true)
+      .max_age(Some(Duration::from_secs(60)))
+      .path("/")
+      .same_site(Some(SameSite::Strict))
+      .secure(true);
+    let string = cookie.to_string();
+    assert_eq!(string, "id=a3fWa; HttpOnly; Max-Age=60; Path=/; SameSite=Strict; Secure");
+    let mut buffer = Vector::new();
+    let parsed = SetCookie::parse(&string, &mut buffer).unwrap();
+    assert_eq!((parsed.name(), parsed.value()), ("id", "a3fWa"));
+    assert_eq!(parsed.to_string(), string);
+  }
+
+  #[test]
+  fn removal() {
+    assert_eq!(
+      SetCookie::removal("id").path("/").to_string(),
+      "id=; Expires=Thu, 01 Jan 1970 00:00:00 GMT; Max-Age=0; Path=/"
+    );
+  }
+}
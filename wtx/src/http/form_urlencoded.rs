@@ -0,0 +1,176 @@
+use crate::misc::{
+  AsciiSet, PercentEncode, Vector, bytes_pos1, bytes_split_once1, from_utf8_basic,
+  percent_encoding::manage_percent_char,
+};
+
+/// Iterator over the raw `name=value` pairs of `application/x-www-form-urlencoded` bodies or
+/// query strings.
+///
+/// <https://url.spec.whatwg.org/#application/x-www-form-urlencoded>
+///
+/// Names and values are returned as-is, use [`form_urlencoded_decode`] to decode them.
+#[derive(Clone, Copy, Debug)]
+pub struct FormUrlencodedIter<'bytes> {
+  bytes: &'bytes [u8],
+}
+
+impl<'bytes> FormUrlencodedIter<'bytes> {
+  /// A leading `?` is ignored.
+  #[inline]
+  pub fn new(bytes: &'bytes [u8]) -> Self {
+    Self { bytes: bytes.strip_prefix(b"?").unwrap_or(bytes) }
+  }
+}
+
+impl<'bytes> Iterator for FormUrlencodedIter<'bytes> {
+  type Item = (&'bytes [u8], &'bytes [u8]);
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if self.bytes.is_empty() {
+        return None;
+      }
+      let (pair, rest) = bytes_split_once1(self.bytes, b'&').unwrap_or((self.bytes, &[]));
+      self.bytes = rest;
+      if pair.is_empty() {
+        continue;
+      }
+      return Some(bytes_split_once1(pair, b'=').unwrap_or((pair, &[])));
+    }
+  }
+}
+
+/// Writes `name=value` pairs separated by `&` using the `application/x-www-form-urlencoded`
+/// serialization.
+#[derive(Debug)]
+pub struct FormUrlencodedWriter<'vec> {
+  initial_len: usize,
+  vector: &'vec mut Vector<u8>,
+}
+
+impl<'vec> FormUrlencodedWriter<'vec> {
+  /// New instance that appends elements to `vector`.
+  #[inline]
+  pub fn new(vector: &'vec mut Vector<u8>) -> Self {
+    Self { initial_len: vector.len(), vector }
+  }
+
+  /// Encodes and writes a pair.
+  #[inline]
+  pub fn write(self, name: &str, value: &str) -> crate::Result<Self> {
+    if self.vector.len() != self.initial_len {
+      self.vector.push(b'&')?;
+    }
+    form_urlencoded_encode(name.as_bytes(), self.vector)?;
+    self.vector.push(b'=')?;
+    form_urlencoded_encode(value.as_bytes(), self.vector)?;
+    Ok(self)
+  }
+
+  /// Same as [`Self::write`] but for optional values.
+  #[inline]
+  pub fn write_opt(self, name: &str, value: Option<&str>) -> crate::Result<Self> {
+    if let Some(elem) = value { self.write(name, elem) } else { Ok(self) }
+  }
+}
+
+/// Decodes a name or a value, i.e., replaces `+` with spaces and decodes percent-encoded bytes.
+///
+/// `component` is returned if there is nothing to decode, otherwise the decoded bytes are appended
+/// into `buffer`.
+#[inline]
+pub fn form_urlencoded_decode<'any>(
+  component: &'any [u8],
+  buffer: &'any mut Vector<u8>,
+) -> crate::Result<&'any str> {
+  if bytes_pos1(component, b'+').is_none() && bytes_pos1(component, b'%').is_none() {
+    return Ok(from_utf8_basic(component)?);
+  }
+  let start = buffer.len();
+  buffer.reserve(component.len())?;
+  let mut bytes = component;
+  while let [byte, rest @ ..] = bytes {
+    bytes = rest;
+    buffer.push(match *byte {
+      b'%' => manage_percent_char(&mut bytes).unwrap_or(b'%'),
+      b'+' => b' ',
+      _ => *byte,
+    })?;
+  }
+  Ok(from_utf8_basic(buffer.get(start..).unwrap_or_default())?)
+}
+
+/// Encodes a name or a value. Spaces are written as `+`.
+#[inline]
+pub fn form_urlencoded_encode(component: &[u8], buffer: &mut Vector<u8>) -> crate::Result<()> {
+  // https://url.spec.whatwg.org/#application-x-www-form-urlencoded-percent-encode-set
+  let ascii_set = AsciiSet::NON_ALPHANUMERIC.remove(b'*').remove(b'-').remove(b'.').remove(b'_');
+  for elem in PercentEncode::new(component, ascii_set) {
+    if elem == b"%20" {
+      buffer.push(b'+')?;
+    } else {
+      buffer.extend_from_copyable_slice(elem)?;
+    }
+  }
+  Ok(())
+}
+
+/// Deserializes a `application/x-www-form-urlencoded` body or query string into `T`.
+#[cfg(feature = "serde_urlencoded")]
+#[inline]
+pub fn from_form_urlencoded<'de, T>(bytes: &'de [u8]) -> crate::Result<T>
+where
+  T: serde::Deserialize<'de>,
+{
+  Ok(serde_urlencoded::from_bytes(bytes.strip_prefix(b"?").unwrap_or(bytes))?)
+}
+
+/// Serializes `data` as `application/x-www-form-urlencoded` and appends the result into `buffer`.
+#[cfg(feature = "serde_urlencoded")]
+#[inline]
+pub fn to_form_urlencoded<T>(data: &T, buffer: &mut Vector<u8>) -> crate::Result<()>
+where
+  T: serde::Serialize,
+{
+  buffer.extend_from_copyable_slice(serde_urlencoded::to_string(data)?.as_bytes())?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{FormUrlencodedIter, FormUrlencodedWriter, form_urlencoded_decode},
+    misc::Vector,
+  };
+
+  #[test]
+  fn decodes_pairs() {
+    let mut buffer = Vector::new();
+    let mut iter = FormUrlencodedIter::new(b"?a=1&&b=x+y%21&c&d=");
+    let mut next = || {
+      let (name, value) = iter.next()?;
+      buffer.clear();
+      let value = form_urlencoded_decode(value, &mut buffer).unwrap();
+      Some((name, alloc::string::String::from(value)))
+    };
+    assert_eq!(next(), Some((&b"a"[..], "1".into())));
+    assert_eq!(next(), Some((&b"b"[..], "x y!".into())));
+    assert_eq!(next(), Some((&b"c"[..], "".into())));
+    assert_eq!(next(), Some((&b"d"[..], "".into())));
+    assert_eq!(next(), None);
+  }
+
+  #[test]
+  fn encodes_pairs() {
+    let mut buffer = Vector::new();
+    let _ = FormUrlencodedWriter::new(&mut buffer)
+      .write("a b", "1*2")
+      .unwrap()
+      .write_opt("c", None)
+      .unwrap()
+      .write("d", "é&=")
+      .unwrap();
+    assert_eq!(buffer.as_slice(), b"a+b=1*2&d=%C3%A9%26%3D");
+  }
+}
@@ -231,4 +231,5 @@ create_statics! {
   Warning = "warning";
   WwwAuthenticate = "www-authenticate";
   XCsrfToken = "x-csrf-token";
+  XRequestId = "x-request-id";
 }
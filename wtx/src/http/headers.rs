@@ -1,4 +1,7 @@
-use crate::misc::{Lease, LeaseMut, Vector};
+use crate::{
+  http::{HttpError, TypedHeader, is_tchar},
+  misc::{Lease, LeaseMut, Vector},
+};
 use core::{
   fmt::{Arguments, Debug, Formatter},
   ptr, str,
@@ -75,6 +78,26 @@ impl Headers {
     *trailers = Trailers::None;
   }
 
+  /// Parses the first header named [`TypedHeader::NAME`], if any.
+  ///
+  /// ```rust
+  /// use wtx::http::{ContentType, Headers, Mime};
+  /// let mut headers = Headers::new();
+  /// headers.set(ContentType::json()).unwrap();
+  /// let content_type = headers.get_as::<ContentType<'_>>().unwrap().unwrap();
+  /// assert_eq!(content_type.mime(), Some(Mime::ApplicationJson));
+  /// ```
+  #[inline]
+  pub fn get_as<'this, T>(&'this self) -> crate::Result<Option<T>>
+  where
+    T: TypedHeader<'this>,
+  {
+    let Some(header) = self.get_by_name(T::NAME.into()) else {
+      return Ok(None);
+    };
+    Ok(Some(T::decode(header.value)?))
+  }
+
   /// Returns the header that is referenced by `idx`, if any.
   #[inline]
   pub fn get_by_idx(&self, idx: usize) -> Option<Header<'_, &str>> {
@@ -235,6 +258,45 @@ impl Headers {
     Ok(())
   }
 
+  /// Similar to [`Self::push_from_iter`] but returns an error if the name isn't a token or if the
+  /// value contains CR, LF or NUL. Should be used when headers are composed by untrusted input to
+  /// avoid response splitting.
+  ///
+  /// ```rust
+  /// use wtx::http::{Header, Headers};
+  /// let mut headers = Headers::new();
+  /// assert!(headers.push_from_iter_checked(Header::from_name_and_value("a b", ["c"])).is_err());
+  /// assert!(headers.push_from_iter_checked(Header::from_name_and_value("a", ["b\r\nc: d"])).is_err());
+  /// assert_eq!(headers.headers_len(), 0);
+  /// headers.push_from_iter_checked(Header::from_name_and_value("a", ["b", " c"])).unwrap();
+  /// assert_eq!(headers.get_by_idx(0).unwrap(), Header::from_name_and_value("a", "b c"));
+  /// ```
+  #[inline]
+  pub fn push_from_iter_checked<'bytes, V>(
+    &mut self,
+    header: Header<'bytes, V>,
+  ) -> crate::Result<()>
+  where
+    V: IntoIterator<Item = &'bytes str>,
+    V::IntoIter: Clone,
+  {
+    if header.name.is_empty() || !header.name.bytes().all(is_tchar) {
+      return Err(HttpError::InvalidHeaderName.into());
+    }
+    let iter = header.value.into_iter();
+    for elem in iter.clone() {
+      if elem.bytes().any(|el| matches!(el, b'\0' | b'\n' | b'\r')) {
+        return Err(HttpError::InvalidHeaderValue.into());
+      }
+    }
+    self.push_from_iter(Header {
+      is_sensitive: header.is_sensitive,
+      is_trailer: header.is_trailer,
+      name: header.name,
+      value: iter,
+    })
+  }
+
   /// Similarly to [`Self::push_from_iter`], pushes several headers.
   #[inline]
   pub fn push_from_iter_many<'bytes, const N: usize, V>(
@@ -266,6 +328,72 @@ impl Headers {
     Ok(())
   }
 
+  /// Retains only the headers specified by the predicate.
+  ///
+  /// ```rust
+  /// use wtx::http::{Header, Headers};
+  /// let mut headers = Headers::new();
+  /// headers.push_from_iter(Header::from_name_and_value("name0", ["value0"])).unwrap();
+  /// headers.push_from_iter(Header::from_name_and_value("name1", ["value1"])).unwrap();
+  /// headers.push_from_iter(Header::from_name_and_value("name2", ["value2"])).unwrap();
+  /// headers.retain(|el| el.name != "name1");
+  /// assert_eq!(headers.bytes_len(), 22);
+  /// assert_eq!(headers.headers_len(), 2);
+  /// assert_eq!(headers.get_by_idx(1).unwrap(), Header::from_name_and_value("name2", "value2"));
+  /// ```
+  #[inline]
+  pub fn retain(&mut self, mut cb: impl FnMut(Header<'_, &str>) -> bool) {
+    let mut bytes_len: usize = 0;
+    let mut headers_len: usize = 0;
+    self.trailers = Trailers::None;
+    for idx in 0..self.headers_parts.len() {
+      let Some(header_parts) = self.headers_parts.get(idx).copied() else {
+        break;
+      };
+      if !cb(Self::map(&self.bytes, header_parts)) {
+        continue;
+      }
+      self.bytes.copy_within(header_parts.header_begin..header_parts.header_end, bytes_len);
+      let diff = header_parts.header_begin.wrapping_sub(bytes_len);
+      if let Some(elem) = self.headers_parts.get_mut(headers_len) {
+        *elem = HeaderParts {
+          header_begin: bytes_len,
+          header_end: header_parts.header_end.wrapping_sub(diff),
+          header_len: header_parts.header_len,
+          header_name_end: header_parts.header_name_end.wrapping_sub(diff),
+          is_sensitive: header_parts.is_sensitive,
+          is_trailer: header_parts.is_trailer,
+        };
+      }
+      Self::manage_trailers(header_parts.is_trailer, headers_len, &mut self.trailers);
+      bytes_len = bytes_len.wrapping_add(header_parts.header_len);
+      headers_len = headers_len.wrapping_add(1);
+    }
+    self.bytes.truncate(bytes_len);
+    self.headers_parts.truncate(headers_len);
+  }
+
+  /// Removes all headers named [`TypedHeader::NAME`] and then pushes the canonical representation
+  /// of `header`.
+  ///
+  /// ```rust
+  /// use wtx::http::{ContentLength, Header, Headers};
+  /// let mut headers = Headers::new();
+  /// headers.push_from_iter(Header::from_name_and_value("content-length", ["1"])).unwrap();
+  /// headers.set(ContentLength(2)).unwrap();
+  /// assert_eq!(headers.headers_len(), 1);
+  /// assert_eq!(headers.get_by_idx(0).unwrap(), Header::from_name_and_value("content-length", "2"));
+  /// ```
+  #[inline]
+  pub fn set<'value, T>(&mut self, header: T) -> crate::Result<()>
+  where
+    T: TypedHeader<'value>,
+  {
+    let name: &str = T::NAME.into();
+    self.retain(|el| el.name != name);
+    self.push_from_fmt(Header::from_name_and_value(name, format_args!("{header}")))
+  }
+
   /// If this instance has one or more trailer headers.
   #[inline]
   pub fn trailers(&self) -> Trailers {
@@ -1,7 +1,15 @@
 use crate::{
-  http::{Method, ReqResBuffer, ReqResData, Response},
-  misc::{Lease, UriRef},
+  http::{
+    ContentCoding, Header, Headers, HttpCache, HttpCacheStore, HttpError, HttpInstrumentation,
+    HttpRecord, KnownHeaderName, Method, RedirectPolicy, ReqResBuffer, ReqResData, Response,
+    RetryPolicy, decompress_body,
+  },
+  misc::{
+    Either, GenericTime, Intersperse, Lease, StreamReader, StreamWriter, UriRef, Vector,
+    Xorshift64, simple_seed, sleep, within,
+  },
 };
+use core::mem;
 
 /// Generic HTTP client
 pub trait HttpClient {
@@ -16,6 +24,35 @@ pub trait HttpClient {
     req_id: Self::ReqId,
   ) -> impl Future<Output = crate::Result<Response<ReqResBuffer>>>;
 
+  /// Receives a response whose body is written into `sink` instead of `rrb`. `progress` is called
+  /// after each write with the number of received bytes and the declared length, if any.
+  ///
+  /// By default, the whole body is received through [`Self::recv_res`] and then written at once.
+  #[inline]
+  fn recv_stream<S>(
+    &mut self,
+    rrb: ReqResBuffer,
+    req_id: Self::ReqId,
+    sink: &mut S,
+    mut progress: impl FnMut(u64, Option<u64>),
+  ) -> impl Future<Output = crate::Result<Response<ReqResBuffer>>>
+  where
+    S: StreamWriter,
+  {
+    async move {
+      let mut res = self.recv_res(rrb, req_id).await?;
+      let content_length = res
+        .rrd
+        .headers
+        .get_by_name(KnownHeaderName::ContentLength.into())
+        .and_then(|el| el.value.parse().ok());
+      sink.write_all(&res.rrd.body).await?;
+      progress(u64::try_from(res.rrd.body.len())?, content_length);
+      res.rrd.body.clear();
+      Ok(res)
+    }
+  }
+
   /// Sends a request a [`ReqResData`] and receives a response using [`ReqResBuffer`].
   #[inline]
   fn send_recv_dual<RRD>(
@@ -35,6 +72,190 @@ pub trait HttpClient {
     }
   }
 
+  /// Similar to [`Self::send_recv_single`] but fresh responses stored in `cache` are returned
+  /// without contacting the server. Stale responses are revalidated and new responses are stored
+  /// when allowed by their headers.
+  #[inline]
+  fn send_recv_cached<S>(
+    &mut self,
+    cache: &mut HttpCache<S>,
+    method: Method,
+    mut rrb: ReqResBuffer,
+    uri: &UriRef<'_>,
+  ) -> impl Future<Output = crate::Result<Response<ReqResBuffer>>>
+  where
+    S: HttpCacheStore,
+  {
+    async move {
+      let cm = match cache.lookup(method, &mut rrb, uri).await? {
+        Either::Left(status_code) => return Ok(Response::http2(rrb, status_code)),
+        Either::Right(elem) => elem,
+      };
+      let res = self.send_recv_single(method, rrb, uri).await?;
+      cache.manage_response(cm, method, res, uri).await
+    }
+  }
+
+  /// Similar to [`Self::send_recv_single`] but also attaches the cookies of `cookie_store` that
+  /// match `uri` and stores the cookies sent by the server.
+  #[cfg(feature = "http-cookie")]
+  #[inline]
+  fn send_recv_cookies(
+    &mut self,
+    cookie_store: &mut crate::http::CookieStore,
+    method: Method,
+    mut rrb: ReqResBuffer,
+    uri: &UriRef<'_>,
+  ) -> impl Future<Output = crate::Result<Response<ReqResBuffer>>> {
+    async move {
+      cookie_store.push_cookie_header(&mut rrb.headers, uri)?;
+      let res = self.send_recv_single(method, rrb, uri).await?;
+      cookie_store.store(&res.rrd.headers, uri)?;
+      Ok(res)
+    }
+  }
+
+  /// Similar to [`Self::send_recv_single`] but also advertises all supported content codings
+  /// through the `Accept-Encoding` header and decompresses the received body.
+  ///
+  /// The decompressed body can not exceed `max_body_len` bytes.
+  #[inline]
+  fn send_recv_decompressed(
+    &mut self,
+    max_body_len: usize,
+    method: Method,
+    mut rrb: ReqResBuffer,
+    uri: &UriRef<'_>,
+  ) -> impl Future<Output = crate::Result<Response<ReqResBuffer>>> {
+    async move {
+      if rrb.headers.get_by_name(KnownHeaderName::AcceptEncoding.into()).is_none() {
+        rrb.headers.push_from_iter(Header::from_name_and_value(
+          KnownHeaderName::AcceptEncoding.into(),
+          Intersperse::new(ContentCoding::SUPPORTED.iter().map(|el| el.as_str()), ", "),
+        ))?;
+      }
+      let mut res = self.send_recv_single(method, rrb, uri).await?;
+      decompress_body(&mut Vector::new(), max_body_len, &mut res.rrd)?;
+      Ok(res)
+    }
+  }
+
+  /// Similar to [`Self::send_recv_single`] but also reports the start and the end of the request
+  /// to `instrumentation`.
+  #[inline]
+  fn send_recv_instrumented<I>(
+    &mut self,
+    instrumentation: &I,
+    method: Method,
+    rrb: ReqResBuffer,
+    uri: &UriRef<'_>,
+  ) -> impl Future<Output = crate::Result<Response<ReqResBuffer>>>
+  where
+    I: HttpInstrumentation,
+  {
+    async move {
+      instrumentation.req_start(method, uri.as_str());
+      let now = GenericTime::now();
+      let req_body_len = rrb.body.len();
+      let rslt = self.send_recv_single(method, rrb, uri).await;
+      let res_parts = rslt.as_ref().ok().map(|el| (el.rrd.body.len(), el.status_code));
+      instrumentation.req_end(&HttpRecord {
+        elapsed: now.elapsed().unwrap_or_default(),
+        method,
+        req_body_len,
+        res_body_len: res_parts.map_or(0, |el| el.0),
+        status_code: res_parts.map(|el| el.1),
+        uri: uri.as_str(),
+      });
+      rslt
+    }
+  }
+
+  /// Sends `req` and receives a response using `res`, following redirections according to the
+  /// rules of `policy`.
+  ///
+  /// `req` can be modified between hops, e.g., the body is removed when the method is rewritten to
+  /// GET. Responses that point to other origins are returned as-is unless allowed by
+  /// [`RedirectPolicy::cross_origin`].
+  #[inline]
+  fn send_recv_redirect(
+    &mut self,
+    method: Method,
+    policy: RedirectPolicy,
+    req: &mut ReqResBuffer,
+    res: ReqResBuffer,
+    uri: &UriRef<'_>,
+  ) -> impl Future<Output = crate::Result<Response<ReqResBuffer>>> {
+    async move {
+      let mut hops = 0;
+      let mut local_method = method;
+      let mut local_res = res;
+      let mut local_uri = uri.to_string();
+      loop {
+        let req_id = self.send_req(local_method, &*req, &local_uri.to_ref()).await?;
+        let response = self.recv_res(local_res, req_id).await?;
+        let has_next_hop = policy.manage_hop(
+          &mut hops,
+          &mut local_method,
+          req,
+          &response.rrd.headers,
+          response.status_code,
+          &mut local_uri,
+        )?;
+        if !has_next_hop {
+          return Ok(response);
+        }
+        local_res = response.rrd;
+        local_res.clear();
+      }
+    }
+  }
+
+  /// Sends `req` and receives a response using `res`, retrying failed attempts according to the
+  /// rules of `policy`.
+  ///
+  /// Attempts that exceed the timeouts of `policy` result in [`HttpError::RequestTimeout`].
+  #[inline]
+  fn send_recv_retry(
+    &mut self,
+    method: Method,
+    policy: RetryPolicy,
+    req: &ReqResBuffer,
+    res: ReqResBuffer,
+    uri: &UriRef<'_>,
+  ) -> impl Future<Output = crate::Result<Response<ReqResBuffer>>> {
+    async move {
+      let now = GenericTime::now();
+      let mut attempt: u8 = 0;
+      let mut local_res = res;
+      let mut rng = Xorshift64::from(simple_seed());
+      loop {
+        attempt = attempt.wrapping_add(1);
+        let attempt_timeout = policy.attempt_timeout_of(now.elapsed()?)?;
+        let fut = async {
+          let req_id = self.send_req(method, req, uri).await?;
+          self.recv_res(mem::take(&mut local_res), req_id).await
+        };
+        let rslt = match attempt_timeout {
+          Some(elem) => {
+            within(elem, fut).await?.unwrap_or_else(|| Err(HttpError::RequestTimeout.into()))
+          }
+          None => fut.await,
+        };
+        let res_parts = rslt.as_ref().ok().map(|el| (&el.rrd.headers, el.status_code));
+        let Some(delay) = policy.next_delay(attempt, now.elapsed()?, method, res_parts, &mut rng)
+        else {
+          return rslt;
+        };
+        if let Ok(elem) = rslt {
+          local_res = elem.rrd;
+          local_res.clear();
+        }
+        sleep(delay).await?;
+      }
+    }
+  }
+
   /// Sends a request and receives a response using a single [`ReqResBuffer`].
   #[inline]
   fn send_recv_single(
@@ -59,6 +280,34 @@ pub trait HttpClient {
   where
     RRD: ReqResData,
     RRD::Body: Lease<[u8]>;
+
+  /// Sends a request whose body is incrementally read from the first element of `body` into the
+  /// second element, which avoids storing large bodies in memory.
+  ///
+  /// By default, the whole body is read into memory and then sent through [`Self::send_req`].
+  #[inline]
+  fn send_stream<SR>(
+    &mut self,
+    method: Method,
+    (sr, buffer): (&mut SR, &mut [u8]),
+    headers: &Headers,
+    uri: &UriRef<'_>,
+  ) -> impl Future<Output = crate::Result<Self::ReqId>>
+  where
+    SR: StreamReader,
+  {
+    async move {
+      let mut body = Vector::new();
+      loop {
+        let read = sr.read(buffer).await?;
+        if read == 0 {
+          break;
+        }
+        body.extend_from_copyable_slice(buffer.get(..read).unwrap_or_default())?;
+      }
+      self.send_req(method, (body.as_slice(), headers), uri).await
+    }
+  }
 }
 
 impl<T> HttpClient for &mut T
@@ -76,6 +325,20 @@ where
     (**self).recv_res(rrb, req_id).await
   }
 
+  #[inline]
+  async fn recv_stream<S>(
+    &mut self,
+    rrb: ReqResBuffer,
+    req_id: Self::ReqId,
+    sink: &mut S,
+    progress: impl FnMut(u64, Option<u64>),
+  ) -> crate::Result<Response<ReqResBuffer>>
+  where
+    S: StreamWriter,
+  {
+    (**self).recv_stream(rrb, req_id, sink, progress).await
+  }
+
   #[inline]
   async fn send_req<RRD>(
     &mut self,
@@ -89,14 +352,28 @@ where
   {
     (**self).send_req(method, rrd, uri).await
   }
+
+  #[inline]
+  async fn send_stream<SR>(
+    &mut self,
+    method: Method,
+    body: (&mut SR, &mut [u8]),
+    headers: &Headers,
+    uri: &UriRef<'_>,
+  ) -> crate::Result<Self::ReqId>
+  where
+    SR: StreamReader,
+  {
+    (**self).send_stream(method, body, headers, uri).await
+  }
 }
 
 #[cfg(feature = "http2")]
 mod http2 {
   use crate::{
-    http::{HttpClient, Method, ReqResBuffer, ReqResData, Request, Response},
+    http::{Headers, HttpClient, Method, ReqResBuffer, ReqResData, Request, Response},
     http2::{ClientStream, Http2, Http2Buffer, Http2Data, Http2RecvStatus},
-    misc::{Lease, LeaseMut, Lock, RefCounter, StreamWriter, UriRef},
+    misc::{Lease, LeaseMut, Lock, RefCounter, StreamReader, StreamWriter, UriRef},
   };
 
   impl<HB, HD, SW> HttpClient for Http2<HD, true>
@@ -123,6 +400,26 @@ mod http2 {
       Ok(Response::http2(res_rrb, status_code))
     }
 
+    #[inline]
+    async fn recv_stream<S>(
+      &mut self,
+      rrb: ReqResBuffer,
+      mut req_id: Self::ReqId,
+      sink: &mut S,
+      progress: impl FnMut(u64, Option<u64>),
+    ) -> crate::Result<Response<ReqResBuffer>>
+    where
+      S: StreamWriter,
+    {
+      let (hrs, res_rrb) = req_id.recv_res_stream(rrb, sink, progress).await?;
+      let status_code = match hrs {
+        Http2RecvStatus::Eos(elem) => elem,
+        _ => return Err(crate::Error::ClosedConnection),
+      };
+      req_id.common().clear(false).await?;
+      Ok(Response::http2(res_rrb, status_code))
+    }
+
     #[inline]
     async fn send_req<RRD>(
       &mut self,
@@ -140,6 +437,24 @@ mod http2 {
       }
       Ok(req_id)
     }
+
+    #[inline]
+    async fn send_stream<SR>(
+      &mut self,
+      method: Method,
+      body: (&mut SR, &mut [u8]),
+      headers: &Headers,
+      uri: &UriRef<'_>,
+    ) -> crate::Result<Self::ReqId>
+    where
+      SR: StreamReader,
+    {
+      let mut req_id = self.stream().await?;
+      if req_id.send_req_stream(body, headers, method, uri).await?.is_closed() {
+        return Err(crate::Error::ClosedConnection);
+      }
+      Ok(req_id)
+    }
   }
 }
 
@@ -147,11 +462,11 @@ mod http2 {
 mod http_client_pool {
   use crate::{
     http::{
-      HttpClient, Method, ReqResBuffer, ReqResData, Request, Response,
+      Headers, HttpClient, Method, ReqResBuffer, ReqResData, Request, Response,
       client_pool::{ClientPool, ClientPoolResource},
     },
     http2::{ClientStream, Http2, Http2Buffer, Http2Data, Http2RecvStatus},
-    misc::{Lease, Lock, RefCounter, StreamWriter, UriRef},
+    misc::{Lease, Lock, RefCounter, StreamReader, StreamWriter, UriRef},
     pool::{ResourceManager, SimplePoolResource},
   };
 
@@ -181,6 +496,20 @@ mod http_client_pool {
       (&*self).recv_res(rrb, req_id).await
     }
 
+    #[inline]
+    async fn recv_stream<S>(
+      &mut self,
+      rrb: ReqResBuffer,
+      req_id: Self::ReqId,
+      sink: &mut S,
+      progress: impl FnMut(u64, Option<u64>),
+    ) -> crate::Result<Response<ReqResBuffer>>
+    where
+      S: StreamWriter,
+    {
+      (&*self).recv_stream(rrb, req_id, sink, progress).await
+    }
+
     #[inline]
     async fn send_req<RRD>(
       &mut self,
@@ -194,6 +523,20 @@ mod http_client_pool {
     {
       (&*self).send_req(method, rrd, uri).await
     }
+
+    #[inline]
+    async fn send_stream<SR>(
+      &mut self,
+      method: Method,
+      body: (&mut SR, &mut [u8]),
+      headers: &Headers,
+      uri: &UriRef<'_>,
+    ) -> crate::Result<Self::ReqId>
+    where
+      SR: StreamReader,
+    {
+      (&*self).send_stream(method, body, headers, uri).await
+    }
   }
 
   impl<AUX, HD, RL, RM, SW> HttpClient for &ClientPool<RL, RM>
@@ -228,6 +571,26 @@ mod http_client_pool {
       Ok(Response::http2(res_rrb, status_code))
     }
 
+    #[inline]
+    async fn recv_stream<S>(
+      &mut self,
+      rrb: ReqResBuffer,
+      mut req_id: Self::ReqId,
+      sink: &mut S,
+      progress: impl FnMut(u64, Option<u64>),
+    ) -> crate::Result<Response<ReqResBuffer>>
+    where
+      S: StreamWriter,
+    {
+      let (hrs, res_rrb) = req_id.recv_res_stream(rrb, sink, progress).await?;
+      let status_code = match hrs {
+        Http2RecvStatus::Eos(elem) => elem,
+        _ => return Err(crate::Error::ClosedConnection),
+      };
+      req_id.common().clear(false).await?;
+      Ok(Response::http2(res_rrb, status_code))
+    }
+
     #[inline]
     async fn send_req<RRD>(
       &mut self,
@@ -245,5 +608,23 @@ mod http_client_pool {
       }
       Ok(req_id)
     }
+
+    #[inline]
+    async fn send_stream<SR>(
+      &mut self,
+      method: Method,
+      body: (&mut SR, &mut [u8]),
+      headers: &Headers,
+      uri: &UriRef<'_>,
+    ) -> crate::Result<Self::ReqId>
+    where
+      SR: StreamReader,
+    {
+      let mut req_id = self.lock(uri).await?.client.stream().await?;
+      if req_id.send_req_stream(body, headers, method, uri).await?.is_closed() {
+        return Err(crate::Error::ClosedConnection);
+      }
+      Ok(req_id)
+    }
   }
 }
@@ -0,0 +1,108 @@
+// https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.7
+
+use crate::misc::{ArrayString, FromRadix10, str_split1};
+use core::fmt::Write;
+
+const DAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+const MONTHS: [&str; 12] =
+  ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Formats the number of seconds elapsed since the UNIX epoch as an IMF-fixdate, which is used by
+/// headers like `Date` or `Last-Modified`. E.g., `Sun, 06 Nov 1994 08:49:37 GMT`.
+#[inline]
+pub fn format_http_date(timestamp: u64) -> crate::Result<ArrayString<29>> {
+  let days = timestamp / SECS_PER_DAY;
+  let secs = timestamp % SECS_PER_DAY;
+  let (year, month, day) = civil_from_days(days);
+  let mut rslt = ArrayString::new();
+  rslt.write_fmt(format_args!(
+    "{}, {day:02} {} {year} {:02}:{:02}:{:02} GMT",
+    DAYS.get(usize::try_from(days % 7).unwrap_or_default()).copied().unwrap_or_default(),
+    MONTHS.get(usize::from(month.wrapping_sub(1))).copied().unwrap_or_default(),
+    secs / 3600,
+    secs % 3600 / 60,
+    secs % 60,
+  ))?;
+  Ok(rslt)
+}
+
+/// Parses IMF-fixdate values into the number of seconds elapsed since the UNIX epoch. Obsolete
+/// formats and dates before the epoch are not supported.
+#[inline]
+pub fn parse_http_date(value: &str) -> Option<u64> {
+  let mut iter = str_split1(value.trim(), b' ');
+  let (Some(_), Some(day), Some(month), Some(year), Some(time), Some("GMT"), None) =
+    (iter.next(), iter.next(), iter.next(), iter.next(), iter.next(), iter.next(), iter.next())
+  else {
+    return None;
+  };
+  let ([d0, d1], [y0, y1, y2, y3], [h0, h1, b':', i0, i1, b':', s0, s1]) =
+    (day.as_bytes(), year.as_bytes(), time.as_bytes())
+  else {
+    return None;
+  };
+  let day = u8::from_radix_10(&[*d0, *d1]).ok()?;
+  let month = MONTHS.iter().position(|el| *el == month)?;
+  let year = u64::from_radix_10(&[*y0, *y1, *y2, *y3]).ok()?;
+  let hour = u64::from_radix_10(&[*h0, *h1]).ok()?;
+  let minute = u64::from_radix_10(&[*i0, *i1]).ok()?;
+  let second = u64::from_radix_10(&[*s0, *s1]).ok()?;
+  if year < 1970 || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+    return None;
+  }
+  let days = days_from_civil(year, u8::try_from(month).ok()?.wrapping_add(1), day);
+  Some(
+    days
+      .wrapping_mul(SECS_PER_DAY)
+      .wrapping_add(hour.wrapping_mul(3600))
+      .wrapping_add(minute.wrapping_mul(60))
+      .wrapping_add(second),
+  )
+}
+
+// https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub(crate) fn civil_from_days(days: u64) -> (u64, u8, u8) {
+  let z = days.wrapping_add(719_468);
+  let era = z / 146_097;
+  let doe = z.wrapping_sub(era.wrapping_mul(146_097));
+  let yoe =
+    doe.wrapping_sub(doe / 1460).wrapping_add(doe / 36_524).wrapping_sub(doe / 146_096) / 365;
+  let doy = doe.wrapping_sub(yoe.wrapping_mul(365).wrapping_add(yoe / 4).wrapping_sub(yoe / 100));
+  let mp = doy.wrapping_mul(5).wrapping_add(2) / 153;
+  let day = doy.wrapping_sub(mp.wrapping_mul(153).wrapping_add(2) / 5).wrapping_add(1);
+  let month = if mp < 10 { mp.wrapping_add(3) } else { mp.wrapping_sub(9) };
+  let year = yoe.wrapping_add(era.wrapping_mul(400)).wrapping_add(u64::from(month <= 2));
+  (year, u8::try_from(month).unwrap_or_default(), u8::try_from(day).unwrap_or_default())
+}
+
+// https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: u64, month: u8, day: u8) -> u64 {
+  let year = if month <= 2 { year.wrapping_sub(1) } else { year };
+  let era = year / 400;
+  let yoe = year.wrapping_sub(era.wrapping_mul(400));
+  let mp = u64::from(if month > 2 { month.wrapping_sub(3) } else { month.wrapping_add(9) });
+  let doy = (mp.wrapping_mul(153).wrapping_add(2) / 5).wrapping_add(u64::from(day)).wrapping_sub(1);
+  let doe = yoe.wrapping_mul(365).wrapping_add(yoe / 4).wrapping_sub(yoe / 100).wrapping_add(doy);
+  era.wrapping_mul(146_097).wrapping_add(doe).wrapping_sub(719_468)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::http_date::{format_http_date, parse_http_date};
+
+  #[test]
+  fn format_and_parse() {
+    for (timestamp, date) in [
+      (0, "Thu, 01 Jan 1970 00:00:00 GMT"),
+      (784_111_777, "Sun, 06 Nov 1994 08:49:37 GMT"),
+      (951_782_400, "Tue, 29 Feb 2000 00:00:00 GMT"),
+      (1_735_689_599, "Tue, 31 Dec 2024 23:59:59 GMT"),
+    ] {
+      assert_eq!(format_http_date(timestamp).unwrap().as_str(), date);
+      assert_eq!(parse_http_date(date), Some(timestamp));
+    }
+    assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 06 Foo 1994 08:49:37 GMT"), None);
+  }
+}
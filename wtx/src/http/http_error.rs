@@ -7,8 +7,30 @@ pub enum HttpError {
   BadRequest,
   /// The length of a header field must be within a threshold.
   HeaderFieldIsTooLarge,
+  /// A header name is empty or contains characters that aren't allowed in tokens.
+  InvalidHeaderName,
+  /// A header value contains CR, LF or NUL.
+  InvalidHeaderValue,
   /// Invalid HTTP/2 or HTTP/3 header
   InvalidHttp2pContent,
+  /// A `multipart/form-data` body is malformed.
+  InvalidMultipartBody,
+  /// The `event` or `id` field of a server-sent event contains a line break.
+  InvalidServerSentEventField,
+  /// A structured field value doesn't follow the grammar of RFC 8941 or contains elements that
+  /// can't be serialized.
+  InvalidStructuredField,
+  /// The value of a typed header couldn't be parsed.
+  InvalidTypedHeader(
+    /// Header name
+    KnownHeaderName,
+  ),
+  /// The decompressed version of a body exceeded the specified length.
+  LargeDecompressedBody,
+  /// The headers of a multipart part exceeded the specified length.
+  LargeMultipartHeaders,
+  /// The contents of a multipart part exceeded the specified length.
+  LargeMultipartPart,
   /// Missing Header
   MissingHeader(
     /// Expected header name
@@ -20,6 +42,14 @@ pub enum HttpError {
   MissingResponseStatusCode,
   /// The URI doesn't have any placeholder
   MissingUriPlaceholder,
+  /// A request, including all of its retries, didn't finish within the specified deadline.
+  RequestTimeout,
+  /// The number of parts of a multipart body exceeded the specified maximum.
+  TooManyMultipartParts,
+  /// The number of followed redirections exceeded the configured maximum.
+  TooManyRedirects,
+  /// A proxy server refused to open a tunnel or sent a malformed response.
+  UnsuccessfulProxyTunnel,
   /// Content-Type mismatch
   UnexpectedContentType,
   /// HTTP version does not match the expected method.
@@ -32,6 +62,12 @@ pub enum HttpError {
     /// Received length
     length: usize,
   },
+  /// The content coding is unknown or its feature is not enabled.
+  UnsupportedContentCoding,
+  /// The server didn't negotiate HTTP/2 through ALPN.
+  UnsupportedNegotiatedProtocol,
+  /// The scheme or the credentials of a proxy are not supported.
+  UnsupportedProxy,
   /// URI mismatch
   UriMismatch,
 }
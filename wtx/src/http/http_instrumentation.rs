@@ -0,0 +1,81 @@
+use crate::http::{Method, StatusCode};
+use core::time::Duration;
+
+/// Hooks invoked at the start and at the end of HTTP requests, which allows the collection of
+/// traces or metrics without changing the sending or receiving paths.
+///
+/// Clients can use [`crate::http::HttpClient::send_recv_instrumented`] while servers can use
+/// `crate::http::server_framework::InstrumentationMiddleware`.
+pub trait HttpInstrumentation {
+  /// Called after a response was received or generated.
+  fn req_end(&self, record: &HttpRecord<'_>);
+
+  /// Called before a request is sent or processed.
+  fn req_start(&self, method: Method, uri: &str);
+}
+
+impl HttpInstrumentation for () {
+  #[inline]
+  fn req_end(&self, _: &HttpRecord<'_>) {}
+
+  #[inline]
+  fn req_start(&self, _: Method, _: &str) {}
+}
+
+impl<T> HttpInstrumentation for &T
+where
+  T: HttpInstrumentation,
+{
+  #[inline]
+  fn req_end(&self, record: &HttpRecord<'_>) {
+    (*self).req_end(record);
+  }
+
+  #[inline]
+  fn req_start(&self, method: Method, uri: &str) {
+    (*self).req_start(method, uri);
+  }
+}
+
+/// Summary of a finished request.
+#[derive(Clone, Copy, Debug)]
+pub struct HttpRecord<'any> {
+  /// Time elapsed since [`HttpInstrumentation::req_start`].
+  pub elapsed: Duration,
+  /// See [`Method`].
+  pub method: Method,
+  /// Number of bytes of the request body.
+  pub req_body_len: usize,
+  /// Number of bytes of the response body.
+  pub res_body_len: usize,
+  /// Status code of the response. `None` if the request failed before a response was obtained.
+  pub status_code: Option<StatusCode>,
+  /// Same value given to [`HttpInstrumentation::req_start`].
+  pub uri: &'any str,
+}
+
+/// Emits a `DEBUG` event when requests start and an `INFO` event when requests end.
+#[cfg(feature = "tracing")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TracingInstrumentation;
+
+#[cfg(feature = "tracing")]
+impl HttpInstrumentation for TracingInstrumentation {
+  #[inline]
+  fn req_end(&self, record: &HttpRecord<'_>) {
+    tracing::info!(
+      latency_us = u64::try_from(record.elapsed.as_micros()).unwrap_or(u64::MAX),
+      method = record.method.strings().custom[0],
+      req_body_len = record.req_body_len,
+      res_body_len = record.res_body_len,
+      status = record.status_code.map(u16::from),
+      uri = record.uri,
+      "HTTP request finished"
+    );
+  }
+
+  #[inline]
+  fn req_start(&self, method: Method, uri: &str) {
+    tracing::debug!(method = method.strings().custom[0], uri, "HTTP request started");
+  }
+}
@@ -38,6 +38,14 @@ impl Method {
   ];
   /// The number of variants
   pub const VARIANTS: u8 = 9;
+
+  /// If multiple identical requests have the same effect of a single request.
+  ///
+  /// <https://datatracker.ietf.org/doc/html/rfc9110#section-9.2.2>
+  #[inline]
+  pub const fn is_idempotent(self) -> bool {
+    matches!(self, Self::Delete | Self::Get | Self::Head | Self::Options | Self::Put | Self::Trace)
+  }
 }
 
 #[cfg(feature = "serde")]
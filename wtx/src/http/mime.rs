@@ -1,3 +1,5 @@
+use crate::{http::content_coding::weighted_elems, misc::str_split_once1};
+
 /// Used to specify the data type that is going to be sent to a counterpart.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Mime {
@@ -17,13 +19,136 @@ pub enum Mime {
   ApplicationYaml,
   /// Anything
   Custom(&'static str),
+  /// multipart/byteranges
+  MultipartByteranges,
   /// multipart/form-data
   MultipartFormData,
+  /// text/event-stream
+  TextEventStream,
   /// text/plain
   TextPlain,
 }
 
 impl Mime {
+  /// All variants, except [`Mime::Custom`].
+  pub const KNOWN: [Self; 11] = [
+    Self::ApplicationGrpc,
+    Self::ApplicationJson,
+    Self::ApplicationOctetStream,
+    Self::ApplicationVndGoogleProtobuf,
+    Self::ApplicationXml,
+    Self::ApplicationXWwwFormUrlEncoded,
+    Self::ApplicationYaml,
+    Self::MultipartByteranges,
+    Self::MultipartFormData,
+    Self::TextEventStream,
+    Self::TextPlain,
+  ];
+
+  /// Maps the value of a `Content-Type` header to one of the [`Mime::KNOWN`] variants. Parameters
+  /// like `charset` are ignored.
+  ///
+  /// ```rust
+  /// use wtx::http::Mime;
+  /// let elem = Mime::from_content_type("Application/JSON; charset=utf-8");
+  /// assert_eq!(elem, Some(Mime::ApplicationJson));
+  /// assert_eq!(Mime::from_content_type("image/png"), None);
+  /// ```
+  #[inline]
+  pub fn from_content_type(content_type: &str) -> Option<Self> {
+    let essence = essence(content_type);
+    Self::KNOWN.into_iter().find(|el| el.as_str().eq_ignore_ascii_case(essence))
+  }
+
+  /// Guesses the media type of a file extension. Unknown extensions are mapped to
+  /// `application/octet-stream`.
+  #[inline]
+  pub fn from_extension(ext: &str) -> Self {
+    let mut buffer = [0; 5];
+    let Some(lower) = buffer.get_mut(..ext.len()) else {
+      return Self::ApplicationOctetStream;
+    };
+    lower.copy_from_slice(ext.as_bytes());
+    lower.make_ascii_lowercase();
+    match &*lower {
+      b"avif" => Self::Custom("image/avif"),
+      b"css" => Self::Custom("text/css; charset=utf-8"),
+      b"csv" => Self::Custom("text/csv; charset=utf-8"),
+      b"gif" => Self::Custom("image/gif"),
+      b"htm" | b"html" => Self::Custom("text/html; charset=utf-8"),
+      b"ico" => Self::Custom("image/x-icon"),
+      b"jpeg" | b"jpg" => Self::Custom("image/jpeg"),
+      b"js" | b"mjs" => Self::Custom("text/javascript; charset=utf-8"),
+      b"json" | b"map" => Self::ApplicationJson,
+      b"md" => Self::Custom("text/markdown; charset=utf-8"),
+      b"mp3" => Self::Custom("audio/mpeg"),
+      b"mp4" => Self::Custom("video/mp4"),
+      b"ogg" => Self::Custom("audio/ogg"),
+      b"otf" => Self::Custom("font/otf"),
+      b"pdf" => Self::Custom("application/pdf"),
+      b"png" => Self::Custom("image/png"),
+      b"svg" => Self::Custom("image/svg+xml"),
+      b"ttf" => Self::Custom("font/ttf"),
+      b"txt" => Self::Custom("text/plain; charset=utf-8"),
+      b"wasm" => Self::Custom("application/wasm"),
+      b"wav" => Self::Custom("audio/wav"),
+      b"webm" => Self::Custom("video/webm"),
+      b"webp" => Self::Custom("image/webp"),
+      b"woff" => Self::Custom("font/woff"),
+      b"woff2" => Self::Custom("font/woff2"),
+      b"xml" => Self::ApplicationXml,
+      b"yaml" | b"yml" => Self::ApplicationYaml,
+      b"zip" => Self::Custom("application/zip"),
+      _ => Self::ApplicationOctetStream,
+    }
+  }
+
+  /// Selects the element of `supported` with the highest quality value of a received `Accept`
+  /// value. More specific media ranges have precedence over wildcards.
+  ///
+  /// Ties are resolved according to the order of `supported`. Returns [`Option::None`] if
+  /// nothing is acceptable. An empty `accept` accepts everything.
+  ///
+  /// ```rust
+  /// use wtx::http::Mime;
+  /// let supported = &[Mime::ApplicationJson, Mime::TextPlain];
+  /// let elem = Mime::negotiate("text/*, application/json;q=0.5", supported);
+  /// assert_eq!(elem, Some(Mime::TextPlain));
+  /// ```
+  #[inline]
+  pub fn negotiate(accept: &str, supported: &[Self]) -> Option<Self> {
+    if accept.trim_ascii().is_empty() {
+      return supported.first().copied();
+    }
+    let mut rslt = (None, 0);
+    for mime in supported {
+      let Some((ty, subty)) = str_split_once1(essence(mime.as_str()), b'/') else {
+        continue;
+      };
+      let mut best = None;
+      for (range, q) in weighted_elems(accept) {
+        let Some((range_ty, range_subty)) = str_split_once1(range, b'/') else {
+          continue;
+        };
+        let specificity = match (range_ty, range_subty) {
+          ("*", "*") => 0,
+          (_, "*") if range_ty.eq_ignore_ascii_case(ty) => 1,
+          _ if range_ty.eq_ignore_ascii_case(ty) && range_subty.eq_ignore_ascii_case(subty) => 2,
+          _ => continue,
+        };
+        if best.is_none_or(|(local_specificity, _)| specificity > local_specificity) {
+          best = Some((specificity, q));
+        }
+      }
+      if let Some((_, q)) = best {
+        if q > rslt.1 {
+          rslt = (Some(*mime), q);
+        }
+      }
+    }
+    rslt.0
+  }
+
   /// Common string representation.
   #[inline]
   pub fn as_str(&self) -> &'static str {
@@ -36,8 +161,32 @@ impl Mime {
       Self::ApplicationXWwwFormUrlEncoded => "application/x-www-form-urlencoded",
       Self::ApplicationYaml => "application/yaml",
       Self::Custom(el) => el,
+      Self::MultipartByteranges => "multipart/byteranges",
       Self::MultipartFormData => "multipart/form-data",
+      Self::TextEventStream => "text/event-stream",
       Self::TextPlain => "text/plain",
     }
   }
 }
+
+// Media type without parameters.
+pub(crate) fn essence(value: &str) -> &str {
+  str_split_once1(value, b';').map_or(value, |el| el.0).trim_ascii()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::Mime;
+
+  #[test]
+  fn negotiate() {
+    let all = &[Mime::ApplicationJson, Mime::Custom("text/html; charset=utf-8"), Mime::TextPlain];
+    assert_eq!(Mime::negotiate("", all), Some(Mime::ApplicationJson));
+    assert_eq!(Mime::negotiate("*/*", all), Some(Mime::ApplicationJson));
+    assert_eq!(Mime::negotiate("TEXT/HTML", all), Some(Mime::Custom("text/html; charset=utf-8")));
+    assert_eq!(Mime::negotiate("text/*;q=0.5, text/plain", all), Some(Mime::TextPlain));
+    assert_eq!(Mime::negotiate("*/*;q=0.1, application/json;q=0", all), Some(all[1]));
+    assert_eq!(Mime::negotiate("image/png", all), None);
+    assert_eq!(Mime::negotiate("text/*;q=0", all), None);
+  }
+}
@@ -24,3 +24,8 @@ pub fn state_tuple_wo_uri<CA, SA>(
   let (body, headers, _) = req.rrd.parts_mut();
   (conn_aux, stream_aux, Request { method: req.method, rrd: (body, headers), version: req.version })
 }
+
+// `tchar` of RFC 9110, which composes tokens like header names or media types.
+pub(crate) fn is_tchar(byte: u8) -> bool {
+  byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+}
@@ -0,0 +1,53 @@
+//! `multipart/form-data` bodies.
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc7578>
+
+mod multipart_builder;
+mod multipart_parser;
+mod multipart_part;
+#[cfg(test)]
+mod tests;
+
+pub use multipart_builder::MultipartBuilder;
+pub use multipart_parser::{MultipartEvent, MultipartParser};
+pub use multipart_part::MultipartPart;
+
+use crate::misc::{ArrayString, Rng};
+
+/// Boundaries can not be longer than 70 bytes.
+const MAX_BOUNDARY_LEN: usize = 70;
+
+/// Extracts the `boundary` parameter of a `multipart/form-data` Content-Type header value.
+#[inline]
+pub fn boundary(content_type: &str) -> Option<&str> {
+  let mut params = content_type.split(';');
+  let mime = params.next()?.trim();
+  if !mime.eq_ignore_ascii_case(crate::http::Mime::MultipartFormData.as_str()) {
+    return None;
+  }
+  for param in params {
+    let Some((name, value)) = crate::misc::str_split_once1(param, b'=') else {
+      continue;
+    };
+    if !name.trim().eq_ignore_ascii_case("boundary") {
+      continue;
+    }
+    let value = value.trim();
+    let value = value.strip_prefix('"').and_then(|el| el.strip_suffix('"')).unwrap_or(value);
+    return (!value.is_empty() && value.len() <= MAX_BOUNDARY_LEN).then_some(value);
+  }
+  None
+}
+
+/// 32 random hexadecimal characters.
+pub(crate) fn random_boundary<RNG>(rng: &mut RNG) -> crate::Result<ArrayString<32>>
+where
+  RNG: Rng,
+{
+  const HEX: &[u8; 16] = b"0123456789abcdef";
+  ArrayString::from_iter(rng.u8_16().into_iter().flat_map(|byte| {
+    let lhs = HEX.get(usize::from(byte >> 4)).copied().unwrap_or_default();
+    let rhs = HEX.get(usize::from(byte & 15)).copied().unwrap_or_default();
+    [lhs, rhs]
+  }))
+}
@@ -0,0 +1,112 @@
+use crate::{
+  http::{Header, KnownHeaderName, Mime, ReqResBuffer, multipart::random_boundary},
+  misc::{ArrayString, Rng, Vector},
+};
+
+/// Writes `multipart/form-data` bodies directly into the body of a [`ReqResBuffer`].
+///
+/// The `Content-Type` header, which contains the randomly generated boundary, is only pushed
+/// after calling [`MultipartBuilder::finish`].
+#[derive(Debug)]
+pub struct MultipartBuilder<'rrb> {
+  boundary: ArrayString<32>,
+  rrb: &'rrb mut ReqResBuffer,
+}
+
+impl<'rrb> MultipartBuilder<'rrb> {
+  /// Creates a new instance with a boundary composed by 32 random hexadecimal characters.
+  #[inline]
+  pub fn new<RNG>(rng: &mut RNG, rrb: &'rrb mut ReqResBuffer) -> crate::Result<Self>
+  where
+    RNG: Rng,
+  {
+    Ok(Self { boundary: random_boundary(rng)?, rrb })
+  }
+
+  /// Boundary that separates parts.
+  #[inline]
+  pub fn boundary(&self) -> &str {
+    self.boundary.as_str()
+  }
+
+  /// Text field.
+  #[inline]
+  pub fn field(&mut self, name: &str, value: &str) -> crate::Result<()> {
+    self.part(name, None, |body| body.extend_from_copyable_slice(value.as_bytes()))
+  }
+
+  /// File field.
+  #[inline]
+  pub fn file(&mut self, name: &str, filename: &str, mime: Mime, data: &[u8]) -> crate::Result<()> {
+    self.file_with(name, filename, mime, |body| body.extend_from_copyable_slice(data))
+  }
+
+  /// File field whose contents are written by `cb`, which avoids intermediary buffers.
+  #[inline]
+  pub fn file_with(
+    &mut self,
+    name: &str,
+    filename: &str,
+    mime: Mime,
+    cb: impl FnOnce(&mut Vector<u8>) -> crate::Result<()>,
+  ) -> crate::Result<()> {
+    self.part(name, Some((filename, mime)), cb)
+  }
+
+  /// Writes the closing delimiter and pushes the `Content-Type` header.
+  #[inline]
+  pub fn finish(self) -> crate::Result<()> {
+    let _ = self.rrb.body.extend_from_copyable_slices([
+      "--".as_bytes(),
+      self.boundary.as_str().as_bytes(),
+      "--\r\n".as_bytes(),
+    ])?;
+    self.rrb.headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::ContentType.into(),
+      [Mime::MultipartFormData.as_str(), "; boundary=", self.boundary.as_str()],
+    ))?;
+    Ok(())
+  }
+
+  fn part(
+    &mut self,
+    name: &str,
+    file: Option<(&str, Mime)>,
+    cb: impl FnOnce(&mut Vector<u8>) -> crate::Result<()>,
+  ) -> crate::Result<()> {
+    let body = &mut self.rrb.body;
+    let _ = body.extend_from_copyable_slices([
+      "--".as_bytes(),
+      self.boundary.as_str().as_bytes(),
+      "\r\nContent-Disposition: form-data; name=\"".as_bytes(),
+    ])?;
+    push_quoted(body, name)?;
+    if let Some((filename, mime)) = file {
+      body.extend_from_copyable_slice(b"\"; filename=\"")?;
+      push_quoted(body, filename)?;
+      let _ = body.extend_from_copyable_slices([
+        "\"\r\nContent-Type: ".as_bytes(),
+        mime.as_str().as_bytes(),
+        "\r\n\r\n".as_bytes(),
+      ])?;
+    } else {
+      body.extend_from_copyable_slice(b"\"\r\n\r\n")?;
+    }
+    cb(body)?;
+    body.extend_from_copyable_slice(b"\r\n")?;
+    Ok(())
+  }
+}
+
+// https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#multipart-form-data
+fn push_quoted(body: &mut Vector<u8>, value: &str) -> crate::Result<()> {
+  for byte in value.bytes() {
+    match byte {
+      b'"' => body.extend_from_copyable_slice(b"%22")?,
+      b'\n' => body.extend_from_copyable_slice(b"%0A")?,
+      b'\r' => body.extend_from_copyable_slice(b"%0D")?,
+      _ => body.push(byte)?,
+    }
+  }
+  Ok(())
+}
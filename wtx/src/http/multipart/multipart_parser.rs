@@ -0,0 +1,242 @@
+use crate::{
+  http::{
+    Headers, HttpError, KnownHeaderName,
+    multipart::{MAX_BOUNDARY_LEN, MultipartPart, boundary},
+  },
+  misc::{ArrayVector, Vector, bytes_pos_any},
+};
+use core::mem;
+
+/// Delimiters are composed by a CRLF, two hyphens and the boundary.
+const MAX_DELIMITER_LEN: usize = MAX_BOUNDARY_LEN + 4;
+
+/// Elements emitted by [`MultipartParser`] in the same order they appear in a body.
+#[derive(Debug)]
+pub enum MultipartEvent<'any> {
+  /// Chunk of the contents of the current part. Can be emitted several times for the same part.
+  Data(&'any [u8]),
+  /// The current part ended.
+  End,
+  /// A new part started.
+  Start(MultipartPart<'any>),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum State {
+  Data,
+  Delimiter,
+  Epilogue,
+  Headers,
+  Preamble,
+}
+
+/// Incremental `multipart/form-data` parser.
+///
+/// Bodies can be fed in arbitrary chunks and the contents of each part are delivered to a
+/// callback, which can then stream files to any sink. Only the bytes that might belong to a
+/// delimiter that is split between two chunks are internally buffered.
+#[derive(Debug)]
+pub struct MultipartParser {
+  buffer: Vector<u8>,
+  delimiter: ArrayVector<u8, MAX_DELIMITER_LEN>,
+  max_headers_len: usize,
+  max_part_len: usize,
+  max_parts: usize,
+  part_len: usize,
+  parts: usize,
+  state: State,
+}
+
+impl MultipartParser {
+  /// Creates a new instance from the value of a `Content-Type` header.
+  #[inline]
+  pub fn new(content_type: &str) -> crate::Result<Self> {
+    let Some(elem) = boundary(content_type) else {
+      return Err(HttpError::UnexpectedContentType.into());
+    };
+    Self::from_boundary(elem)
+  }
+
+  /// Creates a new instance from the `Content-Type` header of `headers`, usually the headers of a
+  /// received request.
+  #[inline]
+  pub fn from_headers(headers: &Headers) -> crate::Result<Self> {
+    let Some(elem) = headers.get_by_name(KnownHeaderName::ContentType.into()) else {
+      return Err(HttpError::MissingHeader(KnownHeaderName::ContentType).into());
+    };
+    Self::new(elem.value)
+  }
+
+  /// Creates a new instance from a raw boundary.
+  #[inline]
+  pub fn from_boundary(boundary: &str) -> crate::Result<Self> {
+    if boundary.is_empty() || boundary.len() > MAX_BOUNDARY_LEN {
+      return Err(HttpError::InvalidMultipartBody.into());
+    }
+    let mut delimiter = ArrayVector::new();
+    delimiter.extend_from_copyable_slice(b"\r\n--")?;
+    delimiter.extend_from_copyable_slice(boundary.as_bytes())?;
+    Ok(Self {
+      buffer: Vector::new(),
+      delimiter,
+      max_headers_len: 8 * 1024,
+      max_part_len: 16 * 1024 * 1024,
+      max_parts: 64,
+      part_len: 0,
+      parts: 0,
+      state: State::Preamble,
+    })
+  }
+
+  /// Maximum number of bytes of the headers of each part. Defaults to 8KiB.
+  #[inline]
+  #[must_use]
+  pub const fn max_headers_len(mut self, elem: usize) -> Self {
+    self.max_headers_len = elem;
+    self
+  }
+
+  /// Maximum number of bytes of the contents of each part. Defaults to 16MiB.
+  #[inline]
+  #[must_use]
+  pub const fn max_part_len(mut self, elem: usize) -> Self {
+    self.max_part_len = elem;
+    self
+  }
+
+  /// Maximum number of parts. Defaults to 64.
+  #[inline]
+  #[must_use]
+  pub const fn max_parts(mut self, elem: usize) -> Self {
+    self.max_parts = elem;
+    self
+  }
+
+  /// Processes the next chunk of a body.
+  #[inline]
+  pub fn feed(
+    &mut self,
+    chunk: &[u8],
+    mut cb: impl FnMut(MultipartEvent<'_>) -> crate::Result<()>,
+  ) -> crate::Result<()> {
+    if self.buffer.is_empty() {
+      let consumed = self.process(chunk, &mut cb)?;
+      self.buffer.extend_from_copyable_slice(chunk.get(consumed..).unwrap_or_default())?;
+    } else {
+      self.buffer.extend_from_copyable_slice(chunk)?;
+      let buffer = mem::take(&mut self.buffer);
+      let rslt = self.process(&buffer, &mut cb);
+      self.buffer = buffer;
+      let _ = self.buffer.drain(..rslt?);
+    }
+    Ok(())
+  }
+
+  /// Should be called after the last chunk. Returns an error if the closing delimiter wasn't
+  /// found.
+  #[inline]
+  pub fn finish(&self) -> crate::Result<()> {
+    if self.state != State::Epilogue {
+      return Err(HttpError::InvalidMultipartBody.into());
+    }
+    Ok(())
+  }
+
+  /// Shortcut for bodies that are entirely stored in memory.
+  #[inline]
+  pub fn parse(
+    &mut self,
+    body: &[u8],
+    cb: impl FnMut(MultipartEvent<'_>) -> crate::Result<()>,
+  ) -> crate::Result<()> {
+    self.feed(body, cb)?;
+    self.finish()
+  }
+
+  // Returns the number of consumed bytes.
+  fn process(
+    &mut self,
+    bytes: &[u8],
+    cb: &mut impl FnMut(MultipartEvent<'_>) -> crate::Result<()>,
+  ) -> crate::Result<usize> {
+    let mut idx: usize = 0;
+    loop {
+      let rest = bytes.get(idx..).unwrap_or_default();
+      match self.state {
+        State::Data => {
+          let delimiter = self.delimiter.as_slice();
+          let (data, has_delimiter) = match bytes_pos_any(rest, delimiter) {
+            Some(pos) => (rest.get(..pos).unwrap_or_default(), true),
+            None => {
+              let len = rest.len().saturating_sub(delimiter.len());
+              (rest.get(..len).unwrap_or_default(), false)
+            }
+          };
+          self.part_len = self.part_len.wrapping_add(data.len());
+          if self.part_len > self.max_part_len {
+            return Err(HttpError::LargeMultipartPart.into());
+          }
+          if !data.is_empty() {
+            cb(MultipartEvent::Data(data))?;
+          }
+          idx = idx.wrapping_add(data.len());
+          if !has_delimiter {
+            return Ok(idx);
+          }
+          cb(MultipartEvent::End)?;
+          idx = idx.wrapping_add(delimiter.len());
+          self.state = State::Delimiter;
+        }
+        State::Delimiter => {
+          if rest.starts_with(b"--") {
+            self.state = State::Epilogue;
+            continue;
+          }
+          let padding = rest.iter().take_while(|el| matches!(el, b' ' | b'\t')).count();
+          match rest.get(padding..).unwrap_or_default() {
+            [b'\r', b'\n', ..] => {
+              idx = idx.wrapping_add(padding).wrapping_add(2);
+              self.state = State::Headers;
+            }
+            [] | [b'-' | b'\r'] if rest.len() <= self.max_headers_len => return Ok(idx),
+            _ => return Err(HttpError::InvalidMultipartBody.into()),
+          }
+        }
+        State::Epilogue => return Ok(bytes.len()),
+        State::Headers => {
+          if rest.starts_with(b"\r\n") {
+            return Err(HttpError::MissingHeader(KnownHeaderName::ContentDisposition).into());
+          }
+          let Some(pos) = bytes_pos_any(rest, b"\r\n\r\n") else {
+            if rest.len() > self.max_headers_len {
+              return Err(HttpError::LargeMultipartHeaders.into());
+            }
+            return Ok(idx);
+          };
+          if pos > self.max_headers_len {
+            return Err(HttpError::LargeMultipartHeaders.into());
+          }
+          if self.parts >= self.max_parts {
+            return Err(HttpError::TooManyMultipartParts.into());
+          }
+          cb(MultipartEvent::Start(MultipartPart::from_headers(
+            rest.get(..pos).unwrap_or_default(),
+          )?))?;
+          idx = idx.wrapping_add(pos).wrapping_add(4);
+          self.part_len = 0;
+          self.parts = self.parts.wrapping_add(1);
+          self.state = State::Data;
+        }
+        State::Preamble => {
+          // The first delimiter doesn't need to be preceded by a CRLF.
+          let dash_boundary = self.delimiter.get(2..).unwrap_or_default();
+          let Some(pos) = bytes_pos_any(rest, dash_boundary) else {
+            return Ok(idx.wrapping_add(rest.len().saturating_sub(dash_boundary.len())));
+          };
+          idx = idx.wrapping_add(pos).wrapping_add(dash_boundary.len());
+          self.state = State::Delimiter;
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,119 @@
+use crate::{
+  http::{HttpError, KnownHeaderName},
+  misc::{from_utf8_basic, str_split_once1, str_split1},
+};
+
+/// Metadata of a part contained in a `multipart/form-data` body.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MultipartPart<'any> {
+  content_type: Option<&'any str>,
+  filename: Option<&'any str>,
+  headers: &'any str,
+  name: &'any str,
+}
+
+impl<'any> MultipartPart<'any> {
+  /// Value of the `Content-Type` header, if any.
+  #[inline]
+  pub const fn content_type(&self) -> Option<&'any str> {
+    self.content_type
+  }
+
+  /// The `filename` parameter of the `Content-Disposition` header. Present when the part
+  /// represents a file.
+  #[inline]
+  pub const fn filename(&self) -> Option<&'any str> {
+    self.filename
+  }
+
+  /// All the headers of this part.
+  #[inline]
+  pub fn headers(&self) -> impl Iterator<Item = (&'any str, &'any str)> {
+    str_split1(self.headers, b'\n').filter_map(|line| {
+      let (name, value) = str_split_once1(line, b':')?;
+      Some((name.trim(), value.trim()))
+    })
+  }
+
+  /// The `name` parameter of the `Content-Disposition` header.
+  #[inline]
+  pub const fn name(&self) -> &'any str {
+    self.name
+  }
+
+  pub(crate) fn from_headers(bytes: &'any [u8]) -> crate::Result<Self> {
+    let headers = from_utf8_basic(bytes).map_err(|_err| HttpError::InvalidMultipartBody)?;
+    let mut content_disposition = None;
+    let mut content_type = None;
+    for line in str_split1(headers, b'\n') {
+      let Some((name, value)) = str_split_once1(line, b':') else {
+        return Err(HttpError::InvalidMultipartBody.into());
+      };
+      let name = name.trim();
+      if name.eq_ignore_ascii_case(KnownHeaderName::ContentDisposition.into()) {
+        content_disposition = Some(value.trim());
+        continue;
+      }
+      if name.eq_ignore_ascii_case(KnownHeaderName::ContentType.into()) {
+        content_type = Some(value.trim());
+      }
+    }
+    let Some(content_disposition) = content_disposition else {
+      return Err(HttpError::MissingHeader(KnownHeaderName::ContentDisposition).into());
+    };
+    let (filename, name) = disposition_params(content_disposition)?;
+    Ok(Self { content_type, filename, headers, name })
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc7578#section-4.2
+fn disposition_params(content_disposition: &str) -> crate::Result<(Option<&str>, &str)> {
+  let (ty, mut rest) =
+    str_split_once1(content_disposition, b';').unwrap_or((content_disposition, ""));
+  if !ty.trim().eq_ignore_ascii_case("form-data") {
+    return Err(HttpError::InvalidMultipartBody.into());
+  }
+  let mut filename = None;
+  let mut name = None;
+  loop {
+    rest = rest.trim_start_matches([' ', '\t', ';']);
+    if rest.is_empty() {
+      break;
+    }
+    let Some((key, after)) = str_split_once1(rest, b'=') else {
+      return Err(HttpError::InvalidMultipartBody.into());
+    };
+    let after = after.trim_start();
+    let (value, next) = if let Some(quoted) = after.strip_prefix('"') {
+      let mut is_escaped = false;
+      let mut end = None;
+      for (idx, byte) in quoted.bytes().enumerate() {
+        match (is_escaped, byte) {
+          (false, b'"') => {
+            end = Some(idx);
+            break;
+          }
+          (false, b'\\') => is_escaped = true,
+          _ => is_escaped = false,
+        }
+      }
+      let Some(end) = end else {
+        return Err(HttpError::InvalidMultipartBody.into());
+      };
+      (quoted.get(..end).unwrap_or_default(), quoted.get(end.wrapping_add(1)..).unwrap_or_default())
+    } else {
+      let (value, next) = str_split_once1(after, b';').unwrap_or((after, ""));
+      (value.trim_end(), next)
+    };
+    match key.trim() {
+      el if el.eq_ignore_ascii_case("filename") => filename = Some(value),
+      el if el.eq_ignore_ascii_case("name") => name = Some(value),
+      _ => {}
+    }
+    rest = next;
+  }
+  let Some(name) = name else {
+    return Err(HttpError::InvalidMultipartBody.into());
+  };
+  Ok((filename, name))
+}
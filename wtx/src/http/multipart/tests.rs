@@ -0,0 +1,104 @@
+use crate::{
+  http::{
+    KnownHeaderName, Mime, ReqResBuffer,
+    multipart::{MultipartBuilder, MultipartEvent, MultipartParser, boundary},
+  },
+  misc::{Vector, Xorshift64, simple_seed},
+};
+use alloc::{string::String, vec::Vec};
+
+static BODY: &[u8] = b"preamble\r\n--foo\r\n\
+  Content-Disposition: form-data; name=\"a\"\r\n\r\n\
+  1\r\n--foo  \r\n\
+  Content-Disposition: form-data; name=\"b\"; filename=\"c;d.txt\"\r\n\
+  Content-Type: text/plain\r\n\r\n\
+  line0\r\nline1\r\n--foo--\r\nepilogue";
+
+#[test]
+fn boundaries_are_extracted() {
+  assert_eq!(boundary("multipart/form-data; boundary=foo"), Some("foo"));
+  assert_eq!(boundary("Multipart/Form-Data;charset=utf-8; Boundary=\"a b\""), Some("a b"));
+  assert_eq!(boundary("multipart/form-data"), None);
+  assert_eq!(boundary("text/plain; boundary=foo"), None);
+}
+
+#[test]
+fn built_bodies_are_parsed() {
+  let mut rrb = ReqResBuffer::empty();
+  let mut builder = MultipartBuilder::new(&mut Xorshift64::from(simple_seed()), &mut rrb).unwrap();
+  builder.field("na\"me", "value").unwrap();
+  builder.file("file", "a.bin", Mime::ApplicationOctetStream, &[0, 1, 2]).unwrap();
+  builder.finish().unwrap();
+  let content_type = rrb.headers.get_by_name(KnownHeaderName::ContentType.into()).unwrap();
+  let mut parser = MultipartParser::new(content_type.value).unwrap();
+  let parts = collect(&mut parser, [rrb.body.as_slice()]);
+  assert_eq!(
+    parts,
+    [
+      (String::from("na%22me"), None, Vec::from(*b"value")),
+      (String::from("file"), Some(String::from("a.bin")), Vec::from([0, 1, 2])),
+    ]
+  );
+}
+
+#[test]
+fn chunked_bodies_are_parsed() {
+  for chunk_len in 1..BODY.len() {
+    let mut parser = MultipartParser::from_boundary("foo").unwrap();
+    let parts = collect(&mut parser, BODY.chunks(chunk_len));
+    assert_eq!(parts.len(), 2, "{chunk_len}");
+    assert_eq!(parts[0], (String::from("a"), None, Vec::from(*b"1")));
+    assert_eq!(
+      parts[1],
+      (String::from("b"), Some(String::from("c;d.txt")), Vec::from(*b"line0\r\nline1"))
+    );
+  }
+}
+
+#[test]
+fn parsers_are_created_from_headers() {
+  let mut rrb = ReqResBuffer::empty();
+  assert!(MultipartParser::from_headers(&rrb.headers).is_err());
+  MultipartBuilder::new(&mut Xorshift64::from(simple_seed()), &mut rrb).unwrap().finish().unwrap();
+  let mut parser = MultipartParser::from_headers(&rrb.headers).unwrap();
+  assert!(collect(&mut parser, [rrb.body.as_slice()]).is_empty());
+}
+
+#[test]
+fn limits_are_enforced() {
+  let mut parser = MultipartParser::from_boundary("foo").unwrap().max_part_len(4);
+  assert!(parser.parse(BODY, |_| Ok(())).is_err());
+  let mut parser = MultipartParser::from_boundary("foo").unwrap().max_parts(1);
+  assert!(parser.parse(BODY, |_| Ok(())).is_err());
+  let mut parser = MultipartParser::from_boundary("foo").unwrap();
+  assert!(parser.parse(BODY.get(..BODY.len() - 20).unwrap(), |_| Ok(())).is_err());
+}
+
+fn collect<'any>(
+  parser: &mut MultipartParser,
+  chunks: impl IntoIterator<Item = &'any [u8]>,
+) -> Vec<(String, Option<String>, Vec<u8>)> {
+  let mut data = Vector::new();
+  let mut parts = Vec::new();
+  let mut metadata = None;
+  for chunk in chunks {
+    parser
+      .feed(chunk, |event| {
+        match event {
+          MultipartEvent::Data(elem) => data.extend_from_copyable_slice(elem)?,
+          MultipartEvent::End => {
+            let (name, filename) = metadata.take().unwrap();
+            parts.push((name, filename, Vec::from(data.as_slice())));
+            data.clear();
+          }
+          MultipartEvent::Start(elem) => {
+            metadata = Some((String::from(elem.name()), elem.filename().map(String::from)));
+          }
+        }
+        Ok(())
+      })
+      .unwrap();
+  }
+  parser.finish().unwrap();
+  parts
+}
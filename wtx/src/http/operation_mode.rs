@@ -1,6 +1,10 @@
 use crate::http::{Protocol, ReqResBuffer, Request};
 use core::net::IpAddr;
 
+#[cfg(all(feature = "http2", feature = "smol"))]
+/// Manual server stream backed by smol structures.
+pub type ManualServerStreamSmol<CA, HB, SA, SW> =
+  ManualStream<CA, crate::http2::ServerStream<crate::http2::Http2DataSmol<HB, SW, false>>, SA>;
 #[cfg(all(feature = "http2", feature = "tokio"))]
 /// Manual server stream backed by tokio structures.
 pub type ManualServerStreamTokio<CA, HB, SA, SW> =
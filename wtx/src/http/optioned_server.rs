@@ -1,12 +1,19 @@
 //! Optioned high-level abstraction for servers. You can use one of listed suggestions or
 //! create your own.
+//!
+//! WebSocket servers are available for `smol` and `tokio` while HTTP/2 servers are only available
+//! for `tokio`.
 //
 // FIXME(STABLE): Return type notation
 
 #[cfg(all(feature = "http2", feature = "tokio"))]
 mod http2_tokio;
-#[cfg(all(feature = "pool", feature = "tokio", feature = "web-socket-handshake"))]
-mod web_socket_tokio;
+#[cfg(all(
+  feature = "pool",
+  any(feature = "smol", feature = "tokio"),
+  feature = "web-socket-handshake"
+))]
+mod web_socket;
 
 /// Optioned abstractions of low-level servers.
 #[derive(Debug)]
@@ -1,18 +1,55 @@
 use crate::{
   http::{
-    AutoStream, ManualServerStreamTokio, OperationMode, Protocol, ReqResBuffer, Request, Response,
-    optioned_server::OptionedServer,
+    AutoStream, ConnLimits, ManualServerStreamTokio, OperationMode, Protocol, ReqResBuffer,
+    Request, Response, optioned_server::OptionedServer,
   },
   http2::{Http2Buffer, Http2ErrorCode, Http2Params, Http2Tokio},
   misc::{Either, FnFut, StreamReader, StreamWriter},
+  sync::Arc,
+};
+#[cfg(all(unix, not(any(target_os = "illumos", target_os = "solaris"))))]
+use alloc::vec::Vec;
+#[cfg(all(unix, not(any(target_os = "illumos", target_os = "solaris"))))]
+use core::net::SocketAddr;
+use core::{
+  future::poll_fn,
+  mem,
+  net::{IpAddr, Ipv4Addr},
+  pin::pin,
+  task::Poll,
+  time::Duration,
+};
+use hashbrown::HashMap;
+#[cfg(unix)]
+use std::path::Path;
+use std::sync::{Mutex, PoisonError};
+#[cfg(all(unix, not(any(target_os = "illumos", target_os = "solaris"))))]
+use tokio::net::TcpSocket;
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
+use tokio::{
+  net::{TcpListener, TcpStream},
+  sync::{Semaphore, mpsc, watch},
 };
-use core::{mem, net::IpAddr};
-use tokio::net::{TcpListener, TcpStream};
 
 impl OptionedServer {
   /// Optioned HTTP/2 server using tokio.
   ///
   /// The order of the callbacks roughly represents their execution order.
+  ///
+  /// Besides the operation mode, `http2_conn_om_cb` can also return a maximum body length that
+  /// overrides [`Http2Params::max_body_len`] for the stream.
+  ///
+  /// When `shutdown` resolves, new connections are no longer accepted and existing connections
+  /// receive a GOAWAY frame that allows the finishing of ongoing streams. This method then returns
+  /// after all handlers are finished or after `grace_period`, whichever comes first.
+  ///
+  /// See [`ConnLimits`] for the restrictions applied to incoming connections.
+  ///
+  /// If [`Http2Params::rrb_pool`] is set, buffers without allocated capacity returned by
+  /// `http2_conn_stream_cb` are replaced by pooled instances. Buffers are given back to the pool
+  /// after automatic responses are sent or when connections are closed while awaiting streams.
+  /// Manual streams own their buffers and are not tracked.
   //
   // It is not possible to use a struct to wrap the callbacks because the compiler asks for
   // explicit types declarations at call-site.
@@ -32,13 +69,21 @@ impl OptionedServer {
     HSEC,
     HSMC,
     SA,
+    SHF,
     SR,
     SW,
     TAC,
     TSC,
     TSF,
   >(
-    (acpt, addr, mut hcacp, hcocp): (ACPT, &str, HCACP, HCOCP),
+    (acpt, addr, conn_limits, (shutdown, grace_period), hcacp, hcocp): (
+      ACPT,
+      &str,
+      ConnLimits,
+      (SHF, Duration),
+      HCACP,
+      HCOCP,
+    ),
     tcp_acceptance_cb: TAC,
     tcp_stream: TSC,
     http2_conn_error_cb: HCEC,
@@ -64,7 +109,7 @@ impl OptionedServer {
         Option<Protocol>,
         Request<&mut ReqResBuffer>,
         &SA,
-      ) -> Result<(HA, OperationMode), ERR>
+      ) -> Result<(HA, Option<u32>, OperationMode), ERR>
       + Send
       + 'static,
     HCOCP: Clone + Send + 'static,
@@ -81,6 +126,7 @@ impl OptionedServer {
     ERR: From<crate::Error> + Send,
     HA: Send + 'static,
     SA: Send + 'static,
+    SHF: Future<Output = ()>,
     HSAC::Future: Send,
     HSMC::Future: Send,
     SR: Send + StreamReader<read(..): Send, read_skip(..): Send> + 'static,
@@ -92,9 +138,442 @@ impl OptionedServer {
     for<'any> &'any HSMC: Send,
     for<'any> &'any SA: Send,
   {
-    let listener = TcpListener::bind(addr).await?;
+    Self::http2_tokio_generic(
+      (
+        TcpListener::bind(addr).await?,
+        acpt,
+        SharedConnLimits::new(&conn_limits),
+        (shutdown, grace_period),
+        hcacp,
+        hcocp,
+      ),
+      tcp_acceptance_cb,
+      tcp_stream,
+      http2_conn_error_cb,
+      http2_conn_acceptance_cb,
+      http2_conn_stream_cb,
+      http2_conn_om_cb,
+      http2_stream_error_cb,
+      http2_stream_auto_cb,
+      http2_stream_manual_cb,
+    )
+    .await
+  }
+
+  /// Similar to [`Self::http2_tokio`] but spawns `acceptors` tasks, each one with its own
+  /// listener bound to `addr` through `SO_REUSEPORT`, which lets the operating system distribute
+  /// incoming connections across multiple cores.
+  ///
+  /// `addr` must be a socket address like `0.0.0.0:9000`. [`ConnLimits`] are shared by all
+  /// acceptors and if one of them stops, all others are also stopped.
+  #[cfg(all(unix, not(any(target_os = "illumos", target_os = "solaris"))))]
+  #[inline]
+  pub async fn http2_tokio_reuse_port<
+    ACPT,
+    CA,
+    ERR,
+    HA,
+    HCAC,
+    HCACP,
+    HCEC,
+    HCOC,
+    HCOCP,
+    HCSC,
+    HSAC,
+    HSEC,
+    HSMC,
+    SA,
+    SHF,
+    SR,
+    SW,
+    TAC,
+    TSC,
+    TSF,
+  >(
+    (acpt, addr, acceptors, conn_limits, (shutdown, grace_period), hcacp, hcocp): (
+      ACPT,
+      &str,
+      u16,
+      ConnLimits,
+      (SHF, Duration),
+      HCACP,
+      HCOCP,
+    ),
+    tcp_acceptance_cb: TAC,
+    tcp_stream: TSC,
+    http2_conn_error_cb: HCEC,
+    http2_conn_acceptance_cb: HCAC,
+    http2_conn_stream_cb: HCSC,
+    http2_conn_om_cb: HCOC,
+    http2_stream_error_cb: HSEC,
+    http2_stream_auto_cb: HSAC,
+    http2_stream_manual_cb: HSMC,
+  ) -> crate::Result<()>
+  where
+    ACPT: Clone + Send + 'static,
+    TAC: Clone + Fn(&mut HCACP) + Send + 'static,
+    TSC: Clone + Fn(ACPT, TcpStream) -> TSF + Send + 'static,
+    HCEC: Clone + Fn(ERR) + Send + 'static,
+    HCAC: Clone + Fn(HCACP) -> crate::Result<(CA, Http2Buffer, Http2Params)> + Send + 'static,
+    HCACP: Clone + Send + 'static,
+    HCSC: Clone + Fn(&mut CA) -> crate::Result<(SA, ReqResBuffer)> + Send + 'static,
+    HCOC: Clone
+      + Fn(
+        &CA,
+        &mut HCOCP,
+        Option<Protocol>,
+        Request<&mut ReqResBuffer>,
+        &SA,
+      ) -> Result<(HA, Option<u32>, OperationMode), ERR>
+      + Send
+      + 'static,
+    HCOCP: Clone + Send + 'static,
+    HSEC: Clone + Fn(ERR) + Send + 'static,
+    HSAC: Clone
+      + FnFut<(HA, AutoStream<CA, SA>), Result = Result<Response<ReqResBuffer>, ERR>>
+      + Send
+      + 'static,
+    HSMC: Clone
+      + FnFut<(HA, ManualServerStreamTokio<CA, Http2Buffer, SA, SW>), Result = Result<(), ERR>>
+      + Send
+      + 'static,
+    CA: Clone + Send + 'static,
+    ERR: From<crate::Error> + Send + 'static,
+    HA: Send + 'static,
+    SA: Send + 'static,
+    SHF: Future<Output = ()>,
+    HSAC::Future: Send,
+    HSMC::Future: Send,
+    SR: Send + StreamReader<read(..): Send, read_skip(..): Send> + 'static,
+    SW: Send + StreamWriter<write_all(..): Send, write_all_vectored(..): Send> + 'static,
+    TSF: Future<Output = crate::Result<(SR, SW)>> + Send + 'static,
+    for<'any> &'any CA: Send,
+    for<'any> &'any HCOC: Send,
+    for<'any> &'any HSAC: Send,
+    for<'any> &'any HSMC: Send,
+    for<'any> &'any SA: Send,
+  {
+    let sock_addr: SocketAddr = addr.parse()?;
+    let scl = SharedConnLimits::new(&conn_limits);
+    let (shutdown_tx, shutdown_rx) = watch::channel(());
+    let (stopped_tx, mut stopped_rx) = mpsc::channel::<()>(1);
+    let mut jhs = Vec::new();
+    for _ in 0..acceptors.max(1) {
+      let socket = if sock_addr.is_ipv4() { TcpSocket::new_v4()? } else { TcpSocket::new_v6()? };
+      socket.set_reuseaddr(true)?;
+      socket.set_reuseport(true)?;
+      socket.bind(sock_addr)?;
+      let listener = socket.listen(1024)?;
+      let mut local_shutdown_rx = shutdown_rx.clone();
+      let local_stopped_tx = stopped_tx.clone();
+      let fut = Self::http2_tokio_generic(
+        (
+          listener,
+          acpt.clone(),
+          scl.clone(),
+          (
+            async move {
+              let _rslt = local_shutdown_rx.changed().await;
+            },
+            grace_period,
+          ),
+          hcacp.clone(),
+          hcocp.clone(),
+        ),
+        tcp_acceptance_cb.clone(),
+        tcp_stream.clone(),
+        http2_conn_error_cb.clone(),
+        http2_conn_acceptance_cb.clone(),
+        http2_conn_stream_cb.clone(),
+        http2_conn_om_cb.clone(),
+        http2_stream_error_cb.clone(),
+        http2_stream_auto_cb.clone(),
+        http2_stream_manual_cb.clone(),
+      );
+      jhs.push(tokio::spawn(async move {
+        let rslt = fut.await;
+        let _rslt = local_stopped_tx.try_send(());
+        rslt
+      }));
+    }
+    drop(stopped_tx);
+    {
+      let mut shutdown_pin = pin!(shutdown);
+      let mut stopped_pin = pin!(stopped_rx.recv());
+      poll_fn(|cx| {
+        if shutdown_pin.as_mut().poll(cx).is_ready() || stopped_pin.as_mut().poll(cx).is_ready() {
+          return Poll::Ready(());
+        }
+        Poll::Pending
+      })
+      .await;
+    }
+    let _rslt = shutdown_tx.send(());
+    let mut rslt = Ok(());
+    for jh in jhs {
+      let local_rslt = match jh.await {
+        Ok(elem) => elem,
+        Err(_err) => Err(crate::Error::ClosedConnection),
+      };
+      if rslt.is_ok() {
+        rslt = local_rslt;
+      }
+    }
+    rslt
+  }
+
+  /// Similar to [`Self::http2_tokio`] but listens to a Unix domain socket located at `path`,
+  /// which is usually the case of applications behind reverse proxies of the same machine.
+  ///
+  /// Stale sockets of previous executions are replaced and the permissions of the new socket
+  /// are set to `mode`, e.g., `0o660`. The socket is removed when the server returns.
+  ///
+  /// Unix peers don't have IP addresses, as such, they are reported as [`Ipv4Addr::LOCALHOST`]
+  /// and [`ConnLimits::max_conns_per_ip`] is ignored.
+  #[cfg(unix)]
+  #[inline]
+  pub async fn http2_tokio_unix<
+    ACPT,
+    CA,
+    ERR,
+    HA,
+    HCAC,
+    HCACP,
+    HCEC,
+    HCOC,
+    HCOCP,
+    HCSC,
+    HSAC,
+    HSEC,
+    HSMC,
+    SA,
+    SHF,
+    SR,
+    SW,
+    TAC,
+    TSC,
+    TSF,
+  >(
+    (acpt, (path, mode), conn_limits, (shutdown, grace_period), hcacp, hcocp): (
+      ACPT,
+      (&Path, u32),
+      ConnLimits,
+      (SHF, Duration),
+      HCACP,
+      HCOCP,
+    ),
+    tcp_acceptance_cb: TAC,
+    tcp_stream: TSC,
+    http2_conn_error_cb: HCEC,
+    http2_conn_acceptance_cb: HCAC,
+    http2_conn_stream_cb: HCSC,
+    http2_conn_om_cb: HCOC,
+    http2_stream_error_cb: HSEC,
+    http2_stream_auto_cb: HSAC,
+    http2_stream_manual_cb: HSMC,
+  ) -> crate::Result<()>
+  where
+    ACPT: Clone + Send + 'static,
+    TAC: Fn(&mut HCACP) + Send + 'static,
+    TSC: Clone + Fn(ACPT, UnixStream) -> TSF + Send + 'static,
+    HCEC: Clone + Fn(ERR) + Send + 'static,
+    HCAC: Clone + Fn(HCACP) -> crate::Result<(CA, Http2Buffer, Http2Params)> + Send + 'static,
+    HCACP: Clone + Send + 'static,
+    HCSC: Clone + Fn(&mut CA) -> crate::Result<(SA, ReqResBuffer)> + Send + 'static,
+    HCOC: Clone
+      + Fn(
+        &CA,
+        &mut HCOCP,
+        Option<Protocol>,
+        Request<&mut ReqResBuffer>,
+        &SA,
+      ) -> Result<(HA, Option<u32>, OperationMode), ERR>
+      + Send
+      + 'static,
+    HCOCP: Clone + Send + 'static,
+    HSEC: Clone + Fn(ERR) + Send + 'static,
+    HSAC: Clone
+      + FnFut<(HA, AutoStream<CA, SA>), Result = Result<Response<ReqResBuffer>, ERR>>
+      + Send
+      + 'static,
+    HSMC: Clone
+      + FnFut<(HA, ManualServerStreamTokio<CA, Http2Buffer, SA, SW>), Result = Result<(), ERR>>
+      + Send
+      + 'static,
+    CA: Clone + Send + 'static,
+    ERR: From<crate::Error> + Send,
+    HA: Send + 'static,
+    SA: Send + 'static,
+    SHF: Future<Output = ()>,
+    HSAC::Future: Send,
+    HSMC::Future: Send,
+    SR: Send + StreamReader<read(..): Send, read_skip(..): Send> + 'static,
+    SW: Send + StreamWriter<write_all(..): Send, write_all_vectored(..): Send> + 'static,
+    TSF: Future<Output = crate::Result<(SR, SW)>> + Send,
+    for<'any> &'any CA: Send,
+    for<'any> &'any HCOC: Send,
+    for<'any> &'any HSAC: Send,
+    for<'any> &'any HSMC: Send,
+    for<'any> &'any SA: Send,
+  {
+    use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+
+    let is_stale_socket =
+      std::fs::symlink_metadata(path).is_ok_and(|elem| elem.file_type().is_socket());
+    if is_stale_socket {
+      std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode))?;
+    let rslt = Self::http2_tokio_generic(
+      (listener, acpt, SharedConnLimits::new(&conn_limits), (shutdown, grace_period), hcacp, hcocp),
+      tcp_acceptance_cb,
+      tcp_stream,
+      http2_conn_error_cb,
+      http2_conn_acceptance_cb,
+      http2_conn_stream_cb,
+      http2_conn_om_cb,
+      http2_stream_error_cb,
+      http2_stream_auto_cb,
+      http2_stream_manual_cb,
+    )
+    .await;
+    let _rslt = std::fs::remove_file(path);
+    rslt
+  }
+
+  async fn http2_tokio_generic<
+    ACPT,
+    CA,
+    ERR,
+    HA,
+    HCAC,
+    HCACP,
+    HCEC,
+    HCOC,
+    HCOCP,
+    HCSC,
+    HSAC,
+    HSEC,
+    HSMC,
+    L,
+    SA,
+    SHF,
+    SR,
+    SW,
+    TAC,
+    TSC,
+    TSF,
+  >(
+    (listener, acpt, scl, (shutdown, grace_period), mut hcacp, hcocp): (
+      L,
+      ACPT,
+      SharedConnLimits,
+      (SHF, Duration),
+      HCACP,
+      HCOCP,
+    ),
+    tcp_acceptance_cb: TAC,
+    tcp_stream: TSC,
+    http2_conn_error_cb: HCEC,
+    http2_conn_acceptance_cb: HCAC,
+    http2_conn_stream_cb: HCSC,
+    http2_conn_om_cb: HCOC,
+    http2_stream_error_cb: HSEC,
+    http2_stream_auto_cb: HSAC,
+    http2_stream_manual_cb: HSMC,
+  ) -> crate::Result<()>
+  where
+    ACPT: Clone + Send + 'static,
+    TAC: Fn(&mut HCACP) + Send + 'static,
+    L: Listener,
+    TSC: Clone + Fn(ACPT, L::Stream) -> TSF + Send + 'static,
+    HCEC: Clone + Fn(ERR) + Send + 'static,
+    HCAC: Clone + Fn(HCACP) -> crate::Result<(CA, Http2Buffer, Http2Params)> + Send + 'static,
+    HCACP: Clone + Send + 'static,
+    HCSC: Clone + Fn(&mut CA) -> crate::Result<(SA, ReqResBuffer)> + Send + 'static,
+    HCOC: Clone
+      + Fn(
+        &CA,
+        &mut HCOCP,
+        Option<Protocol>,
+        Request<&mut ReqResBuffer>,
+        &SA,
+      ) -> Result<(HA, Option<u32>, OperationMode), ERR>
+      + Send
+      + 'static,
+    HCOCP: Clone + Send + 'static,
+    HSEC: Clone + Fn(ERR) + Send + 'static,
+    HSAC: Clone
+      + FnFut<(HA, AutoStream<CA, SA>), Result = Result<Response<ReqResBuffer>, ERR>>
+      + Send
+      + 'static,
+    HSMC: Clone
+      + FnFut<(HA, ManualServerStreamTokio<CA, Http2Buffer, SA, SW>), Result = Result<(), ERR>>
+      + Send
+      + 'static,
+    CA: Clone + Send + 'static,
+    ERR: From<crate::Error> + Send,
+    HA: Send + 'static,
+    SA: Send + 'static,
+    SHF: Future<Output = ()>,
+    HSAC::Future: Send,
+    HSMC::Future: Send,
+    SR: Send + StreamReader<read(..): Send, read_skip(..): Send> + 'static,
+    SW: Send + StreamWriter<write_all(..): Send, write_all_vectored(..): Send> + 'static,
+    TSF: Future<Output = crate::Result<(SR, SW)>> + Send,
+    for<'any> &'any CA: Send,
+    for<'any> &'any HCOC: Send,
+    for<'any> &'any HSAC: Send,
+    for<'any> &'any HSMC: Send,
+    for<'any> &'any SA: Send,
+  {
+    let (shutdown_tx, shutdown_rx) = watch::channel(());
+    let (tasks_tx, mut tasks_rx) = mpsc::channel::<()>(1);
+    let mut shutdown_pin = pin!(shutdown);
     loop {
-      let accepted_stream = listener.accept().await?.0;
+      let mut conn_guard = ConnGuard { conns: None, conns_per_ip: None };
+      if let Some(semaphore) = &scl.conns {
+        let mut acquire_pin = pin!(semaphore.acquire());
+        let rslt = poll_fn(|cx| {
+          if shutdown_pin.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(None);
+          }
+          acquire_pin.as_mut().poll(cx).map(Some)
+        })
+        .await;
+        match rslt {
+          Some(elem) => elem.map_err(|_err| crate::Error::ClosedConnection)?.forget(),
+          None => break,
+        }
+        conn_guard.conns = Some(Arc::clone(semaphore));
+      }
+      let (accepted_stream, peer_opt) = {
+        let mut accept_pin = pin!(listener.accept());
+        let rslt = poll_fn(|cx| {
+          if shutdown_pin.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(None);
+          }
+          accept_pin.as_mut().poll(cx).map(Some)
+        })
+        .await;
+        match rslt {
+          Some(elem) => elem?,
+          None => break,
+        }
+      };
+      let peer = peer_opt.unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST));
+      if let (Some(map), Some(_)) = (&scl.conns_per_ip, peer_opt) {
+        let mut guard = map.lock().unwrap_or_else(PoisonError::into_inner);
+        // Rejected peers must not leave entries behind.
+        let counter = guard.get(&peer).copied().unwrap_or(0);
+        if counter >= scl.max_conns_per_ip {
+          drop(accepted_stream);
+          continue;
+        }
+        let _ = guard.insert(peer, counter.wrapping_add(1));
+        conn_guard.conns_per_ip = Some((Arc::clone(map), peer));
+      }
       tcp_acceptance_cb(&mut hcacp);
 
       let conn_acpt = acpt.clone();
@@ -107,17 +586,22 @@ impl OptionedServer {
       let conn_stream_error = http2_stream_error_cb.clone();
       let conn_stream_manual = http2_stream_manual_cb.clone();
       let conn_tcp_stream = tcp_stream.clone();
-      let peer = accepted_stream.peer_addr()?.ip();
       let mut conn_hcocp = hcocp.clone();
+      let mut conn_shutdown_rx = shutdown_rx.clone();
+      let conn_tasks_tx = tasks_tx.clone();
 
       let _conn_jh = tokio::spawn(async move {
+        let _conn_guard = conn_guard;
+        let _conn_tasks_tx = conn_tasks_tx;
+        let (streams_tx, mut streams_rx) = mpsc::channel::<()>(1);
         let initial_fut = async move {
           let (ca, hb, hp) = conn_http2_acceptance(conn_hcacp)?;
+          let rrb_pool = hp.rrb_pool().cloned();
           let parts = conn_tcp_stream(conn_acpt, accepted_stream).await?;
           let (frame_reader, http2) = Http2Tokio::accept(hb, hp, parts).await?;
-          Ok::<_, ERR>((ca, frame_reader, http2))
+          Ok::<_, ERR>((ca, frame_reader, http2, rrb_pool))
         };
-        let (mut conn_ca, frame_reader, mut http2) = match initial_fut.await {
+        let (mut conn_ca, frame_reader, mut http2, rrb_pool) = match initial_fut.await {
           Err(err) => {
             conn_http2_error(err);
             return;
@@ -126,36 +610,66 @@ impl OptionedServer {
         };
         let another_http2 = http2.clone();
         let _frame_reader_jh = tokio::spawn(frame_reader);
+        // Returns `true` if the shutdown was requested
         let rest = async move {
           loop {
             // !!! The line order is important !!!
-            let (stream_aux, rrb) = conn_http2_stream(&mut conn_ca)?;
+            let (stream_aux, mut rrb) = conn_http2_stream(&mut conn_ca)?;
+            if let Some(elem) = &rrb_pool {
+              if rrb.body.capacity() == 0 {
+                rrb = elem.get();
+              }
+            }
             let stream_ca = conn_ca.clone();
             // !!! The line order is important !!!
-            let (mut stream, rslt) = match http2
-              .stream(rrb, |req, protocol| {
-                let op = conn_http2_om(
+            let stream_rslt = {
+              let mut shutdown_changed_pin = pin!(conn_shutdown_rx.changed());
+              let mut stream_pin = pin!(http2.stream_with_max_body_len(rrb, |req, protocol| {
+                let rslt = conn_http2_om(
                   &stream_ca,
                   &mut conn_hcocp,
                   protocol,
                   Request { method: req.method, rrd: &mut *req.rrd, version: req.version },
                   &stream_aux,
-                )?;
-                Ok::<_, ERR>(match op.1 {
-                  OperationMode::Auto => (op.0, None),
-                  OperationMode::Manual => (op.0, Some(mem::take(req.rrd))),
-                })
+                );
+                match rslt {
+                  Err(err) => (Err(err), None),
+                  Ok((headers_aux, max_body_len, OperationMode::Auto)) => {
+                    (Ok::<_, ERR>((headers_aux, None)), max_body_len)
+                  }
+                  Ok((headers_aux, max_body_len, OperationMode::Manual)) => {
+                    (Ok((headers_aux, Some(mem::take(req.rrd)))), max_body_len)
+                  }
+                }
+              }));
+              poll_fn(|cx| {
+                if shutdown_changed_pin.as_mut().poll(cx).is_ready() {
+                  return Poll::Ready(None);
+                }
+                stream_pin.as_mut().poll(cx).map(Some)
               })
-              .await?
-            {
-              Either::Left(_) => return Ok(()),
-              Either::Right(elem) => elem,
+              .await
+            };
+            let (mut stream, rslt) = match stream_rslt {
+              Some(elem) => match elem? {
+                Either::Left(local_rrb) => {
+                  if let Some(local_rrb_pool) = &rrb_pool {
+                    local_rrb_pool.put(local_rrb);
+                  }
+                  return Ok(false);
+                }
+                Either::Right(elem) => elem,
+              },
+              None => return Ok(true),
             };
             let (headers_aux, opt) = rslt?;
             let stream_auto_cb = conn_stream_auto.clone();
             let stream_err_cb = conn_stream_error.clone();
             let stream_manual_cb = conn_stream_manual.clone();
+            let stream_rrb_pool = rrb_pool.clone();
+            let stream_streams_tx = streams_tx.clone();
             let _stream_jh = tokio::spawn(async move {
+              let _stream_streams_tx = stream_streams_tx;
               let stream_fun = async {
                 if let Some(local_rrb) = opt {
                   let req = Request::http2(stream.method(), local_rrb);
@@ -189,7 +703,13 @@ impl OptionedServer {
                   stream_aux,
                 };
                 let res = stream_auto_cb.call((headers_aux, auto_stream)).await?;
-                if stream.send_res(res).await?.is_closed() {
+                let local_res =
+                  Response { rrd: &res.rrd, status_code: res.status_code, version: res.version };
+                let hss = stream.send_res(local_res).await?;
+                if let Some(elem) = &stream_rrb_pool {
+                  elem.put(res.rrd);
+                }
+                if hss.is_closed() {
                   return Ok(());
                 }
                 Ok::<_, ERR>(())
@@ -203,12 +723,97 @@ impl OptionedServer {
             });
           }
         };
-        if let Err(err) = rest.await {
-          another_http2.send_go_away(Http2ErrorCode::NoError).await;
-          conn_http2_error(err);
+        match rest.await {
+          Err(err) => {
+            another_http2.send_go_away(Http2ErrorCode::NoError).await;
+            conn_http2_error(err);
+          }
+          Ok(true) => {
+            if let Err(err) = another_http2.send_graceful_go_away().await {
+              conn_http2_error(err.into());
+            }
+            let _ = streams_rx.recv().await;
+            another_http2.send_go_away(Http2ErrorCode::NoError).await;
+          }
+          Ok(false) => {}
         }
       });
     }
+    drop(listener);
+    drop(shutdown_tx);
+    drop(tasks_tx);
+    let _rslt = tokio::time::timeout(grace_period, tasks_rx.recv()).await;
+    Ok(())
+  }
+}
+
+trait Listener {
+  type Stream: Send + 'static;
+
+  fn accept(&self) -> impl Future<Output = crate::Result<(Self::Stream, Option<IpAddr>)>>;
+}
+
+impl Listener for TcpListener {
+  type Stream = TcpStream;
+
+  #[inline]
+  async fn accept(&self) -> crate::Result<(Self::Stream, Option<IpAddr>)> {
+    let (stream, addr) = TcpListener::accept(self).await?;
+    Ok((stream, Some(addr.ip())))
+  }
+}
+
+#[cfg(unix)]
+impl Listener for UnixListener {
+  type Stream = UnixStream;
+
+  #[inline]
+  async fn accept(&self) -> crate::Result<(Self::Stream, Option<IpAddr>)> {
+    Ok((UnixListener::accept(self).await?.0, None))
+  }
+}
+
+// Resources of `ConnLimits` that can be shared by several acceptors.
+#[derive(Clone)]
+struct SharedConnLimits {
+  conns: Option<Arc<Semaphore>>,
+  conns_per_ip: Option<Arc<Mutex<HashMap<IpAddr, u32>>>>,
+  max_conns_per_ip: u32,
+}
+
+impl SharedConnLimits {
+  fn new(conn_limits: &ConnLimits) -> Self {
+    let max_conns = usize::try_from(conn_limits.max_conns()).unwrap_or(usize::MAX);
+    Self {
+      conns: (max_conns < Semaphore::MAX_PERMITS).then(|| Arc::new(Semaphore::new(max_conns))),
+      conns_per_ip: (conn_limits.max_conns_per_ip() < u32::MAX)
+        .then(|| Arc::new(Mutex::new(HashMap::new()))),
+      max_conns_per_ip: conn_limits.max_conns_per_ip(),
+    }
+  }
+}
+
+// Gives back the resources of `ConnLimits` when a connection is closed.
+struct ConnGuard {
+  conns: Option<Arc<Semaphore>>,
+  conns_per_ip: Option<(Arc<Mutex<HashMap<IpAddr, u32>>>, IpAddr)>,
+}
+
+impl Drop for ConnGuard {
+  #[inline]
+  fn drop(&mut self) {
+    if let Some(semaphore) = &self.conns {
+      semaphore.add_permits(1);
+    }
+    if let Some((map, peer)) = &self.conns_per_ip {
+      let mut guard = map.lock().unwrap_or_else(PoisonError::into_inner);
+      if let Some(counter) = guard.get_mut(peer) {
+        *counter = counter.saturating_sub(1);
+        if *counter == 0 {
+          let _ = guard.remove(peer);
+        }
+      }
+    }
   }
 }
 
@@ -0,0 +1,202 @@
+use crate::{
+  http::OptionedServer,
+  misc::{_number_or_available_parallelism, FnFut, Lock, Spawner, Stream, Xorshift64},
+  pool::{SimplePool, SimplePoolResource, WebSocketRM},
+  web_socket::{Compression, WebSocket, WebSocketAcceptor, WebSocketBuffer},
+};
+use core::fmt::Debug;
+use std::sync::OnceLock;
+
+#[cfg(feature = "smol")]
+static SMOL_POOL: OnceLock<crate::pool::SimplePoolSmol<WebSocketRM>> = OnceLock::new();
+#[cfg(feature = "tokio")]
+static TOKIO_POOL: OnceLock<crate::pool::SimplePoolTokio<WebSocketRM>> = OnceLock::new();
+
+impl OptionedServer {
+  /// Optioned WebSocket server using smol.
+  #[cfg(feature = "smol")]
+  #[inline]
+  pub async fn web_socket_smol<ACPT, C, E, H, N, S>(
+    addr: &str,
+    buffers_len_opt: Option<usize>,
+    compression_cb: impl Clone + Fn() -> C + Send + 'static,
+    err_cb: impl Clone + Fn(E) + Send + 'static,
+    handle_cb: H,
+    (acceptor_cb, conn_acceptor_cb, net_cb): (
+      impl FnOnce() -> crate::Result<ACPT> + Send + 'static,
+      impl Clone + Fn(&ACPT) -> ACPT + Send + 'static,
+      impl Clone + Fn(ACPT, smol::net::TcpStream) -> N + Send + 'static,
+    ),
+  ) -> crate::Result<()>
+  where
+    ACPT: Send + 'static,
+    C: Compression<false> + Send + 'static,
+    C::NegotiatedCompression: Send,
+    E: Debug + From<crate::Error> + Send + 'static,
+    for<'wsb> H: Clone
+      + FnFut<
+        (WebSocket<C::NegotiatedCompression, Xorshift64, S, &'wsb mut WebSocketBuffer, false>,),
+        Result = Result<(), E>,
+      > + Send
+      + 'static,
+    N: Send + Future<Output = crate::Result<S>>,
+    S: Stream<read(..): Send, write_all(..): Send> + Send,
+    for<'wsb> <H as FnFut<(
+      WebSocket<C::NegotiatedCompression, Xorshift64, S, &'wsb mut WebSocketBuffer, false>,
+    )>>::Future: Send,
+    for<'handle> &'handle H: Send,
+  {
+    let buffers_len = _number_or_available_parallelism(buffers_len_opt)?;
+    Self::web_socket_generic(
+      smol::net::TcpListener::bind(addr).await?,
+      SMOL_POOL
+        .get_or_init(|| SimplePool::new(buffers_len, WebSocketRM::new(|| Ok(Default::default())))),
+      crate::misc::SmolRuntime,
+      compression_cb,
+      err_cb,
+      handle_cb,
+      (acceptor_cb, conn_acceptor_cb, net_cb),
+    )
+    .await
+  }
+
+  /// Optioned WebSocket server using tokio.
+  #[cfg(feature = "tokio")]
+  #[inline]
+  pub async fn web_socket_tokio<ACPT, C, E, H, N, S>(
+    addr: &str,
+    buffers_len_opt: Option<usize>,
+    compression_cb: impl Clone + Fn() -> C + Send + 'static,
+    err_cb: impl Clone + Fn(E) + Send + 'static,
+    handle_cb: H,
+    (acceptor_cb, conn_acceptor_cb, net_cb): (
+      impl FnOnce() -> crate::Result<ACPT> + Send + 'static,
+      impl Clone + Fn(&ACPT) -> ACPT + Send + 'static,
+      impl Clone + Fn(ACPT, tokio::net::TcpStream) -> N + Send + 'static,
+    ),
+  ) -> crate::Result<()>
+  where
+    ACPT: Send + 'static,
+    C: Compression<false> + Send + 'static,
+    C::NegotiatedCompression: Send,
+    E: Debug + From<crate::Error> + Send + 'static,
+    for<'wsb> H: Clone
+      + FnFut<
+        (WebSocket<C::NegotiatedCompression, Xorshift64, S, &'wsb mut WebSocketBuffer, false>,),
+        Result = Result<(), E>,
+      > + Send
+      + 'static,
+    N: Send + Future<Output = crate::Result<S>>,
+    S: Stream<read(..): Send, write_all(..): Send> + Send,
+    for<'wsb> <H as FnFut<(
+      WebSocket<C::NegotiatedCompression, Xorshift64, S, &'wsb mut WebSocketBuffer, false>,
+    )>>::Future: Send,
+    for<'handle> &'handle H: Send,
+  {
+    let buffers_len = _number_or_available_parallelism(buffers_len_opt)?;
+    Self::web_socket_generic(
+      tokio::net::TcpListener::bind(addr).await?,
+      TOKIO_POOL
+        .get_or_init(|| SimplePool::new(buffers_len, WebSocketRM::new(|| Ok(Default::default())))),
+      crate::misc::TokioRuntime,
+      compression_cb,
+      err_cb,
+      handle_cb,
+      (acceptor_cb, conn_acceptor_cb, net_cb),
+    )
+    .await
+  }
+
+  // Accept loop shared by all runtimes. Each connection is handled by a task created through
+  // `spawner` and uses one of the buffers of `pool`.
+  async fn web_socket_generic<ACPT, C, E, H, L, N, RL, S, SP>(
+    listener: L,
+    pool: &'static SimplePool<RL, WebSocketRM>,
+    spawner: SP,
+    compression_cb: impl Clone + Fn() -> C + Send + 'static,
+    err_cb: impl Clone + Fn(E) + Send + 'static,
+    handle_cb: H,
+    (acceptor_cb, conn_acceptor_cb, net_cb): (
+      impl FnOnce() -> crate::Result<ACPT> + Send + 'static,
+      impl Clone + Fn(&ACPT) -> ACPT + Send + 'static,
+      impl Clone + Fn(ACPT, L::Stream) -> N + Send + 'static,
+    ),
+  ) -> crate::Result<()>
+  where
+    ACPT: Send + 'static,
+    C: Compression<false> + Send + 'static,
+    C::NegotiatedCompression: Send,
+    E: Debug + From<crate::Error> + Send + 'static,
+    for<'wsb> H: Clone
+      + FnFut<
+        (WebSocket<C::NegotiatedCompression, Xorshift64, S, &'wsb mut WebSocketBuffer, false>,),
+        Result = Result<(), E>,
+      > + Send
+      + 'static,
+    L: Listener,
+    N: Send + Future<Output = crate::Result<S>>,
+    RL: Lock<Resource = SimplePoolResource<WebSocketBuffer>> + 'static,
+    RL::Guard<'static>: Send,
+    S: Stream<read(..): Send, write_all(..): Send> + Send,
+    SP: Spawner,
+    for<'wsb> <H as FnFut<(
+      WebSocket<C::NegotiatedCompression, Xorshift64, S, &'wsb mut WebSocketBuffer, false>,
+    )>>::Future: Send,
+    for<'handle> &'handle H: Send,
+  {
+    let acceptor = acceptor_cb()?;
+    loop {
+      let conn_acceptor = conn_acceptor_cb(&acceptor);
+      let conn_compression_cb = compression_cb.clone();
+      let conn_conn_err = err_cb.clone();
+      let conn_handle_cb = handle_cb.clone();
+      let conn_net_cb = net_cb.clone();
+      let stream = listener.accept().await?;
+      let mut conn_buffer = pool.get().await?;
+      spawner.spawn(async move {
+        let wsb = &mut ***conn_buffer;
+        let fun = async move {
+          let net = conn_net_cb(conn_acceptor, stream).await?;
+          conn_handle_cb
+            .call((WebSocketAcceptor::default()
+              .compression(conn_compression_cb())
+              .no_masking(true)
+              .wsb(wsb)
+              .accept(net)
+              .await?,))
+            .await?;
+          Ok::<_, E>(())
+        };
+        if let Err(err) = fun.await {
+          conn_conn_err(err);
+        }
+      });
+    }
+  }
+}
+
+trait Listener {
+  type Stream: Send + 'static;
+
+  fn accept(&self) -> impl Future<Output = crate::Result<Self::Stream>>;
+}
+
+#[cfg(feature = "smol")]
+impl Listener for smol::net::TcpListener {
+  type Stream = smol::net::TcpStream;
+
+  #[inline]
+  async fn accept(&self) -> crate::Result<Self::Stream> {
+    Ok(smol::net::TcpListener::accept(self).await?.0)
+  }
+}
+
+#[cfg(feature = "tokio")]
+impl Listener for tokio::net::TcpListener {
+  type Stream = tokio::net::TcpStream;
+
+  #[inline]
+  async fn accept(&self) -> crate::Result<Self::Stream> {
+    Ok(tokio::net::TcpListener::accept(self).await?.0)
+  }
+}
@@ -0,0 +1,320 @@
+use crate::{
+  http::{Header, Headers, KnownHeaderName, Mime, StatusCode, multipart::random_boundary},
+  misc::{ArrayVector, FromRadix10, Rng, Vector, str_split_once1, str_split1},
+};
+use core::fmt::Write;
+
+/// Requests with more ranges are served with the complete representation.
+const MAX_RANGES: usize = 16;
+
+/// A single range of bytes specified in a `Range` header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ByteRangeSpec {
+  /// `first-`
+  From(u64),
+  /// `first-last`
+  FromTo(u64, u64),
+  /// `-suffix_len`
+  Suffix(u64),
+}
+
+impl ByteRangeSpec {
+  /// Resolves this specification into an inclusive interval. Returns `None` if the range can't
+  /// be satisfied by a representation with `complete_len` bytes.
+  #[inline]
+  pub fn resolve(self, complete_len: u64) -> Option<(u64, u64)> {
+    let last = complete_len.checked_sub(1)?;
+    match self {
+      Self::From(first) => (first <= last).then_some((first, last)),
+      Self::FromTo(first, end) => (first <= last).then_some((first, end.min(last))),
+      Self::Suffix(0) => None,
+      Self::Suffix(len) => Some((complete_len.saturating_sub(len), last)),
+    }
+  }
+}
+
+/// Byte ranges requested through the `Range` header.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc9110#section-14>
+#[derive(Debug)]
+pub struct RangeRequest {
+  specs: ArrayVector<ByteRangeSpec, MAX_RANGES>,
+}
+
+impl RangeRequest {
+  /// Extracts the `Range` header considering the precondition imposed by `If-Range`. `etag` and
+  /// `last_modified` are the current validators of the selected representation.
+  ///
+  /// Returns `None` if the complete representation should be sent instead, which happens when
+  /// `Range` is absent, malformed or when the `If-Range` condition evaluates to false.
+  #[inline]
+  pub fn from_headers(
+    headers: &Headers,
+    etag: Option<&str>,
+    last_modified: Option<&str>,
+  ) -> Option<Self> {
+    let [range, if_range] =
+      headers.get_by_names([KnownHeaderName::Range.into(), KnownHeaderName::IfRange.into()]);
+    if let Some(elem) = if_range {
+      if !if_range_matches(elem.value, etag, last_modified) {
+        return None;
+      }
+    }
+    Self::parse(range?.value)
+  }
+
+  /// Parses the value of a `Range` header. Units other than `bytes` are not supported.
+  #[inline]
+  pub fn parse(value: &str) -> Option<Self> {
+    let (unit, set) = str_split_once1(value.trim(), b'=')?;
+    if !unit.trim().eq_ignore_ascii_case("bytes") {
+      return None;
+    }
+    let mut specs = ArrayVector::new();
+    for elem in str_split1(set, b',') {
+      let elem = elem.trim();
+      if elem.is_empty() {
+        continue;
+      }
+      let (first, last) = str_split_once1(elem, b'-')?;
+      let spec = match (first.trim().as_bytes(), last.trim().as_bytes()) {
+        ([], []) => return None,
+        ([], suffix) => ByteRangeSpec::Suffix(u64::from_radix_10(suffix).ok()?),
+        (first, []) => ByteRangeSpec::From(u64::from_radix_10(first).ok()?),
+        (first, last) => {
+          let (first, last) = (u64::from_radix_10(first).ok()?, u64::from_radix_10(last).ok()?);
+          if first > last {
+            return None;
+          }
+          ByteRangeSpec::FromTo(first, last)
+        }
+      };
+      specs.push(spec).ok()?;
+    }
+    if specs.is_empty() {
+      return None;
+    }
+    Some(Self { specs })
+  }
+
+  /// Satisfiable ranges of a representation with `complete_len` bytes as inclusive intervals.
+  ///
+  /// Overlapping or adjacent ranges are coalesced, as such, the returned intervals are disjoint
+  /// and sorted in ascending order.
+  ///
+  /// <https://datatracker.ietf.org/doc/html/rfc9110#section-14.2>
+  #[inline]
+  pub fn resolve(&self, complete_len: u64) -> ArrayVector<(u64, u64), MAX_RANGES> {
+    let mut ranges = ArrayVector::<(u64, u64), MAX_RANGES>::new();
+    for spec in self.specs.iter() {
+      if let Some(elem) = spec.resolve(complete_len) {
+        let _rslt = ranges.push(elem);
+      }
+    }
+    ranges.sort_unstable();
+    let mut rslt = ArrayVector::<(u64, u64), MAX_RANGES>::new();
+    for (first, last) in ranges.iter().copied() {
+      if let Some(elem) = rslt.last_mut() {
+        if first <= elem.1.saturating_add(1) {
+          elem.1 = elem.1.max(last);
+          continue;
+        }
+      }
+      let _rslt = rslt.push((first, last));
+    }
+    rslt
+  }
+
+  /// Requested ranges in the order they were received.
+  #[inline]
+  pub fn specs(&self) -> &[ByteRangeSpec] {
+    &self.specs
+  }
+
+  /// Replaces `body`, which must contain the complete representation, with the ranges returned
+  /// by [`Self::resolve`] and pushes the related headers. Returns the status code that should be
+  /// sent.
+  ///
+  /// `content_type` is the media type of the complete representation, which is pushed as-is for
+  /// single ranges or written inside each part of `multipart/byteranges` responses. In other
+  /// words, `headers` shouldn't contain a `Content-Type` header.
+  #[inline]
+  pub fn apply<RNG>(
+    &self,
+    body: &mut Vector<u8>,
+    content_type: Option<&str>,
+    headers: &mut Headers,
+    rng: &mut RNG,
+  ) -> crate::Result<StatusCode>
+  where
+    RNG: Rng,
+  {
+    let complete_len = u64::try_from(body.len()).unwrap_or(u64::MAX);
+    let mut ranges = ArrayVector::<(usize, usize), MAX_RANGES>::new();
+    for (first, last) in self.resolve(complete_len).iter().copied() {
+      ranges.push((usize::try_from(first)?, usize::try_from(last)?))?;
+    }
+    match ranges.as_slice() {
+      [] => {
+        body.clear();
+        headers.push_from_fmt(Header::from_name_and_value(
+          KnownHeaderName::ContentRange.into(),
+          format_args!("bytes */{complete_len}"),
+        ))?;
+        Ok(StatusCode::RequestedRangeNotSatisfiable)
+      }
+      [(first, last)] => {
+        body.copy_within(*first..=*last, 0);
+        body.truncate(last.wrapping_sub(*first).wrapping_add(1));
+        headers.push_from_fmt(Header::from_name_and_value(
+          KnownHeaderName::ContentRange.into(),
+          format_args!("bytes {first}-{last}/{complete_len}"),
+        ))?;
+        if let Some(elem) = content_type {
+          headers.push_from_iter(Header::from_name_and_value(
+            KnownHeaderName::ContentType.into(),
+            [elem],
+          ))?;
+        }
+        Ok(StatusCode::PartialContent)
+      }
+      _ => {
+        let boundary = random_boundary(rng)?;
+        let mut buffer = Vector::new();
+        for (first, last) in ranges.iter().copied() {
+          buffer.write_fmt(format_args!("--{}\r\n", boundary.as_str()))?;
+          if let Some(elem) = content_type {
+            buffer.write_fmt(format_args!("Content-Type: {elem}\r\n"))?;
+          }
+          buffer.write_fmt(format_args!(
+            "Content-Range: bytes {first}-{last}/{complete_len}\r\n\r\n"
+          ))?;
+          buffer.extend_from_copyable_slice(body.get(first..=last).unwrap_or_default())?;
+          buffer.extend_from_copyable_slice(b"\r\n")?;
+        }
+        buffer.write_fmt(format_args!("--{}--\r\n", boundary.as_str()))?;
+        *body = buffer;
+        headers.push_from_iter(Header::from_name_and_value(
+          KnownHeaderName::ContentType.into(),
+          [Mime::MultipartByteranges.as_str(), "; boundary=", boundary.as_str()],
+        ))?;
+        Ok(StatusCode::PartialContent)
+      }
+    }
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9110#section-13.1.5
+fn if_range_matches(if_range: &str, etag: Option<&str>, last_modified: Option<&str>) -> bool {
+  let if_range = if_range.trim();
+  if if_range.starts_with('"') || if_range.starts_with("W/") {
+    return match etag {
+      Some(elem) => !elem.starts_with("W/") && elem == if_range,
+      None => false,
+    };
+  }
+  last_modified.is_some_and(|elem| elem == if_range)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{ByteRangeSpec, Header, Headers, KnownHeaderName, RangeRequest, StatusCode},
+    misc::{Vector, Xorshift64, simple_seed},
+  };
+
+  #[test]
+  fn if_range_is_evaluated() {
+    let mut headers = Headers::new();
+    headers.push_from_iter(Header::from_name_and_value("range", ["bytes=0-1"])).unwrap();
+    headers.push_from_iter(Header::from_name_and_value("if-range", ["\"foo\""])).unwrap();
+    assert!(RangeRequest::from_headers(&headers, Some("\"foo\""), None).is_some());
+    assert!(RangeRequest::from_headers(&headers, Some("\"bar\""), None).is_none());
+    assert!(RangeRequest::from_headers(&headers, Some("W/\"foo\""), None).is_none());
+    assert!(RangeRequest::from_headers(&headers, None, Some("\"foo\"")).is_none());
+  }
+
+  #[test]
+  fn multiple_ranges() {
+    let mut body = Vector::from_slice(b"0123456789").unwrap();
+    let mut headers = Headers::new();
+    let rr = RangeRequest::parse("bytes=0-1, -2").unwrap();
+    let mut rng = Xorshift64::from(simple_seed());
+    let status_code = rr.apply(&mut body, Some("text/plain"), &mut headers, &mut rng).unwrap();
+    assert_eq!(status_code, StatusCode::PartialContent);
+    let content_type = headers.get_by_name(KnownHeaderName::ContentType.into()).unwrap().value;
+    let boundary = content_type.strip_prefix("multipart/byteranges; boundary=").unwrap();
+    let expected = alloc::format!(
+      "--{boundary}\r\nContent-Type: text/plain\r\nContent-Range: bytes 0-1/10\r\n\r\n01\r\n\
+      --{boundary}\r\nContent-Type: text/plain\r\nContent-Range: bytes 8-9/10\r\n\r\n89\r\n\
+      --{boundary}--\r\n"
+    );
+    assert_eq!(body.as_slice(), expected.as_bytes());
+  }
+
+  #[test]
+  fn overlapping_ranges_are_coalesced() {
+    let rr = RangeRequest::parse("bytes=6-7, 0-1, 1-2, 0-1, -3").unwrap();
+    assert_eq!(rr.resolve(10).as_slice(), [(0, 2), (6, 9)]);
+    let mut body = Vector::from_slice(b"0123456789").unwrap();
+    let mut headers = Headers::new();
+    let rr = RangeRequest::parse("bytes=2-6, 0-4, 7-7").unwrap();
+    let mut rng = Xorshift64::from(simple_seed());
+    assert_eq!(
+      rr.apply(&mut body, None, &mut headers, &mut rng).unwrap(),
+      StatusCode::PartialContent
+    );
+    assert_eq!(body.as_slice(), b"01234567");
+    assert_eq!(
+      headers.get_by_name(KnownHeaderName::ContentRange.into()).unwrap().value,
+      "bytes 0-7/10"
+    );
+  }
+
+  #[test]
+  fn parse() {
+    let rr = RangeRequest::parse("bytes=0-99, 200-, -50").unwrap();
+    assert_eq!(
+      rr.specs(),
+      [ByteRangeSpec::FromTo(0, 99), ByteRangeSpec::From(200), ByteRangeSpec::Suffix(50)]
+    );
+    assert!(RangeRequest::parse("items=0-1").is_none());
+    assert!(RangeRequest::parse("bytes=2-1").is_none());
+    assert!(RangeRequest::parse("bytes=-").is_none());
+    assert!(RangeRequest::parse("bytes=a-1").is_none());
+  }
+
+  #[test]
+  fn single_range() {
+    let mut body = Vector::from_slice(b"0123456789").unwrap();
+    let mut headers = Headers::new();
+    let rr = RangeRequest::parse("bytes=2-4").unwrap();
+    let mut rng = Xorshift64::from(simple_seed());
+    assert_eq!(
+      rr.apply(&mut body, None, &mut headers, &mut rng).unwrap(),
+      StatusCode::PartialContent
+    );
+    assert_eq!(body.as_slice(), b"234");
+    assert_eq!(
+      headers.get_by_name(KnownHeaderName::ContentRange.into()).unwrap().value,
+      "bytes 2-4/10"
+    );
+  }
+
+  #[test]
+  fn unsatisfiable_range() {
+    let mut body = Vector::from_slice(b"0123456789").unwrap();
+    let mut headers = Headers::new();
+    let rr = RangeRequest::parse("bytes=10-").unwrap();
+    let mut rng = Xorshift64::from(simple_seed());
+    assert_eq!(
+      rr.apply(&mut body, None, &mut headers, &mut rng).unwrap(),
+      StatusCode::RequestedRangeNotSatisfiable
+    );
+    assert!(body.is_empty());
+    assert_eq!(
+      headers.get_by_name(KnownHeaderName::ContentRange.into()).unwrap().value,
+      "bytes */10"
+    );
+  }
+}
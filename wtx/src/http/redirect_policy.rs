@@ -0,0 +1,442 @@
+use crate::{
+  http::{Headers, HttpError, KnownHeaderName, Method, ReqResBuffer, StatusCode},
+  misc::{UriRef, UriString, bytes_rpos1, str_split_once1},
+};
+use alloc::string::String;
+
+const BODY_HEADERS: [KnownHeaderName; 5] = [
+  KnownHeaderName::ContentEncoding,
+  KnownHeaderName::ContentLanguage,
+  KnownHeaderName::ContentLength,
+  KnownHeaderName::ContentLocation,
+  KnownHeaderName::ContentType,
+];
+const CREDENTIAL_HEADERS: [KnownHeaderName; 3] =
+  [KnownHeaderName::Authorization, KnownHeaderName::Cookie, KnownHeaderName::ProxyAuthorization];
+
+/// Determines how redirection responses are handled by clients.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc9110#section-15.4>
+///
+/// Redirections that point to a different origin are not followed by default, i.e., their 3xx
+/// responses are returned as-is. See [`Self::cross_origin`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RedirectPolicy {
+  cross_origin: bool,
+  max_hops: u8,
+}
+
+impl RedirectPolicy {
+  /// Follows up to 10 same-origin redirections.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { cross_origin: false, max_hops: 10 }
+  }
+
+  /// Redirections are not followed, i.e., 3xx responses are returned as-is.
+  #[inline]
+  pub const fn none() -> Self {
+    Self { cross_origin: false, max_hops: 0 }
+  }
+
+  /// If redirections to other schemes, hosts or ports should be followed. Credentials and sensitive
+  /// headers are removed before such hops.
+  ///
+  /// Should only be enabled for clients that open a connection per authority, like `ClientPool`,
+  /// otherwise requests are sent to the original server.
+  #[inline]
+  #[must_use]
+  pub const fn cross_origin(mut self, elem: bool) -> Self {
+    self.cross_origin = elem;
+    self
+  }
+
+  /// Maximum number of followed redirections. Exceeding this value results in an error.
+  #[inline]
+  #[must_use]
+  pub const fn max_hops(mut self, elem: u8) -> Self {
+    self.max_hops = elem;
+    self
+  }
+
+  /// Prepares `req`, `method` and `uri` for the next hop. Returns `false` if the received response
+  /// shouldn't be followed.
+  #[inline]
+  pub(crate) fn manage_hop(
+    &self,
+    hops: &mut u8,
+    method: &mut Method,
+    req: &mut ReqResBuffer,
+    res_headers: &Headers,
+    status_code: StatusCode,
+    uri: &mut UriString,
+  ) -> crate::Result<bool> {
+    let (new_method, keep_body) = match (status_code, *method) {
+      (StatusCode::MovedPermanently | StatusCode::Found, Method::Post) => (Method::Get, false),
+      (StatusCode::MovedPermanently | StatusCode::Found, _)
+      | (StatusCode::TemporaryRedirect | StatusCode::PermanentRedirect, _) => (*method, true),
+      (StatusCode::SeeOther, Method::Get | Method::Head) => (*method, true),
+      (StatusCode::SeeOther, _) => (Method::Get, false),
+      _ => return Ok(false),
+    };
+    if self.max_hops == 0 {
+      return Ok(false);
+    }
+    if *hops >= self.max_hops {
+      return Err(HttpError::TooManyRedirects.into());
+    }
+    let Some(location) = res_headers.get_by_name(KnownHeaderName::Location.into()) else {
+      return Err(HttpError::MissingHeader(KnownHeaderName::Location).into());
+    };
+    let next_uri = UriString::new(resolve_location(location.value, &uri.to_ref()));
+    if !is_same_origin(&uri.to_ref(), &next_uri.to_ref()) {
+      if !self.cross_origin {
+        return Ok(false);
+      }
+      req.headers.retain(|header| {
+        !header.is_sensitive
+          && !CREDENTIAL_HEADERS.iter().any(|el| header.name.eq_ignore_ascii_case((*el).into()))
+      });
+    }
+    if !keep_body {
+      req.body.clear();
+      req.headers.retain(|header| {
+        !BODY_HEADERS.iter().any(|el| header.name.eq_ignore_ascii_case((*el).into()))
+      });
+    }
+    *hops = hops.wrapping_add(1);
+    *method = new_method;
+    *uri = next_uri;
+    Ok(true)
+  }
+}
+
+impl Default for RedirectPolicy {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+fn is_same_origin(lhs: &UriRef<'_>, rhs: &UriRef<'_>) -> bool {
+  lhs.scheme().eq_ignore_ascii_case(rhs.scheme())
+    && lhs.hostname().eq_ignore_ascii_case(rhs.hostname())
+    && lhs.port() == rhs.port()
+}
+
+// https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.4
+fn remove_dot_segments(mut input: &str, rslt: &mut String) {
+  let start = rslt.len();
+  while !input.is_empty() {
+    if let Some(elem) = input.strip_prefix("../").or_else(|| input.strip_prefix("./")) {
+      input = elem;
+    } else if input.starts_with("/./") {
+      input = input.get(2..).unwrap_or_default();
+    } else if input == "/." {
+      input = "/";
+    } else if input.starts_with("/../") || input == "/.." {
+      input = input.get(3..).filter(|el| !el.is_empty()).unwrap_or("/");
+      let idx = rslt.get(start..).and_then(|el| bytes_rpos1(el, b'/')).unwrap_or_default();
+      rslt.truncate(start.wrapping_add(idx));
+    } else if input == "." || input == ".." {
+      input = "";
+    } else {
+      let idx =
+        input.get(1..).and_then(|el| el.find('/')).map_or(input.len(), |el| el.wrapping_add(1));
+      let (segment, rest) = input.split_at_checked(idx).unwrap_or((input, ""));
+      rslt.push_str(segment);
+      input = rest;
+    }
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc3986#section-5.2
+fn resolve_location(location: &str, base: &UriRef<'_>) -> String {
+  let location = location.trim();
+  let (location, query_and_fragment) =
+    location.find(['?', '#']).map_or((location, ""), |idx| location.split_at(idx));
+  let mut rslt = String::new();
+  if let Some((scheme, rest)) = split_scheme(location) {
+    rslt.push_str(scheme);
+    rslt.push(':');
+    push_authority_and_path(rest, &mut rslt);
+  } else if location.starts_with("//") {
+    rslt.push_str(base.scheme());
+    rslt.push(':');
+    push_authority_and_path(location, &mut rslt);
+  } else {
+    rslt.push_str(base.scheme());
+    rslt.push_str("://");
+    rslt.push_str(base.authority());
+    if location.is_empty() {
+      rslt.push_str(base.path());
+      if !query_and_fragment.starts_with('?') {
+        let query = base.query();
+        if !query.is_empty() {
+          rslt.push('?');
+          rslt.push_str(query);
+        }
+      }
+    } else if location.starts_with('/') {
+      remove_dot_segments(location, &mut rslt);
+    } else {
+      let path = base.path();
+      let dir = bytes_rpos1(path, b'/').and_then(|idx| path.get(..=idx)).unwrap_or("/");
+      let mut merged = String::from(dir);
+      merged.push_str(location);
+      remove_dot_segments(&merged, &mut rslt);
+    }
+  }
+  rslt.push_str(query_and_fragment);
+  rslt
+}
+
+fn push_authority_and_path(value: &str, rslt: &mut String) {
+  let Some(rest) = value.strip_prefix("//") else {
+    remove_dot_segments(value, rslt);
+    return;
+  };
+  let (authority, path) = rest.find('/').map_or((rest, ""), |idx| rest.split_at(idx));
+  rslt.push_str("//");
+  rslt.push_str(authority);
+  remove_dot_segments(path, rslt);
+}
+
+// Returns the scheme and the rest of `value` if it starts with `ALPHA *( ALPHA / DIGIT / "+" /
+// "-" / "." ) ":"`. Colons that appear in paths, like in `a/b:c`, are not considered.
+fn split_scheme(value: &str) -> Option<(&str, &str)> {
+  let (scheme, rest) = str_split_once1(value, b':')?;
+  let mut iter = scheme.bytes();
+  if !iter.next()?.is_ascii_alphabetic() {
+    return None;
+  }
+  if !iter.all(|el| el.is_ascii_alphanumeric() || matches!(el, b'+' | b'-' | b'.')) {
+    return None;
+  }
+  Some((scheme, rest))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{
+      Header, Headers, KnownHeaderName, Method, RedirectPolicy, ReqResBuffer, StatusCode,
+      redirect_policy::resolve_location,
+    },
+    misc::{UriRef, UriString},
+  };
+  #[cfg(feature = "_async-tests")]
+  use crate::{
+    http::{HttpClient, ReqResData, Response},
+    misc::{Lease, Vector},
+  };
+  use alloc::string::String;
+
+  #[test]
+  fn cross_origin_hops() {
+    let mut req = ReqResBuffer::empty();
+    req.headers.push_from_iter(Header::from_name_and_value("authorization", ["foo"])).unwrap();
+    req.headers.push_from_iter(Header::from_name_and_value("cookie", ["bar=baz"])).unwrap();
+    req.headers.push_from_iter(Header::from_name_and_value("accept", ["*/*"])).unwrap();
+    let mut uri = UriString::new(String::from("http://foo.com/bar"));
+    let res_headers = location("https://baz.com/qux");
+    let (mut hops, mut method) = (0, Method::Get);
+    assert!(
+      !RedirectPolicy::new()
+        .manage_hop(&mut hops, &mut method, &mut req, &res_headers, StatusCode::Found, &mut uri)
+        .unwrap()
+    );
+    assert_eq!(req.headers.headers_len(), 3);
+    assert_eq!(uri.as_str(), "http://foo.com/bar");
+    assert!(
+      RedirectPolicy::new()
+        .cross_origin(true)
+        .manage_hop(&mut hops, &mut method, &mut req, &res_headers, StatusCode::Found, &mut uri)
+        .unwrap()
+    );
+    assert_eq!(req.headers.headers_len(), 1);
+    assert_eq!(req.headers.get_by_idx(0).unwrap().name, "accept");
+    assert_eq!(uri.as_str(), "https://baz.com/qux");
+  }
+
+  #[cfg(feature = "_async-tests")]
+  #[tokio::test]
+  async fn send_recv_redirect_returns_cross_origin_responses() {
+    let mut client = MockClient { idx: 0, sent: Vector::new() };
+    let mut req = ReqResBuffer::empty();
+    req.headers.push_from_iter(Header::from_name_and_value("authorization", ["foo"])).unwrap();
+    let uri = UriRef::new("http://foo.com/a");
+    let res = client
+      .send_recv_redirect(Method::Get, RedirectPolicy::new(), &mut req, ReqResBuffer::empty(), &uri)
+      .await
+      .unwrap();
+    assert_eq!(res.status_code, StatusCode::Found);
+    assert_eq!(res.rrd.headers.get_by_name(b"location").unwrap().value, "http://bar.com/c");
+    assert_eq!(
+      client.sent.as_slice(),
+      &[(String::from("http://foo.com/a"), true), (String::from("http://foo.com/b"), true)]
+    );
+
+    let mut client = MockClient { idx: 0, sent: Vector::new() };
+    let policy = RedirectPolicy::new().cross_origin(true);
+    let res = client
+      .send_recv_redirect(Method::Get, policy, &mut req, ReqResBuffer::empty(), &uri)
+      .await
+      .unwrap();
+    assert_eq!(res.status_code, StatusCode::Ok);
+    assert_eq!(client.sent.get(2), Some(&(String::from("http://bar.com/c"), false)));
+  }
+
+  #[test]
+  fn locations_are_resolved() {
+    let base = UriRef::new("http://foo.com:8080/bar/baz?qux=1");
+    assert_eq!(resolve_location("https://a.com/b", &base), "https://a.com/b");
+    assert_eq!(resolve_location("//a.com/b", &base), "http://a.com/b");
+    assert_eq!(resolve_location("/a", &base), "http://foo.com:8080/a");
+    assert_eq!(resolve_location("a", &base), "http://foo.com:8080/bar/a");
+    assert_eq!(resolve_location("?a=2", &base), "http://foo.com:8080/bar/baz?a=2");
+    assert_eq!(resolve_location("", &base), "http://foo.com:8080/bar/baz?qux=1");
+    assert_eq!(resolve_location("#a", &base), "http://foo.com:8080/bar/baz?qux=1#a");
+    assert_eq!(resolve_location("mailto:a@b.com", &base), "mailto:a@b.com");
+    assert_eq!(resolve_location("a/b:c", &base), "http://foo.com:8080/bar/a/b:c");
+    assert_eq!(resolve_location("a?b=c:d", &base), "http://foo.com:8080/bar/a?b=c:d");
+    assert_eq!(resolve_location("1http://a.com", &base), "http://foo.com:8080/bar/1http://a.com");
+  }
+
+  // https://datatracker.ietf.org/doc/html/rfc3986#section-5.4
+  #[test]
+  fn locations_have_dot_segments_removed() {
+    let base = UriRef::new("http://a/b/c/d;p?q");
+    for (location, uri) in [
+      ("g", "http://a/b/c/g"),
+      ("./g", "http://a/b/c/g"),
+      ("g/", "http://a/b/c/g/"),
+      (".", "http://a/b/c/"),
+      ("./", "http://a/b/c/"),
+      ("..", "http://a/b/"),
+      ("../", "http://a/b/"),
+      ("../g", "http://a/b/g"),
+      ("../..", "http://a/"),
+      ("../../g", "http://a/g"),
+      ("../../../g", "http://a/g"),
+      ("/./g", "http://a/g"),
+      ("/../g", "http://a/g"),
+      ("g.", "http://a/b/c/g."),
+      ("..g", "http://a/b/c/..g"),
+      ("./../g", "http://a/b/g"),
+      ("./g/.", "http://a/b/c/g/"),
+      ("g/./h", "http://a/b/c/g/h"),
+      ("g/../h", "http://a/b/c/h"),
+      ("g;x=1/../y", "http://a/b/c/y"),
+      ("g?y/./x", "http://a/b/c/g?y/./x"),
+      ("//g/./h/../i", "http://g/i"),
+      ("https://g/a/../b", "https://g/b"),
+    ] {
+      assert_eq!(resolve_location(location, &base), uri, "{location}");
+    }
+  }
+
+  #[test]
+  fn methods_are_rewritten() {
+    let policy = RedirectPolicy::new();
+    let mut uri = UriString::new(String::from("http://foo.com"));
+    for (status_code, method, new_method, has_body) in [
+      (StatusCode::MovedPermanently, Method::Post, Method::Get, false),
+      (StatusCode::Found, Method::Put, Method::Put, true),
+      (StatusCode::SeeOther, Method::Head, Method::Head, true),
+      (StatusCode::SeeOther, Method::Put, Method::Get, false),
+      (StatusCode::TemporaryRedirect, Method::Post, Method::Post, true),
+      (StatusCode::PermanentRedirect, Method::Post, Method::Post, true),
+    ] {
+      let mut local_method = method;
+      let mut req = ReqResBuffer::empty();
+      req.body.extend_from_copyable_slice(b"data").unwrap();
+      req.headers.push_from_iter(Header::from_name_and_value("content-type", ["a"])).unwrap();
+      assert!(
+        policy
+          .manage_hop(&mut 0, &mut local_method, &mut req, &location("/"), status_code, &mut uri)
+          .unwrap()
+      );
+      assert_eq!(local_method, new_method);
+      assert_eq!(!req.body.is_empty(), has_body);
+      assert_eq!(req.headers.headers_len() == 1, has_body);
+    }
+  }
+
+  #[test]
+  fn too_many_hops() {
+    let mut req = ReqResBuffer::empty();
+    let mut uri = UriString::new(String::from("http://foo.com"));
+    let policy = RedirectPolicy::new().max_hops(1);
+    let res_headers = location("/");
+    let mut hops = 0;
+    let mut method = Method::Get;
+    let status_code = StatusCode::Found;
+    assert!(
+      policy
+        .manage_hop(&mut hops, &mut method, &mut req, &res_headers, status_code, &mut uri)
+        .unwrap()
+    );
+    assert!(
+      policy
+        .manage_hop(&mut hops, &mut method, &mut req, &res_headers, status_code, &mut uri)
+        .is_err()
+    );
+    assert!(
+      !RedirectPolicy::none()
+        .manage_hop(&mut 0, &mut method, &mut req, &res_headers, status_code, &mut uri)
+        .unwrap()
+    );
+  }
+
+  #[cfg(feature = "_async-tests")]
+  struct MockClient {
+    idx: usize,
+    sent: Vector<(String, bool)>,
+  }
+
+  #[cfg(feature = "_async-tests")]
+  impl HttpClient for MockClient {
+    type ReqId = ();
+
+    async fn recv_res(
+      &mut self,
+      mut rrb: ReqResBuffer,
+      _: Self::ReqId,
+    ) -> crate::Result<Response<ReqResBuffer>> {
+      let (status_code, value) = match self.idx {
+        1 => (StatusCode::Found, "/b"),
+        2 => (StatusCode::Found, "http://bar.com/c"),
+        _ => (StatusCode::Ok, ""),
+      };
+      if !value.is_empty() {
+        rrb.headers.push_from_iter(Header::from_name_and_value("location", [value]))?;
+      }
+      Ok(Response::http2(rrb, status_code))
+    }
+
+    async fn send_req<RRD>(
+      &mut self,
+      _: Method,
+      rrd: RRD,
+      uri: &UriRef<'_>,
+    ) -> crate::Result<Self::ReqId>
+    where
+      RRD: ReqResData,
+      RRD::Body: Lease<[u8]>,
+    {
+      self.idx = self.idx.wrapping_add(1);
+      let has_authorization = rrd.headers().get_by_name(b"authorization").is_some();
+      self.sent.push((String::from(uri.as_str()), has_authorization))?;
+      Ok(())
+    }
+  }
+
+  fn location(value: &str) -> Headers {
+    let mut headers = Headers::new();
+    headers
+      .push_from_iter(Header::from_name_and_value(KnownHeaderName::Location.into(), [value]))
+      .unwrap();
+    headers
+  }
+}
@@ -0,0 +1,95 @@
+use crate::{http::ReqResBuffer, misc::Vector};
+use std::sync::{Mutex, PoisonError};
+
+/// Recycles the allocations of [`ReqResBuffer`] instances between requests and streams.
+///
+/// Can be declared as a `static` item to be used globally or wrapped in an [`crate::sync::Arc`]
+/// to be used by a single server.
+///
+/// HTTP/2 servers created by `OptionedServer::http2_tokio` and its variants only recycle the
+/// buffers of automatic streams. Buffers of manual streams are owned by their handlers, which
+/// can give them back with [`Self::put`]. Connection buffers like `Http2Buffer` are not pooled.
+#[derive(Debug)]
+pub struct ReqResBufferPool {
+  buffers: Mutex<Vector<ReqResBuffer>>,
+  max_buffers: usize,
+  max_capacity: usize,
+}
+
+impl ReqResBufferPool {
+  /// At most `max_buffers` instances are retained and each instance can not have a body whose
+  /// capacity is greater than `max_capacity` bytes.
+  #[inline]
+  pub const fn new(max_buffers: usize, max_capacity: usize) -> Self {
+    Self { buffers: Mutex::new(Vector::new()), max_buffers, max_capacity }
+  }
+
+  /// Retrieves a previously retained instance or creates an empty one if there are none.
+  #[inline]
+  pub fn get(&self) -> ReqResBuffer {
+    self.buffers.lock().unwrap_or_else(PoisonError::into_inner).pop().unwrap_or_default()
+  }
+
+  /// If there are no retained instances.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Number of retained instances.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.buffers.lock().unwrap_or_else(PoisonError::into_inner).len()
+  }
+
+  /// See [`Self::new`].
+  #[inline]
+  pub const fn max_buffers(&self) -> usize {
+    self.max_buffers
+  }
+
+  /// See [`Self::new`].
+  #[inline]
+  pub const fn max_capacity(&self) -> usize {
+    self.max_capacity
+  }
+
+  /// Clears `rrb` and retains it for future usage. The instance is dropped if the pool is full or
+  /// if its body capacity is greater than [`Self::max_capacity`].
+  #[inline]
+  pub fn put(&self, mut rrb: ReqResBuffer) {
+    if rrb.body.capacity() > self.max_capacity {
+      return;
+    }
+    let mut buffers = self.buffers.lock().unwrap_or_else(PoisonError::into_inner);
+    if buffers.len() >= self.max_buffers {
+      return;
+    }
+    rrb.clear();
+    let _rslt = buffers.push(rrb);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{ReqResBuffer, ReqResBufferPool},
+    misc::Vector,
+  };
+
+  #[test]
+  fn put_and_get() {
+    let pool = ReqResBufferPool::new(1, 8);
+    pool.put(ReqResBuffer::from(Vector::with_capacity(16).unwrap()));
+    assert_eq!(pool.len(), 0);
+    let mut rrb = ReqResBuffer::from(Vector::with_capacity(4).unwrap());
+    rrb.body.extend_from_copyable_slice(&[1, 2]).unwrap();
+    pool.put(rrb);
+    pool.put(ReqResBuffer::empty());
+    assert_eq!(pool.len(), 1);
+    let rrb = pool.get();
+    assert!(rrb.body.is_empty());
+    assert!(rrb.body.capacity() >= 4);
+    assert_eq!(pool.len(), 0);
+  }
+}
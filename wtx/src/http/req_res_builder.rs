@@ -54,6 +54,22 @@ impl ReqResBuilder<ReqResBuffer> {
     }
     Ok(self)
   }
+
+  /// Uses `serde_urlencoded` to append the fields of `data` into the query of the URI.
+  #[inline]
+  #[cfg(feature = "serde_urlencoded")]
+  pub fn serde_urlencoded_query<T>(&mut self, data: &T) -> crate::Result<&mut Self>
+  where
+    T: serde::Serialize,
+  {
+    use crate::{http::FormUrlencodedIter, misc::from_utf8_basic};
+    let query = serde_urlencoded::to_string(data)?;
+    let mut query_writer = self.rrd.uri.query_writer()?;
+    for (name, value) in FormUrlencodedIter::new(query.as_bytes()) {
+      query_writer = query_writer.write(from_utf8_basic(name)?, from_utf8_basic(value)?)?;
+    }
+    Ok(self)
+  }
 }
 
 impl<RRD> ReqResBuilder<RRD>
@@ -93,11 +109,7 @@ where
   where
     T: serde::Serialize,
   {
-    self
-      .rrd
-      .body_mut()
-      .lease_mut()
-      .extend_from_copyable_slice(serde_urlencoded::to_string(data)?.as_bytes())?;
+    crate::http::to_form_urlencoded(data, self.rrd.body_mut().lease_mut())?;
     self.content_type(Mime::ApplicationXWwwFormUrlEncoded)
   }
 
@@ -115,6 +127,16 @@ impl<RRD> ReqResBuilder<RRD>
 where
   RRD: ReqResDataMut,
 {
+  /// Media type that the counterpart should send back.
+  #[inline]
+  pub fn accept(&mut self, mime: Mime) -> crate::Result<&mut Self> {
+    self.rrd.headers_mut().push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::Accept.into(),
+      [mime.as_str()],
+    ))?;
+    Ok(self)
+  }
+
   /// Applies a header field in the form of `Authorization: Bearer <token>`.
   #[inline]
   pub fn auth_bearer(&mut self, token: Arguments<'_>) -> crate::Result<&mut Self> {
@@ -0,0 +1,229 @@
+use crate::{
+  http::{Headers, HttpError, KnownHeaderName, Method, StatusCode, parse_http_date},
+  misc::{_backoff_with_jitter, FromRadix10, GenericTime, Rng},
+};
+use core::time::Duration;
+
+/// Determines how failed requests are retried by clients.
+///
+/// Only idempotent methods are retried, either after a transport error or after a `408`, `429`,
+/// `502`, `503` or `504` response. Waiting periods grow exponentially with a random jitter unless
+/// the server sent a `Retry-After` header.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc9110#section-10.2.3>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+  attempt_timeout: Option<Duration>,
+  base_delay: Duration,
+  max_attempts: u8,
+  max_delay: Duration,
+  total_timeout: Option<Duration>,
+}
+
+impl RetryPolicy {
+  /// Up to 3 attempts with delays that start at 100 milliseconds and can not exceed 10 seconds.
+  /// Timeouts are not applied.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      attempt_timeout: None,
+      base_delay: Duration::from_millis(100),
+      max_attempts: 3,
+      max_delay: Duration::from_secs(10),
+      total_timeout: None,
+    }
+  }
+
+  /// Requests are sent only once.
+  #[inline]
+  pub const fn none() -> Self {
+    Self { max_attempts: 1, ..Self::new() }
+  }
+
+  /// Maximum amount of time of each individual attempt.
+  #[inline]
+  #[must_use]
+  pub const fn attempt_timeout(mut self, elem: Option<Duration>) -> Self {
+    self.attempt_timeout = elem;
+    self
+  }
+
+  /// Delay of the first retry, which is doubled after each subsequent attempt.
+  #[inline]
+  #[must_use]
+  pub const fn base_delay(mut self, elem: Duration) -> Self {
+    self.base_delay = elem;
+    self
+  }
+
+  /// Maximum number of attempts, including the first one.
+  #[inline]
+  #[must_use]
+  pub const fn max_attempts(mut self, elem: u8) -> Self {
+    self.max_attempts = elem;
+    self
+  }
+
+  /// Upper bound of computed delays. Responses with greater `Retry-After` values are not retried.
+  #[inline]
+  #[must_use]
+  pub const fn max_delay(mut self, elem: Duration) -> Self {
+    self.max_delay = elem;
+    self
+  }
+
+  /// Maximum amount of time of all attempts and delays.
+  #[inline]
+  #[must_use]
+  pub const fn total_timeout(mut self, elem: Option<Duration>) -> Self {
+    self.total_timeout = elem;
+    self
+  }
+
+  /// Maximum amount of time of the next attempt given the `elapsed` time since the first one.
+  #[inline]
+  pub(crate) fn attempt_timeout_of(&self, elapsed: Duration) -> crate::Result<Option<Duration>> {
+    let Some(total_timeout) = self.total_timeout else {
+      return Ok(self.attempt_timeout);
+    };
+    let Some(remaining) = total_timeout.checked_sub(elapsed).filter(|el| !el.is_zero()) else {
+      return Err(HttpError::RequestTimeout.into());
+    };
+    Ok(Some(self.attempt_timeout.map_or(remaining, |el| el.min(remaining))))
+  }
+
+  /// Amount of time that should be waited before the next attempt. `res` is `None` when the
+  /// previous attempt failed without a response.
+  #[inline]
+  pub(crate) fn next_delay<RNG>(
+    &self,
+    attempt: u8,
+    elapsed: Duration,
+    method: Method,
+    res: Option<(&Headers, StatusCode)>,
+    rng: &mut RNG,
+  ) -> Option<Duration>
+  where
+    RNG: Rng,
+  {
+    if attempt >= self.max_attempts || !method.is_idempotent() {
+      return None;
+    }
+    let delay = match res {
+      None => self.backoff(attempt, rng),
+      Some((headers, status_code)) => {
+        if !matches!(
+          status_code,
+          StatusCode::RequestTimeout
+            | StatusCode::TooManyRequests
+            | StatusCode::BadGateway
+            | StatusCode::ServiceUnavailable
+            | StatusCode::GatewayTimeout
+        ) {
+          return None;
+        }
+        match retry_after(headers) {
+          Some(elem) if elem > self.max_delay => return None,
+          Some(elem) => elem,
+          None => self.backoff(attempt, rng),
+        }
+      }
+    };
+    if let Some(total_timeout) = self.total_timeout {
+      if elapsed.saturating_add(delay) >= total_timeout {
+        return None;
+      }
+    }
+    Some(delay)
+  }
+
+  fn backoff<RNG>(&self, attempt: u8, rng: &mut RNG) -> Duration
+  where
+    RNG: Rng,
+  {
+    _backoff_with_jitter(attempt, self.base_delay, self.max_delay, rng)
+  }
+}
+
+impl Default for RetryPolicy {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// Delta seconds or HTTP date
+fn retry_after(headers: &Headers) -> Option<Duration> {
+  let value = headers.get_by_name(KnownHeaderName::RetryAfter.into())?.value.trim();
+  if let Ok(elem) = u64::from_radix_10(value.as_bytes()) {
+    return Some(Duration::from_secs(elem));
+  }
+  let now = GenericTime::now_timestamp().ok()?.as_secs();
+  Some(Duration::from_secs(parse_http_date(value)?.saturating_sub(now)))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{Header, Headers, KnownHeaderName, Method, RetryPolicy, StatusCode},
+    misc::{Xorshift64, simple_seed},
+  };
+  use core::time::Duration;
+
+  #[test]
+  fn delays_grow_exponentially() {
+    let mut rng = Xorshift64::from(simple_seed());
+    let policy = RetryPolicy::new().base_delay(Duration::from_secs(1)).max_attempts(10);
+    for (attempt, max) in [(1, 1), (2, 2), (3, 4), (5, 10), (9, 10)] {
+      let delay = policy.next_delay(attempt, Duration::ZERO, Method::Get, None, &mut rng).unwrap();
+      assert!(delay >= Duration::from_secs(max) / 2 && delay <= Duration::from_secs(max));
+    }
+    assert_eq!(policy.next_delay(10, Duration::ZERO, Method::Get, None, &mut rng), None);
+  }
+
+  #[test]
+  fn only_idempotent_methods_are_retried() {
+    let mut rng = Xorshift64::from(simple_seed());
+    let policy = RetryPolicy::new();
+    assert!(policy.next_delay(1, Duration::ZERO, Method::Put, None, &mut rng).is_some());
+    assert!(policy.next_delay(1, Duration::ZERO, Method::Post, None, &mut rng).is_none());
+    assert!(
+      RetryPolicy::none().next_delay(1, Duration::ZERO, Method::Get, None, &mut rng).is_none()
+    );
+  }
+
+  #[test]
+  fn responses_are_retried_according_to_their_status_code() {
+    let mut rng = Xorshift64::from(simple_seed());
+    let policy = RetryPolicy::new();
+    let mut headers = Headers::new();
+    let res = Some((&headers, StatusCode::NotFound));
+    assert!(policy.next_delay(1, Duration::ZERO, Method::Get, res, &mut rng).is_none());
+    headers
+      .push_from_iter(Header::from_name_and_value(KnownHeaderName::RetryAfter.into(), ["7"]))
+      .unwrap();
+    let res = Some((&headers, StatusCode::ServiceUnavailable));
+    assert_eq!(
+      policy.next_delay(1, Duration::ZERO, Method::Get, res, &mut rng),
+      Some(Duration::from_secs(7))
+    );
+    let policy = policy.max_delay(Duration::from_secs(5));
+    assert!(policy.next_delay(1, Duration::ZERO, Method::Get, res, &mut rng).is_none());
+  }
+
+  #[test]
+  fn total_timeout() {
+    let mut rng = Xorshift64::from(simple_seed());
+    let policy = RetryPolicy::new()
+      .attempt_timeout(Some(Duration::from_secs(2)))
+      .total_timeout(Some(Duration::from_secs(3)));
+    assert_eq!(policy.attempt_timeout_of(Duration::ZERO).unwrap(), Some(Duration::from_secs(2)));
+    assert_eq!(
+      policy.attempt_timeout_of(Duration::from_secs(2)).unwrap(),
+      Some(Duration::from_secs(1))
+    );
+    assert!(policy.attempt_timeout_of(Duration::from_secs(3)).is_err());
+    let elapsed = Duration::from_millis(2950);
+    assert!(policy.next_delay(1, elapsed, Method::Get, None, &mut rng).is_none());
+  }
+}
@@ -4,58 +4,100 @@
 mod macros;
 
 mod arguments;
+mod attachment;
+mod compression_middleware;
 mod conn_aux;
 mod cors_middleware;
+#[cfg(feature = "http-cookie-secure")]
+mod csrf_middleware;
 mod endpoint;
 pub(crate) mod endpoint_node;
+mod etag_middleware;
+mod health;
+mod instrumentation_middleware;
 mod methods;
+mod metrics;
 mod middleware;
+mod no_content;
+#[cfg(not(feature = "matchit"))]
+mod path_matcher;
 mod path_params;
+mod rate_limit_middleware;
+mod rate_limit_store;
 mod redirect;
+mod request_id_middleware;
 mod res_finalizer;
 mod route_match;
 mod router;
+#[cfg(feature = "tokio")]
+mod serve_dir;
+#[cfg(feature = "tokio")]
+mod serve_file;
 mod server_framework_builder;
 mod server_framework_error;
 mod state;
 mod stream_aux;
 #[cfg(all(feature = "_async-tests", feature = "matchit", test))]
 mod tests;
+mod timeout;
 #[cfg(all(feature = "nightly", feature = "tokio"))]
 mod tokio;
 
 use crate::{
-  http::{AutoStream, OperationMode, ReqResBuffer, Response, conn_params::ConnParams},
+  http::{AutoStream, ConnLimits, OperationMode, ReqResBuffer, Response, conn_params::ConnParams},
   misc::ArrayVector,
   sync::Arc,
 };
 pub use arguments::*;
+pub use attachment::Attachment;
+pub use compression_middleware::CompressionMiddleware;
 pub use conn_aux::ConnAux;
 pub use cors_middleware::{CorsMiddleware, OriginResponse};
+#[cfg(feature = "http-cookie-secure")]
+pub use csrf_middleware::CsrfMiddleware;
 pub use endpoint::Endpoint;
 pub use endpoint_node::EndpointNode;
+pub use etag_middleware::EtagMiddleware;
+#[cfg(all(feature = "database", feature = "pool", feature = "std"))]
+pub use health::DatabaseHealthCheck;
+pub use health::{Health, HealthCheck, HealthChecks};
+pub use instrumentation_middleware::InstrumentationMiddleware;
 pub use methods::{
   get::{Get, get},
   json::{Json, json},
   post::{Post, post},
   web_socket::{WebSocket, web_socket},
 };
+pub use metrics::{InFlight, Metrics, MetricsEndpoint, MetricsMiddleware, push_gauge};
 pub use middleware::Middleware;
+pub use no_content::NoContent;
 pub use path_params::PathParams;
+pub use rate_limit_middleware::{
+  RateLimitByForwardedIp, RateLimitByHeader, RateLimitByPeerIp, RateLimitKey, RateLimitMiddleware,
+  RateLimitPolicy,
+};
+pub use rate_limit_store::*;
 pub use redirect::Redirect;
+pub use request_id_middleware::RequestIdMiddleware;
 pub use res_finalizer::ResFinalizer;
 pub use route_match::RouteMatch;
 pub use router::Router;
+#[cfg(feature = "tokio")]
+pub use serve_dir::ServeDir;
+#[cfg(feature = "tokio")]
+pub use serve_file::ServeFile;
 pub use server_framework_builder::ServerFrameworkBuilder;
 pub use server_framework_error::ServerFrameworkError;
 pub use state::{State, StateClean, StateGeneric};
 pub use stream_aux::StreamAux;
+pub use timeout::Timeout;
 
 /// Server
 #[derive(Debug)]
 pub struct ServerFramework<CA, CACB, CBP, E, EN, M, S, SA, SACB> {
   _ca_cb: CACB,
   _cbp: CBP,
+  _cl: ConnLimits,
   _cp: ConnParams,
   _sa_cb: SACB,
   _router: Arc<Router<CA, E, EN, M, S, SA>>,
@@ -73,6 +115,35 @@ where
     headers_aux: (ArrayVector<RouteMatch, 4>, Arc<Router<CA, E, EN, M, S, SA>>),
     mut auto_stream: AutoStream<CA, SA>,
   ) -> Result<Response<ReqResBuffer>, E> {
+    #[cfg(feature = "tracing")]
+    let status_code = {
+      use tracing::Instrument;
+      let req = &auto_stream.req;
+      let span = tracing::info_span!(
+        "http_request",
+        latency_us = tracing::field::Empty,
+        method = req.method.strings().custom[0],
+        path = req.rrd.uri.path(),
+        request_id = request_id_middleware::incoming_request_id(&req.rrd.headers),
+        status = tracing::field::Empty,
+      );
+      let now = crate::misc::GenericTime::now();
+      let rslt =
+        headers_aux.1.auto(&mut auto_stream, (0, &headers_aux.0)).instrument(span.clone()).await;
+      if let Ok(elapsed) = now.elapsed() {
+        span.record("latency_us", u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX));
+      }
+      if let Some(header) =
+        auto_stream.req.rrd.headers.get_by_name(crate::http::KnownHeaderName::XRequestId.into())
+      {
+        span.record("request_id", header.value);
+      }
+      if let Ok(elem) = &rslt {
+        span.record("status", u16::from(*elem));
+      }
+      rslt?
+    };
+    #[cfg(not(feature = "tracing"))]
     let status_code = headers_aux.1.auto(&mut auto_stream, (0, &headers_aux.0)).await?;
     Ok(Response { rrd: auto_stream.req.rrd, status_code, version: auto_stream.req.version })
   }
@@ -85,9 +156,12 @@ where
     #[cfg(feature = "matchit")]
     return Ok(router._matcher.at(path).map_err(From::from)?.value.clone());
     #[cfg(not(feature = "matchit"))]
-    return Ok((
-      ArrayVector::new(),
-      *router._matcher.get(path).ok_or_else(|| ServerFrameworkError::UnknownPath.into())?,
-    ));
+    return Ok(
+      router
+        ._matcher
+        ._at(path)
+        .ok_or_else(|| crate::Error::from(ServerFrameworkError::UnknownPath))?
+        .clone(),
+    );
   }
 }
@@ -1,19 +1,79 @@
+#[cfg(feature = "base64")]
+mod basic_auth;
+mod bearer_token;
+#[cfg(feature = "http-cookie")]
+mod cookie_jar;
+#[cfg(feature = "jwt")]
+mod jwt;
 mod path_owned;
 mod path_str;
 #[cfg(feature = "serde_json")]
 mod serde_json;
+#[cfg(feature = "serde_urlencoded")]
+mod serde_query;
+#[cfg(feature = "serde_urlencoded")]
+mod serde_urlencoded;
+#[cfg(feature = "http-session")]
+mod session;
 
 use crate::{
-  http::{HttpError, server_framework::RouteMatch},
-  misc::{UriString, bytes_split1},
+  http::{
+    Header, Headers, HttpError, KnownHeaderName, Mime, ReqResBuffer, StatusCode,
+    server_framework::RouteMatch,
+  },
+  misc::{UriString, bytes_split1, str_split_once1},
 };
+#[cfg(feature = "base64")]
+pub use basic_auth::BasicAuth;
+pub use bearer_token::BearerToken;
+use core::fmt::Write;
+#[cfg(feature = "jwt")]
+pub use jwt::Jwt;
 pub use path_owned::PathOwned;
 pub use path_str::PathStr;
 #[cfg(feature = "serde_json")]
 pub use serde_json::SerdeJson;
+#[cfg(feature = "serde_urlencoded")]
+pub use serde_query::SerdeQuery;
+#[cfg(feature = "serde_urlencoded")]
+pub use serde_urlencoded::SerdeUrlencoded;
+#[cfg(feature = "http-session")]
+pub use session::Session;
 
+// Credentials of the `Authorization` header if its scheme is equal to `scheme`, which must be
+// lowercase.
 #[inline]
-fn manage_path<'uri>(
+pub(crate) fn authorization_credentials<'headers>(
+  headers: &'headers Headers,
+  scheme: &str,
+) -> Option<&'headers str> {
+  let header = headers.get_by_name(KnownHeaderName::Authorization.into())?;
+  let (local_scheme, credentials) = str_split_once1(header.value.trim_ascii(), b' ')?;
+  let credentials = credentials.trim_ascii();
+  if !local_scheme.eq_ignore_ascii_case(scheme) || credentials.is_empty() {
+    return None;
+  }
+  Some(credentials)
+}
+
+// Replaces the contents of `rrd` with a plain-text description of an argument that couldn't be
+// parsed.
+#[inline]
+pub(crate) fn bad_request(
+  rrd: &mut ReqResBuffer,
+  error: crate::Error,
+) -> crate::Result<StatusCode> {
+  rrd.clear();
+  rrd.headers.push_from_iter(Header::from_name_and_value(
+    KnownHeaderName::ContentType.into(),
+    [Mime::TextPlain.as_str()],
+  ))?;
+  rrd.body.write_fmt(format_args!("{error}"))?;
+  Ok(StatusCode::BadRequest)
+}
+
+#[inline]
+pub(crate) fn manage_path<'uri>(
   path_defs: (u8, &[RouteMatch]),
   uri: &'uri UriString,
 ) -> crate::Result<&'uri str> {
@@ -0,0 +1,96 @@
+use crate::{
+  http::{
+    AutoStream, Header, Headers, KnownHeaderName, ReqResBuffer, StatusCode,
+    server_framework::{
+      Endpoint, ResFinalizer, RouteMatch, StateGeneric, arguments::authorization_credentials,
+    },
+  },
+  misc::{FnFut, FnFutWrapper, str_split_once1},
+};
+use alloc::string::String;
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+/// Credentials of the `Basic` authentication scheme. Requests without valid credentials are
+/// answered with `401 Unauthorized` and a `WWW-Authenticate` header.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc7617>
+#[derive(Debug, Eq, PartialEq)]
+pub struct BasicAuth {
+  /// Password
+  pub password: String,
+  /// User-ID
+  pub username: String,
+}
+
+impl BasicAuth {
+  /// Decodes the `Authorization` header. Returns [`Option::None`] if the header is absent or
+  /// malformed.
+  #[inline]
+  pub fn from_headers(headers: &Headers) -> Option<Self> {
+    let credentials = authorization_credentials(headers, "basic")?;
+    let string = String::from_utf8(STANDARD.decode(credentials).ok()?).ok()?;
+    let (username, password) = str_split_once1(&string, b':')?;
+    Some(Self { password: password.into(), username: username.into() })
+  }
+}
+
+impl<CA, E, F, RES, S, SA> Endpoint<CA, E, S, SA> for FnFutWrapper<(BasicAuth,), F>
+where
+  E: From<crate::Error>,
+  F: FnFut<(BasicAuth,), Result = RES>,
+  RES: ResFinalizer<E>,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let basic_auth = BasicAuth::from_headers(&auto_stream.req.rrd.headers);
+    auto_stream.req.rrd.clear();
+    let Some(elem) = basic_auth else {
+      return Ok(unauthorized(&mut auto_stream.req.rrd)?);
+    };
+    self.0.call((elem,)).await.finalize_response(&mut auto_stream.req)
+  }
+}
+
+impl<CA, E, F, RES, S, SA, const CLEAN: bool> Endpoint<CA, E, S, SA>
+  for FnFutWrapper<(StateGeneric<'_, CA, SA, ReqResBuffer, CLEAN>, BasicAuth), F>
+where
+  E: From<crate::Error>,
+  F: for<'any> FnFut<(StateGeneric<'any, CA, SA, ReqResBuffer, CLEAN>, BasicAuth), Result = RES>,
+  RES: ResFinalizer<E>,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let Some(elem) = BasicAuth::from_headers(&auto_stream.req.rrd.headers) else {
+      auto_stream.req.rrd.clear();
+      return Ok(unauthorized(&mut auto_stream.req.rrd)?);
+    };
+    self
+      .0
+      .call((
+        StateGeneric::new(
+          &mut auto_stream.conn_aux,
+          &mut auto_stream.stream_aux,
+          &mut auto_stream.req,
+        ),
+        elem,
+      ))
+      .await
+      .finalize_response(&mut auto_stream.req)
+  }
+}
+
+fn unauthorized(rrd: &mut ReqResBuffer) -> crate::Result<StatusCode> {
+  rrd.headers.push_from_iter(Header::from_name_and_value(
+    KnownHeaderName::WwwAuthenticate.into(),
+    [r#"Basic realm="restricted", charset="UTF-8""#],
+  ))?;
+  Ok(StatusCode::Unauthorized)
+}
@@ -0,0 +1,118 @@
+use crate::{
+  http::{
+    AutoStream, Header, Headers, KnownHeaderName, ReqResBuffer, StatusCode,
+    server_framework::{
+      Endpoint, ResFinalizer, RouteMatch, StateGeneric, arguments::authorization_credentials,
+    },
+  },
+  misc::{FnFut, FnFutWrapper},
+};
+use alloc::string::String;
+
+/// Token of the `Bearer` authentication scheme. Requests without a well-formed token are
+/// answered with `401 Unauthorized` and a `WWW-Authenticate` header.
+///
+/// The token itself is not validated.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc6750>
+#[derive(Debug, Eq, PartialEq)]
+pub struct BearerToken(
+  /// Token
+  pub String,
+);
+
+impl BearerToken {
+  /// Extracts the token of the `Authorization` header. Returns [`Option::None`] if the header is
+  /// absent or malformed.
+  #[inline]
+  pub fn from_headers(headers: &Headers) -> Option<Self> {
+    let token = authorization_credentials(headers, "bearer")?;
+    // https://datatracker.ietf.org/doc/html/rfc6750#section-2.1
+    let is_valid = token.trim_end_matches('=').bytes().all(|el| {
+      el.is_ascii_alphanumeric() || matches!(el, b'-' | b'.' | b'_' | b'~' | b'+' | b'/')
+    });
+    is_valid.then(|| Self(token.into()))
+  }
+}
+
+impl<CA, E, F, RES, S, SA> Endpoint<CA, E, S, SA> for FnFutWrapper<(BearerToken,), F>
+where
+  E: From<crate::Error>,
+  F: FnFut<(BearerToken,), Result = RES>,
+  RES: ResFinalizer<E>,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let bearer_token = BearerToken::from_headers(&auto_stream.req.rrd.headers);
+    auto_stream.req.rrd.clear();
+    let Some(elem) = bearer_token else {
+      return Ok(unauthorized(&mut auto_stream.req.rrd)?);
+    };
+    self.0.call((elem,)).await.finalize_response(&mut auto_stream.req)
+  }
+}
+
+impl<CA, E, F, RES, S, SA, const CLEAN: bool> Endpoint<CA, E, S, SA>
+  for FnFutWrapper<(StateGeneric<'_, CA, SA, ReqResBuffer, CLEAN>, BearerToken), F>
+where
+  E: From<crate::Error>,
+  F: for<'any> FnFut<(StateGeneric<'any, CA, SA, ReqResBuffer, CLEAN>, BearerToken), Result = RES>,
+  RES: ResFinalizer<E>,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let Some(elem) = BearerToken::from_headers(&auto_stream.req.rrd.headers) else {
+      auto_stream.req.rrd.clear();
+      return Ok(unauthorized(&mut auto_stream.req.rrd)?);
+    };
+    self
+      .0
+      .call((
+        StateGeneric::new(
+          &mut auto_stream.conn_aux,
+          &mut auto_stream.stream_aux,
+          &mut auto_stream.req,
+        ),
+        elem,
+      ))
+      .await
+      .finalize_response(&mut auto_stream.req)
+  }
+}
+
+fn unauthorized(rrd: &mut ReqResBuffer) -> crate::Result<StatusCode> {
+  rrd.headers.push_from_iter(Header::from_name_and_value(
+    KnownHeaderName::WwwAuthenticate.into(),
+    ["Bearer"],
+  ))?;
+  Ok(StatusCode::Unauthorized)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::{Header, Headers, server_framework::BearerToken};
+
+  #[test]
+  fn from_headers() {
+    let mut headers = Headers::new();
+    assert_eq!(BearerToken::from_headers(&headers), None);
+    headers.push_from_iter(Header::from_name_and_value("authorization", ["Basic abc"])).unwrap();
+    assert_eq!(BearerToken::from_headers(&headers), None);
+    headers.clear();
+    headers.push_from_iter(Header::from_name_and_value("authorization", ["bearer a b"])).unwrap();
+    assert_eq!(BearerToken::from_headers(&headers), None);
+    headers.clear();
+    headers
+      .push_from_iter(Header::from_name_and_value("authorization", ["Bearer mF_9.B5f-4.1JqM="]))
+      .unwrap();
+    assert_eq!(BearerToken::from_headers(&headers), Some(BearerToken("mF_9.B5f-4.1JqM=".into())));
+  }
+}
@@ -0,0 +1,54 @@
+use crate::{
+  http::{
+    AutoStream, CookieJar, ReqResBuffer, StatusCode,
+    server_framework::{Endpoint, ResFinalizer, RouteMatch, StateGeneric},
+  },
+  misc::{FnFut, FnFutWrapper},
+};
+
+impl<CA, E, F, RES, S, SA> Endpoint<CA, E, S, SA> for FnFutWrapper<(CookieJar,), F>
+where
+  E: From<crate::Error>,
+  F: FnFut<(CookieJar,), Result = RES>,
+  RES: ResFinalizer<E>,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let jar = CookieJar::from_headers(&auto_stream.req.rrd.headers)?;
+    auto_stream.req.rrd.clear();
+    self.0.call((jar,)).await.finalize_response(&mut auto_stream.req)
+  }
+}
+
+impl<CA, E, F, RES, S, SA, const CLEAN: bool> Endpoint<CA, E, S, SA>
+  for FnFutWrapper<(StateGeneric<'_, CA, SA, ReqResBuffer, CLEAN>, CookieJar), F>
+where
+  E: From<crate::Error>,
+  F: for<'any> FnFut<(StateGeneric<'any, CA, SA, ReqResBuffer, CLEAN>, CookieJar), Result = RES>,
+  RES: ResFinalizer<E>,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let jar = CookieJar::from_headers(&auto_stream.req.rrd.headers)?;
+    self
+      .0
+      .call((
+        StateGeneric::new(
+          &mut auto_stream.conn_aux,
+          &mut auto_stream.stream_aux,
+          &mut auto_stream.req,
+        ),
+        jar,
+      ))
+      .await
+      .finalize_response(&mut auto_stream.req)
+  }
+}
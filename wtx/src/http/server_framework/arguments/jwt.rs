@@ -0,0 +1,99 @@
+use crate::{
+  http::{
+    AutoStream, Header, KnownHeaderName, ReqResBuffer, StatusCode,
+    server_framework::{
+      Endpoint, ResFinalizer, RouteMatch, StateGeneric, arguments::authorization_credentials,
+    },
+  },
+  jwt::{JwtClaims, JwtDecoder},
+  misc::{FnFut, FnFutWrapper, Lease},
+};
+use serde::de::DeserializeOwned;
+
+/// Claims of a token sent through the `Authorization: Bearer` header, verified by the
+/// [`JwtDecoder`] of the connection auxiliary. Requests with absent or invalid tokens are
+/// answered with `401 Unauthorized` and a `WWW-Authenticate` header.
+#[derive(Debug)]
+pub struct Jwt<T>(
+  /// Claims
+  pub JwtClaims<T>,
+);
+
+impl<T> Jwt<T>
+where
+  T: DeserializeOwned,
+{
+  fn from_auto_stream<CA, SA>(auto_stream: &AutoStream<CA, SA>) -> Option<Self>
+  where
+    CA: Lease<JwtDecoder>,
+  {
+    let token = authorization_credentials(&auto_stream.req.rrd.headers, "bearer")?;
+    auto_stream.conn_aux.lease().decode(token).ok().map(Self)
+  }
+}
+
+impl<CA, E, F, RES, S, SA, T> Endpoint<CA, E, S, SA> for FnFutWrapper<(Jwt<T>,), F>
+where
+  CA: Lease<JwtDecoder>,
+  E: From<crate::Error>,
+  F: FnFut<(Jwt<T>,), Result = RES>,
+  RES: ResFinalizer<E>,
+  T: DeserializeOwned,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let jwt = Jwt::from_auto_stream(auto_stream);
+    auto_stream.req.rrd.clear();
+    let Some(elem) = jwt else {
+      return Ok(unauthorized(&mut auto_stream.req.rrd)?);
+    };
+    self.0.call((elem,)).await.finalize_response(&mut auto_stream.req)
+  }
+}
+
+impl<CA, E, F, RES, S, SA, T, const CLEAN: bool> Endpoint<CA, E, S, SA>
+  for FnFutWrapper<(StateGeneric<'_, CA, SA, ReqResBuffer, CLEAN>, Jwt<T>), F>
+where
+  CA: Lease<JwtDecoder>,
+  E: From<crate::Error>,
+  F: for<'any> FnFut<(StateGeneric<'any, CA, SA, ReqResBuffer, CLEAN>, Jwt<T>), Result = RES>,
+  RES: ResFinalizer<E>,
+  T: DeserializeOwned,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let Some(elem) = Jwt::from_auto_stream(auto_stream) else {
+      auto_stream.req.rrd.clear();
+      return Ok(unauthorized(&mut auto_stream.req.rrd)?);
+    };
+    self
+      .0
+      .call((
+        StateGeneric::new(
+          &mut auto_stream.conn_aux,
+          &mut auto_stream.stream_aux,
+          &mut auto_stream.req,
+        ),
+        elem,
+      ))
+      .await
+      .finalize_response(&mut auto_stream.req)
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc6750#section-3.1
+fn unauthorized(rrd: &mut ReqResBuffer) -> crate::Result<StatusCode> {
+  rrd.headers.push_from_iter(Header::from_name_and_value(
+    KnownHeaderName::WwwAuthenticate.into(),
+    [r#"Bearer error="invalid_token""#],
+  ))?;
+  Ok(StatusCode::Unauthorized)
+}
@@ -1,13 +1,17 @@
 use crate::{
   http::{
     AutoStream, ReqResBuffer, StatusCode,
-    server_framework::{Endpoint, ResFinalizer, RouteMatch, StateGeneric, arguments::manage_path},
+    server_framework::{
+      Endpoint, ResFinalizer, RouteMatch, StateGeneric,
+      arguments::{bad_request, manage_path},
+    },
   },
   misc::{FnFut, FnFutWrapper},
 };
 use core::str::FromStr;
 
-/// URI path converted into an owned type.
+/// URI path converted into an owned type. Paths that can't be converted are answered with
+/// `400 Bad Request`.
 #[derive(Debug)]
 pub struct PathOwned<T>(
   /// Arbitrary type
@@ -30,7 +34,10 @@ where
   ) -> Result<StatusCode, E> {
     auto_stream.req.rrd.clear();
     let path = manage_path(path_defs, &auto_stream.req.rrd.uri)?;
-    let path_owned = PathOwned(P::from_str(path).map_err(Into::into)?);
+    let path_owned = match P::from_str(path) {
+      Ok(elem) => PathOwned(elem),
+      Err(err) => return Ok(bad_request(&mut auto_stream.req.rrd, err.into())?),
+    };
     self.0.call((path_owned,)).await.finalize_response(&mut auto_stream.req)
   }
 }
@@ -51,7 +58,10 @@ where
     path_defs: (u8, &[RouteMatch]),
   ) -> Result<StatusCode, E> {
     let path = manage_path(path_defs, &auto_stream.req.rrd.uri)?;
-    let path_owned = PathOwned(P::from_str(path).map_err(Into::into)?);
+    let path_owned = match P::from_str(path) {
+      Ok(elem) => PathOwned(elem),
+      Err(err) => return Ok(bad_request(&mut auto_stream.req.rrd, err.into())?),
+    };
     self
       .0
       .call((
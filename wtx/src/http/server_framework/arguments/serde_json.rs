@@ -1,13 +1,14 @@
 use crate::{
   http::{
     AutoStream, Mime, ReqBuilder, ReqResBuffer, Request, StatusCode,
-    server_framework::{Endpoint, ResFinalizer, RouteMatch, StateGeneric},
+    server_framework::{Endpoint, ResFinalizer, RouteMatch, StateGeneric, arguments::bad_request},
   },
   misc::{FnFut, FnFutWrapper, LeaseMut},
 };
 use serde::{Serialize, de::DeserializeOwned};
 
-/// Serializes and deserializes using `serde_json`
+/// Serializes and deserializes using `serde_json`. Request bodies that can't be deserialized are
+/// answered with `400 Bad Request`.
 #[derive(Debug)]
 pub struct SerdeJson<T>(
   /// Arbitrary type
@@ -27,9 +28,12 @@ where
     auto_stream: &mut AutoStream<CA, SA>,
     _: (u8, &[RouteMatch]),
   ) -> Result<StatusCode, E> {
-    let elem =
-      serde_json::from_slice(&auto_stream.req.rrd.lease_mut().body).map_err(crate::Error::from)?;
+    let rslt = serde_json::from_slice(&auto_stream.req.rrd.lease_mut().body);
     auto_stream.req.rrd.lease_mut().clear();
+    let elem = match rslt {
+      Ok(elem) => elem,
+      Err(err) => return Ok(bad_request(auto_stream.req.rrd.lease_mut(), err.into())?),
+    };
     self.0.call((SerdeJson(elem),)).await.finalize_response(&mut auto_stream.req)
   }
 }
@@ -48,9 +52,12 @@ where
     auto_stream: &mut AutoStream<CA, SA>,
     _: (u8, &[RouteMatch]),
   ) -> Result<StatusCode, E> {
-    let elem =
-      serde_json::from_slice(&auto_stream.req.rrd.lease_mut().body).map_err(crate::Error::from)?;
+    let rslt = serde_json::from_slice(&auto_stream.req.rrd.lease_mut().body);
     auto_stream.req.rrd.lease_mut().clear();
+    let elem = match rslt {
+      Ok(elem) => elem,
+      Err(err) => return Ok(bad_request(auto_stream.req.rrd.lease_mut(), err.into())?),
+    };
     self
       .0
       .call((
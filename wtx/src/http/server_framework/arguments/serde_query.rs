@@ -0,0 +1,105 @@
+use crate::{
+  http::{
+    AutoStream, ReqResBuffer, StatusCode,
+    server_framework::{Endpoint, ResFinalizer, RouteMatch, StateGeneric, arguments::bad_request},
+  },
+  misc::{FnFut, FnFutWrapper, UriString},
+};
+use serde::de::DeserializeOwned;
+
+/// Deserializes the query of the URI using `serde_urlencoded`. Queries that can't be deserialized
+/// are answered with `400 Bad Request`.
+#[derive(Debug)]
+pub struct SerdeQuery<T>(
+  /// Arbitrary type
+  pub T,
+);
+
+impl<T> SerdeQuery<T>
+where
+  T: DeserializeOwned,
+{
+  /// Deserializes the query of `uri`, ignoring the fragment.
+  #[inline]
+  pub fn from_uri(uri: &UriString) -> crate::Result<Self> {
+    Ok(Self(uri.query_as()?))
+  }
+}
+
+impl<CA, E, F, RES, S, SA, T> Endpoint<CA, E, S, SA> for FnFutWrapper<(SerdeQuery<T>,), F>
+where
+  E: From<crate::Error>,
+  F: FnFut<(SerdeQuery<T>,), Result = RES>,
+  RES: ResFinalizer<E>,
+  T: DeserializeOwned,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let rslt = SerdeQuery::from_uri(&auto_stream.req.rrd.uri);
+    auto_stream.req.rrd.clear();
+    let elem = match rslt {
+      Ok(elem) => elem,
+      Err(err) => return Ok(bad_request(&mut auto_stream.req.rrd, err)?),
+    };
+    self.0.call((elem,)).await.finalize_response(&mut auto_stream.req)
+  }
+}
+
+impl<CA, E, F, RES, S, SA, T, const CLEAN: bool> Endpoint<CA, E, S, SA>
+  for FnFutWrapper<(StateGeneric<'_, CA, SA, ReqResBuffer, CLEAN>, SerdeQuery<T>), F>
+where
+  E: From<crate::Error>,
+  F:
+    for<'any> FnFut<(StateGeneric<'any, CA, SA, ReqResBuffer, CLEAN>, SerdeQuery<T>), Result = RES>,
+  RES: ResFinalizer<E>,
+  T: DeserializeOwned,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let elem = match SerdeQuery::from_uri(&auto_stream.req.rrd.uri) {
+      Ok(elem) => elem,
+      Err(err) => return Ok(bad_request(&mut auto_stream.req.rrd, err)?),
+    };
+    self
+      .0
+      .call((
+        StateGeneric::new(
+          &mut auto_stream.conn_aux,
+          &mut auto_stream.stream_aux,
+          &mut auto_stream.req,
+        ),
+        elem,
+      ))
+      .await
+      .finalize_response(&mut auto_stream.req)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{http::server_framework::SerdeQuery, misc::UriString};
+  use alloc::string::String;
+
+  #[derive(Debug, serde::Deserialize)]
+  struct Params {
+    name: String,
+    page: u32,
+  }
+
+  #[test]
+  fn from_uri() {
+    let uri = UriString::new("http://localhost/users?name=foo%20bar&page=2#top".into());
+    let params = SerdeQuery::<Params>::from_uri(&uri).unwrap().0;
+    assert_eq!((params.name.as_str(), params.page), ("foo bar", 2));
+    let uri = UriString::new("http://localhost/users?name=foo&page=a".into());
+    assert!(SerdeQuery::<Params>::from_uri(&uri).is_err());
+  }
+}
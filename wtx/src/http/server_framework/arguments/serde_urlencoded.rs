@@ -0,0 +1,92 @@
+use crate::{
+  http::{
+    AutoStream, Mime, ReqBuilder, ReqResBuffer, Request, StatusCode, from_form_urlencoded,
+    server_framework::{Endpoint, ResFinalizer, RouteMatch, StateGeneric, arguments::bad_request},
+    to_form_urlencoded,
+  },
+  misc::{FnFut, FnFutWrapper, LeaseMut},
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Serializes and deserializes `application/x-www-form-urlencoded` bodies using
+/// `serde_urlencoded`. Request bodies that can't be deserialized are answered with
+/// `400 Bad Request`.
+#[derive(Debug)]
+pub struct SerdeUrlencoded<T>(
+  /// Arbitrary type
+  pub T,
+);
+
+impl<CA, E, F, RES, S, SA, T> Endpoint<CA, E, S, SA> for FnFutWrapper<(SerdeUrlencoded<T>,), F>
+where
+  E: From<crate::Error>,
+  F: FnFut<(SerdeUrlencoded<T>,), Result = RES>,
+  RES: ResFinalizer<E>,
+  T: DeserializeOwned,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let rslt = from_form_urlencoded(&auto_stream.req.rrd.lease_mut().body);
+    auto_stream.req.rrd.lease_mut().clear();
+    let elem = match rslt {
+      Ok(elem) => elem,
+      Err(err) => return Ok(bad_request(auto_stream.req.rrd.lease_mut(), err)?),
+    };
+    self.0.call((SerdeUrlencoded(elem),)).await.finalize_response(&mut auto_stream.req)
+  }
+}
+
+impl<CA, E, F, RES, S, SA, T, const CLEAN: bool> Endpoint<CA, E, S, SA>
+  for FnFutWrapper<(StateGeneric<'_, CA, SA, ReqResBuffer, CLEAN>, SerdeUrlencoded<T>), F>
+where
+  E: From<crate::Error>,
+  F: for<'any> FnFut<
+      (StateGeneric<'any, CA, SA, ReqResBuffer, CLEAN>, SerdeUrlencoded<T>),
+      Result = RES,
+    >,
+  RES: ResFinalizer<E>,
+  T: DeserializeOwned,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let rslt = from_form_urlencoded(&auto_stream.req.rrd.lease_mut().body);
+    auto_stream.req.rrd.lease_mut().clear();
+    let elem = match rslt {
+      Ok(elem) => elem,
+      Err(err) => return Ok(bad_request(auto_stream.req.rrd.lease_mut(), err)?),
+    };
+    self
+      .0
+      .call((
+        StateGeneric::new(
+          &mut auto_stream.conn_aux,
+          &mut auto_stream.stream_aux,
+          &mut auto_stream.req,
+        ),
+        SerdeUrlencoded(elem),
+      ))
+      .await
+      .finalize_response(&mut auto_stream.req)
+  }
+}
+
+impl<E, T> ResFinalizer<E> for SerdeUrlencoded<T>
+where
+  E: From<crate::Error>,
+  T: Serialize,
+{
+  #[inline]
+  fn finalize_response(self, req: &mut Request<ReqResBuffer>) -> Result<StatusCode, E> {
+    let _ = ReqBuilder::from_req_mut(req).content_type(Mime::ApplicationXWwwFormUrlEncoded)?;
+    to_form_urlencoded(&self.0, &mut req.rrd.lease_mut().body)?;
+    Ok(StatusCode::Ok)
+  }
+}
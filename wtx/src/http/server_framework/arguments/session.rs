@@ -0,0 +1,35 @@
+use crate::{
+  http::{
+    AutoStream, SessionState, StatusCode,
+    server_framework::{Endpoint, ResFinalizer, RouteMatch},
+  },
+  misc::{FnFut, FnFutWrapper, LeaseMut},
+};
+
+/// Session of the current connection, previously loaded by [`crate::http::SessionMiddleware`].
+///
+/// [`Option::None`] means that the client didn't send a valid session cookie.
+#[derive(Debug)]
+pub struct Session<'any, CS>(
+  /// Mutable reference to the state stored in the connection auxiliary
+  pub &'any mut Option<SessionState<CS>>,
+);
+
+impl<CA, CS, E, F, RES, S, SA> Endpoint<CA, E, S, SA> for FnFutWrapper<(Session<'_, CS>,), F>
+where
+  CA: LeaseMut<Option<SessionState<CS>>>,
+  E: From<crate::Error>,
+  F: for<'any> FnFut<(Session<'any, CS>,), Result = RES>,
+  RES: ResFinalizer<E>,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    auto_stream.req.rrd.clear();
+    let session = Session(auto_stream.conn_aux.lease_mut());
+    self.0.call((session,)).await.finalize_response(&mut auto_stream.req)
+  }
+}
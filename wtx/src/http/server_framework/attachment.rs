@@ -0,0 +1,99 @@
+use crate::{
+  http::{
+    Header, KnownHeaderName, Mime, ReqResBuffer, Request, StatusCode,
+    server_framework::ResFinalizer,
+  },
+  misc::{AsciiSet, Lease, PercentEncode, Vector, from_utf8_basic},
+};
+use alloc::borrow::Cow;
+
+/// Content that browsers should download instead of displaying.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Disposition>
+#[derive(Debug)]
+pub struct Attachment<T> {
+  data: T,
+  filename: Cow<'static, str>,
+  mime: Mime,
+}
+
+impl<T> Attachment<T> {
+  /// `application/octet-stream` content that will be saved as `filename`.
+  #[inline]
+  pub fn new(data: T, filename: impl Into<Cow<'static, str>>) -> Self {
+    Self { data, filename: filename.into(), mime: Mime::ApplicationOctetStream }
+  }
+
+  /// Media type of `data`.
+  #[inline]
+  #[must_use]
+  pub fn mime(mut self, elem: Mime) -> Self {
+    self.mime = elem;
+    self
+  }
+}
+
+impl<E, T> ResFinalizer<E> for Attachment<T>
+where
+  E: From<crate::Error>,
+  T: Lease<[u8]>,
+{
+  #[inline]
+  fn finalize_response(self, req: &mut Request<ReqResBuffer>) -> Result<StatusCode, E> {
+    let mut disposition = Vector::new();
+    push_disposition(&mut disposition, &self.filename)?;
+    req.rrd.clear();
+    req.rrd.headers.push_from_iter_many([
+      Header::from_name_and_value(
+        KnownHeaderName::ContentDisposition.into(),
+        [from_utf8_basic(&disposition).map_err(crate::Error::from)?].into_iter(),
+      ),
+      Header::from_name_and_value(
+        KnownHeaderName::ContentType.into(),
+        [self.mime.as_str()].into_iter(),
+      ),
+    ])?;
+    req.rrd.body.extend_from_copyable_slice(self.data.lease())?;
+    Ok(StatusCode::Ok)
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc6266#section-4.3
+fn push_disposition(buffer: &mut Vector<u8>, filename: &str) -> crate::Result<()> {
+  buffer.extend_from_copyable_slice(b"attachment; filename=\"")?;
+  for byte in filename.bytes() {
+    match byte {
+      b'"' | b'\\' => buffer.extend_from_copyable_slice(&[b'\\', byte])?,
+      b' '..=b'~' => buffer.push(byte)?,
+      _ => buffer.push(b'_')?,
+    }
+  }
+  buffer.push(b'"')?;
+  if !filename.bytes().all(|el| matches!(el, b' '..=b'~')) {
+    // https://datatracker.ietf.org/doc/html/rfc5987#section-3.2.1
+    let ascii_set = AsciiSet::NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_');
+    buffer.extend_from_copyable_slice(b"; filename*=UTF-8''")?;
+    for elem in PercentEncode::new(filename.as_bytes(), ascii_set) {
+      buffer.extend_from_copyable_slice(elem)?;
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{http::server_framework::attachment::push_disposition, misc::Vector};
+
+  #[test]
+  fn disposition() {
+    let mut buffer = Vector::new();
+    push_disposition(&mut buffer, "report \"2024\".pdf").unwrap();
+    assert_eq!(buffer.as_slice(), br#"attachment; filename="report \"2024\".pdf""#);
+    buffer.clear();
+    push_disposition(&mut buffer, "résumé.pdf").unwrap();
+    assert_eq!(
+      buffer.as_slice(),
+      br#"attachment; filename="r__sum__.pdf"; filename*=UTF-8''r%C3%A9sum%C3%A9.pdf"#
+    );
+  }
+}
@@ -0,0 +1,227 @@
+use crate::{
+  http::{
+    ContentCoding, Header, HttpError, KnownHeaderName, Mime, ReqResBuffer, Request, Response,
+    StatusCode, content_coding::remove_content_headers, decompress_body, mime::essence,
+    server_framework::Middleware,
+  },
+  misc::{Vector, bytes_split1},
+};
+use core::{mem, net::IpAddr, ops::ControlFlow};
+
+// Formats that are already compressed and wouldn't benefit from another compression pass.
+static COMPRESSED_MIMES: &[&[u8]] = &[
+  b"application/gzip",
+  b"application/pdf",
+  b"application/vnd.rar",
+  b"application/wasm",
+  b"application/x-7z-compressed",
+  b"application/x-bzip2",
+  b"application/x-gzip",
+  b"application/zip",
+  b"application/zstd",
+  b"font/woff",
+  b"font/woff2",
+];
+
+/// Compresses response bodies according to the `Accept-Encoding` header sent by clients and
+/// decompresses request bodies according to their `Content-Encoding` header.
+///
+/// Only the codings enabled through features are available. Responses whose `Content-Type` is
+/// already compressed, like most images, audios, videos and archives, are sent as is unless
+/// an explicit list of media types is provided through [`CompressionMiddleware::mimes`].
+#[derive(Debug)]
+pub struct CompressionMiddleware {
+  codings: &'static [ContentCoding],
+  max_req_body_len: usize,
+  mimes: Option<&'static [Mime]>,
+  min_res_body_len: usize,
+}
+
+impl CompressionMiddleware {
+  /// Uses all supported codings, limits decompressed requests to 4MiB and compresses responses
+  /// that have at least 1024 bytes.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      codings: ContentCoding::SUPPORTED,
+      max_req_body_len: 4 * 1024 * 1024,
+      mimes: None,
+      min_res_body_len: 1024,
+    }
+  }
+
+  /// Codings offered to clients, ordered by preference.
+  #[inline]
+  #[must_use]
+  pub const fn codings(mut self, elem: &'static [ContentCoding]) -> Self {
+    self.codings = elem;
+    self
+  }
+
+  /// Maximum length of a decompressed request body.
+  #[inline]
+  #[must_use]
+  pub const fn max_req_body_len(mut self, elem: usize) -> Self {
+    self.max_req_body_len = elem;
+    self
+  }
+
+  /// If set, only responses whose `Content-Type` matches one of the elements, ignoring
+  /// parameters, are compressed.
+  #[inline]
+  #[must_use]
+  pub const fn mimes(mut self, elem: Option<&'static [Mime]>) -> Self {
+    self.mimes = elem;
+    self
+  }
+
+  /// Bodies of responses smaller than `elem` are not compressed.
+  #[inline]
+  #[must_use]
+  pub const fn min_res_body_len(mut self, elem: usize) -> Self {
+    self.min_res_body_len = elem;
+    self
+  }
+}
+
+impl<CA, E, SA> Middleware<CA, E, SA> for CompressionMiddleware
+where
+  E: From<crate::Error>,
+{
+  type Aux = Option<ContentCoding>;
+
+  #[inline]
+  fn aux(&self) -> Self::Aux {
+    None
+  }
+
+  #[inline]
+  async fn req(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    _: IpAddr,
+    req: &mut Request<ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    let accept_encoding = req.rrd.headers.get_by_name(KnownHeaderName::AcceptEncoding.into());
+    *mw_aux = accept_encoding.and_then(|el| ContentCoding::negotiate(el.value, self.codings));
+    if let Err(err) = decompress_body(&mut Vector::new(), self.max_req_body_len, &mut req.rrd) {
+      let status_code = match err {
+        crate::Error::HttpError(HttpError::LargeDecompressedBody) => StatusCode::PayloadTooLarge,
+        crate::Error::HttpError(HttpError::UnsupportedContentCoding) => {
+          StatusCode::UnsupportedMediaType
+        }
+        _ => return Err(err.into()),
+      };
+      req.rrd.clear();
+      return Ok(ControlFlow::Break(status_code));
+    }
+    Ok(ControlFlow::Continue(()))
+  }
+
+  #[inline]
+  async fn res(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    res: Response<&mut ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    if self.codings.is_empty()
+      || matches!(res.status_code, StatusCode::NoContent | StatusCode::NotModified)
+      || res.rrd.headers.get_by_name(KnownHeaderName::ContentEncoding.into()).is_some()
+    {
+      return Ok(ControlFlow::Continue(()));
+    }
+    res.rrd.headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::Vary.into(),
+      [<&str>::from(KnownHeaderName::AcceptEncoding)],
+    ))?;
+    let Some(coding) = mw_aux.filter(|el| *el != ContentCoding::Identity) else {
+      return Ok(ControlFlow::Continue(()));
+    };
+    if res.rrd.body.len() < self.min_res_body_len
+      || res
+        .rrd
+        .headers
+        .get_by_name(KnownHeaderName::ContentType.into())
+        .map_or(self.mimes.is_some(), |el| !self.is_compressible_mime(el.value))
+    {
+      return Ok(ControlFlow::Continue(()));
+    }
+    let mut buffer = Vector::new();
+    coding.compress(&res.rrd.body, &mut buffer)?;
+    mem::swap(&mut buffer, &mut res.rrd.body);
+    remove_content_headers(&mut res.rrd.headers);
+    res.rrd.headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::ContentEncoding.into(),
+      [coding.as_str()],
+    ))?;
+    Ok(ControlFlow::Continue(()))
+  }
+}
+
+impl CompressionMiddleware {
+  fn is_compressible_mime(&self, content_type: &str) -> bool {
+    match self.mimes {
+      Some(mimes) => {
+        let value = essence(content_type);
+        mimes.iter().any(|el| essence(el.as_str()).eq_ignore_ascii_case(value))
+      }
+      None => !is_compressed_mime(content_type.as_bytes()),
+    }
+  }
+}
+
+impl Default for CompressionMiddleware {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+fn is_compressed_mime(value: &[u8]) -> bool {
+  let mime = bytes_split1(value, b';').next().unwrap_or_default().trim_ascii();
+  let Some(idx) = mime.iter().position(|el| *el == b'/') else {
+    return false;
+  };
+  let (ty, subty) = (mime.get(..idx).unwrap_or_default(), mime.get(idx..).unwrap_or_default());
+  if ty.eq_ignore_ascii_case(b"audio") || ty.eq_ignore_ascii_case(b"video") {
+    return true;
+  }
+  if ty.eq_ignore_ascii_case(b"image") {
+    return !subty.eq_ignore_ascii_case(b"/svg+xml") && !subty.eq_ignore_ascii_case(b"/bmp");
+  }
+  COMPRESSED_MIMES.iter().any(|el| el.eq_ignore_ascii_case(mime))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::{
+    Mime,
+    server_framework::{CompressionMiddleware, compression_middleware::is_compressed_mime},
+  };
+
+  #[test]
+  fn allowed_mimes() {
+    let mw = CompressionMiddleware::new();
+    assert!(mw.is_compressible_mime("text/html"));
+    assert!(!mw.is_compressible_mime("image/png"));
+    let mw = mw.mimes(Some(&[Mime::ApplicationJson, Mime::Custom("text/html; charset=utf-8")]));
+    assert!(mw.is_compressible_mime("application/json"));
+    assert!(mw.is_compressible_mime("Text/HTML; charset=utf-8"));
+    assert!(!mw.is_compressible_mime("text/plain"));
+  }
+
+  #[test]
+  fn compressed_mimes() {
+    assert!(is_compressed_mime(b"image/png"));
+    assert!(is_compressed_mime(b"Video/MP4"));
+    assert!(is_compressed_mime(b"application/zip; charset=binary"));
+    assert!(!is_compressed_mime(b"image/svg+xml"));
+    assert!(!is_compressed_mime(b"text/html; charset=utf-8"));
+    assert!(!is_compressed_mime(b"application/json"));
+    assert!(!is_compressed_mime(b"invalid"));
+  }
+}
@@ -4,10 +4,10 @@ use crate::{
     StatusCode,
     server_framework::{ConnAux, Middleware, ServerFrameworkError},
   },
-  misc::{Intersperse, Vector, str_split1},
+  misc::{Intersperse, Vector, str_split_once1, str_split1},
 };
 use alloc::string::String;
-use core::{ops::ControlFlow, str};
+use core::{net::IpAddr, ops::ControlFlow, str};
 use hashbrown::HashSet;
 
 type AllowHeaders = (bool, Vector<String>);
@@ -20,6 +20,8 @@ type ExposeHeaders = (bool, Vector<String>);
 pub enum OriginResponse {
   /// An internal origin is passed to the response
   AllowedFromInternalList(usize),
+  /// The origin of the request is passed to the response
+  Mirrored(String),
   /// No origin in response
   None,
   /// Response origin is "*"
@@ -35,6 +37,8 @@ pub struct CorsMiddleware {
   // Many local options, many request/response options.
   allow_methods: AllowMethods,
   // Many local options, single request/response option.
+  allow_origin_patterns: Vector<String>,
+  // Many local options, single request/response option.
   allow_origins: AllowOrigins,
   // Many local options, many request/response options.
   expose_headers: ExposeHeaders,
@@ -58,6 +62,7 @@ impl CorsMiddleware {
       allow_credentials: false,
       allow_headers: (false, Vector::new()),
       allow_methods: (false, Vector::new()),
+      allow_origin_patterns: Vector::new(),
       allow_origins: (false, Vector::new()),
       expose_headers: (false, Vector::new()),
       max_age: None,
@@ -76,6 +81,7 @@ impl CorsMiddleware {
       allow_credentials: true,
       allow_headers: (true, Vector::new()),
       allow_methods: (false, Vector::from_iter(Method::ALL.into_iter())?),
+      allow_origin_patterns: Vector::new(),
       allow_origins: (true, Vector::new()),
       expose_headers: (true, Vector::new()),
       max_age: None,
@@ -132,9 +138,23 @@ impl CorsMiddleware {
     self
   }
 
+  /// Origins that contain a single `*`, which matches any non-empty sequence of characters
+  /// without slashes or colons. For example, `https://*.example.com` allows all subdomains of
+  /// `example.com` through HTTPS.
+  ///
+  /// Matched origins are mirrored back in responses.
+  #[inline]
+  #[must_use]
+  pub fn allow_origin_patterns(mut self, patterns: impl IntoIterator<Item = String>) -> Self {
+    self.allow_origin_patterns.clear();
+    let _rslt = self.allow_origin_patterns.extend_from_iter(patterns);
+    self
+  }
+
   /// <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Access-Control-Allow-Origin>
   ///
-  /// Wildcard is only allowed in requests without credentials.
+  /// Wildcard is only allowed in requests without credentials. If credentials are allowed, then
+  /// the origin of each request is mirrored back instead.
   #[inline]
   #[must_use]
   pub fn allow_origins(
@@ -192,6 +212,36 @@ impl CorsMiddleware {
       .find_map(|(idx, el)| (el == origin).then_some((el.as_str(), idx)))
   }
 
+  #[inline]
+  fn matches_origin_pattern(&self, origin: &str) -> bool {
+    self.allow_origin_patterns.iter().any(|pattern| {
+      let Some((prefix, suffix)) = str_split_once1(pattern, b'*') else {
+        return pattern == origin;
+      };
+      let Some(middle) = origin.strip_prefix(prefix).and_then(|el| el.strip_suffix(suffix)) else {
+        return false;
+      };
+      !middle.is_empty() && !middle.contains(['/', ':'])
+    })
+  }
+
+  #[inline]
+  fn origin_response(&self, origin: &str) -> Option<OriginResponse> {
+    if self.allow_origins.0 {
+      if self.allow_credentials {
+        Some(OriginResponse::Mirrored(String::from(origin)))
+      } else {
+        Some(OriginResponse::Wildcard)
+      }
+    } else if let Some((_, idx)) = self.allowed_origin(origin) {
+      Some(OriginResponse::AllowedFromInternalList(idx))
+    } else if self.matches_origin_pattern(origin) {
+      Some(OriginResponse::Mirrored(String::from(origin)))
+    } else {
+      None
+    }
+  }
+
   #[inline]
   fn apply_allow_credentials(allow_credentials: bool, headers: &mut Headers) -> crate::Result<()> {
     if allow_credentials {
@@ -249,6 +299,14 @@ impl CorsMiddleware {
     Ok(())
   }
 
+  // https://fetch.spec.whatwg.org/#cors-protocol-and-http-caches
+  #[inline]
+  fn apply_vary(headers: &mut Headers) -> crate::Result<()> {
+    headers
+      .push_from_iter(Header::from_name_and_value(KnownHeaderName::Vary.into(), ["origin"]))?;
+    Ok(())
+  }
+
   #[inline]
   fn apply_expose_headers(
     (is_wildcard, specifics): &ExposeHeaders,
@@ -290,23 +348,29 @@ impl CorsMiddleware {
       allow_credentials,
       allow_headers: _,
       allow_methods: _,
+      allow_origin_patterns: _,
       allow_origins: _,
       expose_headers,
       max_age: _,
     } = self;
-    Self::apply_allow_credentials(*allow_credentials, headers)?;
     match origin_response {
       OriginResponse::AllowedFromInternalList(idx) => {
         Self::apply_allow_origin(
           self.allow_origins.1.get(*idx).map(|el| el.as_str()).unwrap_or_default(),
           headers,
         )?;
+        Self::apply_vary(headers)?;
       }
-      OriginResponse::None => {}
+      OriginResponse::Mirrored(origin) => {
+        Self::apply_allow_origin(origin, headers)?;
+        Self::apply_vary(headers)?;
+      }
+      OriginResponse::None => return Ok(()),
       OriginResponse::Wildcard => {
         Self::apply_allow_origin("*", headers)?;
       }
     }
+    Self::apply_allow_credentials(*allow_credentials, headers)?;
     Self::apply_expose_headers(expose_headers, headers)?;
     Ok(())
   }
@@ -322,6 +386,7 @@ impl CorsMiddleware {
       allow_credentials,
       allow_headers: _,
       allow_methods,
+      allow_origin_patterns: _,
       allow_origins: _,
       expose_headers: _,
       max_age,
@@ -331,6 +396,7 @@ impl CorsMiddleware {
     Self::apply_allow_methods(allow_methods, headers)?;
     Self::apply_allow_origin(evaluated_allow_origin, headers)?;
     Self::apply_max_age(*max_age, headers)?;
+    Self::apply_vary(headers)?;
     Ok(())
   }
 
@@ -395,12 +461,12 @@ impl CorsMiddleware {
     body: &mut Vector<u8>,
     origin: Header<'_, &str>,
   ) -> crate::Result<()> {
-    let actual_origin = if self.allow_origins.0 {
-      origin.value
-    } else if let Some(allowed_origin) = self.allowed_origin(origin.value) {
-      allowed_origin.0
-    } else {
-      return Err(crate::Error::from(ServerFrameworkError::ForbiddenCorsOrigin));
+    let actual_origin = match self.origin_response(origin.value) {
+      Some(OriginResponse::AllowedFromInternalList(idx)) => {
+        self.allow_origins.1.get(idx).map(|el| el.as_str()).unwrap_or_default()
+      }
+      Some(_) => origin.value,
+      None => return Err(crate::Error::from(ServerFrameworkError::ForbiddenCorsOrigin)),
     };
     body.extend_from_copyable_slice(actual_origin.as_bytes())?;
     Ok(())
@@ -423,6 +489,7 @@ where
     &self,
     _: &mut CA,
     mw_aux: &mut Self::Aux,
+    _: IpAddr,
     req: &mut Request<ReqResBuffer>,
     _: &mut SA,
   ) -> Result<ControlFlow<StatusCode, ()>, E> {
@@ -432,9 +499,12 @@ where
         KnownHeaderName::AccessControlRequestMethod.into(),
         KnownHeaderName::Origin.into(),
       ]);
-      if let (Some(acrh), Some(acrm)) = (acrh_opt, acrm_opt) {
+      // `Access-Control-Request-Headers` is omitted when only safelisted headers are used.
+      if let Some(acrm) = acrm_opt {
         req.rrd.body.clear();
-        self.manage_preflight_headers(acrh, &mut req.rrd.body)?;
+        if let Some(acrh) = acrh_opt {
+          self.manage_preflight_headers(acrh, &mut req.rrd.body)?;
+        }
         self.manage_preflight_methods(acrm)?;
         let idx = req.rrd.body.len();
         self.manage_preflight_origin(&mut req.rrd.body, Self::extract_origin(origin_opt)?)?;
@@ -457,10 +527,9 @@ where
     } else {
       req.rrd.headers.get_by_name(KnownHeaderName::Origin.into())
     };
-    if self.allow_origins.0 {
-      *mw_aux = OriginResponse::Wildcard
-    } else if let Some(origin) = self.allowed_origin(Self::extract_origin(origin_opt)?.value) {
-      *mw_aux = OriginResponse::AllowedFromInternalList(origin.1)
+    // Requests without an `Origin` header are not CORS requests.
+    if let Some(elem) = origin_opt.and_then(|el| self.origin_response(el.value)) {
+      *mw_aux = elem;
     }
     Ok(ControlFlow::Continue(()))
   }
@@ -484,3 +553,60 @@ impl Default for CorsMiddleware {
     Self::new()
   }
 }
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::http::{
+    Header, Method, ReqResBuffer, Request, StatusCode,
+    server_framework::{CorsMiddleware, Middleware, OriginResponse},
+  };
+  use alloc::string::String;
+  use core::{net::Ipv4Addr, ops::ControlFlow};
+
+  #[tokio::test]
+  async fn origin_patterns() {
+    let cors = CorsMiddleware::new()
+      .allow_credentials()
+      .allow_methods(false, [Method::Get])
+      .allow_origin_patterns([String::from("https://*.example.com")])
+      .allow_origins(false, [String::from("https://foo.com")]);
+    assert!(matches!(
+      cors.origin_response("https://foo.com"),
+      Some(OriginResponse::AllowedFromInternalList(0))
+    ));
+    assert!(matches!(
+      cors.origin_response("https://a.b.example.com"),
+      Some(OriginResponse::Mirrored(_))
+    ));
+    assert!(cors.origin_response("https://.example.com").is_none());
+    assert!(cors.origin_response("https://evil.com/.example.com").is_none());
+    assert!(cors.origin_response("http://a.example.com").is_none());
+
+    let mut req = Request::http2(Method::Options, ReqResBuffer::empty());
+    req
+      .rrd
+      .headers
+      .push_from_iter(Header::from_name_and_value("origin", ["https://a.example.com"]))
+      .unwrap();
+    req
+      .rrd
+      .headers
+      .push_from_iter(Header::from_name_and_value("access-control-request-method", ["GET"]))
+      .unwrap();
+    let mut aux = Middleware::<(), crate::Error, ()>::aux(&cors);
+    let rslt = Middleware::<(), crate::Error, ()>::req(
+      &cors,
+      &mut (),
+      &mut aux,
+      Ipv4Addr::LOCALHOST.into(),
+      &mut req,
+      &mut (),
+    )
+    .await;
+    assert_eq!(rslt.unwrap(), ControlFlow::Break(StatusCode::Ok));
+    assert_eq!(
+      req.rrd.headers.get_by_name(b"access-control-allow-origin").map(|el| el.value),
+      Some("https://a.example.com")
+    );
+  }
+}
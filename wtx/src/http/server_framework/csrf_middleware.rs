@@ -0,0 +1,301 @@
+use crate::{
+  http::{
+    Header, Headers, KnownHeaderName, Method, ReqResBuffer, Request, Response, SameSite, SetCookie,
+    StatusCode,
+    cookie::{sign, verify},
+    server_framework::Middleware,
+  },
+  misc::{ArrayString, CryptoRng, Lock, Rng, Vector, str_split_once1, str_split1},
+};
+use alloc::string::String;
+use core::{net::IpAddr, ops::ControlFlow};
+
+// Base64 signature (44) followed by the random nonce (32).
+const TOKEN_LEN: usize = 76;
+
+/// Cross-site request forgery protection through the signed double-submit cookie strategy: unsafe
+/// requests must repeat the value of a cookie in the `X-CSRF-Token` header, which can only be
+/// done by scripts of the same site.
+///
+/// Tokens are composed by a random nonce and its HMAC, which is keyed by a server secret and
+/// bound to the session cookie defined in [`Self::session_cookie_name`]. Cookies injected by third
+/// parties or issued to other sessions are therefore rejected.
+///
+/// Responses of clients without a valid token receive a new cookie and every non-exempt response
+/// carries the current token in the `X-CSRF-Token` header, which spares the need of reading
+/// cookies in scripts.
+///
+/// Applications that use [`crate::http::SessionMiddleware`] are already protected by the
+/// synchronizer token strategy and don't need this middleware.
+///
+/// <https://cheatsheetseries.owasp.org/cheatsheets/Cross-Site_Request_Forgery_Prevention_Cheat_Sheet.html>
+#[derive(Debug)]
+pub struct CsrfMiddleware<RL> {
+  cookie_name: &'static str,
+  exempt_paths: Vector<String>,
+  rng: RL,
+  secret: [u8; 32],
+  secure: bool,
+  session_cookie_name: Option<&'static str>,
+}
+
+impl<RL> CsrfMiddleware<RL>
+where
+  RL: Lock,
+  RL::Resource: CryptoRng,
+{
+  /// Uses a secure cookie named `csrf_token`, doesn't exempt any path and doesn't bind tokens to
+  /// sessions.
+  ///
+  /// `rng` generates the nonces of new tokens and `secret` signs them. It is up to the caller to
+  /// provide a good RNG and a cryptographically secure secret that is shared by all the instances
+  /// of the application.
+  #[inline]
+  pub const fn new(rng: RL, secret: [u8; 32]) -> Self {
+    Self {
+      cookie_name: "csrf_token",
+      exempt_paths: Vector::new(),
+      rng,
+      secret,
+      secure: true,
+      session_cookie_name: None,
+    }
+  }
+}
+
+impl<RL> CsrfMiddleware<RL> {
+  /// Name of the cookie that stores tokens.
+  #[inline]
+  #[must_use]
+  pub const fn cookie_name(mut self, elem: &'static str) -> Self {
+    self.cookie_name = elem;
+    self
+  }
+
+  /// Paths that are not verified, e.g., webhooks authenticated by other means.
+  #[inline]
+  #[must_use]
+  pub fn exempt_paths(mut self, elem: Vector<String>) -> Self {
+    self.exempt_paths = elem;
+    self
+  }
+
+  /// If the cookie should only be sent through HTTPS. Should only be disabled in local
+  /// environments.
+  #[inline]
+  #[must_use]
+  pub const fn secure(mut self, elem: bool) -> Self {
+    self.secure = elem;
+    self
+  }
+
+  /// Name of the cookie that identifies the session of the client, whose value is signed along
+  /// side each token. Tokens become invalid when the session changes, e.g., after a login.
+  #[inline]
+  #[must_use]
+  pub const fn session_cookie_name(mut self, elem: Option<&'static str>) -> Self {
+    self.session_cookie_name = elem;
+    self
+  }
+}
+
+impl<CA, E, RL, SA> Middleware<CA, E, SA> for CsrfMiddleware<RL>
+where
+  E: From<crate::Error>,
+  RL: Lock,
+  RL::Resource: CryptoRng,
+{
+  /// Token that is sent in the response and if it was created in the request.
+  type Aux = Option<(ArrayString<TOKEN_LEN>, bool)>;
+
+  #[inline]
+  fn aux(&self) -> Self::Aux {
+    None
+  }
+
+  #[inline]
+  async fn req(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    _: IpAddr,
+    req: &mut Request<ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    let path = req.rrd.uri.path();
+    if self.exempt_paths.iter().any(|el| el == path) {
+      return Ok(ControlFlow::Continue(()));
+    }
+    let session = self
+      .session_cookie_name
+      .and_then(|name| cookie_value(&req.rrd.headers, name))
+      .unwrap_or_default();
+    let cookie_token = cookie_value(&req.rrd.headers, self.cookie_name)
+      .filter(|el| verify(&self.secret, (session, el)).is_ok());
+    let is_safe =
+      matches!(req.method, Method::Get | Method::Head | Method::Options | Method::Trace);
+    if !is_safe {
+      let header_token = req.rrd.headers.get_by_name(KnownHeaderName::XCsrfToken.into());
+      let is_valid = match (cookie_token, header_token) {
+        (Some(cookie), Some(header)) => {
+          is_eq_in_constant_time(cookie.as_bytes(), header.value.as_bytes())
+        }
+        _ => false,
+      };
+      if !is_valid {
+        req.rrd.clear();
+        return Ok(ControlFlow::Break(StatusCode::Forbidden));
+      }
+    }
+    *mw_aux = Some(match cookie_token.and_then(|el| ArrayString::try_from(el).ok()) {
+      Some(elem) => (elem, false),
+      None => {
+        let nonce = {
+          let mut rng = self.rng.lock().await;
+          let iter = rng.ascii_graphic_iter().filter(u8::is_ascii_alphanumeric).take(32);
+          ArrayString::<32>::from_iter(iter)?
+        };
+        let mut buffer = Vector::new();
+        sign(&mut buffer, &self.secret, (session, nonce.as_str()))?;
+        (ArrayString::try_from(buffer.as_slice())?, true)
+      }
+    });
+    Ok(ControlFlow::Continue(()))
+  }
+
+  #[inline]
+  async fn res(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    res: Response<&mut ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    let Some((token, is_new)) = mw_aux else {
+      return Ok(ControlFlow::Continue(()));
+    };
+    let headers = &mut res.rrd.headers;
+    if *is_new {
+      SetCookie::new(self.cookie_name, token.as_str())
+        .path("/")
+        .same_site(Some(SameSite::Strict))
+        .secure(self.secure)
+        .push(headers)?;
+    }
+    headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::XCsrfToken.into(),
+      [token.as_str()],
+    ))?;
+    Ok(ControlFlow::Continue(()))
+  }
+}
+
+fn cookie_value<'headers>(headers: &'headers Headers, name: &str) -> Option<&'headers str> {
+  headers
+    .iter()
+    .filter(|header| header.name == <&str>::from(KnownHeaderName::Cookie))
+    .flat_map(|header| str_split1(header.value, b';'))
+    .filter_map(|pair| str_split_once1(pair.trim_ascii(), b'='))
+    .find_map(|(local_name, value)| (local_name.trim_ascii() == name).then_some(value.trim_ascii()))
+}
+
+fn is_eq_in_constant_time(lhs: &[u8], rhs: &[u8]) -> bool {
+  if lhs.len() != rhs.len() {
+    return false;
+  }
+  lhs.iter().zip(rhs).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(all(feature = "_async-tests", feature = "rand_chacha", feature = "tokio", test))]
+mod tests {
+  use crate::http::{
+    Header, Method, ReqResBuffer, Request, Response, StatusCode,
+    server_framework::{CsrfMiddleware, Middleware},
+  };
+  use core::{net::Ipv4Addr, ops::ControlFlow};
+  use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+  use tokio::sync::Mutex;
+
+  type Csrf = CsrfMiddleware<Mutex<ChaCha20Rng>>;
+
+  #[tokio::test]
+  async fn signed_double_submit_cookie() {
+    let csrf = csrf();
+    let token = issue(&csrf, None).await;
+    assert_eq!(
+      post(&csrf, &["csrf_token=", &token], None).await,
+      ControlFlow::Break(StatusCode::Forbidden)
+    );
+    let header = Some(token.as_str());
+    assert_eq!(post(&csrf, &["csrf_token=", &token], header).await, ControlFlow::Continue(()));
+    let forged = "a".repeat(token.len());
+    let cookie = ["csrf_token=", &forged];
+    assert_eq!(
+      post(&csrf, &cookie, Some(&forged)).await,
+      ControlFlow::Break(StatusCode::Forbidden)
+    );
+  }
+
+  #[tokio::test]
+  async fn tokens_are_bound_to_sessions() {
+    let csrf = csrf().session_cookie_name(Some("id"));
+    let token = issue(&csrf, Some("id=foo")).await;
+    let header = Some(token.as_str());
+    let cookie = ["id=foo; csrf_token=", &token];
+    assert_eq!(post(&csrf, &cookie, header).await, ControlFlow::Continue(()));
+    let cookie = ["id=bar; csrf_token=", &token];
+    assert_eq!(post(&csrf, &cookie, header).await, ControlFlow::Break(StatusCode::Forbidden));
+  }
+
+  fn csrf() -> Csrf {
+    CsrfMiddleware::new(Mutex::new(ChaCha20Rng::from_seed([1; 32])), [2; 32])
+  }
+
+  async fn issue(csrf: &Csrf, cookie: Option<&str>) -> alloc::string::String {
+    let mut aux = Middleware::<(), crate::Error, ()>::aux(csrf);
+    let mut req = Request::http2(Method::Get, ReqResBuffer::empty());
+    if let Some(elem) = cookie {
+      req.rrd.headers.push_from_iter(Header::from_name_and_value("cookie", [elem])).unwrap();
+    }
+    let rslt = Middleware::<(), crate::Error, ()>::req(
+      csrf,
+      &mut (),
+      &mut aux,
+      Ipv4Addr::LOCALHOST.into(),
+      &mut req,
+      &mut (),
+    )
+    .await;
+    assert_eq!(rslt.unwrap(), ControlFlow::Continue(()));
+    req.rrd.headers.clear();
+    let res = Response::http2(&mut req.rrd, StatusCode::Ok);
+    let _ = Middleware::<(), crate::Error, ()>::res(csrf, &mut (), &mut aux, res, &mut ()).await;
+    let token = aux.unwrap().0;
+    assert!(req.rrd.headers.get_by_name(b"set-cookie").unwrap().value.starts_with("csrf_token="));
+    assert_eq!(req.rrd.headers.get_by_name(b"x-csrf-token").unwrap().value, token.as_str());
+    token.as_str().into()
+  }
+
+  async fn post(csrf: &Csrf, cookie: &[&str], header: Option<&str>) -> ControlFlow<StatusCode, ()> {
+    let mut aux = Middleware::<(), crate::Error, ()>::aux(csrf);
+    let mut req = Request::http2(Method::Post, ReqResBuffer::empty());
+    req
+      .rrd
+      .headers
+      .push_from_iter(Header::from_name_and_value("cookie", cookie.iter().copied()))
+      .unwrap();
+    if let Some(elem) = header {
+      req.rrd.headers.push_from_iter(Header::from_name_and_value("x-csrf-token", [elem])).unwrap();
+    }
+    Middleware::<(), crate::Error, ()>::req(
+      csrf,
+      &mut (),
+      &mut aux,
+      Ipv4Addr::LOCALHOST.into(),
+      &mut req,
+      &mut (),
+    )
+    .await
+    .unwrap()
+  }
+}
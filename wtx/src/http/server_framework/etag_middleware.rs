@@ -0,0 +1,145 @@
+use crate::{
+  http::{
+    Header, KnownHeaderName, Method, ReqResBuffer, Request, Response, StatusCode,
+    server_framework::Middleware,
+  },
+  misc::{ArrayString, str_split1},
+};
+use alloc::string::String;
+use core::{fmt::Write as _, net::IpAddr, ops::ControlFlow};
+
+/// Attaches `ETag` validators derived from the bodies of successful `GET` responses and answers
+/// requests whose `If-None-Match` header matches the validator with `304 Not Modified`.
+///
+/// Responses that already contain an `ETag` header keep it. Generated validators are weak by
+/// default because inner middlewares, like [`crate::http::server_framework::CompressionMiddleware`],
+/// can alter the bytes of equivalent bodies.
+#[derive(Debug)]
+pub struct EtagMiddleware {
+  weak: bool,
+}
+
+impl EtagMiddleware {
+  /// Generates weak validators.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { weak: true }
+  }
+
+  /// If `false`, generated validators are strong, which also allows their usage in range
+  /// requests.
+  #[inline]
+  #[must_use]
+  pub const fn weak(mut self, elem: bool) -> Self {
+    self.weak = elem;
+    self
+  }
+}
+
+impl<CA, E, SA> Middleware<CA, E, SA> for EtagMiddleware
+where
+  E: From<crate::Error>,
+{
+  /// `If-None-Match` of `GET` requests, empty if absent.
+  type Aux = Option<String>;
+
+  #[inline]
+  fn aux(&self) -> Self::Aux {
+    None
+  }
+
+  #[inline]
+  async fn req(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    _: IpAddr,
+    req: &mut Request<ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    if req.method == Method::Get {
+      let if_none_match = req.rrd.headers.get_by_name(KnownHeaderName::IfNoneMatch.into());
+      *mw_aux = Some(if_none_match.map(|el| el.value.into()).unwrap_or_default());
+    }
+    Ok(ControlFlow::Continue(()))
+  }
+
+  #[inline]
+  async fn res(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    res: Response<&mut ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    let Some(if_none_match) = mw_aux else {
+      return Ok(ControlFlow::Continue(()));
+    };
+    if res.status_code != StatusCode::Ok {
+      return Ok(ControlFlow::Continue(()));
+    }
+    let is_not_modified = match res.rrd.headers.get_by_name(KnownHeaderName::Etag.into()) {
+      Some(elem) => if_none_match_matches(if_none_match, elem.value),
+      None => {
+        let etag = body_etag(&res.rrd.body, self.weak)?;
+        res.rrd.headers.push_from_iter(Header::from_name_and_value(
+          KnownHeaderName::Etag.into(),
+          [etag.as_str()],
+        ))?;
+        if_none_match_matches(if_none_match, &etag)
+      }
+    };
+    if is_not_modified {
+      res.rrd.body.clear();
+      return Ok(ControlFlow::Break(StatusCode::NotModified));
+    }
+    Ok(ControlFlow::Continue(()))
+  }
+}
+
+impl Default for EtagMiddleware {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9110#section-13.1.2
+//
+// Uses the weak comparison function.
+pub(crate) fn if_none_match_matches(if_none_match: &str, etag: &str) -> bool {
+  let opaque = etag.strip_prefix("W/").unwrap_or(etag);
+  str_split1(if_none_match, b',')
+    .map(str::trim)
+    .any(|el| el == "*" || el.strip_prefix("W/").unwrap_or(el) == opaque)
+}
+
+// FNV-1a is stable across processes, which allows different instances to agree on validators.
+fn body_etag(body: &[u8], weak: bool) -> crate::Result<ArrayString<20>> {
+  let hash = body.iter().fold(0xcbf2_9ce4_8422_2325_u64, |acc, el| {
+    (acc ^ u64::from(*el)).wrapping_mul(0x0100_0000_01b3)
+  });
+  let mut etag = ArrayString::new();
+  etag.write_fmt(format_args!("{}\"{hash:016x}\"", if weak { "W/" } else { "" }))?;
+  Ok(etag)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::server_framework::etag_middleware::{body_etag, if_none_match_matches};
+
+  #[test]
+  fn body_etags() {
+    assert_eq!(body_etag(b"", false).unwrap().as_str(), "\"cbf29ce484222325\"");
+    assert_eq!(body_etag(b"a", true).unwrap().as_str(), "W/\"af63dc4c8601ec8c\"");
+  }
+
+  #[test]
+  fn if_none_match() {
+    assert!(if_none_match_matches("*", "\"a\""));
+    assert!(if_none_match_matches("\"b\", W/\"a\"", "\"a\""));
+    assert!(if_none_match_matches("\"a\"", "W/\"a\""));
+    assert!(!if_none_match_matches("\"b\"", "\"a\""));
+    assert!(!if_none_match_matches("", "\"a\""));
+  }
+}
@@ -0,0 +1,250 @@
+use crate::{
+  http::{
+    AutoStream, Header, KnownHeaderName, Mime, StatusCode,
+    server_framework::{Endpoint, EndpointNode, RouteMatch},
+  },
+  misc::{ArrayVector, Vector},
+};
+use alloc::string::ToString;
+use core::fmt::Display;
+
+static HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// Set of checks verified by [`Health`].
+pub trait HealthChecks {
+  /// Runs all checks, appending a `"NAME":{"status":...}` member followed by a comma for each
+  /// one into `buffer`. Returns `true` if all checks passed.
+  fn check(&self, buffer: &mut Vector<u8>) -> impl Future<Output = crate::Result<bool>>;
+}
+
+impl<T> HealthChecks for &T
+where
+  T: HealthChecks,
+{
+  #[inline]
+  async fn check(&self, buffer: &mut Vector<u8>) -> crate::Result<bool> {
+    (*self).check(buffer).await
+  }
+}
+
+/// Named asynchronous function that returns an error if something the application depends on,
+/// like a database connection, is unavailable.
+#[derive(Debug)]
+pub struct HealthCheck<F> {
+  fun: F,
+  name: &'static str,
+}
+
+impl<F> HealthCheck<F> {
+  /// New instance
+  #[inline]
+  pub const fn new(name: &'static str, fun: F) -> Self {
+    Self { fun, name }
+  }
+}
+
+impl<F, FUT> HealthChecks for HealthCheck<F>
+where
+  F: Fn() -> FUT,
+  FUT: Future<Output = crate::Result<()>>,
+{
+  #[inline]
+  async fn check(&self, buffer: &mut Vector<u8>) -> crate::Result<bool> {
+    push_check(buffer, self.name, (self.fun)().await)
+  }
+}
+
+/// Named check that acquires a database executor from a pool and issues a `SELECT 1`.
+///
+/// Fails if a connection can't be established or if the command returns an error.
+#[cfg(all(feature = "database", feature = "pool", feature = "std"))]
+#[derive(Debug)]
+pub struct DatabaseHealthCheck<P> {
+  name: &'static str,
+  pool: P,
+}
+
+#[cfg(all(feature = "database", feature = "pool", feature = "std"))]
+impl<P> DatabaseHealthCheck<P> {
+  /// New instance
+  #[inline]
+  pub const fn new(name: &'static str, pool: P) -> Self {
+    Self { name, pool }
+  }
+}
+
+#[cfg(all(feature = "database", feature = "pool", feature = "std"))]
+impl<R, RL, RM> HealthChecks for DatabaseHealthCheck<crate::pool::SimplePool<RL, RM>>
+where
+  R: crate::database::Executor,
+  RL: crate::misc::Lock<Resource = crate::pool::SimplePoolResource<R>>,
+  RM: crate::pool::ResourceManager<CreateAux = (), RecycleAux = (), Resource = R>,
+  RM::Error: Display,
+  <R::Database as crate::misc::DEController>::Error: Display,
+  for<'any> RL: 'any,
+  for<'any> RM: 'any,
+{
+  #[inline]
+  async fn check(&self, buffer: &mut Vector<u8>) -> crate::Result<bool> {
+    let mut guard = match self.pool.get().await {
+      Ok(elem) => elem,
+      Err(err) => return push_check(buffer, self.name, Err(err)),
+    };
+    let rslt = crate::database::Executor::execute(&mut ***guard, "SELECT 1", |_| Ok(())).await;
+    drop(guard);
+    push_check(buffer, self.name, rslt)
+  }
+}
+
+/// Endpoint that answers with `200 OK` if all checks passed or with `503 Service Unavailable`
+/// otherwise. The JSON body contains the individual status of each check.
+///
+/// Liveness endpoints usually don't have checks, i.e., `Health::new(())`, while readiness
+/// endpoints verify dependencies like databases.
+///
+/// <https://datatracker.ietf.org/doc/html/draft-inadarei-api-health-check>
+#[derive(Debug)]
+pub struct Health<T> {
+  checks: T,
+}
+
+impl<T> Health<T> {
+  /// New instance
+  #[inline]
+  pub const fn new(checks: T) -> Self {
+    Self { checks }
+  }
+}
+
+impl<CA, E, S, SA, T> Endpoint<CA, E, S, SA> for Health<T>
+where
+  E: From<crate::Error>,
+  T: HealthChecks,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let rrd = &mut auto_stream.req.rrd;
+    rrd.clear();
+    rrd.headers.push_from_iter_many([
+      Header::from_name_and_value(KnownHeaderName::CacheControl.into(), ["no-store"].into_iter()),
+      Header::from_name_and_value(
+        KnownHeaderName::ContentType.into(),
+        [Mime::ApplicationJson.as_str()].into_iter(),
+      ),
+    ])?;
+    rrd.body.extend_from_copyable_slice(br#"{"checks":{"#)?;
+    let is_healthy = self.checks.check(&mut rrd.body).await?;
+    if rrd.body.last() == Some(&b',') {
+      let _ = rrd.body.pop();
+    }
+    if is_healthy {
+      rrd.body.extend_from_copyable_slice(br#"},"status":"pass"}"#)?;
+      Ok(StatusCode::Ok)
+    } else {
+      rrd.body.extend_from_copyable_slice(br#"},"status":"fail"}"#)?;
+      Ok(StatusCode::ServiceUnavailable)
+    }
+  }
+}
+
+impl<CA, E, S, SA, T> EndpointNode<CA, E, S, SA> for Health<T>
+where
+  E: From<crate::Error>,
+  T: HealthChecks,
+{
+  const IS_ROUTER: bool = false;
+
+  #[inline]
+  fn paths_indices(
+    &self,
+    _: ArrayVector<RouteMatch, 4>,
+    _: &mut Vector<ArrayVector<RouteMatch, 4>>,
+  ) -> crate::Result<()> {
+    Ok(())
+  }
+}
+
+fn push_check<E>(buffer: &mut Vector<u8>, name: &str, rslt: Result<(), E>) -> crate::Result<bool>
+where
+  E: Display,
+{
+  push_json_str(buffer, name)?;
+  match rslt {
+    Ok(()) => {
+      buffer.extend_from_copyable_slice(br#":{"status":"pass"},"#)?;
+      Ok(true)
+    }
+    Err(err) => {
+      buffer.extend_from_copyable_slice(br#":{"output":"#)?;
+      push_json_str(buffer, &err.to_string())?;
+      buffer.extend_from_copyable_slice(br#","status":"fail"},"#)?;
+      Ok(false)
+    }
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc8259#section-7
+fn push_json_str(buffer: &mut Vector<u8>, str: &str) -> crate::Result<()> {
+  buffer.push(b'"')?;
+  for byte in str.bytes() {
+    match byte {
+      b'"' | b'\\' => buffer.extend_from_copyable_slice(&[b'\\', byte])?,
+      0..=31 => {
+        let [first, second] =
+          [byte >> 4, byte & 0b1111].map(|el| HEX.get(usize::from(el)).copied());
+        buffer.extend_from_copyable_slice(b"\\u00")?;
+        buffer.extend_from_copyable_slice(&[first.unwrap_or(b'0'), second.unwrap_or(b'0')])?;
+      }
+      _ => buffer.push(byte)?,
+    }
+  }
+  buffer.push(b'"')?;
+  Ok(())
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    http::server_framework::{HealthCheck, HealthChecks},
+    misc::Vector,
+  };
+
+  #[tokio::test]
+  async fn check() {
+    let mut buffer = Vector::new();
+    let checks = (
+      HealthCheck::new("cache", || async { Ok(()) }),
+      HealthCheck::new("db", || async { Err(crate::Error::ClosedConnection) }),
+    );
+    assert!(!checks.check(&mut buffer).await.unwrap());
+    assert_eq!(
+      buffer.as_slice(),
+      br#""cache":{"status":"pass"},"db":{"output":"ClosedConnection","status":"fail"},"#
+    );
+    buffer.clear();
+    assert!(().check(&mut buffer).await.unwrap());
+    assert!(buffer.is_empty());
+  }
+
+  #[cfg(all(feature = "database", feature = "pool", feature = "tokio"))]
+  #[tokio::test]
+  async fn database_check() {
+    use crate::{
+      http::server_framework::DatabaseHealthCheck,
+      pool::{SimplePoolTokio, SimpleRM},
+    };
+    let mut buffer = Vector::new();
+    let pool = SimplePoolTokio::new(1, SimpleRM::new(|| crate::Result::Ok(())));
+    assert!(DatabaseHealthCheck::new("db", pool).check(&mut buffer).await.unwrap());
+    assert_eq!(buffer.as_slice(), br#""db":{"status":"pass"},"#);
+    buffer.clear();
+    let pool =
+      SimplePoolTokio::new(1, SimpleRM::new(|| Err::<(), _>(crate::Error::ClosedConnection)));
+    assert!(!DatabaseHealthCheck::new("db", pool).check(&mut buffer).await.unwrap());
+    assert_eq!(buffer.as_slice(), br#""db":{"output":"ClosedConnection","status":"fail"},"#);
+  }
+}
@@ -0,0 +1,125 @@
+use crate::{
+  http::{
+    HttpInstrumentation, HttpRecord, Method, ReqResBuffer, Request, Response, StatusCode,
+    server_framework::Middleware,
+  },
+  misc::GenericTime,
+};
+use alloc::string::String;
+use core::{net::IpAddr, ops::ControlFlow};
+
+/// Reports requests to a [`HttpInstrumentation`] implementation.
+///
+/// Requests that result in errors or that are halted by preceding middlewares don't reach
+/// [`HttpInstrumentation::req_end`].
+#[derive(Debug)]
+pub struct InstrumentationMiddleware<I> {
+  instrumentation: I,
+}
+
+impl<I> InstrumentationMiddleware<I> {
+  /// New instance
+  #[inline]
+  pub const fn new(instrumentation: I) -> Self {
+    Self { instrumentation }
+  }
+}
+
+impl<CA, E, I, SA> Middleware<CA, E, SA> for InstrumentationMiddleware<I>
+where
+  E: From<crate::Error>,
+  I: HttpInstrumentation,
+{
+  /// Start, method, length of the request body and relative reference of the URI.
+  type Aux = Option<(GenericTime, Method, usize, String)>;
+
+  #[inline]
+  fn aux(&self) -> Self::Aux {
+    None
+  }
+
+  #[inline]
+  async fn req(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    _: IpAddr,
+    req: &mut Request<ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    let uri = req.rrd.uri.relative_reference_slash();
+    self.instrumentation.req_start(req.method, uri);
+    *mw_aux = Some((GenericTime::now(), req.method, req.rrd.body.len(), uri.into()));
+    Ok(ControlFlow::Continue(()))
+  }
+
+  #[inline]
+  async fn res(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    res: Response<&mut ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    if let Some((start, method, req_body_len, uri)) = mw_aux {
+      self.instrumentation.req_end(&HttpRecord {
+        elapsed: start.elapsed().unwrap_or_default(),
+        method: *method,
+        req_body_len: *req_body_len,
+        res_body_len: res.rrd.body.len(),
+        status_code: Some(res.status_code),
+        uri,
+      });
+    }
+    Ok(ControlFlow::Continue(()))
+  }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::http::{
+    HttpInstrumentation, HttpRecord, Method, ReqResBuffer, Request, Response, StatusCode,
+    server_framework::{InstrumentationMiddleware, Middleware},
+  };
+  use core::{cell::Cell, net::Ipv4Addr};
+
+  #[derive(Default)]
+  struct Lens {
+    end: Cell<Option<(usize, usize, Option<StatusCode>)>>,
+    starts: Cell<usize>,
+  }
+
+  impl HttpInstrumentation for Lens {
+    fn req_end(&self, record: &HttpRecord<'_>) {
+      assert_eq!((record.method, record.uri), (Method::Post, "/"));
+      self.end.set(Some((record.req_body_len, record.res_body_len, record.status_code)));
+    }
+
+    fn req_start(&self, _: Method, _: &str) {
+      self.starts.set(self.starts.get().wrapping_add(1));
+    }
+  }
+
+  #[tokio::test]
+  async fn reports_requests() {
+    let mw = InstrumentationMiddleware::new(Lens::default());
+    let mut aux = Middleware::<(), crate::Error, ()>::aux(&mw);
+    let mut req = Request::http2(Method::Post, ReqResBuffer::empty());
+    req.rrd.body.extend_from_copyable_slice(b"abc").unwrap();
+    let _ = Middleware::<(), crate::Error, ()>::req(
+      &mw,
+      &mut (),
+      &mut aux,
+      Ipv4Addr::LOCALHOST.into(),
+      &mut req,
+      &mut (),
+    )
+    .await;
+    assert_eq!((mw.instrumentation.starts.get(), mw.instrumentation.end.get()), (1, None));
+    req.rrd.clear();
+    req.rrd.body.extend_from_copyable_slice(b"defgh").unwrap();
+    let res = Response::http2(&mut req.rrd, StatusCode::Created);
+    let _ = Middleware::<(), crate::Error, ()>::res(&mw, &mut (), &mut aux, res, &mut ()).await;
+    assert_eq!(mw.instrumentation.end.get(), Some((3, 5, Some(StatusCode::Created))));
+  }
+}
@@ -5,8 +5,20 @@ use crate::{
   },
   misc::{ArrayVector, FnFut, Vector},
 };
+#[cfg(feature = "web-socket")]
+use crate::{
+  http::{Headers, ReqResBuffer, server_framework::StateGeneric},
+  http2::{Http2Buffer, Http2Data, ServerStream, WebSocketOverStream},
+  misc::{FnFutWrapper, LeaseMut, Lock, RefCounter, StreamWriter, Xorshift64, simple_seed},
+};
 
 /// Requires a WebSocket tunneling.
+///
+/// Endpoints can receive a [`ManualStream`] to perform the handshake by themselves or, with the
+/// `web-socket` feature, a `WebSocketOverStream` that already confirmed the handshake. In both
+/// cases, the server must enable the extended CONNECT protocol.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc8441>
 #[derive(Debug)]
 pub struct WebSocket<T>(
   /// Arbitrary type
@@ -78,3 +90,71 @@ where
     Ok(())
   }
 }
+
+#[cfg(feature = "web-socket")]
+impl<CA, E, F, HB, HD, SA, SW> Endpoint<CA, E, ServerStream<HD>, SA>
+  for FnFutWrapper<(WebSocketOverStream<ServerStream<HD>>,), F>
+where
+  E: From<crate::Error>,
+  F: FnFut<(WebSocketOverStream<ServerStream<HD>>,), Result = Result<(), E>>,
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  const OM: OperationMode = OperationMode::Manual;
+
+  #[inline]
+  async fn manual(
+    &self,
+    manual_stream: ManualStream<CA, ServerStream<HD>, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<(), E> {
+    let wos = accept(manual_stream.stream).await?;
+    self.0.call((wos,)).await
+  }
+}
+
+#[cfg(feature = "web-socket")]
+impl<CA, E, F, HB, HD, SA, SW, const CLEAN: bool> Endpoint<CA, E, ServerStream<HD>, SA>
+  for FnFutWrapper<
+    (StateGeneric<'_, CA, SA, ReqResBuffer, CLEAN>, WebSocketOverStream<ServerStream<HD>>),
+    F,
+  >
+where
+  E: From<crate::Error>,
+  F: for<'any> FnFut<
+      (StateGeneric<'any, CA, SA, ReqResBuffer, CLEAN>, WebSocketOverStream<ServerStream<HD>>),
+      Result = Result<(), E>,
+    >,
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  const OM: OperationMode = OperationMode::Manual;
+
+  #[inline]
+  async fn manual(
+    &self,
+    manual_stream: ManualStream<CA, ServerStream<HD>, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<(), E> {
+    let ManualStream { mut conn_aux, mut req, stream, mut stream_aux, .. } = manual_stream;
+    let wos = accept(stream).await?;
+    self.0.call((StateGeneric::new(&mut conn_aux, &mut stream_aux, &mut req), wos)).await
+  }
+}
+
+#[cfg(feature = "web-socket")]
+async fn accept<HB, HD, SW>(
+  stream: ServerStream<HD>,
+) -> crate::Result<WebSocketOverStream<ServerStream<HD>>>
+where
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  WebSocketOverStream::new(&Headers::new(), false, Xorshift64::from(simple_seed()), stream).await
+}
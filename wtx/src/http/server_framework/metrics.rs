@@ -0,0 +1,251 @@
+use crate::{
+  http::{
+    AutoStream, Header, KnownHeaderName, ReqResBuffer, Request, Response, StatusCode,
+    server_framework::{Endpoint, EndpointNode, Middleware, RouteMatch},
+  },
+  metrics::{Counter, Gauge, Histogram, push_metric_header},
+  misc::{ArrayString, ArrayVector, GenericTime, Vector},
+  sync::Arc,
+};
+use core::{fmt::Write, net::IpAddr, ops::ControlFlow, time::Duration};
+
+const CLASSES: [&str; 5] = ["1xx", "2xx", "3xx", "4xx", "5xx"];
+
+/// Registry of HTTP metrics fed by [`MetricsMiddleware`] and rendered by [`MetricsEndpoint`].
+///
+/// <https://prometheus.io/docs/instrumenting/exposition_formats/#text-based-format>
+#[derive(Debug)]
+pub struct Metrics {
+  duration: Histogram<11>,
+  in_flight: Gauge,
+  responses: [Counter; 5],
+}
+
+impl Metrics {
+  /// Instance without any recorded request.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      duration: Histogram::durations(),
+      in_flight: Gauge::new(),
+      responses: [const { Counter::new() }; 5],
+    }
+  }
+
+  /// Registers a response with `status_code` that took `elapsed` to be generated.
+  #[inline]
+  pub fn record(&self, status_code: StatusCode, elapsed: Duration) {
+    let class = usize::from(u16::from(status_code).wrapping_div(100)).saturating_sub(1);
+    if let Some(elem) = self.responses.get(class) {
+      elem.inc();
+    }
+    self.duration.observe_duration(elapsed);
+  }
+
+  /// Writes all metrics into `buffer` using the text exposition format.
+  #[inline]
+  pub fn render(&self, buffer: &mut Vector<u8>) -> crate::Result<()> {
+    push_metric_header(
+      buffer,
+      "http_requests_in_flight",
+      "Requests that are currently being processed.",
+      "gauge",
+    )?;
+    self.in_flight.push(buffer, "http_requests_in_flight", "")?;
+    push_metric_header(buffer, "http_responses_total", "Sent responses.", "counter")?;
+    let mut labels = ArrayString::<16>::new();
+    for (class, elem) in CLASSES.iter().zip(&self.responses) {
+      labels.clear();
+      labels.write_fmt(format_args!("class=\"{class}\""))?;
+      elem.push(buffer, "http_responses_total", &labels)?;
+    }
+    push_metric_header(
+      buffer,
+      "http_request_duration_seconds",
+      "Time taken to generate responses.",
+      "histogram",
+    )?;
+    self.duration.push(buffer, "http_request_duration_seconds")?;
+    Ok(())
+  }
+}
+
+impl Default for Metrics {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Renders [`Metrics`] as well as application-specific metrics written by `cb`, e.g., the number
+/// of available connections of a pool. See [`push_gauge`].
+#[derive(Debug)]
+pub struct MetricsEndpoint<F> {
+  cb: F,
+  metrics: Arc<Metrics>,
+}
+
+impl<F> MetricsEndpoint<F> {
+  /// New instance
+  #[inline]
+  pub const fn new(metrics: Arc<Metrics>, cb: F) -> Self {
+    Self { cb, metrics }
+  }
+}
+
+impl<CA, E, F, S, SA> Endpoint<CA, E, S, SA> for MetricsEndpoint<F>
+where
+  E: From<crate::Error>,
+  F: Fn(&mut Vector<u8>) -> crate::Result<()>,
+{
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    let rrd = &mut auto_stream.req.rrd;
+    rrd.clear();
+    rrd.headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::ContentType.into(),
+      ["text/plain; version=0.0.4; charset=utf-8"],
+    ))?;
+    self.metrics.render(&mut rrd.body)?;
+    (self.cb)(&mut rrd.body)?;
+    Ok(StatusCode::Ok)
+  }
+}
+
+impl<CA, E, F, S, SA> EndpointNode<CA, E, S, SA> for MetricsEndpoint<F>
+where
+  E: From<crate::Error>,
+  F: Fn(&mut Vector<u8>) -> crate::Result<()>,
+{
+  const IS_ROUTER: bool = false;
+
+  #[inline]
+  fn paths_indices(
+    &self,
+    _: ArrayVector<RouteMatch, 4>,
+    _: &mut Vector<ArrayVector<RouteMatch, 4>>,
+  ) -> crate::Result<()> {
+    Ok(())
+  }
+}
+
+/// Feeds [`Metrics`] with the number of in-flight requests as well as the status and the latency
+/// of responses. Requests that end with an error are registered as `5xx` responses.
+#[derive(Debug)]
+pub struct MetricsMiddleware {
+  metrics: Arc<Metrics>,
+}
+
+impl MetricsMiddleware {
+  /// New instance
+  #[inline]
+  pub const fn new(metrics: Arc<Metrics>) -> Self {
+    Self { metrics }
+  }
+}
+
+impl<CA, E, SA> Middleware<CA, E, SA> for MetricsMiddleware
+where
+  E: From<crate::Error>,
+{
+  type Aux = Option<InFlight>;
+
+  #[inline]
+  fn aux(&self) -> Self::Aux {
+    None
+  }
+
+  #[inline]
+  async fn req(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    _: IpAddr,
+    _: &mut Request<ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    self.metrics.in_flight.add(1);
+    *mw_aux = Some(InFlight {
+      is_recorded: false,
+      metrics: Arc::clone(&self.metrics),
+      start: GenericTime::now(),
+    });
+    Ok(ControlFlow::Continue(()))
+  }
+
+  #[inline]
+  async fn res(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    res: Response<&mut ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    if let Some(elem) = mw_aux {
+      elem.metrics.record(res.status_code, elem.start.elapsed().unwrap_or_default());
+      elem.is_recorded = true;
+    }
+    Ok(ControlFlow::Continue(()))
+  }
+}
+
+/// Request tracked by [`MetricsMiddleware`].
+#[derive(Debug)]
+pub struct InFlight {
+  is_recorded: bool,
+  metrics: Arc<Metrics>,
+  start: GenericTime,
+}
+
+impl Drop for InFlight {
+  #[inline]
+  fn drop(&mut self) {
+    self.metrics.in_flight.sub(1);
+    if !self.is_recorded {
+      let elapsed = self.start.elapsed().unwrap_or_default();
+      self.metrics.record(StatusCode::InternalServerError, elapsed);
+    }
+  }
+}
+
+/// Writes a gauge named `name` into `buffer` using the text exposition format.
+#[inline]
+pub fn push_gauge(
+  buffer: &mut Vector<u8>,
+  name: &str,
+  help: &str,
+  value: u64,
+) -> crate::Result<()> {
+  push_metric_header(buffer, name, help, "gauge")?;
+  buffer.write_fmt(format_args!("{name} {value}\n"))?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{StatusCode, server_framework::Metrics},
+    misc::{Vector, from_utf8_basic},
+  };
+  use core::time::Duration;
+
+  #[test]
+  fn render() {
+    let metrics = Metrics::new();
+    metrics.record(StatusCode::Ok, Duration::from_millis(20));
+    metrics.record(StatusCode::NotFound, Duration::from_millis(300));
+    let mut buffer = Vector::new();
+    metrics.render(&mut buffer).unwrap();
+    let text = from_utf8_basic(&buffer).unwrap();
+    assert!(text.contains("http_responses_total{class=\"2xx\"} 1\n"));
+    assert!(text.contains("http_responses_total{class=\"4xx\"} 1\n"));
+    assert!(text.contains("http_request_duration_seconds_bucket{le=\"0.025\"} 1\n"));
+    assert!(text.contains("http_request_duration_seconds_bucket{le=\"0.5\"} 2\n"));
+    assert!(text.contains("http_request_duration_seconds_sum 0.320000\n"));
+    assert!(text.contains("http_request_duration_seconds_count 2\n"));
+  }
+}
@@ -1,7 +1,12 @@
 use crate::http::{ReqResBuffer, Request, Response, StatusCode};
-use core::ops::ControlFlow;
+use core::{net::IpAddr, ops::ControlFlow};
 
 /// Request middleware
+///
+/// Middlewares can be attached to the whole server or to specific routers and tuples of
+/// middlewares are executed like an onion: requests are visited from the first element to the
+/// last element while responses are visited in the reverse order. If a request is halted, only
+/// the elements that already visited the request will visit the generated response.
 pub trait Middleware<CA, E, SA>
 where
   E: From<crate::Error>,
@@ -12,11 +17,13 @@ where
   /// Auxiliary structure
   fn aux(&self) -> Self::Aux;
 
-  /// Modifies or halts requests.
+  /// Modifies or halts requests. A halted request has its status code returned as a response,
+  /// which means that the buffer should contain the intended body.
   fn req(
     &self,
     conn_aux: &mut CA,
     mw_aux: &mut Self::Aux,
+    peer: IpAddr,
     req: &mut Request<ReqResBuffer>,
     stream_aux: &mut SA,
   ) -> impl Future<Output = Result<ControlFlow<StatusCode, ()>, E>>;
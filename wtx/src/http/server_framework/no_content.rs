@@ -0,0 +1,18 @@
+use crate::http::{ReqResBuffer, Request, StatusCode, server_framework::ResFinalizer};
+
+/// Empty response.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/204>
+#[derive(Clone, Copy, Debug)]
+pub struct NoContent;
+
+impl<E> ResFinalizer<E> for NoContent
+where
+  E: From<crate::Error>,
+{
+  #[inline]
+  fn finalize_response(self, req: &mut Request<ReqResBuffer>) -> Result<StatusCode, E> {
+    req.rrd.clear();
+    Ok(StatusCode::NoContent)
+  }
+}
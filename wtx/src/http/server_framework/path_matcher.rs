@@ -0,0 +1,154 @@
+use crate::{
+  http::server_framework::ServerFrameworkError,
+  misc::{Vector, str_split_once1, str_split1},
+};
+use alloc::{boxed::Box, string::String};
+
+/// Dependency-free alternative to `matchit` that associates URI paths with arbitrary values.
+///
+/// Supports static segments, `{name}` placeholders that capture a single non-empty segment and
+/// `{*name}` wildcards that capture the remaining non-empty part of a path. Static segments have
+/// priority over placeholders, which in turn have priority over wildcards.
+#[derive(Debug)]
+pub(crate) struct PathMatcher<T> {
+  root: Node<T>,
+}
+
+impl<T> PathMatcher<T> {
+  pub(crate) const fn new() -> Self {
+    Self { root: Node::new() }
+  }
+
+  pub(crate) fn _at(&self, path: &str) -> Option<&T> {
+    self.root._find(Some(path.strip_prefix('/').unwrap_or(path)))
+  }
+
+  pub(crate) fn insert(&mut self, path: &str, value: T) -> crate::Result<()> {
+    let Some(rest) = path.strip_prefix('/') else {
+      return Err(ServerFrameworkError::InvalidPathPattern.into());
+    };
+    let mut node = &mut self.root;
+    let mut iter = str_split1(rest, b'/').peekable();
+    while let Some(segment) = iter.next() {
+      if let Some(name) = segment.strip_prefix("{*") {
+        if iter.peek().is_some() || !is_valid_name(name) {
+          return Err(ServerFrameworkError::InvalidPathPattern.into());
+        }
+        if node.wildcard.is_some() {
+          return Err(ServerFrameworkError::DuplicatedPath.into());
+        }
+        node.wildcard = Some(value);
+        return Ok(());
+      }
+      if let Some(name) = segment.strip_prefix('{') {
+        if !is_valid_name(name) {
+          return Err(ServerFrameworkError::InvalidPathPattern.into());
+        }
+        node = node.param.get_or_insert_with(|| Box::new(Node::new()));
+        continue;
+      }
+      if segment.contains(['{', '}']) {
+        return Err(ServerFrameworkError::InvalidPathPattern.into());
+      }
+      let idx = if let Some(idx) = node.statics.iter().position(|el| el.0 == segment) {
+        idx
+      } else {
+        node.statics.push((segment.into(), Node::new()))?;
+        node.statics.len().wrapping_sub(1)
+      };
+      node = match node.statics.get_mut(idx) {
+        Some(elem) => &mut elem.1,
+        None => return Err(ServerFrameworkError::InvalidPathPattern.into()),
+      };
+    }
+    if node.value.is_some() {
+      return Err(ServerFrameworkError::DuplicatedPath.into());
+    }
+    node.value = Some(value);
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+struct Node<T> {
+  param: Option<Box<Node<T>>>,
+  statics: Vector<(String, Node<T>)>,
+  value: Option<T>,
+  wildcard: Option<T>,
+}
+
+impl<T> Node<T> {
+  const fn new() -> Self {
+    Self { param: None, statics: Vector::new(), value: None, wildcard: None }
+  }
+
+  // `None` indicates that the whole path was consumed.
+  fn _find(&self, rest: Option<&str>) -> Option<&T> {
+    let Some(rest) = rest else {
+      return self.value.as_ref();
+    };
+    let (segment, next) = match str_split_once1(rest, b'/') {
+      Some((lhs, rhs)) => (lhs, Some(rhs)),
+      None => (rest, None),
+    };
+    for (name, child) in self.statics.iter() {
+      if name == segment {
+        if let Some(elem) = child._find(next) {
+          return Some(elem);
+        }
+      }
+    }
+    if !segment.is_empty() {
+      if let Some(elem) = self.param.as_ref().and_then(|el| el._find(next)) {
+        return Some(elem);
+      }
+    }
+    if rest.is_empty() {
+      return None;
+    }
+    self.wildcard.as_ref()
+  }
+}
+
+fn is_valid_name(name: &str) -> bool {
+  name.strip_suffix('}').is_some_and(|el| !el.is_empty() && !el.contains(['/', '{', '}', '*']))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::server_framework::path_matcher::PathMatcher;
+
+  #[test]
+  fn invalid_patterns() {
+    let mut pm = PathMatcher::new();
+    assert!(pm.insert("a", 0).is_err());
+    assert!(pm.insert("/{}", 0).is_err());
+    assert!(pm.insert("/a{b}", 0).is_err());
+    assert!(pm.insert("/{*a}/b", 0).is_err());
+    assert!(pm.insert("/{a}", 0).is_ok());
+    assert!(pm.insert("/{b}", 0).is_err());
+  }
+
+  #[test]
+  fn matches() {
+    let mut pm = PathMatcher::new();
+    pm.insert("/", 0).unwrap();
+    pm.insert("/users", 1).unwrap();
+    pm.insert("/users/{id}", 2).unwrap();
+    pm.insert("/users/me", 3).unwrap();
+    pm.insert("/users/{id}/posts/{post}", 4).unwrap();
+    pm.insert("/files/{*path}", 5).unwrap();
+    pm.insert("/files/{name}/raw", 6).unwrap();
+    assert_eq!(pm._at("/"), Some(&0));
+    assert_eq!(pm._at("/users"), Some(&1));
+    assert_eq!(pm._at("/users/"), None);
+    assert_eq!(pm._at("/users/1"), Some(&2));
+    assert_eq!(pm._at("/users/me"), Some(&3));
+    assert_eq!(pm._at("/users/1/posts/2"), Some(&4));
+    assert_eq!(pm._at("/users/1/posts"), None);
+    assert_eq!(pm._at("/files/a/b/c"), Some(&5));
+    assert_eq!(pm._at("/files/a/raw"), Some(&6));
+    assert_eq!(pm._at("/files/"), None);
+    assert_eq!(pm._at("/unknown"), None);
+  }
+}
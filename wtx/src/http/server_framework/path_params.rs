@@ -2,6 +2,7 @@
 #[derive(Debug)]
 pub struct PathParams<T> {
   pub(crate) full_path: &'static str,
+  pub(crate) max_body_len: Option<u32>,
   pub(crate) value: T,
 }
 
@@ -9,6 +10,17 @@ impl<T> PathParams<T> {
   /// Creates a new instance
   #[inline]
   pub fn new(full_path: &'static str, value: T) -> Self {
-    Self { full_path, value }
+    Self { full_path, max_body_len: None, value }
+  }
+
+  /// Overrides the global maximum body length of requests. Requests that exceed this limit are
+  /// rejected with `413 Payload Too Large` while their data is still arriving.
+  ///
+  /// Inner paths take precedence over outer paths.
+  #[inline]
+  #[must_use]
+  pub fn max_body_len(mut self, elem: u32) -> Self {
+    self.max_body_len = Some(elem);
+    self
   }
 }
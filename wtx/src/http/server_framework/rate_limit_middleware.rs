@@ -0,0 +1,303 @@
+use crate::{
+  http::{
+    Header, KnownHeaderName, ReqResBuffer, Request, Response, StatusCode,
+    server_framework::{Middleware, RateLimitState, RateLimitStore},
+  },
+  misc::{GenericTime, str_split1},
+};
+use alloc::string::String;
+use core::{net::IpAddr, ops::ControlFlow, time::Duration};
+
+/// Extracts the identifier that shares the same quota from requests.
+pub trait RateLimitKey<CA, SA> {
+  /// Identifier
+  type Key;
+
+  /// Returns [`Option::None`] if the request shouldn't be limited.
+  fn key(
+    &self,
+    conn_aux: &CA,
+    peer: IpAddr,
+    req: &Request<ReqResBuffer>,
+    stream_aux: &SA,
+  ) -> crate::Result<Option<Self::Key>>;
+}
+
+/// Uses the entire value of a header. Requests without the header are not limited.
+#[derive(Debug)]
+pub struct RateLimitByHeader(
+  /// Header name
+  pub &'static str,
+);
+
+impl<CA, SA> RateLimitKey<CA, SA> for RateLimitByHeader {
+  type Key = String;
+
+  #[inline]
+  fn key(
+    &self,
+    _: &CA,
+    _: IpAddr,
+    req: &Request<ReqResBuffer>,
+    _: &SA,
+  ) -> crate::Result<Option<String>> {
+    Ok(req.rrd.headers.get_by_name(self.0.as_bytes()).map(|el| String::from(el.value)))
+  }
+}
+
+/// Uses the last address of the `X-Forwarded-For` header, which is the one appended by the
+/// closest reverse proxy. The server should only be reachable through a proxy that overwrites or
+/// appends this header, otherwise see [`RateLimitByPeerIp`].
+#[derive(Debug)]
+pub struct RateLimitByForwardedIp;
+
+impl<CA, SA> RateLimitKey<CA, SA> for RateLimitByForwardedIp {
+  type Key = IpAddr;
+
+  #[inline]
+  fn key(
+    &self,
+    _: &CA,
+    _: IpAddr,
+    req: &Request<ReqResBuffer>,
+    _: &SA,
+  ) -> crate::Result<Option<IpAddr>> {
+    let Some(header) = req.rrd.headers.get_by_name(b"x-forwarded-for") else {
+      return Ok(None);
+    };
+    Ok(str_split1(header.value, b',').last().and_then(|el| el.trim_ascii().parse().ok()))
+  }
+}
+
+/// Uses the address of the remote peer, which is the address of the client when the server is
+/// directly exposed. Requests of Unix sockets share the same quota.
+#[derive(Debug)]
+pub struct RateLimitByPeerIp;
+
+impl<CA, SA> RateLimitKey<CA, SA> for RateLimitByPeerIp {
+  type Key = IpAddr;
+
+  #[inline]
+  fn key(
+    &self,
+    _: &CA,
+    peer: IpAddr,
+    _: &Request<ReqResBuffer>,
+    _: &SA,
+  ) -> crate::Result<Option<IpAddr>> {
+    Ok(Some(peer))
+  }
+}
+
+/// Algorithm used to limit requests.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RateLimitPolicy {
+  /// Allows at most `max` requests in any window of `window` length. The window is approximated
+  /// by weighting the counter of the previous window.
+  SlidingWindow {
+    /// Maximum number of requests
+    max: u32,
+    /// Window length
+    window: Duration,
+  },
+  /// Allows bursts of `capacity` requests and gives back one request per `interval`.
+  TokenBucket {
+    /// Maximum number of tokens
+    capacity: u32,
+    /// Time needed to generate one token
+    interval: Duration,
+  },
+}
+
+impl RateLimitPolicy {
+  /// Registers a new request in `state`. Returns the amount of time that should be waited if
+  /// the quota is exhausted.
+  #[inline]
+  pub fn apply(&self, state: &mut RateLimitState, now: Duration) -> Option<Duration> {
+    let elapsed = now.saturating_sub(state.timestamp);
+    match *self {
+      Self::SlidingWindow { max, window } => {
+        if elapsed >= window.saturating_mul(2) {
+          state.counter = 0;
+          state.previous = 0;
+          state.timestamp = now;
+        } else if elapsed >= window {
+          state.previous = state.counter;
+          state.counter = 0;
+          state.timestamp = state.timestamp.saturating_add(window);
+        }
+        let window_nanos = window.as_nanos().max(1);
+        let elapsed_nanos = now.saturating_sub(state.timestamp).as_nanos().min(window_nanos);
+        let previous_weight = u128::from(state.previous)
+          .wrapping_mul(window_nanos.wrapping_sub(elapsed_nanos))
+          .div_ceil(window_nanos);
+        if previous_weight.wrapping_add(u128::from(state.counter)) >= u128::from(max) {
+          let window_end = state.timestamp.saturating_add(window);
+          return Some(window_end.saturating_sub(now));
+        }
+        state.counter = state.counter.wrapping_add(1);
+        None
+      }
+      Self::TokenBucket { capacity, interval } => {
+        let interval_nanos = interval.as_nanos().max(1);
+        let refill = elapsed.as_nanos().wrapping_div(interval_nanos);
+        if refill > 0 {
+          let tokens = u128::from(state.counter).saturating_add(refill);
+          state.counter = u32::try_from(tokens).unwrap_or(u32::MAX).min(capacity);
+          state.timestamp = if state.counter == capacity {
+            now
+          } else {
+            let refill_u32 = u32::try_from(refill).unwrap_or(u32::MAX);
+            state.timestamp.saturating_add(interval.saturating_mul(refill_u32))
+          };
+        }
+        if state.counter == 0 {
+          let next = state.timestamp.saturating_add(interval);
+          return Some(next.saturating_sub(now));
+        }
+        state.counter = state.counter.wrapping_sub(1);
+        None
+      }
+    }
+  }
+
+  /// If `state` is equivalent to a state that didn't consume anything, which means that it can
+  /// be discarded.
+  #[inline]
+  pub fn is_idle(&self, state: &RateLimitState, now: Duration) -> bool {
+    let elapsed = now.saturating_sub(state.timestamp);
+    match *self {
+      Self::SlidingWindow { window, .. } => elapsed >= window.saturating_mul(2),
+      Self::TokenBucket { capacity, interval } => {
+        let missing = capacity.saturating_sub(state.counter);
+        elapsed >= interval.saturating_mul(missing)
+      }
+    }
+  }
+}
+
+/// Rejects requests that exceed the quota of their key with a `429 Too Many Requests` response
+/// that contains the `Retry-After` header.
+#[derive(Debug)]
+pub struct RateLimitMiddleware<RK, RS> {
+  key: RK,
+  policy: RateLimitPolicy,
+  store: RS,
+}
+
+impl<RK, RS> RateLimitMiddleware<RK, RS> {
+  /// New instance
+  #[inline]
+  pub const fn new(key: RK, policy: RateLimitPolicy, store: RS) -> Self {
+    Self { key, policy, store }
+  }
+}
+
+impl<CA, E, RK, RS, SA> Middleware<CA, E, SA> for RateLimitMiddleware<RK, RS>
+where
+  E: From<crate::Error>,
+  RK: RateLimitKey<CA, SA>,
+  RS: RateLimitStore<RK::Key>,
+{
+  type Aux = ();
+
+  #[inline]
+  fn aux(&self) -> Self::Aux {}
+
+  #[inline]
+  async fn req(
+    &self,
+    conn_aux: &mut CA,
+    _: &mut Self::Aux,
+    peer: IpAddr,
+    req: &mut Request<ReqResBuffer>,
+    stream_aux: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    let Some(key) = self.key.key(conn_aux, peer, req, stream_aux)? else {
+      return Ok(ControlFlow::Continue(()));
+    };
+    let now = GenericTime::now_timestamp()?;
+    let Some(retry_after) = self.store.check(key, &self.policy, now).await? else {
+      return Ok(ControlFlow::Continue(()));
+    };
+    let secs = retry_after.as_secs().wrapping_add(u64::from(retry_after.subsec_nanos() > 0));
+    req.rrd.clear();
+    req.rrd.headers.push_from_fmt(Header::from_name_and_value(
+      KnownHeaderName::RetryAfter.into(),
+      format_args!("{secs}"),
+    ))?;
+    Ok(ControlFlow::Break(StatusCode::TooManyRequests))
+  }
+
+  #[inline]
+  async fn res(
+    &self,
+    _: &mut CA,
+    _: &mut Self::Aux,
+    _: Response<&mut ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    Ok(ControlFlow::Continue(()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::{
+    Header, Method, ReqResBuffer, Request,
+    server_framework::{
+      RateLimitByForwardedIp, RateLimitByPeerIp, RateLimitKey, RateLimitPolicy, RateLimitState,
+    },
+  };
+  use core::{
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+  };
+
+  #[test]
+  fn ip_keys() {
+    let peer = IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4));
+    let mut req = Request::http2(Method::Get, ReqResBuffer::empty());
+    assert_eq!(RateLimitByForwardedIp.key(&(), peer, &req, &()).unwrap(), None);
+    assert_eq!(RateLimitByPeerIp.key(&(), peer, &req, &()).unwrap(), Some(peer));
+    req
+      .rrd
+      .headers
+      .push_from_iter(Header::from_name_and_value("x-forwarded-for", ["5.6.7.8, 9.10.11.12"]))
+      .unwrap();
+    let forwarded = IpAddr::V4(Ipv4Addr::new(9, 10, 11, 12));
+    assert_eq!(RateLimitByForwardedIp.key(&(), peer, &req, &()).unwrap(), Some(forwarded));
+    assert_eq!(RateLimitByPeerIp.key(&(), peer, &req, &()).unwrap(), Some(peer));
+  }
+
+  #[test]
+  fn sliding_window() {
+    let policy = RateLimitPolicy::SlidingWindow { max: 2, window: Duration::from_secs(10) };
+    let mut state = RateLimitState::new(&policy, Duration::ZERO);
+    assert_eq!(policy.apply(&mut state, Duration::from_secs(1)), None);
+    assert_eq!(policy.apply(&mut state, Duration::from_secs(2)), None);
+    assert_eq!(policy.apply(&mut state, Duration::from_secs(3)), Some(Duration::from_secs(7)));
+    assert_eq!(policy.apply(&mut state, Duration::from_secs(16)), None);
+    assert_eq!(policy.apply(&mut state, Duration::from_secs(17)), Some(Duration::from_secs(3)));
+    assert!(policy.is_idle(&state, Duration::from_secs(30)));
+  }
+
+  #[test]
+  fn token_bucket() {
+    let policy = RateLimitPolicy::TokenBucket { capacity: 2, interval: Duration::from_secs(1) };
+    let mut state = RateLimitState::new(&policy, Duration::ZERO);
+    assert_eq!(policy.apply(&mut state, Duration::ZERO), None);
+    assert_eq!(policy.apply(&mut state, Duration::ZERO), None);
+    assert_eq!(
+      policy.apply(&mut state, Duration::from_millis(400)),
+      Some(Duration::from_millis(600))
+    );
+    assert_eq!(policy.apply(&mut state, Duration::from_millis(1500)), None);
+    assert_eq!(
+      policy.apply(&mut state, Duration::from_millis(1500)),
+      Some(Duration::from_millis(500))
+    );
+    assert!(!policy.is_idle(&state, Duration::from_secs(2)));
+    assert!(policy.is_idle(&state, Duration::from_secs(3)));
+  }
+}
@@ -0,0 +1,116 @@
+use crate::{
+  http::server_framework::RateLimitPolicy,
+  misc::{_random_state, Lock, Vector, Xorshift64, simple_seed},
+};
+use core::{
+  hash::{BuildHasher, Hash},
+  time::Duration,
+};
+use foldhash::fast::FixedState;
+use hashbrown::HashMap;
+
+/// [`RateLimitStoreMemory`] backed by `tokio`
+#[cfg(feature = "tokio")]
+pub type RateLimitStoreMemoryTokio<K> =
+  RateLimitStoreMemory<crate::sync::Arc<tokio::sync::Mutex<HashMap<K, RateLimitState>>>>;
+
+/// Where the consumption of each key is persisted. External stores, like Redis, should apply
+/// [`RateLimitPolicy`] atomically.
+pub trait RateLimitStore<K> {
+  /// Registers a new request of `key` at the `now` timestamp. Returns the amount of time that
+  /// should be waited if the quota is exhausted.
+  fn check(
+    &self,
+    key: K,
+    policy: &RateLimitPolicy,
+    now: Duration,
+  ) -> impl Future<Output = crate::Result<Option<Duration>>>;
+}
+
+impl<K, T> RateLimitStore<K> for &T
+where
+  T: RateLimitStore<K>,
+{
+  #[inline]
+  async fn check(
+    &self,
+    key: K,
+    policy: &RateLimitPolicy,
+    now: Duration,
+  ) -> crate::Result<Option<Duration>> {
+    (*self).check(key, policy, now).await
+  }
+}
+
+/// Consumption of a single key. See [`RateLimitPolicy::apply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RateLimitState {
+  /// Remaining tokens or the number of requests of the current window.
+  pub counter: u32,
+  /// Number of requests of the previous window.
+  pub previous: u32,
+  /// Last refill or the beginning of the current window.
+  pub timestamp: Duration,
+}
+
+impl RateLimitState {
+  /// Instance that didn't consume anything.
+  #[inline]
+  pub const fn new(policy: &RateLimitPolicy, now: Duration) -> Self {
+    let counter = match policy {
+      RateLimitPolicy::SlidingWindow { .. } => 0,
+      RateLimitPolicy::TokenBucket { capacity, .. } => *capacity,
+    };
+    Self { counter, previous: 0, timestamp: now }
+  }
+}
+
+/// Keeps states in memory, distributed among several independently locked shards to reduce
+/// contention. Idle states are periodically evicted.
+#[derive(Clone, Debug)]
+pub struct RateLimitStoreMemory<L> {
+  hasher: FixedState,
+  shards: Vector<L>,
+}
+
+impl<K, L> RateLimitStoreMemory<L>
+where
+  L: Lock<Resource = HashMap<K, RateLimitState>>,
+{
+  /// New instance with `shards` shards. At least one shard is always created.
+  #[inline]
+  pub fn new(shards: usize) -> crate::Result<Self> {
+    let mut vector = Vector::with_capacity(shards.max(1))?;
+    for _ in 0..shards.max(1) {
+      vector.push(L::new(HashMap::new()))?;
+    }
+    Ok(Self { hasher: _random_state(&mut Xorshift64::from(simple_seed())), shards: vector })
+  }
+}
+
+impl<K, L> RateLimitStore<K> for RateLimitStoreMemory<L>
+where
+  K: Eq + Hash,
+  L: Lock<Resource = HashMap<K, RateLimitState>>,
+{
+  #[inline]
+  async fn check(
+    &self,
+    key: K,
+    policy: &RateLimitPolicy,
+    now: Duration,
+  ) -> crate::Result<Option<Duration>> {
+    const EVICTION_THRESHOLD: usize = 4096;
+    let len = u64::try_from(self.shards.len()).unwrap_or(u64::MAX);
+    let idx = usize::try_from(self.hasher.hash_one(&key) % len.max(1)).unwrap_or_default();
+    let Some(shard) = self.shards.get(idx) else {
+      return Ok(None);
+    };
+    let mut map = shard.lock().await;
+    if map.len() >= EVICTION_THRESHOLD {
+      map.retain(|_, state| !policy.is_idle(state, now));
+    }
+    let state = map.entry(key).or_insert_with(|| RateLimitState::new(policy, now));
+    Ok(policy.apply(state, now))
+  }
+}
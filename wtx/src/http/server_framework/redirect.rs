@@ -2,23 +2,24 @@ use crate::http::{
   Header, Headers, KnownHeaderName, ReqResBuffer, ReqResDataMut, Request, StatusCode,
   server_framework::ResFinalizer,
 };
+use alloc::borrow::Cow;
 
 /// Redirects a request to another location.
 #[derive(Debug)]
 pub struct Redirect {
   status_code: StatusCode,
-  uri: &'static str,
+  uri: Cow<'static, str>,
 }
 
 impl Redirect {
   #[inline]
-  fn new(status_code: StatusCode, uri: &'static str) -> Self {
-    Self { status_code, uri }
+  fn new(status_code: StatusCode, uri: impl Into<Cow<'static, str>>) -> Self {
+    Self { status_code, uri: uri.into() }
   }
 
   /// <https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/308>
   #[inline]
-  pub fn permanent(uri: &'static str) -> Self {
+  pub fn permanent(uri: impl Into<Cow<'static, str>>) -> Self {
     Self::new(StatusCode::PermanentRedirect, uri)
   }
 
@@ -31,7 +32,7 @@ impl Redirect {
 
   /// <https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/307>
   #[inline]
-  pub fn temporary(uri: &'static str) -> Self {
+  pub fn temporary(uri: impl Into<Cow<'static, str>>) -> Self {
     Self::new(StatusCode::TemporaryRedirect, uri)
   }
 
@@ -44,7 +45,7 @@ impl Redirect {
 
   /// <https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/303>
   #[inline]
-  pub fn to(uri: &'static str) -> Self {
+  pub fn to(uri: impl Into<Cow<'static, str>>) -> Self {
     Self::new(StatusCode::SeeOther, uri)
   }
 
@@ -68,7 +69,7 @@ where
 {
   #[inline]
   fn finalize_response(self, req: &mut Request<ReqResBuffer>) -> Result<StatusCode, E> {
-    Self::push_headers(req.rrd.headers_mut(), self.uri)?;
+    Self::push_headers(req.rrd.headers_mut(), &self.uri)?;
     Ok(self.status_code)
   }
 }
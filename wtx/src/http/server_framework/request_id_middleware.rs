@@ -0,0 +1,217 @@
+use crate::{
+  http::{
+    Header, Headers, KnownHeaderName, ReqResBuffer, Request, Response, StatusCode,
+    server_framework::Middleware,
+  },
+  misc::{ArrayString, Rng, Xorshift64Sync, str_split1},
+};
+use core::{net::IpAddr, ops::ControlFlow};
+
+const MAX_LEN: usize = 64;
+
+/// Assigns an identifier to each request through the `X-Request-Id` header, which allows the
+/// correlation of logs emitted by different services.
+///
+/// The identifier is the incoming `X-Request-Id` value, the trace-id of an incoming `traceparent`
+/// or a random sequence of 32 hexadecimal characters, in this order. The request always carries
+/// the final identifier, which makes it available to endpoints, and so does the response.
+///
+/// <https://www.w3.org/TR/trace-context/#traceparent-header>
+#[derive(Debug)]
+pub struct RequestIdMiddleware {
+  rng: Xorshift64Sync,
+}
+
+impl RequestIdMiddleware {
+  /// Random identifiers are generated by `rng`, which should be seeded only once with a good
+  /// source of entropy like [`crate::misc::std_seed`].
+  #[inline]
+  pub const fn new(rng: Xorshift64Sync) -> Self {
+    Self { rng }
+  }
+}
+
+#[cfg(feature = "std")]
+impl Default for RequestIdMiddleware {
+  #[inline]
+  fn default() -> Self {
+    Self::new(Xorshift64Sync::from(crate::misc::std_seed()))
+  }
+}
+
+impl<CA, E, SA> Middleware<CA, E, SA> for RequestIdMiddleware
+where
+  E: From<crate::Error>,
+{
+  type Aux = ArrayString<MAX_LEN>;
+
+  #[inline]
+  fn aux(&self) -> Self::Aux {
+    ArrayString::new()
+  }
+
+  #[inline]
+  async fn req(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    _: IpAddr,
+    req: &mut Request<ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    let headers = &mut req.rrd.headers;
+    if let Some(elem) = incoming_request_id(headers) {
+      *mw_aux = ArrayString::try_from(elem)?;
+      return Ok(ControlFlow::Continue(()));
+    }
+    let trace_id = headers.get_by_name(KnownHeaderName::Traceparent.into()).and_then(|el| {
+      let mut iter = str_split1(el.value, b'-');
+      let (Some(version), Some(trace_id)) = (iter.next(), iter.next()) else {
+        return None;
+      };
+      let is_valid = version.len() == 2
+        && trace_id.len() == 32
+        && trace_id.bytes().all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+        && trace_id.bytes().any(|byte| byte != b'0');
+      is_valid.then_some(trace_id)
+    });
+    *mw_aux = match trace_id {
+      Some(elem) => ArrayString::try_from(elem)?,
+      None => {
+        let bytes = (&self.rng).u8_16();
+        ArrayString::from_iter(
+          bytes.into_iter().flat_map(|byte| [hex_char(byte >> 4), hex_char(byte & 0b1111)]),
+        )?
+      }
+    };
+    headers.retain(|el| el.name != <&str>::from(KnownHeaderName::XRequestId));
+    headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::XRequestId.into(),
+      [mw_aux.as_str()],
+    ))?;
+    Ok(ControlFlow::Continue(()))
+  }
+
+  #[inline]
+  async fn res(
+    &self,
+    _: &mut CA,
+    mw_aux: &mut Self::Aux,
+    res: Response<&mut ReqResBuffer>,
+    _: &mut SA,
+  ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    if mw_aux.is_empty() {
+      return Ok(ControlFlow::Continue(()));
+    }
+    let headers = &mut res.rrd.headers;
+    headers.retain(|el| el.name != <&str>::from(KnownHeaderName::XRequestId));
+    headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::XRequestId.into(),
+      [mw_aux.as_str()],
+    ))?;
+    Ok(ControlFlow::Continue(()))
+  }
+}
+
+/// Incoming `X-Request-Id` value composed by at most 64 visible ASCII characters.
+pub(crate) fn incoming_request_id(headers: &Headers) -> Option<&str> {
+  let header = headers.get_by_name(KnownHeaderName::XRequestId.into())?;
+  let value = header.value;
+  let is_valid =
+    !value.is_empty() && value.len() <= MAX_LEN && value.bytes().all(|el| el.is_ascii_graphic());
+  is_valid.then_some(value)
+}
+
+const fn hex_char(nibble: u8) -> u8 {
+  if nibble < 10 { b'0'.wrapping_add(nibble) } else { b'a'.wrapping_add(nibble.wrapping_sub(10)) }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    http::{
+      Header, Method, ReqResBuffer, Request, Response, StatusCode,
+      server_framework::{Middleware, RequestIdMiddleware},
+    },
+    misc::{Xorshift64Sync, simple_seed},
+  };
+  use core::net::Ipv4Addr;
+
+  #[tokio::test]
+  async fn generates_distinct_ids() {
+    let mw = RequestIdMiddleware::new(Xorshift64Sync::from(simple_seed()));
+    let mut ids = [Middleware::<(), crate::Error, ()>::aux(&mw); 2];
+    for aux in &mut ids {
+      let mut req = Request::http2(Method::Get, ReqResBuffer::empty());
+      let _ = Middleware::<(), crate::Error, ()>::req(
+        &mw,
+        &mut (),
+        aux,
+        Ipv4Addr::LOCALHOST.into(),
+        &mut req,
+        &mut (),
+      )
+      .await;
+      assert_eq!(aux.len(), 32);
+    }
+    let [first, second] = ids;
+    assert_ne!(first, second);
+  }
+
+  #[tokio::test]
+  async fn propagates_or_generates() {
+    let mw = RequestIdMiddleware::new(Xorshift64Sync::from(simple_seed()));
+
+    let mut aux = Middleware::<(), crate::Error, ()>::aux(&mw);
+    let mut req = Request::http2(Method::Get, ReqResBuffer::empty());
+    let traceparent = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+    req
+      .rrd
+      .headers
+      .push_from_iter(Header::from_name_and_value("traceparent", [traceparent]))
+      .unwrap();
+    let _ = Middleware::<(), crate::Error, ()>::req(
+      &mw,
+      &mut (),
+      &mut aux,
+      Ipv4Addr::LOCALHOST.into(),
+      &mut req,
+      &mut (),
+    )
+    .await;
+    assert_eq!(aux.as_str(), "4bf92f3577b34da6a3ce929d0e0e4736");
+    req.rrd.clear();
+    let res = Response::http2(&mut req.rrd, StatusCode::Ok);
+    let _ = Middleware::<(), crate::Error, ()>::res(&mw, &mut (), &mut aux, res, &mut ()).await;
+    let header = req.rrd.headers.get_by_name(b"x-request-id").unwrap();
+    assert_eq!(header.value, "4bf92f3577b34da6a3ce929d0e0e4736");
+
+    let mut aux = Middleware::<(), crate::Error, ()>::aux(&mw);
+    let mut req = Request::http2(Method::Get, ReqResBuffer::empty());
+    req.rrd.headers.push_from_iter(Header::from_name_and_value("x-request-id", ["abc"])).unwrap();
+    let _ = Middleware::<(), crate::Error, ()>::req(
+      &mw,
+      &mut (),
+      &mut aux,
+      Ipv4Addr::LOCALHOST.into(),
+      &mut req,
+      &mut (),
+    )
+    .await;
+    assert_eq!(aux.as_str(), "abc");
+
+    let mut aux = Middleware::<(), crate::Error, ()>::aux(&mw);
+    let mut req = Request::http2(Method::Get, ReqResBuffer::empty());
+    let _ = Middleware::<(), crate::Error, ()>::req(
+      &mw,
+      &mut (),
+      &mut aux,
+      Ipv4Addr::LOCALHOST.into(),
+      &mut req,
+      &mut (),
+    )
+    .await;
+    assert_eq!(aux.len(), 32);
+    assert_eq!(req.rrd.headers.get_by_name(b"x-request-id").unwrap().value, aux.as_str());
+  }
+}
@@ -4,6 +4,7 @@ use crate::http::OperationMode;
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct RouteMatch {
   pub(crate) idx: u8,
+  pub(crate) max_body_len: Option<u32>,
   pub(crate) om: OperationMode,
   pub(crate) path: &'static str,
 }
@@ -11,6 +12,6 @@ pub struct RouteMatch {
 impl RouteMatch {
   #[inline]
   pub(crate) fn new(idx: u8, om: OperationMode, path: &'static str) -> Self {
-    Self { idx, om, path }
+    Self { idx, max_body_len: None, om, path }
   }
 }
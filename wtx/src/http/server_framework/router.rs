@@ -1,3 +1,5 @@
+#[cfg(not(feature = "matchit"))]
+use crate::http::server_framework::path_matcher::PathMatcher;
 use crate::{
   http::{
     AutoStream, ManualStream, OperationMode, Response, StatusCode,
@@ -7,14 +9,16 @@ use crate::{
 };
 use core::{marker::PhantomData, ops::ControlFlow};
 
+#[cfg(feature = "matchit")]
+type Matcher = matchit::Router<(ArrayVector<RouteMatch, 4>, OperationMode)>;
+#[cfg(not(feature = "matchit"))]
+type Matcher = PathMatcher<(ArrayVector<RouteMatch, 4>, OperationMode)>;
+
 /// Redirects requests to specific asynchronous functions based on the set of inner URIs.
 #[derive(Debug)]
 pub struct Router<CA, E, EN, M, S, SA> {
   pub(crate) en: EN,
-  #[cfg(feature = "matchit")]
-  pub(crate) _matcher: matchit::Router<(ArrayVector<RouteMatch, 4>, OperationMode)>,
-  #[cfg(not(feature = "matchit"))]
-  pub(crate) _matcher: hashbrown::HashMap<alloc::string::String, OperationMode>,
+  pub(crate) _matcher: Matcher,
   pub(crate) middlewares: M,
   pub(crate) phantom: PhantomData<(CA, E, S, SA)>,
 }
@@ -32,13 +36,13 @@ where
   }
 
   #[inline]
-  #[cfg(feature = "matchit")]
-  fn _matcher(
-    en: &EN,
-  ) -> crate::Result<matchit::Router<(ArrayVector<RouteMatch, 4>, OperationMode)>> {
+  fn _matcher(en: &EN) -> crate::Result<Matcher> {
     let mut vec = Vector::new();
     en.paths_indices(ArrayVector::new(), &mut vec)?;
+    #[cfg(feature = "matchit")]
     let mut _matcher = matchit::Router::new();
+    #[cfg(not(feature = "matchit"))]
+    let mut _matcher = PathMatcher::new();
     for array in vec {
       let [initials @ .., last] = array.as_slice() else {
         continue;
@@ -49,27 +53,10 @@ where
       }
       key.push_str(last.path);
       let om = last.om;
-      _matcher.insert(key, (array, om))?;
+      _matcher.insert(key.as_str(), (array, om))?;
     }
     Ok(_matcher)
   }
-
-  #[inline]
-  #[cfg(not(feature = "matchit"))]
-  fn _matcher(
-    paths: &EN,
-  ) -> crate::Result<hashbrown::HashMap<alloc::string::String, OperationMode>> {
-    let mut paths_indices = Vector::new();
-    paths.paths_indices(ArrayVector::new(), &mut paths_indices)?;
-    let mut paths = hashbrown::HashMap::new();
-    for array in paths_indices {
-      let [first, ..] = array.as_slice() else {
-        continue;
-      };
-      let _ = paths.insert(first.path.into(), first.om);
-    }
-    Ok(paths)
-  }
 }
 
 impl<CA, E, EN, S, SA> Router<CA, E, EN, (), S, SA>
@@ -102,7 +89,13 @@ where
     let mw_aux = &mut self.middlewares.aux();
     if let ControlFlow::Break(el) = self
       .middlewares
-      .req(&mut auto_stream.conn_aux, mw_aux, &mut auto_stream.req, &mut auto_stream.stream_aux)
+      .req(
+        &mut auto_stream.conn_aux,
+        mw_aux,
+        auto_stream.peer,
+        &mut auto_stream.req,
+        &mut auto_stream.stream_aux,
+      )
       .await?
     {
       return Ok(el);
@@ -135,6 +128,7 @@ where
       .req(
         &mut manual_stream.conn_aux,
         mw_aux,
+        manual_stream.peer,
         &mut manual_stream.req,
         &mut manual_stream.stream_aux,
       )
@@ -0,0 +1,142 @@
+use crate::{
+  http::{
+    ManualStream, OperationMode, StatusCode,
+    server_framework::{
+      Endpoint, EndpointNode, RouteMatch, arguments::manage_path, serve_file::serve_file,
+    },
+  },
+  http2::{Http2Buffer, Http2Data, ServerStream},
+  misc::{
+    ArrayVector, LeaseMut, Lock, PercentDecode, RefCounter, StreamWriter, Vector, from_utf8_basic,
+    str_split1,
+  },
+};
+use std::path::PathBuf;
+
+/// Serves the files of a local directory based on the path captured by the last placeholder of a
+/// route, e.g., `/static/{*path}`.
+///
+/// Segments like `..` or `.` are rejected, which means that it is not possible to access files
+/// outside of the root directory.
+#[derive(Debug)]
+pub struct ServeDir {
+  index: Option<&'static str>,
+  precompressed: bool,
+  root: &'static str,
+}
+
+impl ServeDir {
+  /// New instance that serves `index.html` when a directory is requested.
+  #[inline]
+  pub const fn new(root: &'static str) -> Self {
+    Self { index: Some("index.html"), precompressed: false, root }
+  }
+
+  /// File served when a directory is requested. `None` responds requests to directories with 404.
+  #[inline]
+  #[must_use]
+  pub const fn index(mut self, elem: Option<&'static str>) -> Self {
+    self.index = elem;
+    self
+  }
+
+  /// See [`crate::http::server_framework::ServeFile::precompressed`].
+  #[inline]
+  #[must_use]
+  pub const fn precompressed(mut self, elem: bool) -> Self {
+    self.precompressed = elem;
+    self
+  }
+
+  fn file_path(&self, decoded: &str) -> Option<PathBuf> {
+    let mut rslt = PathBuf::from(self.root);
+    let relative = decoded.strip_prefix('/').unwrap_or(decoded);
+    let relative = relative.strip_suffix('/').unwrap_or(relative);
+    if !relative.is_empty() {
+      for segment in str_split1(relative, b'/') {
+        if matches!(segment, "" | "." | "..") || segment.contains(['\\', ':', '\0']) {
+          return None;
+        }
+        rslt.push(segment);
+      }
+    }
+    Some(rslt)
+  }
+}
+
+impl<CA, E, HB, HD, SA, SW> Endpoint<CA, E, ServerStream<HD>, SA> for ServeDir
+where
+  E: From<crate::Error>,
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  const OM: OperationMode = OperationMode::Manual;
+
+  #[inline]
+  async fn manual(
+    &self,
+    mut manual_stream: ManualStream<CA, ServerStream<HD>, SA>,
+    path_defs: (u8, &[RouteMatch]),
+  ) -> Result<(), E> {
+    let ManualStream { req, stream, .. } = &mut manual_stream;
+    let path = manage_path(path_defs, &req.rrd.uri)?;
+    let mut buffer = Vector::new();
+    let decoded = if PercentDecode::new(path.as_bytes()).decode(&mut buffer)? {
+      from_utf8_basic(&buffer).map_err(crate::Error::from)?
+    } else {
+      path
+    };
+    let Some(mut file_path) = self.file_path(decoded) else {
+      req.rrd.clear();
+      let _ = stream.common().send_headers(&req.rrd.headers, true, StatusCode::NotFound).await?;
+      return Ok(());
+    };
+    if let Some(index) = self.index {
+      if tokio::fs::metadata(&file_path).await.is_ok_and(|el| el.is_dir()) {
+        file_path.push(index);
+      }
+    }
+    Ok(serve_file(req, stream, &file_path, self.precompressed).await?)
+  }
+}
+
+impl<CA, E, HB, HD, SA, SW> EndpointNode<CA, E, ServerStream<HD>, SA> for ServeDir
+where
+  E: From<crate::Error>,
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  const IS_ROUTER: bool = false;
+
+  #[inline]
+  fn paths_indices(
+    &self,
+    _: ArrayVector<RouteMatch, 4>,
+    _: &mut Vector<ArrayVector<RouteMatch, 4>>,
+  ) -> crate::Result<()> {
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::server_framework::ServeDir;
+  use std::path::PathBuf;
+
+  #[test]
+  fn file_path() {
+    let sd = ServeDir::new("/srv");
+    assert_eq!(sd.file_path(""), Some(PathBuf::from("/srv")));
+    assert_eq!(sd.file_path("a/b.txt"), Some(PathBuf::from("/srv/a/b.txt")));
+    assert_eq!(sd.file_path("/a/"), Some(PathBuf::from("/srv/a")));
+    assert_eq!(sd.file_path("../etc/passwd"), None);
+    assert_eq!(sd.file_path("a/./b"), None);
+    assert_eq!(sd.file_path("a//b"), None);
+    assert_eq!(sd.file_path("a\\..\\b"), None);
+    assert_eq!(sd.file_path("C:/b"), None);
+  }
+}
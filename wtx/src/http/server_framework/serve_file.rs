@@ -0,0 +1,485 @@
+use crate::{
+  http::{
+    ContentCoding, Header, Headers, KnownHeaderName, ManualStream, Method, Mime, OperationMode,
+    RangeRequest, ReqResBuffer, Request, StatusCode, format_http_date,
+    multipart::random_boundary,
+    parse_http_date,
+    server_framework::{
+      Endpoint, EndpointNode, RouteMatch, etag_middleware::if_none_match_matches,
+    },
+  },
+  http2::{Http2Buffer, Http2Data, SendDataMode, ServerStream},
+  misc::{
+    ArrayString, ArrayVector, LeaseMut, Lock, RefCounter, StreamWriter, Vector, Xorshift64,
+    simple_seed,
+  },
+};
+use core::fmt::Write;
+use std::{
+  ffi::OsString,
+  io::{ErrorKind, SeekFrom},
+  path::Path,
+  time::UNIX_EPOCH,
+};
+use tokio::{
+  fs::File,
+  io::{AsyncReadExt, AsyncSeekExt},
+};
+
+/// Maximum number of bytes that are read from the file system and sent at once.
+const CHUNK_LEN: usize = 16 * 1024;
+
+/// Serves a single file of the local file system.
+///
+/// Only `GET` and `HEAD` requests are accepted. Responses carry `ETag` and `Last-Modified`
+/// validators, which are used to answer conditional and range requests.
+///
+/// Files are sent in chunks directly through the HTTP/2 stream, as such, middlewares that modify
+/// response bodies are not applied.
+#[derive(Debug)]
+pub struct ServeFile {
+  path: &'static str,
+  precompressed: bool,
+}
+
+impl ServeFile {
+  /// New instance
+  #[inline]
+  pub const fn new(path: &'static str) -> Self {
+    Self { path, precompressed: false }
+  }
+
+  /// If enabled, sends the `.br` or `.gz` siblings of the file, when they exist, to clients that
+  /// accept the respective coding.
+  #[inline]
+  #[must_use]
+  pub const fn precompressed(mut self, elem: bool) -> Self {
+    self.precompressed = elem;
+    self
+  }
+}
+
+impl<CA, E, HB, HD, SA, SW> Endpoint<CA, E, ServerStream<HD>, SA> for ServeFile
+where
+  E: From<crate::Error>,
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  const OM: OperationMode = OperationMode::Manual;
+
+  #[inline]
+  async fn manual(
+    &self,
+    mut manual_stream: ManualStream<CA, ServerStream<HD>, SA>,
+    _: (u8, &[RouteMatch]),
+  ) -> Result<(), E> {
+    let ManualStream { req, stream, .. } = &mut manual_stream;
+    Ok(serve_file(req, stream, Path::new(self.path), self.precompressed).await?)
+  }
+}
+
+impl<CA, E, HB, HD, SA, SW> EndpointNode<CA, E, ServerStream<HD>, SA> for ServeFile
+where
+  E: From<crate::Error>,
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  const IS_ROUTER: bool = false;
+
+  #[inline]
+  fn paths_indices(
+    &self,
+    _: ArrayVector<RouteMatch, 4>,
+    _: &mut Vector<ArrayVector<RouteMatch, 4>>,
+  ) -> crate::Result<()> {
+    Ok(())
+  }
+}
+
+pub(crate) async fn serve_file<HB, HD, SW>(
+  req: &mut Request<ReqResBuffer>,
+  stream: &mut ServerStream<HD>,
+  path: &Path,
+  precompressed: bool,
+) -> crate::Result<()>
+where
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  let method = req.method;
+  let rrd = &mut req.rrd;
+  if method != Method::Get && method != Method::Head {
+    rrd.clear();
+    rrd
+      .headers
+      .push_from_iter(Header::from_name_and_value(KnownHeaderName::Allow.into(), ["GET, HEAD"]))?;
+    let _ = send_headers(stream, &rrd.headers, true, StatusCode::MethodNotAllowed).await?;
+    return Ok(());
+  }
+  let Some(metadata) = file_metadata(path).await? else {
+    rrd.clear();
+    let _ = send_headers(stream, &rrd.headers, true, StatusCode::NotFound).await?;
+    return Ok(());
+  };
+  let (coding, metadata) = match precompressed_variant(&rrd.headers, path, precompressed).await? {
+    Some((coding, elem)) => (Some(coding), elem),
+    None => (None, metadata),
+  };
+  let len = metadata.len();
+  let mtime = metadata.modified().ok().and_then(|el| el.duration_since(UNIX_EPOCH).ok());
+  let secs = mtime.map(|el| el.as_secs());
+  let mut etag = ArrayString::<64>::new();
+  etag.write_fmt(format_args!(
+    "\"{len:x}-{:x}{}{}\"",
+    mtime.map(|el| el.as_nanos()).unwrap_or_default(),
+    if coding.is_some() { "-" } else { "" },
+    coding.map(ContentCoding::as_str).unwrap_or_default(),
+  ))?;
+  let last_modified = secs.map(format_http_date).transpose()?;
+  let last_modified_str = last_modified.as_ref().map(|el| el.as_str());
+  let range = if is_not_modified(&rrd.headers, &etag, secs) {
+    None
+  } else {
+    Some(RangeRequest::from_headers(&rrd.headers, Some(etag.as_str()), last_modified_str))
+  };
+  rrd.clear();
+  let headers = &mut rrd.headers;
+  headers.push_from_iter(Header::from_name_and_value(KnownHeaderName::Etag.into(), [&*etag]))?;
+  if let Some(elem) = last_modified_str {
+    headers
+      .push_from_iter(Header::from_name_and_value(KnownHeaderName::LastModified.into(), [elem]))?;
+  }
+  if precompressed {
+    headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::Vary.into(),
+      ["accept-encoding"],
+    ))?;
+  }
+  let Some(range) = range else {
+    let _ = send_headers(stream, headers, true, StatusCode::NotModified).await?;
+    return Ok(());
+  };
+  headers
+    .push_from_iter(Header::from_name_and_value(KnownHeaderName::AcceptRanges.into(), ["bytes"]))?;
+  if let Some(elem) = coding {
+    headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::ContentEncoding.into(),
+      [elem.as_str()],
+    ))?;
+  }
+  let mime = Mime::from_extension(path.extension().and_then(|el| el.to_str()).unwrap_or_default());
+  let mut boundary = None;
+  let mut ranges = ArrayVector::<(u64, u64), 16>::new();
+  let status_code = if let Some(elem) = range {
+    ranges = elem.resolve(len);
+    match ranges.as_slice() {
+      [] => {
+        headers.push_from_fmt(Header::from_name_and_value(
+          KnownHeaderName::ContentRange.into(),
+          format_args!("bytes */{len}"),
+        ))?;
+        StatusCode::RequestedRangeNotSatisfiable
+      }
+      [(first, last)] => {
+        headers.push_from_fmt(Header::from_name_and_value(
+          KnownHeaderName::ContentRange.into(),
+          format_args!("bytes {first}-{last}/{len}"),
+        ))?;
+        push_content_headers(headers, last.wrapping_sub(*first).wrapping_add(1), mime.as_str())?;
+        StatusCode::PartialContent
+      }
+      _ => {
+        let local_boundary = random_boundary(&mut Xorshift64::from(simple_seed()))?;
+        headers.push_from_iter(Header::from_name_and_value(
+          KnownHeaderName::ContentType.into(),
+          [Mime::MultipartByteranges.as_str(), "; boundary=", local_boundary.as_str()],
+        ))?;
+        boundary = Some(local_boundary);
+        StatusCode::PartialContent
+      }
+    }
+  } else {
+    if let Some(last) = len.checked_sub(1) {
+      ranges.push((0, last))?;
+    }
+    push_content_headers(headers, len, mime.as_str())?;
+    StatusCode::Ok
+  };
+  let [first_range, ..] = ranges.as_slice() else {
+    let _ = send_headers(stream, headers, true, status_code).await?;
+    return Ok(());
+  };
+  let is_head = method == Method::Head;
+  if !send_headers(stream, headers, is_head, status_code).await? || is_head {
+    return Ok(());
+  }
+  let mut file = if let Some(elem) = coding {
+    File::open(sibling(path, elem)).await?
+  } else {
+    File::open(path).await?
+  };
+  let mut chunk = alloc::vec![0; usize::try_from(len).unwrap_or(usize::MAX).min(CHUNK_LEN)];
+  let Some(boundary) = boundary else {
+    let _ = send_file_range(&mut chunk, &mut file, *first_range, true, stream).await?;
+    return Ok(());
+  };
+  let mut buffer = Vector::new();
+  for (idx, (first, last)) in ranges.iter().copied().enumerate() {
+    buffer.clear();
+    if idx > 0 {
+      buffer.extend_from_copyable_slice(b"\r\n")?;
+    }
+    buffer.write_fmt(format_args!(
+      "--{}\r\nContent-Type: {}\r\nContent-Range: bytes {first}-{last}/{len}\r\n\r\n",
+      boundary.as_str(),
+      mime.as_str()
+    ))?;
+    if !send_data(&buffer, false, stream).await?
+      || !send_file_range(&mut chunk, &mut file, (first, last), false, stream).await?
+    {
+      return Ok(());
+    }
+  }
+  buffer.clear();
+  buffer.write_fmt(format_args!("\r\n--{}--\r\n", boundary.as_str()))?;
+  let _ = send_data(&buffer, true, stream).await?;
+  Ok(())
+}
+
+const fn extension(coding: ContentCoding) -> &'static str {
+  match coding {
+    ContentCoding::Br => ".br",
+    _ => ".gz",
+  }
+}
+
+async fn file_metadata(path: &Path) -> crate::Result<Option<std::fs::Metadata>> {
+  match tokio::fs::metadata(path).await {
+    Ok(elem) => Ok(elem.is_file().then_some(elem)),
+    Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+    Err(err) => Err(err.into()),
+  }
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9110#section-13.2.2
+fn is_not_modified(headers: &Headers, etag: &str, secs: Option<u64>) -> bool {
+  let [if_none_match, if_modified_since] = headers
+    .get_by_names([KnownHeaderName::IfNoneMatch.into(), KnownHeaderName::IfModifiedSince.into()]);
+  if let Some(elem) = if_none_match {
+    return if_none_match_matches(elem.value, etag);
+  }
+  match (if_modified_since.and_then(|el| parse_http_date(el.value)), secs) {
+    (Some(since), Some(modified)) => modified <= since,
+    _ => false,
+  }
+}
+
+async fn precompressed_variant(
+  headers: &Headers,
+  path: &Path,
+  precompressed: bool,
+) -> crate::Result<Option<(ContentCoding, std::fs::Metadata)>> {
+  if !precompressed {
+    return Ok(None);
+  }
+  let Some(accept_encoding) = headers.get_by_name(KnownHeaderName::AcceptEncoding.into()) else {
+    return Ok(None);
+  };
+  for coding in [ContentCoding::Br, ContentCoding::Gzip] {
+    if ContentCoding::negotiate(accept_encoding.value, &[coding]) != Some(coding) {
+      continue;
+    }
+    if let Some(elem) = file_metadata(Path::new(&sibling(path, coding))).await? {
+      return Ok(Some((coding, elem)));
+    }
+  }
+  Ok(None)
+}
+
+fn push_content_headers(headers: &mut Headers, len: u64, mime: &str) -> crate::Result<()> {
+  headers.push_from_fmt(Header::from_name_and_value(
+    KnownHeaderName::ContentLength.into(),
+    format_args!("{len}"),
+  ))?;
+  headers
+    .push_from_iter(Header::from_name_and_value(KnownHeaderName::ContentType.into(), [mime]))?;
+  Ok(())
+}
+
+// Returns `false` if the stream was closed.
+async fn send_data<HB, HD, SW>(
+  bytes: &[u8],
+  is_eos: bool,
+  stream: &mut ServerStream<HD>,
+) -> crate::Result<bool>
+where
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  let hss = stream.common().send_data(SendDataMode::scattered_data_frames(bytes), is_eos).await?;
+  Ok(!hss.is_closed())
+}
+
+// Sends the inclusive `range` of `file` using `chunk` as an intermediary buffer. Returns `false`
+// if the stream was closed.
+async fn send_file_range<HB, HD, SW>(
+  chunk: &mut [u8],
+  file: &mut File,
+  (first, last): (u64, u64),
+  is_eos: bool,
+  stream: &mut ServerStream<HD>,
+) -> crate::Result<bool>
+where
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  let _ = file.seek(SeekFrom::Start(first)).await?;
+  let mut remaining = last.wrapping_sub(first).wrapping_add(1);
+  while remaining > 0 {
+    let len = usize::try_from(remaining).unwrap_or(usize::MAX).min(chunk.len());
+    let bytes = chunk.get_mut(..len).unwrap_or_default();
+    let _ = file.read_exact(bytes).await?;
+    remaining = remaining.wrapping_sub(u64::try_from(len)?);
+    if !send_data(bytes, is_eos && remaining == 0, stream).await? {
+      return Ok(false);
+    }
+  }
+  Ok(true)
+}
+
+// Returns `false` if the stream was closed.
+async fn send_headers<HB, HD, SW>(
+  stream: &mut ServerStream<HD>,
+  headers: &Headers,
+  is_eos: bool,
+  status_code: StatusCode,
+) -> crate::Result<bool>
+where
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+{
+  Ok(!stream.common().send_headers(headers, is_eos, status_code).await?.is_closed())
+}
+
+// Path of the precompressed variant of `path`.
+fn sibling(path: &Path, coding: ContentCoding) -> OsString {
+  let mut rslt = OsString::from(path.as_os_str());
+  rslt.push(extension(coding));
+  rslt
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    http::{
+      Header, KnownHeaderName, Method, ReqResBuffer, Request, StatusCode,
+      server_framework::serve_file::serve_file,
+    },
+    http2::{Http2Buffer, Http2ErrorCode, Http2Params, Http2RecvStatus, Http2Tokio},
+    misc::{Either, UriRef, Xorshift64, simple_seed},
+    tests::_uri,
+  };
+  use alloc::vec::Vec;
+  use core::{mem, time::Duration};
+  use tokio::net::{TcpListener, TcpStream, tcp::OwnedWriteHalf};
+
+  #[tokio::test]
+  async fn streams_files_in_chunks() {
+    let data: Vec<u8> = (0..100_000u32).map(|el| u8::try_from(el % 251).unwrap()).collect();
+    let path = std::env::temp_dir().join("wtx-serve-file.bin");
+    std::fs::write(&path, &data).unwrap();
+    let uri = _uri();
+    let listener = TcpListener::bind(uri.hostname_with_implied_port()).await.unwrap();
+    let server_path = path.clone();
+    let _server_jh = tokio::spawn(async move {
+      let (tcp_stream, _) = listener.accept().await.unwrap();
+      let (frame_reader, mut http2) = Http2Tokio::accept(
+        Http2Buffer::new(&mut Xorshift64::from(simple_seed())),
+        Http2Params::default(),
+        tcp_stream.into_split(),
+      )
+      .await
+      .unwrap();
+      let _jh = tokio::spawn(frame_reader);
+      for _ in 0..3 {
+        let Either::Right((mut stream, rrb)) =
+          http2.stream(ReqResBuffer::empty(), |req, _| mem::take(req.rrd)).await.unwrap()
+        else {
+          panic!();
+        };
+        let mut req = Request::http2(stream.method(), rrb);
+        serve_file(&mut req, &mut stream, &server_path, false).await.unwrap();
+      }
+    });
+
+    let (frame_reader, mut http2) = Http2Tokio::connect(
+      Http2Buffer::new(&mut Xorshift64::from(simple_seed())),
+      Http2Params::default(),
+      TcpStream::connect(uri.hostname_with_implied_port()).await.unwrap().into_split(),
+    )
+    .await
+    .unwrap();
+    let _jh = tokio::spawn(frame_reader);
+    let uri_ref = uri.to_ref();
+
+    let (status_code, mut rrb) =
+      request(&mut http2, ReqResBuffer::empty(), Method::Get, &uri_ref).await;
+    assert_eq!(status_code, StatusCode::Ok);
+    assert_eq!(rrb.body.as_slice(), data.as_slice());
+    assert_eq!(
+      rrb.headers.get_by_name(KnownHeaderName::ContentLength.into()).unwrap().value,
+      "100000"
+    );
+
+    rrb.clear();
+    rrb
+      .headers
+      .push_from_iter(Header::from_name_and_value(
+        KnownHeaderName::Range.into(),
+        ["bytes=30000-79999"],
+      ))
+      .unwrap();
+    let (status_code, mut rrb) = request(&mut http2, rrb, Method::Get, &uri_ref).await;
+    assert_eq!(status_code, StatusCode::PartialContent);
+    assert_eq!(rrb.body.as_slice(), data.get(30_000..80_000).unwrap());
+    assert_eq!(
+      rrb.headers.get_by_name(KnownHeaderName::ContentRange.into()).unwrap().value,
+      "bytes 30000-79999/100000"
+    );
+
+    rrb.clear();
+    let (status_code, rrb) = request(&mut http2, rrb, Method::Head, &uri_ref).await;
+    assert_eq!(status_code, StatusCode::Ok);
+    assert!(rrb.body.is_empty());
+
+    http2.send_go_away(Http2ErrorCode::NoError).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    std::fs::remove_file(path).unwrap();
+  }
+
+  async fn request(
+    http2: &mut Http2Tokio<Http2Buffer, OwnedWriteHalf, true>,
+    rrb: ReqResBuffer,
+    method: Method,
+    uri: &UriRef<'_>,
+  ) -> (StatusCode, ReqResBuffer) {
+    let mut stream = http2.stream().await.unwrap();
+    let _ = stream.send_req(rrb.as_http2_request(method), uri).await.unwrap();
+    let (Http2RecvStatus::Eos(status_code), rrb) = stream.recv_res(rrb).await.unwrap() else {
+      panic!();
+    };
+    (status_code, rrb)
+  }
+}
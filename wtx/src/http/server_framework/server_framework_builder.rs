@@ -1,5 +1,6 @@
 use crate::{
   http::{
+    ConnLimits,
     conn_params::ConnParams,
     server_framework::{ConnAux, Router, ServerFramework, StreamAux},
   },
@@ -10,6 +11,7 @@ use crate::{
 #[derive(Debug)]
 pub struct ServerFrameworkBuilder<CA, CBP, E, EN, M, S, SA> {
   cbp: CBP,
+  cl: ConnLimits,
   cp: ConnParams,
   router: Arc<Router<CA, E, EN, M, S, SA>>,
 }
@@ -22,7 +24,15 @@ where
   /// New instance with default connection values.
   #[inline]
   pub fn new(cbp: CBP, router: Router<CA, E, EN, M, S, SA>) -> Self {
-    Self { cbp, cp: ConnParams::default(), router: Arc::new(router) }
+    Self { cbp, cl: ConnLimits::new(), cp: ConnParams::default(), router: Arc::new(router) }
+  }
+
+  /// Restrictions applied to incoming connections.
+  #[inline]
+  #[must_use]
+  pub fn conn_limits(mut self, elem: ConnLimits) -> Self {
+    self.cl = elem;
+    self
   }
 
   /// Maximum number of active concurrent streams
@@ -47,6 +57,7 @@ where
     ServerFramework {
       _ca_cb: ca_cb,
       _cbp: self.cbp,
+      _cl: self.cl,
       _cp: self.cp,
       _sa_cb: ra_cb,
       _router: self.router,
@@ -77,6 +88,7 @@ where
     ServerFramework {
       _ca_cb: dflt_conn,
       _cbp: self.cbp,
+      _cl: self.cl,
       _cp: self.cp,
       _sa_cb: dflt_stream,
       _router: self.router,
@@ -95,6 +107,7 @@ impl<CBP, E, EN, M, S> ServerFrameworkBuilder<(), CBP, E, EN, M, S, ()> {
     ServerFramework {
       _ca_cb: nothing_conn,
       _cbp: self.cbp,
+      _cl: self.cl,
       _cp: self.cp,
       _sa_cb: nothing_stream,
       _router: self.router,
@@ -118,6 +131,7 @@ where
     ServerFramework {
       _ca_cb: ca_cb,
       _cbp: self.cbp,
+      _cl: self.cl,
       _cp: self.cp,
       _sa_cb: nothing_stream,
       _router: self.router,
@@ -141,6 +155,7 @@ where
     ServerFramework {
       _ca_cb: nothing_conn,
       _cbp: self.cbp,
+      _cl: self.cl,
       _cp: self.cp,
       _sa_cb: ra_cb,
       _router: self.router,
@@ -1,12 +1,16 @@
 /// Server Framework Error
 #[derive(Debug)]
 pub enum ServerFrameworkError {
+  /// A path was registered more than once
+  DuplicatedPath,
   /// Client requested a CORS header that isn't allowed
   ForbiddenCorsHeader,
   /// Client requested a CORS method that isn't allowed
   ForbiddenCorsMethod,
   /// Client requested a CORS origin that isn't allowed
   ForbiddenCorsOrigin,
+  /// Paths must start with a slash and placeholders must occupy entire segments
+  InvalidPathPattern,
   /// Client sent a request with invalid WebSocket tunneling parameters
   InvalidWebSocketParameters,
   /// Entered in a route that has an incompatible operation mode
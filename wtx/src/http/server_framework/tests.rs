@@ -5,6 +5,7 @@ use crate::http::{
     endpoint::Endpoint, get,
   },
 };
+use alloc::vec::Vec;
 use core::{
   net::{IpAddr, Ipv4Addr},
   ops::ControlFlow,
@@ -36,6 +37,67 @@ fn compiles() {
   let _sf = ServerFrameworkBuilder::new((), router).without_aux();
 }
 
+#[tokio::test]
+async fn middlewares_are_executed_like_an_onion() {
+  struct Recorder(u8);
+
+  impl Middleware<Vec<(u8, bool)>, crate::Error, ()> for Recorder {
+    type Aux = ();
+
+    fn aux(&self) -> Self::Aux {
+      ()
+    }
+
+    async fn req(
+      &self,
+      conn_aux: &mut Vec<(u8, bool)>,
+      _: &mut Self::Aux,
+      _: IpAddr,
+      _: &mut Request<ReqResBuffer>,
+      _: &mut (),
+    ) -> crate::Result<ControlFlow<StatusCode, ()>> {
+      conn_aux.push((self.0, true));
+      if self.0 == 2 {
+        return Ok(ControlFlow::Break(StatusCode::Unauthorized));
+      }
+      Ok(ControlFlow::Continue(()))
+    }
+
+    async fn res(
+      &self,
+      conn_aux: &mut Vec<(u8, bool)>,
+      _: &mut Self::Aux,
+      _: Response<&mut ReqResBuffer>,
+      _: &mut (),
+    ) -> crate::Result<ControlFlow<StatusCode, ()>> {
+      conn_aux.push((self.0, false));
+      Ok(ControlFlow::Continue(()))
+    }
+  }
+
+  let mut record = Vec::new();
+  let mut req = Request::http2(Method::Get, ReqResBuffer::default());
+
+  let mws = (Recorder(0), Recorder(1));
+  let mut aux = mws.aux();
+  let rslt =
+    mws.req(&mut record, &mut aux, Ipv4Addr::LOCALHOST.into(), &mut req, &mut ()).await.unwrap();
+  assert_eq!(rslt, ControlFlow::Continue(()));
+  let version = req.version;
+  let res = Response { rrd: &mut req.rrd, status_code: StatusCode::Ok, version };
+  let rslt = mws.res(&mut record, &mut aux, res, &mut ()).await.unwrap();
+  assert_eq!(rslt, ControlFlow::Continue(()));
+  assert_eq!(record, [(0, true), (1, true), (1, false), (0, false)]);
+
+  record.clear();
+  let mws = (Recorder(0), Recorder(1), Recorder(2), Recorder(3));
+  let mut aux = mws.aux();
+  let rslt =
+    mws.req(&mut record, &mut aux, Ipv4Addr::LOCALHOST.into(), &mut req, &mut ()).await.unwrap();
+  assert_eq!(rslt, ControlFlow::Break(StatusCode::Unauthorized));
+  assert_eq!(record, [(0, true), (1, true), (2, true), (1, false), (0, false)]);
+}
+
 // /aaa ->   /bbb ->  /ccc
 //      \         \
 //       \         -> /ddd
@@ -75,6 +137,7 @@ async fn nested_middlewares() {
       &self,
       conn_aux: &mut Counter,
       _: &mut Self::Aux,
+      _: IpAddr,
       _: &mut Request<ReqResBuffer>,
       stream_aux: &mut Counter,
     ) -> crate::Result<ControlFlow<StatusCode, ()>> {
@@ -0,0 +1,227 @@
+use crate::{
+  http::{
+    AutoStream, ManualStream, OperationMode, StatusCode,
+    server_framework::{Endpoint, EndpointNode, RouteMatch},
+  },
+  http2::{Http2Buffer, Http2Data, ServerStream},
+  misc::{ArrayVector, LeaseMut, Lock, RefCounter, StreamWriter, Vector, within},
+};
+use core::time::Duration;
+
+/// Drops the inner endpoint, router or set of paths if the processing of a request isn't finished
+/// within the specified duration.
+///
+/// Streams are answered with `504 Gateway Timeout` unless manual endpoints already sent the
+/// response headers, in which case they are reset with the `CANCEL` error code.
+#[derive(Debug)]
+pub struct Timeout<T> {
+  duration: Duration,
+  en: T,
+}
+
+impl<T> Timeout<T> {
+  /// New instance
+  #[inline]
+  pub const fn new(duration: Duration, en: T) -> Self {
+    Self { duration, en }
+  }
+}
+
+impl<CA, E, HB, HD, SA, SW, T> Endpoint<CA, E, ServerStream<HD>, SA> for Timeout<T>
+where
+  E: From<crate::Error>,
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+  T: Endpoint<CA, E, ServerStream<HD>, SA>,
+{
+  const OM: OperationMode = T::OM;
+
+  #[inline]
+  async fn auto(
+    &self,
+    auto_stream: &mut AutoStream<CA, SA>,
+    path_defs: (u8, &[RouteMatch]),
+  ) -> Result<StatusCode, E> {
+    match within(self.duration, self.en.auto(auto_stream, path_defs)).await? {
+      Some(elem) => elem,
+      None => {
+        auto_stream.req.rrd.clear();
+        Ok(StatusCode::GatewayTimeout)
+      }
+    }
+  }
+
+  #[inline]
+  async fn manual(
+    &self,
+    manual_stream: ManualStream<CA, ServerStream<HD>, SA>,
+    path_defs: (u8, &[RouteMatch]),
+  ) -> Result<(), E> {
+    let mut stream = manual_stream.stream.clone();
+    match within(self.duration, self.en.manual(manual_stream, path_defs)).await? {
+      Some(elem) => elem,
+      None => {
+        stream.send_gateway_timeout().await?;
+        Ok(())
+      }
+    }
+  }
+}
+
+impl<CA, E, HB, HD, SA, SW, T> EndpointNode<CA, E, ServerStream<HD>, SA> for Timeout<T>
+where
+  E: From<crate::Error>,
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  SW: StreamWriter,
+  T: EndpointNode<CA, E, ServerStream<HD>, SA>,
+{
+  const IS_ROUTER: bool = T::IS_ROUTER;
+
+  #[inline]
+  fn paths_indices(
+    &self,
+    prev: ArrayVector<RouteMatch, 4>,
+    vec: &mut Vector<ArrayVector<RouteMatch, 4>>,
+  ) -> crate::Result<()> {
+    self.en.paths_indices(prev, vec)
+  }
+}
+
+#[cfg(all(feature = "_async-tests", test))]
+mod tests {
+  use crate::{
+    http::{
+      AutoStream, Headers, ManualStream, Method, ReqResBuffer, Request, StatusCode,
+      server_framework::{Endpoint, StateClean, Timeout, get},
+    },
+    http2::{
+      Http2Buffer, Http2DataTokio, Http2ErrorCode, Http2Params, Http2RecvStatus, Http2Tokio,
+      ServerStream,
+    },
+    misc::{Either, UriRef, Xorshift64, simple_seed, sleep},
+    tests::_uri,
+  };
+  use core::{
+    mem,
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+  };
+  use tokio::net::{TcpListener, TcpStream, tcp::OwnedWriteHalf};
+
+  type Stream = ServerStream<Http2DataTokio<Http2Buffer, OwnedWriteHalf, false>>;
+
+  #[tokio::test]
+  async fn slow_handlers_receive_gateway_timeout() {
+    let mut auto_stream = AutoStream {
+      conn_aux: (),
+      peer: IpAddr::V4(Ipv4Addr::LOCALHOST),
+      protocol: None,
+      req: Request::http2(Method::Get, ReqResBuffer::default()),
+      stream_aux: (),
+    };
+    let fast = Timeout::new(Duration::from_millis(500), get(fast));
+    let status_code =
+      Endpoint::<(), crate::Error, Stream, ()>::auto(&fast, &mut auto_stream, (0, &[]))
+        .await
+        .unwrap();
+    assert_eq!(status_code, StatusCode::Ok);
+    let slow = Timeout::new(Duration::from_millis(10), get(slow));
+    let status_code =
+      Endpoint::<(), crate::Error, Stream, ()>::auto(&slow, &mut auto_stream, (0, &[]))
+        .await
+        .unwrap();
+    assert_eq!(status_code, StatusCode::GatewayTimeout);
+  }
+
+  #[tokio::test]
+  async fn slow_manual_handlers_are_answered_or_reset() {
+    let uri = _uri();
+    let listener = TcpListener::bind(uri.hostname_with_implied_port()).await.unwrap();
+    let _server_jh = tokio::spawn(async move {
+      let (tcp_stream, _) = listener.accept().await.unwrap();
+      let (frame_reader, mut http2) = Http2Tokio::accept(
+        Http2Buffer::new(&mut Xorshift64::from(simple_seed())),
+        Http2Params::default(),
+        tcp_stream.into_split(),
+      )
+      .await
+      .unwrap();
+      let _jh = tokio::spawn(frame_reader);
+      let timeout = Timeout::new(Duration::from_millis(10), get(manual));
+      for _ in 0..3 {
+        let Either::Right((stream, rrb)) =
+          http2.stream(ReqResBuffer::empty(), |req, _| mem::take(req.rrd)).await.unwrap()
+        else {
+          panic!();
+        };
+        let manual_stream = ManualStream {
+          conn_aux: (),
+          peer: IpAddr::V4(Ipv4Addr::LOCALHOST),
+          protocol: None,
+          req: Request::http2(stream.method(), rrb),
+          stream,
+          stream_aux: (),
+        };
+        Endpoint::<(), crate::Error, Stream, ()>::manual(&timeout, manual_stream, (0, &[]))
+          .await
+          .unwrap();
+      }
+    });
+
+    let (frame_reader, mut http2) = Http2Tokio::connect(
+      Http2Buffer::new(&mut Xorshift64::from(simple_seed())),
+      Http2Params::default(),
+      TcpStream::connect(uri.hostname_with_implied_port()).await.unwrap().into_split(),
+    )
+    .await
+    .unwrap();
+    let _jh = tokio::spawn(frame_reader);
+    for (path, status_code) in [
+      ("/received", Some(StatusCode::GatewayTimeout)),
+      ("/not-received", Some(StatusCode::GatewayTimeout)),
+      ("/headers", None),
+    ] {
+      let local_uri = alloc::format!("{}{path}", uri.as_str());
+      let mut stream = http2.stream().await.unwrap();
+      let rrb = ReqResBuffer::empty();
+      let _ = stream.send_req(rrb.as_http2_request(Method::Get), &UriRef::new(&local_uri)).await;
+      let rslt = stream.recv_res(rrb).await.unwrap();
+      match status_code {
+        Some(elem) => assert!(matches!(rslt.0, Http2RecvStatus::Eos(local) if local == elem)),
+        None => assert!(matches!(rslt.0, Http2RecvStatus::ClosedStream)),
+      }
+    }
+    http2.send_go_away(Http2ErrorCode::NoError).await;
+  }
+
+  async fn fast(_: StateClean<'_, (), (), ReqResBuffer>) -> crate::Result<StatusCode> {
+    Ok(StatusCode::Ok)
+  }
+
+  async fn manual(mut manual_stream: ManualStream<(), Stream, ()>) -> crate::Result<()> {
+    match manual_stream.req.rrd.uri.path() {
+      "/headers" => {
+        let _ = manual_stream
+          .stream
+          .common()
+          .send_headers(&Headers::new(), false, StatusCode::Ok)
+          .await?;
+      }
+      "/received" => {
+        let _ = manual_stream.stream.recv_req().await?;
+      }
+      _ => {}
+    }
+    sleep(Duration::from_secs(5)).await?;
+    Ok(())
+  }
+
+  async fn slow(_: StateClean<'_, (), (), ReqResBuffer>) -> crate::Result<StatusCode> {
+    sleep(Duration::from_secs(5)).await?;
+    Ok(StatusCode::Ok)
+  }
+}
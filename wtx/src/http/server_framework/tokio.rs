@@ -9,6 +9,7 @@ use crate::{
   misc::{ArrayVector, SeedableRng},
   sync::Arc,
 };
+use core::time::Duration;
 use tokio::net::tcp::OwnedWriteHalf;
 
 type Stream = ServerStream<Http2DataTokio<Http2Buffer, OwnedWriteHalf, false>>;
@@ -46,9 +47,9 @@ where
     headers_cb: impl Clone + Fn(Request<&mut ReqResBuffer>) -> Result<(), E> + Send + Sync + 'static,
     stream_error_cb: impl Clone + Fn(E) + Send + 'static,
   ) -> crate::Result<()> {
-    let Self { _ca_cb, _cbp, _cp, _sa_cb, _router } = self;
+    let Self { _ca_cb, _cbp, _cl, _cp, _sa_cb, _router } = self;
     OptionedServer::http2_tokio(
-      ((), host, _cbp, _router),
+      ((), host, _cl, (core::future::pending(), Duration::ZERO), _cbp, _router),
       |local_rng| {
         *local_rng = CBP::from_rng(local_rng);
       },
@@ -61,8 +62,9 @@ where
       move |ca| Ok((SA::stream_aux(_sa_cb(ca))?, ReqResBuffer::empty())),
       move |_, local_router, _, req, _| {
         let rslt = Self::_route_params(req.rrd.uri.path(), local_router)?;
+        let max_body_len = rslt.0.iter().rev().find_map(|el| el.max_body_len);
         headers_cb(req)?;
-        Ok(((rslt.0, Arc::clone(local_router)), rslt.1))
+        Ok(((rslt.0, Arc::clone(local_router)), max_body_len, rslt.1))
       },
       stream_error_cb,
       Self::_auto,
@@ -109,12 +111,12 @@ where
     headers_cb: impl Clone + Fn(Request<&mut ReqResBuffer>) -> Result<(), E> + Send + Sync + 'static,
     stream_error_cb: impl Clone + Fn(E) + Send + 'static,
   ) -> crate::Result<()> {
-    let Self { _ca_cb, _cbp, _cp, _sa_cb, _router } = self;
+    let Self { _ca_cb, _cbp, _cl, _cp, _sa_cb, _router } = self;
     let tls_acceptor = crate::misc::TokioRustlsAcceptor::without_client_auth()
       .http2()
       .build_with_cert_chain_and_priv_key(cert_chain, priv_key)?;
     OptionedServer::http2_tokio(
-      (tls_acceptor, host, _cbp, _router),
+      (tls_acceptor, host, _cl, (core::future::pending(), Duration::ZERO), _cbp, _router),
       |local_rng| {
         *local_rng = CBP::from_rng(local_rng);
       },
@@ -127,8 +129,9 @@ where
       move |ca| Ok((SA::stream_aux(_sa_cb(ca))?, ReqResBuffer::empty())),
       move |_, local_router, _, req, _| {
         let rslt = Self::_route_params(req.rrd.uri.path(), local_router)?;
+        let max_body_len = rslt.0.iter().rev().find_map(|el| el.max_body_len);
         headers_cb(req)?;
-        Ok(((rslt.0, Arc::clone(local_router)), rslt.1))
+        Ok(((rslt.0, Arc::clone(local_router)), max_body_len, rslt.1))
       },
       stream_error_cb,
       Self::_auto,
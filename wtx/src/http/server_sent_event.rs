@@ -0,0 +1,110 @@
+use crate::{
+  http::HttpError,
+  misc::{Lease, Vector},
+};
+use core::{fmt::Write, time::Duration};
+
+/// A single message of a `text/event-stream` body.
+///
+/// <https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServerSentEvent<D> {
+  data: D,
+  event: Option<D>,
+  id: Option<D>,
+  retry: Option<Duration>,
+}
+
+impl<D> ServerSentEvent<D> {
+  /// New instance with the contents of the `data` field. Line breaks are sent as multiple `data`
+  /// fields, which are joined back by clients.
+  #[inline]
+  pub const fn new(data: D) -> Self {
+    Self { data, event: None, id: None, retry: None }
+  }
+
+  /// Type of the event, which allows clients to register specific listeners.
+  #[inline]
+  #[must_use]
+  pub fn event(mut self, elem: D) -> Self {
+    self.event = Some(elem);
+    self
+  }
+
+  /// Identifier sent back by clients in the `Last-Event-ID` header when reconnecting.
+  #[inline]
+  #[must_use]
+  pub fn id(mut self, elem: D) -> Self {
+    self.id = Some(elem);
+    self
+  }
+
+  /// Reconnection time that clients should wait after the connection is lost.
+  #[inline]
+  #[must_use]
+  pub fn retry(mut self, elem: Duration) -> Self {
+    self.retry = Some(elem);
+    self
+  }
+}
+
+impl<D> ServerSentEvent<D>
+where
+  D: Lease<str>,
+{
+  /// Appends the textual representation of this event into `buffer`.
+  #[inline]
+  pub fn write(&self, buffer: &mut Vector<u8>) -> crate::Result<()> {
+    if let Some(elem) = &self.event {
+      write_field(buffer, "event", elem.lease())?;
+    }
+    if let Some(elem) = &self.id {
+      write_field(buffer, "id", elem.lease())?;
+    }
+    if let Some(elem) = self.retry {
+      buffer.write_fmt(format_args!("retry: {}\n", elem.as_millis()))?;
+    }
+    let mut rest = self.data.lease();
+    loop {
+      let Some(idx) = rest.find(['\r', '\n']) else {
+        let _ = buffer.extend_from_copyable_slices([b"data: ", rest.as_bytes(), b"\n\n"])?;
+        return Ok(());
+      };
+      let (line, after) =
+        (rest.get(..idx).unwrap_or_default(), rest.get(idx..).unwrap_or_default());
+      let _ = buffer.extend_from_copyable_slices([b"data: ", line.as_bytes(), b"\n"])?;
+      rest = after.strip_prefix("\r\n").or_else(|| after.get(1..)).unwrap_or_default();
+    }
+  }
+}
+
+fn write_field(buffer: &mut Vector<u8>, name: &str, value: &str) -> crate::Result<()> {
+  if value.contains(['\0', '\r', '\n']) {
+    return Err(HttpError::InvalidServerSentEventField.into());
+  }
+  let _ = buffer.extend_from_copyable_slices([name.as_bytes(), b": ", value.as_bytes(), b"\n"])?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{http::ServerSentEvent, misc::Vector};
+  use core::time::Duration;
+
+  #[test]
+  fn write() {
+    let mut buffer = Vector::new();
+    ServerSentEvent::new("foo").write(&mut buffer).unwrap();
+    ServerSentEvent::new("a\nb\r\nc\rd")
+      .event("update")
+      .id("1")
+      .retry(Duration::from_secs(3))
+      .write(&mut buffer)
+      .unwrap();
+    assert_eq!(
+      buffer.as_slice(),
+      b"data: foo\n\nevent: update\nid: 1\nretry: 3000\ndata: a\ndata: b\ndata: c\ndata: d\n\n"
+    );
+    assert!(ServerSentEvent::new("").event("a\nb").write(&mut buffer).is_err());
+  }
+}
@@ -4,6 +4,7 @@ mod session_manager_builder;
 mod session_middleware;
 mod session_state;
 mod session_store;
+mod session_store_memory;
 
 pub use session_error::SessionError;
 pub use session_manager::*;
@@ -11,6 +12,7 @@ pub use session_manager_builder::SessionManagerBuilder;
 pub use session_middleware::SessionMiddleware;
 pub use session_state::SessionState;
 pub use session_store::SessionStore;
+pub use session_store_memory::*;
 
 type SessionCsrf = crate::misc::ArrayString<32>;
 type SessionKey = crate::misc::ArrayString<32>;
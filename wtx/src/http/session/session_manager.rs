@@ -60,6 +60,35 @@ where
     Ok(())
   }
 
+  /// Replaces the current session, if any, with a new one that has a different key and a
+  /// different CSRF token. Should be called whenever privileges change, e.g., after a login, to
+  /// prevent session fixation attacks.
+  ///
+  /// The `rrd` body is used as a temporary buffer but no existing content is erased.
+  #[inline]
+  pub async fn rotate_session_cookie<RNG, RRD, S>(
+    &mut self,
+    custom_state: CS,
+    rng: RNG,
+    rrd: &mut RRD,
+    state: &mut Option<SessionState<CS>>,
+    store: &mut S,
+  ) -> Result<(), E>
+  where
+    CS: Serialize,
+    RNG: Rng,
+    RRD: LeaseMut<ReqResBuffer>,
+    S: SessionStore<CS, E>,
+  {
+    if let Some(elem) = state.take() {
+      if elem.expires_at.is_some() {
+        store.delete(&elem.session_key).await?;
+      }
+    }
+    *state = Some(self.create_session(custom_state, rng, rrd, store).await?);
+    Ok(())
+  }
+
   /// Saves the session in the store and also modifies headers.
   ///
   /// The `rrd` body is used as a temporary buffer but no existing content is erased.
@@ -67,10 +96,46 @@ where
   pub async fn set_session_cookie<RNG, RRD, S>(
     &mut self,
     custom_state: CS,
-    mut rng: RNG,
+    rng: RNG,
     rrd: &mut RRD,
     store: &mut S,
   ) -> Result<(), E>
+  where
+    CS: Serialize,
+    RNG: Rng,
+    RRD: LeaseMut<ReqResBuffer>,
+    S: SessionStore<CS, E>,
+  {
+    let _ = self.create_session(custom_state, rng, rrd, store).await?;
+    Ok(())
+  }
+
+  #[inline]
+  pub(crate) fn clear_cookie(
+    cookie_def: &mut CookieGeneric<&'static str, Vector<u8>>,
+    headers: &mut Headers,
+  ) -> crate::Result<()> {
+    let prev_expires = cookie_def.expires;
+    let prev_max_age = cookie_def.max_age;
+    cookie_def.expires = Some(DateTime::from_timestamp_nanos(0));
+    cookie_def.max_age = None;
+    cookie_def.value.clear();
+    let rslt = headers.push_from_fmt(Header::from_name_and_value(
+      KnownHeaderName::SetCookie.into(),
+      format_args!("{}", cookie_def.map_mut(move |el| el, |_| "")),
+    ));
+    cookie_def.expires = prev_expires;
+    cookie_def.max_age = prev_max_age;
+    rslt
+  }
+
+  async fn create_session<RNG, RRD, S>(
+    &mut self,
+    custom_state: CS,
+    mut rng: RNG,
+    rrd: &mut RRD,
+    store: &mut S,
+  ) -> Result<SessionState<CS>, E>
   where
     CS: Serialize,
     RNG: Rng,
@@ -130,26 +195,7 @@ where
       KnownHeaderName::XCsrfToken.into(),
       [local_state.session_csrf.as_str()],
     ))?;
-    Ok(())
-  }
-
-  #[inline]
-  pub(crate) fn clear_cookie(
-    cookie_def: &mut CookieGeneric<&'static str, Vector<u8>>,
-    headers: &mut Headers,
-  ) -> crate::Result<()> {
-    let prev_expires = cookie_def.expires;
-    let prev_max_age = cookie_def.max_age;
-    cookie_def.expires = Some(DateTime::from_timestamp_nanos(0));
-    cookie_def.max_age = None;
-    cookie_def.value.clear();
-    let rslt = headers.push_from_fmt(Header::from_name_and_value(
-      KnownHeaderName::SetCookie.into(),
-      format_args!("{}", cookie_def.map_mut(move |el| el, |_| "")),
-    ));
-    cookie_def.expires = prev_expires;
-    cookie_def.max_age = prev_max_age;
-    rslt
+    Ok(local_state)
   }
 }
 
@@ -1,6 +1,6 @@
 use crate::{
   http::{
-    KnownHeaderName, Method, ReqResBuffer, Request, Response, SessionError, SessionManager,
+    Header, KnownHeaderName, Method, ReqResBuffer, Request, Response, SessionError, SessionManager,
     SessionManagerInner, SessionState, SessionStore, StatusCode,
     cookie::{cookie_str::CookieStr, decrypt},
     server_framework::Middleware,
@@ -10,13 +10,18 @@ use crate::{
 };
 use alloc::string::String;
 use chrono::DateTime;
-use core::ops::ControlFlow;
+use core::{net::IpAddr, ops::ControlFlow};
 use serde::de::DeserializeOwned;
 
 /// Decodes cookies received from requests and manages them.
+///
+/// Requests that mutate resources (`DELETE`, `PATCH`, `POST` or `PUT`) are protected against
+/// cross-site request forgery through the synchronizer token strategy: the `X-CSRF-Token` header
+/// must contain the token of the session, which is sent back in every response.
 #[derive(Debug)]
 pub struct SessionMiddleware<SMI, SS> {
   allowed_paths: Vector<String>,
+  csrf_exempt_paths: Vector<String>,
   session_manager: SessionManager<SMI>,
   session_store: SS,
 }
@@ -29,7 +34,16 @@ impl<SMI, SS> SessionMiddleware<SMI, SS> {
     session_manager: SessionManager<SMI>,
     session_store: SS,
   ) -> Self {
-    Self { allowed_paths, session_manager, session_store }
+    Self { allowed_paths, csrf_exempt_paths: Vector::new(), session_manager, session_store }
+  }
+
+  /// Paths whose mutable requests don't need the `X-CSRF-Token` header, e.g., webhooks
+  /// authenticated by other means.
+  #[inline]
+  #[must_use]
+  pub fn csrf_exempt_paths(mut self, elem: Vector<String>) -> Self {
+    self.csrf_exempt_paths = elem;
+    self
   }
 }
 
@@ -54,6 +68,7 @@ where
     &self,
     ca: &mut CA,
     _: &mut Self::Aux,
+    _: IpAddr,
     req: &mut Request<ReqResBuffer>,
     _: &mut SA,
   ) -> Result<ControlFlow<StatusCode, ()>, E> {
@@ -112,6 +127,8 @@ where
         || req.method == Method::Patch
         || req.method == Method::Post
         || req.method == Method::Put;
+      let path = req.rrd.uri.path();
+      let is_mutable = is_mutable && self.csrf_exempt_paths.iter().all(|el| el != path);
       let session_csrf_opt = Some(ss_des.session_csrf.as_ref());
       if is_mutable && session_csrf_opt != x_csrf_token_value.map(|el| el.as_bytes()) {
         let session_key = &ss_des.session_key;
@@ -134,11 +151,22 @@ where
   #[inline]
   async fn res(
     &self,
-    _: &mut CA,
+    ca: &mut CA,
     _: &mut Self::Aux,
-    _: Response<&mut ReqResBuffer>,
+    res: Response<&mut ReqResBuffer>,
     _: &mut SA,
   ) -> Result<ControlFlow<StatusCode, ()>, E> {
+    let Some(state) = ca.lease() else {
+      return Ok(ControlFlow::Continue(()));
+    };
+    let headers = &mut res.rrd.headers;
+    // `SessionManager` already sends the token of new sessions.
+    if headers.get_by_name(KnownHeaderName::XCsrfToken.into()).is_none() {
+      headers.push_from_iter(Header::from_name_and_value(
+        KnownHeaderName::XCsrfToken.into(),
+        [state.session_csrf.as_str()],
+      ))?;
+    }
     Ok(ControlFlow::Continue(()))
   }
 }
@@ -140,7 +140,7 @@ mod pool {
 mod postgres {
   use crate::{
     database::{
-      Executor as _, Record, Typed,
+      Executor as _, Record, Records as _, Typed,
       client::postgres::{ExecutorBuffer, Postgres, PostgresExecutor},
     },
     http::session::{SessionKey, SessionState, SessionStore},
@@ -193,12 +193,16 @@ mod postgres {
 
     #[inline]
     async fn read(&mut self, session_key: SessionKey) -> Result<Option<SessionState<CS>>, E> {
-      let rec = self
-        .fetch_with_stmt(
+      let recs = self
+        .fetch_many_with_stmt(
           "SELECT csrf, custom_state, expires_at FROM session WHERE key=$1",
           (&session_key,),
+          |_| Ok(()),
         )
         .await?;
+      let Some(rec) = recs.get(0) else {
+        return Ok(None);
+      };
       Ok(Some(SessionState {
         session_csrf: rec.decode::<_, &[u8]>(0)?.try_into()?,
         custom_state: rec.decode(1)?,
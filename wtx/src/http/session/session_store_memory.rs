@@ -0,0 +1,83 @@
+use crate::{
+  http::{SessionState, SessionStore, session::SessionKey},
+  misc::Lock,
+};
+use alloc::collections::BTreeMap;
+use chrono::Utc;
+
+/// [`SessionStoreMemory`] backed by `tokio`
+#[cfg(feature = "tokio")]
+pub type SessionStoreMemoryTokio<CS> =
+  SessionStoreMemory<crate::sync::Arc<tokio::sync::Mutex<BTreeMap<SessionKey, SessionState<CS>>>>>;
+
+/// Keeps sessions in memory, which means that everything is lost when the process ends.
+///
+/// Clones share the same sessions if `L` is a reference-counted lock like
+/// [`SessionStoreMemoryTokio`], which allows the usage with [`crate::http::SessionMiddleware`]
+/// through a [`crate::pool::SimplePool`] whose [`crate::pool::SimpleRM`] returns clones.
+#[derive(Clone, Debug)]
+pub struct SessionStoreMemory<L> {
+  sessions: L,
+}
+
+impl<CS, L> SessionStoreMemory<L>
+where
+  L: Lock<Resource = BTreeMap<SessionKey, SessionState<CS>>>,
+{
+  /// Empty instance
+  #[inline]
+  pub fn new() -> Self {
+    Self { sessions: L::new(BTreeMap::new()) }
+  }
+}
+
+impl<CS, L> Default for SessionStoreMemory<L>
+where
+  L: Lock<Resource = BTreeMap<SessionKey, SessionState<CS>>>,
+{
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<CS, E, L> SessionStore<CS, E> for SessionStoreMemory<L>
+where
+  CS: Clone,
+  L: Lock<Resource = BTreeMap<SessionKey, SessionState<CS>>>,
+{
+  #[inline]
+  async fn create(&mut self, state: &SessionState<CS>) -> Result<(), E> {
+    let _ = self.sessions.lock().await.insert(state.session_key, state.clone());
+    Ok(())
+  }
+
+  #[inline]
+  async fn delete(&mut self, session_key: &SessionKey) -> Result<(), E> {
+    let _ = self.sessions.lock().await.remove(session_key);
+    Ok(())
+  }
+
+  #[inline]
+  async fn delete_expired(&mut self) -> Result<(), E> {
+    let now = Utc::now();
+    self.sessions.lock().await.retain(|_, el| el.expires_at.is_none_or(|expires| expires > now));
+    Ok(())
+  }
+
+  #[inline]
+  async fn read(&mut self, session_key: SessionKey) -> Result<Option<SessionState<CS>>, E> {
+    let now = Utc::now();
+    let sessions = self.sessions.lock().await;
+    let state = sessions.get(&session_key);
+    Ok(state.filter(|el| el.expires_at.is_none_or(|expires| expires > now)).cloned())
+  }
+
+  #[inline]
+  async fn update(&mut self, session_key: &SessionKey, state: &SessionState<CS>) -> Result<(), E> {
+    let mut sessions = self.sessions.lock().await;
+    let _ = sessions.remove(session_key);
+    let _ = sessions.insert(state.session_key, state.clone());
+    Ok(())
+  }
+}
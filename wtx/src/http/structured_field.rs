@@ -0,0 +1,667 @@
+use crate::{
+  http::{HttpError, is_tchar},
+  misc::Vector,
+};
+use alloc::string::String;
+use core::fmt::Write;
+
+const BASE64_ALPHABET: &[u8; 64] =
+  b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const MAX_DECIMAL_INTEGER: u64 = 999_999_999_999;
+const MAX_INTEGER: u64 = 999_999_999_999_999;
+
+/// Value of an item or of a parameter, without parameters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StructuredBareItem {
+  /// `?1` or `?0`
+  Boolean(bool),
+  /// Arbitrary bytes transmitted as base64 between colons.
+  ByteSequence(Vector<u8>),
+  /// Number with at most three fractional digits stored as thousandths, which means that `1.5`
+  /// is represented as `1500`.
+  Decimal(i64),
+  /// Number within the `-999_999_999_999_999..=999_999_999_999_999` interval.
+  Integer(i64),
+  /// Printable ASCII characters between double quotes.
+  String(String),
+  /// Short textual word like `gzip` or `text/html`.
+  Token(String),
+}
+
+/// Bare item with an ordered set of parameters. Along with [`StructuredList`] and
+/// [`StructuredDictionary`], represents the top-level types of structured fields.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc8941>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StructuredItem {
+  /// See [`StructuredBareItem`].
+  pub bare_item: StructuredBareItem,
+  /// Key-value pairs. Keys are unique.
+  pub params: Vector<(String, StructuredBareItem)>,
+}
+
+impl StructuredItem {
+  /// Item without parameters.
+  #[inline]
+  pub const fn new(bare_item: StructuredBareItem) -> Self {
+    Self { bare_item, params: Vector::new() }
+  }
+
+  /// Parses a field whose top-level type is an item, like `Content-Length: 42`.
+  #[inline]
+  pub fn parse(value: &str) -> crate::Result<Self> {
+    let mut parser = Parser::new(value);
+    let rslt = parser.item()?;
+    parser.finish()?;
+    Ok(rslt)
+  }
+
+  /// Value of the parameter identified by `key`.
+  #[inline]
+  pub fn param(&self, key: &str) -> Option<&StructuredBareItem> {
+    param(&self.params, key)
+  }
+
+  /// Writes the textual representation of this instance into `buffer`.
+  #[inline]
+  pub fn serialize(&self, buffer: &mut Vector<u8>) -> crate::Result<()> {
+    serialize_bare_item(&self.bare_item, buffer)?;
+    serialize_params(&self.params, buffer)
+  }
+}
+
+/// Parenthesized sequence of items with its own parameters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StructuredInnerList {
+  /// Items in the order they were received.
+  pub items: Vector<StructuredItem>,
+  /// Key-value pairs. Keys are unique.
+  pub params: Vector<(String, StructuredBareItem)>,
+}
+
+impl StructuredInnerList {
+  /// Value of the parameter identified by `key`.
+  #[inline]
+  pub fn param(&self, key: &str) -> Option<&StructuredBareItem> {
+    param(&self.params, key)
+  }
+}
+
+/// Member of a list or value of a dictionary.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StructuredMember {
+  /// See [`StructuredInnerList`].
+  InnerList(StructuredInnerList),
+  /// See [`StructuredItem`].
+  Item(StructuredItem),
+}
+
+/// Comma-separated sequence of members, like `Accept-Encoding: gzip, br;q=0.5`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StructuredList {
+  /// Members in the order they were received.
+  pub members: Vector<StructuredMember>,
+}
+
+impl StructuredList {
+  /// Parses a field whose top-level type is a list. Several lines of the same field should be
+  /// combined with commas before being parsed.
+  #[inline]
+  pub fn parse(value: &str) -> crate::Result<Self> {
+    let mut parser = Parser::new(value);
+    let mut members = Vector::new();
+    if !parser.bytes.is_empty() {
+      loop {
+        members.push(parser.member()?)?;
+        if parser.separator()? {
+          break;
+        }
+      }
+    }
+    parser.finish()?;
+    Ok(Self { members })
+  }
+
+  /// Writes the textual representation of this instance into `buffer`.
+  #[inline]
+  pub fn serialize(&self, buffer: &mut Vector<u8>) -> crate::Result<()> {
+    for (idx, member) in self.members.iter().enumerate() {
+      if idx > 0 {
+        buffer.extend_from_copyable_slice(b", ")?;
+      }
+      serialize_member(member, buffer)?;
+    }
+    Ok(())
+  }
+}
+
+/// Comma-separated sequence of key-value pairs, like `Priority: u=1, i`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StructuredDictionary {
+  /// Key-value pairs in the order they were received. Keys are unique.
+  pub members: Vector<(String, StructuredMember)>,
+}
+
+impl StructuredDictionary {
+  /// Parses a field whose top-level type is a dictionary. Several lines of the same field should
+  /// be combined with commas before being parsed.
+  ///
+  /// Repeated keys overwrite the value of their first occurrence.
+  #[inline]
+  pub fn parse(value: &str) -> crate::Result<Self> {
+    let mut parser = Parser::new(value);
+    let mut members = Vector::new();
+    if !parser.bytes.is_empty() {
+      loop {
+        let key = parser.key()?;
+        let member = if parser.peek() == Some(b'=') {
+          parser.advance();
+          parser.member()?
+        } else {
+          StructuredMember::Item(StructuredItem {
+            bare_item: StructuredBareItem::Boolean(true),
+            params: parser.params()?,
+          })
+        };
+        insert(&mut members, key, member)?;
+        if parser.separator()? {
+          break;
+        }
+      }
+    }
+    parser.finish()?;
+    Ok(Self { members })
+  }
+
+  /// Value of the member identified by `key`.
+  #[inline]
+  pub fn get(&self, key: &str) -> Option<&StructuredMember> {
+    self.members.iter().find_map(|(local_key, member)| (local_key == key).then_some(member))
+  }
+
+  /// Writes the textual representation of this instance into `buffer`.
+  #[inline]
+  pub fn serialize(&self, buffer: &mut Vector<u8>) -> crate::Result<()> {
+    for (idx, (key, member)) in self.members.iter().enumerate() {
+      if idx > 0 {
+        buffer.extend_from_copyable_slice(b", ")?;
+      }
+      serialize_key(key, buffer)?;
+      match member {
+        StructuredMember::Item(StructuredItem {
+          bare_item: StructuredBareItem::Boolean(true),
+          params,
+        }) => serialize_params(params, buffer)?,
+        _ => {
+          buffer.push(b'=')?;
+          serialize_member(member, buffer)?;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+struct Parser<'bytes> {
+  bytes: &'bytes [u8],
+}
+
+impl<'bytes> Parser<'bytes> {
+  fn new(value: &'bytes str) -> Self {
+    let mut this = Self { bytes: value.as_bytes() };
+    this.skip_sp();
+    this
+  }
+
+  fn advance(&mut self) {
+    self.bytes = self.bytes.get(1..).unwrap_or_default();
+  }
+
+  fn bare_item(&mut self) -> crate::Result<StructuredBareItem> {
+    match self.peek() {
+      Some(b'"') => self.string(),
+      Some(b':') => self.byte_sequence(),
+      Some(b'?') => self.boolean(),
+      Some(b'-' | b'0'..=b'9') => self.number(),
+      Some(b'*' | b'A'..=b'Z' | b'a'..=b'z') => self.token(),
+      _ => Err(invalid()),
+    }
+  }
+
+  fn boolean(&mut self) -> crate::Result<StructuredBareItem> {
+    self.advance();
+    let rslt = match self.next_byte() {
+      Some(b'0') => false,
+      Some(b'1') => true,
+      _ => return Err(invalid()),
+    };
+    Ok(StructuredBareItem::Boolean(rslt))
+  }
+
+  fn byte_sequence(&mut self) -> crate::Result<StructuredBareItem> {
+    self.advance();
+    let idx = self.bytes.iter().position(|el| *el == b':').ok_or_else(invalid)?;
+    let (encoded, rest) = self.bytes.split_at(idx);
+    self.bytes = rest.get(1..).unwrap_or_default();
+    Ok(StructuredBareItem::ByteSequence(base64_decode(encoded)?))
+  }
+
+  fn finish(mut self) -> crate::Result<()> {
+    self.skip_sp();
+    if !self.bytes.is_empty() {
+      return Err(invalid());
+    }
+    Ok(())
+  }
+
+  fn inner_list(&mut self) -> crate::Result<StructuredInnerList> {
+    self.advance();
+    let mut items = Vector::new();
+    loop {
+      self.skip_sp();
+      if self.peek() == Some(b')') {
+        self.advance();
+        return Ok(StructuredInnerList { items, params: self.params()? });
+      }
+      items.push(self.item()?)?;
+      if !matches!(self.peek(), Some(b' ' | b')')) {
+        return Err(invalid());
+      }
+    }
+  }
+
+  fn item(&mut self) -> crate::Result<StructuredItem> {
+    Ok(StructuredItem { bare_item: self.bare_item()?, params: self.params()? })
+  }
+
+  fn key(&mut self) -> crate::Result<String> {
+    if !matches!(self.peek(), Some(b'*' | b'a'..=b'z')) {
+      return Err(invalid());
+    }
+    let len = self.bytes.iter().position(|el| !is_key_char(*el)).unwrap_or(self.bytes.len());
+    let (key, rest) = self.bytes.split_at(len);
+    self.bytes = rest;
+    Ok(key.iter().copied().map(char::from).collect())
+  }
+
+  fn member(&mut self) -> crate::Result<StructuredMember> {
+    if self.peek() == Some(b'(') {
+      Ok(StructuredMember::InnerList(self.inner_list()?))
+    } else {
+      Ok(StructuredMember::Item(self.item()?))
+    }
+  }
+
+  fn next_byte(&mut self) -> Option<u8> {
+    let [first, rest @ ..] = self.bytes else {
+      return None;
+    };
+    self.bytes = rest;
+    Some(*first)
+  }
+
+  fn number(&mut self) -> crate::Result<StructuredBareItem> {
+    let is_negative = self.peek() == Some(b'-');
+    if is_negative {
+      self.advance();
+    }
+    if !self.peek().is_some_and(|el| el.is_ascii_digit()) {
+      return Err(invalid());
+    }
+    let mut fraction: i64 = 0;
+    let mut fraction_len: Option<u8> = None;
+    let mut integer: i64 = 0;
+    let mut integer_len: u8 = 0;
+    while let Some(byte) = self.peek() {
+      if byte.is_ascii_digit() {
+        let digit = i64::from(byte.wrapping_sub(b'0'));
+        if let Some(len) = &mut fraction_len {
+          *len = len.wrapping_add(1);
+          if *len > 3 {
+            return Err(invalid());
+          }
+          fraction = fraction.wrapping_mul(10).wrapping_add(digit);
+        } else {
+          integer_len = integer_len.wrapping_add(1);
+          if integer_len > 15 {
+            return Err(invalid());
+          }
+          integer = integer.wrapping_mul(10).wrapping_add(digit);
+        }
+      } else if byte == b'.' && fraction_len.is_none() {
+        if integer_len > 12 {
+          return Err(invalid());
+        }
+        fraction_len = Some(0);
+      } else {
+        break;
+      }
+      self.advance();
+    }
+    let sign = if is_negative { -1 } else { 1 };
+    match fraction_len {
+      None => Ok(StructuredBareItem::Integer(integer.wrapping_mul(sign))),
+      Some(0) => Err(invalid()),
+      Some(len) => {
+        let scale = 10i64.wrapping_pow(u32::from(3u8.wrapping_sub(len)));
+        let thousandths = integer.wrapping_mul(1000).wrapping_add(fraction.wrapping_mul(scale));
+        Ok(StructuredBareItem::Decimal(thousandths.wrapping_mul(sign)))
+      }
+    }
+  }
+
+  fn params(&mut self) -> crate::Result<Vector<(String, StructuredBareItem)>> {
+    let mut params = Vector::new();
+    while self.peek() == Some(b';') {
+      self.advance();
+      self.skip_sp();
+      let key = self.key()?;
+      let value = if self.peek() == Some(b'=') {
+        self.advance();
+        self.bare_item()?
+      } else {
+        StructuredBareItem::Boolean(true)
+      };
+      insert(&mut params, key, value)?;
+    }
+    Ok(params)
+  }
+
+  fn peek(&self) -> Option<u8> {
+    self.bytes.first().copied()
+  }
+
+  // Consumes the comma that separates members. Returns `true` if there are no more members.
+  fn separator(&mut self) -> crate::Result<bool> {
+    self.skip_ows();
+    if self.bytes.is_empty() {
+      return Ok(true);
+    }
+    if self.next_byte() != Some(b',') {
+      return Err(invalid());
+    }
+    self.skip_ows();
+    if self.bytes.is_empty() {
+      return Err(invalid());
+    }
+    Ok(false)
+  }
+
+  fn skip_ows(&mut self) {
+    while matches!(self.peek(), Some(b' ' | b'\t')) {
+      self.advance();
+    }
+  }
+
+  fn skip_sp(&mut self) {
+    while self.peek() == Some(b' ') {
+      self.advance();
+    }
+  }
+
+  fn string(&mut self) -> crate::Result<StructuredBareItem> {
+    self.advance();
+    let mut rslt = String::new();
+    loop {
+      match self.next_byte() {
+        Some(b'"') => return Ok(StructuredBareItem::String(rslt)),
+        Some(b'\\') => match self.next_byte() {
+          Some(byte @ (b'"' | b'\\')) => rslt.push(char::from(byte)),
+          _ => return Err(invalid()),
+        },
+        Some(byte @ b' '..=b'~') => rslt.push(char::from(byte)),
+        _ => return Err(invalid()),
+      }
+    }
+  }
+
+  fn token(&mut self) -> crate::Result<StructuredBareItem> {
+    let len = self.bytes.iter().position(|el| !is_token_char(*el)).unwrap_or(self.bytes.len());
+    let (token, rest) = self.bytes.split_at(len);
+    self.bytes = rest;
+    Ok(StructuredBareItem::Token(token.iter().copied().map(char::from).collect()))
+  }
+}
+
+fn base64_decode(encoded: &[u8]) -> crate::Result<Vector<u8>> {
+  let trimmed =
+    encoded.strip_suffix(b"==").or_else(|| encoded.strip_suffix(b"=")).unwrap_or(encoded);
+  let mut rslt = Vector::with_capacity(trimmed.len().wrapping_mul(3).wrapping_div(4))?;
+  let mut acc: u32 = 0;
+  let mut bits: u8 = 0;
+  for byte in trimmed {
+    let idx = BASE64_ALPHABET.iter().position(|el| el == byte).ok_or_else(invalid)?;
+    acc = acc.wrapping_shl(6) | u32::try_from(idx)?;
+    bits = bits.wrapping_add(6);
+    if bits >= 8 {
+      bits = bits.wrapping_sub(8);
+      rslt.push(u8::try_from(acc.wrapping_shr(bits.into()) & 0xFF)?)?;
+      acc &= 1u32.wrapping_shl(bits.into()).wrapping_sub(1);
+    }
+  }
+  if bits >= 6 {
+    return Err(invalid());
+  }
+  Ok(rslt)
+}
+
+fn base64_encode(bytes: &[u8], buffer: &mut Vector<u8>) -> crate::Result<()> {
+  let symbol = |idx: u32| BASE64_ALPHABET.get(usize::try_from(idx & 63).unwrap_or_default());
+  for chunk in bytes.chunks(3) {
+    let [a, b, c] = match chunk {
+      [a] => [*a, 0, 0],
+      [a, b] => [*a, *b, 0],
+      [a, b, c, ..] => [*a, *b, *c],
+      [] => break,
+    };
+    let triple = u32::from(a).wrapping_shl(16) | u32::from(b).wrapping_shl(8) | u32::from(c);
+    let mut quad = [b'='; 4];
+    for (idx, elem) in quad.iter_mut().enumerate().take(chunk.len().wrapping_add(1)) {
+      let shift = 18u32.wrapping_sub(u32::try_from(idx)?.wrapping_mul(6));
+      *elem = *symbol(triple.wrapping_shr(shift)).ok_or_else(invalid)?;
+    }
+    buffer.extend_from_copyable_slice(&quad)?;
+  }
+  Ok(())
+}
+
+fn insert<T>(vector: &mut Vector<(String, T)>, key: String, value: T) -> crate::Result<()> {
+  if let Some(elem) = vector.iter_mut().find(|el| el.0 == key) {
+    elem.1 = value;
+  } else {
+    vector.push((key, value))?;
+  }
+  Ok(())
+}
+
+fn invalid() -> crate::Error {
+  HttpError::InvalidStructuredField.into()
+}
+
+fn is_key_char(byte: u8) -> bool {
+  matches!(byte, b'*' | b'-' | b'.' | b'0'..=b'9' | b'_' | b'a'..=b'z')
+}
+
+fn is_token_char(byte: u8) -> bool {
+  is_tchar(byte) || matches!(byte, b':' | b'/')
+}
+
+fn param<'params>(
+  params: &'params [(String, StructuredBareItem)],
+  key: &str,
+) -> Option<&'params StructuredBareItem> {
+  params.iter().find_map(|(local_key, value)| (local_key == key).then_some(value))
+}
+
+fn serialize_bare_item(
+  bare_item: &StructuredBareItem,
+  buffer: &mut Vector<u8>,
+) -> crate::Result<()> {
+  match bare_item {
+    StructuredBareItem::Boolean(elem) => {
+      buffer.extend_from_copyable_slice(if *elem { b"?1" } else { b"?0" })?;
+    }
+    StructuredBareItem::ByteSequence(elem) => {
+      buffer.push(b':')?;
+      base64_encode(elem, buffer)?;
+      buffer.push(b':')?;
+    }
+    StructuredBareItem::Decimal(elem) => {
+      let abs = elem.unsigned_abs();
+      let integer = abs.wrapping_div(1000);
+      if integer > MAX_DECIMAL_INTEGER {
+        return Err(invalid());
+      }
+      if *elem < 0 {
+        buffer.push(b'-')?;
+      }
+      let mut fraction = abs.wrapping_rem(1000);
+      let mut fraction_len: usize = 3;
+      while fraction_len > 1 && fraction.wrapping_rem(10) == 0 {
+        fraction = fraction.wrapping_div(10);
+        fraction_len = fraction_len.wrapping_sub(1);
+      }
+      buffer.write_fmt(format_args!("{integer}.{fraction:0fraction_len$}"))?;
+    }
+    StructuredBareItem::Integer(elem) => {
+      if elem.unsigned_abs() > MAX_INTEGER {
+        return Err(invalid());
+      }
+      buffer.write_fmt(format_args!("{elem}"))?;
+    }
+    StructuredBareItem::String(elem) => {
+      buffer.push(b'"')?;
+      for byte in elem.bytes() {
+        match byte {
+          b'"' | b'\\' => buffer.extend_from_copyable_slice(&[b'\\', byte])?,
+          b' '..=b'~' => buffer.push(byte)?,
+          _ => return Err(invalid()),
+        }
+      }
+      buffer.push(b'"')?;
+    }
+    StructuredBareItem::Token(elem) => {
+      let [b'*' | b'A'..=b'Z' | b'a'..=b'z', rest @ ..] = elem.as_bytes() else {
+        return Err(invalid());
+      };
+      if !rest.iter().all(|el| is_token_char(*el)) {
+        return Err(invalid());
+      }
+      buffer.extend_from_copyable_slice(elem.as_bytes())?;
+    }
+  }
+  Ok(())
+}
+
+fn serialize_key(key: &str, buffer: &mut Vector<u8>) -> crate::Result<()> {
+  let [b'*' | b'a'..=b'z', rest @ ..] = key.as_bytes() else {
+    return Err(invalid());
+  };
+  if !rest.iter().all(|el| is_key_char(*el)) {
+    return Err(invalid());
+  }
+  buffer.extend_from_copyable_slice(key.as_bytes())
+}
+
+fn serialize_member(member: &StructuredMember, buffer: &mut Vector<u8>) -> crate::Result<()> {
+  match member {
+    StructuredMember::InnerList(elem) => {
+      buffer.push(b'(')?;
+      for (idx, item) in elem.items.iter().enumerate() {
+        if idx > 0 {
+          buffer.push(b' ')?;
+        }
+        item.serialize(buffer)?;
+      }
+      buffer.push(b')')?;
+      serialize_params(&elem.params, buffer)
+    }
+    StructuredMember::Item(elem) => elem.serialize(buffer),
+  }
+}
+
+fn serialize_params(
+  params: &[(String, StructuredBareItem)],
+  buffer: &mut Vector<u8>,
+) -> crate::Result<()> {
+  for (key, value) in params {
+    buffer.push(b';')?;
+    serialize_key(key, buffer)?;
+    if *value != StructuredBareItem::Boolean(true) {
+      buffer.push(b'=')?;
+      serialize_bare_item(value, buffer)?;
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{
+      StructuredBareItem, StructuredDictionary, StructuredItem, StructuredList, StructuredMember,
+    },
+    misc::Vector,
+  };
+  use alloc::string::String;
+
+  #[test]
+  fn bare_items() {
+    let cases: [(&str, StructuredBareItem); 7] = [
+      ("?0", StructuredBareItem::Boolean(false)),
+      (":aGVsbG8=:", StructuredBareItem::ByteSequence(Vector::from_slice(b"hello").unwrap())),
+      ("-1.5", StructuredBareItem::Decimal(-1500)),
+      ("0.025", StructuredBareItem::Decimal(25)),
+      ("42", StructuredBareItem::Integer(42)),
+      ("\"a \\\"b\\\\\"", StructuredBareItem::String(String::from("a \"b\\"))),
+      ("text/html", StructuredBareItem::Token(String::from("text/html"))),
+    ];
+    for (value, bare_item) in cases {
+      let item = StructuredItem::parse(value).unwrap();
+      assert_eq!(item, StructuredItem::new(bare_item));
+      let mut buffer = Vector::new();
+      item.serialize(&mut buffer).unwrap();
+      assert_eq!(buffer.as_slice(), value.as_bytes());
+    }
+  }
+
+  #[test]
+  fn dictionary() {
+    let dict = StructuredDictionary::parse("u=1,  i, a=(1 2);x, u=3").unwrap();
+    assert_eq!(dict.members.len(), 3);
+    let Some(StructuredMember::Item(item)) = dict.get("u") else {
+      panic!();
+    };
+    assert_eq!(item.bare_item, StructuredBareItem::Integer(3));
+    let mut buffer = Vector::new();
+    dict.serialize(&mut buffer).unwrap();
+    assert_eq!(buffer.as_slice(), b"u=3, i, a=(1 2);x");
+  }
+
+  #[test]
+  fn invalid_values() {
+    assert!(StructuredDictionary::parse("A=1").is_err());
+    assert!(StructuredItem::parse("1.").is_err());
+    assert!(StructuredItem::parse("1.2345").is_err());
+    assert!(StructuredItem::parse("1234567890123456").is_err());
+    assert!(StructuredItem::parse("\"\u{e9}\"").is_err());
+    assert!(StructuredList::parse("a,").is_err());
+    assert!(StructuredList::parse("(a b").is_err());
+  }
+
+  #[test]
+  fn list() {
+    let list = StructuredList::parse("gzip;q=0.5, (\"a\" b);lvl=?0, *").unwrap();
+    let [StructuredMember::Item(first), StructuredMember::InnerList(second), _] =
+      list.members.as_slice()
+    else {
+      panic!();
+    };
+    assert_eq!(first.param("q"), Some(&StructuredBareItem::Decimal(500)));
+    assert_eq!(second.items.len(), 2);
+    assert_eq!(second.param("lvl"), Some(&StructuredBareItem::Boolean(false)));
+    let mut buffer = Vector::new();
+    list.serialize(&mut buffer).unwrap();
+    assert_eq!(buffer.as_slice(), b"gzip;q=0.5, (\"a\" b);lvl=?0, *");
+  }
+}
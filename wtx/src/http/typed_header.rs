@@ -0,0 +1,306 @@
+use crate::{
+  http::{
+    HttpError, KnownHeaderName, Mime, format_http_date, is_tchar, mime::essence, parse_http_date,
+  },
+  misc::{FromRadix10, str_split_once1},
+};
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Header with a well-known name whose value is validated when parsed and written in a canonical
+/// form when formatted.
+///
+/// See [`crate::http::Headers::get_as`] and [`crate::http::Headers::set`].
+pub trait TypedHeader<'value>: Display + Sized {
+  /// Header name
+  const NAME: KnownHeaderName;
+
+  /// Parses the textual value of a header.
+  fn decode(value: &'value str) -> crate::Result<Self>;
+}
+
+/// Size of the body in bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ContentLength(
+  /// Number of bytes
+  pub u64,
+);
+
+impl Display for ContentLength {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    self.0.fmt(f)
+  }
+}
+
+impl<'value> TypedHeader<'value> for ContentLength {
+  const NAME: KnownHeaderName = KnownHeaderName::ContentLength;
+
+  #[inline]
+  fn decode(value: &'value str) -> crate::Result<Self> {
+    let rslt = u64::from_radix_10(value.trim_ascii().as_bytes());
+    Ok(Self(rslt.map_err(|_err| invalid(KnownHeaderName::ContentLength))?))
+  }
+}
+
+/// Media type of the body, optionally followed by parameters like `charset`.
+///
+/// Type, subtype and parameter names are formatted in lower case.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ContentType<'any>(&'any str);
+
+impl<'any> ContentType<'any> {
+  /// Validates `value`, which must be composed by a `type/subtype` pair and optional parameters.
+  ///
+  /// ```rust
+  /// use wtx::http::ContentType;
+  /// let content_type = ContentType::new("text/html; charset=utf-8").unwrap();
+  /// assert_eq!(content_type.essence(), "text/html");
+  /// assert_eq!(content_type.param("charset"), Some("utf-8"));
+  /// assert!(ContentType::new("text").is_err());
+  /// ```
+  #[inline]
+  pub fn new(value: &'any str) -> crate::Result<Self> {
+    let value = value.trim_ascii();
+    let (ty, subty) = str_split_once1(essence(value), b'/').ok_or_else(invalid_content_type)?;
+    if !is_token(ty) || !is_token(subty) {
+      return Err(invalid_content_type());
+    }
+    for_each_param(value, |_, _| Ok(()))?;
+    Ok(Self(value))
+  }
+
+  /// `application/x-www-form-urlencoded`
+  #[inline]
+  pub const fn form_urlencoded() -> Self {
+    Self("application/x-www-form-urlencoded")
+  }
+
+  /// `application/json`
+  #[inline]
+  pub const fn json() -> Self {
+    Self("application/json")
+  }
+
+  /// `application/octet-stream`
+  #[inline]
+  pub const fn octet_stream() -> Self {
+    Self("application/octet-stream")
+  }
+
+  /// `text/plain; charset=utf-8`
+  #[inline]
+  pub const fn text_plain() -> Self {
+    Self("text/plain; charset=utf-8")
+  }
+
+  /// Media type without parameters.
+  #[inline]
+  pub fn essence(&self) -> &'any str {
+    essence(self.0)
+  }
+
+  /// Maps the media type to one of the [`Mime::KNOWN`] variants.
+  #[inline]
+  pub fn mime(&self) -> Option<Mime> {
+    Mime::from_content_type(self.0)
+  }
+
+  /// Value of the parameter identified by the case-insensitive `name`. Quoted values are returned
+  /// with their quotes.
+  #[inline]
+  pub fn param(&self, name: &str) -> Option<&'any str> {
+    let mut rslt = None;
+    let _rslt = for_each_param(self.0, |local_name, value| {
+      if rslt.is_none() && local_name.eq_ignore_ascii_case(name) {
+        rslt = Some(value);
+      }
+      Ok(())
+    });
+    rslt
+  }
+}
+
+impl Display for ContentType<'_> {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write_lowercase(f, self.essence())?;
+    for_each_param(self.0, |name, value| {
+      f.write_str("; ")?;
+      write_lowercase(f, name)?;
+      f.write_char('=')?;
+      f.write_str(value)?;
+      Ok(())
+    })
+    .map_err(|_err| fmt::Error)
+  }
+}
+
+impl<'value> TypedHeader<'value> for ContentType<'value> {
+  const NAME: KnownHeaderName = KnownHeaderName::ContentType;
+
+  #[inline]
+  fn decode(value: &'value str) -> crate::Result<Self> {
+    Self::new(value)
+  }
+}
+
+/// Date and time at which the message was originated, in seconds since the UNIX epoch.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Date(
+  /// Seconds since the UNIX epoch
+  pub u64,
+);
+
+impl Display for Date {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str(format_http_date(self.0).map_err(|_err| fmt::Error)?.as_str())
+  }
+}
+
+impl<'value> TypedHeader<'value> for Date {
+  const NAME: KnownHeaderName = KnownHeaderName::Date;
+
+  #[inline]
+  fn decode(value: &'value str) -> crate::Result<Self> {
+    Ok(Self(parse_http_date(value).ok_or_else(|| invalid(KnownHeaderName::Date))?))
+  }
+}
+
+/// Date and time at which the origin server believes the selected representation was last
+/// modified, in seconds since the UNIX epoch.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LastModified(
+  /// Seconds since the UNIX epoch
+  pub u64,
+);
+
+impl Display for LastModified {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str(format_http_date(self.0).map_err(|_err| fmt::Error)?.as_str())
+  }
+}
+
+impl<'value> TypedHeader<'value> for LastModified {
+  const NAME: KnownHeaderName = KnownHeaderName::LastModified;
+
+  #[inline]
+  fn decode(value: &'value str) -> crate::Result<Self> {
+    Ok(Self(parse_http_date(value).ok_or_else(|| invalid(KnownHeaderName::LastModified))?))
+  }
+}
+
+// Calls `cb` with the name and the value of each parameter that follows the media type of `value`.
+fn for_each_param<'any>(
+  value: &'any str,
+  mut cb: impl FnMut(&'any str, &'any str) -> crate::Result<()>,
+) -> crate::Result<()> {
+  let Some((_, mut rest)) = str_split_once1(value, b';') else {
+    return Ok(());
+  };
+  loop {
+    let (name, after_name) =
+      str_split_once1(rest.trim_ascii_start(), b'=').ok_or_else(invalid_content_type)?;
+    if !is_token(name) {
+      return Err(invalid_content_type());
+    }
+    let value_len = if after_name.starts_with('"') {
+      quoted_string_len(after_name)?
+    } else {
+      after_name.find([';', ' ', '\t']).unwrap_or(after_name.len())
+    };
+    let (value, after_value) = after_name.split_at_checked(value_len).unwrap_or_default();
+    if !value.starts_with('"') && !is_token(value) {
+      return Err(invalid_content_type());
+    }
+    cb(name, value)?;
+    let after_value = after_value.trim_ascii_start();
+    if after_value.is_empty() {
+      return Ok(());
+    }
+    rest = after_value.strip_prefix(';').ok_or_else(invalid_content_type)?;
+  }
+}
+
+fn invalid(name: KnownHeaderName) -> crate::Error {
+  HttpError::InvalidTypedHeader(name).into()
+}
+
+fn invalid_content_type() -> crate::Error {
+  invalid(KnownHeaderName::ContentType)
+}
+
+fn is_token(value: &str) -> bool {
+  !value.is_empty() && value.bytes().all(is_tchar)
+}
+
+// Length of the quoted string located at the beginning of `value`, including both quotes.
+fn quoted_string_len(value: &str) -> crate::Result<usize> {
+  let mut iter = value.bytes().enumerate().skip(1);
+  while let Some((idx, byte)) = iter.next() {
+    match byte {
+      b'"' => return Ok(idx.wrapping_add(1)),
+      b'\\' => {
+        let _escaped = iter.next();
+      }
+      b'\t' | b' '..=b'~' | 0x80..=0xFF => {}
+      _ => break,
+    }
+  }
+  Err(invalid_content_type())
+}
+
+fn write_lowercase(f: &mut Formatter<'_>, value: &str) -> fmt::Result {
+  for elem in value.chars() {
+    f.write_char(elem.to_ascii_lowercase())?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http::{
+    ContentLength, ContentType, Date, Header, Headers, KnownHeaderName, LastModified, Mime,
+  };
+
+  #[test]
+  fn content_type() {
+    let content_type = ContentType::new("Text/HTML;Charset=\"utf-8\" ; level=1").unwrap();
+    assert_eq!(content_type.mime(), None);
+    assert_eq!(content_type.param("charset"), Some("\"utf-8\""));
+    assert_eq!(alloc::format!("{content_type}"), "text/html; charset=\"utf-8\"; level=1");
+    assert_eq!(ContentType::json().mime(), Some(Mime::ApplicationJson));
+    assert!(ContentType::new("text/").is_err());
+    assert!(ContentType::new("text/html; charset").is_err());
+    assert!(ContentType::new("text/html; charset=\"utf-8").is_err());
+  }
+
+  #[test]
+  fn get_as_and_set() {
+    let mut headers = Headers::new();
+    headers
+      .push_from_iter(Header::from_name_and_value(KnownHeaderName::ContentLength.into(), ["1"]))
+      .unwrap();
+    headers
+      .push_from_iter(Header::from_name_and_value(KnownHeaderName::ContentLength.into(), ["2"]))
+      .unwrap();
+    assert_eq!(headers.get_as::<ContentLength>().unwrap(), Some(ContentLength(1)));
+    headers.set(ContentLength(42)).unwrap();
+    assert_eq!(headers.headers_len(), 1);
+    assert_eq!(headers.get_as::<ContentLength>().unwrap(), Some(ContentLength(42)));
+    assert_eq!(headers.get_as::<ContentType<'_>>().unwrap(), None);
+    headers.set(Date(784_111_777)).unwrap();
+    assert_eq!(
+      headers.get_by_name(KnownHeaderName::Date.into()).unwrap().value,
+      "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    headers
+      .push_from_iter(Header::from_name_and_value(
+        KnownHeaderName::LastModified.into(),
+        ["yesterday"],
+      ))
+      .unwrap();
+    assert!(headers.get_as::<LastModified>().is_err());
+  }
+}
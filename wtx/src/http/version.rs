@@ -9,5 +9,7 @@ create_enum! {
     /// HTTP/2
     #[default]
     Http2 = (2, "HTTP/2"),
+    /// HTTP/3
+    Http3 = (3, "HTTP/3"),
   }
 }
@@ -2,7 +2,8 @@
 //!
 //! 1. Does not support padded headers when writing.
 //! 2. Does not support push promises (Deprecated by major third-parties).
-//! 3. Does not support prioritization (Deprecated by the RFC).
+//! 3. Prioritization signals (Deprecated by the RFC) are received and sent but frames are not
+//!    automatically scheduled.
 
 #[macro_use]
 mod macros;
@@ -25,21 +26,27 @@ mod http2_buffer;
 mod http2_data;
 mod http2_error;
 mod http2_error_code;
+#[cfg(feature = "metrics")]
+mod http2_metrics;
 mod http2_params;
 mod http2_params_send;
 mod http2_status;
+mod http2_stream;
 mod huffman;
 mod huffman_tables;
 mod index_map;
 mod initial_server_header;
 mod misc;
 mod ping_frame;
+mod priority_frame;
 mod process_receipt_frame_ty;
 mod reset_stream_frame;
 mod send_data_mode;
 mod send_msg;
+mod server_sent_events_over_stream;
 mod server_stream;
 mod settings_frame;
+mod stream_priority;
 mod stream_receiver;
 mod stream_state;
 #[cfg(all(feature = "_async-tests", test))]
@@ -56,7 +63,7 @@ use crate::{
   http::{Method, Protocol, ReqResBuffer, Request},
   http2::misc::{
     frame_reader_rslt, manage_initial_stream_receiving, process_higher_operation_err, protocol_err,
-    sorp_mut, write_array,
+    set_max_body_len, sorp_mut, write_array,
   },
   misc::{
     ConnectionState, Either, Lease, LeaseMut, Lock, RefCounter, SingleTypeStorage, StreamReader,
@@ -77,14 +84,22 @@ pub use http2_buffer::Http2Buffer;
 pub use http2_data::Http2Data;
 pub use http2_error::Http2Error;
 pub use http2_error_code::Http2ErrorCode;
+#[cfg(feature = "metrics")]
+pub use http2_metrics::Http2Metrics;
 pub use http2_params::Http2Params;
 pub use http2_status::{Http2RecvStatus, Http2SendStatus};
+pub use http2_stream::Http2Stream;
 pub use send_data_mode::{SendDataMode, SendDataModeBytes};
+pub use server_sent_events_over_stream::ServerSentEventsOverStream;
 pub use server_stream::ServerStream;
+pub use stream_priority::StreamPriority;
 #[cfg(feature = "web-socket")]
 pub use web_socket_over_stream::WebSocketOverStream;
 pub use window::{Window, Windows};
 
+#[cfg(feature = "http3")]
+pub(crate) use huffman::huffman_decode;
+
 pub(crate) const MAX_BODY_LEN: u32 = max_body_len!();
 pub(crate) const MAX_HPACK_LEN: u32 = max_hpack_len!();
 pub(crate) const MAX_CONCURRENT_STREAMS_NUM: u32 = max_concurrent_streams_num!();
@@ -97,6 +112,14 @@ pub(crate) const READ_BUFFER_LEN: u32 = read_buffer_len!();
 
 const PREFACE: &[u8; 24] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
 
+/// [`Http2`] instance using the mutex from smol.
+#[cfg(feature = "smol")]
+pub type Http2Smol<HB, SW, const IS_CLIENT: bool> =
+  Http2<Http2DataSmol<HB, SW, IS_CLIENT>, IS_CLIENT>;
+/// [`Http2Data`] instance using the mutex from smol.
+#[cfg(feature = "smol")]
+pub type Http2DataSmol<HB, SW, const IS_CLIENT: bool> =
+  Arc<smol::lock::Mutex<Http2Data<HB, SW, IS_CLIENT>>>;
 /// [`Http2`] instance using the mutex from tokio.
 #[cfg(feature = "tokio")]
 pub type Http2Tokio<HB, SW, const IS_CLIENT: bool> =
@@ -223,6 +246,21 @@ where
     ))
   }
 
+  /// Sends a GOAWAY frame with the identifier of the last received stream but, unlike
+  /// [`Self::send_go_away`], doesn't close the connection. Ongoing streams can still be finished
+  /// while the peer is advised to open new connections.
+  ///
+  /// [`Self::send_go_away`] should be called afterwards.
+  ///
+  /// <https://datatracker.ietf.org/doc/html/rfc9113#section-6.8>
+  #[inline]
+  pub async fn send_graceful_go_away(&self) -> crate::Result<()> {
+    let mut lock = self.hd.lock().await;
+    let hdpm = lock.parts_mut();
+    let gaf = go_away_frame::GoAwayFrame::new(Http2ErrorCode::NoError, *hdpm.last_stream_id);
+    write_array([&gaf.bytes()], &self.is_conn_open, hdpm.stream_writer).await
+  }
+
   /// Awaits for an initial header to create a stream.
   ///
   /// Returns [`Either::Left`] if the network connection has been closed, either locally
@@ -232,6 +270,20 @@ where
     &mut self,
     rrb: ReqResBuffer,
     cb: impl FnOnce(Request<&mut ReqResBuffer>, Option<Protocol>) -> T,
+  ) -> crate::Result<Either<ReqResBuffer, (ServerStream<HD>, T)>> {
+    self.stream_with_max_body_len(rrb, |req, protocol| (cb(req, protocol), None)).await
+  }
+
+  /// Similar to [`Self::stream`] but `cb` can also return a maximum body length that overrides
+  /// [`Http2Params::max_body_len`] for the stream.
+  ///
+  /// The frame reader only processes DATA frames after the execution of `cb`, as such, the
+  /// returned length is applied before the arrival of the body.
+  #[inline]
+  pub(crate) async fn stream_with_max_body_len<T>(
+    &mut self,
+    rrb: ReqResBuffer,
+    cb: impl FnOnce(Request<&mut ReqResBuffer>, Option<Protocol>) -> (T, Option<u32>),
   ) -> crate::Result<Either<ReqResBuffer, (ServerStream<HD>, T)>> {
     let Self { hd, is_conn_open, ish_id } = self;
     let curr_ish_id = *ish_id;
@@ -260,10 +312,18 @@ where
         ));
         Poll::Pending
       } else {
+        if let Some(ish) = hdpm.hb.initial_server_headers.get_mut(&curr_ish_id) {
+          // Spurious wake-ups can happen before the arrival of the initial headers.
+          if ish.stream_id.is_zero() && is_conn_open.load(Ordering::Relaxed) {
+            ish.waker.clone_from(cx.waker());
+            return Poll::Pending;
+          }
+        }
         let Some(ish) = hdpm.hb.initial_server_headers.remove(&curr_ish_id) else {
           return Poll::Ready(Err(protocol_err(Http2Error::UnknownInitialServerHeaderId)));
         };
         hdpm.hb.initial_server_headers.decrease_cursor();
+        hdpm.hb.read_frame_waker.wake();
         if !is_conn_open.load(Ordering::Relaxed) {
           let this_rrb = if ish.stream_id.is_zero() {
             ish.rrb
@@ -286,7 +346,10 @@ where
       }
       Either::Right((method, protocol, stream_id, mut guard)) => {
         let sorp = sorp_mut(&mut guard.parts_mut().hb.sorp, stream_id)?;
-        let elem_cb = cb(Request::http2(method, &mut sorp.rrb), protocol);
+        let (elem_cb, max_body_len) = cb(Request::http2(method, &mut sorp.rrb), protocol);
+        if let Some(elem) = max_body_len {
+          set_max_body_len(elem, sorp);
+        }
         drop(guard);
         Ok(Either::Right((
           ServerStream::new(
@@ -356,13 +419,19 @@ where
     drop(hdpm.hb.scrp.insert(
       stream_id,
       stream_receiver::StreamControlRecvParams {
+        has_sent_headers: false,
         is_stream_open: true,
+        priority: StreamPriority::DEFAULT,
         stream_state: stream_state::StreamState::Idle,
         waker: Waker::noop().clone(),
         windows: Windows::initial(hdpm.hp, hdpm.hps),
       },
     ));
     *hdpm.last_stream_id = hdpm.last_stream_id.wrapping_add(u31::U31::TWO);
+    #[cfg(feature = "metrics")]
+    if let Some(elem) = hdpm.hp.metrics() {
+      elem.streams_opened().inc();
+    }
     drop(guard);
     Ok(ClientStream::new(self.hd.clone(), Arc::clone(&self.is_conn_open), span, stream_id))
   }
@@ -1,22 +1,34 @@
 use crate::{
-  http::{ReqResBuffer, ReqResData, Request, StatusCode},
+  http::{Headers, Method, Protocol, ReqResBuffer, ReqResData, Request, StatusCode},
   http2::{
-    CommonStream, Http2Buffer, Http2Data, Http2RecvStatus, Http2SendStatus,
+    CommonStream, Http2Buffer, Http2Data, Http2Error, Http2RecvStatus, Http2SendStatus,
+    Http2Stream, SendDataMode, StreamPriority,
     hpack_static_headers::{HpackStaticRequestHeaders, HpackStaticResponseHeaders},
     misc::{
       frame_reader_rslt, manage_initial_stream_receiving, manage_recurrent_stream_receiving,
-      process_higher_operation_err,
+      process_higher_operation_err, protocol_err, scrp_mut, sorp_mut, status_recv, write_array,
+    },
+    priority_frame::PriorityFrame,
+    send_msg::{
+      encode_headers, send_msg, write_eos_data_frame, write_standalone_data,
+      write_standalone_headers,
     },
-    send_msg::send_msg,
     stream_receiver::StreamOverallRecvParams,
     stream_state::StreamState,
     u31::U31,
-    window::Windows,
+    window::{Windows, WindowsPair},
+  },
+  misc::{
+    Lease, LeaseMut, Lock, RefCounter, StreamReader, StreamWriter, UriRef, Vector, span::Span,
   },
-  misc::{Lease, LeaseMut, Lock, RefCounter, StreamWriter, UriRef, span::Span},
-  sync::{Arc, AtomicBool},
+  sync::{Arc, AtomicBool, Ordering},
+};
+use core::{
+  future::poll_fn,
+  mem,
+  pin::pin,
+  task::{Poll, Waker, ready},
 };
-use core::{future::poll_fn, pin::pin, task::Poll};
 
 /// Groups the methods used by clients that connect to servers.
 #[derive(Debug)]
@@ -94,8 +106,12 @@ where
             body_len: 0,
             content_length: None,
             has_initial_header: false,
+            has_large_body: false,
             has_one_or_more_data_frames: false,
+            has_sent_headers: false,
             is_stream_open: true,
+            max_body_len: hdpm.hp.max_body_len(),
+            priority: StreamPriority::DEFAULT,
             rrb: elem,
             status_code: StatusCode::Ok,
             stream_state: StreamState::HalfClosedLocal,
@@ -117,6 +133,250 @@ where
     rslt
   }
 
+  /// Receive Streamed Response
+  ///
+  /// Similar to [`Self::recv_res`] but received DATA frames are written into `sink` instead of
+  /// being stored in the body of the returned [`ReqResBuffer`]. After each write, `progress` is
+  /// called with the number of received bytes and the value of the `Content-Length` header, if
+  /// any.
+  ///
+  /// [`crate::http2::Http2Params::max_body_len`] only delimits the bytes that weren't written into
+  /// `sink`.
+  #[inline]
+  pub async fn recv_res_stream<S>(
+    &mut self,
+    rrb: ReqResBuffer,
+    sink: &mut S,
+    mut progress: impl FnMut(u64, Option<u64>),
+  ) -> crate::Result<(Http2RecvStatus<StatusCode, ()>, ReqResBuffer)>
+  where
+    S: StreamWriter,
+  {
+    let rrb_opt = &mut Some(rrb);
+    let Self { hd, is_conn_open, span, stream_id, windows } = self;
+    let _e = span.enter();
+    _trace!("Receiving streamed response");
+    let mut chunk = Vector::new();
+    let mut content_length = None;
+    let mut received: u64 = 0;
+    let rslt = async {
+      loop {
+        let mut lock_pin = pin!(hd.lock());
+        let rslt_opt = poll_fn(|cx| {
+          let mut lock = lock_pin!(cx, hd, lock_pin);
+          let hdpm = lock.parts_mut();
+          if let Some(mut elem) = rrb_opt.take() {
+            if !manage_initial_stream_receiving(is_conn_open, &mut elem) {
+              frame_reader_rslt(hdpm.frame_reader_error)?;
+              return Poll::Ready(Ok(Some((Http2RecvStatus::ClosedConnection, elem))));
+            }
+            drop(hdpm.hb.sorp.insert(
+              *stream_id,
+              StreamOverallRecvParams {
+                body_len: 0,
+                content_length: None,
+                has_initial_header: false,
+                has_large_body: false,
+                has_one_or_more_data_frames: false,
+                has_sent_headers: false,
+                is_stream_open: true,
+                max_body_len: hdpm.hp.max_body_len(),
+                priority: StreamPriority::DEFAULT,
+                rrb: elem,
+                status_code: StatusCode::Ok,
+                stream_state: StreamState::HalfClosedLocal,
+                waker: cx.waker().clone(),
+                windows: *windows,
+              },
+            ));
+            return Poll::Pending;
+          }
+          let sorp = sorp_mut(&mut hdpm.hb.sorp, *stream_id)?;
+          // `Content-Length` is verified locally because the body is not accumulated.
+          if let Some(elem) = sorp.content_length.take() {
+            content_length = Some(elem);
+          }
+          if !sorp.rrb.body.is_empty() {
+            frame_reader_rslt(hdpm.frame_reader_error)?;
+            mem::swap(&mut sorp.rrb.body, &mut chunk);
+            sorp.body_len = 0;
+            return Poll::Ready(Ok(None));
+          }
+          manage_recurrent_stream_receiving(
+            cx,
+            hdpm,
+            is_conn_open,
+            *stream_id,
+            |_, _, local_sorp| local_sorp.status_code,
+          )
+          .map(|el| el.map(Some))
+        })
+        .await?;
+        if let Some((hrs, res_rrb)) = rslt_opt {
+          if let (Http2RecvStatus::Eos(_), Some(elem)) = (&hrs, content_length) {
+            if u64::try_from(elem).ok() != Some(received) {
+              return Err(protocol_err(Http2Error::InvalidContentLength));
+            }
+          }
+          return Ok((hrs, res_rrb));
+        }
+        sink.write_all(&chunk).await?;
+        received = received.wrapping_add(u64::try_from(chunk.len()).unwrap_or(u64::MAX));
+        progress(received, content_length.and_then(|el| u64::try_from(el).ok()));
+        chunk.clear();
+      }
+    }
+    .await;
+    if let Err(err) = &rslt {
+      process_higher_operation_err(err, hd).await;
+    }
+    rslt
+  }
+
+  /// Send Extended CONNECT
+  ///
+  /// Sends the headers of an Extended CONNECT request (RFC 8441) and awaits the initial headers
+  /// of the response. [`Http2RecvStatus::Ongoing`] means that the stream remains open in both
+  /// directions, as such, `protocol` can be tunneled through [`CommonStream::send_data`] and
+  /// [`CommonStream::recv_data`].
+  ///
+  /// Returns an error if the server didn't enable the connect protocol in its settings.
+  ///
+  /// Shouldn't be called more than once.
+  #[inline]
+  pub async fn send_connect(
+    &mut self,
+    headers: &Headers,
+    protocol: Protocol,
+    rrb: ReqResBuffer,
+    uri: &UriRef<'_>,
+  ) -> crate::Result<Http2RecvStatus<StatusCode, StatusCode>> {
+    let Self { hd, is_conn_open, span, stream_id, windows: _ } = self;
+    let _e = span.enter();
+    _trace!("Sending extended connect");
+    // The server advertises its support in the first settings frame.
+    let has_connect_protocol = {
+      let mut lock_pin = pin!(hd.lock());
+      poll_fn(|cx| {
+        let mut lock = lock_pin!(cx, hd, lock_pin);
+        let hdpm = lock.parts_mut();
+        if hdpm.hps.has_settings {
+          return Poll::Ready(Ok::<_, crate::Error>(Some(hdpm.hps.enable_connect_protocol == 1)));
+        }
+        if !is_conn_open.load(Ordering::Relaxed) {
+          frame_reader_rslt(hdpm.frame_reader_error)?;
+          return Poll::Ready(Ok(None));
+        }
+        cx.waker().wake_by_ref();
+        Poll::Pending
+      })
+      .await?
+    };
+    match has_connect_protocol {
+      None => return Ok(Http2RecvStatus::ClosedConnection),
+      Some(false) => return Err(protocol_err(Http2Error::ExtendedConnectIsDisabled)),
+      Some(true) => {}
+    }
+    let hsreqh = HpackStaticRequestHeaders {
+      authority: uri.authority(),
+      method: Some(Method::Connect),
+      path: uri.relative_reference_slash(),
+      protocol: Some(protocol),
+      scheme: uri.scheme(),
+    };
+    let hsresh = HpackStaticResponseHeaders::EMPTY;
+    let rslt = async {
+      {
+        let mut guard = hd.lock().await;
+        let hdpm = guard.parts_mut();
+        let Http2Buffer { hpack_enc, hpack_enc_buffer, scrp, sorp, .. } = hdpm.hb;
+        if !is_conn_open.load(Ordering::Relaxed) {
+          return Ok(Http2RecvStatus::ClosedConnection);
+        }
+        let elem = scrp_mut(scrp, *stream_id)?;
+        if !elem.is_stream_open {
+          return Ok(Http2RecvStatus::ClosedStream);
+        }
+        if !elem.stream_state.can_send::<true>() {
+          return Err(protocol_err(Http2Error::InvalidSendStreamState));
+        }
+        encode_headers::<true>(
+          headers,
+          (hpack_enc, hpack_enc_buffer),
+          (hsreqh, hsresh),
+          *stream_id,
+        )?;
+        let _ = write_standalone_headers::<_, true>(
+          hpack_enc_buffer,
+          (hsreqh, hsresh),
+          is_conn_open,
+          false,
+          hdpm.hps.max_frame_len,
+          hdpm.stream_writer,
+          *stream_id,
+        )
+        .await?;
+        let local_windows = elem.windows;
+        drop(scrp.remove(stream_id));
+        drop(sorp.insert(
+          *stream_id,
+          StreamOverallRecvParams {
+            body_len: 0,
+            content_length: None,
+            has_initial_header: false,
+            has_large_body: false,
+            has_one_or_more_data_frames: false,
+            has_sent_headers: false,
+            is_stream_open: true,
+            max_body_len: hdpm.hp.max_body_len(),
+            priority: StreamPriority::DEFAULT,
+            rrb,
+            status_code: StatusCode::Ok,
+            stream_state: StreamState::Open,
+            waker: Waker::noop().clone(),
+            windows: local_windows,
+          },
+        ));
+      }
+      let mut local_lock_pin = pin!(hd.lock());
+      poll_fn(|cx| {
+        let mut lock = lock_pin!(cx, hd, local_lock_pin);
+        let hdpm = lock.parts_mut();
+        let sorp = sorp_mut(&mut hdpm.hb.sorp, *stream_id)?;
+        if let Some(elem) =
+          status_recv(is_conn_open, sorp, |local_sorp| Ok(local_sorp.status_code))?
+        {
+          return Poll::Ready(Ok(elem));
+        }
+        frame_reader_rslt(hdpm.frame_reader_error)?;
+        if sorp.has_initial_header {
+          return Poll::Ready(Ok(Http2RecvStatus::Ongoing(sorp.status_code)));
+        }
+        sorp.waker.clone_from(cx.waker());
+        Poll::Pending
+      })
+      .await
+    }
+    .await;
+    if let Err(err) = &rslt {
+      process_higher_operation_err(err, hd).await;
+    }
+    rslt
+  }
+
+  /// Send Priority
+  ///
+  /// Sends a PRIORITY frame that assigns a dependency and a weight to this stream. Should be called
+  /// after the sending of the initial headers because servers ignore the frames of idle streams.
+  #[inline]
+  pub async fn send_priority(&mut self, sp: StreamPriority) -> crate::Result<()> {
+    let _e = self.span.enter();
+    _trace!("Sending priority");
+    let pf = PriorityFrame::new(sp, self.stream_id)?;
+    let mut lock = self.hd.lock().await;
+    write_array([&pf.bytes()], &self.is_conn_open, lock.parts_mut().stream_writer).await
+  }
+
   /// Send Request
   ///
   /// Sends all data related to a request.
@@ -161,4 +421,155 @@ where
     )
     .await
   }
+
+  /// Send Streamed Request
+  ///
+  /// Similar to [`Self::send_req`] but the body is incrementally read from `body` into `buffer`.
+  /// Each chunk is sent through one or more DATA frames as soon as the flow control windows allow,
+  /// as such, large bodies don't need to be entirely stored in memory.
+  ///
+  /// Shouldn't be called more than once.
+  #[inline]
+  pub async fn send_req_stream<SR>(
+    &mut self,
+    (body, buffer): (&mut SR, &mut [u8]),
+    headers: &Headers,
+    method: Method,
+    uri: &UriRef<'_>,
+  ) -> crate::Result<Http2SendStatus>
+  where
+    SR: StreamReader,
+  {
+    let Self { hd, is_conn_open, span, stream_id, windows } = self;
+    let _e = span.enter();
+    _trace!("Sending streamed request");
+    let hsreqh = HpackStaticRequestHeaders {
+      authority: uri.authority(),
+      method: Some(method),
+      path: uri.relative_reference_slash(),
+      protocol: None,
+      scheme: uri.scheme(),
+    };
+    let hsresh = HpackStaticResponseHeaders::EMPTY;
+    let rslt = async {
+      {
+        let mut guard = hd.lock().await;
+        let hdpm = guard.parts_mut();
+        let Http2Buffer { hpack_enc, hpack_enc_buffer, scrp, .. } = hdpm.hb;
+        if !is_conn_open.load(Ordering::Relaxed) {
+          return Ok(Http2SendStatus::ClosedConnection);
+        }
+        let elem = scrp_mut(scrp, *stream_id)?;
+        if !elem.is_stream_open {
+          return Ok(Http2SendStatus::ClosedStream);
+        }
+        if !elem.stream_state.can_send::<true>() {
+          return Err(protocol_err(Http2Error::InvalidSendStreamState));
+        }
+        encode_headers::<true>(
+          headers,
+          (hpack_enc, hpack_enc_buffer),
+          (hsreqh, hsresh),
+          *stream_id,
+        )?;
+        let _ = write_standalone_headers::<_, true>(
+          hpack_enc_buffer,
+          (hsreqh, hsresh),
+          is_conn_open,
+          false,
+          hdpm.hps.max_frame_len,
+          hdpm.stream_writer,
+          *stream_id,
+        )
+        .await?;
+        elem.stream_state = StreamState::Open;
+      }
+      loop {
+        let read = body.read(buffer).await?;
+        if read == 0 {
+          break;
+        }
+        let mut data = SendDataMode::scattered_data_frames(buffer.get(..read).unwrap_or_default());
+        let mut has_data = false;
+        let mut lock_pin = pin!(hd.lock());
+        let status = poll_fn(|cx| -> Poll<crate::Result<Http2SendStatus>> {
+          let mut lock = lock_pin!(cx, hd, lock_pin);
+          let hdpm = lock.parts_mut();
+          if !is_conn_open.load(Ordering::Relaxed) {
+            return Poll::Ready(Ok(Http2SendStatus::ClosedConnection));
+          }
+          let elem = scrp_mut(&mut hdpm.hb.scrp, *stream_id)?;
+          if !elem.is_stream_open {
+            return Poll::Ready(Ok(Http2SendStatus::ClosedStream));
+          }
+          let mut wp = WindowsPair::new(hdpm.windows, &mut elem.windows);
+          if let Ok(available_send @ 1..=u32::MAX) = u32::try_from(wp.available_send()) {
+            let fut = write_standalone_data(
+              available_send,
+              &mut data,
+              false,
+              &mut has_data,
+              true,
+              is_conn_open,
+              hdpm.hps.max_frame_len,
+              hdpm.stream_writer,
+              *stream_id,
+              &mut wp,
+            );
+            let _ = ready!(pin!(fut).poll(cx))?;
+            if data.len() == 0 {
+              return Poll::Ready(Ok(Http2SendStatus::Ok));
+            }
+          }
+          cx.waker().wake_by_ref();
+          Poll::Pending
+        })
+        .await?;
+        if status.is_closed() {
+          return Ok(status);
+        }
+      }
+      let mut guard = hd.lock().await;
+      let hdpm = guard.parts_mut();
+      scrp_mut(&mut hdpm.hb.scrp, *stream_id)?.stream_state = StreamState::HalfClosedLocal;
+      write_eos_data_frame(is_conn_open, hdpm.stream_writer, *stream_id).await?;
+      if let Some(scrp) = hdpm.hb.scrp.remove(stream_id) {
+        *windows = scrp.windows;
+      }
+      Ok(Http2SendStatus::Ok)
+    }
+    .await;
+    if let Err(err) = &rslt {
+      process_higher_operation_err(err, hd).await;
+    }
+    rslt
+  }
+}
+
+impl<HD> Http2Stream<true> for ClientStream<HD> {
+  type HD = HD;
+
+  #[inline]
+  fn common(&mut self) -> CommonStream<'_, HD, true> {
+    CommonStream {
+      hd: &mut self.hd,
+      is_conn_open: &self.is_conn_open,
+      span: &mut self.span,
+      stream_id: self.stream_id,
+    }
+  }
+}
+
+impl<HD> Lease<ClientStream<HD>> for ClientStream<HD> {
+  #[inline]
+  fn lease(&self) -> &ClientStream<HD> {
+    self
+  }
+}
+
+impl<HD> LeaseMut<ClientStream<HD>> for ClientStream<HD> {
+  #[inline]
+  fn lease_mut(&mut self) -> &mut ClientStream<HD> {
+    self
+  }
 }
@@ -3,7 +3,10 @@ use crate::{
   http2::{
     Http2Buffer, Http2Data, Http2RecvStatus, Http2SendStatus, SendDataMode,
     hpack_static_headers::{HpackStaticRequestHeaders, HpackStaticResponseHeaders},
-    misc::{check_content_length, frame_reader_rslt, sorp_mut, status_recv, status_send},
+    misc::{
+      check_content_length, frame_reader_rslt, send_large_body_res, sorp_mut, status_recv,
+      status_send,
+    },
     send_data_mode::SendDataModeBytes,
     send_msg::{
       encode_headers, write_standalone_data, write_standalone_headers, write_standalone_trailers,
@@ -62,32 +65,44 @@ where
   ///
   /// Low level operation that retrieves a DATA frame sent by the remote peer. Shouldn't interact
   /// with higher operations that receive data.
+  ///
+  /// Servers automatically respond with `413 Payload Too Large` when the sum of all received
+  /// frames exceeds the maximum allowed length. In such a scenario, the returned status is
+  /// [`Http2RecvStatus::ClosedStream`].
   #[inline]
   pub async fn recv_data(&mut self) -> crate::Result<Http2RecvStatus<Vector<u8>, Vector<u8>>> {
     let _e = self.span.enter();
     _trace!("Fetching data");
     let mut pin = pin!(self.hd.lock());
-    poll_fn(|cx| {
+    let rslt = poll_fn::<crate::Result<_>, _>(|cx| {
       let mut lock = lock_pin!(cx, self.hd, pin);
       let hdpm = lock.parts_mut();
       let sorp = sorp_mut(&mut hdpm.hb.sorp, self.stream_id)?;
+      if sorp.has_large_body && sorp.is_stream_open {
+        return Poll::Ready(Ok(None));
+      }
       if let Some(elem) = status_recv(self.is_conn_open, sorp, |local_sorp| {
         check_content_length(local_sorp)?;
         Ok(mem::take(&mut local_sorp.rrb.body))
       })? {
-        return Poll::Ready(Ok(elem));
+        return Poll::Ready(Ok(Some(elem)));
       }
       if sorp.has_one_or_more_data_frames && !sorp.rrb.body.is_empty() {
         frame_reader_rslt(hdpm.frame_reader_error)?;
         let rslt = sorp.rrb.body.clone();
         sorp.rrb.body.clear();
-        Poll::Ready(Ok(Http2RecvStatus::Ongoing(rslt)))
+        Poll::Ready(Ok(Some(Http2RecvStatus::Ongoing(rslt))))
       } else {
         sorp.waker.clone_from(cx.waker());
         Poll::Pending
       }
     })
-    .await
+    .await?;
+    if let Some(elem) = rslt {
+      return Ok(elem);
+    }
+    let _rrb = send_large_body_res(self.hd, self.is_conn_open, self.stream_id).await?;
+    Ok(Http2RecvStatus::ClosedStream)
   }
 
   /// Receive Trailers
@@ -179,12 +194,13 @@ where
         cx.waker().wake_by_ref();
         return Poll::Pending;
       };
+      // The end-of-stream flag is only set in the last frame, which is dictated by `is_eos`.
       let fut = write_standalone_data(
         available_send,
         &mut data,
-        is_eos,
-        &mut has_data,
         false,
+        &mut has_data,
+        !is_eos,
         self.is_conn_open,
         hdpm.hps.max_frame_len,
         hdpm.stream_writer,
@@ -230,6 +246,7 @@ where
       headers,
       (&mut hdpm.hb.hpack_enc, &mut hdpm.hb.hpack_enc_buffer),
       (HpackStaticRequestHeaders::EMPTY, hsreh),
+      self.stream_id,
     )?;
     let _ = write_standalone_headers::<_, IS_CLIENT>(
       &mut hdpm.hb.hpack_enc_buffer,
@@ -241,6 +258,7 @@ where
       self.stream_id,
     )
     .await?;
+    sorp.has_sent_headers = true;
     Ok(Http2SendStatus::Ok)
   }
 
@@ -249,6 +267,7 @@ where
   pub async fn send_reset(&self, error_code: crate::http2::Http2ErrorCode) {
     let mut guard = self.hd.lock().await;
     let hdpm = guard.parts_mut();
+    crate::http2::misc::push_reset_stream(&mut hdpm.hb.reset_streams, self.stream_id);
     let _ = crate::http2::misc::send_reset_stream(
       error_code,
       &mut hdpm.hb.scrp,
@@ -2,7 +2,7 @@ use crate::http2::{
   Http2Error,
   common_flags::CommonFlags,
   frame_init::{FrameInit, FrameInitTy},
-  misc::{protocol_err, trim_frame_pad},
+  misc::protocol_err,
   u31::U31,
 };
 
@@ -35,17 +35,21 @@ impl DataFrame {
     self.cf.has_eos()
   }
 
+  // The payload itself is not handled here because it is transferred directly from the stream.
   #[inline]
-  pub(crate) fn read(mut data: &[u8], mut fi: FrameInit) -> crate::Result<(Self, &[u8])> {
+  pub(crate) fn read(mut fi: FrameInit, pad_len: Option<u8>) -> crate::Result<Self> {
     if fi.stream_id.is_zero() {
       return Err(protocol_err(Http2Error::InvalidDataFrameZeroId));
     }
     fi.cf.only_eos_pad();
-    let pad_len = trim_frame_pad(fi.cf, &mut data)?;
-    let Ok(data_len) = u32::try_from(data.len()).map(U31::from_u32) else {
-      return Err(protocol_err(Http2Error::InvalidDataFrameDataLen));
+    let data_len = match pad_len {
+      Some(elem) => fi.data_len.checked_sub(1).and_then(|el| el.checked_sub(u32::from(elem))),
+      None => Some(fi.data_len),
     };
-    Ok((Self { cf: fi.cf, data_len, pad_len, stream_id: fi.stream_id }, data))
+    let Some(data_len) = data_len else {
+      return Err(protocol_err(Http2Error::InvalidFramePad));
+    };
+    Ok(Self { cf: fi.cf, data_len: U31::from_u32(data_len), pad_len, stream_id: fi.stream_id })
   }
 
   #[inline]
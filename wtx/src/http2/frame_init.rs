@@ -5,6 +5,7 @@ create_enum! {
   pub(crate) enum FrameInitTy<u8> {
     Data = (0),
     Headers = (1),
+    Priority = (2),
     Reset = (3),
     Settings = (4),
     Ping = (6),
@@ -20,6 +21,7 @@ impl FrameInitTy {
     match self {
       Self::Data => 0,
       Self::Headers => 1,
+      Self::Priority => 2,
       Self::Reset => 3,
       Self::Settings => 4,
       Self::Ping => 6,
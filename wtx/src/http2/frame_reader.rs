@@ -11,6 +11,7 @@ macro_rules! prft {
       pfb: $pfb,
       read_frame_waker: &$hdpm.hb.read_frame_waker,
       recv_streams_num: &mut $hdpm.recv_streams_num,
+      reset_streams: &$hdpm.hb.reset_streams,
       stream_reader: $stream_reader,
       stream_writer: &mut $hdpm.stream_writer,
       uri_buffer: &mut $hdpm.hb.uri_buffer,
@@ -21,6 +22,7 @@ macro_rules! prft {
 use crate::{
   http2::{
     Http2Buffer, Http2Data, Http2Error,
+    data_frame::DataFrame,
     frame_init::{FrameInit, FrameInitTy},
     go_away_frame::GoAwayFrame,
     misc::{process_higher_operation_err, protocol_err, read_frame, send_go_away, write_array},
@@ -29,8 +31,11 @@ use crate::{
     settings_frame::SettingsFrame,
     window_update_frame::WindowUpdateFrame,
   },
-  misc::{LeaseMut, Lock, RefCounter, StreamReader, StreamWriter, net::PartitionedFilledBuffer},
-  sync::{Arc, AtomicBool, AtomicWaker},
+  misc::{
+    BufferMode, LeaseMut, Lock, RefCounter, StreamReader, StreamWriter, Usize,
+    net::{PartitionedFilledBuffer, read_payload_into, skip_payload},
+  },
+  sync::{Arc, AtomicBool, AtomicWaker, Ordering},
 };
 use core::{
   future::poll_fn,
@@ -55,6 +60,8 @@ pub(crate) async fn frame_reader<HB, HD, SR, SW, const IS_CLIENT: bool>(
 {
   let span = _trace_span!("Starting the reading of frames");
   let _e = span.enter();
+  #[cfg(feature = "metrics")]
+  let metrics = hd.lock().await.parts_mut().hp.metrics().cloned();
   loop {
     let fi = match read_frame::<_, false>(
       &is_conn_open,
@@ -76,8 +83,17 @@ pub(crate) async fn frame_reader<HB, HD, SR, SW, const IS_CLIENT: bool>(
       }
       Ok(Some(fi)) => fi,
     };
+    #[cfg(feature = "metrics")]
+    if let Some(elem) = &metrics {
+      elem.frame_received(fi.data_len);
+    }
     if let Err(err) = manage_fi(fi, &hd, &is_conn_open, &mut pfb, &mut stream_reader).await {
       process_higher_operation_err(&err, &hd).await;
+      // Stream errors are already handled by the reset of the stream and the connection can
+      // still be used.
+      if let crate::Error::Http2ErrorReset(..) = err {
+        continue;
+      }
       finish(Some(err), &hd, &mut pfb).await;
     }
   }
@@ -103,6 +119,12 @@ async fn finish<HB, HD, SW, const IS_CLIENT: bool>(
   _trace!("Finishing the reading of frames");
 }
 
+// Initial headers that only reset their streams don't consume the pending `Http2::stream` call.
+#[inline]
+fn is_stream_err(rslt: &crate::Result<()>) -> bool {
+  matches!(rslt, Err(crate::Error::Http2ErrorReset(..)))
+}
+
 #[inline]
 async fn manage_fi<HB, HD, SR, SW, const IS_CLIENT: bool>(
   fi: FrameInit,
@@ -123,9 +145,51 @@ where
       return Err(protocol_err(Http2Error::InvalidContinuationFrame));
     }
     FrameInitTy::Data => {
+      // Payloads are read without holding the lock, otherwise a peer that stalls in the middle
+      // of a frame would block every other writer of the connection.
+      let pad_len = if fi.cf.has_pad() {
+        let mut array = [0; 1];
+        read_payload_into(&mut array, pfb, stream_reader).await?;
+        let [local_pad_len] = array;
+        Some(local_pad_len)
+      } else {
+        None
+      };
+      let df = DataFrame::read(fi, pad_len)?;
+      let mut body = {
+        let mut lock_pin = pin!(hd.lock());
+        poll_fn(|cx| {
+          let mut local_lock = lock_pin!(cx, hd, lock_pin);
+          let mut local_hdpm = local_lock.parts_mut();
+          // Servers only process the body after `Http2::stream` receives the initial headers,
+          // which can override the maximum body length of the stream.
+          if !IS_CLIENT
+            && is_conn_open.load(Ordering::Relaxed)
+            && local_hdpm.hb.initial_server_headers.iter().any(|el| el.1.stream_id == fi.stream_id)
+          {
+            local_hdpm.hb.read_frame_waker.register(cx.waker());
+            return Poll::Pending;
+          }
+          let prft = prft!(fi, local_hdpm, pfb, stream_reader);
+          Poll::Ready(prft.data_init::<IS_CLIENT>(&mut local_hdpm.hb.sorp))
+        })
+        .await?
+      };
+      let data_len = *Usize::from(df.data_len().u32());
+      if let Some(elem) = &mut body {
+        let body_len = elem.len();
+        elem.expand(BufferMode::Additional(data_len), 0)?;
+        let body_bytes = elem.get_mut(body_len..).unwrap_or_default();
+        read_payload_into(body_bytes, pfb, stream_reader).await?;
+      } else {
+        skip_payload(data_len, pfb, stream_reader).await?;
+      }
+      if let Some(local_pad_len) = pad_len {
+        skip_payload(usize::from(local_pad_len), pfb, stream_reader).await?;
+      }
       let mut lock = hd.lock().await;
       let mut hdpm = lock.parts_mut();
-      prft!(fi, hdpm, pfb, stream_reader).data(&mut hdpm.hb.sorp).await?;
+      prft!(fi, hdpm, pfb, stream_reader).data_finish(body, &df, &mut hdpm.hb.sorp).await?;
     }
     FrameInitTy::GoAway => {
       let gaf = GoAwayFrame::read(pfb._current(), fi)?;
@@ -144,8 +208,10 @@ where
       } else if let Some(ish) = hdpm.hb.initial_server_headers.front_mut() {
         let prft = prft!(fi, hdpm, pfb, stream_reader);
         let rslt = prft.header_server_init(ish, &mut hdpm.hb.sorp).await;
-        ish.waker.wake_by_ref();
-        hdpm.hb.initial_server_headers.increase_cursor();
+        if !is_stream_err(&rslt) {
+          ish.waker.wake_by_ref();
+          hdpm.hb.initial_server_headers.increase_cursor();
+        }
         rslt?;
       } else {
         drop(lock);
@@ -160,8 +226,10 @@ where
           let prft = prft!(fi, local_hdpm, pfb, stream_reader);
           let poll = pin!(prft.header_server_init(ish, &mut local_hdpm.hb.sorp)).poll(cx);
           let rslt = ready!(poll);
-          ish.waker.wake_by_ref();
-          local_hdpm.hb.initial_server_headers.increase_cursor();
+          if !is_stream_err(&rslt) {
+            ish.waker.wake_by_ref();
+            local_hdpm.hb.initial_server_headers.increase_cursor();
+          }
           Poll::Ready(rslt)
         })
         .await?;
@@ -174,6 +242,12 @@ where
         write_array([&pf.bytes()], is_conn_open, hd.lock().await.parts_mut().stream_writer).await?;
       }
     }
+    FrameInitTy::Priority => {
+      let mut lock = hd.lock().await;
+      let mut hdpm = lock.parts_mut();
+      let prft = prft!(fi, hdpm, pfb, stream_reader);
+      prft.priority(&mut hdpm.hb.scrp, &mut hdpm.hb.sorp)?;
+    }
     FrameInitTy::Reset => {
       let mut lock = hd.lock().await;
       let mut hdpm = lock.parts_mut();
@@ -1,7 +1,7 @@
 use crate::{
   http::{Header, KnownHeaderName, Method, ReqResBuffer},
   http2::{
-    Http2Error, Http2Params,
+    Http2Error, Http2Params, StreamPriority,
     common_flags::CommonFlags,
     frame_init::{FrameInit, FrameInitTy},
     hpack_decoder::HpackDecoder,
@@ -21,6 +21,7 @@ pub(crate) struct HeadersFrame<'uri> {
   hsreqh: HpackStaticRequestHeaders<'uri>,
   hsresh: HpackStaticResponseHeaders,
   is_over_size: bool,
+  priority: Option<StreamPriority>,
   stream_id: U31,
 }
 
@@ -30,7 +31,14 @@ impl<'uri> HeadersFrame<'uri> {
     (hsreqh, hsresh): (HpackStaticRequestHeaders<'uri>, HpackStaticResponseHeaders),
     stream_id: U31,
   ) -> Self {
-    Self { cf: CommonFlags::empty(), hsreqh, hsresh, is_over_size: false, stream_id }
+    Self {
+      cf: CommonFlags::empty(),
+      hsreqh,
+      hsresh,
+      is_over_size: false,
+      priority: None,
+      stream_id,
+    }
   }
 
   #[inline]
@@ -58,6 +66,11 @@ impl<'uri> HeadersFrame<'uri> {
     self.is_over_size
   }
 
+  #[inline]
+  pub(crate) const fn priority(&self) -> Option<StreamPriority> {
+    self.priority
+  }
+
   #[expect(clippy::too_many_lines, reason = "variables are highly coupled")]
   #[inline]
   pub(crate) fn read<const IS_CLIENT: bool, const IS_TRAILER: bool>(
@@ -79,7 +92,7 @@ impl<'uri> HeadersFrame<'uri> {
     let (rrb_body, rrb_headers, rrb_uri) = (&lease.body, &mut lease.headers, &mut lease.uri);
     let mut data_bytes = data.unwrap_or_else(|| rrb_body.get(rrb_body_start..).unwrap_or_default());
     let _ = trim_frame_pad(fi.cf, &mut data_bytes)?;
-    trim_priority(fi.cf, &mut data_bytes);
+    let priority = trim_priority(fi.cf, &mut data_bytes);
     let max_headers_len = *Usize::from(hp.max_headers_len());
     let mut content_length = None;
     let mut expanded_headers_len = 0;
@@ -219,13 +232,21 @@ impl<'uri> HeadersFrame<'uri> {
         if status.is_some() {
           return Err(protocol_err(Http2Error::InvalidHeaderData));
         }
-        if let Some(Method::Connect) = method {
-          if uri_buffer.authority.is_empty() {
-            return Err(protocol_err(Http2Error::InvalidHeaderData));
-          }
-        } else if uri_buffer.path.is_empty() || uri_buffer.scheme.is_empty() {
+        let (has_authority, has_path, has_scheme) = (
+          !uri_buffer.authority.is_empty(),
+          !uri_buffer.path.is_empty(),
+          !uri_buffer.scheme.is_empty(),
+        );
+        let is_valid = match (method, protocol) {
+          // Extended CONNECT (RFC 8441) carries the same pseudo-headers of a regular request.
+          (Some(Method::Connect), Some(_)) => has_authority && has_path && has_scheme,
+          // Regular CONNECT (RFC 9113) only carries the authority.
+          (Some(Method::Connect), None) => has_authority && !has_path && !has_scheme,
+          (_, Some(_)) => false,
+          (_, None) => has_path && has_scheme,
+        };
+        if !is_valid {
           return Err(protocol_err(Http2Error::InvalidHeaderData));
-        } else {
         }
         rrb_uri.reset(|buffer| {
           buffer.reserve(*Usize::from(
@@ -252,6 +273,7 @@ impl<'uri> HeadersFrame<'uri> {
         hsreqh: HpackStaticRequestHeaders { authority: "", method, path: "", protocol, scheme: "" },
         hsresh: HpackStaticResponseHeaders { status_code: status },
         is_over_size,
+        priority,
         stream_id: fi.stream_id,
       },
     ))
@@ -319,11 +341,14 @@ fn push_uri<const N: usize>(
 }
 
 #[inline]
-pub(crate) fn trim_priority(cf: CommonFlags, data: &mut &[u8]) {
+pub(crate) fn trim_priority(cf: CommonFlags, data: &mut &[u8]) -> Option<StreamPriority> {
   if cf.has_pri() {
-    let [_, _, _, _, _, rest @ ..] = data else {
-      return;
+    let [a, b, c, d, e, rest @ ..] = data else {
+      return None;
     };
+    let sp = StreamPriority::from_bytes([*a, *b, *c, *d, *e]);
     *data = rest;
+    return Some(sp);
   }
+  None
 }
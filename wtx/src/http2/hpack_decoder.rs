@@ -379,7 +379,7 @@ impl HpackDecoder {
         if local_max_bytes > self.max_bytes.0 {
           return Err(crate::Error::Http2ErrorGoAway(
             Http2ErrorCode::CompressionError,
-            Some(Http2Error::OutOfBoundsIndex),
+            Some(Http2Error::VeryLargeHpackSizeUpdate),
           ));
         }
         self.dyn_headers.set_max_bytes(*Usize::from(local_max_bytes), |_| {});
@@ -420,3 +420,25 @@ impl TryFrom<u8> for DecodeIdx {
     })
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::http2::{Http2Error, Http2ErrorCode, hpack_decoder::HpackDecoder};
+
+  #[test]
+  fn very_large_hpack_size_update() {
+    let mut hd = HpackDecoder::new();
+    hd.set_max_bytes(4096);
+    // Size update of 4096 bytes
+    hd.decode(&[0x3f, 0xe1, 0x1f], |_| Ok(())).unwrap();
+    // Size update of 4097 bytes
+    let err = hd.decode(&[0x3f, 0xe2, 0x1f], |_| Ok(())).unwrap_err();
+    assert!(matches!(
+      err,
+      crate::Error::Http2ErrorGoAway(
+        Http2ErrorCode::CompressionError,
+        Some(Http2Error::VeryLargeHpackSizeUpdate)
+      )
+    ));
+  }
+}
@@ -11,8 +11,8 @@
 use crate::{
   http::{Header, KnownHeaderName, Method, StatusCode},
   http2::{
-    Http2Error, hpack_header::HpackHeaderBasic, hpack_headers::HpackHeaders,
-    huffman::huffman_encode, misc::protocol_err,
+    Http2Error, Http2ErrorCode, hpack_header::HpackHeaderBasic, hpack_headers::HpackHeaders,
+    huffman::huffman_encode, misc::protocol_err, u31::U31,
   },
   misc::{
     _random_state, Rng, Usize, Vector, bytes_transfer::shift_copyable_chunks, hints::_unreachable,
@@ -36,6 +36,8 @@ pub(crate) struct HpackEncoder {
   max_dyn_sub_bytes: Option<(u32, Option<u32>)>,
   // Defined by the system.
   max_dyn_super_bytes: u32,
+  // Defined by external actors.
+  max_headers_len: u32,
   rs: FixedState,
 }
 
@@ -51,17 +53,49 @@ impl HpackEncoder {
       indcs: HashMap::new(),
       max_dyn_sub_bytes: None,
       max_dyn_super_bytes: 0,
+      max_headers_len: u32::MAX,
       rs: _random_state(rng),
     }
   }
 
   #[inline]
   pub(crate) fn clear(&mut self) {
-    let Self { dyn_headers, idx, indcs, max_dyn_sub_bytes, max_dyn_super_bytes: _, rs: _ } = self;
+    let Self {
+      dyn_headers,
+      idx,
+      indcs,
+      max_dyn_sub_bytes,
+      max_dyn_super_bytes: _,
+      max_headers_len,
+      rs: _,
+    } = self;
     dyn_headers.clear();
     *idx = 0;
     indcs.clear();
     *max_dyn_sub_bytes = None;
+    *max_headers_len = u32::MAX;
+  }
+
+  // Must be called before `encode` because an oversized block should not modify the dynamic
+  // table. Only the stream of the block is affected because the connection is still valid.
+  #[inline]
+  pub(crate) fn check_headers_len<'user>(
+    &self,
+    pseudo_headers_len: usize,
+    stream_id: U31,
+    user_headers: impl IntoIterator<Item = Header<'user, &'user str>>,
+  ) -> crate::Result<()> {
+    let len = user_headers.into_iter().fold(pseudo_headers_len, |acc, el| {
+      acc.wrapping_add(HpackHeaderBasic::Field.len(el.name, el.value))
+    });
+    if len > *Usize::from(self.max_headers_len) {
+      return Err(crate::Error::Http2ErrorReset(
+        Http2ErrorCode::InternalError,
+        Some(Http2Error::VeryLargeLocalHeadersLen),
+        stream_id.u32(),
+      ));
+    }
+    Ok(())
   }
 
   #[inline]
@@ -84,8 +118,10 @@ impl HpackEncoder {
     buffer.reserve(reserve)?;
     self.manage_size_update(buffer)?;
     for (hhb, value) in pseudo_headers_iter {
-      let idx = self.encode_idx(("", value, false), hhb, Self::shi_pseudo((hhb, value)))?;
-      Self::manage_encode(buffer, ("", value), idx)?;
+      // `:protocol` is not part of the static table so its name must be sent literally.
+      let name = hhb.pseudo_name();
+      let idx = self.encode_idx((name, value, false), hhb, Self::shi_pseudo((hhb, value)))?;
+      Self::manage_encode(buffer, (name, value), idx)?;
     }
     for Header { is_sensitive, name, value, .. } in user_headers_iter {
       let idx = self.encode_idx(
@@ -98,6 +134,18 @@ impl HpackEncoder {
     Ok(())
   }
 
+  #[inline]
+  pub(crate) fn max_dyn_super_bytes(&self) -> u32 {
+    self.max_dyn_super_bytes
+  }
+
+  #[inline]
+  pub(crate) fn pseudo_headers_len<'pseudo>(
+    pseudo_headers: impl IntoIterator<Item = (HpackHeaderBasic, &'pseudo str)>,
+  ) -> usize {
+    pseudo_headers.into_iter().fold(0, |acc, (hhb, value)| acc.wrapping_add(hhb.len("", value)))
+  }
+
   #[inline]
   pub(crate) fn reserve(&mut self, headers: usize, bytes: usize) -> crate::Result<()> {
     self.dyn_headers.reserve(headers, bytes)
@@ -129,6 +177,11 @@ impl HpackEncoder {
     Ok(())
   }
 
+  #[inline]
+  pub(crate) fn set_max_headers_len(&mut self, max_headers_len: u32) {
+    self.max_headers_len = max_headers_len;
+  }
+
   pub(crate) fn set_max_dyn_super_bytes(&mut self, max_dyn_super_bytes: u32) {
     self.max_dyn_sub_bytes = None;
     self.max_dyn_super_bytes = max_dyn_super_bytes;
@@ -469,7 +522,7 @@ impl HpackEncoder {
         (has_value, idx, name)
       }
       HpackHeaderBasic::Scheme => {
-        let name = ":path";
+        let name = ":scheme";
         let (has_value, idx) = match value {
           "http" => (true, 6),
           "https" => (true, 7),
@@ -651,3 +704,35 @@ struct StaticHeader {
   idx: u32,
   name: &'static str,
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::Header,
+    http2::{
+      Http2Error, Http2ErrorCode, hpack_encoder::HpackEncoder, hpack_header::HpackHeaderBasic,
+      u31::U31,
+    },
+    misc::{Vector, Xorshift64, simple_seed},
+  };
+
+  #[test]
+  fn very_large_local_headers_len() {
+    let mut buffer = Vector::new();
+    let mut he = HpackEncoder::new(&mut Xorshift64::from(simple_seed()));
+    he.set_max_headers_len(150);
+    let header = Header::from_name_and_value("foo", "0123456789012345678901234567890123456789");
+    let pseudo_headers_len = HpackHeaderBasic::Path.len("", "/");
+    he.check_headers_len(pseudo_headers_len, U31::ONE, [header]).unwrap();
+    he.encode(&mut buffer, [(HpackHeaderBasic::Path, "/")], [header]).unwrap();
+    let err = he.check_headers_len(pseudo_headers_len, U31::ONE, [header, header]).unwrap_err();
+    assert!(matches!(
+      err,
+      crate::Error::Http2ErrorReset(
+        Http2ErrorCode::InternalError,
+        Some(Http2Error::VeryLargeLocalHeadersLen),
+        1
+      )
+    ));
+  }
+}
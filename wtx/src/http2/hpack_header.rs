@@ -15,6 +15,19 @@ pub(crate) enum HpackHeaderBasic {
 }
 
 impl HpackHeaderBasic {
+  /// Name of a pseudo-header or an empty string for user fields.
+  pub(crate) const fn pseudo_name(self) -> &'static str {
+    match self {
+      HpackHeaderBasic::Authority => ":authority",
+      HpackHeaderBasic::Field => "",
+      HpackHeaderBasic::Method(_) => ":method",
+      HpackHeaderBasic::Path => ":path",
+      HpackHeaderBasic::Protocol(_) => ":protocol",
+      HpackHeaderBasic::Scheme => ":scheme",
+      HpackHeaderBasic::StatusCode(_) => ":status",
+    }
+  }
+
   pub(crate) const fn len(self, name: &str, value: &str) -> usize {
     match self {
       HpackHeaderBasic::Authority => 10usize.wrapping_add(value.len()).wrapping_add(32),
@@ -21,8 +21,8 @@ impl HpackStaticRequestHeaders<'_> {
   pub(crate) fn iter(&self) -> impl Iterator<Item = (HpackHeaderBasic, &str)> {
     let Self { authority, method, path, protocol, scheme } = *self;
     let enums = [
-      method.map(|el| (HpackHeaderBasic::Method(el), "")),
-      protocol.map(|el| (HpackHeaderBasic::Protocol(el), "")),
+      method.map(|el| (HpackHeaderBasic::Method(el), el.strings().custom[0])),
+      protocol.map(|el| (HpackHeaderBasic::Protocol(el), el.strings().custom[0])),
     ]
     .into_iter()
     .flatten();
@@ -48,6 +48,6 @@ impl HpackStaticResponseHeaders {
 
   pub(crate) fn iter(&self) -> impl Iterator<Item = (HpackHeaderBasic, &str)> {
     let Self { status_code } = *self;
-    status_code.map(|el| (HpackHeaderBasic::StatusCode(el), "")).into_iter()
+    status_code.map(|el| (HpackHeaderBasic::StatusCode(el), el.strings().number)).into_iter()
   }
 }
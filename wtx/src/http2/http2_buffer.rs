@@ -1,9 +1,9 @@
 use crate::{
   http2::{
     Scrp, Sorp, hpack_decoder::HpackDecoder, hpack_encoder::HpackEncoder, index_map::IndexMap,
-    initial_server_header::InitialServerHeader, uri_buffer::UriBuffer,
+    initial_server_header::InitialServerHeader, u31::U31, uri_buffer::UriBuffer,
   },
-  misc::{Lease, LeaseMut, Rng, Vector, net::PartitionedFilledBuffer, simple_seed},
+  misc::{Deque, Lease, LeaseMut, Rng, Vector, net::PartitionedFilledBuffer, simple_seed},
   sync::{Arc, AtomicBool, AtomicWaker, Ordering},
 };
 use alloc::boxed::Box;
@@ -19,6 +19,7 @@ pub struct Http2Buffer {
   pub(crate) is_conn_open: Arc<AtomicBool>,
   pub(crate) pfb: PartitionedFilledBuffer,
   pub(crate) read_frame_waker: Arc<AtomicWaker>,
+  pub(crate) reset_streams: Deque<U31>,
   pub(crate) scrp: Scrp,
   pub(crate) sorp: Sorp,
   pub(crate) uri_buffer: Box<UriBuffer>,
@@ -39,6 +40,7 @@ impl Http2Buffer {
       is_conn_open: Arc::new(AtomicBool::new(false)),
       pfb: PartitionedFilledBuffer::new(),
       read_frame_waker: Arc::new(AtomicWaker::new()),
+      reset_streams: Deque::new(),
       scrp: HashMap::new(),
       sorp: HashMap::new(),
       uri_buffer: Box::new(UriBuffer::new()),
@@ -55,6 +57,7 @@ impl Http2Buffer {
       is_conn_open,
       pfb,
       read_frame_waker,
+      reset_streams,
       scrp,
       sorp,
       uri_buffer,
@@ -66,6 +69,7 @@ impl Http2Buffer {
     is_conn_open.store(false, Ordering::Relaxed);
     pfb._clear();
     let _waker = read_frame_waker.take();
+    reset_streams.clear();
     scrp.clear();
     sorp.clear();
     uri_buffer.clear();
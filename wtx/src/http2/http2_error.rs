@@ -26,6 +26,8 @@ pub enum Http2Error {
   ExceedAmountOfOpenedStreams,
   /// The number of active concurrent streams extrapolated the threshold
   ExceedAmountOfActiveConcurrentStreams,
+  /// The server didn't allow the tunneling of protocols through Extended CONNECT requests.
+  ExtendedConnectIsDisabled,
   /// Frame has a zero stream ID but shouldn't because of its type.
   FrameIdIsZeroButShouldNot,
   /// The system only supports 2 header frames when sending data
@@ -60,6 +62,12 @@ pub enum Http2Error {
   InvalidPingFrameBytes,
   #[doc = stream_id_must_be_zero!()]
   InvalidPingFrameNonZeroId,
+  /// A stream can not depend on itself
+  InvalidPriorityDependency,
+  #[doc = invalid_frame_bytes!()]
+  InvalidPriorityFrameBytes,
+  #[doc = stream_id_must_not_be_zero!()]
+  InvalidPriorityFrameZeroId,
   /// Invalid frame after received EOS
   InvalidReceivedFrameAfterEos,
   #[doc = invalid_frame_bytes!()]
@@ -152,8 +160,13 @@ pub enum Http2Error {
   VeryLargeAmountOfFrameMismatches,
   /// Header integers must be equal or lesser than `u16::MAX`
   VeryLargeHeaderInteger,
-  /// Received headers is too large to sent
+  /// Received headers are greater than the locally advertised `SETTINGS_MAX_HEADER_LIST_SIZE`
   VeryLargeHeadersLen,
+  /// Received HPACK size update is greater than the locally advertised
+  /// `SETTINGS_HEADER_TABLE_SIZE`
+  VeryLargeHpackSizeUpdate,
+  /// Local headers are greater than the `SETTINGS_MAX_HEADER_LIST_SIZE` of the counterpart
+  VeryLargeLocalHeadersLen,
   /// Windows size can not be reduced
   WindowSizeCanNotBeReduced,
 }
@@ -0,0 +1,76 @@
+use crate::{
+  metrics::{Counter, push_metric_header},
+  misc::Vector,
+};
+
+/// Metrics of HTTP/2 connections that share the same instance through
+/// [`crate::http2::Http2Params::set_metrics`].
+///
+/// Only received frames are taken into consideration, which includes frames of any stream as
+/// well as control frames.
+#[derive(Debug)]
+pub struct Http2Metrics {
+  bytes_received: Counter,
+  frames_received: Counter,
+  streams_opened: Counter,
+}
+
+impl Http2Metrics {
+  /// Instance without any recorded event.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      bytes_received: Counter::new(),
+      frames_received: Counter::new(),
+      streams_opened: Counter::new(),
+    }
+  }
+
+  /// Number of received bytes, including the 9 bytes of each frame header.
+  #[inline]
+  pub fn bytes_received(&self) -> &Counter {
+    &self.bytes_received
+  }
+
+  /// Number of received frames.
+  #[inline]
+  pub fn frames_received(&self) -> &Counter {
+    &self.frames_received
+  }
+
+  /// Writes all metrics into `buffer` using the text exposition format.
+  #[inline]
+  pub fn render(&self, buffer: &mut Vector<u8>) -> crate::Result<()> {
+    push_metric_header(buffer, "http2_bytes_received_total", "Received bytes.", "counter")?;
+    self.bytes_received.push(buffer, "http2_bytes_received_total", "")?;
+    push_metric_header(buffer, "http2_frames_received_total", "Received frames.", "counter")?;
+    self.frames_received.push(buffer, "http2_frames_received_total", "")?;
+    push_metric_header(
+      buffer,
+      "http2_streams_opened_total",
+      "Locally or remotely initiated streams.",
+      "counter",
+    )?;
+    self.streams_opened.push(buffer, "http2_streams_opened_total", "")?;
+    Ok(())
+  }
+
+  /// Number of locally or remotely initiated streams.
+  #[inline]
+  pub fn streams_opened(&self) -> &Counter {
+    &self.streams_opened
+  }
+
+  #[inline]
+  pub(crate) fn frame_received(&self, data_len: u32) {
+    self.bytes_received.add(u64::from(data_len).wrapping_add(9));
+    self.frames_received.inc();
+  }
+}
+
+impl Default for Http2Metrics {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -1,8 +1,14 @@
+#[cfg(feature = "std")]
+use crate::http::ReqResBufferPool;
+#[cfg(feature = "metrics")]
+use crate::http2::Http2Metrics;
 use crate::http2::{
   MAX_BODY_LEN, MAX_CONCURRENT_STREAMS_NUM, MAX_FRAME_LEN, MAX_FRAME_LEN_LOWER_BOUND,
   MAX_FRAME_LEN_UPPER_BOUND, MAX_HEADERS_LEN, MAX_HPACK_LEN, MAX_RECV_STREAMS_NUM, READ_BUFFER_LEN,
   settings_frame::SettingsFrame, u31::U31,
 };
+#[cfg(any(feature = "metrics", feature = "std"))]
+use crate::sync::Arc;
 
 /// Indicates to a remote peer the receiving parameters of a connection as well as its streams.
 ///
@@ -17,7 +23,11 @@ pub struct Http2Params {
   max_headers_len: u32,
   max_hpack_len: (u32, u32),
   max_recv_streams_num: u32,
+  #[cfg(feature = "metrics")]
+  metrics: Option<Arc<Http2Metrics>>,
   read_buffer_len: u32,
+  #[cfg(feature = "std")]
+  rrb_pool: Option<Arc<ReqResBufferPool>>,
 }
 
 impl Http2Params {
@@ -72,6 +82,9 @@ impl Http2Params {
   /// The final Request/Response header is composed by the sum of headers and trailers. Contents
   /// may or may not originate from the HPACK structure that holds cached decoded headers.
   ///
+  /// Local headers, on the other hand, are verified against the value advertised by the
+  /// counterpart.
+  ///
   /// Corresponds to `SETTINGS_MAX_HEADER_LIST_SIZE`. Defaults to
   #[doc = concat!(max_headers_len!())]
   /// bytes.
@@ -126,6 +139,15 @@ impl Http2Params {
     self.max_recv_streams_num
   }
 
+  /// Metrics
+  ///
+  /// Shared instance that receives the events of the connection. Defaults to `None`.
+  #[cfg(feature = "metrics")]
+  #[inline]
+  pub fn metrics(&self) -> Option<&Arc<Http2Metrics>> {
+    self.metrics.as_ref()
+  }
+
   /// Read Buffer Length.
   ///
   /// Allocated space intended to read bytes sent by external actors.
@@ -138,6 +160,16 @@ impl Http2Params {
     self.read_buffer_len
   }
 
+  /// Request/Response buffer pool
+  ///
+  /// Servers only. Shared instance used to recycle the buffers of automatic streams, see
+  /// [`ReqResBufferPool`]. Defaults to `None`.
+  #[cfg(feature = "std")]
+  #[inline]
+  pub fn rrb_pool(&self) -> Option<&Arc<ReqResBufferPool>> {
+    self.rrb_pool.as_ref()
+  }
+
   /// Mutable version of [`Self::enable_connect_protocol`].
   #[inline]
   #[must_use]
@@ -202,6 +234,15 @@ impl Http2Params {
     self
   }
 
+  /// Mutable version of [`Self::metrics`].
+  #[cfg(feature = "metrics")]
+  #[inline]
+  #[must_use]
+  pub fn set_metrics(mut self, value: Option<Arc<Http2Metrics>>) -> Self {
+    self.metrics = value;
+    self
+  }
+
   /// Mutable version of [`Self::read_buffer_len`].
   #[inline]
   #[must_use]
@@ -210,6 +251,15 @@ impl Http2Params {
     self
   }
 
+  /// Mutable version of [`Self::rrb_pool`].
+  #[cfg(feature = "std")]
+  #[inline]
+  #[must_use]
+  pub fn set_rrb_pool(mut self, value: Option<Arc<ReqResBufferPool>>) -> Self {
+    self.rrb_pool = value;
+    self
+  }
+
   #[inline]
   pub(crate) fn to_settings_frame(&self) -> SettingsFrame {
     let mut settings_frame = SettingsFrame::empty();
@@ -235,7 +285,11 @@ impl Default for Http2Params {
       max_headers_len: MAX_HEADERS_LEN,
       max_hpack_len: (MAX_HPACK_LEN, MAX_HPACK_LEN),
       max_recv_streams_num: MAX_RECV_STREAMS_NUM,
+      #[cfg(feature = "metrics")]
+      metrics: None,
       read_buffer_len: READ_BUFFER_LEN,
+      #[cfg(feature = "std")]
+      rrb_pool: None,
     }
   }
 }
@@ -8,10 +8,11 @@ use core::cmp::Ordering;
 #[derive(Debug)]
 pub(crate) struct Http2ParamsSend {
   pub(crate) enable_connect_protocol: u32,
+  // If at least one non-ACK settings frame was received from the peer.
+  pub(crate) has_settings: bool,
   pub(crate) initial_window_len: U31,
   pub(crate) max_concurrent_streams_num: u32,
   pub(crate) max_frame_len: u32,
-  pub(crate) max_headers_len: u32,
   pub(crate) max_hpack_len: u32,
 }
 
@@ -24,6 +25,7 @@ impl Http2ParamsSend {
     sf: &SettingsFrame,
     sorp: &mut Sorp,
   ) -> crate::Result<()> {
+    self.has_settings = true;
     if let Some(elem) = sf.enable_connect_protocol() {
       self.enable_connect_protocol = u32::from(elem);
     }
@@ -56,10 +58,11 @@ impl Http2ParamsSend {
     }
     if let Some(elem) = sf.header_table_size() {
       self.max_hpack_len = elem;
-      hpack_enc.set_max_dyn_sub_bytes(elem)?;
+      // Counterparts can advertise lengths greater than the local encoder limit
+      hpack_enc.set_max_dyn_sub_bytes(elem.min(hpack_enc.max_dyn_super_bytes()))?;
     }
     if let Some(elem) = sf.max_header_list_size() {
-      self.max_headers_len = elem;
+      hpack_enc.set_max_headers_len(elem);
     }
     if let Some(elem) = sf.max_frame_size() {
       self.max_frame_len = elem.clamp(MAX_FRAME_LEN_LOWER_BOUND, MAX_FRAME_LEN_UPPER_BOUND);
@@ -78,9 +81,9 @@ impl Default for Http2ParamsSend {
   fn default() -> Self {
     Self {
       enable_connect_protocol: 0,
+      has_settings: false,
       initial_window_len: U31::from_u32(initial_window_len!()),
       max_hpack_len: MAX_HPACK_LEN,
-      max_headers_len: u32::MAX,
       max_frame_len: MAX_FRAME_LEN,
       max_concurrent_streams_num: u32::MAX,
     }
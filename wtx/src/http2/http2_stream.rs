@@ -0,0 +1,22 @@
+use crate::http2::CommonStream;
+
+/// Client or server streams that expose the operations of [`CommonStream`].
+pub trait Http2Stream<const IS_CLIENT: bool> {
+  /// See [`crate::http2::Http2Data`].
+  type HD;
+
+  /// See [`CommonStream`].
+  fn common(&mut self) -> CommonStream<'_, Self::HD, IS_CLIENT>;
+}
+
+impl<T, const IS_CLIENT: bool> Http2Stream<IS_CLIENT> for &mut T
+where
+  T: Http2Stream<IS_CLIENT>,
+{
+  type HD = T::HD;
+
+  #[inline]
+  fn common(&mut self) -> CommonStream<'_, Self::HD, IS_CLIENT> {
+    (**self).common()
+  }
+}
@@ -1,5 +1,5 @@
 use crate::{
-  http::ReqResBuffer,
+  http::{Headers, ReqResBuffer, StatusCode},
   http2::{
     Http2Buffer, Http2Data, Http2Error, Http2ErrorCode, Http2Params, Http2RecvStatus,
     Http2SendStatus, Scrp, Sorp,
@@ -8,25 +8,30 @@ use crate::{
     go_away_frame::GoAwayFrame,
     headers_frame::HeadersFrame,
     hpack_decoder::HpackDecoder,
+    hpack_static_headers::{HpackStaticRequestHeaders, HpackStaticResponseHeaders},
     http2_data::Http2DataPartsMut,
     reset_stream_frame::ResetStreamFrame,
+    send_msg::{encode_headers, write_standalone_headers},
     stream_receiver::{StreamControlRecvParams, StreamOverallRecvParams},
     stream_state::StreamState,
     u31::U31,
     uri_buffer::UriBuffer,
   },
   misc::{
-    LeaseMut, Lock, RefCounter, StreamReader, StreamWriter, Usize,
+    Deque, LeaseMut, Lock, RefCounter, StreamReader, StreamWriter, Usize,
     net::{PartitionedFilledBuffer, read_header, read_payload},
   },
   sync::{AtomicBool, AtomicWaker, Ordering},
 };
 use core::{
   future::poll_fn,
+  mem,
   pin::pin,
   task::{Context, Poll, ready},
 };
 
+const MAX_RESET_STREAMS: usize = 32;
+
 #[inline]
 pub(crate) fn check_content_length(sorp: &StreamOverallRecvParams) -> crate::Result<()> {
   let Some(content_length) = sorp.content_length else {
@@ -162,6 +167,7 @@ pub(crate) async fn process_higher_operation_err<HB, HD, SW, const IS_CLIENT: bo
       send_go_away(*http2_error_code, &mut hdpm).await;
     }
     crate::Error::Http2ErrorReset(http2_error_code, _, stream_id) => {
+      push_reset_stream(&mut hdpm.hb.reset_streams, stream_id.into());
       let _ = send_reset_stream(
         *http2_error_code,
         &mut hdpm.hb.scrp,
@@ -177,6 +183,21 @@ pub(crate) async fn process_higher_operation_err<HB, HD, SW, const IS_CLIENT: bo
   }
 }
 
+/// Remembers streams that were locally reset because in-flight DATA frames must be ignored
+/// instead of being treated as frames of closed streams.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc9113#section-5.4.2>
+#[inline]
+pub(crate) fn push_reset_stream(reset_streams: &mut Deque<U31>, stream_id: U31) {
+  if reset_streams.iter().any(|el| *el == stream_id) {
+    return;
+  }
+  if reset_streams.len() >= MAX_RESET_STREAMS {
+    let _ = reset_streams.pop_front();
+  }
+  let _rslt = reset_streams.push_back(stream_id);
+}
+
 #[inline]
 pub(crate) async fn read_frame<SR, const IS_HEADER_BLOCK: bool>(
   is_conn_open: &AtomicBool,
@@ -221,6 +242,11 @@ where
         continue;
       };
       _trace!("Received frame: {fi:?}");
+      if !IS_HEADER_BLOCK && fi.ty == FrameInitTy::Data {
+        // DATA payloads are transferred directly to the buffer of the stream by the caller.
+        pfb._set_indices(pfb._current_end_idx().wrapping_add(9), 0, read.wrapping_sub(9))?;
+        return Ok(fi);
+      }
       read_payload((9, data_len_usize), pfb, &mut read, stream_reader).await?;
       return Ok(fi);
     }
@@ -303,6 +329,10 @@ where
       else {
         break 'continuation_frames;
       };
+      #[cfg(feature = "metrics")]
+      if let Some(elem) = hp.metrics() {
+        elem.frame_received(frame_fi.data_len);
+      }
       let has_diff_id = fi.stream_id != frame_fi.stream_id;
       let is_not_continuation = frame_fi.ty != FrameInitTy::Continuation;
       if has_diff_id || is_not_continuation {
@@ -360,6 +390,55 @@ pub(crate) async fn send_go_away<SW, const IS_CLIENT: bool>(
   hdpm.hb.read_frame_waker.wake();
 }
 
+/// Responds with `413 Payload Too Large` and then asks the client to stop sending the rest of
+/// the body through a `RST_STREAM` frame with `NO_ERROR`.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc9113#section-8.1>
+#[inline]
+pub(crate) async fn send_large_body_res<HB, HD, SW, const IS_CLIENT: bool>(
+  hd: &HD,
+  is_conn_open: &AtomicBool,
+  stream_id: U31,
+) -> crate::Result<ReqResBuffer>
+where
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, IS_CLIENT>>,
+  SW: StreamWriter,
+{
+  let mut lock = hd.lock().await;
+  let hdpm = lock.parts_mut();
+  let hsreh = HpackStaticResponseHeaders { status_code: Some(StatusCode::PayloadTooLarge) };
+  encode_headers::<false>(
+    &Headers::new(),
+    (&mut hdpm.hb.hpack_enc, &mut hdpm.hb.hpack_enc_buffer),
+    (HpackStaticRequestHeaders::EMPTY, hsreh),
+    stream_id,
+  )?;
+  let _ = write_standalone_headers::<_, IS_CLIENT>(
+    &mut hdpm.hb.hpack_enc_buffer,
+    (HpackStaticRequestHeaders::EMPTY, hsreh),
+    is_conn_open,
+    true,
+    hdpm.hps.max_frame_len,
+    hdpm.stream_writer,
+    stream_id,
+  )
+  .await?;
+  push_reset_stream(&mut hdpm.hb.reset_streams, stream_id);
+  let _ = send_reset_stream(
+    Http2ErrorCode::NoError,
+    &mut hdpm.hb.scrp,
+    &mut hdpm.hb.sorp,
+    hdpm.stream_writer,
+    stream_id,
+  )
+  .await;
+  let mut rrb = mem::take(&mut sorp_mut(&mut hdpm.hb.sorp, stream_id)?.rrb);
+  rrb.clear();
+  Ok(rrb)
+}
+
 #[inline]
 pub(crate) async fn send_reset_stream<SW>(
   error_code: Http2ErrorCode,
@@ -372,7 +451,11 @@ where
   SW: StreamWriter,
 {
   let mut has_stored = false;
-  let _rslt = stream_writer.write_all(&ResetStreamFrame::new(error_code, stream_id).bytes()).await;
+  // Idle streams were never announced to the peer.
+  if !scrp.get(&stream_id).is_some_and(|el| el.stream_state == StreamState::Idle) {
+    let _rslt =
+      stream_writer.write_all(&ResetStreamFrame::new(error_code, stream_id).bytes()).await;
+  }
   if let Some(elem) = scrp.get_mut(&stream_id) {
     has_stored = true;
     elem.is_stream_open = false;
@@ -388,6 +471,18 @@ where
   has_stored
 }
 
+/// Overrides the maximum body length of a stream. Bodies that already exceed the new length are
+/// discarded and later answered with `413 Payload Too Large`.
+#[inline]
+pub(crate) fn set_max_body_len(max_body_len: u32, sorp: &mut StreamOverallRecvParams) {
+  sorp.max_body_len = max_body_len;
+  if !sorp.has_large_body && sorp.body_len > max_body_len {
+    sorp.has_large_body = true;
+    sorp.rrb.body.clear();
+    sorp.waker.wake_by_ref();
+  }
+}
+
 #[inline]
 pub(crate) fn server_header_stream_state(has_eos: bool) -> StreamState {
   if has_eos { StreamState::HalfClosedRemote } else { StreamState::Open }
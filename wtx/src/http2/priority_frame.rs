@@ -0,0 +1,86 @@
+use crate::http2::{
+  Http2Error, Http2ErrorCode, StreamPriority,
+  common_flags::CommonFlags,
+  frame_init::{FrameInit, FrameInitTy},
+  misc::protocol_err,
+  u31::U31,
+};
+
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct PriorityFrame {
+  sp: StreamPriority,
+  stream_id: U31,
+}
+
+impl PriorityFrame {
+  #[inline]
+  pub(crate) fn new(sp: StreamPriority, stream_id: U31) -> crate::Result<Self> {
+    if stream_id.is_zero() {
+      return Err(protocol_err(Http2Error::InvalidPriorityFrameZeroId));
+    }
+    if sp.dependency() == stream_id.u32() {
+      return Err(crate::Error::Http2ErrorReset(
+        Http2ErrorCode::ProtocolError,
+        Some(Http2Error::InvalidPriorityDependency),
+        stream_id.u32(),
+      ));
+    }
+    Ok(Self { sp, stream_id })
+  }
+
+  #[inline]
+  pub(crate) fn read(bytes: &[u8], fi: FrameInit) -> crate::Result<Self> {
+    let [a, b, c, d, e] = bytes else {
+      return Err(crate::Error::Http2ErrorGoAway(
+        Http2ErrorCode::FrameSizeError,
+        Some(Http2Error::InvalidPriorityFrameBytes),
+      ));
+    };
+    Self::new(StreamPriority::from_bytes([*a, *b, *c, *d, *e]), fi.stream_id)
+  }
+
+  #[inline]
+  pub(crate) const fn bytes(&self) -> [u8; 14] {
+    let [a, b, c, d, e, f, g, h, i] =
+      FrameInit::new(CommonFlags::empty(), 5, self.stream_id, FrameInitTy::Priority).bytes();
+    let [j, k, l, m, n] = self.sp.bytes();
+    [a, b, c, d, e, f, g, h, i, j, k, l, m, n]
+  }
+
+  #[inline]
+  pub(crate) const fn sp(&self) -> StreamPriority {
+    self.sp
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::http2::{
+    Http2Error, Http2ErrorCode, StreamPriority,
+    common_flags::CommonFlags,
+    frame_init::{FrameInit, FrameInitTy},
+    priority_frame::PriorityFrame,
+    u31::U31,
+  };
+
+  #[test]
+  fn bytes_and_read() {
+    let sp = StreamPriority::new(3, true, 255);
+    let bytes = PriorityFrame::new(sp, U31::from_u32(5)).unwrap().bytes();
+    let (fi, _) = FrameInit::from_array(bytes[..9].try_into().unwrap());
+    let fi = fi.unwrap();
+    assert_eq!(
+      fi,
+      FrameInit::new(CommonFlags::empty(), 5, U31::from_u32(5), FrameInitTy::Priority)
+    );
+    assert_eq!(PriorityFrame::read(&bytes[9..], fi).unwrap().sp(), sp);
+    assert!(matches!(
+      PriorityFrame::new(sp, U31::from_u32(3)),
+      Err(crate::Error::Http2ErrorReset(
+        Http2ErrorCode::ProtocolError,
+        Some(Http2Error::InvalidPriorityDependency),
+        3
+      ))
+    ));
+  }
+}
@@ -1,7 +1,7 @@
 use crate::{
   http::{HttpError, StatusCode},
   http2::{
-    Http2Error, Http2ErrorCode, Http2Params, Scrp, Sorp,
+    Http2Error, Http2ErrorCode, Http2Params, Scrp, Sorp, StreamPriority,
     data_frame::DataFrame,
     frame_init::FrameInit,
     hpack_decoder::HpackDecoder,
@@ -11,6 +11,7 @@ use crate::{
       protocol_err, read_header_and_continuations, send_reset_stream, server_header_stream_state,
       sorp_mut,
     },
+    priority_frame::PriorityFrame,
     reset_stream_frame::ResetStreamFrame,
     stream_receiver::StreamOverallRecvParams,
     stream_state::StreamState,
@@ -19,7 +20,7 @@ use crate::{
     window::{Windows, WindowsPair},
     window_update_frame::WindowUpdateFrame,
   },
-  misc::{StreamReader, StreamWriter, net::PartitionedFilledBuffer},
+  misc::{Deque, StreamReader, StreamWriter, Vector, net::PartitionedFilledBuffer},
   sync::{AtomicBool, AtomicWaker},
 };
 use core::{mem, task::Waker};
@@ -36,6 +37,7 @@ pub(crate) struct ProcessReceiptFrameTy<'instance, SR, SW> {
   pub(crate) pfb: &'instance mut PartitionedFilledBuffer,
   pub(crate) read_frame_waker: &'instance AtomicWaker,
   pub(crate) recv_streams_num: &'instance mut u32,
+  pub(crate) reset_streams: &'instance Deque<U31>,
   pub(crate) stream_reader: &'instance mut SR,
   pub(crate) stream_writer: &'instance mut SW,
   pub(crate) uri_buffer: &'instance mut UriBuffer,
@@ -46,8 +48,20 @@ where
   SR: StreamReader,
   SW: StreamWriter,
 {
+  /// Validates a DATA frame whose payload wasn't read yet. Returns the body that should receive
+  /// the payload or `None` if the payload must be discarded.
+  ///
+  /// The body is detached from the stream because the payload is read without holding the lock
+  /// of the connection.
   #[inline]
-  pub(crate) async fn data(self, sorp: &mut Sorp) -> crate::Result<()> {
+  pub(crate) fn data_init<const IS_CLIENT: bool>(
+    self,
+    sorp: &mut Sorp,
+  ) -> crate::Result<Option<Vector<u8>>> {
+    // Frames of locally reset streams can still be in-flight and must be ignored.
+    if self.is_reset_stream() {
+      return Ok(None);
+    }
     let Some(elem) = sorp.get_mut(&self.fi.stream_id) else {
       if self.fi.stream_id <= *self.last_stream_id {
         return Err(crate::Error::Http2ErrorGoAway(
@@ -57,23 +71,53 @@ where
       }
       return Err(protocol_err(Http2Error::UnknownDataStreamReceiver));
     };
-    if elem.stream_state.recv_eos() {
+    // Frames of rejected requests can still be in-flight after the sending of `RST_STREAM`.
+    if !elem.has_large_body && elem.stream_state.recv_eos() {
       return Err(crate::Error::Http2ErrorGoAway(
         Http2ErrorCode::StreamClosed,
         Some(Http2Error::InvalidReceivedFrameAfterEos),
       ));
     }
     let local_body_len_opt = elem.body_len.checked_add(self.fi.data_len);
-    let Some(local_body_len) = local_body_len_opt.filter(|el| *el <= self.hp.max_body_len()) else {
-      return Err(protocol_err(Http2Error::LargeBodyLen(
-        local_body_len_opt,
-        self.hp.max_body_len(),
-      )));
+    elem.body_len = match local_body_len_opt.filter(|el| *el <= elem.max_body_len) {
+      Some(local_body_len) => local_body_len,
+      // Servers discard the rest of the body and later respond with `413 Payload Too Large`.
+      None if !IS_CLIENT => {
+        elem.has_large_body = true;
+        elem.rrb.body.clear();
+        local_body_len_opt.unwrap_or(u32::MAX)
+      }
+      None => {
+        return Err(protocol_err(Http2Error::LargeBodyLen(local_body_len_opt, elem.max_body_len)));
+      }
+    };
+    if elem.has_large_body {
+      return Ok(None);
+    }
+    Ok(Some(mem::take(&mut elem.rrb.body)))
+  }
+
+  /// Attaches the body returned by [`Self::data_init`] after the reading of the payload.
+  #[inline]
+  pub(crate) async fn data_finish(
+    self,
+    body: Option<Vector<u8>>,
+    df: &DataFrame,
+    sorp: &mut Sorp,
+  ) -> crate::Result<()> {
+    let is_reset_stream = self.is_reset_stream();
+    let Some(elem) = sorp.get_mut(&self.fi.stream_id).filter(|_| !is_reset_stream) else {
+      // The stream was locally closed before or while the payload was being read. Only the
+      // connection window is affected.
+      return self
+        .conn_windows
+        .withdrawn_conn_recv(self.hp, self.is_conn_open, self.stream_writer, df.data_len())
+        .await;
     };
-    elem.body_len = local_body_len;
-    let (df, body_bytes) = DataFrame::read(self.pfb._current(), self.fi)?;
-    elem.rrb.body.extend_from_copyable_slice(body_bytes)?;
-    elem.has_one_or_more_data_frames = true;
+    if let Some(local_body) = body {
+      elem.rrb.body = local_body;
+      elem.has_one_or_more_data_frames = true;
+    }
     WindowsPair::new(self.conn_windows, &mut elem.windows)
       .withdrawn_recv(
         self.hp,
@@ -83,7 +127,7 @@ where
         df.data_len(),
       )
       .await?;
-    if df.has_eos() {
+    if df.has_eos() && !elem.stream_state.recv_eos() {
       elem.stream_state = StreamState::HalfClosedRemote;
     }
     elem.waker.wake_by_ref();
@@ -93,6 +137,7 @@ where
   #[inline]
   pub(crate) async fn header_client(self, sorp: &mut Sorp) -> crate::Result<()> {
     let elem = sorp_mut(sorp, self.fi.stream_id)?;
+    let is_initial_header = !elem.has_initial_header;
     let has_eos = if elem.has_initial_header {
       read_header_and_continuations::<_, _, true, true>(
         self.fi,
@@ -129,6 +174,9 @@ where
     if has_eos {
       elem.stream_state = StreamState::Closed;
       elem.waker.wake_by_ref();
+    } else if is_initial_header {
+      // Extended CONNECT requests await the initial headers to start tunneling.
+      elem.waker.wake_by_ref();
     }
     Ok(())
   }
@@ -145,7 +193,6 @@ where
     if *self.recv_streams_num >= self.hp.max_recv_streams_num() {
       return Err(protocol_err(Http2Error::ExceedAmountOfOpenedStreams));
     }
-    *self.recv_streams_num = self.recv_streams_num.wrapping_add(1);
     *self.last_stream_id = self.fi.stream_id;
     let tuple = read_header_and_continuations::<_, _, false, false>(
       self.fi,
@@ -157,10 +204,31 @@ where
       &mut ish.rrb,
       self.stream_reader,
       self.uri_buffer,
-      |hf| Ok((hf.hsreqh().method.ok_or(HttpError::MissingRequestMethod)?, hf.hsreqh().protocol)),
+      |hf| {
+        let method = hf.hsreqh().method.ok_or(HttpError::MissingRequestMethod)?;
+        Ok((method, hf.priority(), hf.hsreqh().protocol))
+      },
     )
     .await?;
-    let (content_length, has_eos, (method, protocol)) = tuple;
+    let (content_length, has_eos, (method, priority_opt, protocol)) = tuple;
+    let priority = priority_opt.unwrap_or(StreamPriority::DEFAULT);
+    if priority.dependency() == self.fi.stream_id.u32() {
+      // Only this stream is affected, which means that `ish` must be available to the next one.
+      ish.rrb.clear();
+      return Err(crate::Error::Http2ErrorReset(
+        Http2ErrorCode::ProtocolError,
+        Some(Http2Error::InvalidPriorityDependency),
+        self.fi.stream_id.u32(),
+      ));
+    }
+    if protocol.is_some() && !self.hp.enable_connect_protocol() {
+      return Err(protocol_err(Http2Error::ExtendedConnectIsDisabled));
+    }
+    *self.recv_streams_num = self.recv_streams_num.wrapping_add(1);
+    #[cfg(feature = "metrics")]
+    if let Some(elem) = self.hp.metrics() {
+      elem.streams_opened().inc();
+    }
     ish.method = method;
     ish.protocol = protocol;
     ish.stream_id = self.fi.stream_id;
@@ -171,8 +239,12 @@ where
         body_len: 0,
         content_length,
         has_initial_header: true,
+        has_large_body: false,
         has_one_or_more_data_frames: false,
+        has_sent_headers: false,
         is_stream_open: true,
+        max_body_len: self.hp.max_body_len(),
+        priority,
         rrb: mem::take(&mut ish.rrb),
         status_code: StatusCode::Ok,
         stream_state,
@@ -211,6 +283,18 @@ where
     Ok(())
   }
 
+  #[inline]
+  pub(crate) fn priority(self, scrp: &mut Scrp, sorp: &mut Sorp) -> crate::Result<()> {
+    let pf = PriorityFrame::read(self.pfb._current(), self.fi)?;
+    // Frames of idle or already removed streams are ignored.
+    if let Some(elem) = scrp.get_mut(&self.fi.stream_id) {
+      elem.priority = pf.sp();
+    } else if let Some(elem) = sorp.get_mut(&self.fi.stream_id) {
+      elem.priority = pf.sp();
+    }
+    Ok(())
+  }
+
   #[inline]
   pub(crate) async fn reset(self, scrp: &mut Scrp, sorp: &mut Sorp) -> crate::Result<()> {
     let rsf = ResetStreamFrame::read(self.pfb._current(), self.fi)?;
@@ -241,4 +325,9 @@ where
     waker.wake_by_ref();
     Ok(())
   }
+
+  #[inline]
+  fn is_reset_stream(&self) -> bool {
+    self.reset_streams.iter().any(|el| *el == self.fi.stream_id)
+  }
 }
@@ -55,10 +55,17 @@ pub(crate) fn encode_headers<const IS_CLIENT: bool>(
   headers: &Headers,
   (hpack_enc, hpack_enc_buffer): (&mut HpackEncoder, &mut Vector<u8>),
   (hsreqh, hsresh): (HpackStaticRequestHeaders<'_>, HpackStaticResponseHeaders),
+  stream_id: U31,
 ) -> crate::Result<()> {
   hpack_enc_buffer.clear();
+  let pseudo_headers_len = if IS_CLIENT {
+    HpackEncoder::pseudo_headers_len(hsreqh.iter())
+  } else {
+    HpackEncoder::pseudo_headers_len(hsresh.iter())
+  };
   match headers.trailers() {
     Trailers::None => {
+      hpack_enc.check_headers_len(pseudo_headers_len, stream_id, headers.iter())?;
       if IS_CLIENT {
         hpack_enc.encode(hpack_enc_buffer, hsreqh.iter(), headers.iter())?;
       } else {
@@ -66,6 +73,8 @@ pub(crate) fn encode_headers<const IS_CLIENT: bool>(
       }
     }
     Trailers::Mixed => {
+      let iter = headers.iter().filter(|el| !el.is_trailer);
+      hpack_enc.check_headers_len(pseudo_headers_len, stream_id, iter)?;
       let iter = headers.iter().filter(|el| !el.is_trailer);
       if IS_CLIENT {
         hpack_enc.encode(hpack_enc_buffer, hsreqh.iter(), iter)?;
@@ -74,6 +83,7 @@ pub(crate) fn encode_headers<const IS_CLIENT: bool>(
       }
     }
     Trailers::Tail(idx) => {
+      hpack_enc.check_headers_len(pseudo_headers_len, stream_id, headers.iter().take(idx))?;
       let iter = headers.iter().take(idx);
       if IS_CLIENT {
         hpack_enc.encode(hpack_enc_buffer, hsreqh.iter(), iter)?;
@@ -239,7 +249,10 @@ where
       return Err(protocol_err(Http2Error::InvalidDataFrameDataLen));
     }
     let frame0_len = data_frame_len(data_len);
-    let frame0 = DataFrame::new(frame0_len.into(), stream_id);
+    let mut frame0 = DataFrame::new(frame0_len.into(), stream_id);
+    if force_eos || !has_trailers {
+      frame0.set_eos();
+    }
     write_array(data.concat(&init!(frame0_len, frame0)), is_conn_open, stream).await?;
     wp.withdrawn_send(Some(stream_id), frame0_len.into())?;
     *has_data = true;
@@ -281,6 +294,21 @@ where
   Ok(should_stop)
 }
 
+/// Sends an empty data frame that closes the sending side of a stream.
+#[inline]
+pub(crate) async fn write_eos_data_frame<SW>(
+  is_conn_open: &AtomicBool,
+  stream: &mut SW,
+  stream_id: U31,
+) -> crate::Result<()>
+where
+  SW: StreamWriter,
+{
+  let mut frame = DataFrame::new(U31::ZERO, stream_id);
+  frame.set_eos();
+  write_array([&init!(0u32, frame)], is_conn_open, stream).await
+}
+
 /// Tries to send all trailer headers
 #[inline]
 pub(crate) async fn write_standalone_trailers<SW>(
@@ -295,7 +323,7 @@ where
   SW: StreamWriter,
 {
   hpack_enc_buffer.clear();
-  encode_trailers(headers, (hpack_enc, hpack_enc_buffer))?;
+  encode_trailers(headers, (hpack_enc, hpack_enc_buffer), stream_id)?;
   let (left0 @ [_, ..], right0) = split_frame_bytes(hpack_enc_buffer, max_frame_len) else {
     return Ok(());
   };
@@ -362,7 +390,12 @@ where
   'msg: {
     let Ok(available_send @ 1..=u32::MAX) = u32::try_from(wp.available_send()) else {
       if !*has_headers {
-        encode_headers::<IS_CLIENT>(headers, (hpack_enc, hpack_enc_buffer), (hsreqh, hsresh))?;
+        encode_headers::<IS_CLIENT>(
+          headers,
+          (hpack_enc, hpack_enc_buffer),
+          (hsreqh, hsresh),
+          stream_id,
+        )?;
         if write_standalone_headers::<SW, IS_CLIENT>(
           hpack_enc_buffer,
           (hsreqh, hsresh),
@@ -377,6 +410,7 @@ where
           break 'msg;
         }
         change_initial_stream_state::<IS_CLIENT>(&mut elem.stream_state);
+        elem.has_sent_headers = true;
         *has_headers = true;
       }
       return Ok(Some(false));
@@ -400,6 +434,7 @@ where
         break 'msg;
       }
       change_initial_stream_state::<IS_CLIENT>(&mut elem.stream_state);
+      elem.has_sent_headers = true;
       *has_headers = true;
     }
 
@@ -444,15 +479,19 @@ where
 fn encode_trailers(
   headers: &Headers,
   (hpack_enc, hpack_enc_buffer): (&mut HpackEncoder, &mut Vector<u8>),
+  stream_id: U31,
 ) -> crate::Result<()> {
   match headers.trailers() {
     Trailers::None => {
+      hpack_enc.check_headers_len(0, stream_id, headers.iter())?;
       hpack_enc.encode(hpack_enc_buffer, [], headers.iter())?;
     }
     Trailers::Mixed => {
+      hpack_enc.check_headers_len(0, stream_id, headers.iter().filter(|el| el.is_trailer))?;
       hpack_enc.encode(hpack_enc_buffer, [], headers.iter().filter(|el| el.is_trailer))?;
     }
     Trailers::Tail(idx) => {
+      hpack_enc.check_headers_len(0, stream_id, headers.iter().skip(idx))?;
       hpack_enc.encode(hpack_enc_buffer, [], headers.iter().skip(idx))?;
     }
   }
@@ -484,7 +523,7 @@ where
     None
   }
 
-  encode_headers::<IS_CLIENT>(headers, (hpack_enc, hpack_enc_buffer), (hsreqh, hsresh))?;
+  encode_headers::<IS_CLIENT>(headers, (hpack_enc, hpack_enc_buffer), (hsreqh, hsresh), stream_id)?;
 
   'headers_with_others: {
     let Some(data_len) = has_delimited_bytes(data_bytes, available_send.min(max_frame_len)) else {
@@ -493,7 +532,7 @@ where
 
     if headers.trailers().has_any() {
       let idx = hpack_enc_buffer.len();
-      encode_trailers(headers, (hpack_enc, hpack_enc_buffer))?;
+      encode_trailers(headers, (hpack_enc, hpack_enc_buffer), stream_id)?;
       let Some((headers_bytes, trailers_bytes)) = hpack_enc_buffer.split_at_checked(idx) else {
         break 'headers_with_others;
       };
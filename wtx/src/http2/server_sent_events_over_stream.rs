@@ -0,0 +1,167 @@
+//! Tools to manage Server-Sent Events in HTTP/2 streams
+
+use crate::{
+  http::{Header, Headers, KnownHeaderName, Mime, ServerSentEvent, StatusCode},
+  http2::{Http2Buffer, Http2Data, SendDataMode, ServerStream},
+  misc::{
+    Either, Lease, LeaseMut, Lock, RefCounter, SingleTypeStorage, StreamWriter, Vector, sleep,
+  },
+};
+use core::{fmt::Write, future::poll_fn, mem, pin::pin, task::Poll, time::Duration};
+
+/// Keeps a stream open to send `text/event-stream` messages.
+#[derive(Debug)]
+pub struct ServerSentEventsOverStream<S> {
+  buffer: Vector<u8>,
+  stream: S,
+}
+
+impl<HB, HD, S, SW> ServerSentEventsOverStream<S>
+where
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  S: LeaseMut<ServerStream<HD>> + SingleTypeStorage<Item = HD>,
+  SW: StreamWriter,
+{
+  /// Creates a new instance sending an `Ok` status code along side `headers`, which receive the
+  /// `Content-Type` and `Cache-Control` headers expected by clients.
+  ///
+  /// If `retry` is set, then clients are also instructed to wait the specified amount of time
+  /// before reconnecting.
+  #[inline]
+  pub async fn new(
+    headers: &mut Headers,
+    retry: Option<Duration>,
+    mut stream: S,
+  ) -> crate::Result<Self> {
+    headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::ContentType.into(),
+      [Mime::TextEventStream.as_str()],
+    ))?;
+    headers.push_from_iter(Header::from_name_and_value(
+      KnownHeaderName::CacheControl.into(),
+      ["no-cache"],
+    ))?;
+    let hss = stream.lease_mut().common().send_headers(headers, false, StatusCode::Ok).await?;
+    if hss.is_closed() {
+      return Err(crate::Error::ClosedConnection);
+    }
+    let mut this = Self { buffer: Vector::new(), stream };
+    if let Some(elem) = retry {
+      this.buffer.write_fmt(format_args!("retry: {}\n\n", elem.as_millis()))?;
+      let buffer = mem::take(&mut this.buffer);
+      this.send_bytes(&buffer).await?;
+      this.buffer = buffer;
+    }
+    Ok(this)
+  }
+
+  /// Ends the stream.
+  #[inline]
+  pub async fn close(&mut self) -> crate::Result<()> {
+    let _ = self.stream.lease_mut().common().send_trailers(&Headers::new()).await?;
+    Ok(())
+  }
+
+  /// Sends a comment line that prevents intermediaries from closing idle connections.
+  #[inline]
+  pub async fn keep_alive(&mut self) -> crate::Result<()> {
+    self.send_bytes(b":\n\n").await
+  }
+
+  /// Sends a single event.
+  #[inline]
+  pub async fn send<D>(&mut self, event: &ServerSentEvent<D>) -> crate::Result<()>
+  where
+    D: Lease<str>,
+  {
+    let mut buffer = mem::take(&mut self.buffer);
+    buffer.clear();
+    event.write(&mut buffer)?;
+    let rslt = self.send_bytes(&buffer).await;
+    self.buffer = buffer;
+    rslt
+  }
+
+  /// Sends all events returned by `cb` until [`Option::None`] is returned, which closes the
+  /// stream. A keep-alive comment is sent whenever `cb` takes more than `interval` to return.
+  #[inline]
+  pub async fn send_all<D>(
+    &mut self,
+    interval: Duration,
+    mut cb: impl AsyncFnMut() -> crate::Result<Option<ServerSentEvent<D>>>,
+  ) -> crate::Result<()>
+  where
+    D: Lease<str>,
+  {
+    loop {
+      let event = {
+        let mut event_fut = pin!(cb());
+        loop {
+          let mut sleep_fut = pin!(sleep(interval));
+          let rslt = poll_fn(|cx| {
+            if let Poll::Ready(elem) = event_fut.as_mut().poll(cx) {
+              return Poll::Ready(Either::Left(elem));
+            }
+            if let Poll::Ready(elem) = sleep_fut.as_mut().poll(cx) {
+              return Poll::Ready(Either::Right(elem));
+            }
+            Poll::Pending
+          })
+          .await;
+          match rslt {
+            Either::Left(elem) => break elem?,
+            Either::Right(elem) => {
+              elem?;
+              self.keep_alive().await?;
+            }
+          }
+        }
+      };
+      let Some(elem) = event else {
+        return self.close().await;
+      };
+      self.send(&elem).await?;
+    }
+  }
+
+  /// Sends all events received by a `tokio` broadcast channel until all senders are dropped.
+  /// Lagged receivers skip missed events. See [`Self::send_all`].
+  #[cfg(feature = "tokio")]
+  #[inline]
+  pub async fn send_broadcast<D>(
+    &mut self,
+    interval: Duration,
+    receiver: &mut tokio::sync::broadcast::Receiver<ServerSentEvent<D>>,
+  ) -> crate::Result<()>
+  where
+    D: Clone + Lease<str>,
+  {
+    use tokio::sync::broadcast::error::RecvError;
+    self
+      .send_all(interval, async || {
+        loop {
+          match receiver.recv().await {
+            Ok(elem) => return Ok(Some(elem)),
+            Err(RecvError::Closed) => return Ok(None),
+            Err(RecvError::Lagged(_)) => {}
+          }
+        }
+      })
+      .await
+  }
+
+  async fn send_bytes(&mut self, bytes: &[u8]) -> crate::Result<()> {
+    let hss = self
+      .stream
+      .lease_mut()
+      .common()
+      .send_data(SendDataMode::scattered_data_frames(bytes), false)
+      .await?;
+    if hss.is_closed() {
+      return Err(crate::Error::ClosedConnection);
+    }
+    Ok(())
+  }
+}
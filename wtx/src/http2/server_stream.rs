@@ -1,17 +1,22 @@
 use crate::{
-  http::{Method, Protocol, ReqResBuffer, ReqResData, Response},
+  http::{Headers, Method, Protocol, ReqResBuffer, ReqResData, Response, StatusCode},
   http2::{
-    CommonStream, Http2Buffer, Http2Data, Http2RecvStatus, Http2SendStatus,
+    CommonStream, Http2Buffer, Http2Data, Http2ErrorCode, Http2RecvStatus, Http2SendStatus,
+    Http2Stream, StreamPriority,
     hpack_static_headers::{HpackStaticRequestHeaders, HpackStaticResponseHeaders},
-    misc::{manage_recurrent_stream_receiving, process_higher_operation_err},
-    send_msg::send_msg,
+    misc::{
+      manage_recurrent_stream_receiving, process_higher_operation_err, push_reset_stream,
+      send_large_body_res, send_reset_stream, set_max_body_len,
+    },
+    send_msg::{encode_headers, send_msg, write_standalone_headers},
     stream_receiver::StreamControlRecvParams,
+    stream_state::StreamState,
     u31::U31,
   },
   misc::{Lease, LeaseMut, Lock, RefCounter, SingleTypeStorage, StreamWriter, span::Span},
   sync::{Arc, AtomicBool},
 };
-use core::{future::poll_fn, pin::pin};
+use core::{future::poll_fn, pin::pin, task::Poll};
 
 /// Created when a server receives an initial stream.
 #[derive(Clone, Debug)]
@@ -62,12 +67,39 @@ where
     self.method
   }
 
+  /// Latest priority sent by the client through the initial headers or through PRIORITY frames.
+  /// Defaults to [`StreamPriority::DEFAULT`].
+  ///
+  /// Can be used to schedule the sending of concurrent responses. For example, large downloads of
+  /// streams with higher weights can be sent before the others.
+  #[inline]
+  pub async fn priority(&self) -> StreamPriority {
+    let mut lock = self.hd.lock().await;
+    let hdpm = lock.parts_mut();
+    if let Some(elem) = hdpm.hb.scrp.get(&self.stream_id) {
+      return elem.priority;
+    }
+    hdpm.hb.sorp.get(&self.stream_id).map_or(StreamPriority::DEFAULT, |el| el.priority)
+  }
+
   /// See [`Protocol`].
   #[inline]
   pub fn protocol(&self) -> Option<Protocol> {
     self.protocol
   }
 
+  /// Overrides [`crate::http2::Http2Params::max_body_len`] for this stream.
+  ///
+  /// Data frames that were already received are also verified. Frames received before this call
+  /// are still subject to the previous limit.
+  #[inline]
+  pub async fn set_max_body_len(&mut self, max_body_len: u32) {
+    let mut lock = self.hd.lock().await;
+    if let Some(elem) = lock.parts_mut().hb.sorp.get_mut(&self.stream_id) {
+      set_max_body_len(max_body_len, elem);
+    }
+  }
+
   /// Receive request
   ///
   /// High level operation that awaits for the data necessary to build a request.
@@ -76,6 +108,10 @@ where
   /// or externally.
   ///
   /// Shouldn't be called more than once.
+  ///
+  /// Bodies that exceed the maximum allowed length are automatically answered with a
+  /// `413 Payload Too Large` response and the returned status is
+  /// [`Http2RecvStatus::ClosedStream`].
   #[inline]
   pub async fn recv_req(&mut self) -> crate::Result<(Http2RecvStatus<(), ()>, ReqResBuffer)> {
     let Self { hd, is_conn_open, method: _, protocol: _, span, stream_id } = self;
@@ -84,16 +120,24 @@ where
     let mut lock_pin = pin!(hd.lock());
     let rslt = poll_fn(|cx| {
       let mut lock = lock_pin!(cx, hd, lock_pin);
+      let hdpm = lock.parts_mut();
+      if let Some(elem) = hdpm.hb.sorp.get(stream_id) {
+        if elem.has_large_body && elem.is_stream_open {
+          return Poll::Ready(Ok(None));
+        }
+      }
       manage_recurrent_stream_receiving(
         cx,
-        lock.parts_mut(),
+        hdpm,
         is_conn_open,
         *stream_id,
         |local_cx, hdpm, sorp| {
           drop(hdpm.hb.scrp.insert(
             *stream_id,
             StreamControlRecvParams {
+              has_sent_headers: sorp.has_sent_headers,
               is_stream_open: true,
+              priority: sorp.priority,
               stream_state: sorp.stream_state,
               waker: local_cx.waker().clone(),
               windows: sorp.windows,
@@ -101,8 +145,16 @@ where
           ));
         },
       )
+      .map(|el| el.map(Some))
     })
     .await;
+    let rslt = match rslt {
+      Ok(Some(elem)) => Ok(elem),
+      Ok(None) => send_large_body_res(hd, is_conn_open, *stream_id)
+        .await
+        .map(|el| (Http2RecvStatus::ClosedStream, el)),
+      Err(err) => Err(err),
+    };
     if let Err(err) = &rslt {
       process_higher_operation_err(err, hd).await;
     }
@@ -145,6 +197,71 @@ where
     }
     Ok(Http2SendStatus::Ok)
   }
+
+  /// Answers with `504 Gateway Timeout` if the response headers weren't sent, otherwise resets
+  /// the stream with the `CANCEL` error code.
+  ///
+  /// Requests that weren't fully received are also reset with the `NO_ERROR` error code after
+  /// the response.
+  ///
+  /// <https://datatracker.ietf.org/doc/html/rfc9113#section-8.1>
+  #[inline]
+  pub(crate) async fn send_gateway_timeout(&mut self) -> crate::Result<()> {
+    let _e = self.span.enter();
+    _trace!("Sending gateway timeout");
+    let mut lock = self.hd.lock().await;
+    let hdpm = lock.parts_mut();
+    let (has_sent_headers, is_req_received) =
+      match (hdpm.hb.scrp.get(&self.stream_id), hdpm.hb.sorp.get(&self.stream_id)) {
+        (Some(elem), _) if elem.is_stream_open && elem.stream_state.can_send::<false>() => {
+          (elem.has_sent_headers, true)
+        }
+        (None, Some(elem)) if elem.is_stream_open => {
+          (elem.has_sent_headers, elem.stream_state.recv_eos())
+        }
+        _ => return Ok(()),
+      };
+    let error_code = if has_sent_headers {
+      Http2ErrorCode::Cancel
+    } else {
+      let hsreh = HpackStaticResponseHeaders { status_code: Some(StatusCode::GatewayTimeout) };
+      encode_headers::<false>(
+        &Headers::new(),
+        (&mut hdpm.hb.hpack_enc, &mut hdpm.hb.hpack_enc_buffer),
+        (HpackStaticRequestHeaders::EMPTY, hsreh),
+        self.stream_id,
+      )?;
+      let _ = write_standalone_headers::<_, false>(
+        &mut hdpm.hb.hpack_enc_buffer,
+        (HpackStaticRequestHeaders::EMPTY, hsreh),
+        &self.is_conn_open,
+        true,
+        hdpm.hps.max_frame_len,
+        hdpm.stream_writer,
+        self.stream_id,
+      )
+      .await?;
+      if is_req_received {
+        if let Some(elem) = hdpm.hb.scrp.get_mut(&self.stream_id) {
+          elem.stream_state = StreamState::Closed;
+        } else if let Some(elem) = hdpm.hb.sorp.get_mut(&self.stream_id) {
+          elem.stream_state = StreamState::Closed;
+        }
+        return Ok(());
+      }
+      Http2ErrorCode::NoError
+    };
+    push_reset_stream(&mut hdpm.hb.reset_streams, self.stream_id);
+    let _ = send_reset_stream(
+      error_code,
+      &mut hdpm.hb.scrp,
+      &mut hdpm.hb.sorp,
+      hdpm.stream_writer,
+      self.stream_id,
+    )
+    .await;
+    Ok(())
+  }
 }
 
 impl<HD> Lease<ServerStream<HD>> for ServerStream<HD> {
@@ -161,6 +278,20 @@ impl<HD> LeaseMut<ServerStream<HD>> for ServerStream<HD> {
   }
 }
 
+impl<HD> Http2Stream<false> for ServerStream<HD> {
+  type HD = HD;
+
+  #[inline]
+  fn common(&mut self) -> CommonStream<'_, HD, false> {
+    CommonStream {
+      hd: &mut self.hd,
+      is_conn_open: &self.is_conn_open,
+      span: &mut self.span,
+      stream_id: self.stream_id,
+    }
+  }
+}
+
 impl<HD> SingleTypeStorage for ServerStream<HD> {
   type Item = HD;
 }
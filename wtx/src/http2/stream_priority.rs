@@ -0,0 +1,70 @@
+use crate::http2::u31::U31;
+
+const EXCLUSIVE: u32 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
+
+/// Dependency and weight of a stream as defined in RFC 7540.
+///
+/// Sent by clients through [`crate::http2::ClientStream::send_priority`] and received by servers
+/// through [`crate::http2::ServerStream::priority`], which allows the scheduling of concurrent
+/// responses.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StreamPriority {
+  dependency: U31,
+  is_exclusive: bool,
+  weight: u8,
+}
+
+impl StreamPriority {
+  /// Priority assigned to streams that didn't receive any other value. Depends on the root stream
+  /// with a weight of 16.
+  pub const DEFAULT: Self = Self { dependency: U31::ZERO, is_exclusive: false, weight: 15 };
+
+  /// `dependency` is capped to an integer of 31 bits and `weight` is the actual weight minus one.
+  #[inline]
+  pub const fn new(dependency: u32, is_exclusive: bool, weight: u8) -> Self {
+    Self { dependency: U31::from_u32(dependency), is_exclusive, weight }
+  }
+
+  /// Identifier of the stream that this stream depends on. Zero is the root stream.
+  #[inline]
+  pub const fn dependency(&self) -> u32 {
+    self.dependency.u32()
+  }
+
+  /// If this stream becomes the sole dependency of its parent.
+  #[inline]
+  pub const fn is_exclusive(&self) -> bool {
+    self.is_exclusive
+  }
+
+  /// Value between 0 and 255 that represents a weight between 1 and 256.
+  #[inline]
+  pub const fn weight(&self) -> u8 {
+    self.weight
+  }
+
+  #[inline]
+  pub(crate) const fn bytes(&self) -> [u8; 5] {
+    let first =
+      if self.is_exclusive { self.dependency.u32() | EXCLUSIVE } else { self.dependency.u32() };
+    let [a, b, c, d] = first.to_be_bytes();
+    [a, b, c, d, self.weight]
+  }
+
+  #[inline]
+  pub(crate) const fn from_bytes([a, b, c, d, e]: [u8; 5]) -> Self {
+    let first = u32::from_be_bytes([a, b, c, d]);
+    Self {
+      dependency: U31::from_u32(first),
+      is_exclusive: first & EXCLUSIVE == EXCLUSIVE,
+      weight: e,
+    }
+  }
+}
+
+impl Default for StreamPriority {
+  #[inline]
+  fn default() -> Self {
+    Self::DEFAULT
+  }
+}
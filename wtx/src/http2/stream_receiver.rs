@@ -1,6 +1,6 @@
 use crate::{
   http::{ReqResBuffer, StatusCode},
-  http2::{stream_state::StreamState, window::Windows},
+  http2::{StreamPriority, stream_state::StreamState, window::Windows},
 };
 use core::task::Waker;
 
@@ -10,7 +10,9 @@ use core::task::Waker;
 /// Used only by unidirectional streams when they are sending data or when the state is closed.
 #[derive(Debug)]
 pub(crate) struct StreamControlRecvParams {
+  pub(crate) has_sent_headers: bool,
   pub(crate) is_stream_open: bool,
+  pub(crate) priority: StreamPriority,
   pub(crate) stream_state: StreamState,
   pub(crate) waker: Waker,
   pub(crate) windows: Windows,
@@ -25,8 +27,12 @@ pub(crate) struct StreamOverallRecvParams {
   pub(crate) content_length: Option<usize>,
   pub(crate) body_len: u32,
   pub(crate) has_initial_header: bool,
+  pub(crate) has_large_body: bool,
   pub(crate) has_one_or_more_data_frames: bool,
+  pub(crate) has_sent_headers: bool,
   pub(crate) is_stream_open: bool,
+  pub(crate) max_body_len: u32,
+  pub(crate) priority: StreamPriority,
   pub(crate) rrb: ReqResBuffer,
   pub(crate) status_code: StatusCode,
   pub(crate) stream_state: StreamState,
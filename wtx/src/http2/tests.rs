@@ -1,3 +1,5 @@
 mod connections;
 #[cfg(all(feature = "_integration-tests", feature = "serde_json"))]
 mod hpack;
+#[cfg(feature = "web-socket")]
+mod web_socket_over_stream;
@@ -1,11 +1,17 @@
 use crate::{
   http::{Header, Headers, Method, ReqResBuffer, ReqResData, Request, StatusCode},
-  http2::{Http2Buffer, Http2ErrorCode, Http2Params, Http2Tokio},
-  misc::{Either, UriRef, UriString, Xorshift64, simple_seed},
+  http2::{Http2Buffer, Http2Error, Http2ErrorCode, Http2Params, Http2Tokio},
+  misc::{
+    BytesStream, Either, StreamReader, StreamWriter, UriRef, UriString, Xorshift64, simple_seed,
+  },
   tests::_uri,
 };
+use alloc::{vec, vec::Vec};
 use core::time::Duration;
-use tokio::net::{TcpListener, TcpStream, tcp::OwnedWriteHalf};
+use tokio::{
+  io::AsyncReadExt,
+  net::{TcpListener, TcpStream, tcp::OwnedWriteHalf},
+};
 
 #[tokio::test]
 async fn connections() {
@@ -16,6 +22,174 @@ async fn connections() {
   client(&uri).await;
 }
 
+#[tokio::test]
+async fn data_frames_after_large_body_res() {
+  let uri = _uri();
+  let listener = TcpListener::bind(uri.hostname_with_implied_port()).await.unwrap();
+  let _server_jh = tokio::spawn(async move {
+    let mut http2 = raw_accept(&listener, Http2Params::default().set_max_body_len(4)).await;
+    let Either::Right((mut first, _)) =
+      http2.stream(ReqResBuffer::empty(), |_, _| {}).await.unwrap()
+    else {
+      panic!();
+    };
+    assert!(first.recv_req().await.unwrap().0.is_closed());
+    first.common().clear(false).await.unwrap();
+    let _rrb = stream_server(&mut http2, ReqResBuffer::empty(), |_| {}).await;
+    tokio::time::sleep(Duration::from_secs(5)).await;
+  });
+
+  let mut stream = raw_connect(&uri).await;
+  // POST request with a body larger than the allowed length
+  stream.write_all(&[0, 0, 3, 1, 4, 0, 0, 0, 1, 131, 132, 134]).await.unwrap();
+  stream.write_all(&[0, 0, 8, 0, 0, 0, 0, 0, 1, 1, 2, 3, 4, 5, 6, 7, 8]).await.unwrap();
+  let mut frames = Vec::new();
+  while frames.last() != Some(&(3, 1)) {
+    frames.push(raw_frame(&mut stream).await);
+  }
+  assert!(frames.contains(&(1, 1)));
+  tokio::time::sleep(Duration::from_millis(100)).await;
+  // The rest of the body was already in-flight
+  stream.write_all(&[0, 0, 4, 0, 1, 0, 0, 0, 1, 9, 10, 11, 12]).await.unwrap();
+  // GET request without body
+  stream.write_all(&[0, 0, 3, 1, 5, 0, 0, 0, 3, 130, 132, 134]).await.unwrap();
+  loop {
+    let frame = raw_frame(&mut stream).await;
+    assert_ne!(frame.0, 7);
+    if frame == (1, 3) {
+      break;
+    }
+  }
+}
+
+#[tokio::test]
+async fn large_local_headers_only_reset_the_stream() {
+  let uri = _uri();
+  let listener = TcpListener::bind(uri.hostname_with_implied_port()).await.unwrap();
+  let _server_jh = tokio::spawn(async move {
+    let mut http2 = raw_accept(&listener, Http2Params::default()).await;
+    let Either::Right((mut first, _)) =
+      http2.stream(ReqResBuffer::empty(), |_, _| {}).await.unwrap()
+    else {
+      panic!();
+    };
+    let (_, mut rrb) = first.recv_req().await.unwrap();
+    rrb.headers.push_from_iter(Header::from_name_and_value("foo", ["0"; 128])).unwrap();
+    let err = first.send_res(rrb.as_http2_response(StatusCode::Ok)).await.unwrap_err();
+    assert!(matches!(
+      err,
+      crate::Error::Http2ErrorReset(_, Some(Http2Error::VeryLargeLocalHeadersLen), 1)
+    ));
+    let _rrb = stream_server(&mut http2, ReqResBuffer::empty(), |_| {}).await;
+    tokio::time::sleep(Duration::from_secs(5)).await;
+  });
+
+  let mut stream = raw_connect(&uri).await;
+  // SETTINGS frame with a `SETTINGS_MAX_HEADER_LIST_SIZE` of 100 bytes
+  stream.write_all(&[0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 100]).await.unwrap();
+  // GET requests without body
+  stream.write_all(&[0, 0, 3, 1, 5, 0, 0, 0, 1, 130, 132, 134]).await.unwrap();
+  while raw_frame(&mut stream).await != (3, 1) {}
+  stream.write_all(&[0, 0, 3, 1, 5, 0, 0, 0, 3, 130, 132, 134]).await.unwrap();
+  loop {
+    let frame = raw_frame(&mut stream).await;
+    assert_ne!(frame.0, 7);
+    if frame == (1, 3) {
+      break;
+    }
+  }
+}
+
+#[tokio::test]
+async fn max_body_len_of_stream() {
+  let uri = _uri();
+  let listener = TcpListener::bind(uri.hostname_with_implied_port()).await.unwrap();
+  let _server_jh = tokio::spawn(async move {
+    let mut http2 = raw_accept(&listener, Http2Params::default().set_max_body_len(4)).await;
+    let Either::Right((mut stream, _)) =
+      http2.stream_with_max_body_len(ReqResBuffer::empty(), |_, _| ((), Some(8))).await.unwrap()
+    else {
+      panic!();
+    };
+    let (hrs, rrb) = stream.recv_req().await.unwrap();
+    assert!(!hrs.is_closed());
+    assert_eq!(rrb.body.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+    let _ = stream.send_res(rrb.as_http2_response(StatusCode::Ok)).await.unwrap();
+    tokio::time::sleep(Duration::from_secs(5)).await;
+  });
+
+  let mut stream = raw_connect(&uri).await;
+  // POST request with a body that is only allowed by the length of the stream
+  stream.write_all(&[0, 0, 3, 1, 4, 0, 0, 0, 1, 131, 132, 134]).await.unwrap();
+  stream.write_all(&[0, 0, 8, 0, 1, 0, 0, 0, 1, 1, 2, 3, 4, 5, 6, 7, 8]).await.unwrap();
+  loop {
+    let frame = raw_frame(&mut stream).await;
+    assert_ne!(frame.0, 3);
+    if frame == (1, 1) {
+      break;
+    }
+  }
+}
+
+#[tokio::test]
+async fn self_dependent_streams_are_reset() {
+  let uri = _uri();
+  let listener = TcpListener::bind(uri.hostname_with_implied_port()).await.unwrap();
+  let _server_jh = tokio::spawn(async move {
+    let mut http2 = raw_accept(&listener, Http2Params::default()).await;
+    let _rrb = stream_server(&mut http2, ReqResBuffer::empty(), |_| {}).await;
+    tokio::time::sleep(Duration::from_secs(5)).await;
+  });
+
+  let mut stream = raw_connect(&uri).await;
+  // GET request that depends on itself
+  stream.write_all(&[0, 0, 8, 1, 37, 0, 0, 0, 1, 0, 0, 0, 1, 15, 130, 132, 134]).await.unwrap();
+  // PRIORITY frame that depends on itself
+  stream.write_all(&[0, 0, 5, 2, 0, 0, 0, 0, 5, 0, 0, 0, 5, 15]).await.unwrap();
+  // GET request without body
+  stream.write_all(&[0, 0, 3, 1, 5, 0, 0, 0, 7, 130, 132, 134]).await.unwrap();
+  let mut frames = Vec::new();
+  while frames.last() != Some(&(1, 7)) {
+    let frame = raw_frame(&mut stream).await;
+    assert_ne!(frame.0, 7);
+    frames.push(frame);
+  }
+  assert!(frames.contains(&(3, 1)));
+  assert!(frames.contains(&(3, 5)));
+}
+
+#[tokio::test]
+async fn stalled_data_frame() {
+  let uri = _uri();
+  let listener = TcpListener::bind(uri.hostname_with_implied_port()).await.unwrap();
+  let _server_jh = tokio::spawn(async move {
+    let mut http2 = raw_accept(&listener, Http2Params::default()).await;
+    let Either::Right((mut first, _)) =
+      http2.stream(ReqResBuffer::empty(), |_, _| {}).await.unwrap()
+    else {
+      panic!();
+    };
+    let Either::Right((_second, _)) = http2.stream(ReqResBuffer::empty(), |_, _| {}).await.unwrap()
+    else {
+      panic!();
+    };
+    // Gives time to the frame reader to start awaiting the rest of the payload.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let (_, rrb) = first.recv_req().await.unwrap();
+    let _ = first.send_res(rrb.as_http2_response(StatusCode::Ok)).await.unwrap();
+    tokio::time::sleep(Duration::from_secs(5)).await;
+  });
+
+  let mut stream = raw_connect(&uri).await;
+  // GET request without body
+  stream.write_all(&[0, 0, 3, 1, 5, 0, 0, 0, 1, 130, 132, 134]).await.unwrap();
+  // POST request whose DATA frame announces 10 bytes but only sends 2
+  stream.write_all(&[0, 0, 3, 1, 4, 0, 0, 0, 3, 131, 132, 134]).await.unwrap();
+  stream.write_all(&[0, 0, 10, 0, 0, 0, 0, 0, 3, 1, 2]).await.unwrap();
+  let fut = async { while raw_frame(&mut stream).await != (1, 1) {} };
+  tokio::time::timeout(Duration::from_secs(2), fut).await.unwrap();
+}
+
 async fn client(uri: &UriString) {
   let mut rrb = ReqResBuffer::empty();
   rrb.headers.reserve(6, 1).unwrap();
@@ -49,6 +223,35 @@ async fn client(uri: &UriString) {
   rrb = stream_client(&mut http2, rrb, &uri_ref).await;
   _3(rrb.body(), rrb.headers());
 
+  rrb.clear();
+  let mut buffer = [0; 4096];
+  let mut bytes_stream = BytesStream::default();
+  bytes_stream.write_all(&[7; 20_000]).await.unwrap();
+  let mut stream = http2.stream().await.unwrap();
+  let _ = stream
+    .send_req_stream(
+      (&mut bytes_stream, buffer.as_mut_slice()),
+      &rrb.headers,
+      Method::Post,
+      &uri_ref,
+    )
+    .await
+    .unwrap();
+  rrb = stream.recv_res(rrb).await.unwrap().1;
+  _4(rrb.body(), rrb.headers());
+
+  rrb.clear();
+  rrb.body.extend_from_copyable_slice(&[7; 20_000]).unwrap();
+  let mut sink = BytesStream::default();
+  let mut progress = 0;
+  let mut stream = http2.stream().await.unwrap();
+  let _ = stream.send_req(rrb.as_http2_request(Method::Post), &uri_ref).await.unwrap();
+  rrb = stream.recv_res_stream(rrb, &mut sink, |received, _| progress = received).await.unwrap().1;
+  assert_eq!((rrb.body().len(), progress), (0, 20_000));
+  let mut body = [0; 20_000];
+  assert_eq!(sink.read(&mut body).await.unwrap(), 20_000);
+  _4(&body, rrb.headers());
+
   http2.send_go_away(Http2ErrorCode::NoError).await;
 
   tokio::time::sleep(Duration::from_millis(100)).await;
@@ -80,10 +283,18 @@ async fn server(uri: &UriString) {
       _2(req.rrd.body(), req.rrd.headers());
     })
     .await;
-    let _rrb = stream_server(&mut http2, rrb, |req| {
+    rrb = stream_server(&mut http2, rrb, |req| {
       _3(req.rrd.body(), req.rrd.headers());
     })
     .await;
+    rrb = stream_server(&mut http2, rrb, |req| {
+      _4(req.rrd.body(), req.rrd.headers());
+    })
+    .await;
+    let _rrb = stream_server(&mut http2, rrb, |req| {
+      _4(req.rrd.body(), req.rrd.headers());
+    })
+    .await;
   });
 }
 
@@ -113,6 +324,41 @@ async fn stream_client(
   stream.recv_res(rrb).await.unwrap().1
 }
 
+async fn raw_accept(
+  listener: &TcpListener,
+  hp: Http2Params,
+) -> Http2Tokio<Http2Buffer, OwnedWriteHalf, false> {
+  let (stream, _) = listener.accept().await.unwrap();
+  let (frame_reader, http2) = Http2Tokio::accept(
+    Http2Buffer::new(&mut Xorshift64::from(simple_seed())),
+    hp,
+    stream.into_split(),
+  )
+  .await
+  .unwrap();
+  let _jh = tokio::spawn(frame_reader);
+  http2
+}
+
+// Connects without an HTTP/2 client to send frames that are not supported by the client
+// implementation.
+async fn raw_connect(uri: &UriString) -> TcpStream {
+  let mut stream = TcpStream::connect(uri.hostname_with_implied_port()).await.unwrap();
+  stream.write_all(b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n").await.unwrap();
+  stream.write_all(&[0, 0, 0, 4, 0, 0, 0, 0, 0]).await.unwrap();
+  stream
+}
+
+// Returns the type and the stream ID of the next frame.
+async fn raw_frame(stream: &mut TcpStream) -> (u8, u32) {
+  let mut header = [0; 9];
+  let _ = stream.read_exact(&mut header).await.unwrap();
+  let [a, b, c, ty, _, d, e, f, g] = header;
+  let mut payload = vec![0; usize::try_from(u32::from_be_bytes([0, a, b, c])).unwrap()];
+  let _ = stream.read_exact(&mut payload).await.unwrap();
+  (ty, u32::from_be_bytes([d, e, f, g]))
+}
+
 #[track_caller]
 fn _0(body: &[u8], headers: &Headers) {
   assert_eq!((body.len(), headers.bytes_len(), headers.headers_len()), (0, 0, 0));
@@ -129,3 +375,8 @@ fn _2(body: &[u8], headers: &Headers) {
 fn _3(body: &[u8], headers: &Headers) {
   assert_eq!((body.len(), headers.bytes_len(), headers.headers_len()), (3, 6, 1));
 }
+#[track_caller]
+fn _4(body: &[u8], headers: &Headers) {
+  assert_eq!((body.len(), headers.bytes_len(), headers.headers_len()), (20_000, 0, 0));
+  assert!(body.iter().all(|el| *el == 7));
+}
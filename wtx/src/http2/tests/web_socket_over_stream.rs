@@ -0,0 +1,64 @@
+use crate::{
+  http::{Headers, Protocol, ReqResBuffer},
+  http2::{Http2Buffer, Http2ErrorCode, Http2Params, Http2Tokio, WebSocketOverStream},
+  misc::{Either, Vector, Xorshift64, simple_seed},
+  tests::_uri,
+  web_socket::{Frame, OpCode},
+};
+use core::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+
+#[tokio::test]
+async fn web_socket_over_stream() {
+  let uri = _uri();
+  let listener = TcpListener::bind(uri.hostname_with_implied_port()).await.unwrap();
+  let _server_jh = tokio::spawn(async move {
+    let (tcp_stream, _) = listener.accept().await.unwrap();
+    let (frame_reader, mut http2) = Http2Tokio::accept(
+      Http2Buffer::new(&mut Xorshift64::from(simple_seed())),
+      Http2Params::default().set_enable_connect_protocol(true),
+      tcp_stream.into_split(),
+    )
+    .await
+    .unwrap();
+    let _jh = tokio::spawn(frame_reader);
+    let Either::Right((stream, protocol)) =
+      http2.stream(ReqResBuffer::empty(), |_, protocol| protocol).await.unwrap()
+    else {
+      panic!();
+    };
+    assert_eq!(protocol, Some(Protocol::WebSocket));
+    let mut buffer = Vector::new();
+    let mut wos =
+      WebSocketOverStream::new(&Headers::new(), false, Xorshift64::from(simple_seed()), stream)
+        .await
+        .unwrap();
+    let mut frame = wos.read_frame(&mut buffer).await.unwrap();
+    wos.write_frame(&mut Frame::new_fin(OpCode::Text, frame.payload_mut())).await.unwrap();
+  });
+
+  let (frame_reader, mut http2) = Http2Tokio::connect(
+    Http2Buffer::new(&mut Xorshift64::from(simple_seed())),
+    Http2Params::default(),
+    TcpStream::connect(uri.hostname_with_implied_port()).await.unwrap().into_split(),
+  )
+  .await
+  .unwrap();
+  let _jh = tokio::spawn(frame_reader);
+  let stream = http2.stream().await.unwrap();
+  let mut buffer = Vector::new();
+  let mut wos = WebSocketOverStream::connect(
+    &Headers::new(),
+    false,
+    Xorshift64::from(simple_seed()),
+    stream,
+    &uri.to_ref(),
+  )
+  .await
+  .unwrap();
+  wos.write_frame(&mut Frame::new_fin(OpCode::Text, *b"Hello")).await.unwrap();
+  let frame = wos.read_frame(&mut buffer).await.unwrap();
+  assert_eq!(frame.text_payload(), Some("Hello"));
+  http2.send_go_away(Http2ErrorCode::NoError).await;
+  tokio::time::sleep(Duration::from_millis(100)).await;
+}
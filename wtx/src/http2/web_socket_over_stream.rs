@@ -1,14 +1,14 @@
 //! Tools to manage WebSocket connections in HTTP/2 streams
 
 use crate::{
-  http::{Headers, StatusCode},
-  http2::{Http2Buffer, Http2Data, Http2ErrorCode, Http2RecvStatus, SendDataMode, ServerStream},
-  misc::{
-    ConnectionState, LeaseMut, Lock, RefCounter, SingleTypeStorage, StreamWriter, Vector,
-    Xorshift64,
+  http::{Headers, Protocol, ReqResBuffer, StatusCode},
+  http2::{
+    ClientStream, Http2Buffer, Http2Data, Http2ErrorCode, Http2RecvStatus, Http2Stream,
+    SendDataMode,
   },
+  misc::{ConnectionState, LeaseMut, Lock, RefCounter, StreamWriter, UriRef, Vector, Xorshift64},
   web_socket::{
-    Frame, FrameMut, OpCode, ReadFrameInfo,
+    Frame, FrameMut, OpCode, ReadFrameInfo, WebSocketError,
     web_socket_reader::{
       manage_auto_reply, manage_op_code_of_continuation_frames,
       manage_op_code_of_first_continuation_frame, manage_op_code_of_first_final_frame,
@@ -20,20 +20,23 @@ use crate::{
 };
 
 /// WebSocket tunneling
+///
+/// Servers accept Extended CONNECT requests through [`WebSocketOverStream::new`] while clients
+/// send them through [`WebSocketOverStream::connect`].
 #[derive(Debug)]
-pub struct WebSocketOverStream<S> {
+pub struct WebSocketOverStream<S, const IS_CLIENT: bool = false> {
   connection_state: ConnectionState,
   no_masking: bool,
   rng: Xorshift64,
   stream: S,
 }
 
-impl<HB, HD, S, SW> WebSocketOverStream<S>
+impl<HB, HD, S, SW> WebSocketOverStream<S, false>
 where
   HB: LeaseMut<Http2Buffer>,
   HD: RefCounter,
   HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
-  S: LeaseMut<ServerStream<HD>> + SingleTypeStorage<Item = HD>,
+  S: Http2Stream<false, HD = HD>,
   SW: StreamWriter,
 {
   /// Creates a new instance sending an `Ok` status codes that confirms the WebSocket handshake.
@@ -44,18 +47,65 @@ where
     rng: Xorshift64,
     mut stream: S,
   ) -> crate::Result<Self> {
-    let hss = stream.lease_mut().common().send_headers(headers, false, StatusCode::Ok).await?;
+    let hss = stream.common().send_headers(headers, false, StatusCode::Ok).await?;
     if hss.is_closed() {
       return Err(crate::Error::ClosedConnection);
     }
     Ok(Self { connection_state: ConnectionState::Open, no_masking, rng, stream })
   }
+}
+
+impl<HB, HD, S, SW> WebSocketOverStream<S, true>
+where
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, true>>,
+  S: Http2Stream<true, HD = HD> + LeaseMut<ClientStream<HD>>,
+  SW: StreamWriter,
+{
+  /// Creates a new instance sending an Extended CONNECT request that asks for the tunneling of
+  /// WebSocket frames. The server must respond with an `Ok` status code.
+  #[inline]
+  pub async fn connect(
+    headers: &Headers,
+    no_masking: bool,
+    rng: Xorshift64,
+    mut stream: S,
+    uri: &UriRef<'_>,
+  ) -> crate::Result<Self> {
+    let hrs = stream
+      .lease_mut()
+      .send_connect(headers, Protocol::WebSocket, ReqResBuffer::empty(), uri)
+      .await?;
+    match hrs {
+      Http2RecvStatus::Ongoing(StatusCode::Ok) => {}
+      Http2RecvStatus::ClosedConnection
+      | Http2RecvStatus::ClosedStream
+      | Http2RecvStatus::Eos(_) => {
+        return Err(crate::Error::ClosedConnection);
+      }
+      Http2RecvStatus::Ongoing(_) => {
+        stream.common().send_reset(Http2ErrorCode::Cancel).await;
+        return Err(WebSocketError::MissingSwitchingProtocols.into());
+      }
+    }
+    Ok(Self { connection_state: ConnectionState::Open, no_masking, rng, stream })
+  }
+}
 
+impl<HB, HD, S, SW, const IS_CLIENT: bool> WebSocketOverStream<S, IS_CLIENT>
+where
+  HB: LeaseMut<Http2Buffer>,
+  HD: RefCounter,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, IS_CLIENT>>,
+  S: Http2Stream<IS_CLIENT, HD = HD>,
+  SW: StreamWriter,
+{
   /// Closes the stream as well as the WebSocket connection.
   #[inline]
   pub async fn close(&mut self) -> crate::Result<()> {
     self.write_frame(&mut Frame::new_fin(OpCode::Close, &mut [])).await?;
-    self.stream.lease_mut().common().send_reset(Http2ErrorCode::NoError).await;
+    self.stream.common().send_reset(Http2ErrorCode::NoError).await;
     Ok(())
   }
 
@@ -67,24 +117,25 @@ where
   pub async fn read_frame<'buffer>(
     &mut self,
     buffer: &'buffer mut Vector<u8>,
-  ) -> crate::Result<FrameMut<'buffer, false>> {
+  ) -> crate::Result<FrameMut<'buffer, IS_CLIENT>> {
     buffer.clear();
     let first_rfi = loop {
-      let (rfi, is_eos) = recv_data(buffer, self.no_masking, self.stream.lease_mut()).await?;
+      let (rfi, is_eos) =
+        recv_data::<HB, HD, S, SW, IS_CLIENT>(buffer, self.no_masking, &mut self.stream).await?;
       if !rfi.fin {
         if is_eos {
           return Err(crate::Error::ClosedConnection);
         }
         break rfi;
       }
-      if manage_auto_reply::<_, _, false>(
-        self.stream.lease_mut(),
+      if manage_auto_reply::<_, _, IS_CLIENT>(
+        &mut self.stream,
         &mut self.connection_state,
         self.no_masking,
         rfi.op_code,
         buffer,
         &mut self.rng,
-        &mut write_control_frame_cb,
+        &mut write_control_frame_cb::<HB, HD, S, SW, IS_CLIENT>,
       )
       .await?
       {
@@ -93,7 +144,8 @@ where
       }
     };
     loop {
-      let (rfi, is_eos) = recv_data(buffer, self.no_masking, self.stream.lease_mut()).await?;
+      let (rfi, is_eos) =
+        recv_data::<HB, HD, S, SW, IS_CLIENT>(buffer, self.no_masking, &mut self.stream).await?;
       if !rfi.fin && is_eos {
         return Err(crate::Error::ClosedConnection);
       }
@@ -104,14 +156,14 @@ where
         manage_text_of_first_continuation_frame,
       )?;
       let payload = buffer.get_mut(begin..).unwrap_or_default();
-      if !manage_auto_reply::<_, _, false>(
-        self.stream.lease_mut(),
+      if !manage_auto_reply::<_, _, IS_CLIENT>(
+        &mut self.stream,
         &mut self.connection_state,
         self.no_masking,
         rfi.op_code,
         payload,
         &mut self.rng,
-        &mut write_control_frame_cb,
+        &mut write_control_frame_cb::<HB, HD, S, SW, IS_CLIENT>,
       )
       .await?
       {
@@ -133,11 +185,11 @@ where
 
   /// Writes a frame to the stream.
   #[inline]
-  pub async fn write_frame<P>(&mut self, frame: &mut Frame<P, false>) -> crate::Result<()>
+  pub async fn write_frame<P>(&mut self, frame: &mut Frame<P, IS_CLIENT>) -> crate::Result<()>
   where
     P: LeaseMut<[u8]>,
   {
-    manage_normal_frame::<_, _, false>(
+    manage_normal_frame::<_, _, IS_CLIENT>(
       &mut self.connection_state,
       frame,
       self.no_masking,
@@ -146,7 +198,6 @@ where
     let (header, payload) = frame.header_and_payload();
     let hss = self
       .stream
-      .lease_mut()
       .common()
       .send_data(SendDataMode::single_data_frame([header, payload.lease()]), false)
       .await?;
@@ -158,15 +209,16 @@ where
 }
 
 #[inline]
-async fn recv_data<HB, HD, SW>(
+async fn recv_data<HB, HD, S, SW, const IS_CLIENT: bool>(
   buffer: &mut Vector<u8>,
   no_masking: bool,
-  stream: &mut ServerStream<HD>,
+  stream: &mut S,
 ) -> crate::Result<(ReadFrameInfo, bool)>
 where
   HB: LeaseMut<Http2Buffer>,
   HD: RefCounter,
-  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, IS_CLIENT>>,
+  S: Http2Stream<IS_CLIENT, HD = HD>,
   SW: StreamWriter,
 {
   let (data, is_eos) = match stream.common().recv_data().await? {
@@ -180,23 +232,24 @@ where
     Http2RecvStatus::Ongoing(data) => (data, false),
   };
   let mut slice = data.as_slice();
-  let rfi = ReadFrameInfo::from_bytes::<false>(&mut slice, usize::MAX, (true, 0), no_masking)?;
+  let rfi = ReadFrameInfo::from_bytes::<IS_CLIENT>(&mut slice, usize::MAX, (true, 0), no_masking)?;
   let before = buffer.len();
   buffer.extend_from_copyable_slice(slice)?;
-  unmask_nb::<false>(buffer.get_mut(before..).unwrap_or_default(), no_masking, &rfi)?;
+  unmask_nb::<IS_CLIENT>(buffer.get_mut(before..).unwrap_or_default(), no_masking, &rfi)?;
   Ok((rfi, is_eos))
 }
 
 #[inline]
-async fn write_control_frame_cb<HB, HD, SW>(
-  stream: &mut ServerStream<HD>,
+async fn write_control_frame_cb<HB, HD, S, SW, const IS_CLIENT: bool>(
+  stream: &mut S,
   header: &[u8],
   payload: &[u8],
 ) -> crate::Result<()>
 where
   HB: LeaseMut<Http2Buffer>,
   HD: RefCounter,
-  HD::Item: Lock<Resource = Http2Data<HB, SW, false>>,
+  HD::Item: Lock<Resource = Http2Data<HB, SW, IS_CLIENT>>,
+  S: Http2Stream<IS_CLIENT, HD = HD>,
   SW: StreamWriter,
 {
   let array = [header, payload];
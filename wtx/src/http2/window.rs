@@ -121,6 +121,36 @@ impl Windows {
   pub(crate) fn send_mut(&mut self) -> &mut Window {
     &mut self.send
   }
+
+  /// Withdrawn - Receive
+  ///
+  /// Connection counterpart of [`WindowsPair::withdrawn_recv`] used by frames of streams that
+  /// are no longer tracked.
+  #[inline]
+  pub(crate) async fn withdrawn_conn_recv<SW>(
+    &mut self,
+    hp: &Http2Params,
+    is_conn_open: &AtomicBool,
+    stream_writer: &mut SW,
+    value: U31,
+  ) -> crate::Result<()>
+  where
+    SW: StreamWriter,
+  {
+    self.recv.withdrawn(None, value.i32())?;
+    if self.recv.is_invalid() {
+      let iwl = U31::from_u32(hp.initial_window_len()).i32();
+      let conn_value = self.recv.available().abs().wrapping_add(iwl);
+      self.recv.deposit(None, conn_value)?;
+      write_array(
+        [&WindowUpdateFrame::new(U31::from_i32(conn_value), U31::ZERO)?.bytes()],
+        is_conn_open,
+        stream_writer,
+      )
+      .await?;
+    }
+    Ok(())
+  }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,19 @@
+//! HTTP/3
+//!
+//! Experimental client that runs over any QUIC implementation that implements
+//! [`QuicConnection`].
+//!
+//! 1. Only the static table of QPACK is supported, i.e., the capacity of the dynamic table is
+//!    always zero.
+//! 2. Does not support server push.
+//! 3. Responses are fully received before being processed.
+
+mod frame;
+mod http3_client;
+mod http3_error;
+mod qpack;
+mod quic;
+
+pub use http3_client::Http3Client;
+pub use http3_error::Http3Error;
+pub use quic::{QuicConnection, QuicStream};
@@ -0,0 +1,94 @@
+use crate::{http3::Http3Error, misc::Vector};
+
+pub(crate) const CONTROL_STREAM_TY: u64 = 0x00;
+pub(crate) const DATA_FRAME_TY: u64 = 0x00;
+pub(crate) const HEADERS_FRAME_TY: u64 = 0x01;
+pub(crate) const SETTINGS_FRAME_TY: u64 = 0x04;
+
+/// Returns the type, the payload and the remaining bytes of the first frame of `bytes`.
+#[inline]
+pub(crate) fn decode_frame(bytes: &[u8]) -> crate::Result<(u64, &[u8], &[u8])> {
+  let (ty, rest) = decode_varint(bytes)?;
+  let (len, rest) = decode_varint(rest)?;
+  let Some((payload, rest)) = usize::try_from(len).ok().and_then(|el| rest.split_at_checked(el))
+  else {
+    return Err(Http3Error::InsufficientBytes.into());
+  };
+  Ok((ty, payload, rest))
+}
+
+/// Returns the integer and the remaining bytes of a variable-length integer (RFC 9000).
+#[inline]
+pub(crate) fn decode_varint(bytes: &[u8]) -> crate::Result<(u64, &[u8])> {
+  let [first, ..] = bytes else {
+    return Err(Http3Error::InsufficientBytes.into());
+  };
+  let Some((varint, rest)) = bytes.split_at_checked(1usize << (first >> 6)) else {
+    return Err(Http3Error::InsufficientBytes.into());
+  };
+  let mut rslt = u64::from(first & 0b0011_1111);
+  for byte in varint.get(1..).unwrap_or_default() {
+    rslt = (rslt << 8) | u64::from(*byte);
+  }
+  Ok((rslt, rest))
+}
+
+#[inline]
+pub(crate) fn encode_frame_header(
+  buffer: &mut Vector<u8>,
+  len: usize,
+  ty: u64,
+) -> crate::Result<()> {
+  encode_varint(buffer, ty)?;
+  encode_varint(buffer, u64::try_from(len)?)
+}
+
+#[inline]
+pub(crate) fn encode_varint(buffer: &mut Vector<u8>, value: u64) -> crate::Result<()> {
+  let [b0, b1, b2, b3, b4, b5, b6, b7] = value.to_be_bytes();
+  match value {
+    0..=0x3F => buffer.push(b7)?,
+    0x40..=0x3FFF => buffer.extend_from_copyable_slice(&[b6 | 0b0100_0000, b7])?,
+    0x4000..=0x3FFF_FFFF => {
+      buffer.extend_from_copyable_slice(&[b4 | 0b1000_0000, b5, b6, b7])?;
+    }
+    0x4000_0000..=0x3FFF_FFFF_FFFF_FFFF => {
+      buffer.extend_from_copyable_slice(&[b0 | 0b1100_0000, b1, b2, b3, b4, b5, b6, b7])?;
+    }
+    _ => return Err(Http3Error::LargeInteger.into()),
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http3::frame::{decode_frame, decode_varint, encode_frame_header, encode_varint},
+    misc::Vector,
+  };
+
+  #[test]
+  fn frames_are_decoded() {
+    let mut buffer = Vector::new();
+    encode_frame_header(&mut buffer, 3, 0x01).unwrap();
+    buffer.extend_from_copyable_slice(&[1, 2, 3, 4]).unwrap();
+    assert_eq!(decode_frame(&buffer).unwrap(), (0x01, &[1, 2, 3][..], &[4][..]));
+    assert!(decode_frame(&[0, 5, 1]).is_err());
+  }
+
+  #[test]
+  fn varints_are_encoded_with_the_smallest_length() {
+    for (value, bytes) in [
+      (37, &[0x25][..]),
+      (15_293, &[0x7B, 0xBD][..]),
+      (494_878_333, &[0x9D, 0x7F, 0x3E, 0x7D][..]),
+      (151_288_809_941_952_652, &[0xC2, 0x19, 0x7C, 0x5E, 0xFF, 0x14, 0xE8, 0x8C][..]),
+    ] {
+      let mut buffer = Vector::new();
+      encode_varint(&mut buffer, value).unwrap();
+      assert_eq!(buffer.as_slice(), bytes);
+      assert_eq!(decode_varint(bytes).unwrap(), (value, &[][..]));
+    }
+    assert!(encode_varint(&mut Vector::new(), u64::MAX).is_err());
+  }
+}
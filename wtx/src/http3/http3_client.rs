@@ -0,0 +1,323 @@
+use crate::{
+  http::{
+    Header, Headers, HttpClient, HttpError, KnownHeaderName, Method, ReqResBuffer, ReqResData,
+    Response, StatusCode, Version,
+  },
+  http3::{
+    Http3Error, QuicConnection, QuicStream,
+    frame::{
+      CONTROL_STREAM_TY, DATA_FRAME_TY, HEADERS_FRAME_TY, SETTINGS_FRAME_TY, decode_frame,
+      encode_frame_header, encode_varint,
+    },
+    qpack::{QpackDecoder, encode_field_line, encode_field_section_prefix},
+  },
+  misc::{BufferMode, Lease, StreamReader, StreamWriter, UriRef, Vector},
+};
+use core::fmt::{Debug, Formatter};
+
+const READ_LEN: usize = 1024 * 16;
+
+/// HTTP/3 client that sends each request through a new bidirectional stream of a QUIC
+/// connection.
+pub struct Http3Client<C>
+where
+  C: QuicConnection,
+{
+  buffer: Vector<u8>,
+  conn: C,
+  control: Option<C::UniStream>,
+  qpack_dec: QpackDecoder,
+}
+
+impl<C> Http3Client<C>
+where
+  C: QuicConnection,
+{
+  /// Creates a new instance over an already established QUIC connection.
+  ///
+  /// The control stream is lazily opened when the first request is sent.
+  #[inline]
+  pub const fn new(conn: C) -> Self {
+    Self { buffer: Vector::new(), conn, control: None, qpack_dec: QpackDecoder::new() }
+  }
+
+  /// Underlying QUIC connection.
+  #[inline]
+  pub fn conn(&self) -> &C {
+    &self.conn
+  }
+
+  /// Mutable version of [`Self::conn`].
+  #[inline]
+  pub fn conn_mut(&mut self) -> &mut C {
+    &mut self.conn
+  }
+
+  async fn open_control_stream(&mut self) -> crate::Result<()> {
+    if self.control.is_some() {
+      return Ok(());
+    }
+    let mut stream = self.conn.open_uni().await?;
+    self.buffer.clear();
+    encode_varint(&mut self.buffer, CONTROL_STREAM_TY)?;
+    encode_frame_header(&mut self.buffer, 0, SETTINGS_FRAME_TY)?;
+    stream.write_all(&self.buffer).await?;
+    self.control = Some(stream);
+    Ok(())
+  }
+
+  // Opens a stream and sends a HEADERS frame. The field section is placed at the beginning of the
+  // buffer followed by the HEADERS frame header and, if applicable, by the DATA frame header.
+  async fn send_headers(
+    &mut self,
+    body_len: Option<usize>,
+    headers: &Headers,
+    method: Method,
+    uri: &UriRef<'_>,
+  ) -> crate::Result<(C::BiStream, usize)> {
+    self.open_control_stream().await?;
+    let mut stream = self.conn.open_bi().await?;
+    self.buffer.clear();
+    encode_field_section_prefix(&mut self.buffer)?;
+    encode_field_line(&mut self.buffer, (":method", method.strings().custom[0]))?;
+    encode_field_line(&mut self.buffer, (":scheme", uri.scheme()))?;
+    encode_field_line(&mut self.buffer, (":authority", uri.authority()))?;
+    encode_field_line(&mut self.buffer, (":path", uri.relative_reference_slash()))?;
+    for header in headers.iter() {
+      encode_field_line(&mut self.buffer, (header.name, header.value))?;
+    }
+    let section_len = self.buffer.len();
+    encode_frame_header(&mut self.buffer, section_len, HEADERS_FRAME_TY)?;
+    let headers_len = self.buffer.len();
+    if let Some(elem) = body_len.filter(|el| *el > 0) {
+      encode_frame_header(&mut self.buffer, elem, DATA_FRAME_TY)?;
+    }
+    let (section, frame_headers) =
+      self.buffer.as_slice().split_at_checked(section_len).unwrap_or_default();
+    let (headers_fh, data_fh) =
+      frame_headers.split_at_checked(headers_len.wrapping_sub(section_len)).unwrap_or_default();
+    stream.write_all_vectored(&[headers_fh, section]).await?;
+    Ok((stream, data_fh.len()))
+  }
+
+  async fn read_res(&mut self, stream: &mut C::BiStream) -> crate::Result<()> {
+    self.buffer.clear();
+    loop {
+      let len = self.buffer.len();
+      self.buffer.expand(BufferMode::Additional(READ_LEN), 0)?;
+      let rslt = stream.read(self.buffer.get_mut(len..).unwrap_or_default()).await;
+      self.buffer.truncate(len.wrapping_add(*rslt.as_ref().unwrap_or(&0)));
+      if rslt? == 0 {
+        return Ok(());
+      }
+    }
+  }
+}
+
+impl<C> Debug for Http3Client<C>
+where
+  C: Debug + QuicConnection,
+{
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("Http3Client").field("conn", &self.conn).finish_non_exhaustive()
+  }
+}
+
+impl<C> HttpClient for Http3Client<C>
+where
+  C: QuicConnection,
+{
+  type ReqId = C::BiStream;
+
+  #[inline]
+  async fn recv_res(
+    &mut self,
+    mut rrb: ReqResBuffer,
+    mut req_id: Self::ReqId,
+  ) -> crate::Result<Response<ReqResBuffer>> {
+    self.read_res(&mut req_id).await?;
+    rrb.body.clear();
+    rrb.headers.clear();
+    let mut bytes = self.buffer.as_slice();
+    let status_code = decode_headers(&mut bytes, &mut rrb.headers, &mut self.qpack_dec)?;
+    while let Some(data) = next_data(&mut bytes, &mut rrb.headers, &mut self.qpack_dec)? {
+      rrb.body.extend_from_copyable_slice(data)?;
+    }
+    Ok(Response::new(rrb, status_code, Version::Http3))
+  }
+
+  #[inline]
+  async fn recv_stream<S>(
+    &mut self,
+    mut rrb: ReqResBuffer,
+    mut req_id: Self::ReqId,
+    sink: &mut S,
+    mut progress: impl FnMut(u64, Option<u64>),
+  ) -> crate::Result<Response<ReqResBuffer>>
+  where
+    S: StreamWriter,
+  {
+    self.read_res(&mut req_id).await?;
+    rrb.body.clear();
+    rrb.headers.clear();
+    let mut bytes = self.buffer.as_slice();
+    let status_code = decode_headers(&mut bytes, &mut rrb.headers, &mut self.qpack_dec)?;
+    let content_length = rrb
+      .headers
+      .get_by_name(KnownHeaderName::ContentLength.into())
+      .and_then(|el| el.value.parse().ok());
+    let mut received: u64 = 0;
+    while let Some(data) = next_data(&mut bytes, &mut rrb.headers, &mut self.qpack_dec)? {
+      sink.write_all(data).await?;
+      received = received.wrapping_add(u64::try_from(data.len())?);
+      progress(received, content_length);
+    }
+    Ok(Response::new(rrb, status_code, Version::Http3))
+  }
+
+  #[inline]
+  async fn send_req<RRD>(
+    &mut self,
+    method: Method,
+    rrd: RRD,
+    uri: &UriRef<'_>,
+  ) -> crate::Result<Self::ReqId>
+  where
+    RRD: ReqResData,
+    RRD::Body: Lease<[u8]>,
+  {
+    let body = rrd.body().lease();
+    let (mut stream, data_fh_len) =
+      self.send_headers(Some(body.len()), rrd.headers(), method, uri).await?;
+    if data_fh_len > 0 {
+      let data_fh = self.buffer.get(self.buffer.len().wrapping_sub(data_fh_len)..);
+      stream.write_all_vectored(&[data_fh.unwrap_or_default(), body]).await?;
+    }
+    stream.finish().await?;
+    Ok(stream)
+  }
+
+  #[inline]
+  async fn send_stream<SR>(
+    &mut self,
+    method: Method,
+    (sr, buffer): (&mut SR, &mut [u8]),
+    headers: &Headers,
+    uri: &UriRef<'_>,
+  ) -> crate::Result<Self::ReqId>
+  where
+    SR: StreamReader,
+  {
+    let (mut stream, _) = self.send_headers(None, headers, method, uri).await?;
+    loop {
+      let read = sr.read(buffer).await?;
+      if read == 0 {
+        break;
+      }
+      self.buffer.clear();
+      encode_frame_header(&mut self.buffer, read, DATA_FRAME_TY)?;
+      stream.write_all_vectored(&[&self.buffer, buffer.get(..read).unwrap_or_default()]).await?;
+    }
+    stream.finish().await?;
+    Ok(stream)
+  }
+}
+
+// Interim responses are discarded and unknown frames are ignored.
+fn decode_headers(
+  bytes: &mut &[u8],
+  headers: &mut Headers,
+  qpack_dec: &mut QpackDecoder,
+) -> crate::Result<StatusCode> {
+  loop {
+    if bytes.is_empty() {
+      return Err(Http3Error::MissingHeadersFrame.into());
+    }
+    let (ty, payload, rest) = decode_frame(bytes)?;
+    *bytes = rest;
+    match ty {
+      DATA_FRAME_TY => return Err(Http3Error::UnexpectedDataFrame.into()),
+      HEADERS_FRAME_TY => {
+        let mut status_code = None;
+        headers.clear();
+        qpack_dec.decode(payload, |(name, value)| {
+          if name == ":status" {
+            status_code = Some(StatusCode::try_from(value)?);
+          } else if !name.starts_with(':') {
+            headers.push_from_iter(Header::from_name_and_value(name, [value]))?;
+          }
+          Ok(())
+        })?;
+        let Some(elem) = status_code else {
+          return Err(HttpError::MissingResponseStatusCode.into());
+        };
+        if !(100..200).contains(&u16::from(elem)) {
+          return Ok(elem);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+// Returns the payload of the next DATA frame. Trailers are pushed into `headers`.
+fn next_data<'bytes>(
+  bytes: &mut &'bytes [u8],
+  headers: &mut Headers,
+  qpack_dec: &mut QpackDecoder,
+) -> crate::Result<Option<&'bytes [u8]>> {
+  while !bytes.is_empty() {
+    let (ty, payload, rest) = decode_frame(bytes)?;
+    *bytes = rest;
+    match ty {
+      DATA_FRAME_TY => return Ok(Some(payload)),
+      HEADERS_FRAME_TY => {
+        qpack_dec.decode(payload, |(name, value)| {
+          headers.push_from_iter(Header {
+            is_sensitive: false,
+            is_trailer: true,
+            name,
+            value: [value],
+          })
+        })?;
+      }
+      _ => {}
+    }
+  }
+  Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http::{Headers, StatusCode},
+    http3::{
+      frame::{DATA_FRAME_TY, HEADERS_FRAME_TY, encode_frame_header},
+      http3_client::{decode_headers, next_data},
+      qpack::QpackDecoder,
+    },
+    misc::Vector,
+  };
+
+  #[test]
+  fn responses_are_decoded() {
+    let mut buffer = Vector::new();
+    let interim = [0, 0, 0b1111_1111, 0];
+    encode_frame_header(&mut buffer, interim.len(), HEADERS_FRAME_TY).unwrap();
+    buffer.extend_from_copyable_slice(&interim).unwrap();
+    let final_headers = [0, 0, 0b1101_1001, 0b1110_1110];
+    encode_frame_header(&mut buffer, final_headers.len(), HEADERS_FRAME_TY).unwrap();
+    buffer.extend_from_copyable_slice(&final_headers).unwrap();
+    encode_frame_header(&mut buffer, 2, DATA_FRAME_TY).unwrap();
+    buffer.extend_from_copyable_slice(b"{}").unwrap();
+    let mut bytes = buffer.as_slice();
+    let mut headers = Headers::new();
+    let mut qpack_dec = QpackDecoder::new();
+    let status_code = decode_headers(&mut bytes, &mut headers, &mut qpack_dec).unwrap();
+    assert_eq!(status_code, StatusCode::Ok);
+    assert_eq!(headers.get_by_name(b"content-type").unwrap().value, "application/json");
+    assert_eq!(next_data(&mut bytes, &mut headers, &mut qpack_dec).unwrap(), Some(&b"{}"[..]));
+    assert_eq!(next_data(&mut bytes, &mut headers, &mut qpack_dec).unwrap(), None);
+  }
+}
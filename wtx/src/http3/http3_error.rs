@@ -0,0 +1,16 @@
+/// HTTP/3 Error
+#[derive(Debug)]
+pub enum Http3Error {
+  /// A field line referenced the QPACK dynamic table, whose capacity is always zero.
+  DynamicTableIsNotSupported,
+  /// A frame, an integer or a field section ended prematurely.
+  InsufficientBytes,
+  /// A field line referenced an index that is not part of the QPACK static table.
+  InvalidStaticTableIndex,
+  /// A QPACK integer or a variable-length integer is larger than the supported maximum.
+  LargeInteger,
+  /// The response stream was finished without a HEADERS frame.
+  MissingHeadersFrame,
+  /// A DATA frame was received before the HEADERS frame.
+  UnexpectedDataFrame,
+}
@@ -0,0 +1,305 @@
+use crate::{
+  http::{_HeaderNameBuffer, _HeaderValueBuffer},
+  http2::huffman_decode,
+  http3::Http3Error,
+  misc::{ArrayVector, Vector, from_utf8_basic},
+};
+
+// RFC 9204, Appendix A
+static STATIC_TABLE: [(&str, &str); 99] = [
+  (":authority", ""),
+  (":path", "/"),
+  ("age", "0"),
+  ("content-disposition", ""),
+  ("content-length", "0"),
+  ("cookie", ""),
+  ("date", ""),
+  ("etag", ""),
+  ("if-modified-since", ""),
+  ("if-none-match", ""),
+  ("last-modified", ""),
+  ("link", ""),
+  ("location", ""),
+  ("referer", ""),
+  ("set-cookie", ""),
+  (":method", "CONNECT"),
+  (":method", "DELETE"),
+  (":method", "GET"),
+  (":method", "HEAD"),
+  (":method", "OPTIONS"),
+  (":method", "POST"),
+  (":method", "PUT"),
+  (":scheme", "http"),
+  (":scheme", "https"),
+  (":status", "103"),
+  (":status", "200"),
+  (":status", "304"),
+  (":status", "404"),
+  (":status", "503"),
+  ("accept", "*/*"),
+  ("accept", "application/dns-message"),
+  ("accept-encoding", "gzip, deflate, br"),
+  ("accept-ranges", "bytes"),
+  ("access-control-allow-headers", "cache-control"),
+  ("access-control-allow-headers", "content-type"),
+  ("access-control-allow-origin", "*"),
+  ("cache-control", "max-age=0"),
+  ("cache-control", "max-age=2592000"),
+  ("cache-control", "max-age=604800"),
+  ("cache-control", "no-cache"),
+  ("cache-control", "no-store"),
+  ("cache-control", "public, max-age=31536000"),
+  ("content-encoding", "br"),
+  ("content-encoding", "gzip"),
+  ("content-type", "application/dns-message"),
+  ("content-type", "application/javascript"),
+  ("content-type", "application/json"),
+  ("content-type", "application/x-www-form-urlencoded"),
+  ("content-type", "image/gif"),
+  ("content-type", "image/jpeg"),
+  ("content-type", "image/png"),
+  ("content-type", "text/css"),
+  ("content-type", "text/html; charset=utf-8"),
+  ("content-type", "text/plain"),
+  ("content-type", "text/plain;charset=utf-8"),
+  ("range", "bytes=0-"),
+  ("strict-transport-security", "max-age=31536000"),
+  ("strict-transport-security", "max-age=31536000; includesubdomains"),
+  ("strict-transport-security", "max-age=31536000; includesubdomains; preload"),
+  ("vary", "accept-encoding"),
+  ("vary", "origin"),
+  ("x-content-type-options", "nosniff"),
+  ("x-xss-protection", "1; mode=block"),
+  (":status", "100"),
+  (":status", "204"),
+  (":status", "206"),
+  (":status", "302"),
+  (":status", "400"),
+  (":status", "403"),
+  (":status", "421"),
+  (":status", "425"),
+  (":status", "500"),
+  ("accept-language", ""),
+  ("access-control-allow-credentials", "FALSE"),
+  ("access-control-allow-credentials", "TRUE"),
+  ("access-control-allow-headers", "*"),
+  ("access-control-allow-methods", "get"),
+  ("access-control-allow-methods", "get, post, options"),
+  ("access-control-allow-methods", "options"),
+  ("access-control-expose-headers", "content-length"),
+  ("access-control-request-headers", "content-type"),
+  ("access-control-request-method", "get"),
+  ("access-control-request-method", "post"),
+  ("alt-svc", "clear"),
+  ("authorization", ""),
+  ("content-security-policy", "script-src 'none'; object-src 'none'; base-uri 'none'"),
+  ("early-data", "1"),
+  ("expect-ct", ""),
+  ("forwarded", ""),
+  ("if-range", ""),
+  ("origin", ""),
+  ("purpose", "prefetch"),
+  ("server", ""),
+  ("timing-allow-origin", "*"),
+  ("upgrade-insecure-requests", "1"),
+  ("user-agent", ""),
+  ("x-forwarded-for", ""),
+  ("x-frame-options", "deny"),
+  ("x-frame-options", "sameorigin"),
+];
+
+/// Decodes field sections that only reference the static table.
+#[derive(Debug)]
+pub(crate) struct QpackDecoder {
+  name: _HeaderNameBuffer,
+  value: _HeaderValueBuffer,
+}
+
+impl QpackDecoder {
+  #[inline]
+  pub(crate) const fn new() -> Self {
+    Self { name: ArrayVector::new(), value: ArrayVector::new() }
+  }
+
+  /// Calls `cb` with the name and the value of each field line of `data`.
+  #[inline]
+  pub(crate) fn decode(
+    &mut self,
+    mut data: &[u8],
+    mut cb: impl FnMut((&str, &str)) -> crate::Result<()>,
+  ) -> crate::Result<()> {
+    let (_, required_insert_count) = decode_int(&mut data, 0b1111_1111)?;
+    let _delta_base = decode_int(&mut data, 0b0111_1111)?;
+    if required_insert_count != 0 {
+      return Err(Http3Error::DynamicTableIsNotSupported.into());
+    }
+    while let [first, ..] = data {
+      if first & 0b1000_0000 == 0b1000_0000 {
+        if first & 0b0100_0000 == 0 {
+          return Err(Http3Error::DynamicTableIsNotSupported.into());
+        }
+        let (_, idx) = decode_int(&mut data, 0b0011_1111)?;
+        cb(static_entry(idx)?)?;
+      } else if first & 0b0100_0000 == 0b0100_0000 {
+        if first & 0b0001_0000 == 0 {
+          return Err(Http3Error::DynamicTableIsNotSupported.into());
+        }
+        let (_, idx) = decode_int(&mut data, 0b0000_1111)?;
+        let (name, _) = static_entry(idx)?;
+        let value = decode_str(&mut data, 0b1000_0000, &mut self.value)?;
+        cb((name, value))?;
+      } else if first & 0b0010_0000 == 0b0010_0000 {
+        let name = decode_str(&mut data, 0b0000_1000, &mut self.name)?;
+        let value = decode_str(&mut data, 0b1000_0000, &mut self.value)?;
+        cb((name, value))?;
+      } else {
+        return Err(Http3Error::DynamicTableIsNotSupported.into());
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Field sections are always composed by literal field lines with literal names, which don't
+/// require any interaction with the decoder of the peer.
+#[inline]
+pub(crate) fn encode_field_line(
+  buffer: &mut Vector<u8>,
+  (name, value): (&str, &str),
+) -> crate::Result<()> {
+  encode_int(buffer, 0b0010_0000, 0b0000_0111, name.len())?;
+  for byte in name.as_bytes() {
+    buffer.push(byte.to_ascii_lowercase())?;
+  }
+  encode_int(buffer, 0, 0b0111_1111, value.len())?;
+  buffer.extend_from_copyable_slice(value.as_bytes())
+}
+
+/// Required Insert Count and Delta Base are always zero.
+#[inline]
+pub(crate) fn encode_field_section_prefix(buffer: &mut Vector<u8>) -> crate::Result<()> {
+  buffer.extend_from_copyable_slice(&[0, 0])
+}
+
+#[inline]
+fn decode_int(data: &mut &[u8], mask: u8) -> crate::Result<(u8, usize)> {
+  let [first, rest @ ..] = data else {
+    return Err(Http3Error::InsufficientBytes.into());
+  };
+  *data = rest;
+  let n = *first & mask;
+  let mut rslt = (*first, usize::from(n));
+  if n < mask {
+    return Ok(rslt);
+  }
+  let mut shift: u32 = 0;
+  for _ in 0..4 {
+    let [first, rest @ ..] = data else {
+      return Err(Http3Error::InsufficientBytes.into());
+    };
+    *data = rest;
+    rslt.1 = rslt.1.wrapping_add(usize::from(first & 0b0111_1111) << shift);
+    shift = shift.wrapping_add(7);
+    if first & 0b1000_0000 == 0 {
+      return Ok(rslt);
+    }
+  }
+  Err(Http3Error::LargeInteger.into())
+}
+
+#[inline]
+fn decode_str<'buffer, 'data, const N: usize>(
+  data: &mut &'data [u8],
+  huffman_mask: u8,
+  buffer: &'buffer mut ArrayVector<u8, N>,
+) -> crate::Result<&'buffer str>
+where
+  'data: 'buffer,
+{
+  let (first, len) = decode_int(data, huffman_mask.wrapping_sub(1))?;
+  let Some((bytes, rest)) = data.split_at_checked(len) else {
+    return Err(Http3Error::InsufficientBytes.into());
+  };
+  *data = rest;
+  if first & huffman_mask == huffman_mask {
+    huffman_decode(bytes, buffer)
+  } else {
+    Ok(from_utf8_basic(bytes)?)
+  }
+}
+
+#[inline]
+fn encode_int(
+  buffer: &mut Vector<u8>,
+  first_byte: u8,
+  mask: u8,
+  mut n: usize,
+) -> crate::Result<()> {
+  #[inline]
+  fn last_byte(n: usize) -> u8 {
+    let [.., rslt] = n.to_be_bytes();
+    rslt
+  }
+
+  if n < usize::from(mask) {
+    return buffer.push(first_byte | last_byte(n));
+  }
+  buffer.push(first_byte | mask)?;
+  n = n.wrapping_sub(mask.into());
+  while n > 127 {
+    buffer.push(0b1000_0000 | last_byte(n))?;
+    n >>= 7;
+  }
+  buffer.push(last_byte(n))
+}
+
+#[inline]
+fn static_entry(idx: usize) -> crate::Result<(&'static str, &'static str)> {
+  STATIC_TABLE.get(idx).copied().ok_or_else(|| Http3Error::InvalidStaticTableIndex.into())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    http3::qpack::{QpackDecoder, encode_field_line, encode_field_section_prefix},
+    misc::Vector,
+  };
+  use alloc::{borrow::ToOwned, vec::Vec};
+
+  #[test]
+  fn encoded_field_lines_are_decoded() {
+    let mut buffer = Vector::new();
+    encode_field_section_prefix(&mut buffer).unwrap();
+    encode_field_line(&mut buffer, (":path", "/index.html")).unwrap();
+    encode_field_line(&mut buffer, ("X-Custom", &"a".repeat(300))).unwrap();
+    let mut fields = Vec::new();
+    QpackDecoder::new()
+      .decode(&buffer, |(name, value)| {
+        fields.push((name.to_owned(), value.to_owned()));
+        Ok(())
+      })
+      .unwrap();
+    assert_eq!(
+      fields,
+      [(":path".into(), "/index.html".into()), ("x-custom".into(), "a".repeat(300))]
+    );
+  }
+
+  #[test]
+  fn static_references_are_decoded() {
+    let mut fields = Vec::new();
+    QpackDecoder::new()
+      .decode(&[0, 0, 0b1101_1001, 0b0101_1111, 0b0001_1101, 3, b'b', b'a', b'r'], |el| {
+        fields.push(el.0.to_owned() + "=" + el.1);
+        Ok(())
+      })
+      .unwrap();
+    assert_eq!(fields, [":status=200", "content-type=bar"]);
+  }
+
+  #[test]
+  fn dynamic_references_are_rejected() {
+    assert!(QpackDecoder::new().decode(&[1, 0], |_| Ok(())).is_err());
+    assert!(QpackDecoder::new().decode(&[0, 0, 0b1000_0001], |_| Ok(())).is_err());
+  }
+}
@@ -0,0 +1,39 @@
+use crate::misc::{StreamReader, StreamWriter};
+
+/// An established QUIC connection provided by an external implementation.
+pub trait QuicConnection {
+  /// Bidirectional stream used by requests and responses.
+  type BiStream: QuicStream + StreamReader;
+  /// Unidirectional stream used by the control stream.
+  type UniStream: QuicStream;
+
+  /// Opens a new bidirectional stream.
+  fn open_bi(&mut self) -> impl Future<Output = crate::Result<Self::BiStream>>;
+
+  /// Opens a new unidirectional stream.
+  fn open_uni(&mut self) -> impl Future<Output = crate::Result<Self::UniStream>>;
+}
+
+impl<T> QuicConnection for &mut T
+where
+  T: QuicConnection,
+{
+  type BiStream = T::BiStream;
+  type UniStream = T::UniStream;
+
+  #[inline]
+  async fn open_bi(&mut self) -> crate::Result<Self::BiStream> {
+    (**self).open_bi().await
+  }
+
+  #[inline]
+  async fn open_uni(&mut self) -> crate::Result<Self::UniStream> {
+    (**self).open_uni().await
+  }
+}
+
+/// The sending part of a QUIC stream.
+pub trait QuicStream: StreamWriter {
+  /// Gracefully closes the sending part of the stream, which indicates the end of a message.
+  fn finish(&mut self) -> impl Future<Output = crate::Result<()>>;
+}
@@ -0,0 +1,17 @@
+//! JSON Web Tokens signed with HS256, RS256 or ES256.
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc7519>
+
+mod jwt_algorithm;
+mod jwt_claims;
+mod jwt_decoder;
+mod jwt_encoder;
+mod jwt_error;
+mod jwt_validation;
+
+pub use jwt_algorithm::JwtAlgorithm;
+pub use jwt_claims::{JwtAudience, JwtClaims};
+pub use jwt_decoder::JwtDecoder;
+pub use jwt_encoder::JwtEncoder;
+pub use jwt_error::JwtError;
+pub use jwt_validation::JwtValidation;
@@ -0,0 +1,32 @@
+use crate::jwt::JwtError;
+
+create_enum! {
+  /// Algorithms used to sign and verify tokens.
+  ///
+  /// <https://datatracker.ietf.org/doc/html/rfc7518#section-3.1>
+  #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+  pub enum JwtAlgorithm<u8> {
+    /// ECDSA using P-256 and SHA-256
+    Es256 = (0, "ES256"),
+    /// HMAC using SHA-256
+    Hs256 = (1, "HS256"),
+    /// RSASSA-PKCS1-v1_5 using SHA-256
+    Rs256 = (2, "RS256"),
+  }
+}
+
+impl JwtAlgorithm {
+  /// Name used in the `alg` header parameter.
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Es256 => "ES256",
+      Self::Hs256 => "HS256",
+      Self::Rs256 => "RS256",
+    }
+  }
+
+  pub(crate) fn from_header(str: &str) -> crate::Result<Self> {
+    Self::try_from(str).map_err(|_err| JwtError::UnsupportedAlgorithm.into())
+  }
+}
@@ -0,0 +1,63 @@
+use crate::misc::Vector;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+/// Registered claims along side custom claims, which are flattened into the same JSON object.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc7519#section-4.1>
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct JwtClaims<T> {
+  /// Audience
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub aud: Option<JwtAudience>,
+  /// Expiration time in seconds since the UNIX epoch.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub exp: Option<u64>,
+  /// Issued at in seconds since the UNIX epoch.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub iat: Option<u64>,
+  /// Issuer
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub iss: Option<String>,
+  /// JWT ID
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub jti: Option<String>,
+  /// Not before in seconds since the UNIX epoch.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub nbf: Option<u64>,
+  /// Subject
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sub: Option<String>,
+  /// Custom claims
+  #[serde(flatten)]
+  pub custom: T,
+}
+
+impl<T> JwtClaims<T> {
+  /// Instance without registered claims.
+  #[inline]
+  pub const fn new(custom: T) -> Self {
+    Self { aud: None, exp: None, iat: None, iss: None, jti: None, nbf: None, sub: None, custom }
+  }
+}
+
+/// The `aud` claim, which can be a single string or an array of strings.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JwtAudience {
+  /// Many recipients
+  Many(Vector<String>),
+  /// Single recipient
+  One(String),
+}
+
+impl JwtAudience {
+  /// If `audience` is one of the recipients.
+  #[inline]
+  pub fn contains(&self, audience: &str) -> bool {
+    match self {
+      Self::Many(elem) => elem.iter().any(|el| el == audience),
+      Self::One(elem) => elem == audience,
+    }
+  }
+}
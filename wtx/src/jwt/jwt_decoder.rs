@@ -0,0 +1,160 @@
+use crate::{
+  jwt::{JwtAlgorithm, JwtClaims, JwtError, JwtValidation},
+  misc::str_split1,
+};
+use alloc::vec::Vec;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use ring::{
+  hmac,
+  signature::{self, UnparsedPublicKey},
+};
+use serde::{Deserialize, de::DeserializeOwned};
+
+/// Verifies the signature of tokens and validates their claims.
+///
+/// The `alg` header of tokens must be equal to the algorithm of the key, which prevents
+/// algorithm confusion attacks.
+#[derive(Debug)]
+pub struct JwtDecoder {
+  key: VerifyingKey,
+  validation: JwtValidation,
+}
+
+impl JwtDecoder {
+  /// ES256 with an uncompressed P-256 public key, i.e., `0x04` followed by both coordinates.
+  #[inline]
+  pub fn es256(public_key: &[u8]) -> Self {
+    let key = UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_FIXED, public_key.into());
+    Self { key: VerifyingKey::Es256(key), validation: JwtValidation::new() }
+  }
+
+  /// HS256 with a shared secret.
+  #[inline]
+  pub fn hs256(secret: &[u8]) -> Self {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret);
+    Self { key: VerifyingKey::Hs256(key), validation: JwtValidation::new() }
+  }
+
+  /// RS256 with a RSA public key encoded as PKCS#1 DER (`RSAPublicKey`).
+  #[inline]
+  pub fn rs256(public_key: &[u8]) -> Self {
+    let key = UnparsedPublicKey::new(&signature::RSA_PKCS1_2048_8192_SHA256, public_key.into());
+    Self { key: VerifyingKey::Rs256(key), validation: JwtValidation::new() }
+  }
+
+  /// See [`JwtValidation`].
+  #[inline]
+  #[must_use]
+  pub fn validation(mut self, elem: JwtValidation) -> Self {
+    self.validation = elem;
+    self
+  }
+
+  /// Algorithm of the underlying key.
+  #[inline]
+  pub const fn algorithm(&self) -> JwtAlgorithm {
+    match self.key {
+      VerifyingKey::Es256(_) => JwtAlgorithm::Es256,
+      VerifyingKey::Hs256(_) => JwtAlgorithm::Hs256,
+      VerifyingKey::Rs256(_) => JwtAlgorithm::Rs256,
+    }
+  }
+
+  /// Verifies the signature of `token` and then validates its claims.
+  #[inline]
+  pub fn decode<T>(&self, token: &str) -> crate::Result<JwtClaims<T>>
+  where
+    T: DeserializeOwned,
+  {
+    let claims = self.decode_unvalidated(token)?;
+    self.validation.validate(&claims)?;
+    Ok(claims)
+  }
+
+  pub(crate) fn decode_unvalidated<T>(&self, token: &str) -> crate::Result<JwtClaims<T>>
+  where
+    T: DeserializeOwned,
+  {
+    let mut iter = str_split1(token, b'.');
+    let (Some(header), Some(payload), Some(signature), None) =
+      (iter.next(), iter.next(), iter.next(), iter.next())
+    else {
+      return Err(JwtError::MalformedToken.into());
+    };
+    let header_bytes = decode_section(header)?;
+    let header_json: Header = serde_json::from_slice(&header_bytes)?;
+    if JwtAlgorithm::from_header(&header_json.alg)? != self.algorithm() {
+      return Err(JwtError::MismatchedAlgorithm.into());
+    }
+    let signature_bytes = decode_section(signature)?;
+    let message = token.get(..header.len().wrapping_add(payload.len()).wrapping_add(1));
+    let message = message.unwrap_or_default().as_bytes();
+    let is_valid = match &self.key {
+      VerifyingKey::Es256(key) | VerifyingKey::Rs256(key) => {
+        key.verify(message, &signature_bytes).is_ok()
+      }
+      VerifyingKey::Hs256(key) => hmac::verify(key, message, &signature_bytes).is_ok(),
+    };
+    if !is_valid {
+      return Err(JwtError::InvalidSignature.into());
+    }
+    Ok(serde_json::from_slice(&decode_section(payload)?)?)
+  }
+}
+
+#[derive(Deserialize)]
+struct Header {
+  alg: alloc::string::String,
+}
+
+#[derive(Debug)]
+enum VerifyingKey {
+  Es256(UnparsedPublicKey<Vec<u8>>),
+  Hs256(hmac::Key),
+  Rs256(UnparsedPublicKey<Vec<u8>>),
+}
+
+fn decode_section(section: &str) -> crate::Result<Vec<u8>> {
+  URL_SAFE_NO_PAD.decode(section).map_err(|_err| JwtError::MalformedToken.into())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::jwt::{JwtClaims, JwtDecoder, JwtEncoder, JwtValidation};
+  use alloc::string::String;
+
+  #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+  struct Custom {
+    admin: bool,
+  }
+
+  #[test]
+  fn hs256() {
+    let encoder = JwtEncoder::hs256(b"01234567890123456789012345678901");
+    let mut claims = JwtClaims::new(Custom { admin: true });
+    claims.exp = Some(u64::MAX / 2);
+    claims.iss = Some(String::from("wtx"));
+    let token = encoder.encode(&claims).unwrap();
+    let validation = JwtValidation::new().issuer(Some(String::from("wtx")));
+    let decoder = JwtDecoder::hs256(b"01234567890123456789012345678901").validation(validation);
+    assert_eq!(decoder.decode::<Custom>(&token).unwrap(), claims);
+    assert!(JwtDecoder::hs256(b"foo").decode::<Custom>(&token).is_err());
+    assert!(JwtDecoder::es256(&[4; 65]).decode::<Custom>(&token).is_err());
+    let mut tampered = String::from(token.get(..token.len() - 1).unwrap());
+    tampered.push(if token.ends_with('A') { 'B' } else { 'A' });
+    assert!(decoder.decode::<Custom>(&tampered).is_err());
+  }
+
+  #[test]
+  fn validation() {
+    let mut claims = JwtClaims::new(());
+    let validation = JwtValidation::new().audience(Some(String::from("a")));
+    assert!(validation.validate_at(&claims, 10).is_err());
+    claims.exp = Some(10);
+    claims.aud = Some(crate::jwt::JwtAudience::One(String::from("a")));
+    assert!(validation.validate_at(&claims, 10).is_ok());
+    assert!(validation.validate_at(&claims, 71).is_err());
+    claims.nbf = Some(100);
+    assert!(validation.validate_at(&claims, 10).is_err());
+  }
+}
@@ -0,0 +1,112 @@
+use crate::jwt::{JwtAlgorithm, JwtClaims, JwtError};
+use alloc::{string::String, vec::Vec};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use ring::{
+  hmac,
+  rand::SystemRandom,
+  rsa,
+  signature::{self, EcdsaKeyPair},
+};
+use serde::Serialize;
+
+/// Creates signed tokens.
+#[derive(Debug)]
+pub struct JwtEncoder {
+  key: SigningKey,
+  rng: SystemRandom,
+}
+
+impl JwtEncoder {
+  /// ES256 with a P-256 private key encoded as PKCS#8 DER.
+  #[inline]
+  pub fn es256(pkcs8_der: &[u8]) -> crate::Result<Self> {
+    let rng = SystemRandom::new();
+    let alg = &signature::ECDSA_P256_SHA256_FIXED_SIGNING;
+    let key_pair =
+      EcdsaKeyPair::from_pkcs8(alg, pkcs8_der, &rng).map_err(|_err| JwtError::InvalidKey)?;
+    Ok(Self { key: SigningKey::Es256(key_pair), rng })
+  }
+
+  /// HS256 with a shared secret, which should have at least 32 bytes.
+  #[inline]
+  pub fn hs256(secret: &[u8]) -> Self {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret);
+    Self { key: SigningKey::Hs256(key), rng: SystemRandom::new() }
+  }
+
+  /// RS256 with a RSA private key encoded as PKCS#8 DER.
+  #[inline]
+  pub fn rs256(pkcs8_der: &[u8]) -> crate::Result<Self> {
+    let key_pair = rsa::KeyPair::from_pkcs8(pkcs8_der).map_err(|_err| JwtError::InvalidKey)?;
+    Ok(Self { key: SigningKey::Rs256(key_pair), rng: SystemRandom::new() })
+  }
+
+  /// Algorithm of the underlying key.
+  #[inline]
+  pub const fn algorithm(&self) -> JwtAlgorithm {
+    match self.key {
+      SigningKey::Es256(_) => JwtAlgorithm::Es256,
+      SigningKey::Hs256(_) => JwtAlgorithm::Hs256,
+      SigningKey::Rs256(_) => JwtAlgorithm::Rs256,
+    }
+  }
+
+  /// Serializes and signs `claims`, returning the compact representation of the token.
+  #[inline]
+  pub fn encode<T>(&self, claims: &JwtClaims<T>) -> crate::Result<String>
+  where
+    T: Serialize,
+  {
+    let mut token = String::new();
+    let header = [r#"{"alg":""#, self.algorithm().as_str(), r#"","typ":"JWT"}"#].concat();
+    URL_SAFE_NO_PAD.encode_string(header, &mut token);
+    token.push('.');
+    URL_SAFE_NO_PAD.encode_string(serde_json::to_vec(claims)?, &mut token);
+    let signature = self.sign(token.as_bytes())?;
+    token.push('.');
+    URL_SAFE_NO_PAD.encode_string(signature, &mut token);
+    Ok(token)
+  }
+
+  /// Pushes an `Authorization: Bearer` header with a new token, which allows the authentication
+  /// of outgoing requests. `client_api_framework` transports can use `JwtBearer` instead.
+  #[cfg(feature = "http")]
+  #[inline]
+  pub fn push_bearer<T>(
+    &self,
+    claims: &JwtClaims<T>,
+    headers: &mut crate::http::Headers,
+  ) -> crate::Result<()>
+  where
+    T: Serialize,
+  {
+    let token = self.encode(claims)?;
+    headers.push_from_iter(crate::http::Header::from_name_and_value(
+      crate::http::KnownHeaderName::Authorization.into(),
+      ["Bearer ", token.as_str()],
+    ))
+  }
+
+  fn sign(&self, message: &[u8]) -> crate::Result<Vec<u8>> {
+    Ok(match &self.key {
+      SigningKey::Es256(key_pair) => {
+        key_pair.sign(&self.rng, message).map_err(|_err| JwtError::InvalidKey)?.as_ref().into()
+      }
+      SigningKey::Hs256(key) => hmac::sign(key, message).as_ref().into(),
+      SigningKey::Rs256(key_pair) => {
+        let mut signature = alloc::vec![0; key_pair.public().modulus_len()];
+        key_pair
+          .sign(&signature::RSA_PKCS1_SHA256, &self.rng, message, &mut signature)
+          .map_err(|_err| JwtError::InvalidKey)?;
+        signature
+      }
+    })
+  }
+}
+
+#[derive(Debug)]
+enum SigningKey {
+  Es256(EcdsaKeyPair),
+  Hs256(hmac::Key),
+  Rs256(rsa::KeyPair),
+}
@@ -0,0 +1,24 @@
+/// JWT error
+#[derive(Debug)]
+pub enum JwtError {
+  /// The `exp` claim is in the past.
+  ExpiredToken,
+  /// The `nbf` claim is in the future.
+  ImmatureToken,
+  /// The `aud` claim doesn't contain the expected audience.
+  InvalidAudience,
+  /// The `iss` claim differs from the expected issuer.
+  InvalidIssuer,
+  /// Key couldn't be parsed or doesn't match the algorithm.
+  InvalidKey,
+  /// Signature doesn't match the contents of the token.
+  InvalidSignature,
+  /// Token doesn't have three Base64 sections separated by dots.
+  MalformedToken,
+  /// The `alg` header differs from the algorithm of the key.
+  MismatchedAlgorithm,
+  /// Validation requires the `exp` claim but the token has none.
+  MissingExpiration,
+  /// The `alg` header contains an algorithm that isn't supported.
+  UnsupportedAlgorithm,
+}
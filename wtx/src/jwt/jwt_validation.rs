@@ -0,0 +1,94 @@
+use crate::{
+  jwt::{JwtClaims, JwtError},
+  misc::GenericTime,
+};
+use alloc::string::String;
+use core::time::Duration;
+
+/// Rules applied to the registered claims of decoded tokens.
+#[derive(Clone, Debug)]
+pub struct JwtValidation {
+  audience: Option<String>,
+  issuer: Option<String>,
+  leeway: Duration,
+  require_exp: bool,
+}
+
+impl JwtValidation {
+  /// Requires the `exp` claim, tolerates clock skews of 60 seconds and doesn't verify `aud` or
+  /// `iss`.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { audience: None, issuer: None, leeway: Duration::from_secs(60), require_exp: true }
+  }
+
+  /// Value that must be present in the `aud` claim.
+  #[inline]
+  #[must_use]
+  pub fn audience(mut self, elem: Option<String>) -> Self {
+    self.audience = elem;
+    self
+  }
+
+  /// Value that must be equal to the `iss` claim.
+  #[inline]
+  #[must_use]
+  pub fn issuer(mut self, elem: Option<String>) -> Self {
+    self.issuer = elem;
+    self
+  }
+
+  /// Tolerance applied to `exp` and `nbf` to account for clock skews.
+  #[inline]
+  #[must_use]
+  pub const fn leeway(mut self, elem: Duration) -> Self {
+    self.leeway = elem;
+    self
+  }
+
+  /// If tokens without the `exp` claim should be rejected.
+  #[inline]
+  #[must_use]
+  pub const fn require_exp(mut self, elem: bool) -> Self {
+    self.require_exp = elem;
+    self
+  }
+
+  /// Validates `claims` against the current time.
+  #[inline]
+  pub fn validate<T>(&self, claims: &JwtClaims<T>) -> crate::Result<()> {
+    self.validate_at(claims, GenericTime::now_timestamp()?.as_secs())
+  }
+
+  pub(crate) fn validate_at<T>(&self, claims: &JwtClaims<T>, now: u64) -> crate::Result<()> {
+    let leeway = self.leeway.as_secs();
+    match claims.exp {
+      Some(exp) if exp.saturating_add(leeway) < now => return Err(JwtError::ExpiredToken.into()),
+      None if self.require_exp => return Err(JwtError::MissingExpiration.into()),
+      _ => {}
+    }
+    if let Some(nbf) = claims.nbf {
+      if now.saturating_add(leeway) < nbf {
+        return Err(JwtError::ImmatureToken.into());
+      }
+    }
+    if let Some(audience) = &self.audience {
+      if !claims.aud.as_ref().is_some_and(|el| el.contains(audience)) {
+        return Err(JwtError::InvalidAudience.into());
+      }
+    }
+    if let Some(issuer) = &self.issuer {
+      if claims.iss.as_ref() != Some(issuer) {
+        return Err(JwtError::InvalidIssuer.into());
+      }
+    }
+    Ok(())
+  }
+}
+
+impl Default for JwtValidation {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -14,6 +14,8 @@ extern crate test;
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "acme")]
+pub mod acme;
 #[cfg(all(feature = "_bench", test))]
 pub(crate) mod bench;
 #[cfg(feature = "client-api-framework")]
@@ -29,6 +31,12 @@ pub mod grpc;
 pub mod http;
 #[cfg(feature = "http2")]
 pub mod http2;
+#[cfg(feature = "http3")]
+pub mod http3;
+#[cfg(feature = "jwt")]
+pub mod jwt;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod misc;
 #[cfg(feature = "pool")]
 pub mod pool;
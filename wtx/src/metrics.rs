@@ -0,0 +1,44 @@
+//! Lightweight and `no_std` metrics that can be rendered using the text exposition format of
+//! Prometheus.
+//!
+//! Some structures of this project are able to feed metrics, see the `*Metrics` types of the
+//! respective modules.
+//!
+//! <https://prometheus.io/docs/instrumenting/exposition_formats/#text-based-format>
+
+mod counter;
+mod gauge;
+mod histogram;
+
+use crate::misc::Vector;
+pub use counter::Counter;
+use core::fmt::Write as _;
+pub use gauge::Gauge;
+pub use histogram::Histogram;
+
+/// Writes the `HELP` and `TYPE` lines of the metric named `name`, which should precede its
+/// samples.
+#[inline]
+pub fn push_metric_header(
+  buffer: &mut Vector<u8>,
+  name: &str,
+  help: &str,
+  ty: &str,
+) -> crate::Result<()> {
+  buffer.write_fmt(format_args!("# HELP {name} {help}\n# TYPE {name} {ty}\n"))?;
+  Ok(())
+}
+
+fn push_sample(
+  buffer: &mut Vector<u8>,
+  name: &str,
+  labels: &str,
+  value: u64,
+) -> crate::Result<()> {
+  if labels.is_empty() {
+    buffer.write_fmt(format_args!("{name} {value}\n"))?;
+  } else {
+    buffer.write_fmt(format_args!("{name}{{{labels}}} {value}\n"))?;
+  }
+  Ok(())
+}
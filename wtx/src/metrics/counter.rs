@@ -0,0 +1,48 @@
+use crate::{
+  metrics::push_sample,
+  misc::Vector,
+  sync::{AtomicU64, Ordering},
+};
+
+/// Value that can only increase, e.g., the number of received requests.
+#[derive(Debug)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+  /// Zeroed instance
+  #[inline]
+  pub const fn new() -> Self {
+    Self(AtomicU64::new(0))
+  }
+
+  /// Increases the current value by `value`.
+  #[inline]
+  pub fn add(&self, value: u64) {
+    let _ = self.0.fetch_add(value, Ordering::Relaxed);
+  }
+
+  /// Current value
+  #[inline]
+  pub fn get(&self) -> u64 {
+    self.0.load(Ordering::Relaxed)
+  }
+
+  /// Increases the current value by one.
+  #[inline]
+  pub fn inc(&self) {
+    self.add(1);
+  }
+
+  /// Writes a sample named `name` with the optional comma-separated `labels`.
+  #[inline]
+  pub fn push(&self, buffer: &mut Vector<u8>, name: &str, labels: &str) -> crate::Result<()> {
+    push_sample(buffer, name, labels, self.get())
+  }
+}
+
+impl Default for Counter {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,54 @@
+use crate::{
+  metrics::push_sample,
+  misc::Vector,
+  sync::{AtomicU64, Ordering},
+};
+
+/// Value that can increase or decrease, e.g., the number of active connections.
+#[derive(Debug)]
+pub struct Gauge(AtomicU64);
+
+impl Gauge {
+  /// Zeroed instance
+  #[inline]
+  pub const fn new() -> Self {
+    Self(AtomicU64::new(0))
+  }
+
+  /// Increases the current value by `value`.
+  #[inline]
+  pub fn add(&self, value: u64) {
+    let _ = self.0.fetch_add(value, Ordering::Relaxed);
+  }
+
+  /// Current value
+  #[inline]
+  pub fn get(&self) -> u64 {
+    self.0.load(Ordering::Relaxed)
+  }
+
+  /// Writes a sample named `name` with the optional comma-separated `labels`.
+  #[inline]
+  pub fn push(&self, buffer: &mut Vector<u8>, name: &str, labels: &str) -> crate::Result<()> {
+    push_sample(buffer, name, labels, self.get())
+  }
+
+  /// Overwrites the current value.
+  #[inline]
+  pub fn set(&self, value: u64) {
+    self.0.store(value, Ordering::Relaxed);
+  }
+
+  /// Decreases the current value by `value`.
+  #[inline]
+  pub fn sub(&self, value: u64) {
+    let _ = self.0.fetch_sub(value, Ordering::Relaxed);
+  }
+}
+
+impl Default for Gauge {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,139 @@
+use crate::{
+  misc::Vector,
+  sync::{AtomicU64, Ordering},
+};
+use core::{fmt::Write as _, time::Duration};
+
+// Upper bounds, in microseconds, of the default buckets of the Prometheus clients.
+const DURATION_BOUNDS: [(u64, &str); 11] = [
+  (5_000, "0.005"),
+  (10_000, "0.01"),
+  (25_000, "0.025"),
+  (50_000, "0.05"),
+  (100_000, "0.1"),
+  (250_000, "0.25"),
+  (500_000, "0.5"),
+  (1_000_000, "1"),
+  (2_500_000, "2.5"),
+  (5_000_000, "5"),
+  (10_000_000, "10"),
+];
+
+/// Distribution of observed values grouped into cumulative buckets, e.g., the latency of
+/// requests.
+#[derive(Debug)]
+pub struct Histogram<const N: usize> {
+  bounds: [(u64, &'static str); N],
+  buckets: [AtomicU64; N],
+  count: AtomicU64,
+  decimals: u8,
+  sum: AtomicU64,
+}
+
+impl<const N: usize> Histogram<N> {
+  /// `bounds` are inclusive upper bounds in ascending order along with their textual
+  /// representation, which is used as the `le` label.
+  ///
+  /// `decimals` is the number of fractional digits of the rendered sum. For example, a value of
+  /// `3` renders a sum of `1500` as `1.500`, which is useful when observed values are
+  /// milliseconds but exposed values are seconds.
+  #[inline]
+  pub const fn new(bounds: [(u64, &'static str); N], decimals: u8) -> Self {
+    Self {
+      bounds,
+      buckets: [const { AtomicU64::new(0) }; N],
+      count: AtomicU64::new(0),
+      decimals,
+      sum: AtomicU64::new(0),
+    }
+  }
+
+  /// Number of observed values.
+  #[inline]
+  pub fn count(&self) -> u64 {
+    self.count.load(Ordering::Relaxed)
+  }
+
+  /// Registers `value`.
+  #[inline]
+  pub fn observe(&self, value: u64) {
+    if let Some(idx) = self.bounds.iter().position(|(bound, _)| value <= *bound) {
+      if let Some(elem) = self.buckets.get(idx) {
+        let _ = elem.fetch_add(1, Ordering::Relaxed);
+      }
+    }
+    let _ = self.count.fetch_add(1, Ordering::Relaxed);
+    let _ = self.sum.fetch_add(value, Ordering::Relaxed);
+  }
+
+  /// Writes the `_bucket`, `_sum` and `_count` samples of the histogram named `name`.
+  #[inline]
+  pub fn push(&self, buffer: &mut Vector<u8>, name: &str) -> crate::Result<()> {
+    let mut cumulative: u64 = 0;
+    for ((_, le), elem) in self.bounds.iter().zip(&self.buckets) {
+      cumulative = cumulative.wrapping_add(elem.load(Ordering::Relaxed));
+      buffer.write_fmt(format_args!("{name}_bucket{{le=\"{le}\"}} {cumulative}\n"))?;
+    }
+    let count = self.count();
+    buffer.write_fmt(format_args!("{name}_bucket{{le=\"+Inf\"}} {count}\n"))?;
+    let sum = self.sum();
+    if self.decimals == 0 {
+      buffer.write_fmt(format_args!("{name}_sum {sum}\n"))?;
+    } else {
+      let divisor = 10u64.saturating_pow(self.decimals.into());
+      let (int, frac) = (sum.wrapping_div(divisor), sum.wrapping_rem(divisor));
+      let width = usize::from(self.decimals);
+      buffer.write_fmt(format_args!("{name}_sum {int}.{frac:0width$}\n"))?;
+    }
+    buffer.write_fmt(format_args!("{name}_count {count}\n"))?;
+    Ok(())
+  }
+
+  /// Sum of all observed values.
+  #[inline]
+  pub fn sum(&self) -> u64 {
+    self.sum.load(Ordering::Relaxed)
+  }
+}
+
+impl Histogram<11> {
+  /// Durations observed through [`Histogram::observe_duration`] that are exposed in seconds
+  /// using the default buckets of the Prometheus clients.
+  #[inline]
+  pub const fn durations() -> Self {
+    Self::new(DURATION_BOUNDS, 6)
+  }
+
+  /// Registers `elapsed` in microseconds. Should only be used with instances created by
+  /// [`Histogram::durations`].
+  #[inline]
+  pub fn observe_duration(&self, elapsed: Duration) {
+    self.observe(u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    metrics::Histogram,
+    misc::{Vector, from_utf8_basic},
+  };
+
+  #[test]
+  fn push() {
+    let histogram = Histogram::new([(10, "10"), (100, "100")], 1);
+    histogram.observe(5);
+    histogram.observe(50);
+    histogram.observe(500);
+    let mut buffer = Vector::new();
+    histogram.push(&mut buffer, "foo").unwrap();
+    assert_eq!(
+      from_utf8_basic(&buffer).unwrap(),
+      "foo_bucket{le=\"10\"} 1\n\
+       foo_bucket{le=\"100\"} 2\n\
+       foo_bucket{le=\"+Inf\"} 3\n\
+       foo_sum 55.5\n\
+       foo_count 3\n"
+    );
+  }
+}
@@ -7,6 +7,7 @@ pub(crate) mod counter_writer;
 pub(crate) mod hints;
 #[cfg(any(feature = "http2", feature = "mysql", feature = "postgres", feature = "web-socket"))]
 pub(crate) mod net;
+pub(crate) mod percent_encoding;
 #[cfg(feature = "http2")]
 pub(crate) mod span;
 
@@ -17,6 +18,7 @@ mod collections;
 mod connection_state;
 mod decode;
 mod decontroller;
+mod dns_resolver;
 mod either;
 mod encode;
 mod enum_var_strings;
@@ -31,11 +33,11 @@ mod lease;
 mod lock;
 mod num_array;
 mod optimization;
-mod percent_encoding;
 mod query_writer;
 mod ref_counter;
 mod rng;
 mod role;
+mod runtime;
 mod single_type_storage;
 mod stream;
 mod suffix_writer;
@@ -49,7 +51,7 @@ mod vector;
 mod wrapper;
 
 #[cfg(feature = "tokio-rustls")]
-pub use self::tokio_rustls::{TokioRustlsAcceptor, TokioRustlsConnector};
+pub use self::tokio_rustls::{TokioRustlsAcceptor, TokioRustlsCertResolver, TokioRustlsConnector};
 pub use array_chunks::{ArrayChunks, ArrayChunksMut};
 pub use buffer_mode::BufferMode;
 pub use clear::Clear;
@@ -58,6 +60,7 @@ pub use connection_state::ConnectionState;
 use core::{any::type_name, ops::Range, time::Duration};
 pub use decode::{Decode, DecodeSeq};
 pub use decontroller::DEController;
+pub use dns_resolver::*;
 pub use either::Either;
 pub use encode::Encode;
 pub use enum_var_strings::EnumVarStrings;
@@ -77,8 +80,15 @@ pub use query_writer::QueryWriter;
 pub use ref_counter::RefCounter;
 pub use rng::*;
 pub use role::Role;
+#[cfg(feature = "smol")]
+pub use runtime::SmolRuntime;
+#[cfg(feature = "tokio")]
+pub use runtime::TokioRuntime;
+pub use runtime::Spawner;
 pub use single_type_storage::SingleTypeStorage;
-pub use stream::{BytesStream, Stream, StreamReader, StreamWithTls, StreamWriter};
+pub use stream::{BytesStream, Stream, StreamReader, StreamWithTls, StreamWriter, TlsConnector};
+#[cfg(feature = "embedded-io-async")]
+pub use stream::EmbeddedIoStream;
 pub use suffix_writer::{SuffixWriter, SuffixWriterFbvm, SuffixWriterMut};
 pub use uri::{Uri, UriArrayString, UriCow, UriRef, UriString};
 pub use usize::Usize;
@@ -143,6 +153,27 @@ where
   sq.end()
 }
 
+/// Exponential delay of `attempt`, starting at `base` and capped at `max`. Half of the delay is
+/// fixed and the other half is random.
+#[cfg(any(feature = "client-api-framework", feature = "http"))]
+pub(crate) fn _backoff_with_jitter<RNG>(
+  attempt: u8,
+  base: Duration,
+  max: Duration,
+  rng: &mut RNG,
+) -> Duration
+where
+  RNG: Rng,
+{
+  let factor = 2u32.saturating_pow(u32::from(attempt.saturating_sub(1)));
+  let delay = base.saturating_mul(factor).min(max);
+  let half = delay.checked_div(2).unwrap_or_default();
+  let half_nanos = u64::try_from(half.as_nanos()).unwrap_or(u64::MAX);
+  let jitter =
+    u64::from_be_bytes(rng.u8_8()).checked_rem(half_nanos.wrapping_add(1)).unwrap_or_default();
+  half.saturating_add(Duration::from_nanos(jitter))
+}
+
 /// Sleeps for the specified amount of time.
 #[allow(clippy::unused_async, reason = "depends on the selected set of features")]
 #[inline]
@@ -152,7 +183,12 @@ pub async fn sleep(duration: Duration) -> crate::Result<()> {
     tokio::time::sleep(duration).await;
     Ok(())
   }
-  #[cfg(not(feature = "tokio"))]
+  #[cfg(all(feature = "smol", not(feature = "tokio")))]
+  {
+    let _instant = smol::Timer::after(duration).await;
+    Ok(())
+  }
+  #[cfg(not(any(feature = "smol", feature = "tokio")))]
   {
     let now = GenericTime::now();
     core::future::poll_fn(|cx| {
@@ -166,6 +202,23 @@ pub async fn sleep(duration: Duration) -> crate::Result<()> {
   }
 }
 
+/// Returns `None` if `fut` didn't finish before `duration`.
+#[cfg(any(feature = "client-api-framework", feature = "http"))]
+pub(crate) async fn within<F>(duration: Duration, fut: F) -> crate::Result<Option<F::Output>>
+where
+  F: Future,
+{
+  let mut fut_pin = core::pin::pin!(fut);
+  let mut sleep_pin = core::pin::pin!(sleep(duration));
+  core::future::poll_fn(|cx| {
+    if let core::task::Poll::Ready(elem) = fut_pin.as_mut().poll(cx) {
+      return core::task::Poll::Ready(Ok(Some(elem)));
+    }
+    sleep_pin.as_mut().poll(cx).map(|rslt| rslt.map(|()| None))
+  })
+  .await
+}
+
 /// A tracing register with optioned parameters.
 #[cfg(feature = "_tracing-tree")]
 #[inline]
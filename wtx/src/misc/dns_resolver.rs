@@ -0,0 +1,198 @@
+use crate::misc::Vector;
+use core::{net::IpAddr, time::Duration};
+
+/// Translates hostnames into IP addresses before connections are established.
+///
+/// Custom implementations can, for example, query DNS-over-HTTPS servers or service registries.
+pub trait DnsResolver {
+  /// Appends the addresses of `hostname` into `addrs`. The returned duration, if any, indicates
+  /// for how long the addresses remain valid.
+  fn resolve(
+    &self,
+    hostname: &str,
+    addrs: &mut Vector<IpAddr>,
+  ) -> impl Future<Output = crate::Result<Option<Duration>>>;
+}
+
+impl<T> DnsResolver for &T
+where
+  T: DnsResolver,
+{
+  #[inline]
+  async fn resolve(
+    &self,
+    hostname: &str,
+    addrs: &mut Vector<IpAddr>,
+  ) -> crate::Result<Option<Duration>> {
+    (**self).resolve(hostname, addrs).await
+  }
+}
+
+/// Wraps another resolver to store its addresses for the duration of their time-to-live. Static
+/// addresses can also be assigned to specific hostnames.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct CachingDnsResolver<R> {
+  cache: std::sync::Mutex<Vector<CachedAddrs>>,
+  default_ttl: Duration,
+  overrides: Vector<(alloc::string::String, Vector<IpAddr>)>,
+  resolver: R,
+}
+
+#[cfg(feature = "std")]
+impl<R> CachingDnsResolver<R> {
+  /// Addresses returned without a time-to-live are stored for 60 seconds.
+  #[inline]
+  pub const fn new(resolver: R) -> Self {
+    Self {
+      cache: std::sync::Mutex::new(Vector::new()),
+      default_ttl: Duration::from_secs(60),
+      overrides: Vector::new(),
+      resolver,
+    }
+  }
+
+  /// Removes all cached addresses.
+  #[expect(clippy::unwrap_used, reason = "poisoning is ignored")]
+  #[inline]
+  pub fn clear(&self) {
+    self.cache.lock().unwrap().clear();
+  }
+
+  /// Time-to-live of addresses returned without one. Zero disables caching for such addresses.
+  #[inline]
+  #[must_use]
+  pub const fn default_ttl(mut self, elem: Duration) -> Self {
+    self.default_ttl = elem;
+    self
+  }
+
+  /// `hostname` is always resolved to `addrs` without querying the inner resolver.
+  #[inline]
+  pub fn override_hostname(
+    mut self,
+    hostname: impl Into<alloc::string::String>,
+    addrs: &[IpAddr],
+  ) -> crate::Result<Self> {
+    let mut vector = Vector::new();
+    vector.extend_from_copyable_slice(addrs)?;
+    self.overrides.push((hostname.into(), vector))?;
+    Ok(self)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<R> DnsResolver for CachingDnsResolver<R>
+where
+  R: DnsResolver,
+{
+  #[expect(clippy::unwrap_used, reason = "poisoning is ignored")]
+  #[inline]
+  async fn resolve(
+    &self,
+    hostname: &str,
+    addrs: &mut Vector<IpAddr>,
+  ) -> crate::Result<Option<Duration>> {
+    use crate::misc::GenericTime;
+
+    if let Some((_, elem)) = self.overrides.iter().find(|el| el.0.eq_ignore_ascii_case(hostname)) {
+      addrs.extend_from_copyable_slice(elem)?;
+      return Ok(None);
+    }
+    {
+      let now = GenericTime::now();
+      let mut cache = self.cache.lock().unwrap();
+      cache.retain(|el| el.expires_at > now);
+      if let Some(elem) = cache.iter().find(|el| el.hostname.eq_ignore_ascii_case(hostname)) {
+        addrs.extend_from_copyable_slice(&elem.addrs)?;
+        return Ok(elem.expires_at.duration_since(now).ok());
+      }
+    }
+    let mut local_addrs = Vector::new();
+    let ttl = self.resolver.resolve(hostname, &mut local_addrs).await?.unwrap_or(self.default_ttl);
+    addrs.extend_from_copyable_slice(&local_addrs)?;
+    if !ttl.is_zero() && !local_addrs.is_empty() {
+      let expires_at = GenericTime::now().checked_add(ttl)?;
+      self.cache.lock().unwrap().push(CachedAddrs {
+        addrs: local_addrs,
+        expires_at,
+        hostname: hostname.into(),
+      })?;
+    }
+    Ok(Some(ttl))
+  }
+}
+
+/// Uses the resolver of the operating system through `tokio`.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioDnsResolver;
+
+#[cfg(feature = "tokio")]
+impl DnsResolver for TokioDnsResolver {
+  #[inline]
+  async fn resolve(
+    &self,
+    hostname: &str,
+    addrs: &mut Vector<IpAddr>,
+  ) -> crate::Result<Option<Duration>> {
+    for elem in tokio::net::lookup_host((hostname, 0)).await? {
+      addrs.push(elem.ip())?;
+    }
+    Ok(None)
+  }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct CachedAddrs {
+  addrs: Vector<IpAddr>,
+  expires_at: crate::misc::GenericTime,
+  hostname: alloc::string::String,
+}
+
+#[cfg(all(feature = "_async-tests", feature = "std", test))]
+mod tests {
+  use crate::{
+    misc::{CachingDnsResolver, DnsResolver, Vector},
+    sync::{AtomicUsize, Ordering},
+  };
+  use core::{
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+  };
+
+  struct CountingResolver(AtomicUsize);
+
+  impl DnsResolver for CountingResolver {
+    async fn resolve(
+      &self,
+      _: &str,
+      addrs: &mut Vector<IpAddr>,
+    ) -> crate::Result<Option<Duration>> {
+      let _ = self.0.fetch_add(1, Ordering::Relaxed);
+      addrs.push(IpAddr::V4(Ipv4Addr::LOCALHOST))?;
+      Ok(Some(Duration::from_secs(10)))
+    }
+  }
+
+  #[tokio::test]
+  async fn cached_and_overridden_addresses() {
+    let local = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+    let resolver = CachingDnsResolver::new(CountingResolver(AtomicUsize::new(0)))
+      .override_hostname("local.foo.com", &[local])
+      .unwrap();
+    let mut addrs = Vector::new();
+    let _ = resolver.resolve("foo.com", &mut addrs).await.unwrap();
+    let _ = resolver.resolve("FOO.com", &mut addrs).await.unwrap();
+    let _ = resolver.resolve("local.foo.com", &mut addrs).await.unwrap();
+    assert_eq!(
+      addrs.as_slice(),
+      &[IpAddr::V4(Ipv4Addr::LOCALHOST), IpAddr::V4(Ipv4Addr::LOCALHOST), local]
+    );
+    assert_eq!(resolver.resolver.0.load(Ordering::Relaxed), 1);
+    resolver.clear();
+    let _ = resolver.resolve("foo.com", &mut addrs).await.unwrap();
+    assert_eq!(resolver.resolver.0.load(Ordering::Relaxed), 2);
+  }
+}
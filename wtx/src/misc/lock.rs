@@ -61,6 +61,30 @@ where
   }
 }
 
+#[cfg(feature = "smol")]
+mod smol {
+  use crate::misc::Lock;
+  use smol::lock::{Mutex, MutexGuard};
+
+  impl<T> Lock for Mutex<T> {
+    type Guard<'guard>
+      = MutexGuard<'guard, Self::Resource>
+    where
+      Self: 'guard;
+    type Resource = T;
+
+    #[inline]
+    fn new(resource: Self::Resource) -> Self {
+      Mutex::new(resource)
+    }
+
+    #[inline]
+    async fn lock(&self) -> Self::Guard<'_> {
+      (*self).lock().await
+    }
+  }
+}
+
 #[cfg(feature = "tokio")]
 mod tokio {
   use crate::misc::Lock;
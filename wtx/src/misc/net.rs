@@ -56,3 +56,145 @@ where
   )?;
   Ok(())
 }
+
+#[cfg(feature = "http2")]
+/// Transfers the remaining bytes of the current payload directly into `dst` without
+/// intermediate copies. Bytes that were already fetched along side the header are taken from
+/// `pfb`.
+#[inline]
+pub(crate) async fn read_payload_into<SR>(
+  dst: &mut [u8],
+  pfb: &mut PartitionedFilledBuffer,
+  stream_reader: &mut SR,
+) -> crate::Result<()>
+where
+  SR: StreamReader,
+{
+  let mut read = pfb._following_len().min(dst.len());
+  if let (Some(lhs), Some(rhs)) = (dst.get_mut(..read), pfb._following().get(..read)) {
+    lhs.copy_from_slice(rhs);
+  }
+  consume_following(read, pfb)?;
+  while let Some(local_buffer) = dst.get_mut(read..).filter(|el| !el.is_empty()) {
+    let local_read = stream_reader.read(local_buffer).await?;
+    if local_read == 0 {
+      return Err(crate::Error::ClosedConnection);
+    }
+    read = read.wrapping_add(local_read);
+  }
+  Ok(())
+}
+
+#[cfg(feature = "http2")]
+/// Discards the remaining `len` bytes of the current payload.
+#[inline]
+pub(crate) async fn skip_payload<SR>(
+  len: usize,
+  pfb: &mut PartitionedFilledBuffer,
+  stream_reader: &mut SR,
+) -> crate::Result<()>
+where
+  SR: StreamReader,
+{
+  let buffered = pfb._following_len().min(len);
+  consume_following(buffered, pfb)?;
+  let rest = len.wrapping_sub(buffered);
+  if rest > 0 {
+    stream_reader.read_skip(rest).await?;
+  }
+  Ok(())
+}
+
+#[cfg(feature = "http2")]
+#[inline]
+fn consume_following(len: usize, pfb: &mut PartitionedFilledBuffer) -> crate::Result<()> {
+  let antecedent_len = pfb._current_end_idx().wrapping_add(len);
+  let following_len = pfb._following_len().wrapping_sub(len);
+  pfb._set_indices(antecedent_len, 0, following_len)
+}
+
+#[cfg(all(feature = "_bench", feature = "http2", test))]
+mod bench {
+  use crate::{
+    bench::_data,
+    misc::{
+      BufferMode, BytesStream, StreamWriter, Vector,
+      net::{PartitionedFilledBuffer, read_payload, read_payload_into},
+    },
+  };
+  use core::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+  };
+
+  const LEN: usize = 1024 * 1024 * 8;
+
+  #[bench]
+  fn copy_from_connection_buffer(b: &mut test::Bencher) {
+    let data = _data(LEN);
+    let mut body = Vector::new();
+    let mut pfb = PartitionedFilledBuffer::new();
+    let mut stream = BytesStream::default();
+    b.iter(|| {
+      body.clear();
+      pfb._clear();
+      poll_ready(stream.write_all(&data)).unwrap();
+      poll_ready(read_payload((0, LEN), &mut pfb, &mut 0, &mut stream)).unwrap();
+      body.extend_from_copyable_slice(pfb._current()).unwrap();
+    });
+  }
+
+  #[bench]
+  fn transfer_to_body(b: &mut test::Bencher) {
+    let data = _data(LEN);
+    let mut body = Vector::new();
+    let mut pfb = PartitionedFilledBuffer::new();
+    let mut stream = BytesStream::default();
+    b.iter(|| {
+      body.clear();
+      pfb._clear();
+      poll_ready(stream.write_all(&data)).unwrap();
+      body.expand(BufferMode::Len(LEN), 0).unwrap();
+      poll_ready(read_payload_into(&mut body, &mut pfb, &mut stream)).unwrap();
+    });
+  }
+
+  fn poll_ready<T>(fut: impl Future<Output = T>) -> T {
+    match pin!(fut).poll(&mut Context::from_waker(Waker::noop())) {
+      Poll::Ready(elem) => elem,
+      Poll::Pending => panic!(),
+    }
+  }
+}
+
+#[cfg(all(feature = "http2", test))]
+mod tests {
+  use crate::misc::{
+    BytesStream, StreamWriter,
+    net::{PartitionedFilledBuffer, read_payload_into},
+  };
+  use core::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+  };
+
+  #[test]
+  fn read_payload_into_only_takes_filled_bytes() {
+    let mut pfb = PartitionedFilledBuffer::_with_capacity(32).unwrap();
+    pfb._all_mut().get_mut(..4).unwrap().copy_from_slice(&[1, 2, 3, 4]);
+    pfb._set_indices(0, 0, 4).unwrap();
+    let mut stream = BytesStream::default();
+    poll_ready(stream.write_all(&[5, 6])).unwrap();
+    let mut dst = [0; 6];
+    poll_ready(read_payload_into(&mut dst, &mut pfb, &mut stream)).unwrap();
+    assert_eq!(dst, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(pfb._following_len(), 0);
+  }
+
+  fn poll_ready<T>(fut: impl Future<Output = T>) -> T {
+    match pin!(fut).poll(&mut Context::from_waker(Waker::noop())) {
+      Poll::Ready(elem) => elem,
+      Poll::Pending => panic!(),
+    }
+  }
+}
@@ -157,7 +157,7 @@ impl<'bytes> PercentDecode<'bytes> {
 }
 
 #[inline]
-fn manage_percent_char(bytes: &mut &[u8]) -> Option<u8> {
+pub(crate) fn manage_percent_char(bytes: &mut &[u8]) -> Option<u8> {
   let [a, b, rest @ ..] = bytes else {
     return None;
   };
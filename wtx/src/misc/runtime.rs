@@ -0,0 +1,39 @@
+/// Executes detached tasks that run concurrently with the caller.
+pub trait Spawner {
+  /// Spawns `fut` in the background. The output is discarded.
+  fn spawn<F>(&self, fut: F)
+  where
+    F: Future<Output = ()> + Send + 'static;
+}
+
+/// [`Spawner`] backed by the global executor of `smol`.
+#[cfg(feature = "smol")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SmolRuntime;
+
+#[cfg(feature = "smol")]
+impl Spawner for SmolRuntime {
+  #[inline]
+  fn spawn<F>(&self, fut: F)
+  where
+    F: Future<Output = ()> + Send + 'static,
+  {
+    smol::spawn(fut).detach();
+  }
+}
+
+/// [`Spawner`] backed by the current runtime of `tokio`.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioRuntime;
+
+#[cfg(feature = "tokio")]
+impl Spawner for TokioRuntime {
+  #[inline]
+  fn spawn<F>(&self, fut: F)
+  where
+    F: Future<Output = ()> + Send + 'static,
+  {
+    let _jh = tokio::spawn(fut);
+  }
+}
@@ -31,20 +31,28 @@ macro_rules! _local_write_all_vectored {
 mod bytes_stream;
 #[cfg(feature = "embassy-net")]
 mod embassy_net;
+#[cfg(feature = "embedded-io-async")]
+mod embedded_io_stream;
+#[cfg(feature = "smol")]
+mod smol;
 #[cfg(feature = "std")]
 mod std;
 mod stream_reader;
 mod stream_with_tls;
 mod stream_writer;
+mod tls_connector;
 #[cfg(feature = "tokio")]
 mod tokio;
 #[cfg(feature = "tokio-rustls")]
 mod tokio_rustls;
 
 pub use bytes_stream::BytesStream;
+#[cfg(feature = "embedded-io-async")]
+pub use embedded_io_stream::EmbeddedIoStream;
 pub use stream_reader::StreamReader;
 pub use stream_with_tls::StreamWithTls;
 pub use stream_writer::StreamWriter;
+pub use tls_connector::TlsConnector;
 
 /// A stream of values produced asynchronously.
 pub trait Stream: StreamReader + StreamWriter {}
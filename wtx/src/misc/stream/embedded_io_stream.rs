@@ -0,0 +1,43 @@
+use crate::misc::{StreamReader, StreamWriter};
+use embedded_io_async::{Error, Read, Write};
+
+/// Allows the usage of `embedded-io-async` streams, like the TCP connections provided by
+/// implementations of `embedded-nal-async`, wherever a [`crate::misc::Stream`] is expected.
+///
+/// ```ignore
+/// let conn = tcp_connect.connect(addr).await?;
+/// let executor = PostgresExecutor::connect(&config, ee, &mut rng, EmbeddedIoStream(conn)).await?;
+/// ```
+#[derive(Debug)]
+pub struct EmbeddedIoStream<T>(
+  /// Inner stream
+  pub T,
+);
+
+impl<T> StreamReader for EmbeddedIoStream<T>
+where
+  T: Read,
+{
+  #[inline]
+  async fn read(&mut self, bytes: &mut [u8]) -> crate::Result<usize> {
+    self.0.read(bytes).await.map_err(|err| err.kind().into())
+  }
+}
+
+impl<T> StreamWriter for EmbeddedIoStream<T>
+where
+  T: Write,
+{
+  #[inline]
+  async fn write_all(&mut self, bytes: &[u8]) -> crate::Result<()> {
+    self.0.write_all(bytes).await.map_err(|err| err.kind().into())
+  }
+
+  #[inline]
+  async fn write_all_vectored(&mut self, bytes: &[&[u8]]) -> crate::Result<()> {
+    for elem in bytes {
+      self.write_all(elem).await?;
+    }
+    Ok(())
+  }
+}
@@ -24,3 +24,33 @@ impl StreamWriter for TcpStream {
     Ok(())
   }
 }
+
+#[cfg(unix)]
+mod unix {
+  use crate::misc::{StreamReader, StreamWriter};
+  use std::{
+    io::{Read, Write},
+    os::unix::net::UnixStream,
+  };
+
+  impl StreamReader for UnixStream {
+    #[inline]
+    async fn read(&mut self, bytes: &mut [u8]) -> crate::Result<usize> {
+      Ok(<Self as Read>::read(self, bytes)?)
+    }
+  }
+
+  impl StreamWriter for UnixStream {
+    #[inline]
+    async fn write_all(&mut self, bytes: &[u8]) -> crate::Result<()> {
+      <Self as Write>::write_all(self, bytes)?;
+      Ok(())
+    }
+
+    #[inline]
+    async fn write_all_vectored(&mut self, bytes: &[&[u8]]) -> crate::Result<()> {
+      _local_write_all_vectored!(bytes, self, |io_slices| self.write_vectored(io_slices));
+      Ok(())
+    }
+  }
+}
@@ -1,3 +1,5 @@
+use crate::misc::Either;
+
 /// A stream of values sent asynchronously.
 pub trait StreamReader {
   /// Pulls some bytes from this source into the specified buffer, returning how many bytes
@@ -26,6 +28,20 @@ pub trait StreamReader {
   }
 }
 
+impl<L, R> StreamReader for Either<L, R>
+where
+  L: StreamReader,
+  R: StreamReader,
+{
+  #[inline]
+  async fn read(&mut self, bytes: &mut [u8]) -> crate::Result<usize> {
+    match self {
+      Either::Left(elem) => elem.read(bytes).await,
+      Either::Right(elem) => elem.read(bytes).await,
+    }
+  }
+}
+
 impl<T> StreamReader for &mut T
 where
   T: StreamReader,
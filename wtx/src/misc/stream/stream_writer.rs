@@ -1,3 +1,5 @@
+use crate::misc::Either;
+
 /// A stream of values written asynchronously.
 pub trait StreamWriter {
   /// Attempts to write ***all*** `bytes`.
@@ -11,6 +13,28 @@ pub trait StreamWriter {
   fn write_all_vectored(&mut self, bytes: &[&[u8]]) -> impl Future<Output = crate::Result<()>>;
 }
 
+impl<L, R> StreamWriter for Either<L, R>
+where
+  L: StreamWriter,
+  R: StreamWriter,
+{
+  #[inline]
+  async fn write_all(&mut self, bytes: &[u8]) -> crate::Result<()> {
+    match self {
+      Either::Left(elem) => elem.write_all(bytes).await,
+      Either::Right(elem) => elem.write_all(bytes).await,
+    }
+  }
+
+  #[inline]
+  async fn write_all_vectored(&mut self, bytes: &[&[u8]]) -> crate::Result<()> {
+    match self {
+      Either::Left(elem) => elem.write_all_vectored(bytes).await,
+      Either::Right(elem) => elem.write_all_vectored(bytes).await,
+    }
+  }
+}
+
 impl<T> StreamWriter for &mut T
 where
   T: StreamWriter,
@@ -0,0 +1,14 @@
+use crate::misc::{Stream, StreamWithTls};
+
+/// Upgrades an already established stream into an encrypted stream.
+pub trait TlsConnector<S> {
+  /// Encrypted stream
+  type TlsStream: Stream + StreamWithTls;
+
+  /// Performs the TLS handshake. `hostname` is used to verify the certificate of the server.
+  fn connect(
+    self,
+    hostname: &str,
+    stream: S,
+  ) -> impl Future<Output = crate::Result<Self::TlsStream>>;
+}
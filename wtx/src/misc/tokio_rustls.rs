@@ -1,12 +1,16 @@
 use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
-use rustls_pki_types::ServerName;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use std::sync::RwLock;
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio_rustls::{
   TlsAcceptor, TlsConnector,
   client::TlsStream,
   rustls::{
-    ClientConfig, ConfigBuilder, RootCertStore, ServerConfig, client::WantsClientCert,
-    server::WantsServerCert,
+    ClientConfig, ConfigBuilder, RootCertStore, ServerConfig,
+    client::WantsClientCert,
+    crypto::ring::sign::any_supported_type,
+    server::{ClientHello, ResolvesServerCert, WantsServerCert},
+    sign::CertifiedKey,
   },
 };
 
@@ -14,6 +18,7 @@ use tokio_rustls::{
 #[derive(Debug)]
 pub struct TokioRustlsConnector {
   alpn_protocols: Vec<Vec<u8>>,
+  client_auth: Option<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)>,
   store: RootCertStore,
 }
 
@@ -33,6 +38,31 @@ impl TokioRustlsConnector {
     return Err(crate::Error::MissingCaProviders);
   }
 
+  /// Presents a PEM-encoded certificate chain and its PEM-encoded private key to servers that
+  /// require client authentication, also known as mutual TLS.
+  ///
+  /// Only used by the [`crate::misc::TlsConnector`] implementation.
+  #[inline]
+  pub fn client_auth(mut self, cert_chain: &[u8], priv_key: &[u8]) -> crate::Result<Self> {
+    self.client_auth = Some((
+      rustls_pemfile::certs(&mut &*cert_chain).collect::<Result<_, _>>()?,
+      rustls_pemfile::private_key(&mut &*priv_key)?
+        .ok_or_else(|| invalid_input_err("No private key"))?,
+    ));
+    Ok(self)
+  }
+
+  /// Similar to [`Self::client_auth`] but reads the contents from the file system.
+  #[inline]
+  pub async fn client_auth_from_paths(
+    self,
+    cert_chain_path: &str,
+    priv_key_path: &str,
+  ) -> crate::Result<Self> {
+    let (cert_chain, priv_key) = read_paths(cert_chain_path, priv_key_path).await?;
+    self.client_auth(&cert_chain, &priv_key)
+  }
+
   /// Connects using a generic stream without client authentication.
   #[inline]
   pub async fn connect_without_client_auth<S>(
@@ -45,7 +75,7 @@ impl TokioRustlsConnector {
   {
     Ok(
       self
-        .tls_connector(|elem| elem.with_no_client_auth())
+        .tls_connector(|elem| Ok(elem.with_no_client_auth()))?
         .connect(Self::server_name(hostname)?, stream)
         .await?,
     )
@@ -85,18 +115,38 @@ impl TokioRustlsConnector {
   #[inline]
   fn tls_connector(
     self,
-    cb: impl FnOnce(ConfigBuilder<ClientConfig, WantsClientCert>) -> ClientConfig,
-  ) -> TlsConnector {
-    let mut config = cb(ClientConfig::builder().with_root_certificates(self.store));
+    cb: impl FnOnce(ConfigBuilder<ClientConfig, WantsClientCert>) -> crate::Result<ClientConfig>,
+  ) -> crate::Result<TlsConnector> {
+    let mut config = cb(ClientConfig::builder().with_root_certificates(self.store))?;
     config.alpn_protocols = self.alpn_protocols;
-    TlsConnector::from(Arc::new(config))
+    Ok(TlsConnector::from(Arc::new(config)))
+  }
+}
+
+impl<S> crate::misc::TlsConnector<S> for TokioRustlsConnector
+where
+  S: AsyncRead + AsyncWrite + Unpin,
+{
+  type TlsStream = TlsStream<S>;
+
+  #[inline]
+  async fn connect(mut self, hostname: &str, stream: S) -> crate::Result<Self::TlsStream> {
+    let Some((cert_chain, priv_key)) = self.client_auth.take() else {
+      return self.connect_without_client_auth(hostname, stream).await;
+    };
+    Ok(
+      self
+        .tls_connector(|elem| Ok(elem.with_client_auth_cert(cert_chain, priv_key)?))?
+        .connect(Self::server_name(hostname)?, stream)
+        .await?,
+    )
   }
 }
 
 impl Default for TokioRustlsConnector {
   #[inline]
   fn default() -> Self {
-    Self { alpn_protocols: Vec::new(), store: RootCertStore::empty() }
+    Self { alpn_protocols: Vec::new(), client_auth: None, store: RootCertStore::empty() }
   }
 }
 
@@ -134,6 +184,30 @@ impl TokioRustlsAcceptor {
     Ok(TlsAcceptor::from(Arc::new(config)))
   }
 
+  /// Creates a [`tokio_rustls::TlsAcceptor`] whose certificates are provided by `resolver`, which
+  /// allows the replacement of certificates without restarting the server.
+  ///
+  /// If `resolver` answers ACME challenges, then `acme-tls/1` is also advertised alongside `h2` or
+  /// `http/1.1`.
+  #[inline]
+  pub fn build_with_cert_resolver(self, resolver: Arc<TokioRustlsCertResolver>) -> TlsAcceptor {
+    #[cfg(feature = "acme")]
+    let has_acme_challenges = resolver.acme_challenges.is_some();
+    let mut config = self.builder.with_cert_resolver(resolver);
+    if self.is_http2 {
+      config.alpn_protocols.clear();
+      config.alpn_protocols.push("h2".into());
+    }
+    #[cfg(feature = "acme")]
+    if has_acme_challenges {
+      if !self.is_http2 {
+        config.alpn_protocols.push("http/1.1".into());
+      }
+      config.alpn_protocols.push(crate::acme::ACME_TLS_ALPN.into());
+    }
+    TlsAcceptor::from(Arc::new(config))
+  }
+
   /// Erases the set of ALPN protocols when building and then pushes the expected ALPN value for an
   /// HTTP2 connection.
   #[inline]
@@ -143,6 +217,113 @@ impl TokioRustlsAcceptor {
   }
 }
 
+/// Holds a certificate chain and its private key that can be reloaded at any time. New TLS
+/// handshakes use the most recent pair while established connections are not affected.
+///
+/// See [`TokioRustlsAcceptor::build_with_cert_resolver`].
+#[derive(Debug)]
+pub struct TokioRustlsCertResolver {
+  #[cfg(feature = "acme")]
+  acme_challenges: Option<Arc<crate::acme::AcmeChallenges>>,
+  certified_key: RwLock<Option<Arc<CertifiedKey>>>,
+}
+
+impl TokioRustlsCertResolver {
+  /// New instance with a PEM-encoded certificate chain and a PEM-encoded private key.
+  #[inline]
+  pub fn new(cert_chain: &[u8], priv_key: &[u8]) -> crate::Result<Self> {
+    let elem = Arc::new(certified_key(cert_chain, priv_key)?);
+    Ok(Self {
+      #[cfg(feature = "acme")]
+      acme_challenges: None,
+      certified_key: RwLock::new(Some(elem)),
+    })
+  }
+
+  /// New instance without a certificate chain, which is useful when the first certificate is
+  /// issued by an ACME server. Handshakes fail until [`Self::reload`] is called.
+  #[inline]
+  pub const fn empty() -> Self {
+    Self {
+      #[cfg(feature = "acme")]
+      acme_challenges: None,
+      certified_key: RwLock::new(None),
+    }
+  }
+
+  /// Answers TLS-ALPN-01 challenges with the certificates of `elem`, which are presented to
+  /// handshakes that negotiate `acme-tls/1`.
+  #[cfg(feature = "acme")]
+  #[inline]
+  #[must_use]
+  pub fn acme_challenges(mut self, elem: Arc<crate::acme::AcmeChallenges>) -> Self {
+    self.acme_challenges = Some(elem);
+    self
+  }
+
+  /// Reads the PEM-encoded certificate chain and the PEM-encoded private key from the file system.
+  #[inline]
+  pub async fn from_paths(cert_chain_path: &str, priv_key_path: &str) -> crate::Result<Self> {
+    let (cert_chain, priv_key) = read_paths(cert_chain_path, priv_key_path).await?;
+    Self::new(&cert_chain, &priv_key)
+  }
+
+  /// Replaces the current pair with a new PEM-encoded certificate chain and a new PEM-encoded
+  /// private key. The current pair is kept if the new contents are invalid.
+  #[inline]
+  pub fn reload(&self, cert_chain: &[u8], priv_key: &[u8]) -> crate::Result<()> {
+    let elem = Arc::new(certified_key(cert_chain, priv_key)?);
+    if let Ok(mut guard) = self.certified_key.write() {
+      *guard = Some(elem);
+    }
+    Ok(())
+  }
+
+  /// Similar to [`Self::reload`] but reads the contents from the file system, which is useful
+  /// when certificates are periodically renewed by external tools.
+  #[inline]
+  pub async fn reload_from_paths(
+    &self,
+    cert_chain_path: &str,
+    priv_key_path: &str,
+  ) -> crate::Result<()> {
+    let (cert_chain, priv_key) = read_paths(cert_chain_path, priv_key_path).await?;
+    self.reload(&cert_chain, &priv_key)
+  }
+}
+
+impl ResolvesServerCert for TokioRustlsCertResolver {
+  #[inline]
+  fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+    #[cfg(feature = "acme")]
+    if let Some(acme_challenges) = &self.acme_challenges {
+      if client_hello.alpn().is_some_and(|mut el| el.any(|elem| elem == crate::acme::ACME_TLS_ALPN))
+      {
+        return acme_challenges.tls_alpn01_certified_key(client_hello.server_name()?);
+      }
+    }
+    #[cfg(not(feature = "acme"))]
+    let _client_hello = client_hello;
+    self.certified_key.read().ok()?.clone()
+  }
+}
+
+#[inline]
+fn certified_key(cert_chain: &[u8], priv_key: &[u8]) -> crate::Result<CertifiedKey> {
+  let certs = rustls_pemfile::certs(&mut &*cert_chain).collect::<Result<_, _>>()?;
+  let key = rustls_pemfile::private_key(&mut &*priv_key)?
+    .ok_or_else(|| invalid_input_err("No private key"))?;
+  Ok(CertifiedKey::new(certs, any_supported_type(&key)?))
+}
+
+#[inline]
+async fn read_paths(
+  cert_chain_path: &str,
+  priv_key_path: &str,
+) -> crate::Result<(Vec<u8>, Vec<u8>)> {
+  Ok((tokio::fs::read(cert_chain_path).await?, tokio::fs::read(priv_key_path).await?))
+}
+
 #[inline]
 fn invalid_input_err<E>(err: E) -> std::io::Error
 where
@@ -56,11 +56,14 @@ macro_rules! impl_tuples {
         http::{
           OperationMode, HttpError, StatusCode, AutoStream, ManualStream, Request,
           ReqResBuffer, Response,
-          server_framework::{ConnAux, Endpoint, Middleware, StreamAux, RouteMatch, EndpointNode, PathParams}
+          server_framework::{
+            ConnAux, Endpoint, HealthChecks, Middleware, StreamAux, RouteMatch, EndpointNode,
+            PathParams
+          }
         },
         misc::{ArrayVector, Vector}
       };
-      use core::ops::ControlFlow;
+      use core::{net::IpAddr, ops::ControlFlow};
 
       $(
         impl<$($T,)*> ConnAux for ($($T,)*)
@@ -87,6 +90,19 @@ macro_rules! impl_tuples {
           }
         }
 
+        impl<$($T,)*> HealthChecks for ($($T,)*)
+        where
+          $($T: HealthChecks,)*
+        {
+          #[allow(unused_mut, reason = "0-arity tuple")]
+          #[inline]
+          async fn check(&self, _buffer: &mut Vector<u8>) -> crate::Result<bool> {
+            let mut _is_healthy = true;
+            $( _is_healthy &= self.$N.check(_buffer).await?; )*
+            Ok(_is_healthy)
+          }
+        }
+
         impl<$($T,)* CA, ERR, SA> Middleware<CA, ERR, SA> for ($($T,)*)
         where
           $($T: Middleware<CA, ERR, SA>,)*
@@ -99,21 +115,43 @@ macro_rules! impl_tuples {
             ($(self.$N.aux(),)*)
           }
 
+          #[allow(unreachable_code, unused_variables, reason = "0-arity tuple")]
           #[inline]
           async fn req(
             &self,
             _conn_aux: &mut CA,
             _mw_aux: &mut Self::Aux,
+            _peer: IpAddr,
             _req: &mut Request<ReqResBuffer>,
             _stream_aux: &mut SA,
           ) -> Result<ControlFlow<StatusCode, ()>, ERR> {
-            $({
-              let rslt = self.$N.req(_conn_aux, &mut _mw_aux.$N, _req, _stream_aux).await?;
+            let mut _idx: usize = 0;
+            let halted: Option<StatusCode> = loop {
+              let rslt: ControlFlow<StatusCode, ()> = match _idx {
+                $($N => self.$N.req(_conn_aux, &mut _mw_aux.$N, _peer, _req, _stream_aux).await?,)*
+                _ => break None,
+              };
               if let ControlFlow::Break(status_code) = rslt {
-                return Ok(ControlFlow::Break(status_code));
+                break Some(status_code);
               }
-            })*
-            Ok(ControlFlow::Continue(()))
+              _idx = _idx.wrapping_add(1);
+            };
+            let Some(status_code) = halted else {
+              return Ok(ControlFlow::Continue(()));
+            };
+            // Previous elements also observe the response generated by the halted request
+            while let Some(idx) = _idx.checked_sub(1) {
+              _idx = idx;
+              let _local_res = Response { rrd: &mut _req.rrd, status_code, version: _req.version };
+              let rslt: ControlFlow<StatusCode, ()> = match idx {
+                $($N => self.$N.res(_conn_aux, &mut _mw_aux.$N, _local_res, _stream_aux).await?,)*
+                _ => ControlFlow::Continue(()),
+              };
+              if let ControlFlow::Break(local_status_code) = rslt {
+                return Ok(ControlFlow::Break(local_status_code));
+              }
+            }
+            Ok(ControlFlow::Break(status_code))
           }
 
           #[inline]
@@ -124,17 +162,23 @@ macro_rules! impl_tuples {
             _res: Response<&mut ReqResBuffer>,
             _stream_aux: &mut SA,
           ) -> Result<ControlFlow<StatusCode, ()>, ERR> {
-            $({
-              let local_res = Response {
+            // Responses are visited in the reverse order of requests
+            let mut _idx: usize = const { 0 $(+ { const $T: usize = 1; $T })* };
+            while let Some(idx) = _idx.checked_sub(1) {
+              _idx = idx;
+              let _local_res = Response {
                 rrd: &mut *_res.rrd,
                 status_code: _res.status_code,
                 version: _res.version,
               };
-              let rslt = self.$N.res(_conn_aux, &mut _mw_aux.$N, local_res, _stream_aux).await?;
+              let rslt: ControlFlow<StatusCode, ()> = match idx {
+                $($N => self.$N.res(_conn_aux, &mut _mw_aux.$N, _local_res, _stream_aux).await?,)*
+                _ => ControlFlow::Continue(()),
+              };
               if let ControlFlow::Break(status_code) = rslt {
                 return Ok(ControlFlow::Break(status_code));
               }
-            })*
+            }
             Ok(ControlFlow::Continue(()))
           }
         }
@@ -230,7 +274,10 @@ macro_rules! impl_tuples {
           ) -> crate::Result<()> {
             $({
               let mut local_prev = _prev.clone();
-              local_prev.push(RouteMatch::new($N, $T::OM, self.$N.full_path))?;
+              local_prev.push(RouteMatch {
+                max_body_len: self.$N.max_body_len,
+                ..RouteMatch::new($N, $T::OM, self.$N.full_path)
+              })?;
               if $T::IS_ROUTER {
                 self.$N.value.paths_indices(local_prev, _vec)?;
               } else {
@@ -6,7 +6,7 @@ mod simple_pool;
 
 #[cfg(feature = "postgres")]
 pub use resource_manager::database::PostgresRM;
-pub use resource_manager::{ResourceManager, SimpleRM};
+pub use resource_manager::{ResourceManager, SimpleRM, ValidatedRM};
 #[cfg(feature = "std")]
 pub use simple_pool::*;
 
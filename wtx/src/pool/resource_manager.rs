@@ -24,6 +24,19 @@ pub trait ResourceManager {
     aux: &Self::RecycleAux,
     resource: &mut Self::Resource,
   ) -> impl Future<Output = Result<(), Self::Error>>;
+
+  /// Checks if an existing resource that isn't invalid can still be used when it is acquired, e.g.,
+  /// by sending a ping. Resources that fail validation are recycled.
+  ///
+  /// Does nothing by default.
+  #[inline]
+  fn validate(
+    &self,
+    resource: &mut Self::Resource,
+  ) -> impl Future<Output = Result<(), Self::Error>> {
+    let _resource = resource;
+    async { Ok(()) }
+  }
 }
 
 impl ResourceManager for () {
@@ -89,6 +102,62 @@ where
   }
 }
 
+/// Wraps another manager and runs `cb` whenever an existing resource is acquired.
+///
+/// Useful for liveness checks like a Postgres query or an HTTP/2 ping. Resources that fail
+/// validation are recycled by the wrapped manager.
+#[derive(Debug)]
+pub struct ValidatedRM<F, RM> {
+  /// Validation callback
+  pub cb: F,
+  /// Wrapped manager
+  pub rm: RM,
+}
+
+impl<F, RM> ValidatedRM<F, RM> {
+  /// Shortcut constructor
+  #[inline]
+  pub fn new(cb: F, rm: RM) -> Self {
+    Self { cb, rm }
+  }
+}
+
+impl<F, RM> ResourceManager for ValidatedRM<F, RM>
+where
+  F: AsyncFn(&mut RM::Resource) -> Result<(), RM::Error>,
+  RM: ResourceManager,
+{
+  type CreateAux = RM::CreateAux;
+  type Error = RM::Error;
+  type RecycleAux = RM::RecycleAux;
+  type Resource = RM::Resource;
+
+  #[inline]
+  async fn create(&self, aux: &Self::CreateAux) -> Result<Self::Resource, Self::Error> {
+    self.rm.create(aux).await
+  }
+
+  #[inline]
+  async fn is_invalid(&self, resource: &Self::Resource) -> bool {
+    self.rm.is_invalid(resource).await
+  }
+
+  #[inline]
+  async fn recycle(
+    &self,
+    aux: &Self::RecycleAux,
+    resource: &mut Self::Resource,
+  ) -> Result<(), Self::Error> {
+    self.rm.recycle(aux, resource).await
+  }
+
+  #[inline]
+  async fn validate(&self, resource: &mut Self::Resource) -> Result<(), Self::Error> {
+    self.rm.validate(resource).await?;
+    (self.cb)(resource).await
+  }
+}
+
 #[cfg(feature = "postgres")]
 pub(crate) mod database {
   use crate::misc::Vector;
@@ -201,7 +270,7 @@ pub(crate) mod database {
         DEFAULT_MAX_STMTS, Executor as _,
         client::postgres::{ExecutorBuffer, PostgresExecutor},
       },
-      misc::{CryptoRng, TokioRustlsConnector, Vector},
+      misc::{CryptoRng, TlsConnector as _, TokioRustlsConnector, Vector},
       pool::{PostgresRM, ResourceManager},
     };
     use alloc::string::String;
@@ -248,7 +317,10 @@ pub(crate) mod database {
               if let Some(elem) = &self._certs {
                 rslt = rslt.push_certs(elem.as_slice())?;
               }
-              rslt.connect_without_client_auth(uri.hostname(), stream).await
+              if let Some((cert_chain, priv_key)) = config.ssl_cert_and_key() {
+                rslt = rslt.client_auth_from_paths(cert_chain, priv_key).await?;
+              }
+              rslt.connect(uri.hostname(), stream).await
             },
           )
         })
@@ -279,7 +351,10 @@ pub(crate) mod database {
               if let Some(elem) = &self._certs {
                 rslt = rslt.push_certs(elem.as_slice())?;
               }
-              rslt.connect_without_client_auth(uri.hostname(), stream).await
+              if let Some((cert_chain, priv_key)) = config.ssl_cert_and_key() {
+                rslt = rslt.client_auth_from_paths(cert_chain, priv_key).await?;
+              }
+              rslt.connect(uri.hostname(), stream).await
             },
           )
         })?;
@@ -11,12 +11,19 @@ use core::{
 };
 use std::sync::Mutex;
 
+/// A [`SimplePool`] synchronized by [`smol::lock::Mutex`].
+#[cfg(feature = "smol")]
+pub type SimplePoolSmol<RM> =
+  SimplePool<smol::lock::Mutex<SimplePoolResource<<RM as ResourceManager>::Resource>>, RM>;
 /// A [`SimplePool`] synchronized by [`tokio::sync::Mutex`].
 #[cfg(feature = "tokio")]
 pub type SimplePoolTokio<RM> =
   SimplePool<tokio::sync::Mutex<SimplePoolResource<<RM as ResourceManager>::Resource>>, RM>;
 
 /// Pool with a fixed number of elements.
+///
+/// Existing resources are checked through [`ResourceManager::is_invalid`] and
+/// [`ResourceManager::validate`] when acquired and recycled if necessary.
 #[derive(Debug)]
 pub struct SimplePool<RL, RM> {
   available_idxs: Arc<Mutex<Vec<usize>>>,
@@ -59,6 +66,19 @@ where
     Ok(())
   }
 
+  /// Number of elements that can be immediately acquired.
+  #[expect(clippy::unwrap_used, reason = "poisoning is ignored")]
+  #[inline]
+  pub fn available(&self) -> usize {
+    self.available_idxs.lock().unwrap().len()
+  }
+
+  /// Maximum number of elements.
+  #[inline]
+  pub fn capacity(&self) -> usize {
+    self.locks.len()
+  }
+
   #[inline]
   pub(crate) async fn _into_for_each<FUN>(&self, mut cb: impl FnMut(R) -> FUN)
   where
@@ -143,7 +163,7 @@ where
         resource.0 = Some(self.rm.create(ca).await?);
       }
       Some(elem) => {
-        if self.rm.is_invalid(elem).await {
+        if self.rm.is_invalid(elem).await || self.rm.validate(elem).await.is_err() {
           self.rm.recycle(ra, elem).await?;
         }
       }
@@ -243,6 +263,29 @@ impl<R> DerefMut for SimplePoolResource<R> {
   }
 }
 
+#[cfg(feature = "smol")]
+mod _smol {
+  use crate::{
+    misc::{Lease, LeaseMut},
+    pool::{SimplePoolGetElem, SimplePoolResource},
+  };
+  use smol::lock::MutexGuard;
+
+  impl<R> Lease<R> for SimplePoolGetElem<MutexGuard<'_, SimplePoolResource<R>>> {
+    #[inline]
+    fn lease(&self) -> &R {
+      &self.resource
+    }
+  }
+
+  impl<R> LeaseMut<R> for SimplePoolGetElem<MutexGuard<'_, SimplePoolResource<R>>> {
+    #[inline]
+    fn lease_mut(&mut self) -> &mut R {
+      &mut self.resource
+    }
+  }
+}
+
 #[cfg(feature = "tokio")]
 mod _tokio {
   use crate::{
@@ -268,7 +311,33 @@ mod _tokio {
 
 #[cfg(all(feature = "_async-tests", test))]
 mod tests {
-  use crate::pool::{SimpleRM, simple_pool::SimplePoolTokio};
+  use crate::pool::{ResourceManager, SimpleRM, ValidatedRM, simple_pool::SimplePoolTokio};
+
+  struct ZeroRM;
+
+  impl ResourceManager for ZeroRM {
+    type CreateAux = ();
+    type Error = crate::Error;
+    type RecycleAux = ();
+    type Resource = i32;
+
+    async fn create(&self, _: &Self::CreateAux) -> Result<Self::Resource, Self::Error> {
+      Ok(0)
+    }
+
+    async fn is_invalid(&self, _: &Self::Resource) -> bool {
+      false
+    }
+
+    async fn recycle(
+      &self,
+      _: &Self::RecycleAux,
+      resource: &mut Self::Resource,
+    ) -> Result<(), Self::Error> {
+      *resource = 0;
+      Ok(())
+    }
+  }
 
   #[tokio::test]
   async fn held_lock_is_not_modified() {
@@ -287,6 +356,21 @@ mod tests {
     assert_eq!([***pool.get().await.unwrap(), ***pool.get().await.unwrap()], [1, 2]);
   }
 
+  #[tokio::test]
+  async fn resources_that_fail_validation_are_recycled() {
+    let pool = SimplePoolTokio::new(
+      1,
+      ValidatedRM::new(
+        async |el: &mut i32| if *el < 0 { Err(crate::Error::ClosedConnection) } else { Ok(()) },
+        ZeroRM,
+      ),
+    );
+    ***pool.get().await.unwrap() = 2;
+    assert_eq!(***pool.get().await.unwrap(), 2);
+    ***pool.get().await.unwrap() = -1;
+    assert_eq!(***pool.get().await.unwrap(), 0);
+  }
+
   fn pool() -> SimplePoolTokio<SimpleRM<fn() -> crate::Result<i32>>> {
     SimplePoolTokio::new(2, SimpleRM::new(|| Ok(0)))
   }
@@ -19,10 +19,14 @@ mod web_socket_buffer;
 #[cfg(feature = "web-socket-handshake")]
 mod web_socket_connector;
 mod web_socket_error;
+#[cfg(feature = "metrics")]
+mod web_socket_metrics;
 mod web_socket_parts;
 pub(crate) mod web_socket_reader;
 pub(crate) mod web_socket_writer;
 
+#[cfg(feature = "metrics")]
+use crate::sync::Arc;
 use crate::{
   _MAX_PAYLOAD_LEN,
   misc::{ConnectionState, LeaseMut, Lock, Rng, Stream},
@@ -49,6 +53,8 @@ pub use web_socket_buffer::WebSocketBuffer;
 #[cfg(feature = "web-socket-handshake")]
 pub use web_socket_connector::WebSocketConnector;
 pub use web_socket_error::WebSocketError;
+#[cfg(feature = "metrics")]
+pub use web_socket_metrics::WebSocketMetrics;
 pub use web_socket_parts::{
   web_socket_part_mut::{WebSocketCommonPartMut, WebSocketReaderPartMut, WebSocketWriterPartMut},
   web_socket_part_owned::{
@@ -81,6 +87,8 @@ pub type WebSocketOwned<NC, R, S, const IS_CLIENT: bool> =
 pub struct WebSocket<NC, R, S, WSB, const IS_CLIENT: bool> {
   connection_state: ConnectionState,
   max_payload_len: usize,
+  #[cfg(feature = "metrics")]
+  metrics: Option<Arc<WebSocketMetrics>>,
   nc: NC,
   no_masking: bool,
   rng: R,
@@ -95,6 +103,15 @@ impl<NC, R, S, WSB, const IS_CLIENT: bool> WebSocket<NC, R, S, WSB, IS_CLIENT> {
   pub fn set_max_payload_len(&mut self, max_payload_len: usize) {
     self.max_payload_len = max_payload_len;
   }
+
+  /// Shared instance that records the frames read by [`WebSocket::read_frame`] and written by
+  /// [`WebSocket::write_frame`]. Frames handled by split parts are not recorded. Defaults to
+  /// `None`.
+  #[cfg(feature = "metrics")]
+  #[inline]
+  pub fn set_metrics(&mut self, metrics: Option<Arc<WebSocketMetrics>>) {
+    self.metrics = metrics;
+  }
 }
 
 impl<NC, R, S, WSB, const IS_CLIENT: bool> WebSocket<NC, R, S, WSB, IS_CLIENT>
@@ -110,6 +127,8 @@ where
     Ok(Self {
       connection_state: ConnectionState::Open,
       max_payload_len: _MAX_PAYLOAD_LEN,
+      #[cfg(feature = "metrics")]
+      metrics: None,
       nc,
       no_masking,
       rng,
@@ -127,7 +146,8 @@ where
     WebSocketReaderPartMut<'_, NC, R, S, IS_CLIENT>,
     WebSocketWriterPartMut<'_, NC, R, S, IS_CLIENT>,
   ) {
-    let WebSocket { connection_state, nc, no_masking, rng, stream, wsb, max_payload_len } = self;
+    let WebSocket { connection_state, nc, no_masking, rng, stream, wsb, max_payload_len, .. } =
+      self;
     let WebSocketBuffer {
       writer_buffer,
       network_buffer,
@@ -161,7 +181,17 @@ where
   /// until all fragments are received.
   #[inline]
   pub async fn read_frame(&mut self) -> crate::Result<FrameMut<'_, IS_CLIENT>> {
-    let WebSocket { connection_state, max_payload_len, nc, no_masking, rng, stream, wsb } = self;
+    let WebSocket {
+      connection_state,
+      max_payload_len,
+      #[cfg(feature = "metrics")]
+      metrics,
+      nc,
+      no_masking,
+      rng,
+      stream,
+      wsb,
+    } = self;
     let WebSocketBuffer {
       network_buffer,
       reader_buffer_first,
@@ -187,6 +217,10 @@ where
         }
       )
     );
+    #[cfg(feature = "metrics")]
+    if let Some(elem) = metrics {
+      elem.frame_received(frame.payload().len());
+    }
     Ok(frame)
   }
 
@@ -196,9 +230,21 @@ where
   where
     P: LeaseMut<[u8]>,
   {
-    let WebSocket { connection_state, nc, no_masking, rng, stream, wsb, .. } = self;
+    let WebSocket {
+      connection_state,
+      #[cfg(feature = "metrics")]
+      metrics,
+      nc,
+      no_masking,
+      rng,
+      stream,
+      wsb,
+      ..
+    } = self;
     let WebSocketBuffer { writer_buffer, .. } = wsb.lease_mut();
-    web_socket_writer::write_frame(
+    #[cfg(feature = "metrics")]
+    let payload_len = frame.payload().lease().len();
+    let _compressed_len = web_socket_writer::write_frame(
       connection_state,
       frame,
       *no_masking,
@@ -208,6 +254,10 @@ where
       writer_buffer,
     )
     .await?;
+    #[cfg(feature = "metrics")]
+    if let Some(elem) = metrics {
+      elem.frame_sent(payload_len, _compressed_len);
+    }
     Ok(())
   }
 }
@@ -226,7 +276,8 @@ where
   where
     C: Clone + Lock<Resource = WebSocketCommonPartOwned<NC, R, SW, IS_CLIENT>>,
   {
-    let WebSocket { connection_state, nc, no_masking, rng, stream, wsb, max_payload_len } = self;
+    let WebSocket { connection_state, nc, no_masking, rng, stream, wsb, max_payload_len, .. } =
+      self;
     let WebSocketBuffer {
       writer_buffer,
       network_buffer,
@@ -0,0 +1,158 @@
+use crate::{
+  metrics::{Counter, push_metric_header},
+  misc::{Usize, Vector},
+};
+
+/// Metrics of WebSocket connections that share the same instance through
+/// [`crate::web_socket::WebSocket::set_metrics`].
+///
+/// Received frames are the complete messages returned by
+/// [`crate::web_socket::WebSocket::read_frame`], which means that continuation frames are counted
+/// as a single frame.
+#[derive(Debug)]
+pub struct WebSocketMetrics {
+  compression_input_bytes: Counter,
+  compression_output_bytes: Counter,
+  frames_received: Counter,
+  frames_sent: Counter,
+  payload_bytes_received: Counter,
+  payload_bytes_sent: Counter,
+}
+
+impl WebSocketMetrics {
+  /// Instance without any recorded event.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      compression_input_bytes: Counter::new(),
+      compression_output_bytes: Counter::new(),
+      frames_received: Counter::new(),
+      frames_sent: Counter::new(),
+      payload_bytes_received: Counter::new(),
+      payload_bytes_sent: Counter::new(),
+    }
+  }
+
+  /// Number of original payload bytes of compressed frames. Along with
+  /// [`Self::compression_output_bytes`], allows the calculation of the compression ratio.
+  #[inline]
+  pub fn compression_input_bytes(&self) -> &Counter {
+    &self.compression_input_bytes
+  }
+
+  /// Number of compressed payload bytes of compressed frames.
+  #[inline]
+  pub fn compression_output_bytes(&self) -> &Counter {
+    &self.compression_output_bytes
+  }
+
+  /// Number of received frames.
+  #[inline]
+  pub fn frames_received(&self) -> &Counter {
+    &self.frames_received
+  }
+
+  /// Number of sent frames.
+  #[inline]
+  pub fn frames_sent(&self) -> &Counter {
+    &self.frames_sent
+  }
+
+  /// Number of received payload bytes after decompression.
+  #[inline]
+  pub fn payload_bytes_received(&self) -> &Counter {
+    &self.payload_bytes_received
+  }
+
+  /// Number of sent payload bytes before compression.
+  #[inline]
+  pub fn payload_bytes_sent(&self) -> &Counter {
+    &self.payload_bytes_sent
+  }
+
+  /// Writes all metrics into `buffer` using the text exposition format.
+  #[inline]
+  pub fn render(&self, buffer: &mut Vector<u8>) -> crate::Result<()> {
+    push_metric_header(
+      buffer,
+      "web_socket_compression_input_bytes_total",
+      "Payload bytes given to the compressor.",
+      "counter",
+    )?;
+    self.compression_input_bytes.push(buffer, "web_socket_compression_input_bytes_total", "")?;
+    push_metric_header(
+      buffer,
+      "web_socket_compression_output_bytes_total",
+      "Payload bytes returned by the compressor.",
+      "counter",
+    )?;
+    self.compression_output_bytes.push(buffer, "web_socket_compression_output_bytes_total", "")?;
+    push_metric_header(buffer, "web_socket_frames_received_total", "Received frames.", "counter")?;
+    self.frames_received.push(buffer, "web_socket_frames_received_total", "")?;
+    push_metric_header(buffer, "web_socket_frames_sent_total", "Sent frames.", "counter")?;
+    self.frames_sent.push(buffer, "web_socket_frames_sent_total", "")?;
+    push_metric_header(
+      buffer,
+      "web_socket_payload_bytes_received_total",
+      "Received payload bytes after decompression.",
+      "counter",
+    )?;
+    self.payload_bytes_received.push(buffer, "web_socket_payload_bytes_received_total", "")?;
+    push_metric_header(
+      buffer,
+      "web_socket_payload_bytes_sent_total",
+      "Sent payload bytes before compression.",
+      "counter",
+    )?;
+    self.payload_bytes_sent.push(buffer, "web_socket_payload_bytes_sent_total", "")?;
+    Ok(())
+  }
+
+  #[inline]
+  pub(crate) fn frame_received(&self, payload_len: usize) {
+    self.frames_received.inc();
+    self.payload_bytes_received.add(Usize::from(payload_len).into_u64());
+  }
+
+  #[inline]
+  pub(crate) fn frame_sent(&self, payload_len: usize, compressed_len: Option<usize>) {
+    self.frames_sent.inc();
+    self.payload_bytes_sent.add(Usize::from(payload_len).into_u64());
+    if let Some(elem) = compressed_len {
+      self.compression_input_bytes.add(Usize::from(payload_len).into_u64());
+      self.compression_output_bytes.add(Usize::from(elem).into_u64());
+    }
+  }
+}
+
+impl Default for WebSocketMetrics {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    misc::{Vector, from_utf8_basic},
+    web_socket::WebSocketMetrics,
+  };
+
+  #[test]
+  fn render() {
+    let metrics = WebSocketMetrics::new();
+    metrics.frame_received(4);
+    metrics.frame_sent(10, None);
+    metrics.frame_sent(100, Some(25));
+    let mut buffer = Vector::new();
+    metrics.render(&mut buffer).unwrap();
+    let text = from_utf8_basic(&buffer).unwrap();
+    assert!(text.contains("web_socket_compression_input_bytes_total 100\n"));
+    assert!(text.contains("web_socket_compression_output_bytes_total 25\n"));
+    assert!(text.contains("web_socket_frames_received_total 1\n"));
+    assert!(text.contains("web_socket_frames_sent_total 2\n"));
+    assert!(text.contains("web_socket_payload_bytes_received_total 4\n"));
+    assert!(text.contains("web_socket_payload_bytes_sent_total 110\n"));
+  }
+}
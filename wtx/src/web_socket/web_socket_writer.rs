@@ -67,6 +67,7 @@ pub(crate) fn manage_normal_frame<P, R, const IS_CLIENT: bool>(
   mask_frame(frame, no_masking, rng);
 }
 
+// Returns the length of the compressed payload, if any.
 #[inline]
 pub(crate) async fn write_frame<NC, P, R, SW, const IS_CLIENT: bool>(
   connection_state: &mut ConnectionState,
@@ -76,7 +77,7 @@ pub(crate) async fn write_frame<NC, P, R, SW, const IS_CLIENT: bool>(
   rng: &mut R,
   stream: &mut SW,
   writer_buffer: &mut Vector<u8>,
-) -> crate::Result<()>
+) -> crate::Result<Option<usize>>
 where
   NC: NegotiatedCompression,
   P: LeaseMut<[u8]>,
@@ -86,12 +87,13 @@ where
   if manage_compression(frame, nc) {
     let fr = manage_frame_compression(connection_state, nc, frame, no_masking, rng, writer_buffer)?;
     stream.write_all_vectored(&[fr.header(), fr.payload()]).await?;
+    Ok(Some(fr.payload().len()))
   } else {
     manage_normal_frame::<_, _, IS_CLIENT>(connection_state, frame, no_masking, rng);
     let (header, payload) = frame.header_and_payload_mut();
     stream.write_all_vectored(&[header, payload.lease()]).await?;
+    Ok(None)
   }
-  Ok(())
 }
 
 #[inline]